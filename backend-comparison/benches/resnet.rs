@@ -1,24 +1,15 @@
 use backend_comparison::persistence::save;
+use burn::nn::vision::{ResNet, ResNetConfig};
 use burn::tensor::{backend::Backend, Distribution, Shape, Tensor};
 use burn_common::benchmark::{run_benchmark, Benchmark};
 
-// Files retrieved during build to avoid reimplementing ResNet for benchmarks
-mod block {
-    extern crate alloc;
-    include!(concat!(env!("OUT_DIR"), "/block.rs"));
-}
-
-mod model {
-    include!(concat!(env!("OUT_DIR"), "/resnet.rs"));
-}
-
 pub struct ResNetBenchmark<B: Backend> {
     shape: Shape,
     device: B::Device,
 }
 
 impl<B: Backend> Benchmark for ResNetBenchmark<B> {
-    type Args = (model::ResNet<B>, Tensor<B, 4>);
+    type Args = (ResNet<B>, Tensor<B, 4>);
 
     fn name(&self) -> String {
         "resnet50".into()
@@ -34,7 +25,7 @@ impl<B: Backend> Benchmark for ResNetBenchmark<B> {
 
     fn prepare(&self) -> Self::Args {
         // 1k classes like ImageNet
-        let model = model::ResNet::resnet50(1000, &self.device);
+        let model = ResNetConfig::resnet50(1000).init(&self.device);
         let input = Tensor::random(self.shape.clone(), Distribution::Default, &self.device);
 
         (model, input)
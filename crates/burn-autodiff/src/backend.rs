@@ -73,6 +73,13 @@ impl<B: Backend, C: CheckpointStrategy> AutodiffBackend for Autodiff<B, C> {
     ) -> Option<B::FloatTensorPrimitive> {
         grads.remove::<B>(tensor)
     }
+
+    fn grad_remove_rows(
+        tensor: &AutodiffTensor<B>,
+        grads: &mut Gradients,
+    ) -> Option<(IntTensor<B>, B::FloatTensorPrimitive)> {
+        grads.remove_rows::<B>(tensor)
+    }
     fn inner(tensor: AutodiffTensor<B>) -> B::FloatTensorPrimitive {
         tensor.primitive
     }
@@ -1,4 +1,13 @@
-use burn_tensor::{backend::Backend, container::TensorContainer, ops::FloatTensor, TensorMetadata};
+use alloc::{boxed::Box, vec};
+use core::any::Any;
+
+use burn_tensor::{
+    backend::Backend,
+    container::TensorContainer,
+    ops::{FloatTensor, IntTensor},
+    Shape, TensorMetadata,
+};
+use hashbrown::HashMap;
 
 use crate::{
     graph::{NodeRef, Requirement},
@@ -12,6 +21,23 @@ pub type GradID = u64;
 /// Gradients container used during the backward pass.
 pub struct Gradients {
     container: TensorContainer<GradID>,
+    rows: HashMap<GradID, Box<dyn Any + Send>>,
+}
+
+/// Gradients of a subset of the rows of a tensor, see [register_rows](Gradients::register_rows).
+struct RowGradients<B: Backend> {
+    rows: IntTensor<B>,
+    values: FloatTensor<B>,
+    shape: Shape,
+}
+
+impl<B: Backend> RowGradients<B> {
+    fn into_dense(self) -> FloatTensor<B> {
+        let device = B::float_device(&self.values);
+        let zeros = B::float_zeros(self.shape, &device);
+
+        B::float_select_assign(zeros, 0, self.rows, self.values)
+    }
 }
 
 impl Gradients {
@@ -19,6 +45,7 @@ impl Gradients {
     pub fn new<B: Backend>(root_node: NodeRef, root_tensor: FloatTensor<B>) -> Self {
         let mut gradients = Self {
             container: TensorContainer::new(),
+            rows: HashMap::new(),
         };
         gradients.register::<B>(
             root_node.id,
@@ -32,6 +59,8 @@ impl Gradients {
     /// Each tensor should be consumed exactly 1 time if its gradients are only required during the
     /// backward pass, otherwise, it may be consume multiple times.
     pub fn consume<B: Backend>(&mut self, node: &NodeRef) -> FloatTensor<B> {
+        self.densify::<B>(node.id.value);
+
         match node.requirement {
             Requirement::Grad => self
                 .container
@@ -49,16 +78,49 @@ impl Gradients {
 
     /// Removes a grad tensor from the container.
     pub fn remove<B: Backend>(&mut self, tensor: &AutodiffTensor<B>) -> Option<FloatTensor<B>> {
+        self.densify::<B>(tensor.node.id.value);
         self.container
             .remove::<B>(&tensor.node.id.value)
             .map(|tensor| tensor.tensor())
     }
 
+    /// Removes the row-sparse grad of a tensor from the container.
+    ///
+    /// Returns `None` if the tensor has no row-sparse grad or if it also has a dense grad, in which
+    /// case both are summed by [remove](Gradients::remove).
+    pub fn remove_rows<B: Backend>(
+        &mut self,
+        tensor: &AutodiffTensor<B>,
+    ) -> Option<(IntTensor<B>, FloatTensor<B>)> {
+        let id = tensor.node.id.value;
+        if self.container.get::<B>(&id).is_some() {
+            return None;
+        }
+
+        self.rows.remove(&id).map(|grad| {
+            let grad = grad.downcast::<RowGradients<B>>().unwrap();
+            (grad.rows, grad.values)
+        })
+    }
+
     /// Gets a grad tensor from the container.
     pub fn get<B: Backend>(&self, tensor: &AutodiffTensor<B>) -> Option<FloatTensor<B>> {
-        self.container
-            .get::<B>(&tensor.node.id.value)
-            .map(|tensor| tensor.tensor())
+        let id = tensor.node.id.value;
+        let dense = self.container.get::<B>(&id).map(|tensor| tensor.tensor());
+        let rows = self.rows.get(&id).map(|grad| {
+            let grad = grad.downcast_ref::<RowGradients<B>>().unwrap();
+            RowGradients::<B> {
+                rows: grad.rows.clone(),
+                values: grad.values.clone(),
+                shape: grad.shape.clone(),
+            }
+            .into_dense()
+        });
+
+        match (dense, rows) {
+            (Some(dense), Some(rows)) => Some(B::float_add(dense, rows)),
+            (dense, rows) => dense.or(rows),
+        }
     }
 
     /// Register a grad tensor in the container.
@@ -75,4 +137,49 @@ impl Gradients {
                 .register::<B>(node_id.value, burn_tensor::TensorPrimitive::Float(value));
         }
     }
+
+    /// Register the grad of some rows of a tensor in the container.
+    ///
+    /// The rows may contain duplicates, in which case their grads are summed when the grad is made
+    /// dense. If rows are already registered for the tensor, both are concatenated.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_id` - The node of the tensor.
+    /// * `rows` - The indices of the rows along the first dimension.
+    /// * `values` - The grad of each row, its first dimension matching the number of rows.
+    /// * `shape` - The shape of the tensor.
+    pub fn register_rows<B: Backend>(
+        &mut self,
+        node_id: NodeID,
+        rows: IntTensor<B>,
+        values: FloatTensor<B>,
+        shape: Shape,
+    ) {
+        let grad = match self.rows.remove(&node_id.value) {
+            Some(grad_old) => {
+                let grad_old = grad_old.downcast::<RowGradients<B>>().unwrap();
+                RowGradients::<B> {
+                    rows: B::int_cat(vec![grad_old.rows, rows], 0),
+                    values: B::float_cat(vec![grad_old.values, values], 0),
+                    shape,
+                }
+            }
+            None => RowGradients {
+                rows,
+                values,
+                shape,
+            },
+        };
+
+        self.rows.insert(node_id.value, Box::new(grad));
+    }
+
+    /// Merge the row-sparse grad of a tensor into its dense grad.
+    fn densify<B: Backend>(&mut self, id: GradID) {
+        if let Some(grad) = self.rows.remove(&id) {
+            let grad = grad.downcast::<RowGradients<B>>().unwrap();
+            self.register::<B>(NodeID { value: id }, grad.into_dense());
+        }
+    }
 }
@@ -4,7 +4,7 @@ use alloc::vec::Vec;
 use burn_tensor::{
     backend::Backend,
     ops::{BoolTensor, BoolTensorOps, IntTensor},
-    Device, Shape, TensorData,
+    Device, Shape, Slice, TensorData,
 };
 
 impl<B: Backend, C: CheckpointStrategy> BoolTensorOps<Self> for Autodiff<B, C> {
@@ -48,6 +48,18 @@ impl<B: Backend, C: CheckpointStrategy> BoolTensorOps<Self> for Autodiff<B, C> {
         B::bool_slice_assign(tensor, ranges, value)
     }
 
+    fn bool_slice_with_steps(tensor: BoolTensor<Self>, slices: &[Slice]) -> BoolTensor<Self> {
+        B::bool_slice_with_steps(tensor, slices)
+    }
+
+    fn bool_slice_assign_with_steps(
+        tensor: BoolTensor<Self>,
+        slices: &[Slice],
+        value: BoolTensor<Self>,
+    ) -> BoolTensor<Self> {
+        B::bool_slice_assign_with_steps(tensor, slices, value)
+    }
+
     fn bool_cat(tensors: Vec<BoolTensor<B>>, dim: usize) -> BoolTensor<B> {
         B::bool_cat(tensors, dim)
     }
@@ -4,7 +4,7 @@ use alloc::vec::Vec;
 use burn_tensor::{
     backend::Backend,
     ops::{BoolTensor, IntTensor, IntTensorOps},
-    Device, Distribution, Shape, TensorData,
+    Device, Distribution, Reduce, Shape, Slice, TensorData,
 };
 
 impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
@@ -44,6 +44,18 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_slice_assign(tensor, ranges, value)
     }
 
+    fn int_slice_with_steps(tensor: IntTensor<B>, slices: &[Slice]) -> IntTensor<B> {
+        B::int_slice_with_steps(tensor, slices)
+    }
+
+    fn int_slice_assign_with_steps(
+        tensor: IntTensor<B>,
+        slices: &[Slice],
+        value: IntTensor<B>,
+    ) -> IntTensor<B> {
+        B::int_slice_assign_with_steps(tensor, slices, value)
+    }
+
     fn int_cat(tensors: Vec<IntTensor<B>>, dim: usize) -> IntTensor<B> {
         B::int_cat(tensors, dim)
     }
@@ -189,6 +201,17 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_scatter(dim, tensor, indices, value)
     }
 
+    fn int_scatter_reduce(
+        dim: usize,
+        tensor: IntTensor<B>,
+        indices: IntTensor<B>,
+        value: IntTensor<B>,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> IntTensor<B> {
+        B::int_scatter_reduce(dim, tensor, indices, value, reduce, include_self)
+    }
+
     fn int_select(tensor: IntTensor<B>, dim: usize, indices: IntTensor<B>) -> IntTensor<B> {
         B::int_select(tensor, dim, indices)
     }
@@ -350,6 +373,24 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_argsort(tensor, dim, descending)
     }
 
+    async fn int_unique(
+        tensor: IntTensor<Self>,
+    ) -> (IntTensor<Self>, IntTensor<Self>, IntTensor<Self>) {
+        B::int_unique(tensor).await
+    }
+
+    fn int_searchsorted(
+        sorted: IntTensor<Self>,
+        values: IntTensor<Self>,
+        right: bool,
+    ) -> IntTensor<Self> {
+        B::int_searchsorted(sorted, values, right)
+    }
+
+    fn int_bincount(tensor: IntTensor<Self>, min_length: usize) -> IntTensor<Self> {
+        B::int_bincount(tensor, min_length)
+    }
+
     fn bitwise_and(lhs: IntTensor<Self>, rhs: IntTensor<Self>) -> IntTensor<Self> {
         B::bitwise_and(lhs, rhs)
     }
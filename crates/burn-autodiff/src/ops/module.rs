@@ -6,8 +6,12 @@ use crate::ops::{unary, Backward, Ops};
 use crate::tensor::AutodiffTensor;
 use crate::Autodiff;
 
+use alloc::vec;
+use alloc::vec::Vec;
+
 use burn_tensor::backend::Backend;
 use burn_tensor::ops::*;
+use burn_tensor::{Shape, TensorMetadata};
 
 use super::OpsKind;
 
@@ -54,6 +58,49 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         panic!("Can't differentiate embedding backward.");
     }
 
+    fn embedding_sparse(weights: AutodiffTensor<B>, indices: IntTensor<B>) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct EmbeddingSparse;
+
+        impl<B: Backend> Backward<B, 1> for EmbeddingSparse {
+            type State = (Shape, IntTensor<B>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let [node_weights] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+                let (shape_weights, indices) = ops.state;
+
+                if let Some(node) = node_weights {
+                    let [batch_size, seq_length] = indices.shape().dims();
+                    let d_model = shape_weights.dims[1];
+
+                    let rows = B::int_reshape(indices, Shape::new([batch_size * seq_length]));
+                    let values =
+                        B::float_reshape(grad, Shape::new([batch_size * seq_length, d_model]));
+
+                    grads.register_rows::<B>(node.id, rows, values, shape_weights);
+                }
+            }
+        }
+
+        match EmbeddingSparse
+            .prepare::<C>([weights.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (weights.primitive.shape(), indices.clone()),
+                B::embedding(weights.primitive, indices),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::embedding(weights.primitive, indices)),
+        }
+    }
+
     fn conv1d(
         x: AutodiffTensor<B>,
         weight: AutodiffTensor<B>,
@@ -1175,20 +1222,157 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         }
     }
 
-    // TODO: Support a custom unfold4d operation by overriding the default implementation.
-    //
-    // We don't override it now because the fold operation isn't available for the backward pass.
-    // This implies that when autodiff is enabled, custom unfold operations defined by backends
-    // won't be used. Instead, the conv2d operation with custom weights matrix will be used.
-    // Therefore, the conv2d backward pass will be used for the unfold4d backward pass.
-    //
-    // fn unfold4d(
-    //     x:AutodiffTensor<B>,
-    //     kernel_size: [usize; 2],
-    //     options: UnfoldOptions,
-    // ) -> AutodiffTensor<B> {
-    //     todo!()
-    // }
+    fn unfold4d(
+        x: AutodiffTensor<B>,
+        kernel_size: [usize; 2],
+        options: UnfoldOptions,
+    ) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct Unfold4D;
+
+        impl<B: Backend> Backward<B, 1> for Unfold4D {
+            type State = ([usize; 2], [usize; 2], UnfoldOptions);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (input_size, kernel_size, options) = ops.state;
+
+                // The folding is the adjoint of the unfolding.
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    B::fold4d(grad, input_size, kernel_size, options)
+                });
+            }
+        }
+
+        match Unfold4D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let [_, _, height, width] = x.primitive.shape().dims();
+                let output = B::unfold4d(x.primitive, kernel_size, options.clone());
+                prep.finish(([height, width], kernel_size, options), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::unfold4d(x.primitive, kernel_size, options)),
+        }
+    }
+
+    fn fold4d(
+        x: AutodiffTensor<B>,
+        output_size: [usize; 2],
+        kernel_size: [usize; 2],
+        options: UnfoldOptions,
+    ) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct Fold4D;
+
+        impl<B: Backend> Backward<B, 1> for Fold4D {
+            type State = ([usize; 2], UnfoldOptions);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (kernel_size, options) = ops.state;
+
+                // The unfolding is the adjoint of the folding.
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    B::unfold4d(grad, kernel_size, options)
+                });
+            }
+        }
+
+        match Fold4D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::fold4d(x.primitive, output_size, kernel_size, options.clone());
+                prep.finish((kernel_size, options), output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::fold4d(x.primitive, output_size, kernel_size, options))
+            }
+        }
+    }
+
+    fn pixel_shuffle(x: AutodiffTensor<B>, upscale_factor: usize) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct PixelShuffle;
+
+        impl<B: Backend> Backward<B, 1> for PixelShuffle {
+            type State = usize;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let upscale_factor = ops.state;
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    B::pixel_unshuffle(grad, upscale_factor)
+                });
+            }
+        }
+
+        match PixelShuffle
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                upscale_factor,
+                B::pixel_shuffle(x.primitive, upscale_factor),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::pixel_shuffle(x.primitive, upscale_factor)),
+        }
+    }
+
+    fn pixel_unshuffle(x: AutodiffTensor<B>, downscale_factor: usize) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct PixelUnshuffle;
+
+        impl<B: Backend> Backward<B, 1> for PixelUnshuffle {
+            type State = usize;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let downscale_factor = ops.state;
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    B::pixel_shuffle(grad, downscale_factor)
+                });
+            }
+        }
+
+        match PixelUnshuffle
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                downscale_factor,
+                B::pixel_unshuffle(x.primitive, downscale_factor),
+            ),
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::pixel_unshuffle(x.primitive, downscale_factor))
+            }
+        }
+    }
 
     fn avg_pool1d(
         x: AutodiffTensor<B>,
@@ -1609,15 +1793,109 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         panic!("Can't differentiate adaptive avg pool2d backward.");
     }
 
-    fn interpolate(
+    fn max_pool3d(
         x: AutodiffTensor<B>,
-        output_size: [usize; 2],
-        options: InterpolateOptions,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> AutodiffTensor<B> {
+        match MaxPool3D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let output =
+                    B::max_pool3d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+                prep.finish(
+                    (
+                        x_state,
+                        output.indices,
+                        kernel_size,
+                        stride,
+                        padding,
+                        dilation,
+                    ),
+                    output.output,
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::max_pool3d(
+                x.primitive,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+            )),
+        }
+    }
+
+    fn max_pool3d_with_indices(
+        x: AutodiffTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<Self> {
+        match MaxPool3D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let output =
+                    B::max_pool3d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+
+                let output_tensor = prep.finish(
+                    (
+                        x_state,
+                        output.indices.clone(),
+                        kernel_size,
+                        stride,
+                        padding,
+                        dilation,
+                    ),
+                    output.output,
+                );
+
+                MaxPool3dWithIndices::new(output_tensor, output.indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let output =
+                    B::max_pool3d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+                let output_tensor = prep.finish(output.output);
+
+                MaxPool3dWithIndices::new(output_tensor, output.indices)
+            }
+        }
+    }
+
+    fn max_pool3d_with_indices_backward(
+        _x: AutodiffTensor<B>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        _dilation: [usize; 3],
+        _output_grad: AutodiffTensor<B>,
+        _indices: IntTensor<B>,
+    ) -> MaxPool3dBackward<Self> {
+        panic!("Can't differentiate max pool3d with indices backward.");
+    }
+
+    fn avg_pool3d(
+        x: AutodiffTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
     ) -> AutodiffTensor<B> {
         #[derive(Debug)]
-        struct Interpolate;
-        impl<B: Backend> Backward<B, 1> for Interpolate {
-            type State = (NodeID, [usize; 2], InterpolateOptions);
+        struct AvgPool3D;
+
+        impl<B: Backend> Backward<B, 1> for AvgPool3D {
+            type State = (NodeID, [usize; 3], [usize; 3], [usize; 3], bool);
 
             fn backward(
                 self,
@@ -1627,47 +1905,575 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
             ) {
                 let [node_parent] = ops.parents;
                 let grad = grads.consume::<B>(&ops.node);
-
-                let (x_state, output_size, options) = ops.state;
-                let state = checkpointer.retrieve_node_output(x_state);
+                let (x_state, kernel_size, stride, padding, count_include_pad) = ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
 
                 if let Some(node) = node_parent {
-                    let grad = B::interpolate_backward(state, grad, output_size, options);
+                    let grad = B::avg_pool3d_backward(
+                        x,
+                        grad,
+                        kernel_size,
+                        stride,
+                        padding,
+                        count_include_pad,
+                    );
                     grads.register::<B>(node.id, grad);
                 }
             }
         }
 
-        match Interpolate
+        match AvgPool3D
             .prepare::<C>([x.node.clone()])
             .compute_bound()
             .stateful()
         {
             OpsKind::Tracked(mut prep) => {
                 let x_state = prep.checkpoint(&x);
-                let output = B::interpolate(x.primitive.clone(), output_size, options.clone());
-                prep.finish((x_state, output_size, options), output)
-            }
-            OpsKind::UnTracked(prep) => {
-                prep.finish(B::interpolate(x.primitive, output_size, options))
+                prep.finish(
+                    (x_state, kernel_size, stride, padding, count_include_pad),
+                    B::avg_pool3d(
+                        x.primitive.clone(),
+                        kernel_size,
+                        stride,
+                        padding,
+                        count_include_pad,
+                    ),
+                )
             }
+            OpsKind::UnTracked(prep) => prep.finish(B::avg_pool3d(
+                x.primitive,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+            )),
         }
     }
 
-    fn interpolate_backward(
-        _x: FloatTensor<Autodiff<B, C>>,
-        _grad: FloatTensor<Autodiff<B, C>>,
-        _output_size: [usize; 2],
-        _options: InterpolateOptions,
-    ) -> <Autodiff<B> as Backend>::FloatTensorPrimitive {
-        panic!("Can't differentiate interpolate backward.");
+    fn avg_pool3d_backward(
+        _x: AutodiffTensor<B>,
+        _grad: AutodiffTensor<B>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        _count_include_pad: bool,
+    ) -> AutodiffTensor<B> {
+        panic!("Can't differentiate avg pool 3d backward.");
     }
-}
 
-#[derive(Debug)]
-struct MaxPool1D;
+    fn adaptive_avg_pool3d(x: AutodiffTensor<B>, output_size: [usize; 3]) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct AdaptiveAvgPool3D;
 
-impl<B: Backend> Backward<B, 1> for MaxPool1D {
+        impl<B: Backend> Backward<B, 1> for AdaptiveAvgPool3D {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+                let state = checkpointer.retrieve_node_output(ops.state);
+
+                if let Some(node) = node_parent {
+                    let grad = B::adaptive_avg_pool3d_backward(state, grad);
+                    grads.register::<B>(node.id, grad);
+                }
+            }
+        }
+
+        match AdaptiveAvgPool3D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                prep.finish(x_state, B::adaptive_avg_pool3d(x.primitive, output_size))
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::adaptive_avg_pool3d(x.primitive, output_size))
+            }
+        }
+    }
+
+    fn adaptive_avg_pool3d_backward(
+        _x: AutodiffTensor<B>,
+        _grad: AutodiffTensor<B>,
+    ) -> <Autodiff<B> as Backend>::FloatTensorPrimitive {
+        panic!("Can't differentiate adaptive avg pool3d backward.");
+    }
+
+    fn adaptive_max_pool1d(x: AutodiffTensor<B>, output_size: usize) -> AutodiffTensor<B> {
+        Self::adaptive_max_pool1d_with_indices(x, output_size).output
+    }
+
+    fn adaptive_max_pool1d_with_indices(
+        x: AutodiffTensor<B>,
+        output_size: usize,
+    ) -> AdaptiveMaxPool1dWithIndices<Self> {
+        #[derive(Debug)]
+        struct AdaptiveMaxPool1D;
+
+        impl<B: Backend> Backward<B, 1> for AdaptiveMaxPool1D {
+            type State = (NodeID, IntTensor<B>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+                let (x_state, indices) = ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
+
+                if let Some(node) = node_parent {
+                    let grad = B::adaptive_max_pool1d_with_indices_backward(x, grad, indices);
+                    grads.register::<B>(node.id, grad.x_grad);
+                }
+            }
+        }
+
+        match AdaptiveMaxPool1D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let output = B::adaptive_max_pool1d_with_indices(x.primitive, output_size);
+                let output_tensor = prep.finish((x_state, output.indices.clone()), output.output);
+
+                AdaptiveMaxPool1dWithIndices::new(output_tensor, output.indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let output = B::adaptive_max_pool1d_with_indices(x.primitive, output_size);
+                let output_tensor = prep.finish(output.output);
+
+                AdaptiveMaxPool1dWithIndices::new(output_tensor, output.indices)
+            }
+        }
+    }
+
+    fn adaptive_max_pool1d_with_indices_backward(
+        _x: AutodiffTensor<B>,
+        _output_grad: AutodiffTensor<B>,
+        _indices: IntTensor<B>,
+    ) -> AdaptiveMaxPool1dBackward<Self> {
+        panic!("Can't differentiate adaptive max pool1d with indices backward.");
+    }
+
+    fn adaptive_max_pool2d(x: AutodiffTensor<B>, output_size: [usize; 2]) -> AutodiffTensor<B> {
+        Self::adaptive_max_pool2d_with_indices(x, output_size).output
+    }
+
+    fn adaptive_max_pool2d_with_indices(
+        x: AutodiffTensor<B>,
+        output_size: [usize; 2],
+    ) -> AdaptiveMaxPool2dWithIndices<Self> {
+        #[derive(Debug)]
+        struct AdaptiveMaxPool2D;
+
+        impl<B: Backend> Backward<B, 1> for AdaptiveMaxPool2D {
+            type State = (NodeID, IntTensor<B>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+                let (x_state, indices) = ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
+
+                if let Some(node) = node_parent {
+                    let grad = B::adaptive_max_pool2d_with_indices_backward(x, grad, indices);
+                    grads.register::<B>(node.id, grad.x_grad);
+                }
+            }
+        }
+
+        match AdaptiveMaxPool2D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let output = B::adaptive_max_pool2d_with_indices(x.primitive, output_size);
+                let output_tensor = prep.finish((x_state, output.indices.clone()), output.output);
+
+                AdaptiveMaxPool2dWithIndices::new(output_tensor, output.indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let output = B::adaptive_max_pool2d_with_indices(x.primitive, output_size);
+                let output_tensor = prep.finish(output.output);
+
+                AdaptiveMaxPool2dWithIndices::new(output_tensor, output.indices)
+            }
+        }
+    }
+
+    fn adaptive_max_pool2d_with_indices_backward(
+        _x: AutodiffTensor<B>,
+        _output_grad: AutodiffTensor<B>,
+        _indices: IntTensor<B>,
+    ) -> AdaptiveMaxPool2dBackward<Self> {
+        panic!("Can't differentiate adaptive max pool2d with indices backward.");
+    }
+
+    fn max_unpool2d(
+        x: AutodiffTensor<B>,
+        indices: IntTensor<B>,
+        output_size: [usize; 2],
+    ) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct MaxUnpool2D;
+
+        impl<B: Backend> Backward<B, 1> for MaxUnpool2D {
+            type State = IntTensor<B>;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let indices = ops.state;
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    B::max_unpool2d_backward(indices, grad)
+                });
+            }
+        }
+
+        match MaxUnpool2D
+            .prepare::<C>([x.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                indices.clone(),
+                B::max_unpool2d(x.primitive, indices, output_size),
+            ),
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::max_unpool2d(x.primitive, indices, output_size))
+            }
+        }
+    }
+
+    fn max_unpool2d_backward(
+        _indices: IntTensor<B>,
+        _grad: AutodiffTensor<B>,
+    ) -> AutodiffTensor<B> {
+        panic!("Can't differentiate max unpool2d backward.");
+    }
+
+    fn interpolate(
+        x: AutodiffTensor<B>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct Interpolate;
+        impl<B: Backend> Backward<B, 1> for Interpolate {
+            type State = (NodeID, [usize; 2], InterpolateOptions);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+
+                let (x_state, output_size, options) = ops.state;
+                let state = checkpointer.retrieve_node_output(x_state);
+
+                if let Some(node) = node_parent {
+                    let grad = B::interpolate_backward(state, grad, output_size, options);
+                    grads.register::<B>(node.id, grad);
+                }
+            }
+        }
+
+        match Interpolate
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let output = B::interpolate(x.primitive.clone(), output_size, options.clone());
+                prep.finish((x_state, output_size, options), output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::interpolate(x.primitive, output_size, options))
+            }
+        }
+    }
+
+    fn interpolate_backward(
+        _x: FloatTensor<Autodiff<B, C>>,
+        _grad: FloatTensor<Autodiff<B, C>>,
+        _output_size: [usize; 2],
+        _options: InterpolateOptions,
+    ) -> <Autodiff<B> as Backend>::FloatTensorPrimitive {
+        panic!("Can't differentiate interpolate backward.");
+    }
+
+    fn linear_recurrence(
+        a: AutodiffTensor<B>,
+        b: AutodiffTensor<B>,
+        dim: usize,
+    ) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct LinearRecurrence;
+
+        impl<B: Backend> Backward<B, 2> for LinearRecurrence {
+            type State = (FloatTensor<B>, FloatTensor<B>, usize);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let [node_a, node_b] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+                let (a, output, dim) = ops.state;
+
+                // The gradient of `b` follows the reversed recurrence
+                // `grad_b[t] = grad[t] + a[t + 1] * grad_b[t + 1]`.
+                let a_next = shift_with_zeros::<B>(a, dim, false);
+                let grad_b = B::float_flip(
+                    B::linear_recurrence(
+                        B::float_flip(a_next, &[dim]),
+                        B::float_flip(grad, &[dim]),
+                        dim,
+                    ),
+                    &[dim],
+                );
+
+                if let Some(node) = node_a {
+                    let output_previous = shift_with_zeros::<B>(output, dim, true);
+                    grads.register::<B>(node.id, B::float_mul(grad_b.clone(), output_previous));
+                }
+
+                if let Some(node) = node_b {
+                    grads.register::<B>(node.id, grad_b);
+                }
+            }
+        }
+
+        /// Shifts the tensor by one position along the dimension, towards the end of the
+        /// sequences when `forward` is true, filling the new position with zeros.
+        fn shift_with_zeros<B: Backend>(
+            tensor: FloatTensor<B>,
+            dim: usize,
+            forward: bool,
+        ) -> FloatTensor<B> {
+            let shape = tensor.shape();
+            let length = shape.dims[dim];
+            let device = B::float_device(&tensor);
+
+            let mut ranges: Vec<_> = shape.dims.iter().map(|&size| 0..size).collect();
+            ranges[dim] = match forward {
+                true => 0..length - 1,
+                false => 1..length,
+            };
+            let mut zeros_shape = shape.dims.clone();
+            zeros_shape[dim] = 1;
+
+            let zeros = B::float_zeros(Shape::from(zeros_shape), &device);
+            let tensor = B::float_slice(tensor, &ranges);
+
+            match forward {
+                true => B::float_cat(vec![zeros, tensor], dim),
+                false => B::float_cat(vec![tensor, zeros], dim),
+            }
+        }
+
+        match LinearRecurrence
+            .prepare::<C>([a.node.clone(), b.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::linear_recurrence(a.primitive.clone(), b.primitive, dim);
+                prep.finish((a.primitive, output.clone(), dim), output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::linear_recurrence(a.primitive, b.primitive, dim))
+            }
+        }
+    }
+
+    fn ctc_loss(
+        log_probs: AutodiffTensor<B>,
+        targets: IntTensor<B>,
+        input_lengths: IntTensor<B>,
+        target_lengths: IntTensor<B>,
+        blank: usize,
+    ) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct CtcLoss;
+
+        impl<B: Backend> Backward<B, 1> for CtcLoss {
+            type State = (
+                FloatTensor<B>,
+                IntTensor<B>,
+                IntTensor<B>,
+                IntTensor<B>,
+                FloatTensor<B>,
+                usize,
+            );
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (log_probs, targets, input_lengths, target_lengths, loss, blank) = ops.state;
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    B::ctc_loss_backward(
+                        log_probs,
+                        targets,
+                        input_lengths,
+                        target_lengths,
+                        loss,
+                        grad,
+                        blank,
+                    )
+                });
+            }
+        }
+
+        match CtcLoss
+            .prepare::<C>([log_probs.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let loss = B::ctc_loss(
+                    log_probs.primitive.clone(),
+                    targets.clone(),
+                    input_lengths.clone(),
+                    target_lengths.clone(),
+                    blank,
+                );
+                prep.finish(
+                    (
+                        log_probs.primitive,
+                        targets,
+                        input_lengths,
+                        target_lengths,
+                        loss.clone(),
+                        blank,
+                    ),
+                    loss,
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::ctc_loss(
+                log_probs.primitive,
+                targets,
+                input_lengths,
+                target_lengths,
+                blank,
+            )),
+        }
+    }
+
+    fn ctc_loss_backward(
+        _log_probs: AutodiffTensor<B>,
+        _targets: IntTensor<B>,
+        _input_lengths: IntTensor<B>,
+        _target_lengths: IntTensor<B>,
+        _loss: AutodiffTensor<B>,
+        _grad: AutodiffTensor<B>,
+        _blank: usize,
+    ) -> AutodiffTensor<B> {
+        panic!("Can't differentiate ctc loss backward.");
+    }
+
+    fn grouped_matmul(
+        lhs: AutodiffTensor<B>,
+        rhs: AutodiffTensor<B>,
+        offsets: IntTensor<B>,
+    ) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct GroupedMatmul;
+
+        impl<B: Backend> Backward<B, 2> for GroupedMatmul {
+            type State = (FloatTensor<B>, FloatTensor<B>, IntTensor<B>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let [node_lhs, node_rhs] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+                let (lhs, rhs, offsets) = ops.state;
+
+                if let Some(node) = node_rhs {
+                    let grad_rhs =
+                        B::grouped_matmul_rhs_backward(lhs, grad.clone(), offsets.clone());
+                    grads.register::<B>(node.id, grad_rhs);
+                }
+
+                // The rows of each group are multiplied with the transposed matrix of the group,
+                // the rows after the last group having a zero gradient.
+                if let Some(node) = node_lhs {
+                    let rhs = B::float_swap_dims(rhs, 1, 2);
+                    grads.register::<B>(node.id, B::grouped_matmul(grad, rhs, offsets));
+                }
+            }
+        }
+
+        match GroupedMatmul
+            .prepare::<C>([lhs.node.clone(), rhs.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::grouped_matmul(
+                    lhs.primitive.clone(),
+                    rhs.primitive.clone(),
+                    offsets.clone(),
+                );
+                prep.finish((lhs.primitive, rhs.primitive, offsets), output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::grouped_matmul(lhs.primitive, rhs.primitive, offsets))
+            }
+        }
+    }
+
+    fn grouped_matmul_rhs_backward(
+        _lhs: AutodiffTensor<B>,
+        _grad: AutodiffTensor<B>,
+        _offsets: IntTensor<B>,
+    ) -> AutodiffTensor<B> {
+        panic!("Can't differentiate grouped matmul backward.");
+    }
+}
+
+#[derive(Debug)]
+struct MaxPool1D;
+
+impl<B: Backend> Backward<B, 1> for MaxPool1D {
     type State = (NodeID, IntTensor<B>, usize, usize, usize, usize);
 
     fn backward(
@@ -1736,3 +2542,43 @@ impl<B: Backend> Backward<B, 1> for MaxPool2D {
         }
     }
 }
+
+#[derive(Debug)]
+struct MaxPool3D;
+
+impl<B: Backend> Backward<B, 1> for MaxPool3D {
+    type State = (
+        NodeID,
+        IntTensor<B>,
+        [usize; 3],
+        [usize; 3],
+        [usize; 3],
+        [usize; 3],
+    );
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        checkpointer: &mut Checkpointer,
+    ) {
+        let [node_parent] = ops.parents;
+        let grad = grads.consume::<B>(&ops.node);
+        let (x_state, indices, kernel_size, stride, padding, dilation) = ops.state;
+        let x = checkpointer.retrieve_node_output(x_state);
+
+        if let Some(node) = node_parent {
+            let grad = B::max_pool3d_with_indices_backward(
+                x,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+                grad,
+                indices,
+            );
+
+            grads.register::<B>(node.id, grad.x_grad);
+        }
+    }
+}
@@ -22,7 +22,8 @@ use crate::{
 use burn_tensor::{
     backend::Backend,
     ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, IntTensor},
-    Device, ElementConversion, Shape, TensorData, TensorMetadata,
+    pad_indices, Device, ElementConversion, PadMode, Reduce, Shape, Slice, TensorData,
+    TensorMetadata,
 };
 
 use super::maxmin::MaxMinDim;
@@ -543,6 +544,97 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
             .stateless(B::float_remainder_scalar(lhs.primitive, rhs))
     }
 
+    fn float_fmod(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Fmod;
+
+        retro_binary!(RetroFmod, B::float_fmod);
+
+        impl<B: Backend> Backward<B, 2> for Fmod {
+            type State = (Option<NodeID>, Option<NodeID>, BinaryOpsBroadcast);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let (lhs, rhs, broadcast) = ops.state;
+                let lhs = lhs.map(|lhs| checkpointer.retrieve_node_output(lhs));
+                let rhs = rhs.map(|rhs| checkpointer.retrieve_node_output(rhs));
+
+                binary::<B, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| {
+                        // fmod(x, y) = x - trunc(x / y) * y
+                        // partial(x - trunc(x / y) * y, x) = 1
+                        broadcast.backward_lhs::<B>(grad)
+                    },
+                    |grad| {
+                        // partial(x - trunc(x / y) * y, y) = - trunc(x / y)
+                        let rhs = rhs.unwrap();
+                        let lhs = lhs.unwrap();
+                        let value = B::float_neg(B::float_trunc(B::float_div(lhs, rhs)));
+                        let grad = B::float_mul(grad, value);
+                        broadcast.backward_rhs::<B>(grad)
+                    },
+                );
+            }
+        }
+
+        let lhs_tracked = lhs.is_tracked();
+        let rhs_tracked = rhs.is_tracked();
+        let broadcast = BinaryOpsBroadcast::new::<B>(&lhs.primitive, &rhs.primitive);
+
+        match Fmod
+            .prepare::<C>([lhs.node.clone(), rhs.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroFmod::<B>::new(lhs.node.id, rhs.node.id))
+            .parents([&lhs, &rhs])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let lhs_state = rhs_tracked.then(|| prep.checkpoint(&lhs));
+                let rhs_state = (lhs_tracked || rhs_tracked).then(|| prep.checkpoint(&rhs));
+
+                prep.finish(
+                    (lhs_state, rhs_state, broadcast),
+                    B::float_fmod(lhs.primitive, rhs.primitive),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_fmod(lhs.primitive, rhs.primitive)),
+        }
+    }
+
+    fn float_fmod_scalar(lhs: FloatTensor<Self>, rhs: FloatElem<B>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct FmodScalar;
+
+        retro_unary_scalar!(RetroFmodScalar, B::float_fmod_scalar);
+
+        impl<B: Backend> Backward<B, 1> for FmodScalar {
+            type State = ();
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| grad);
+            }
+        }
+
+        FmodScalar
+            .prepare::<C>([lhs.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroFmodScalar::<B>::new(lhs.node.id, rhs))
+            .parents([&lhs])
+            .stateless(B::float_fmod_scalar(lhs.primitive, rhs))
+    }
+
     fn float_matmul(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
         #[derive(Debug)]
         struct Matmul;
@@ -994,6 +1086,160 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_scatter_reduce(
+        dim: usize,
+        tensor: FloatTensor<Self>,
+        indices: IntTensor<B>,
+        value: FloatTensor<Self>,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct ScatterReduce;
+
+        impl<B: Backend> Backward<B, 2> for ScatterReduce {
+            type State = (
+                usize,
+                IntTensor<B>,
+                Reduce,
+                bool,
+                FloatTensor<B>,
+                FloatTensor<B>,
+                FloatTensor<B>,
+            );
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (dim, indices, reduce, include_self, tensor, value, output) = ops.state;
+                let device = B::float_device(&tensor);
+
+                // Number of values scattered at each position of the tensor.
+                let counts = B::float_scatter(
+                    dim,
+                    B::float_zeros(tensor.shape(), &device),
+                    indices.clone(),
+                    B::float_ones(value.shape(), &device),
+                );
+                let scattered = B::float_greater_elem(counts.clone(), 0.elem());
+                let not_scattered = B::bool_not(scattered.clone());
+
+                // Factors applied to the gradient of the scattered positions of the tensor, to the
+                // gradient of the output before being gathered and to the gathered gradient.
+                let (factor_tensor, factor_output, factor_value) = match reduce {
+                    Reduce::Sum => (None, None, None),
+                    Reduce::Mean => {
+                        let counts = match include_self {
+                            true => B::float_add_scalar(counts, 1.elem()),
+                            false => counts,
+                        };
+                        let counts = B::float_mask_fill(counts, not_scattered, 1.elem());
+                        let factor = B::float_recip(counts);
+
+                        (Some(factor.clone()), Some(factor), None)
+                    }
+                    Reduce::Max | Reduce::Min => {
+                        // The gradient is split evenly between the elements equal to the output.
+                        let equal_tensor = match include_self {
+                            true => B::bool_into_float(B::float_equal(tensor, output.clone())),
+                            false => B::float_zeros(output.shape(), &device),
+                        };
+                        let equal_value = B::bool_into_float(B::float_equal(
+                            value,
+                            B::float_gather(dim, output, indices.clone()),
+                        ));
+                        let num_equal = B::float_scatter(
+                            dim,
+                            equal_tensor.clone(),
+                            indices.clone(),
+                            equal_value.clone(),
+                        );
+                        let num_equal = B::float_mask_fill(
+                            num_equal.clone(),
+                            B::float_equal_elem(num_equal, 0.elem()),
+                            1.elem(),
+                        );
+                        let factor = B::float_recip(num_equal);
+
+                        (
+                            Some(B::float_mul(equal_tensor, factor.clone())),
+                            Some(factor),
+                            Some(equal_value),
+                        )
+                    }
+                    Reduce::Prod => (
+                        Some(B::float_div(output.clone(), tensor)),
+                        Some(output),
+                        Some(B::float_recip(value)),
+                    ),
+                };
+
+                let mul = |grad: FloatTensor<B>, factor: Option<FloatTensor<B>>| match factor {
+                    Some(factor) => B::float_mul(grad, factor),
+                    None => grad,
+                };
+
+                binary::<B, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| match include_self {
+                        true => {
+                            let grad_scattered = mul(grad.clone(), factor_tensor);
+                            B::float_mask_where(grad, scattered, grad_scattered)
+                        }
+                        false => B::float_mask_fill(grad, scattered, 0.elem()),
+                    },
+                    |grad| {
+                        let grad = B::float_gather(dim, mul(grad, factor_output), indices);
+                        mul(grad, factor_value)
+                    },
+                );
+            }
+        }
+
+        match ScatterReduce
+            .prepare::<C>([tensor.node, value.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_scatter_reduce(
+                    dim,
+                    tensor.primitive.clone(),
+                    indices.clone(),
+                    value.primitive.clone(),
+                    reduce,
+                    include_self,
+                );
+
+                prep.finish(
+                    (
+                        dim,
+                        indices,
+                        reduce,
+                        include_self,
+                        tensor.primitive,
+                        value.primitive,
+                        output.clone(),
+                    ),
+                    output,
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_scatter_reduce(
+                dim,
+                tensor.primitive,
+                indices,
+                value.primitive,
+                reduce,
+                include_self,
+            )),
+        }
+    }
+
     fn float_select(
         tensor: FloatTensor<Self>,
         dim: usize,
@@ -1265,6 +1511,144 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_slice_with_steps(tensor: FloatTensor<Self>, slices: &[Slice]) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct SliceWithSteps;
+
+        #[derive(new, Debug)]
+        struct RetroSliceWithSteps<B: Backend> {
+            tensor_id: NodeID,
+            slices: Vec<Slice>,
+            _backend: PhantomData<B>,
+        }
+
+        impl<B: Backend> RetroForward for RetroSliceWithSteps<B> {
+            fn forward(&self, states: &mut BackwardStates, out_node: NodeID) {
+                let tensor = states.get_state::<B::FloatTensorPrimitive>(&self.tensor_id);
+                let out = B::float_slice_with_steps(tensor, &self.slices);
+                states.save(out_node, out)
+            }
+        }
+
+        impl<B: Backend> Backward<B, 1> for SliceWithSteps {
+            type State = (Vec<Slice>, Shape, B::Device);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (slices, shape, device) = ops.state;
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    let zeros = B::float_zeros(shape, &device);
+                    B::float_slice_assign_with_steps(zeros, &slices, grad)
+                });
+            }
+        }
+
+        match SliceWithSteps
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroSliceWithSteps::<B>::new(
+                tensor.node.id,
+                slices.to_vec(),
+            ))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (
+                    slices.to_vec(),
+                    tensor.primitive.shape(),
+                    B::float_device(&tensor.primitive),
+                ),
+                B::float_slice_with_steps(tensor.primitive, slices),
+            ),
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::float_slice_with_steps(tensor.primitive, slices))
+            }
+        }
+    }
+
+    fn float_slice_assign_with_steps(
+        tensor: FloatTensor<Self>,
+        slices: &[Slice],
+        value: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct SliceAssignWithSteps;
+
+        #[derive(new, Debug)]
+        struct RetroSliceAssignWithSteps<B: Backend> {
+            tensor_id: NodeID,
+            slices: Vec<Slice>,
+            value_id: NodeID,
+            _backend: PhantomData<B>,
+        }
+
+        impl<B: Backend> RetroForward for RetroSliceAssignWithSteps<B> {
+            fn forward(&self, states: &mut BackwardStates, out_node: NodeID) {
+                let tensor = states.get_state::<B::FloatTensorPrimitive>(&self.tensor_id);
+                let value = states.get_state::<B::FloatTensorPrimitive>(&self.value_id);
+                let out = B::float_slice_assign_with_steps(tensor, &self.slices, value);
+                states.save(out_node, out)
+            }
+        }
+
+        impl<B: Backend> Backward<B, 2> for SliceAssignWithSteps {
+            type State = (Vec<Slice>, Shape, B::Device);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (slices, shape_rhs, device) = ops.state;
+                let [slices_4lhs, slices_4rhs] = duplicate(&ops.parents, Some(slices));
+
+                binary::<B, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| {
+                        let zeros = B::float_zeros(shape_rhs, &device);
+                        B::float_slice_assign_with_steps(grad, &slices_4lhs.unwrap(), zeros)
+                    },
+                    |grad| B::float_slice_with_steps(grad, &slices_4rhs.unwrap()),
+                );
+            }
+        }
+
+        match SliceAssignWithSteps
+            .prepare::<C>([tensor.node.clone(), value.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroSliceAssignWithSteps::<B>::new(
+                tensor.node.id,
+                slices.to_vec(),
+                value.node.id,
+            ))
+            .parents([&tensor, &value])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (
+                    slices.to_vec(),
+                    value.primitive.shape(),
+                    B::float_device(&value.primitive),
+                ),
+                B::float_slice_assign_with_steps(tensor.primitive, slices, value.primitive),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_slice_assign_with_steps(
+                tensor.primitive,
+                slices,
+                value.primitive,
+            )),
+        }
+    }
+
     fn float_mask_where(
         tensor: FloatTensor<Self>,
         mask: BoolTensor<Self>,
@@ -2093,33 +2477,768 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
-    fn float_cat(tensors: Vec<FloatTensor<Self>>, dim: usize) -> FloatTensor<Self> {
-        #[derive(new, Debug)]
-        struct CatStep<B: Backend> {
-            nodes: Vec<Option<NodeRef>>,
-            // The dimension of each tensor along the dim dimension.
-            // This indicates the number of dimension concatenated for each tensor.
-            dim_sizes: Vec<usize>,
-            output: NodeRef,
-            phantom: PhantomData<B>,
-            dim: usize,
-        }
+    fn float_asin(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Asin;
 
-        impl<B: Backend> Step for CatStep<B> {
-            fn step(self: Box<Self>, grads: &mut Gradients, _checkpointer: &mut Checkpointer) {
-                let grad = grads.consume::<B>(&self.output);
-                let ranges: Vec<_> = grad.shape().dims.iter().map(|v| 0..*v).collect();
+        retro_unary!(RetroAsin, B::float_asin);
 
-                let mut current_index = 0;
+        impl<B: Backend> Backward<B, 1> for Asin {
+            type State = NodeID;
 
-                self.nodes
-                    .into_iter()
-                    .zip(self.dim_sizes)
-                    .filter_map(|(node, dim_size)| node.map(|node| (node, dim_size)))
-                    .for_each(|(node, dim_size)| {
-                        let mut ranges = ranges.clone();
-                        ranges[self.dim] = current_index..dim_size + current_index;
-                        current_index += dim_size;
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // d/dx asin(x) = 1 / sqrt(1 - x^2)
+                    let square = B::float_powf_scalar(input, 2.0);
+                    let value = B::float_add_scalar(B::float_neg(square), 1.elem());
+                    let value = B::float_recip(B::float_sqrt(value));
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Asin
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroAsin::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_asin(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_asin(tensor.primitive)),
+        }
+    }
+
+    fn float_acos(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Acos;
+
+        retro_unary!(RetroAcos, B::float_acos);
+
+        impl<B: Backend> Backward<B, 1> for Acos {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // d/dx acos(x) = -1 / sqrt(1 - x^2)
+                    let square = B::float_powf_scalar(input, 2.0);
+                    let value = B::float_add_scalar(B::float_neg(square), 1.elem());
+                    let value = B::float_neg(B::float_recip(B::float_sqrt(value)));
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Acos
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroAcos::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_acos(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_acos(tensor.primitive)),
+        }
+    }
+
+    fn float_atan(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Atan;
+
+        retro_unary!(RetroAtan, B::float_atan);
+
+        impl<B: Backend> Backward<B, 1> for Atan {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // d/dx atan(x) = 1 / (1 + x^2)
+                    let square = B::float_powf_scalar(input, 2.0);
+                    let value = B::float_recip(B::float_add_scalar(square, 1.elem()));
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Atan
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroAtan::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_atan(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_atan(tensor.primitive)),
+        }
+    }
+
+    fn float_sinh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Sinh;
+
+        retro_unary!(RetroSinh, B::float_sinh);
+
+        impl<B: Backend> Backward<B, 1> for Sinh {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // d/dx sinh(x) = cosh(x)
+                    let value = B::float_cosh(input);
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Sinh
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroSinh::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_sinh(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_sinh(tensor.primitive)),
+        }
+    }
+
+    fn float_cosh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Cosh;
+
+        retro_unary!(RetroCosh, B::float_cosh);
+
+        impl<B: Backend> Backward<B, 1> for Cosh {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // d/dx cosh(x) = sinh(x)
+                    let value = B::float_sinh(input);
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Cosh
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroCosh::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_cosh(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_cosh(tensor.primitive)),
+        }
+    }
+
+    fn float_asinh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Asinh;
+
+        retro_unary!(RetroAsinh, B::float_asinh);
+
+        impl<B: Backend> Backward<B, 1> for Asinh {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // d/dx asinh(x) = 1 / sqrt(x^2 + 1)
+                    let square = B::float_powf_scalar(input, 2.0);
+                    let value =
+                        B::float_recip(B::float_sqrt(B::float_add_scalar(square, 1.elem())));
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Asinh
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroAsinh::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_asinh(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_asinh(tensor.primitive)),
+        }
+    }
+
+    fn float_acosh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Acosh;
+
+        retro_unary!(RetroAcosh, B::float_acosh);
+
+        impl<B: Backend> Backward<B, 1> for Acosh {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // d/dx acosh(x) = 1 / sqrt(x^2 - 1)
+                    let square = B::float_powf_scalar(input, 2.0);
+                    let value =
+                        B::float_recip(B::float_sqrt(B::float_sub_scalar(square, 1.elem())));
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Acosh
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroAcosh::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_acosh(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_acosh(tensor.primitive)),
+        }
+    }
+
+    fn float_atanh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Atanh;
+
+        retro_unary!(RetroAtanh, B::float_atanh);
+
+        impl<B: Backend> Backward<B, 1> for Atanh {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // d/dx atanh(x) = 1 / (1 - x^2)
+                    let square = B::float_powf_scalar(input, 2.0);
+                    let value = B::float_recip(B::float_add_scalar(B::float_neg(square), 1.elem()));
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Atanh
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroAtanh::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_atanh(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_atanh(tensor.primitive)),
+        }
+    }
+
+    fn float_expm1(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Expm1;
+
+        retro_unary!(RetroExpm1, B::float_expm1);
+
+        impl<B: Backend> Backward<B, 1> for Expm1 {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // d/dx (exp(x) - 1) = exp(x)
+                    let value = B::float_exp(input);
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Expm1
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroExpm1::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_expm1(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_expm1(tensor.primitive)),
+        }
+    }
+
+    fn float_erfinv(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct ErfInv;
+
+        retro_unary!(RetroErfInv, B::float_erfinv);
+
+        impl<B: Backend> Backward<B, 1> for ErfInv {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // d/dx erfinv(x) = sqrt(pi) / 2 * exp(erfinv(x)^2)
+                    let square = B::float_powf_scalar(B::float_erfinv(input), 2.0);
+                    let value = B::float_mul_scalar(
+                        B::float_exp(square),
+                        (core::f64::consts::PI.sqrt() / 2.0).elem(),
+                    );
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match ErfInv
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroErfInv::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_erfinv(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_erfinv(tensor.primitive)),
+        }
+    }
+
+    fn float_lgamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Lgamma;
+
+        retro_unary!(RetroLgamma, B::float_lgamma);
+
+        impl<B: Backend> Backward<B, 1> for Lgamma {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // d/dx log(|gamma(x)|) = digamma(x)
+                    let value = B::float_digamma(input);
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Lgamma
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroLgamma::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_lgamma(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_lgamma(tensor.primitive)),
+        }
+    }
+
+    fn float_digamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Digamma;
+
+        retro_unary!(RetroDigamma, B::float_digamma);
+
+        impl<B: Backend> Backward<B, 1> for Digamma {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // d/dx digamma(x) = trigamma(x)
+                    let value = trigamma::<B>(input);
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Digamma
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroDigamma::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_digamma(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_digamma(tensor.primitive)),
+        }
+    }
+
+    fn float_atan2(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Atan2;
+
+        retro_binary!(RetroAtan2, B::float_atan2);
+
+        impl<B: Backend> Backward<B, 2> for Atan2 {
+            type State = (NodeID, NodeID, BinaryOpsBroadcast);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let (lhs, rhs, broadcast) = ops.state;
+                let lhs: B::FloatTensorPrimitive = checkpointer.retrieve_node_output(lhs);
+                let rhs: B::FloatTensorPrimitive = checkpointer.retrieve_node_output(rhs);
+
+                // The denominator of both partial derivatives is x^2 + y^2.
+                let denominator = B::float_add(
+                    B::float_powf_scalar(lhs.clone(), 2.0),
+                    B::float_powf_scalar(rhs.clone(), 2.0),
+                );
+                let denominator_rhs = denominator.clone();
+
+                binary::<B, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| {
+                        // partial(atan2(y, x), y) = x / (x^2 + y^2)
+                        let grad = B::float_mul(grad, B::float_div(rhs, denominator));
+                        broadcast.backward_lhs::<B>(grad)
+                    },
+                    |grad| {
+                        // partial(atan2(y, x), x) = -y / (x^2 + y^2)
+                        let value = B::float_neg(B::float_div(lhs, denominator_rhs));
+                        let grad = B::float_mul(grad, value);
+                        broadcast.backward_rhs::<B>(grad)
+                    },
+                );
+            }
+        }
+
+        let broadcast = BinaryOpsBroadcast::new::<B>(&lhs.primitive, &rhs.primitive);
+
+        match Atan2
+            .prepare::<C>([lhs.node.clone(), rhs.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroAtan2::<B>::new(lhs.node.id, rhs.node.id))
+            .parents([&lhs, &rhs])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let lhs_state = prep.checkpoint(&lhs);
+                let rhs_state = prep.checkpoint(&rhs);
+
+                prep.finish(
+                    (lhs_state, rhs_state, broadcast),
+                    B::float_atan2(lhs.primitive, rhs.primitive),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_atan2(lhs.primitive, rhs.primitive)),
+        }
+    }
+
+    fn float_hypot(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Hypot;
+
+        retro_binary!(RetroHypot, B::float_hypot);
+
+        impl<B: Backend> Backward<B, 2> for Hypot {
+            type State = (NodeID, NodeID, BinaryOpsBroadcast);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let (lhs, rhs, broadcast) = ops.state;
+                let lhs: B::FloatTensorPrimitive = checkpointer.retrieve_node_output(lhs);
+                let rhs: B::FloatTensorPrimitive = checkpointer.retrieve_node_output(rhs);
+
+                let output = B::float_hypot(lhs.clone(), rhs.clone());
+                let output_rhs = output.clone();
+
+                binary::<B, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| {
+                        // partial(sqrt(x^2 + y^2), x) = x / sqrt(x^2 + y^2)
+                        let grad = B::float_mul(grad, B::float_div(lhs, output));
+                        broadcast.backward_lhs::<B>(grad)
+                    },
+                    |grad| {
+                        // partial(sqrt(x^2 + y^2), y) = y / sqrt(x^2 + y^2)
+                        let grad = B::float_mul(grad, B::float_div(rhs, output_rhs));
+                        broadcast.backward_rhs::<B>(grad)
+                    },
+                );
+            }
+        }
+
+        let broadcast = BinaryOpsBroadcast::new::<B>(&lhs.primitive, &rhs.primitive);
+
+        match Hypot
+            .prepare::<C>([lhs.node.clone(), rhs.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroHypot::<B>::new(lhs.node.id, rhs.node.id))
+            .parents([&lhs, &rhs])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let lhs_state = prep.checkpoint(&lhs);
+                let rhs_state = prep.checkpoint(&rhs);
+
+                prep.finish(
+                    (lhs_state, rhs_state, broadcast),
+                    B::float_hypot(lhs.primitive, rhs.primitive),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_hypot(lhs.primitive, rhs.primitive)),
+        }
+    }
+
+    fn float_trunc(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Trunc;
+        retro_unary!(RetroTrunc, B::float_trunc);
+
+        impl<B: Backend> Backward<B, 1> for Trunc {
+            type State = (Shape, B::Device);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (shape, device) = ops.state;
+                unary::<B, _>(ops.parents, ops.node, grads, |_grad| {
+                    B::float_zeros(shape, &device)
+                })
+            }
+        }
+
+        match Trunc
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroTrunc::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(preps) => preps.finish(
+                (tensor.primitive.shape(), B::float_device(&tensor.primitive)),
+                B::float_trunc(tensor.primitive),
+            ),
+            OpsKind::UnTracked(preps) => preps.finish(B::float_trunc(tensor.primitive)),
+        }
+    }
+
+    fn float_nan_to_num(
+        tensor: FloatTensor<Self>,
+        nan: FloatElem<B>,
+        posinf: FloatElem<B>,
+        neginf: FloatElem<B>,
+    ) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct NanToNum;
+
+        #[derive(new, Debug)]
+        struct RetroNanToNum<B: Backend> {
+            tensor_id: NodeID,
+            nan: FloatElem<B>,
+            posinf: FloatElem<B>,
+            neginf: FloatElem<B>,
+        }
+
+        impl<B: Backend> RetroForward for RetroNanToNum<B> {
+            fn forward(&self, states: &mut BackwardStates, out_node: NodeID) {
+                let tensor = states.get_state::<B::FloatTensorPrimitive>(&self.tensor_id);
+                let out = B::float_nan_to_num(tensor, self.nan, self.posinf, self.neginf);
+                states.save(out_node, out)
+            }
+        }
+
+        impl<B: Backend> Backward<B, 1> for NanToNum {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // The replaced values are constants, only the finite values have a gradient.
+                    let finite = B::float_lower_elem(B::float_abs(input), f64::INFINITY.elem());
+                    B::float_mask_fill(grad, B::bool_not(finite), 0.elem())
+                });
+            }
+        }
+
+        match NanToNum
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroNanToNum::<B>::new(tensor.node.id, nan, posinf, neginf))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(
+                    state,
+                    B::float_nan_to_num(tensor.primitive, nan, posinf, neginf),
+                )
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::float_nan_to_num(tensor.primitive, nan, posinf, neginf))
+            }
+        }
+    }
+
+    fn float_cat(tensors: Vec<FloatTensor<Self>>, dim: usize) -> FloatTensor<Self> {
+        #[derive(new, Debug)]
+        struct CatStep<B: Backend> {
+            nodes: Vec<Option<NodeRef>>,
+            // The dimension of each tensor along the dim dimension.
+            // This indicates the number of dimension concatenated for each tensor.
+            dim_sizes: Vec<usize>,
+            output: NodeRef,
+            phantom: PhantomData<B>,
+            dim: usize,
+        }
+
+        impl<B: Backend> Step for CatStep<B> {
+            fn step(self: Box<Self>, grads: &mut Gradients, _checkpointer: &mut Checkpointer) {
+                let grad = grads.consume::<B>(&self.output);
+                let ranges: Vec<_> = grad.shape().dims.iter().map(|v| 0..*v).collect();
+
+                let mut current_index = 0;
+
+                self.nodes
+                    .into_iter()
+                    .zip(self.dim_sizes)
+                    .filter_map(|(node, dim_size)| node.map(|node| (node, dim_size)))
+                    .for_each(|(node, dim_size)| {
+                        let mut ranges = ranges.clone();
+                        ranges[self.dim] = current_index..dim_size + current_index;
+                        current_index += dim_size;
                         grads.register::<B>(node.id, B::float_slice(grad.clone(), &ranges));
                     });
             }
@@ -2438,6 +3557,100 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_pad(
+        tensor: FloatTensor<Self>,
+        padding: &[(usize, usize)],
+        mode: PadMode,
+    ) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Pad;
+
+        #[derive(new, Debug)]
+        struct RetroPad<B: Backend> {
+            input_id: NodeID,
+            padding: Vec<(usize, usize)>,
+            mode: PadMode,
+            _backend: PhantomData<B>,
+        }
+
+        impl<B: Backend> RetroForward for RetroPad<B> {
+            fn forward(&self, states: &mut BackwardStates, out_node: NodeID) {
+                let input = states.get_state::<B::FloatTensorPrimitive>(&self.input_id);
+                let out = B::float_pad(input, &self.padding, self.mode);
+                states.save(out_node, out)
+            }
+        }
+
+        impl<B: Backend> Backward<B, 1> for Pad {
+            type State = (Shape, Vec<(usize, usize)>, PadMode, B::Device);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (shape, padding, mode, device) = ops.state;
+                let offset = shape.num_dims() - padding.len();
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    if let PadMode::Constant(_) = mode {
+                        // The padded elements are constant, only the input elements have a gradient.
+                        let mut ranges = shape.dims.iter().map(|&size| 0..size).collect::<Vec<_>>();
+                        for (i, &(before, _)) in padding.iter().enumerate() {
+                            ranges[offset + i] = before..before + shape.dims[offset + i];
+                        }
+
+                        return B::float_slice(grad, &ranges);
+                    }
+
+                    // Each padded element is a copy of an input element, so the gradients of the
+                    // copies are accumulated on the input, in the reverse order of the forward pass.
+                    let mut grad = grad;
+                    for (i, &padding) in padding.iter().enumerate().rev() {
+                        if padding == (0, 0) {
+                            continue;
+                        }
+
+                        let dim = offset + i;
+                        let indices = pad_indices(shape.dims[dim], padding, mode).unwrap();
+                        let length = indices.len();
+                        let indices = B::int_from_data(
+                            TensorData::new(indices, [length]).convert::<B::IntElem>(),
+                            &device,
+                        );
+
+                        let mut shape_input = grad.shape();
+                        shape_input.dims[dim] = shape.dims[dim];
+                        let zeros = B::float_zeros(shape_input, &device);
+                        grad = B::float_select_assign(zeros, dim, indices, grad);
+                    }
+
+                    grad
+                });
+            }
+        }
+
+        match Pad
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroPad::<B>::new(tensor.node.id, padding.to_vec(), mode))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (
+                    tensor.primitive.shape(),
+                    padding.to_vec(),
+                    mode,
+                    B::float_device(&tensor.primitive),
+                ),
+                B::float_pad(tensor.primitive, padding, mode),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_pad(tensor.primitive, padding, mode)),
+        }
+    }
+
     fn float_sort(tensor: FloatTensor<Self>, dim: usize, descending: bool) -> FloatTensor<Self> {
         match super::sort::SortDim
             .prepare::<C>([tensor.node])
@@ -2488,6 +3701,30 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         B::float_argsort(tensor.primitive, dim, descending)
     }
 
+    async fn float_unique(
+        tensor: FloatTensor<Self>,
+    ) -> (FloatTensor<Self>, IntTensor<B>, IntTensor<B>) {
+        let (values, inverse, counts) = B::float_unique(tensor.primitive).await;
+        (AutodiffTensor::new(values), inverse, counts)
+    }
+
+    fn float_searchsorted(
+        sorted: FloatTensor<Self>,
+        values: FloatTensor<Self>,
+        right: bool,
+    ) -> IntTensor<B> {
+        B::float_searchsorted(sorted.primitive, values.primitive, right)
+    }
+
+    fn float_histc(
+        tensor: FloatTensor<Self>,
+        bins: usize,
+        min: f64,
+        max: f64,
+    ) -> FloatTensor<Self> {
+        AutodiffTensor::new(B::float_histc(tensor.primitive, bins, min, max))
+    }
+
     fn float_repeat_dim(tensor: FloatTensor<Self>, dim: usize, times: usize) -> FloatTensor<Self> {
         #[derive(Debug)]
         struct Repeat;
@@ -2596,3 +3833,45 @@ impl BinaryOpsBroadcast {
         }
     }
 }
+
+/// Trigamma function, the derivative of the digamma function.
+fn trigamma<B: Backend>(tensor: B::FloatTensorPrimitive) -> B::FloatTensorPrimitive {
+    use core::f64::consts::PI;
+
+    // The reflection formula gives the values below 0.5 from the values above.
+    let reflected = B::float_lower_elem(tensor.clone(), 0.5.elem());
+    let one_minus = B::float_add_scalar(B::float_neg(tensor.clone()), 1.elem());
+    let z = B::float_mask_where(tensor.clone(), reflected.clone(), one_minus);
+
+    // trigamma(z) = trigamma(z + 6) + sum(1 / (z + k)^2) moves the values in the asymptotic range.
+    let mut shift = B::float_recip(B::float_powf_scalar(z.clone(), 2.0));
+    for k in 1..6 {
+        let term = B::float_add_scalar(z.clone(), k.elem());
+        shift = B::float_add(shift, B::float_recip(B::float_powf_scalar(term, 2.0)));
+    }
+    let y = B::float_add_scalar(z, 6.elem());
+    let y_recip = B::float_recip(y);
+    let y2 = B::float_powf_scalar(y_recip.clone(), 2.0);
+
+    // 1/y + 1/(2y^2) + 1/(6y^3) - 1/(30y^5) + 1/(42y^7) - 1/(30y^9)
+    let mut series = B::float_mul_scalar(y2.clone(), (-1.0 / 30.0).elem());
+    for coefficient in [1.0 / 42.0, -1.0 / 30.0, 1.0 / 6.0] {
+        series = B::float_add_scalar(B::float_mul(series, y2.clone()), coefficient.elem());
+    }
+    let series = B::float_mul(series, y2);
+    let series = B::float_add(series, B::float_mul_scalar(y_recip.clone(), 0.5.elem()));
+    let series = B::float_mul(B::float_add_scalar(series, 1.elem()), y_recip);
+    let output = B::float_add(series, shift);
+
+    // trigamma(x) = pi^2 / sin^2(pi * x) - trigamma(1 - x)
+    let sin = B::float_sin(B::float_mul_scalar(tensor, PI.elem()));
+    let reflection = B::float_sub(
+        B::float_mul_scalar(
+            B::float_recip(B::float_powf_scalar(sin, 2.0)),
+            (PI * PI).elem(),
+        ),
+        output.clone(),
+    );
+
+    B::float_mask_where(output, reflected, reflection)
+}
@@ -0,0 +1,19 @@
+#[burn_tensor_testgen::testgen(ad_acosh)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_acosh() {
+        let data = TensorData::from([[1.5, 2.0], [3.0, 5.0]]);
+
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(data, &device).require_grad();
+
+        let grads = tensor.clone().acosh().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[0.894427, 0.57735], [0.353553, 0.204124]]);
+        grad.to_data().assert_approx_eq(&expected, 3);
+    }
+}
@@ -0,0 +1,26 @@
+#[burn_tensor_testgen::testgen(ad_adaptive_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::adaptive_avg_pool3d;
+
+    #[test]
+    fn test_adaptive_avg_pool3d_overlapping_windows() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<5>::ones([1, 1, 2, 2, 3], &device).require_grad();
+        let x_grad_expected = TestAutodiffTensor::<5>::from_floats(
+            [[[
+                [[0.125, 0.25, 0.125], [0.125, 0.25, 0.125]],
+                [[0.125, 0.25, 0.125], [0.125, 0.25, 0.125]],
+            ]]],
+            &device,
+        );
+
+        let output = adaptive_avg_pool3d(x.clone(), [1, 1, 2]);
+        let grads = output.backward();
+
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+}
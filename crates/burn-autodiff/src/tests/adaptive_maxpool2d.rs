@@ -0,0 +1,39 @@
+#[burn_tensor_testgen::testgen(ad_adaptive_max_pool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{adaptive_max_pool1d, adaptive_max_pool2d};
+
+    #[test]
+    fn test_adaptive_max_pool2d_overlapping_windows() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_floats([[[[1.0, 7.0, 2.0], [3.0, 4.0, 6.0]]]], &device)
+            .require_grad();
+        let x_grad_expected =
+            TestAutodiffTensor::<4>::from_floats([[[[0.0, 2.0, 0.0], [0.0, 0.0, 0.0]]]], &device);
+
+        let output = adaptive_max_pool2d(x.clone(), [1, 2]);
+        let grads = output.backward();
+
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+
+    #[test]
+    fn test_adaptive_max_pool1d() {
+        let device = Default::default();
+        let x =
+            TestAutodiffTensor::from_floats([[[1.0, 5.0, 2.0, 4.0, 3.0]]], &device).require_grad();
+        let x_grad_expected =
+            TestAutodiffTensor::<3>::from_floats([[[0.0, 1.0, 0.0, 1.0, 0.0]]], &device);
+
+        let output = adaptive_max_pool1d(x.clone(), 2);
+        let grads = output.backward();
+
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+}
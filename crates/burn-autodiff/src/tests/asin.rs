@@ -0,0 +1,19 @@
+#[burn_tensor_testgen::testgen(ad_asin)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_asin() {
+        let data = TensorData::from([[-0.5, 0.0], [0.3, 0.9]]);
+
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(data, &device).require_grad();
+
+        let grads = tensor.clone().asin().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[1.1547, 1.0], [1.04828, 2.29416]]);
+        grad.to_data().assert_approx_eq(&expected, 3);
+    }
+}
@@ -0,0 +1,19 @@
+#[burn_tensor_testgen::testgen(ad_atan)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_atan() {
+        let data = TensorData::from([[-3.0, 0.0], [0.5, 2.0]]);
+
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(data, &device).require_grad();
+
+        let grads = tensor.clone().atan().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[0.1, 1.0], [0.8, 0.2]]);
+        grad.to_data().assert_approx_eq(&expected, 3);
+    }
+}
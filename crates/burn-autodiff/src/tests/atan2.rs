@@ -0,0 +1,25 @@
+#[burn_tensor_testgen::testgen(ad_atan2)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_atan2() {
+        let data_1 = TensorData::from([[1.0, -2.0], [0.5, 3.0]]);
+        let data_2 = TensorData::from([[2.0, 1.0], [-1.5, -4.0]]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_data(data_1, &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2, &device).require_grad();
+
+        let grads = tensor_1.clone().atan2(tensor_2.clone()).sum().backward();
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[0.4, 0.2], [-0.6, -0.16]]);
+        grad_1.to_data().assert_approx_eq(&expected, 3);
+
+        let expected = TensorData::from([[-0.2, 0.4], [-0.2, -0.12]]);
+        grad_2.to_data().assert_approx_eq(&expected, 3);
+    }
+}
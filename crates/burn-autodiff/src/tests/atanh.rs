@@ -0,0 +1,19 @@
+#[burn_tensor_testgen::testgen(ad_atanh)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_atanh() {
+        let data = TensorData::from([[-0.5, 0.0], [0.3, 0.9]]);
+
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(data, &device).require_grad();
+
+        let grads = tensor.clone().atanh().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[1.33333, 1.0], [1.0989, 5.26316]]);
+        grad.to_data().assert_approx_eq(&expected, 3);
+    }
+}
@@ -0,0 +1,41 @@
+#[burn_tensor_testgen::testgen(ad_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::avg_pool3d;
+
+    #[test]
+    fn test_avg_pool3d_overlapping_windows() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<5>::ones([1, 1, 3, 3, 3], &device).require_grad();
+
+        let output = avg_pool3d(x.clone(), [2, 2, 2], [1, 1, 1], [0, 0, 0], true);
+        let grads = output.backward();
+
+        // Each position is counted by as many windows as the product of its counts along each
+        // dimension, which are `[1, 2, 1]`.
+        let counts = [1.0, 2.0, 1.0];
+        let expected = counts.map(|d| counts.map(|h| counts.map(|w| d * h * w / 8.0)));
+        let x_grad_expected = TestAutodiffTensor::<5>::from_floats([[expected]], &device);
+
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+
+    #[test]
+    fn test_avg_pool3d_count_exclude_pad() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<5>::ones([1, 1, 2, 2, 2], &device).require_grad();
+        let x_grad_expected = TestAutodiffTensor::<5>::ones([1, 1, 2, 2, 2], &device);
+
+        // Every window holds a single position of the input once the padding is excluded.
+        let output = avg_pool3d(x.clone(), [2, 2, 2], [2, 2, 2], [1, 1, 1], false);
+        let grads = output.backward();
+
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+}
@@ -0,0 +1,52 @@
+#[burn_tensor_testgen::testgen(ad_complex_tensor)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ComplexTensor, TensorData};
+
+    #[test]
+    fn should_diff_complex_abs() {
+        let device = Default::default();
+        let tensor = ComplexTensor::new(
+            TestAutodiffTensor::<1>::from_floats([3.0, 0.0], &device),
+            TestAutodiffTensor::from_floats([4.0, -2.0], &device),
+        )
+        .require_grad();
+
+        let grads = tensor.clone().abs().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.into_data().assert_approx_eq(
+            &TensorData::complex(TensorData::from([0.6, 0.0]), TensorData::from([0.8, -1.0])),
+            4,
+        );
+    }
+
+    #[test]
+    fn should_diff_complex_mul_with_conjugate_gradient() {
+        let device = Default::default();
+        let tensor = ComplexTensor::new(
+            TestAutodiffTensor::<2>::from_floats([[1.0, -1.0]], &device),
+            TestAutodiffTensor::from_floats([[0.5, 2.0]], &device),
+        )
+        .require_grad();
+        let weight = ComplexTensor::new(
+            TestAutodiffTensor::<2>::from_floats([[2.0, 1.0]], &device),
+            TestAutodiffTensor::from_floats([[3.0, -1.0]], &device),
+        );
+
+        // The gradient of real(w * z) is the conjugate of w.
+        let output = (tensor.clone() * weight).matmul(ComplexTensor::from_real(
+            TestAutodiffTensor::ones([2, 1], &device),
+        ));
+        let grads = output.real().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.into_data().assert_approx_eq(
+            &TensorData::complex(
+                TensorData::from([[2.0, 1.0]]),
+                TensorData::from([[-3.0, 1.0]]),
+            ),
+            4,
+        );
+    }
+}
@@ -0,0 +1,53 @@
+#[burn_tensor_testgen::testgen(ad_ctc_loss)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::ctc_loss;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn test_ctc_loss_grad_is_opposite_of_posteriors() {
+        let device = Default::default();
+        let log_probs = TestAutodiffTensor::<3>::from_floats(
+            [[[0.3, 0.7], [0.6, 0.4]], [[0.5, 0.5], [0.9, 0.1]]],
+            &device,
+        )
+        .log()
+        .detach()
+        .require_grad();
+        let targets = Tensor::<TestAutodiffBackend, 2, Int>::from_data([[1], [1]], &device);
+        let input_lengths = Tensor::<TestAutodiffBackend, 1, Int>::from_data([2, 1], &device);
+        let target_lengths = Tensor::<TestAutodiffBackend, 1, Int>::from_data([1, 1], &device);
+
+        let loss = ctc_loss(log_probs.clone(), targets, input_lengths, target_lengths, 0);
+        let grads = loss.sum().backward();
+        let grad = log_probs.grad(&grads).unwrap();
+
+        // The step after the end of the second sequence has no gradient.
+        grad.to_data().assert_approx_eq(
+            &TensorData::from([
+                [[-0.146341, -0.853659], [-0.512195, -0.487805]],
+                [[0.0, -1.0], [0.0, 0.0]],
+            ]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_ctc_loss_grad_of_impossible_alignment_is_zero() {
+        let device = Default::default();
+        let log_probs = TestAutodiffTensor::<3>::from_floats([[[0.3, 0.7], [0.6, 0.4]]], &device)
+            .log()
+            .detach()
+            .require_grad();
+        let targets = Tensor::<TestAutodiffBackend, 2, Int>::from_data([[1, 1]], &device);
+        let input_lengths = Tensor::<TestAutodiffBackend, 1, Int>::from_data([2], &device);
+        let target_lengths = Tensor::<TestAutodiffBackend, 1, Int>::from_data([2], &device);
+
+        let loss = ctc_loss(log_probs.clone(), targets, input_lengths, target_lengths, 0);
+        let grads = loss.sum().backward();
+        let grad = log_probs.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&TensorData::from([[[0.0, 0.0], [0.0, 0.0]]]), 3);
+    }
+}
@@ -0,0 +1,88 @@
+#[burn_tensor_testgen::testgen(ad_distributions)]
+mod tests {
+    use super::*;
+    use burn_tensor::distributions::{
+        Distribution, Gamma, MultivariateNormal, Normal, Reparameterized,
+    };
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_normal_log_prob() {
+        let device = Default::default();
+        let loc = TestAutodiffTensor::<1>::from_floats([0.0, 1.0], &device).require_grad();
+        let scale = TestAutodiffTensor::from_floats([1.0, 2.0], &device).require_grad();
+        let value = TestAutodiffTensor::from_floats([0.5, -1.0], &device);
+
+        let distribution = Normal::new(loc.clone(), scale.clone());
+        let grads = distribution.log_prob(value).sum().backward();
+
+        let loc_grad = loc.grad(&grads).unwrap();
+        let scale_grad = scale.grad(&grads).unwrap();
+
+        loc_grad
+            .to_data()
+            .assert_approx_eq(&TensorData::from([0.5, -0.5]), 4);
+        scale_grad
+            .to_data()
+            .assert_approx_eq(&TensorData::from([-0.75, 0.0]), 4);
+    }
+
+    #[test]
+    fn should_diff_normal_rsample() {
+        let device = Default::default();
+        let loc = TestAutodiffTensor::<1>::from_floats([0.0, 1.0, 2.0], &device).require_grad();
+        let scale = TestAutodiffTensor::from_floats([1.0, 2.0, 3.0], &device).require_grad();
+
+        let distribution = Normal::new(loc.clone(), scale.clone());
+        let sample = distribution.rsample();
+        let grads = sample.clone().sum().backward();
+
+        let loc_grad = loc.grad(&grads).unwrap();
+        let scale_grad = scale.grad(&grads).unwrap();
+        let noise = (sample.inner() - loc.inner()) / scale.inner();
+
+        loc_grad
+            .to_data()
+            .assert_approx_eq(&TensorData::from([1.0, 1.0, 1.0]), 4);
+        scale_grad.to_data().assert_approx_eq(&noise.to_data(), 4);
+    }
+
+    #[test]
+    fn should_diff_gamma_log_prob() {
+        let device = Default::default();
+        let concentration =
+            TestAutodiffTensor::<1>::from_floats([2.0, 3.0], &device).require_grad();
+        let rate = TestAutodiffTensor::from_floats([1.0, 2.0], &device).require_grad();
+        let value = TestAutodiffTensor::from_floats([1.5, 0.5], &device);
+
+        let distribution = Gamma::new(concentration.clone(), rate.clone());
+        let grads = distribution.log_prob(value).sum().backward();
+
+        let concentration_grad = concentration.grad(&grads).unwrap();
+        let rate_grad = rate.grad(&grads).unwrap();
+
+        concentration_grad
+            .to_data()
+            .assert_approx_eq(&TensorData::from([-0.017319227, -0.92278434]), 3);
+        rate_grad
+            .to_data()
+            .assert_approx_eq(&TensorData::from([0.5, 1.0]), 4);
+    }
+
+    #[test]
+    fn should_diff_multivariate_normal_log_prob() {
+        let device = Default::default();
+        let loc = TestAutodiffTensor::<2>::from_floats([[1.0, -1.0]], &device).require_grad();
+        let scale_tril = TestAutodiffTensor::from_floats([[[2.0, 0.0], [1.0, 1.0]]], &device);
+        let value = TestAutodiffTensor::from_floats([[0.0, 0.0]], &device);
+
+        let distribution = MultivariateNormal::new(loc.clone(), scale_tril);
+        let grads = distribution.log_prob(value).sum().backward();
+
+        let loc_grad = loc.grad(&grads).unwrap();
+
+        loc_grad
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[-1.0, 1.5]]), 4);
+    }
+}
@@ -0,0 +1,79 @@
+#[burn_tensor_testgen::testgen(ad_embedding_sparse)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module, Int, Tensor, TensorData};
+
+    #[test]
+    fn should_return_row_gradients() {
+        let device = Default::default();
+        let weights = TestAutodiffTensor::<2>::from_data(
+            [[0.0, 1.0], [2.0, 3.0], [4.0, 5.0], [6.0, 7.0]],
+            &device,
+        )
+        .require_grad();
+        let indices = Tensor::<TestAutodiffBackend, 2, Int>::from_data([[3, 1, 3]], &device);
+
+        let output = module::embedding_sparse(weights.clone(), indices);
+        let mut grads = output.mul_scalar(2.0).backward();
+
+        let (rows, values) = weights.grad_remove_rows(&mut grads).unwrap();
+
+        rows.into_data()
+            .assert_eq(&TensorData::from([3, 1, 3]), false);
+        values.into_data().assert_eq(
+            &TensorData::from([[2.0, 2.0], [2.0, 2.0], [2.0, 2.0]]),
+            false,
+        );
+        assert!(weights.grad(&grads).is_none());
+    }
+
+    #[test]
+    fn should_match_dense_gradients() {
+        let device = Default::default();
+        let weights = TestAutodiffTensor::<2>::from_data(
+            [[0.0, 1.0], [2.0, 3.0], [4.0, 5.0], [6.0, 7.0]],
+            &device,
+        )
+        .require_grad();
+        let indices = Tensor::<TestAutodiffBackend, 2, Int>::from_data([[3, 1], [0, 3]], &device);
+        let scale = TestAutodiffTensor::<3>::from_data(
+            [[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]],
+            &device,
+        );
+
+        let output = module::embedding(weights.clone(), indices.clone());
+        let grads = (output * scale.clone()).backward();
+        let grad_dense = weights.grad(&grads).unwrap();
+
+        let output = module::embedding_sparse(weights.clone(), indices);
+        let grads = (output * scale).backward();
+        let grad_sparse = weights.grad(&grads).unwrap();
+
+        grad_sparse
+            .into_data()
+            .assert_eq(&grad_dense.into_data(), false);
+    }
+
+    #[test]
+    fn should_merge_row_gradients_with_dense_gradients() {
+        let device = Default::default();
+        let weights =
+            TestAutodiffTensor::<2>::from_data([[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]], &device)
+                .require_grad();
+        let indices = Tensor::<TestAutodiffBackend, 2, Int>::from_data([[2]], &device);
+
+        let output = module::embedding_sparse(weights.clone(), indices);
+        let loss = output.sum() + weights.clone().sum();
+        let mut grads = loss.backward();
+
+        assert!(weights.grad_remove_rows(&mut grads).is_none());
+        weights
+            .grad_remove(&mut grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(
+                &TensorData::from([[1.0, 1.0], [1.0, 1.0], [2.0, 2.0]]),
+                false,
+            );
+    }
+}
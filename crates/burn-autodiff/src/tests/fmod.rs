@@ -0,0 +1,39 @@
+#[burn_tensor_testgen::testgen(ad_fmod)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_fmod() {
+        let data_1 = TensorData::from([[5.5, -5.5], [7.0, -1.0]]);
+        let data_2 = TensorData::from([[2.0, 2.0], [-3.0, 4.0]]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_data(data_1, &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2, &device).require_grad();
+
+        let grads = tensor_1.clone().fmod(tensor_2.clone()).sum().backward();
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[1.0, 1.0], [1.0, 1.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 3);
+
+        let expected = TensorData::from([[-2.0, 2.0], [2.0, 0.0]]);
+        grad_2.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_diff_fmod_scalar() {
+        let data = TensorData::from([[5.5, -5.5], [7.0, -1.0]]);
+
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(data, &device).require_grad();
+
+        let grads = tensor.clone().fmod_scalar(2.0).sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[1.0, 1.0], [1.0, 1.0]]);
+        grad.to_data().assert_approx_eq(&expected, 3);
+    }
+}
@@ -0,0 +1,58 @@
+#[burn_tensor_testgen::testgen(ad_fold4d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{fold4d, unfold4d};
+    use burn_tensor::ops::UnfoldOptions;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_unfold4d_backward() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<4>::ones([1, 1, 3, 3], &device).require_grad();
+
+        let output = unfold4d(
+            x.clone(),
+            [2, 2],
+            UnfoldOptions::new([1, 1], [0, 0], [1, 1]),
+        );
+        let grads = output.sum().backward();
+
+        let x_grad = x.grad(&grads).unwrap();
+        x_grad.to_data().assert_approx_eq(
+            &TensorData::from([[[[1., 2., 1.], [2., 4., 2.], [1., 2., 1.]]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_fold4d_backward() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<3>::zeros([1, 4, 4], &device).require_grad();
+        let weights = TestAutodiffTensor::<4>::from_data(
+            TestTensorInt::arange(0..9, &device)
+                .reshape([1, 1, 3, 3])
+                .float()
+                .into_data(),
+            &device,
+        );
+
+        let output = fold4d(
+            x.clone(),
+            [3, 3],
+            [2, 2],
+            UnfoldOptions::new([1, 1], [0, 0], [1, 1]),
+        );
+        let grads = (output * weights).sum().backward();
+
+        let x_grad = x.grad(&grads).unwrap();
+        x_grad.to_data().assert_approx_eq(
+            &TensorData::from([[
+                [0., 1., 3., 4.],
+                [1., 2., 4., 5.],
+                [3., 4., 6., 7.],
+                [4., 5., 7., 8.],
+            ]]),
+            3,
+        );
+    }
+}
@@ -0,0 +1,39 @@
+#[burn_tensor_testgen::testgen(ad_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::grid_sample_2d;
+    use burn_tensor::ops::{GridSampleOptions, GridSamplePaddingMode, InterpolateMode};
+
+    #[test]
+    fn test_grid_sample_2d_bilinear() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]], &device)
+            .require_grad();
+        let grid = TestAutodiffTensor::<4>::from_floats([[[[0.2, -0.4], [-0.6, 0.8]]]], &device)
+            .require_grad();
+        let x_grad_expected =
+            TestAutodiffTensor::<4>::from_floats([[[[0.27, 0.63], [0.66, 0.07]]]], &device);
+        let grid_grad_expected =
+            TestAutodiffTensor::<4>::from_floats([[[[1.0, 2.0], [2.1, -2.7]]]], &device);
+
+        let output = grid_sample_2d(
+            x.clone(),
+            grid.clone(),
+            GridSampleOptions::new(
+                InterpolateMode::Bilinear,
+                GridSamplePaddingMode::Zeros,
+                false,
+            ),
+        );
+        let grads = output.backward();
+
+        let x_grad_actual = x.grad(&grads).unwrap();
+        let grid_grad_actual = grid.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+        grid_grad_expected
+            .to_data()
+            .assert_approx_eq(&grid_grad_actual.to_data(), 3);
+    }
+}
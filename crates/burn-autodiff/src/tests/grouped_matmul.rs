@@ -0,0 +1,30 @@
+#[burn_tensor_testgen::testgen(ad_grouped_matmul)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::grouped_matmul;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn test_grouped_matmul_grad() {
+        let device = Default::default();
+        let lhs =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device)
+                .require_grad();
+        let rhs = TestAutodiffTensor::<3>::from_floats([[[1.0], [1.0]], [[2.0], [-1.0]]], &device)
+            .require_grad();
+        let offsets = Tensor::<TestAutodiffBackend, 1, Int>::from_data([1, 2], &device);
+
+        let output = grouped_matmul(lhs.clone(), rhs.clone(), offsets);
+        let grads = output.sum().backward();
+
+        let lhs_grad = lhs.grad(&grads).unwrap();
+        let rhs_grad = rhs.grad(&grads).unwrap();
+        // The last row isn't part of any group.
+        lhs_grad
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 1.0], [2.0, -1.0], [0.0, 0.0]]), 3);
+        rhs_grad
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[[1.0], [2.0]], [[3.0], [4.0]]]), 3);
+    }
+}
@@ -0,0 +1,25 @@
+#[burn_tensor_testgen::testgen(ad_hypot)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_hypot() {
+        let data_1 = TensorData::from([[1.0, -2.0], [0.5, 3.0]]);
+        let data_2 = TensorData::from([[2.0, 1.0], [-1.5, -4.0]]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_data(data_1, &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2, &device).require_grad();
+
+        let grads = tensor_1.clone().hypot(tensor_2.clone()).sum().backward();
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[0.447214, -0.894427], [0.316228, 0.6]]);
+        grad_1.to_data().assert_approx_eq(&expected, 3);
+
+        let expected = TensorData::from([[0.894427, 0.447214], [-0.948683, -0.8]]);
+        grad_2.to_data().assert_approx_eq(&expected, 3);
+    }
+}
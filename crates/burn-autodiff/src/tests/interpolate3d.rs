@@ -0,0 +1,38 @@
+#[burn_tensor_testgen::testgen(ad_interpolate3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::interpolate3d;
+    use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+
+    #[test]
+    fn test_interpolate3d_trilinear() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<5>::from_floats(
+            [[[[[0.0, 1.0], [2.0, 3.0]], [[4.0, 5.0], [6.0, 7.0]]]]],
+            &device,
+        )
+        .require_grad();
+        let weights = TestAutodiffTensor::<5>::from_data(
+            TestTensorInt::arange(0..18, &device)
+                .reshape::<5, _>([1, 1, 3, 2, 3])
+                .into_data(),
+            &device,
+        );
+        let x_grad_expected = TestAutodiffTensor::<5>::from_floats(
+            [[[[[5.25, 8.25], [12.0, 15.0]], [[23.25, 26.25], [30.0, 33.0]]]]],
+            &device,
+        );
+
+        let output = interpolate3d(
+            x.clone(),
+            [3, 2, 3],
+            InterpolateOptions::new(InterpolateMode::Trilinear).with_align_corners(false),
+        );
+        let grads = (output * weights).backward();
+
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+}
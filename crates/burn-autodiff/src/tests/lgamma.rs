@@ -0,0 +1,19 @@
+#[burn_tensor_testgen::testgen(ad_lgamma)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_lgamma() {
+        let data = TensorData::from([[-2.5, 0.2], [1.0, 4.5]]);
+
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(data, &device).require_grad();
+
+        let grads = tensor.clone().lgamma().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[1.10316, -5.28904], [-0.577216, 1.38887]]);
+        grad.to_data().assert_approx_eq(&expected, 3);
+    }
+}
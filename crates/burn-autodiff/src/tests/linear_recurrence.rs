@@ -0,0 +1,50 @@
+#[burn_tensor_testgen::testgen(ad_linear_recurrence)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::linear_recurrence;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_linear_recurrence_last_dim() {
+        let device = Default::default();
+        let a =
+            TestAutodiffTensor::<2>::from_floats([[0.5, 2.0, -1.0, 0.5]], &device).require_grad();
+        let b =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0, 3.0, 4.0]], &device).require_grad();
+
+        let output = linear_recurrence(a.clone(), b.clone(), 1);
+        let grads = output.sum().backward();
+
+        let a_grad = a.grad(&grads).unwrap();
+        let b_grad = b.grad(&grads).unwrap();
+        a_grad
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[0.0, -0.5, 6.0, -1.0]]), 3);
+        b_grad
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[0.0, -0.5, 1.5, 1.0]]), 3);
+    }
+
+    #[test]
+    fn test_linear_recurrence_first_dim() {
+        let device = Default::default();
+        let a =
+            TestAutodiffTensor::<2>::from_floats([[0.5, 2.0], [1.0, -1.0], [2.0, 0.5]], &device)
+                .require_grad();
+        let b =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 1.0], [2.0, 3.0], [-1.0, 4.0]], &device)
+                .require_grad();
+
+        let output = linear_recurrence(a.clone(), b.clone(), 0);
+        let grads = output.sum().backward();
+
+        let a_grad = a.grad(&grads).unwrap();
+        let b_grad = b.grad(&grads).unwrap();
+        a_grad
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[0.0, 0.0], [3.0, 1.5], [3.0, 2.0]]), 3);
+        b_grad
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[4.0, -0.5], [3.0, 1.5], [1.0, 1.0]]), 3);
+    }
+}
@@ -0,0 +1,46 @@
+#[burn_tensor_testgen::testgen(ad_max_unpool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{max_pool2d_with_indices, max_unpool2d};
+
+    #[test]
+    fn test_max_unpool2d() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_floats(
+            [[[
+                [0.0, 14.0, 5.0, 19.0],
+                [10.0, 1.0, 15.0, 6.0],
+                [20.0, 11.0, 2.0, 16.0],
+                [7.0, 21.0, 12.0, 3.0],
+            ]]],
+            &device,
+        )
+        .require_grad();
+        let weights = TestAutodiffTensor::<1>::from_floats(
+            [
+                0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0,
+                15.0,
+            ],
+            &device,
+        )
+        .reshape([1, 1, 4, 4]);
+        let x_grad_expected = TestAutodiffTensor::<4>::from_floats(
+            [[[
+                [0.0, 1.0, 0.0, 3.0],
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 11.0],
+                [0.0, 13.0, 0.0, 0.0],
+            ]]],
+            &device,
+        );
+
+        let (pooled, indices) = max_pool2d_with_indices(x.clone(), [2, 2], [2, 2], [0, 0], [1, 1]);
+        let output = max_unpool2d(pooled, indices, [4, 4]) * weights;
+        let grads = output.backward();
+
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+}
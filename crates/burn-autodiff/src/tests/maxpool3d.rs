@@ -0,0 +1,55 @@
+#[burn_tensor_testgen::testgen(ad_max_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::max_pool3d;
+
+    #[test]
+    fn test_max_pool3d_overlapping_windows() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_floats(
+            [[[
+                [[0.0, 4.0, 1.0], [2.0, 3.0, 5.0], [8.0, 1.0, 0.0]],
+                [[1.0, 2.0, 0.0], [9.0, 3.0, 1.0], [0.0, 7.0, 6.0]],
+            ]]],
+            &device,
+        )
+        .require_grad();
+        let x_grad_expected = TestAutodiffTensor::<5>::from_floats(
+            [[[
+                [[0.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, 0.0]],
+                [[0.0, 0.0, 0.0], [2.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            ]]],
+            &device,
+        );
+
+        let output = max_pool3d(x.clone(), [2, 2, 2], [1, 1, 1], [0, 0, 0], [1, 1, 1]);
+        let grads = output.backward();
+
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool3d_with_padding() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_floats(
+            [[[[[1.0, 2.0], [3.0, 4.0]], [[8.0, 7.0], [6.0, 5.0]]]]],
+            &device,
+        )
+        .require_grad();
+        let x_grad_expected = TestAutodiffTensor::<5>::from_floats(
+            [[[[[1.0, 2.0], [2.0, 4.0]], [[8.0, 4.0], [4.0, 2.0]]]]],
+            &device,
+        );
+
+        let output = max_pool3d(x.clone(), [2, 2, 2], [1, 1, 1], [1, 1, 1], [1, 1, 1]);
+        let grads = output.backward();
+
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+}
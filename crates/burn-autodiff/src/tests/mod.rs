@@ -1,12 +1,22 @@
 #![allow(missing_docs)]
 
 mod abs;
+mod acos;
+mod acosh;
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
+mod adaptive_avgpool3d;
+mod adaptive_maxpool2d;
 mod add;
 mod aggregation;
+mod asin;
+mod asinh;
+mod atan;
+mod atan2;
+mod atanh;
 mod avgpool1d;
 mod avgpool2d;
+mod avgpool3d;
 mod backward;
 mod bridge;
 mod broadcast;
@@ -14,6 +24,7 @@ mod cat;
 mod ceil;
 mod checkpoint;
 mod complex;
+mod complex_tensor;
 mod conv1d;
 mod conv2d;
 mod conv3d;
@@ -21,32 +32,53 @@ mod conv_transpose1d;
 mod conv_transpose2d;
 mod conv_transpose3d;
 mod cos;
+mod cosh;
 mod cross_entropy;
+mod ctc_loss;
 mod deform_conv2d;
+mod digamma;
+mod distributions;
 mod div;
+mod einsum;
+mod embedding_sparse;
 mod erf;
+mod erfinv;
 mod exp;
 mod expand;
+mod expm1;
 mod flip;
 mod floor;
+mod fmod;
+mod fold4d;
 mod gather_scatter;
 mod gelu;
 mod gradients;
+mod grid_sample;
+mod grouped_matmul;
+mod hypot;
+mod interpolate3d;
+mod lgamma;
+mod linear_recurrence;
 mod log;
 mod log1p;
 mod log_sigmoid;
 mod mask;
 mod matmul;
+mod max_unpool2d;
 mod maxmin;
 mod maxpool1d;
 mod maxpool2d;
+mod maxpool3d;
 mod memory_management;
 mod mul;
 mod multithread;
+mod nan_to_num;
 mod nearest_interpolate;
 mod neg;
 mod nonzero;
+mod pad;
 mod permute;
+mod pixel_shuffle;
 mod pow;
 mod recip;
 mod relu;
@@ -54,17 +86,21 @@ mod remainder;
 mod repeat_dim;
 mod reshape;
 mod round;
+mod scatter_reduce;
 mod select;
 mod sigmoid;
 mod sign;
 mod sin;
+mod sinh;
 mod slice;
 mod softmax;
 mod sort;
+mod sparse;
 mod sqrt;
 mod sub;
 mod tanh;
 mod transpose;
+mod trunc;
 
 #[macro_export]
 macro_rules! testgen_all {
@@ -141,8 +177,21 @@ macro_rules! testgen_with_float_param {
         burn_autodiff::testgen_ad_avg_pool2d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool1d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
+        burn_autodiff::testgen_ad_max_pool3d!();
+        burn_autodiff::testgen_ad_avg_pool3d!();
+        burn_autodiff::testgen_ad_adaptive_avg_pool3d!();
+        burn_autodiff::testgen_ad_adaptive_max_pool2d!();
+        burn_autodiff::testgen_ad_max_unpool2d!();
         burn_autodiff::testgen_module_backward!();
         burn_autodiff::testgen_ad_nearest_interpolate!();
+        burn_autodiff::testgen_ad_interpolate3d!();
+        burn_autodiff::testgen_ad_grid_sample!();
+        burn_autodiff::testgen_ad_linear_recurrence!();
+        burn_autodiff::testgen_ad_ctc_loss!();
+        burn_autodiff::testgen_ad_grouped_matmul!();
+        burn_autodiff::testgen_ad_fold4d!();
+        burn_autodiff::testgen_ad_pixel_shuffle!();
+        burn_autodiff::testgen_ad_embedding_sparse!();
 
         // Tensor
         burn_autodiff::testgen_ad_complex!();
@@ -156,14 +205,37 @@ macro_rules! testgen_with_float_param {
         burn_autodiff::testgen_ad_div!();
         burn_autodiff::testgen_ad_remainder!();
         burn_autodiff::testgen_ad_erf!();
+        burn_autodiff::testgen_ad_distributions!();
+        burn_autodiff::testgen_ad_sparse!();
+        burn_autodiff::testgen_ad_complex_tensor!();
+        burn_autodiff::testgen_ad_pad!();
+        burn_autodiff::testgen_ad_acos!();
+        burn_autodiff::testgen_ad_acosh!();
+        burn_autodiff::testgen_ad_asin!();
+        burn_autodiff::testgen_ad_asinh!();
+        burn_autodiff::testgen_ad_atan!();
+        burn_autodiff::testgen_ad_atan2!();
+        burn_autodiff::testgen_ad_atanh!();
+        burn_autodiff::testgen_ad_cosh!();
+        burn_autodiff::testgen_ad_digamma!();
+        burn_autodiff::testgen_ad_erfinv!();
+        burn_autodiff::testgen_ad_expm1!();
+        burn_autodiff::testgen_ad_fmod!();
+        burn_autodiff::testgen_ad_hypot!();
+        burn_autodiff::testgen_ad_lgamma!();
+        burn_autodiff::testgen_ad_nan_to_num!();
+        burn_autodiff::testgen_ad_sinh!();
+        burn_autodiff::testgen_ad_trunc!();
         burn_autodiff::testgen_ad_exp!();
         burn_autodiff::testgen_ad_slice!();
         burn_autodiff::testgen_ad_gather_scatter!();
+        burn_autodiff::testgen_ad_scatter_reduce!();
         burn_autodiff::testgen_ad_select!();
         burn_autodiff::testgen_ad_log!();
         burn_autodiff::testgen_ad_log1p!();
         burn_autodiff::testgen_ad_mask!();
         burn_autodiff::testgen_ad_matmul!();
+        burn_autodiff::testgen_ad_einsum!();
         burn_autodiff::testgen_ad_mul!();
         burn_autodiff::testgen_ad_neg!();
         burn_autodiff::testgen_ad_powf!();
@@ -0,0 +1,20 @@
+#[burn_tensor_testgen::testgen(ad_nan_to_num)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_nan_to_num() {
+        let data = TensorData::from([[f32::NAN, 2.0], [f32::INFINITY, f32::NEG_INFINITY]]);
+
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(data, &device).require_grad();
+
+        let tensor_2 = tensor.clone().nan_to_num(0.0, Some(1.0), Some(-1.0));
+        let grads = tensor_2.mul_scalar(3.0).sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[0.0, 3.0], [0.0, 0.0]]);
+        grad.to_data().assert_approx_eq(&expected, 3);
+    }
+}
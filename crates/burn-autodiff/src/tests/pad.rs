@@ -0,0 +1,59 @@
+#[burn_tensor_testgen::testgen(ad_pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::{PadMode, TensorData};
+
+    #[test]
+    fn should_diff_pad_constant() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_floats([[1.0, 2.0]], &device).require_grad();
+        let weight = TestAutodiffTensor::from_floats(
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]],
+            &device,
+        );
+
+        let output = tensor
+            .clone()
+            .pad_with(&[(1, 1), (1, 0)], PadMode::Constant(5.0));
+        let grads = (output * weight).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_eq(&TensorData::from([[5.0, 6.0]]), false);
+    }
+
+    #[test]
+    fn should_diff_pad_reflect() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0, 3.0]], &device).require_grad();
+        let weight = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]], &device);
+
+        let output = tensor.clone().pad_with(&[(0, 0), (2, 1)], PadMode::Reflect);
+        let grads = (output * weight).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_eq(&TensorData::from([[3.0, 12.0, 6.0]]), false);
+    }
+
+    #[test]
+    fn should_diff_pad_replicate_and_circular() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+        let output = tensor
+            .clone()
+            .pad_with(&[(1, 0), (0, 0)], PadMode::Replicate)
+            .pad_with(&[(0, 0), (1, 2)], PadMode::Circular);
+        let grads = output.sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_eq(&TensorData::from([[4.0, 6.0], [2.0, 3.0]]), false);
+    }
+}
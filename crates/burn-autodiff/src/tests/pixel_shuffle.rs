@@ -0,0 +1,60 @@
+#[burn_tensor_testgen::testgen(ad_pixel_shuffle)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{pixel_shuffle, pixel_unshuffle};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_pixel_shuffle_backward() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<4>::zeros([1, 4, 2, 2], &device).require_grad();
+        let weights = TestAutodiffTensor::<4>::from_data(
+            TestTensorInt::arange(0..16, &device)
+                .reshape([1, 1, 4, 4])
+                .float()
+                .into_data(),
+            &device,
+        );
+
+        let output = pixel_shuffle(x.clone(), 2);
+        let grads = (output * weights).sum().backward();
+
+        let x_grad = x.grad(&grads).unwrap();
+        x_grad.to_data().assert_approx_eq(
+            &TensorData::from([[
+                [[0., 2.], [8., 10.]],
+                [[1., 3.], [9., 11.]],
+                [[4., 6.], [12., 14.]],
+                [[5., 7.], [13., 15.]],
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_pixel_unshuffle_backward() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<4>::zeros([1, 1, 4, 4], &device).require_grad();
+        let weights = TestAutodiffTensor::<4>::from_data(
+            TestTensorInt::arange(0..16, &device)
+                .reshape([1, 4, 2, 2])
+                .float()
+                .into_data(),
+            &device,
+        );
+
+        let output = pixel_unshuffle(x.clone(), 2);
+        let grads = (output * weights).sum().backward();
+
+        let x_grad = x.grad(&grads).unwrap();
+        x_grad.to_data().assert_approx_eq(
+            &TensorData::from([[[
+                [0., 4., 1., 5.],
+                [8., 12., 9., 13.],
+                [2., 6., 3., 7.],
+                [10., 14., 11., 15.],
+            ]]]),
+            3,
+        );
+    }
+}
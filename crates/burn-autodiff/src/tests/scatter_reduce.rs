@@ -0,0 +1,95 @@
+#[burn_tensor_testgen::testgen(ad_scatter_reduce)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, Reduce, Tensor, TensorData};
+
+    fn grads(
+        tensor: [[f32; 3]; 2],
+        values: [[f32; 3]; 2],
+        reduce: Reduce,
+        include_self: bool,
+    ) -> (TensorData, TensorData) {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::from_data(TensorData::from(tensor), &device).require_grad();
+        let values =
+            TestAutodiffTensor::from_data(TensorData::from(values), &device).require_grad();
+        let weights = TestAutodiffTensor::<2>::from_data(
+            TensorData::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]),
+            &device,
+        );
+        let indices = Tensor::<TestAutodiffBackend, 2, Int>::from_data(
+            TensorData::from([[0, 0, 2], [1, 1, 1]]),
+            &device,
+        );
+
+        let output =
+            tensor
+                .clone()
+                .scatter_reduce(1, indices, values.clone(), reduce, include_self);
+        let grads = output.mul(weights).sum().backward();
+
+        (
+            tensor.grad(&grads).unwrap().into_data(),
+            values.grad(&grads).unwrap().into_data(),
+        )
+    }
+
+    #[test]
+    fn should_diff_scatter_reduce_sum() {
+        let (grad_tensor, grad_values) = grads(
+            [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            Reduce::Sum,
+            false,
+        );
+
+        grad_tensor.assert_eq(&TensorData::from([[0.0, 2.0, 0.0], [4.0, 0.0, 6.0]]), false);
+        grad_values.assert_eq(&TensorData::from([[1.0, 1.0, 3.0], [5.0, 5.0, 5.0]]), false);
+    }
+
+    #[test]
+    fn should_diff_scatter_reduce_mean() {
+        let (grad_tensor, grad_values) = grads(
+            [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            Reduce::Mean,
+            true,
+        );
+
+        grad_tensor.assert_approx_eq(
+            &TensorData::from([[1.0 / 3.0, 2.0, 1.5], [4.0, 1.25, 6.0]]),
+            3,
+        );
+        grad_values.assert_approx_eq(
+            &TensorData::from([[1.0 / 3.0, 1.0 / 3.0, 1.5], [1.25, 1.25, 1.25]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_diff_scatter_reduce_max_with_ties() {
+        let (grad_tensor, grad_values) = grads(
+            [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+            [[2.0, 2.0, 3.0], [4.0, 6.0, 6.0]],
+            Reduce::Max,
+            false,
+        );
+
+        grad_tensor.assert_approx_eq(&TensorData::from([[0.0, 2.0, 0.0], [4.0, 0.0, 6.0]]), 3);
+        grad_values.assert_approx_eq(&TensorData::from([[0.5, 0.5, 3.0], [0.0, 2.5, 2.5]]), 3);
+    }
+
+    #[test]
+    fn should_diff_scatter_reduce_prod() {
+        let (grad_tensor, grad_values) = grads(
+            [[1.0, 2.0, 3.0], [1.0, 2.0, 1.0]],
+            [[2.0, 3.0, 4.0], [1.0, 2.0, 3.0]],
+            Reduce::Prod,
+            true,
+        );
+
+        grad_tensor.assert_approx_eq(&TensorData::from([[6.0, 2.0, 12.0], [4.0, 30.0, 6.0]]), 3);
+        grad_values.assert_approx_eq(&TensorData::from([[3.0, 2.0, 9.0], [60.0, 30.0, 20.0]]), 3);
+    }
+}
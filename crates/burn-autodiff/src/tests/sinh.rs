@@ -0,0 +1,19 @@
+#[burn_tensor_testgen::testgen(ad_sinh)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_sinh() {
+        let data = TensorData::from([[-2.0, 0.0], [0.5, 1.5]]);
+
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(data, &device).require_grad();
+
+        let grads = tensor.clone().sinh().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[3.7622, 1.0], [1.12763, 2.35241]]);
+        grad.to_data().assert_approx_eq(&expected, 3);
+    }
+}
@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(ad_slice)]
 mod tests {
     use super::*;
-    use burn_tensor::TensorData;
+    use burn_tensor::{s, TensorData};
 
     #[test]
     fn should_diff_matmul_with_slice() {
@@ -128,4 +128,43 @@ mod tests {
             .to_data()
             .assert_approx_eq(&cat_grad_2.to_data(), 3);
     }
+
+    #[test]
+    fn should_diff_slice_with_steps() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device)
+                .require_grad();
+        let weights = TestAutodiffTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let output = tensor.clone().slice(s![.., ..;-2]);
+        let grads = output.mul(weights).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_eq(&TensorData::from([[2.0, 0.0, 1.0], [4.0, 0.0, 3.0]]), false);
+    }
+
+    #[test]
+    fn should_diff_slice_assign_with_steps() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<1>::from_data([1.0, 2.0, 3.0, 4.0], &device).require_grad();
+        let values = TestAutodiffTensor::<1>::from_data([5.0, 6.0], &device).require_grad();
+        let weights = TestAutodiffTensor::<1>::from_data([1.0, 2.0, 3.0, 4.0], &device);
+
+        let output = tensor.clone().slice_assign(s![1..;2], values.clone());
+        let grads = output.mul(weights).sum().backward();
+
+        let grad_tensor = tensor.grad(&grads).unwrap();
+        let grad_values = values.grad(&grads).unwrap();
+
+        grad_tensor
+            .to_data()
+            .assert_eq(&TensorData::from([1.0, 0.0, 3.0, 0.0]), false);
+        grad_values
+            .to_data()
+            .assert_eq(&TensorData::from([2.0, 4.0]), false);
+    }
 }
@@ -0,0 +1,51 @@
+#[burn_tensor_testgen::testgen(ad_sparse)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, SparseTensor, Tensor, TensorData};
+
+    #[test]
+    fn should_diff_spmm() {
+        let device = Default::default();
+        let indices =
+            Tensor::<TestAutodiffBackend, 2, Int>::from_ints([[0, 1, 1], [1, 0, 2]], &device);
+        let values = TestAutodiffTensor::<1>::from_floats([2.0, 3.0, 4.0], &device).require_grad();
+        let dense =
+            TestAutodiffTensor::<2>::from_floats([[1.0, -1.0], [2.0, 0.5], [3.0, 2.0]], &device)
+                .require_grad();
+
+        let sparse = SparseTensor::from_coo(indices, values.clone(), [2, 3]).into_csr();
+        let output = sparse.spmm(dense.clone());
+        let grads = (output * TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device))
+            .sum()
+            .backward();
+
+        let values_grad = values.grad(&grads).unwrap();
+        let dense_grad = dense.grad(&grads).unwrap();
+
+        values_grad
+            .to_data()
+            .assert_approx_eq(&TensorData::from([3.0, -1.0, 17.0]), 4);
+        dense_grad.to_data().assert_approx_eq(
+            &TensorData::from([[9.0, 12.0], [2.0, 4.0], [12.0, 16.0]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn should_diff_sparse_to_dense() {
+        let device = Default::default();
+        let dense =
+            TestAutodiffTensor::<2>::from_floats([[0.0, 2.0], [3.0, 0.0]], &device).require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let sparse =
+            SparseTensor::from_dense(dense.clone()).map_values(|values| values.mul_scalar(2.0));
+        let grads = (sparse.into_dense() * weights).sum().backward();
+
+        let dense_grad = dense.grad(&grads).unwrap();
+
+        dense_grad
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[0.0, 4.0], [6.0, 0.0]]), 4);
+    }
+}
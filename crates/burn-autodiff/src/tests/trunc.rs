@@ -0,0 +1,19 @@
+#[burn_tensor_testgen::testgen(ad_trunc)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_trunc() {
+        let data = TensorData::from([[-1.5, -0.2], [0.7, 2.5]]);
+
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(data, &device).require_grad();
+
+        let grads = tensor.clone().trunc().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[0.0, 0.0], [0.0, 0.0]]);
+        grad.to_data().assert_approx_eq(&expected, 3);
+    }
+}
@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use burn_tensor::{backend::Backend, Element, Shape, TensorData, TensorMetadata};
+use burn_tensor::{backend::Backend, Element, Reduce, Shape, TensorData, TensorMetadata};
 use candle_core::WithDType;
 use half::{bf16, f16};
 
@@ -145,3 +145,45 @@ pub fn mask_where_broadcasted(
 
     CandleTensor::new(mask.tensor.where_cond(&value.tensor, &tensor).unwrap())
 }
+
+pub fn scatter_reduce(
+    dim: usize,
+    tensor: CandleTensor,
+    indices: CandleTensor,
+    value: CandleTensor,
+    reduce: Reduce,
+    include_self: bool,
+) -> CandleTensor {
+    if !matches!(reduce, Reduce::Sum | Reduce::Mean) {
+        panic!("Not supported by Candle")
+    }
+
+    let tensor = tensor.tensor;
+    let zeros = tensor.zeros_like().unwrap();
+    let counts = zeros
+        .scatter_add(&indices.tensor, &value.tensor.ones_like().unwrap(), dim)
+        .unwrap();
+
+    let mut output = match include_self {
+        true => tensor
+            .scatter_add(&indices.tensor, &value.tensor, dim)
+            .unwrap(),
+        false => {
+            let sum = zeros
+                .scatter_add(&indices.tensor, &value.tensor, dim)
+                .unwrap();
+            let scattered = counts.gt(0.0).unwrap();
+            scattered.where_cond(&sum, &tensor).unwrap()
+        }
+    };
+
+    if let Reduce::Mean = reduce {
+        let counts = match include_self {
+            true => (counts + 1.0).unwrap(),
+            false => counts.maximum(1.0).unwrap(),
+        };
+        output = output.div(&counts).unwrap();
+    }
+
+    CandleTensor::new(output)
+}
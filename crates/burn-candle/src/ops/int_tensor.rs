@@ -1,6 +1,6 @@
 use burn_tensor::{
     ops::{BoolTensor, FloatTensor, IntElem, IntTensor, IntTensorOps},
-    Bool, Device, Distribution, ElementConversion, Shape, TensorData,
+    Bool, Device, Distribution, ElementConversion, Reduce, Shape, TensorData,
 };
 
 use crate::{
@@ -101,6 +101,17 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<Self> for Candle<F
         )
     }
 
+    fn int_scatter_reduce(
+        dim: usize,
+        tensor: IntTensor<Self>,
+        indices: IntTensor<Self>,
+        value: IntTensor<Self>,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> IntTensor<Self> {
+        super::base::scatter_reduce(dim, tensor, indices, value, reduce, include_self)
+    }
+
     fn int_select(
         tensor: IntTensor<Self>,
         dim: usize,
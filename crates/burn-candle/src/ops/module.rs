@@ -286,12 +286,15 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
                 .tensor
                 .upsample_nearest2d(output_size[0], output_size[1])
                 .unwrap(),
-            InterpolateMode::Bilinear => {
+            InterpolateMode::Linear | InterpolateMode::Bilinear => {
                 panic!("bilinear interpolation is not supported by Candle")
             }
             InterpolateMode::Bicubic => {
                 panic!("bicubic interpolation is not supported by Candle")
             }
+            InterpolateMode::Trilinear => {
+                panic!("trilinear interpolation is only supported by the 3D interpolation")
+            }
         };
 
         CandleTensor::new(tensor)
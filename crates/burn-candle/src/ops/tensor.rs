@@ -2,7 +2,7 @@ use std::borrow::Borrow;
 
 use burn_tensor::{
     ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, IntTensor},
-    Device, Distribution, ElementConversion, FloatDType, Shape, TensorData,
+    Device, Distribution, ElementConversion, FloatDType, Reduce, Shape, TensorData,
 };
 use candle_core::{backend::BackendStorage, shape, Tensor};
 use half::{bf16, f16};
@@ -179,6 +179,17 @@ impl<F: FloatCandleElement, I: IntCandleElement> FloatTensorOps<Self> for Candle
         )
     }
 
+    fn float_scatter_reduce(
+        dim: usize,
+        tensor: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+        value: FloatTensor<Self>,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> FloatTensor<Self> {
+        super::base::scatter_reduce(dim, tensor, indices, value, reduce, include_self)
+    }
+
     fn float_select(
         tensor: FloatTensor<Self>,
         dim: usize,
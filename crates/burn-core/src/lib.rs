@@ -40,6 +40,10 @@ pub mod record;
 /// Module for the tensor.
 pub mod tensor;
 
+/// Utilities shared by the tests of the crates depending on burn-core.
+#[cfg(any(test, feature = "export_tests"))]
+pub mod test_utils;
+
 extern crate alloc;
 
 /// Backend for test cases
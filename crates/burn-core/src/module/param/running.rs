@@ -84,13 +84,11 @@ impl<const D: usize, B: Backend> Module<B> for RunningState<Tensor<B, D>> {
     }
 
     fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
-        let mut tensor = self.value.lock().unwrap();
-        let tensor_out = mapper.map_float(self.id, tensor.clone());
-
-        *tensor = tensor_out;
-        core::mem::drop(tensor);
+        // The mapped state doesn't share its value with the clones of the current one.
+        self.sync();
+        let tensor = mapper.map_float(self.id, self.value());
 
-        self
+        Self::with_id(self.id, tensor)
     }
 
     fn into_record(self) -> Self::Record {
@@ -10,6 +10,19 @@ pub(crate) fn checks_channels_div_groups(channels_in: usize, channels_out: usize
     }
 }
 
+/// The output padding of a transposed convolution only resolves the ambiguity of the output size,
+/// so it must be smaller than either the stride or the dilation.
+pub(crate) fn checks_padding_out(padding_out: &[usize], stride: &[usize], dilation: &[usize]) {
+    for ((padding_out, stride), dilation) in padding_out.iter().zip(stride).zip(dilation) {
+        if padding_out >= stride && padding_out >= dilation {
+            panic!(
+                "The output padding must be smaller than either the stride or the dilation. Got \
+                 padding_out={padding_out}, stride={stride}, dilation={dilation}"
+            );
+        }
+    }
+}
+
 // https://github.com/tracel-ai/burn/issues/2676
 /// Only symmetric padding is currently supported. As such, using `Same` padding with an even kernel
 /// size is not supported as it will not produce the same output size.
@@ -6,7 +6,7 @@ use crate::{
     config::Config,
     module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param},
     nn::{conv::checks, Initializer, PaddingConfig1d},
-    tensor::{backend::Backend, module::conv1d, ops::ConvOptions, Tensor},
+    tensor::{backend::Backend, module::conv1d, ops::ConvOptions, PadMode, Tensor},
 };
 
 /// Configuration to create a [1D convolution](Conv1d) layer using the [init function](Conv1dConfig::init).
@@ -34,6 +34,9 @@ pub struct Conv1dConfig {
     /// size is not supported as it will not produce the same output size.
     #[config(default = "PaddingConfig1d::Valid")]
     pub padding: PaddingConfig1d,
+    /// The values of the padded elements, zeros by default.
+    #[config(default = "PadMode::Constant(0.0)")]
+    pub padding_mode: PadMode,
     /// If bias should be added to the output.
     #[config(default = true)]
     pub bias: bool,
@@ -64,6 +67,8 @@ pub struct Conv1d<B: Backend> {
     pub groups: usize,
     /// Padding configuration.
     pub padding: Ignored<PaddingConfig1d>,
+    /// The values of the padded elements.
+    pub padding_mode: Ignored<PadMode>,
 }
 
 impl<B: Backend> ModuleDisplay for Conv1d<B> {
@@ -121,6 +126,7 @@ impl Conv1dConfig {
             stride: self.stride,
             kernel_size: self.kernel_size,
             padding: Ignored(self.padding.clone()),
+            padding_mode: Ignored(self.padding_mode),
             dilation: self.dilation,
             groups: self.groups,
         }
@@ -141,6 +147,10 @@ impl<B: Backend> Conv1d<B> {
         let padding = self
             .padding
             .calculate_padding_1d(length, self.kernel_size, self.stride);
+        let (input, padding) = match *self.padding_mode {
+            PadMode::Constant(0.0) => (input, padding),
+            mode => (input.pad_with(&[(padding, padding)], mode), 0),
+        };
 
         conv1d(
             input,
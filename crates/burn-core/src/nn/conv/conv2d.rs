@@ -9,7 +9,7 @@ use crate::nn::PaddingConfig2d;
 use crate::tensor::backend::Backend;
 use crate::tensor::module::conv2d;
 use crate::tensor::ops::ConvOptions;
-use crate::tensor::Tensor;
+use crate::tensor::{PadMode, Tensor};
 
 use crate::nn::conv::checks;
 
@@ -36,6 +36,9 @@ pub struct Conv2dConfig {
     /// size is not supported as it will not produce the same output size.
     #[config(default = "PaddingConfig2d::Valid")]
     pub padding: PaddingConfig2d,
+    /// The values of the padded elements, zeros by default.
+    #[config(default = "PadMode::Constant(0.0)")]
+    pub padding_mode: PadMode,
     /// If bias should be added to the output.
     #[config(default = true)]
     pub bias: bool,
@@ -66,6 +69,8 @@ pub struct Conv2d<B: Backend> {
     pub groups: usize,
     /// The padding configuration.
     pub padding: Ignored<PaddingConfig2d>,
+    /// The values of the padded elements.
+    pub padding_mode: Ignored<PadMode>,
 }
 
 impl Conv2dConfig {
@@ -108,6 +113,7 @@ impl Conv2dConfig {
             kernel_size: self.kernel_size,
             dilation: self.dilation,
             padding: Ignored(self.padding.clone()),
+            padding_mode: Ignored(self.padding_mode),
             groups: self.groups,
         }
     }
@@ -153,6 +159,11 @@ impl<B: Backend> Conv2d<B> {
         let padding =
             self.padding
                 .calculate_padding_2d(height_in, width_in, &self.kernel_size, &self.stride);
+        let (input, padding) = match *self.padding_mode {
+            PadMode::Constant(0.0) => (input, padding),
+            mode => (input.pad_with(&padding.map(|p| (p, p)), mode), [0, 0]),
+        };
+
         conv2d(
             input,
             self.weight.val(),
@@ -243,6 +254,24 @@ mod tests {
         let _ = config.init::<TestBackend>(&device);
     }
 
+    #[test]
+    fn padding_mode_replicate() {
+        let device = Default::default();
+        let config = Conv2dConfig::new([1, 1], [3, 3])
+            .with_padding(PaddingConfig2d::Explicit(1, 1))
+            .with_padding_mode(PadMode::Replicate)
+            .with_bias(false)
+            .with_initializer(Initializer::Constant { value: 1.0 });
+        let conv = config.init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]], &device);
+
+        let output = conv.forward(input);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[18.0, 21.0], [24.0, 27.0]]]]), 3);
+    }
+
     #[test]
     fn display() {
         let config = Conv2dConfig::new([5, 1], [5, 5]);
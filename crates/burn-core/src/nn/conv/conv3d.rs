@@ -9,7 +9,7 @@ use crate::nn::PaddingConfig3d;
 use crate::tensor::backend::Backend;
 use crate::tensor::module::conv3d;
 use crate::tensor::ops::ConvOptions;
-use crate::tensor::Tensor;
+use crate::tensor::{PadMode, Tensor};
 
 use crate::nn::conv::checks;
 
@@ -32,6 +32,9 @@ pub struct Conv3dConfig {
     /// The padding configuration.
     #[config(default = "PaddingConfig3d::Valid")]
     pub padding: PaddingConfig3d,
+    /// The values of the padded elements, zeros by default.
+    #[config(default = "PadMode::Constant(0.0)")]
+    pub padding_mode: PadMode,
     /// If bias should be added to the output.
     #[config(default = true)]
     pub bias: bool,
@@ -62,6 +65,8 @@ pub struct Conv3d<B: Backend> {
     pub groups: usize,
     /// The padding configuration.
     pub padding: Ignored<PaddingConfig3d>,
+    /// The values of the padded elements.
+    pub padding_mode: Ignored<PadMode>,
 }
 
 impl Conv3dConfig {
@@ -105,6 +110,7 @@ impl Conv3dConfig {
             kernel_size: self.kernel_size,
             dilation: self.dilation,
             padding: Ignored(self.padding.clone()),
+            padding_mode: Ignored(self.padding_mode),
             groups: self.groups,
         }
     }
@@ -154,6 +160,11 @@ impl<B: Backend> Conv3d<B> {
             &self.kernel_size,
             &self.stride,
         );
+        let (input, padding) = match *self.padding_mode {
+            PadMode::Constant(0.0) => (input, padding),
+            mode => (input.pad_with(&padding.map(|p| (p, p)), mode), [0, 0, 0]),
+        };
+
         conv3d(
             input,
             self.weight.val(),
@@ -96,6 +96,7 @@ impl ConvTranspose2dConfig {
     /// Initialize a new [conv transpose 2d](ConvTranspose2d) module.
     pub fn init<B: Backend>(&self, device: &B::Device) -> ConvTranspose2d<B> {
         checks::checks_channels_div_groups(self.channels[0], self.channels[1], self.groups);
+        checks::checks_padding_out(&self.padding_out, &self.stride, &self.dilation);
 
         let shape = [
             self.channels[0],
@@ -198,4 +199,27 @@ mod tests {
             "ConvTranspose2d {channels: [5, 2], stride: [1, 1], kernel_size: [5, 5], dilation: [1, 1], groups: 1, padding: [0, 0], padding_out: [0, 0], params: 252}"
         );
     }
+
+    #[test]
+    fn padding_out_smaller_than_stride_or_dilation() {
+        let device = Default::default();
+
+        let _ = ConvTranspose2dConfig::new([4, 4], [3, 3])
+            .with_stride([2, 1])
+            .with_dilation([1, 3])
+            .with_padding_out([1, 2])
+            .with_groups(2)
+            .init::<TestBackend>(&device);
+    }
+
+    #[test]
+    #[should_panic = "The output padding must be smaller than either the stride or the dilation."]
+    fn padding_out_too_large() {
+        let device = Default::default();
+
+        let _ = ConvTranspose2dConfig::new([4, 4], [3, 3])
+            .with_stride([2, 2])
+            .with_padding_out([0, 2])
+            .init::<TestBackend>(&device);
+    }
 }
@@ -96,6 +96,7 @@ impl ConvTranspose3dConfig {
     /// Initialize a new [conv transpose 2d](ConvTranspose3d) module.
     pub fn init<B: Backend>(&self, device: &B::Device) -> ConvTranspose3d<B> {
         checks::checks_channels_div_groups(self.channels[0], self.channels[1], self.groups);
+        checks::checks_padding_out(&self.padding_out, &self.stride, &self.dilation);
 
         let shape = [
             self.channels[0],
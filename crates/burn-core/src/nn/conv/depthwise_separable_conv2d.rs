@@ -0,0 +1,134 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::nn::conv::{Conv2d, Conv2dConfig};
+use crate::nn::{Initializer, PaddingConfig2d};
+use crate::tensor::backend::Backend;
+use crate::tensor::{PadMode, Tensor};
+
+/// Configuration to create a [depthwise separable 2D convolution](DepthwiseSeparableConv2d)
+/// layer, using the [init function](DepthwiseSeparableConv2dConfig::init).
+#[derive(Config, Debug)]
+pub struct DepthwiseSeparableConv2dConfig {
+    /// The number of channels.
+    pub channels: [usize; 2],
+    /// The size of the kernel of the depthwise convolution.
+    pub kernel_size: [usize; 2],
+    /// The stride of the depthwise convolution.
+    #[config(default = "[1, 1]")]
+    pub stride: [usize; 2],
+    /// Spacing between kernel elements of the depthwise convolution.
+    #[config(default = "[1, 1]")]
+    pub dilation: [usize; 2],
+    /// The number of output channels of the depthwise convolution for each input channel.
+    #[config(default = "1")]
+    pub depth_multiplier: usize,
+    /// The padding configuration of the depthwise convolution.
+    #[config(default = "PaddingConfig2d::Valid")]
+    pub padding: PaddingConfig2d,
+    /// The values of the padded elements, zeros by default.
+    #[config(default = "PadMode::Constant(0.0)")]
+    pub padding_mode: PadMode,
+    /// If bias should be added to the outputs of both convolutions.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0),fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// Applies a depthwise separable 2D convolution over input tensors, as used by the MobileNet and
+/// Xception models.
+///
+/// The spatial filtering is done by a depthwise convolution, with a group per input channel,
+/// followed by a pointwise `1x1` convolution mixing the channels. This uses a fraction of the
+/// parameters and computations of a regular convolution.
+///
+/// Should be created with [DepthwiseSeparableConv2dConfig].
+#[derive(Module, Debug)]
+pub struct DepthwiseSeparableConv2d<B: Backend> {
+    /// The depthwise convolution.
+    pub depthwise: Conv2d<B>,
+    /// The pointwise convolution.
+    pub pointwise: Conv2d<B>,
+}
+
+impl DepthwiseSeparableConv2dConfig {
+    /// Initialize a new [depthwise separable conv2d](DepthwiseSeparableConv2d) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> DepthwiseSeparableConv2d<B> {
+        let [channels_in, channels_out] = self.channels;
+        let channels_hidden = channels_in * self.depth_multiplier;
+
+        let depthwise = Conv2dConfig::new([channels_in, channels_hidden], self.kernel_size)
+            .with_stride(self.stride)
+            .with_dilation(self.dilation)
+            .with_groups(channels_in)
+            .with_padding(self.padding.clone())
+            .with_padding_mode(self.padding_mode)
+            .with_bias(self.bias)
+            .with_initializer(self.initializer.clone())
+            .init(device);
+        let pointwise = Conv2dConfig::new([channels_hidden, channels_out], [1, 1])
+            .with_bias(self.bias)
+            .with_initializer(self.initializer.clone())
+            .init(device);
+
+        DepthwiseSeparableConv2d {
+            depthwise,
+            pointwise,
+        }
+    }
+}
+
+impl<B: Backend> DepthwiseSeparableConv2d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, height_in, width_in]`
+    /// - output: `[batch_size, channels_out, height_out, width_out]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        self.pointwise.forward(self.depthwise.forward(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn forward_should_match_composed_convolutions() {
+        let device = Default::default();
+        let config = DepthwiseSeparableConv2dConfig::new([4, 6], [3, 3])
+            .with_depth_multiplier(2)
+            .with_padding(PaddingConfig2d::Same);
+        let conv = config.init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 4>::random([2, 4, 7, 5], Distribution::Default, &device);
+
+        let output = conv.forward(input.clone());
+        let expected = conv.pointwise.forward(conv.depthwise.forward(input));
+
+        assert_eq!(output.dims(), [2, 6, 7, 5]);
+        assert_eq!(conv.depthwise.weight.dims(), [8, 1, 3, 3]);
+        assert_eq!(conv.pointwise.weight.dims(), [6, 8, 1, 1]);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn display() {
+        let config = DepthwiseSeparableConv2dConfig::new([4, 6], [3, 3]);
+        let conv = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", conv),
+            "DepthwiseSeparableConv2d {\n  depthwise: Conv2d {stride: [1, 1], kernel_size: [3, 3], dilation: [1, 1], groups: 4, padding: Valid, params: 40}\n  pointwise: Conv2d {stride: [1, 1], kernel_size: [1, 1], dilation: [1, 1], groups: 1, padding: Valid, params: 30}\n  params: 70\n}"
+        );
+    }
+}
@@ -5,6 +5,7 @@ mod conv_transpose1d;
 mod conv_transpose2d;
 mod conv_transpose3d;
 mod deform_conv2d;
+mod depthwise_separable_conv2d;
 
 pub(crate) mod checks;
 
@@ -15,3 +16,4 @@ pub use conv_transpose1d::*;
 pub use conv_transpose2d::*;
 pub use conv_transpose3d::*;
 pub use deform_conv2d::*;
+pub use depthwise_separable_conv2d::*;
@@ -3,7 +3,7 @@ use crate as burn;
 use crate::config::Config;
 use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
 use crate::tensor::backend::Backend;
-use crate::tensor::{Distribution, Tensor};
+use crate::tensor::{Distribution, Generator, Tensor};
 
 /// Configuration to create a [Dropout](Dropout) layer using the [init function](DropoutConfig::init).
 #[derive(Config, Debug)]
@@ -54,9 +54,39 @@ impl Dropout {
             return input;
         }
 
+        let mask = input.random_like(Distribution::Bernoulli(1.0 - self.prob));
+        self.apply_mask(input, mask)
+    }
+
+    /// Applies the forward pass on the input tensor, sampling the dropped elements with the given
+    /// [generator](Generator) instead of the global random state of the backend.
+    ///
+    /// See [Dropout](Dropout) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any]`
+    /// - output: `[..., any]`
+    pub fn forward_with<B: Backend, const D: usize>(
+        &self,
+        input: Tensor<B, D>,
+        generator: &mut Generator,
+    ) -> Tensor<B, D> {
+        if !B::ad_enabled() || self.prob == 0.0 {
+            return input;
+        }
+
+        let mask = input.random_like_with(Distribution::Bernoulli(1.0 - self.prob), generator);
+        self.apply_mask(input, mask)
+    }
+
+    fn apply_mask<B: Backend, const D: usize>(
+        &self,
+        input: Tensor<B, D>,
+        mask: Tensor<B, D>,
+    ) -> Tensor<B, D> {
         let prob_keep = 1.0 - self.prob;
-        let random = input.random_like(Distribution::Bernoulli(prob_keep));
-        let x = input * random;
+        let x = input * mask;
 
         x * (1.0 / prob_keep)
     }
@@ -97,6 +127,20 @@ mod tests {
         assert_ne!(tensor.to_data(), output.to_data());
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_generator_should_be_reproducible() {
+        let tensor =
+            Tensor::<TestAutodiffBackend, 2>::ones(Shape::new([100, 100]), &Default::default());
+        let dropout = DropoutConfig::new(0.5).init();
+
+        let output_1 = dropout.forward_with(tensor.clone(), &mut Generator::new(0));
+        let output_2 = dropout.forward_with(tensor.clone(), &mut Generator::new(0));
+
+        assert_ne!(tensor.to_data(), output_1.to_data());
+        assert_eq!(output_1.to_data(), output_2.to_data());
+    }
+
     #[test]
     fn without_ad_backend_should_not_change_input() {
         let tensor = Tensor::<TestBackend, 2>::ones(Shape::new([100, 100]), &Default::default());
@@ -9,7 +9,7 @@ use crate::tensor::backend::Backend;
 use crate::tensor::Int;
 use crate::tensor::Tensor;
 
-use crate::tensor::module::embedding;
+use crate::tensor::module::{embedding, embedding_sparse};
 
 /// Configuration to create an [Embedding](Embedding) layer using the [init function](EmbeddingConfig::init).
 #[derive(Config)]
@@ -21,6 +21,13 @@ pub struct EmbeddingConfig {
     /// The type of function used to initialize neural network parameters
     #[config(default = "Initializer::Normal{mean:0.0, std:1.0}")]
     pub initializer: Initializer,
+    /// If the gradients of the weights only contain the rows used by the forward pass, see
+    /// [embedding_sparse](crate::tensor::module::embedding_sparse).
+    ///
+    /// The optimizers that support row-sparse gradients, like [Adam](crate::optim::Adam) and
+    /// [Sgd](crate::optim::Sgd), then only update those rows.
+    #[config(default = false)]
+    pub sparse_grad: bool,
 }
 
 /// Lookup table to store a fix number of vectors.
@@ -32,6 +39,8 @@ pub struct Embedding<B: Backend> {
     /// The learnable weights of the module of shape `[n_embedding, d_model]` initialized
     /// from a normal distribution `N(0, 1)`.
     pub weight: Param<Tensor<B, 2>>,
+    /// If the gradients of the weights are row-sparse.
+    pub sparse_grad: bool,
 }
 
 impl<B: Backend> ModuleDisplay for Embedding<B> {
@@ -57,7 +66,10 @@ impl EmbeddingConfig {
             .initializer
             .init([self.n_embedding, self.d_model], device);
 
-        Embedding { weight }
+        Embedding {
+            weight,
+            sparse_grad: self.sparse_grad,
+        }
     }
 }
 
@@ -71,7 +83,29 @@ impl<B: Backend> Embedding<B> {
     /// - input: `[batch_size, seq_length]`
     /// - output: `[batch_size, seq_length, d_model]`
     pub fn forward(&self, input: Tensor<B, 2, Int>) -> Tensor<B, 3> {
-        embedding(self.weight.val(), input)
+        match self.sparse_grad {
+            true => embedding_sparse(self.weight.val(), input),
+            false => embedding(self.weight.val(), input),
+        }
+    }
+
+    /// Projects the input vectors on the embedding vectors, using the weights as the ones of an
+    /// output linear layer without bias.
+    ///
+    /// This ties the weights of the input embedding and of the output projection of a language
+    /// model, both uses of the weights contributing to their gradients.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_model]`
+    /// - output: `[..., n_embedding]`
+    pub fn attend<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if D == 1 {
+            // Insert and remove an extra batch dimension for the batch matmul to work.
+            return Self::attend::<2>(self, input.unsqueeze()).flatten(0, 1);
+        }
+
+        input.matmul(self.weight.val().transpose().unsqueeze())
     }
 }
 
@@ -79,7 +113,7 @@ impl<B: Backend> Embedding<B> {
 mod tests {
     use super::*;
     use crate::tensor::TensorData;
-    use crate::TestBackend;
+    use crate::{TestAutodiffBackend, TestBackend};
 
     #[test]
     fn initializer_default() {
@@ -119,6 +153,43 @@ mod tests {
             .assert_approx_eq(&TensorData::zeros::<f32, _>(embed.weight.shape()), 3);
     }
 
+    #[test]
+    fn sparse_grad_should_match_dense_grad() {
+        let device = Default::default();
+        let embed = EmbeddingConfig::new(6, 3).init::<TestAutodiffBackend>(&device);
+        let embed_sparse = Embedding {
+            weight: embed.weight.clone(),
+            sparse_grad: true,
+        };
+        let input = Tensor::<TestAutodiffBackend, 2, Int>::from_data([[1, 4], [4, 0]], &device);
+
+        let grads = embed.forward(input.clone()).sum().backward();
+        let grad = embed.weight.grad(&grads).unwrap();
+        let mut grads = embed_sparse.forward(input).sum().backward();
+        let (rows, values) = embed_sparse.weight.grad_remove_rows(&mut grads).unwrap();
+
+        assert_eq!(rows.dims(), [4]);
+        Tensor::zeros([6, 3], &device)
+            .select_assign(0, rows, values)
+            .into_data()
+            .assert_eq(&grad.into_data(), false);
+    }
+
+    #[test]
+    fn attend_should_use_transposed_weights() {
+        let device = Default::default();
+        let embed = EmbeddingConfig::new(4, 2).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::from_data([[[1.0, 2.0], [0.5, -1.0]]], &device);
+
+        let output = embed.attend(input.clone());
+        let expected = input.matmul(embed.weight.val().transpose().unsqueeze());
+
+        assert_eq!(output.dims(), [1, 2, 4]);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
     #[test]
     fn display() {
         let config = EmbeddingConfig::new(100, 10);
@@ -0,0 +1,305 @@
+use crate as burn;
+
+use super::Initializer;
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param};
+use crate::tensor::backend::Backend;
+use crate::tensor::module::{embedding, embedding_sparse};
+use crate::tensor::{Int, Reduce, Tensor};
+
+/// The reduction applied to the embedding vectors of each bag of an [EmbeddingBag].
+#[derive(Config, Debug, PartialEq)]
+pub enum EmbeddingBagMode {
+    /// Sum of the vectors of the bag.
+    Sum,
+    /// Mean of the vectors of the bag.
+    Mean,
+    /// Element-wise maximum of the vectors of the bag.
+    Max,
+}
+
+/// Configuration to create an [EmbeddingBag](EmbeddingBag) layer using the
+/// [init function](EmbeddingBagConfig::init).
+#[derive(Config)]
+pub struct EmbeddingBagConfig {
+    /// The number of embedding vectors.
+    pub n_embedding: usize,
+    /// The size of each vector.
+    pub d_model: usize,
+    /// The reduction applied to the vectors of each bag.
+    #[config(default = "EmbeddingBagMode::Mean")]
+    pub mode: EmbeddingBagMode,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::Normal{mean:0.0, std:1.0}")]
+    pub initializer: Initializer,
+    /// If the gradients of the weights only contain the rows used by the forward pass, see
+    /// [EmbeddingConfig::sparse_grad](super::EmbeddingConfig::sparse_grad).
+    #[config(default = false)]
+    pub sparse_grad: bool,
+}
+
+/// Lookup table reducing the vectors of bags of indices, without computing the intermediate
+/// embeddings of each index.
+///
+/// The bags are either given as a 2D tensor with one bag of the same size per row, or as a 1D tensor
+/// of indices with the offsets of each bag, see [forward_offsets](EmbeddingBag::forward_offsets).
+///
+/// Should be created with [EmbeddingBagConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct EmbeddingBag<B: Backend> {
+    /// The learnable weights of the module of shape `[n_embedding, d_model]` initialized
+    /// from a normal distribution `N(0, 1)`.
+    pub weight: Param<Tensor<B, 2>>,
+    /// The reduction applied to the vectors of each bag.
+    pub mode: Ignored<EmbeddingBagMode>,
+    /// If the gradients of the weights are row-sparse.
+    pub sparse_grad: bool,
+}
+
+impl<B: Backend> ModuleDisplay for EmbeddingBag<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [n_embedding, d_model] = self.weight.shape().dims();
+        content
+            .add("n_embedding", &n_embedding)
+            .add("d_model", &d_model)
+            .add("mode", &self.mode)
+            .optional()
+    }
+}
+
+impl EmbeddingBagConfig {
+    /// Initialize a new [embedding bag](EmbeddingBag) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> EmbeddingBag<B> {
+        let weight = self
+            .initializer
+            .init([self.n_embedding, self.d_model], device);
+
+        EmbeddingBag {
+            weight,
+            mode: Ignored(self.mode.clone()),
+            sparse_grad: self.sparse_grad,
+        }
+    }
+}
+
+impl<B: Backend> EmbeddingBag<B> {
+    /// Applies the forward pass on bags of the same size, one per row of the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, bag_size]`
+    /// - output: `[batch_size, d_model]`
+    pub fn forward(&self, input: Tensor<B, 2, Int>) -> Tensor<B, 2> {
+        let embeddings = self.lookup(input);
+
+        let output = match *self.mode {
+            EmbeddingBagMode::Sum => embeddings.sum_dim(1),
+            EmbeddingBagMode::Mean => embeddings.mean_dim(1),
+            EmbeddingBagMode::Max => embeddings.max_dim(1),
+        };
+
+        output.squeeze(1)
+    }
+
+    /// Applies the forward pass on bags of any size, given by the offsets of their first index.
+    ///
+    /// The bag `i` contains the indices from `offsets[i]` to `offsets[i + 1]`, the last one ending
+    /// with the indices. The offsets must be in ascending order and start with zero, an empty bag
+    /// having the same offset as the next one and an output of zeros.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - The indices of all the bags.
+    /// * `offsets` - The offset of the first index of each bag.
+    /// * `per_sample_weights` - The weights multiplying the vector of each index, only supported
+    ///   by the [sum](EmbeddingBagMode::Sum) mode.
+    ///
+    /// # Shapes
+    ///
+    /// - indices: `[num_indices]`
+    /// - offsets: `[batch_size]`
+    /// - per_sample_weights: `[num_indices]`
+    /// - output: `[batch_size, d_model]`
+    pub fn forward_offsets(
+        &self,
+        indices: Tensor<B, 1, Int>,
+        offsets: Tensor<B, 1, Int>,
+        per_sample_weights: Option<Tensor<B, 1>>,
+    ) -> Tensor<B, 2> {
+        let [num_indices] = indices.dims();
+        let [batch_size] = offsets.dims();
+        let [_, d_model] = self.weight.dims();
+        let device = indices.device();
+
+        let mut values = self.lookup(indices.unsqueeze()).squeeze::<2>(0);
+        if let Some(weights) = per_sample_weights {
+            assert_eq!(
+                *self.mode,
+                EmbeddingBagMode::Sum,
+                "Per sample weights are only supported by the sum mode."
+            );
+            values = values * weights.unsqueeze_dim(1);
+        }
+
+        // The bag of each index is the number of bags starting at or before it minus one.
+        let positions = Tensor::arange(0..num_indices as i64, &device);
+        let bags = offsets.searchsorted(positions, true).sub_scalar(1);
+        let output = Tensor::zeros([batch_size, d_model], &device);
+
+        match *self.mode {
+            EmbeddingBagMode::Sum => output.select_assign(0, bags, values),
+            EmbeddingBagMode::Mean => {
+                let counts = Tensor::<B, 1>::zeros([batch_size], &device)
+                    .select_assign(0, bags.clone(), Tensor::ones([num_indices], &device))
+                    .clamp_min(1.0);
+
+                output.select_assign(0, bags, values) / counts.unsqueeze_dim(1)
+            }
+            EmbeddingBagMode::Max => {
+                let bags = bags.unsqueeze_dim::<2>(1).repeat_dim(1, d_model);
+
+                output.scatter_reduce(0, bags, values, Reduce::Max, false)
+            }
+        }
+    }
+
+    fn lookup(&self, indices: Tensor<B, 2, Int>) -> Tensor<B, 3> {
+        match self.sparse_grad {
+            true => embedding_sparse(self.weight.val(), indices),
+            false => embedding(self.weight.val(), indices),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    fn embedding_bag<B: Backend>(mode: EmbeddingBagMode, device: &B::Device) -> EmbeddingBag<B> {
+        let weight = Tensor::from_data([[1.0, -1.0], [2.0, 0.0], [-3.0, 4.0], [0.5, 0.5]], device);
+
+        EmbeddingBag {
+            weight: Param::from_tensor(weight),
+            mode: Ignored(mode),
+            sparse_grad: false,
+        }
+    }
+
+    #[test]
+    fn forward_offsets_sum() {
+        let device = Default::default();
+        let bag = embedding_bag::<TestBackend>(EmbeddingBagMode::Sum, &device);
+        let indices = Tensor::from_data([0, 1, 2, 3, 1], &device);
+        let offsets = Tensor::from_data([0, 2, 2], &device);
+
+        let output = bag.forward_offsets(indices, offsets, None);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[3.0, -1.0], [0.0, 0.0], [-0.5, 4.5]]), 3);
+    }
+
+    #[test]
+    fn forward_offsets_mean() {
+        let device = Default::default();
+        let bag = embedding_bag::<TestBackend>(EmbeddingBagMode::Mean, &device);
+        let indices = Tensor::from_data([0, 1, 2, 3, 1], &device);
+        let offsets = Tensor::from_data([0, 2, 2], &device);
+
+        let output = bag.forward_offsets(indices, offsets, None);
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[1.5, -0.5], [0.0, 0.0], [-0.5 / 3.0, 1.5]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn forward_offsets_max() {
+        let device = Default::default();
+        let bag = embedding_bag::<TestBackend>(EmbeddingBagMode::Max, &device);
+        let indices = Tensor::from_data([0, 1, 2, 3, 1], &device);
+        let offsets = Tensor::from_data([0, 2, 2], &device);
+
+        let output = bag.forward_offsets(indices, offsets, None);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[2.0, 0.0], [0.0, 0.0], [2.0, 4.0]]), 3);
+    }
+
+    #[test]
+    fn forward_offsets_per_sample_weights() {
+        let device = Default::default();
+        let bag = embedding_bag::<TestBackend>(EmbeddingBagMode::Sum, &device);
+        let indices = Tensor::from_data([0, 1, 3], &device);
+        let offsets = Tensor::from_data([0, 2], &device);
+        let weights = Tensor::from_data([2.0, 0.5, -1.0], &device);
+
+        let output = bag.forward_offsets(indices, offsets, Some(weights));
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[3.0, -2.0], [-0.5, -0.5]]), 3);
+    }
+
+    #[test]
+    fn forward_should_match_forward_offsets() {
+        let device = Default::default();
+        for mode in [
+            EmbeddingBagMode::Sum,
+            EmbeddingBagMode::Mean,
+            EmbeddingBagMode::Max,
+        ] {
+            let bag = embedding_bag::<TestBackend>(mode, &device);
+            let input = Tensor::<TestBackend, 2, Int>::from_data([[0, 2, 3], [1, 1, 0]], &device);
+
+            let output = bag.forward(input.clone());
+            let expected =
+                bag.forward_offsets(input.reshape([6]), Tensor::from_data([0, 3], &device), None);
+
+            output
+                .into_data()
+                .assert_approx_eq(&expected.into_data(), 3);
+        }
+    }
+
+    #[test]
+    fn sparse_grad_should_only_contain_used_rows() {
+        let device = Default::default();
+        let mut bag = embedding_bag::<TestAutodiffBackend>(EmbeddingBagMode::Mean, &device);
+        bag.sparse_grad = true;
+        let indices = Tensor::from_data([3, 1, 3], &device);
+        let offsets = Tensor::from_data([0, 1], &device);
+
+        let output = bag.forward_offsets(indices, offsets, None);
+        let mut grads = output.sum().backward();
+        let (rows, values) = bag.weight.grad_remove_rows(&mut grads).unwrap();
+
+        rows.into_data()
+            .assert_eq(&TensorData::from([3, 1, 3]), false);
+        values
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 1.0], [0.5, 0.5], [0.5, 0.5]]), 3);
+    }
+
+    #[test]
+    fn display() {
+        let config = EmbeddingBagConfig::new(100, 10).with_mode(EmbeddingBagMode::Sum);
+        let bag = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", bag),
+            "EmbeddingBag {n_embedding: 100, d_model: 10, mode: Sum, params: 1000}"
+        );
+    }
+}
@@ -3,7 +3,8 @@ use crate::tensor::Shape;
 use crate::config::Config;
 use crate::module::{Param, ParamId};
 use crate::tensor::backend::Backend;
-use crate::tensor::{Distribution, Tensor};
+use crate::tensor::{Distribution, Generator, Int, Tensor};
+use alloc::vec::Vec;
 
 use crate as burn;
 
@@ -68,6 +69,66 @@ pub enum Initializer {
         /// The gain to use in initialization formula
         gain: f64,
     },
+    /// Fills tensor with values drawn from a normal distribution with specified mean and std,
+    /// redrawn outside of the interval `[a, b]`
+    TruncatedNormal {
+        /// The mean of the normal distribution
+        mean: f64,
+
+        /// The standard deviation of the normal distribution
+        std: f64,
+
+        /// The minimum value, not relative to the mean
+        a: f64,
+
+        /// The maximum value, not relative to the mean
+        b: f64,
+    },
+    /// Fills tensor with a (semi) orthogonal matrix, as described in [Exact solutions to the
+    /// nonlinear dynamics of learning in deep linear neural networks](https://arxiv.org/abs/1312.6120)
+    ///
+    /// The tensor is viewed as a matrix with its first dimension as rows and the others flattened
+    /// as columns, the rows or the columns, whichever are fewer, being orthonormal.
+    Orthogonal {
+        /// The gain to use in initialization formula
+        gain: f64,
+    },
+    /// Fills tensor with values drawn from a distribution with a variance of `scale / fan`,
+    /// generalizing the Kaiming (`scale = 2`, fan in) and Xavier (`scale = 1`, average fan)
+    /// initializations
+    VarianceScaling {
+        /// The scale of the variance, the square of the gain
+        scale: f64,
+
+        /// The fan dividing the scale
+        mode: FanMode,
+
+        /// The distribution to draw from
+        distribution: VarianceScalingDistribution,
+    },
+}
+
+/// The fan used by the [variance scaling](Initializer::VarianceScaling) initialization.
+#[derive(Config, Debug, PartialEq)]
+pub enum FanMode {
+    /// The number of input units
+    In,
+    /// The number of output units
+    Out,
+    /// The average of the numbers of input and output units
+    Avg,
+}
+
+/// The distribution used by the [variance scaling](Initializer::VarianceScaling) initialization.
+#[derive(Config, Debug, PartialEq)]
+pub enum VarianceScalingDistribution {
+    /// Normal distribution truncated at two standard deviations, with its standard deviation
+    /// corrected so that the variance is the expected one
+    TruncatedNormal,
+    /// Normal distribution
+    Normal,
+    /// Uniform distribution
+    Uniform,
 }
 
 impl Initializer {
@@ -95,6 +156,37 @@ impl Initializer {
         fan_in: Option<usize>,
         fan_out: Option<usize>,
         device: &B::Device,
+    ) -> Param<Tensor<B, D>> {
+        self.init_lazy(shape, fan_in, fan_out, None, device)
+    }
+
+    /// Inits a tensor parameter of given shape with values depending on initializer kind, drawn
+    /// with the given [generator](Generator) instead of the global random state of the backend.
+    ///
+    /// The generator is split when this method is called, so the values of the parameter do not
+    /// depend on when it is lazily initialized.
+    ///
+    /// # Params
+    ///
+    /// - shape: Shape of the initiated tensor.
+    pub fn init_with_generator<B: Backend, const D: usize, S: Into<Shape>>(
+        &self,
+        shape: S,
+        fan_in: Option<usize>,
+        fan_out: Option<usize>,
+        generator: &mut Generator,
+        device: &B::Device,
+    ) -> Param<Tensor<B, D>> {
+        self.init_lazy(shape, fan_in, fan_out, Some(generator.split()), device)
+    }
+
+    fn init_lazy<B: Backend, const D: usize, S: Into<Shape>>(
+        &self,
+        shape: S,
+        fan_in: Option<usize>,
+        fan_out: Option<usize>,
+        generator: Option<Generator>,
+        device: &B::Device,
     ) -> Param<Tensor<B, D>> {
         let device = device.clone();
         let shape: Shape = shape.into();
@@ -103,7 +195,9 @@ impl Initializer {
         Param::uninitialized(
             ParamId::new(),
             move |device, require_grad| {
-                let mut tensor = config.init_tensor(shape.clone(), fan_in, fan_out, device);
+                let mut generator = generator;
+                let mut tensor =
+                    config.init_tensor(shape.clone(), fan_in, fan_out, generator.as_mut(), device);
 
                 if require_grad {
                     tensor = tensor.require_grad();
@@ -121,6 +215,7 @@ impl Initializer {
         shape: S,
         fan_in: Option<usize>,
         fan_out: Option<usize>,
+        generator: Option<&mut Generator>,
         device: &B::Device,
     ) -> Tensor<B, D> {
         let shape = shape.into();
@@ -128,24 +223,83 @@ impl Initializer {
             Initializer::Constant { value } => Tensor::<B, D>::full(shape, *value, device),
             Initializer::Ones => Tensor::<B, D>::ones(shape, device),
             Initializer::Zeros => Tensor::<B, D>::zeros(shape, device),
-            Initializer::Uniform { min, max } => uniform_draw(shape, *min, *max, device),
-            Initializer::Normal { mean, std } => normal_draw(shape, *mean, *std, device),
+            Initializer::Uniform { min, max } => uniform_draw(shape, *min, *max, generator, device),
+            Initializer::Normal { mean, std } => normal_draw(shape, *mean, *std, generator, device),
             Initializer::KaimingUniform { gain, fan_out_only } => {
                 let a = 3.0f64.sqrt() * *gain * self.kaiming_std(*fan_out_only, fan_in, fan_out);
-                uniform_draw(shape, -a, a, device)
+                uniform_draw(shape, -a, a, generator, device)
             }
             Initializer::KaimingNormal { gain, fan_out_only } => {
                 let std = *gain * self.kaiming_std(*fan_out_only, fan_in, fan_out);
-                normal_draw(shape, 0.0, std, device)
+                normal_draw(shape, 0.0, std, generator, device)
             }
             Initializer::XavierUniform { gain } => {
                 let a = 3.0f64.sqrt() * *gain * self.xavier_std(fan_in, fan_out);
-                uniform_draw(shape, -a, a, device)
+                uniform_draw(shape, -a, a, generator, device)
             }
             Initializer::XavierNormal { gain } => {
                 let std = *gain * self.xavier_std(fan_in, fan_out);
-                normal_draw(shape, 0.0, std, device)
+                normal_draw(shape, 0.0, std, generator, device)
             }
+            Initializer::TruncatedNormal { mean, std, a, b } => {
+                truncated_normal_draw(shape, *mean, *std, *a, *b, generator, device)
+            }
+            Initializer::Orthogonal { gain } => orthogonal_draw(shape, *gain, generator, device),
+            Initializer::VarianceScaling {
+                scale,
+                mode,
+                distribution,
+            } => {
+                let std = (*scale / self.variance_scaling_fan(mode, fan_in, fan_out)).sqrt();
+                match distribution {
+                    VarianceScalingDistribution::TruncatedNormal => {
+                        // Standard deviation of the standard normal distribution truncated to [-2, 2].
+                        let std = std / 0.879_625_661_034_239_8;
+                        truncated_normal_draw(
+                            shape,
+                            0.0,
+                            std,
+                            -2.0 * std,
+                            2.0 * std,
+                            generator,
+                            device,
+                        )
+                    }
+                    VarianceScalingDistribution::Normal => {
+                        normal_draw(shape, 0.0, std, generator, device)
+                    }
+                    VarianceScalingDistribution::Uniform => {
+                        let a = 3.0f64.sqrt() * std;
+                        uniform_draw(shape, -a, a, generator, device)
+                    }
+                }
+            }
+        }
+    }
+
+    fn variance_scaling_fan(
+        &self,
+        mode: &FanMode,
+        fan_in: Option<usize>,
+        fan_out: Option<usize>,
+    ) -> f64 {
+        let expect_fan_in = || {
+            fan_in.expect(
+                "Can't use variance scaling initialization without specifying fan in. Use \
+                 init_with method and provide fan_in.",
+            )
+        };
+        let expect_fan_out = || {
+            fan_out.expect(
+                "Can't use variance scaling initialization without specifying fan out. Use \
+                 init_with method and provide fan_out.",
+            )
+        };
+
+        match mode {
+            FanMode::In => expect_fan_in() as f64,
+            FanMode::Out => expect_fan_out() as f64,
+            FanMode::Avg => (expect_fan_in() + expect_fan_out()) as f64 / 2.0,
         }
     }
 
@@ -180,20 +334,136 @@ fn uniform_draw<B: Backend, const D: usize, S: Into<Shape>>(
     shape: S,
     low: f64,
     high: f64,
+    generator: Option<&mut Generator>,
     device: &B::Device,
 ) -> Tensor<B, D> {
     let distribution = Distribution::Uniform(low, high);
-    Tensor::<B, D>::random(shape, distribution, device)
+    random_draw(shape, distribution, generator, device)
 }
 
 fn normal_draw<B: Backend, const D: usize, S: Into<Shape>>(
     shape: S,
     mean: f64,
     std: f64,
+    generator: Option<&mut Generator>,
     device: &B::Device,
 ) -> Tensor<B, D> {
     let distribution = Distribution::Normal(mean, std);
-    Tensor::<B, D>::random(shape, distribution, device)
+    random_draw(shape, distribution, generator, device)
+}
+
+fn truncated_normal_draw<B: Backend, const D: usize, S: Into<Shape>>(
+    shape: S,
+    mean: f64,
+    std: f64,
+    a: f64,
+    b: f64,
+    generator: Option<&mut Generator>,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    assert!(
+        a < b,
+        "The minimum value of the truncated normal distribution must be lower than its maximum."
+    );
+
+    // Draw uniformly between the cumulative distribution function of the bounds, and use the
+    // inverse of the cumulative distribution function to get the values.
+    let cdf = |x: f64| erf((x - mean) / (std * core::f64::consts::SQRT_2));
+    let tensor: Tensor<B, D> = uniform_draw(shape, cdf(a), cdf(b), generator, device);
+
+    tensor
+        .erfinv()
+        .mul_scalar(std * core::f64::consts::SQRT_2)
+        .add_scalar(mean)
+        .clamp(a, b)
+}
+
+fn orthogonal_draw<B: Backend, const D: usize>(
+    shape: Shape,
+    gain: f64,
+    generator: Option<&mut Generator>,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    assert!(
+        D >= 2,
+        "Can't use orthogonal initialization on a tensor with less than 2 dimensions."
+    );
+    let rows = shape.dims[0];
+    let cols = shape.num_elements() / rows;
+    let (size, num_vectors) = (usize::max(rows, cols), usize::min(rows, cols));
+
+    // Householder QR decomposition of a random matrix, with the signs of the columns of Q
+    // corrected so that the diagonal of R is positive.
+    let mut matrix: Tensor<B, 2> = normal_draw([size, num_vectors], 0.0, 1.0, generator, device);
+    let identity = Tensor::<B, 2>::eye(size, device);
+    let rows_index = Tensor::<B, 1, Int>::arange(0..size as i64, device).reshape([size, 1]);
+    let mut reflectors = Vec::with_capacity(num_vectors);
+    let mut signs = Vec::with_capacity(num_vectors);
+
+    for i in 0..num_vectors {
+        // The reflector zeroes the elements of the column below the diagonal.
+        let below = rows_index.clone().greater_equal_elem(i as i64).float();
+        let column = matrix.clone().narrow(1, i, 1) * below;
+        let unit = identity.clone().narrow(1, i, 1);
+
+        // The sign of the diagonal element is chosen to avoid cancellations.
+        let sign = column
+            .clone()
+            .narrow(0, i, 1)
+            .greater_equal_elem(0.0)
+            .float()
+            .mul_scalar(2.0)
+            .sub_scalar(1.0);
+        let norm = column.clone().powi_scalar(2).sum().sqrt().unsqueeze();
+        let reflector = column + unit * sign.clone() * norm;
+        let norm = reflector.clone().powi_scalar(2).sum().sqrt().unsqueeze();
+        let reflector = reflector / norm;
+
+        let projection = reflector.clone().transpose().matmul(matrix.clone());
+        matrix = matrix - reflector.clone().matmul(projection).mul_scalar(2.0);
+        reflectors.push(reflector);
+        // The diagonal element of R has the opposite sign of the diagonal element of the column.
+        signs.push(sign.neg());
+    }
+
+    // Q is the product of the reflectors applied to the first columns of the identity.
+    let mut basis = identity.narrow(1, 0, num_vectors);
+    for reflector in reflectors.into_iter().rev() {
+        let projection = reflector.clone().transpose().matmul(basis.clone());
+        basis = basis - reflector.matmul(projection).mul_scalar(2.0);
+    }
+    let basis = basis * Tensor::cat(signs, 1);
+
+    let matrix = match rows < cols {
+        true => basis.transpose(),
+        false => basis,
+    };
+
+    matrix.mul_scalar(gain).reshape(shape)
+}
+
+/// Error function using the formula 7.1.26 of Abramowitz and Stegun, with a maximum error of
+/// `1.5e-7`.
+fn erf(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.327_591_1 * x.abs());
+    let polynomial =
+        ((((1.061_405_429 * t - 1.453_152_027) * t + 1.421_413_741) * t - 0.284_496_736) * t
+            + 0.254_829_592)
+            * t;
+
+    x.signum() * (1.0 - polynomial * (-x * x).exp())
+}
+
+fn random_draw<B: Backend, const D: usize, S: Into<Shape>>(
+    shape: S,
+    distribution: Distribution,
+    generator: Option<&mut Generator>,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    match generator {
+        Some(generator) => Tensor::<B, D>::random_with(shape, distribution, generator, device),
+        None => Tensor::<B, D>::random(shape, distribution, device),
+    }
 }
 
 #[cfg(test)]
@@ -265,6 +535,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn initializer_init_with_generator_should_be_reproducible() {
+        let initializer = Initializer::KaimingNormal {
+            gain: 1.0,
+            fan_out_only: false,
+        };
+        let init = |generator: &mut Generator| {
+            initializer
+                .init_with_generator::<TB, 2, _>(
+                    [8, 8],
+                    Some(8),
+                    None,
+                    generator,
+                    &Default::default(),
+                )
+                .into_value()
+                .into_data()
+        };
+
+        let mut generator_1 = Generator::new(0);
+        let mut generator_2 = Generator::new(0);
+
+        let data_1 = init(&mut generator_1);
+        let data_2 = init(&mut generator_2);
+        let data_3 = init(&mut generator_1);
+
+        data_1.assert_eq(&data_2, true);
+        assert_ne!(data_1, data_3);
+    }
+
     #[test]
     fn initializer_constant_init() {
         let value = 5.0;
@@ -423,6 +723,147 @@ mod tests {
         assert_normal_init(expected_mean, expected_var, &tensor)
     }
 
+    #[test]
+    fn initializer_truncated_normal_init() {
+        TB::seed(0);
+
+        let (mean, std, a, b) = (0.5, 1.0, -0.5, 1.0);
+        let tensor: Tensor<TB, 1> = Initializer::TruncatedNormal { mean, std, a, b }
+            .init([2000], &Default::default())
+            .into_value();
+        let mean_act: f32 = tensor.clone().mean().into_scalar().elem();
+
+        tensor.into_data().assert_within_range(a as f32..b as f32);
+        // Mean of the normal distribution N(0.5, 1) truncated to [-0.5, 1.0].
+        assert!(
+            (mean_act - 0.2934).abs() < 0.05,
+            "Expected mean to be 0.2934 += 0.05, but got {mean_act}"
+        );
+    }
+
+    #[test]
+    fn initializer_orthogonal_init() {
+        TB::seed(0);
+
+        let gain = 2.0;
+        let tall: Tensor<TB, 2> = Initializer::Orthogonal { gain }
+            .init([8, 5], &Default::default())
+            .into_value();
+        let wide: Tensor<TB, 3> = Initializer::Orthogonal { gain }
+            .init([3, 2, 4], &Default::default())
+            .into_value();
+        let wide = wide.reshape([3, 8]);
+
+        tall.clone()
+            .transpose()
+            .matmul(tall)
+            .into_data()
+            .assert_approx_eq(
+                &Tensor::<TB, 2>::eye(5, &Default::default())
+                    .mul_scalar(4.0)
+                    .into_data(),
+                3,
+            );
+        wide.clone()
+            .matmul(wide.transpose())
+            .into_data()
+            .assert_approx_eq(
+                &Tensor::<TB, 2>::eye(3, &Default::default())
+                    .mul_scalar(4.0)
+                    .into_data(),
+                3,
+            );
+    }
+
+    #[test]
+    #[should_panic]
+    fn initializer_orthogonal_1d() {
+        let _: Tensor<TB, 1> = Initializer::Orthogonal { gain: 1.0 }
+            .init([5], &Default::default())
+            .into_value();
+    }
+
+    #[test]
+    fn initializer_variance_scaling_normal_init() {
+        TB::seed(0);
+
+        let scale = 2.0;
+        let (fan_in, fan_out) = (1000, 10);
+        let expected_var = scale / ((fan_in + fan_out) as f64 / 2.0);
+
+        let tensor: Tensor<TB, 2> = Initializer::VarianceScaling {
+            scale,
+            mode: FanMode::Avg,
+            distribution: VarianceScalingDistribution::Normal,
+        }
+        .init_with(
+            [fan_out, fan_in],
+            Some(fan_in),
+            Some(fan_out),
+            &Default::default(),
+        )
+        .into_value();
+        assert_normal_init(0.0, expected_var, &tensor)
+    }
+
+    #[test]
+    fn initializer_variance_scaling_truncated_normal_init() {
+        TB::seed(0);
+
+        let scale = 3.0;
+        let (fan_in, fan_out) = (1000, 10);
+        let expected_var = scale / fan_in as f64;
+
+        let tensor: Tensor<TB, 2> = Initializer::VarianceScaling {
+            scale,
+            mode: FanMode::In,
+            distribution: VarianceScalingDistribution::TruncatedNormal,
+        }
+        .init_with([fan_out, fan_in], Some(fan_in), None, &Default::default())
+        .into_value();
+
+        let bound = 2.0 * expected_var.sqrt() as f32 / 0.879_625_7;
+        tensor
+            .clone()
+            .into_data()
+            .assert_within_range(-bound..bound);
+        let var_act: f64 = tensor.reshape([-1]).var(0).into_scalar().elem();
+        assert!(
+            (var_act / expected_var - 1.0).abs() < 0.05,
+            "Expected variance to be {expected_var}, but got {var_act}"
+        );
+    }
+
+    #[test]
+    fn initializer_variance_scaling_uniform_init() {
+        TB::seed(0);
+
+        let scale = 2.0;
+        let (fan_in, fan_out) = (5, 6);
+        let bound = (3.0 * scale / fan_out as f64).sqrt();
+
+        let tensor: Tensor<TB, 2> = Initializer::VarianceScaling {
+            scale,
+            mode: FanMode::Out,
+            distribution: VarianceScalingDistribution::Uniform,
+        }
+        .init_with([fan_out, fan_in], None, Some(fan_out), &Default::default())
+        .into_value();
+        tensor.into_data().assert_within_range(-bound..bound);
+    }
+
+    #[test]
+    #[should_panic]
+    fn initializer_variance_scaling_no_fan() {
+        let _: Tensor<TB, 2> = Initializer::VarianceScaling {
+            scale: 1.0,
+            mode: FanMode::Avg,
+            distribution: VarianceScalingDistribution::Normal,
+        }
+        .init_with([5, 6], Some(6), None, &Default::default())
+        .into_value();
+    }
+
     #[test]
     #[should_panic]
     fn initializer_xavier_uniform_no_fan() {
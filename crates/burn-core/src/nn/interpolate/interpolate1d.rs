@@ -32,6 +32,12 @@ pub struct Interpolate1dConfig {
     /// Determines how the output values are calculated.
     #[config(default = "InterpolateMode::Nearest")]
     pub mode: InterpolateMode,
+
+    /// If true, the centers of the corner elements of the input and output are aligned,
+    /// otherwise their outer corners are aligned.
+    /// Only used by the linear and cubic modes.
+    #[config(default = true)]
+    pub align_corners: bool,
 }
 
 /// Interpolate module for resizing 1D tensors with shape [N, C, L].
@@ -57,6 +63,9 @@ pub struct Interpolate1d {
 
     /// Interpolation mode used for resizing
     pub mode: Ignored<InterpolateMode>,
+
+    /// Whether the corner elements of the input and output are aligned
+    pub align_corners: bool,
 }
 
 impl Interpolate1dConfig {
@@ -66,6 +75,7 @@ impl Interpolate1dConfig {
             output_size: self.output_size,
             scale_factor: self.scale_factor,
             mode: Ignored(self.mode),
+            align_corners: self.align_corners,
         }
     }
 }
@@ -102,7 +112,8 @@ impl Interpolate1d {
         let result = interpolate(
             input,
             [1, output_size],
-            InterpolateOptions::new(self.mode.0.clone().into()),
+            InterpolateOptions::new(self.mode.0.clone().into_ops(1))
+                .with_align_corners(self.align_corners),
         );
 
         result.squeeze_dims(&[2])
@@ -163,6 +174,7 @@ impl ModuleDisplay for Interpolate1d {
             .add("mode", &self.mode)
             .add("output_size", &format!("{:?}", self.output_size))
             .add("scale_factor", &self.scale_factor)
+            .add("align_corners", &self.align_corners)
             .optional()
     }
 }
@@ -242,7 +254,7 @@ mod tests {
         assert_eq!(
             alloc::format!("{}", layer),
             "Interpolate1d {mode: Nearest, output_size: Some(20), \
-            scale_factor: None}"
+            scale_factor: None, align_corners: true}"
         );
     }
 }
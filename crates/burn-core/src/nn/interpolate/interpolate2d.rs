@@ -32,6 +32,12 @@ pub struct Interpolate2dConfig {
     /// Determines how the output values are calculated.
     #[config(default = "InterpolateMode::Nearest")]
     pub mode: InterpolateMode,
+
+    /// If true, the centers of the corner elements of the input and output are aligned,
+    /// otherwise their outer corners are aligned.
+    /// Only used by the linear and cubic modes.
+    #[config(default = true)]
+    pub align_corners: bool,
 }
 
 /// Interpolate module for resizing tensors with shape [N, C, H, W].
@@ -58,6 +64,9 @@ pub struct Interpolate2d {
 
     /// Interpolation mode used for resizing
     pub mode: Ignored<InterpolateMode>,
+
+    /// Whether the corner elements of the input and output are aligned
+    pub align_corners: bool,
 }
 
 impl Interpolate2dConfig {
@@ -67,6 +76,7 @@ impl Interpolate2dConfig {
             output_size: self.output_size,
             scale_factor: self.scale_factor,
             mode: Ignored(self.mode),
+            align_corners: self.align_corners,
         }
     }
 }
@@ -97,7 +107,8 @@ impl Interpolate2d {
         interpolate(
             input,
             output_size,
-            InterpolateOptions::new(self.mode.0.clone().into()),
+            InterpolateOptions::new(self.mode.0.clone().into())
+                .with_align_corners(self.align_corners),
         )
     }
 }
@@ -162,6 +173,7 @@ impl ModuleDisplay for Interpolate2d {
             .add("mode", &self.mode)
             .add("output_size", &format!("{:?}", self.output_size))
             .add("scale_factor", &self.scale_factor)
+            .add("align_corners", &self.align_corners)
             .optional()
     }
 }
@@ -245,7 +257,7 @@ mod tests {
         assert_eq!(
             alloc::format!("{}", layer),
             "Interpolate2d {mode: Nearest, output_size: Some([20, 20]), \
-            scale_factor: None}"
+            scale_factor: None, align_corners: true}"
         );
     }
 }
@@ -0,0 +1,268 @@
+use alloc::format;
+
+use burn_tensor::module::interpolate3d;
+
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::ops::InterpolateOptions;
+use crate::tensor::Tensor;
+
+use super::InterpolateMode;
+
+/// Configuration for the 3D interpolation module.
+///
+/// This struct defines the configuration options for the 3D interpolation operation.
+/// It allows specifying the output size, scale factor, and interpolation mode.
+#[derive(Config, Debug)]
+pub struct Interpolate3dConfig {
+    /// Output size of the interpolated tensor.
+    /// If specified, this takes precedence over `scale_factor`.
+    #[config(default = "None")]
+    pub output_size: Option<[usize; 3]>,
+
+    /// Scale factor for resizing the input tensor.
+    /// This is used when `output_size` is not specified.
+    #[config(default = "None")]
+    pub scale_factor: Option<[f32; 3]>,
+
+    /// Interpolation mode to use for resizing.
+    /// Determines how the output values are calculated.
+    #[config(default = "InterpolateMode::Nearest")]
+    pub mode: InterpolateMode,
+
+    /// If true, the centers of the corner elements of the input and output are aligned,
+    /// otherwise their outer corners are aligned.
+    /// Only used by the linear and cubic modes.
+    #[config(default = true)]
+    pub align_corners: bool,
+}
+
+/// Interpolate module for resizing tensors with shape [N, C, D, H, W].
+///
+/// This struct represents an interpolation module that can resize tensors
+/// using various interpolation methods. It provides flexibility in specifying
+/// either an output size or a scale factor for resizing, along with options
+/// for the interpolation mode.
+///
+/// The module can be used to upsample or downsample tensors, preserving the
+/// number of channels and batch size while adjusting the depth, height and width
+/// dimensions.
+///
+/// The module can be created using the [Interpolate3dConfig] struct and the
+/// `init` method, which returns an instance of the [Interpolate3d] struct.
+#[derive(Module, Clone, Debug)]
+#[module(custom_display)]
+pub struct Interpolate3d {
+    /// Output size of the interpolated tensor
+    pub output_size: Option<[usize; 3]>,
+
+    /// Scale factor for resizing the input tensor
+    pub scale_factor: Option<[f32; 3]>,
+
+    /// Interpolation mode used for resizing
+    pub mode: Ignored<InterpolateMode>,
+
+    /// Whether the corner elements of the input and output are aligned
+    pub align_corners: bool,
+}
+
+impl Interpolate3dConfig {
+    /// Initialize the interpolation module
+    pub fn init(self) -> Interpolate3d {
+        Interpolate3d {
+            output_size: self.output_size,
+            scale_factor: self.scale_factor,
+            mode: Ignored(self.mode),
+            align_corners: self.align_corners,
+        }
+    }
+}
+impl Interpolate3d {
+    /// Performs the forward pass of the interpolation module
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Input tensor with shape [N, C, D, H, W]
+    ///
+    /// # Returns
+    ///
+    /// Resized tensor with shape [N, C, D', H', W'], where D', H' and W' are determined by
+    /// the output_size or scale_factor specified in the module configuration
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let input = Tensor::<Backend, 5>::random([1, 3, 16, 64, 64], Distribution::Uniform(0.0, 1.0), &device);
+    /// let interpolate = Interpolate3dConfig::new()
+    ///     .with_output_size(Some([32, 128, 128]))
+    ///     .init();
+    /// let output = interpolate.forward(input);
+    /// assert_eq!(output.dims(), [1, 3, 32, 128, 128]);
+    /// ```
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 5>) -> Tensor<B, 5> {
+        let output_size = calculate_output_size(input.dims(), self.output_size, self.scale_factor);
+        interpolate3d(
+            input,
+            output_size,
+            InterpolateOptions::new(self.mode.0.clone().into_ops(3))
+                .with_align_corners(self.align_corners),
+        )
+    }
+}
+
+/// Calculates the output size for tensor interpolation.
+///
+/// # Arguments
+///
+/// * `input_dims` - The dimensions of the input tensor [N, C, D, H, W].
+/// * `output_size` - Optional desired output size [D', H', W'].
+/// * `scale_factor` - Optional scale factor for depth, height and width [scale_d, scale_h, scale_w].
+///
+/// # Returns
+///
+/// A tuple [D', H', W'] representing the calculated output size.
+///
+/// # Panics
+///
+/// Panics if neither `output_size` nor `scale_factor` is provided,
+/// or if the scale factor results in dimensions exceeding usize::MAX.
+fn calculate_output_size(
+    input_dims: [usize; 5],
+    output_size: Option<[usize; 3]>,
+    scale_factor: Option<[f32; 3]>,
+) -> [usize; 3] {
+    match (output_size, scale_factor) {
+        (Some(output_size), None) => {
+            // Use provided
+            output_size
+        }
+        (None, Some(scale_factor)) => {
+            // Calculate output size based on scale factor
+            let [_, _, d, h, w] = input_dims;
+            let names = ["depth", "height", "width"];
+
+            let mut output_size = [0; 3];
+            for (i, size) in [d, h, w].into_iter().enumerate() {
+                let new_dim = (size as f64) * (scale_factor[i] as f64);
+
+                if new_dim > usize::MAX as f64 {
+                    panic!("Scale factor for {} is too large", names[i]);
+                }
+
+                output_size[i] = new_dim as usize;
+            }
+
+            output_size
+        }
+        _ => panic!("Either output_size or scale_factor must be provided"),
+    }
+}
+
+impl ModuleDisplay for Interpolate3d {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("mode", &self.mode)
+            .add("output_size", &format!("{:?}", self.output_size))
+            .add("scale_factor", &self.scale_factor)
+            .add("align_corners", &self.align_corners)
+            .optional()
+    }
+}
+#[cfg(test)]
+mod tests {
+    use burn_tensor::{Distribution, TensorData};
+
+    use crate::TestBackend;
+
+    use super::*;
+
+    #[test]
+    fn test_calculate_output_size() {
+        let input_dims = [1, 1, 4, 4, 4];
+
+        let output_size = calculate_output_size(input_dims, Some([2, 2, 2]), None);
+        assert_eq!(output_size, [2, 2, 2]);
+
+        let output_size = calculate_output_size(input_dims, None, Some([2.0, 0.5, 1.5]));
+        assert_eq!(output_size, [8, 2, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Either output_size or scale_factor must be provided")]
+    fn test_missing_params() {
+        calculate_output_size([1, 1, 4, 4, 4], None, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Scale factor for depth is too large")]
+    fn test_infinite_depth() {
+        calculate_output_size([1, 1, usize::MAX - 1, 4, 4], None, Some([2.0, 1.0, 1.0]));
+    }
+
+    #[test]
+    fn test_module() {
+        let input = Tensor::<TestBackend, 5>::random(
+            [2, 3, 4, 4, 4],
+            Distribution::Uniform(0.0, 1.0),
+            &Default::default(),
+        );
+
+        // Test with output_size
+        let config = Interpolate3dConfig::new().with_output_size(Some([8, 6, 8]));
+        let interpolate = config.init();
+        let output = interpolate.forward(input.clone());
+        assert_eq!(output.dims(), [2, 3, 8, 6, 8]);
+
+        // Test with scale_factor
+        let config = Interpolate3dConfig::new().with_scale_factor(Some([0.5, 0.5, 0.5]));
+        let interpolate = config.init();
+        let output = interpolate.forward(input.clone());
+        assert_eq!(output.dims(), [2, 3, 2, 2, 2]);
+
+        // Test with different interpolation mode
+        let config = Interpolate3dConfig::new()
+            .with_output_size(Some([6, 6, 6]))
+            .with_mode(InterpolateMode::Linear);
+        let interpolate = config.init();
+        let output = interpolate.forward(input);
+        assert_eq!(output.dims(), [2, 3, 6, 6, 6]);
+    }
+
+    #[test]
+    fn test_trilinear_half_pixel() {
+        let device = Default::default();
+        let input =
+            Tensor::<TestBackend, 5>::from_floats([[[[[0.0, 1.0]], [[2.0, 3.0]]]]], &device);
+
+        let interpolate = Interpolate3dConfig::new()
+            .with_output_size(Some([2, 1, 4]))
+            .with_mode(InterpolateMode::Linear)
+            .with_align_corners(false)
+            .init();
+        let output = interpolate.forward(input);
+
+        let expected = TensorData::from([[[[[0.0, 0.25, 0.75, 1.0]], [[2.0, 2.25, 2.75, 3.0]]]]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn display() {
+        let config = Interpolate3dConfig::new().with_output_size(Some([20, 20, 20]));
+        let layer = config.init();
+
+        assert_eq!(
+            alloc::format!("{}", layer),
+            "Interpolate3d {mode: Nearest, output_size: Some([20, 20, 20]), \
+            scale_factor: None, align_corners: true}"
+        );
+    }
+}
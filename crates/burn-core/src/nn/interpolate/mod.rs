@@ -1,8 +1,10 @@
 mod interpolate1d;
 mod interpolate2d;
+mod interpolate3d;
 
 pub use interpolate1d::*;
 pub use interpolate2d::*;
+pub use interpolate3d::*;
 
 use crate::tensor::ops::InterpolateMode as OpsInterpolateMode;
 
@@ -35,12 +37,24 @@ pub enum InterpolateMode {
     Cubic,
 }
 
-impl From<InterpolateMode> for OpsInterpolateMode {
-    fn from(mode: InterpolateMode) -> Self {
-        match mode {
+impl InterpolateMode {
+    /// Returns the mode of the interpolation operations for the given number of spatial
+    /// dimensions, where the linear mode is the linear, bilinear or trilinear interpolation.
+    pub(crate) fn into_ops(self, num_spatial_dims: usize) -> OpsInterpolateMode {
+        match self {
             InterpolateMode::Nearest => OpsInterpolateMode::Nearest,
-            InterpolateMode::Linear => OpsInterpolateMode::Bilinear,
+            InterpolateMode::Linear => match num_spatial_dims {
+                1 => OpsInterpolateMode::Linear,
+                2 => OpsInterpolateMode::Bilinear,
+                _ => OpsInterpolateMode::Trilinear,
+            },
             InterpolateMode::Cubic => OpsInterpolateMode::Bicubic,
         }
     }
 }
+
+impl From<InterpolateMode> for OpsInterpolateMode {
+    fn from(mode: InterpolateMode) -> Self {
+        mode.into_ops(2)
+    }
+}
@@ -0,0 +1,184 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::{Int, Tensor};
+use crate::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create a [Cosine embedding loss](CosineEmbeddingLoss).
+#[derive(Config, Debug)]
+pub struct CosineEmbeddingLossConfig {
+    /// The margin under which the cosine similarity of the dissimilar pairs isn't penalized.
+    /// It should be in the range `[-1, 1]`.
+    #[config(default = 0.0)]
+    pub margin: f32,
+    /// A small value added to the squared norms of the inputs to avoid a division by zero.
+    #[config(default = 1e-8)]
+    pub eps: f64,
+}
+
+impl CosineEmbeddingLossConfig {
+    /// Initialize [Cosine embedding loss](CosineEmbeddingLoss).
+    pub fn init(&self) -> CosineEmbeddingLoss {
+        self.assertions();
+        CosineEmbeddingLoss {
+            margin: self.margin,
+            eps: self.eps,
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            (-1. ..=1.).contains(&self.margin),
+            "Margin for Cosine embedding loss must be in the range [-1, 1]."
+        );
+        assert!(
+            self.eps > 0.,
+            "eps for Cosine embedding loss must be a positive number."
+        );
+    }
+}
+
+/// Calculate the Cosine embedding loss of pairs of embeddings labeled as similar or dissimilar.
+///
+/// The loss for each pair is given by
+///
+/// ```text
+/// L = 1 - cos(x1, x2)               if y ==  1
+/// L = max(0, cos(x1, x2) - margin)  if y == -1
+/// ```
+///
+/// where `cos` is the cosine similarity along the last dimension.
+#[derive(Module, Debug, Clone)]
+#[module(custom_display)]
+pub struct CosineEmbeddingLoss {
+    /// The margin for the dissimilar pairs.
+    pub margin: f32,
+    /// A small value added to the squared norms of the inputs.
+    pub eps: f64,
+}
+
+impl ModuleDisplay for CosineEmbeddingLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("margin", &self.margin)
+            .add("eps", &self.eps)
+            .optional()
+    }
+}
+
+impl CosineEmbeddingLoss {
+    /// Compute the loss of each pair, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - input1: `[batch_size, embedding_size]`
+    /// - input2: `[batch_size, embedding_size]`
+    /// - targets: `[batch_size]`
+    /// - output: `[1]`
+    pub fn forward<B: Backend>(
+        &self,
+        input1: Tensor<B, 2>,
+        input2: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(input1, input2, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss of each pair.
+    ///
+    /// The targets are `1` for the similar pairs and `-1` for the dissimilar ones.
+    ///
+    /// # Shapes
+    ///
+    /// - input1: `[batch_size, embedding_size]`
+    /// - input2: `[batch_size, embedding_size]`
+    /// - targets: `[batch_size]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction<B: Backend>(
+        &self,
+        input1: Tensor<B, 2>,
+        input2: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, _] = input1.dims();
+
+        let dot = (input1.clone() * input2.clone()).sum_dim(1);
+        let norm1 = input1.powi_scalar(2).sum_dim(1).add_scalar(self.eps);
+        let norm2 = input2.powi_scalar(2).sum_dim(1).add_scalar(self.eps);
+        let cosine = (dot / (norm1 * norm2).sqrt()).reshape([batch_size]);
+
+        let similar = cosine.clone().neg().add_scalar(1.);
+        let dissimilar = cosine.sub_scalar(self.margin).clamp_min(0.);
+
+        dissimilar.mask_where(targets.equal_elem(1), similar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    #[test]
+    fn test_cosine_embedding_loss() {
+        let device = Default::default();
+        let input1 = TestTensor::<2>::from_data(
+            TensorData::from([[1., 0.], [1., 1.], [1., 0.], [0., 2.]]),
+            &device,
+        );
+        let input2 = TestTensor::<2>::from_data(
+            TensorData::from([[1., 1.], [2., 2.], [1., 1.], [1., 0.]]),
+            &device,
+        );
+        let targets = Tensor::<TestBackend, 1, Int>::from_data([1, 1, -1, -1], &device);
+
+        let loss = CosineEmbeddingLossConfig::new().with_margin(0.5).init();
+
+        let loss_no_reduction =
+            loss.forward_no_reduction(input1.clone(), input2.clone(), targets.clone());
+        let loss_mean = loss.forward(
+            input1.clone(),
+            input2.clone(),
+            targets.clone(),
+            Reduction::Auto,
+        );
+        let loss_sum = loss.forward(input1, input2, targets, Reduction::Sum);
+
+        let expected = TensorData::from([0.292893, 0., 0.207107, 0.]);
+        loss_no_reduction.into_data().assert_approx_eq(&expected, 5);
+
+        let expected = TensorData::from([0.125]);
+        loss_mean.into_data().assert_approx_eq(&expected, 5);
+
+        let expected = TensorData::from([0.5]);
+        loss_sum.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn display() {
+        let loss = CosineEmbeddingLossConfig::new().init();
+
+        assert_eq!(
+            alloc::format!("{}", loss),
+            "CosineEmbeddingLoss {margin: 0, eps: 0.00000001}"
+        );
+    }
+}
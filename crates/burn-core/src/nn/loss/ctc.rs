@@ -0,0 +1,281 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::module::ctc_loss;
+use crate::tensor::{Int, Tensor};
+use crate::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create a [CTC loss](CtcLoss).
+#[derive(Config, Debug)]
+pub struct CtcLossConfig {
+    /// The index of the blank class.
+    #[config(default = 0)]
+    pub blank: usize,
+    /// Whether the infinite losses of the impossible alignments, and their gradients, are set to
+    /// zero. This happens when an input sequence is too short for its target.
+    #[config(default = false)]
+    pub zero_infinity: bool,
+}
+
+impl CtcLossConfig {
+    /// Initialize [CTC loss](CtcLoss).
+    pub fn init(&self) -> CtcLoss {
+        CtcLoss {
+            blank: self.blank,
+            zero_infinity: self.zero_infinity,
+        }
+    }
+}
+
+/// The Connectionist Temporal Classification loss, used to train sequence models on unaligned
+/// targets, such as speech recognition and optical character recognition.
+///
+/// The loss is the negative log-likelihood of the target, summed over every alignment of the
+/// target to the input sequence. An alignment is a sequence of classes of the length of the input
+/// that gives the target once the repeated classes are merged and the blanks are removed.
+///
+/// The sum is computed by the [ctc_loss](crate::tensor::module::ctc_loss) operation with the
+/// forward recursion of the forward-backward algorithm, and its gradients with the backward
+/// recursion.
+///
+/// See also: [Connectionist Temporal Classification](https://www.cs.toronto.edu/~graves/icml_2006.pdf)
+#[derive(Module, Debug, Clone)]
+#[module(custom_display)]
+pub struct CtcLoss {
+    /// The index of the blank class.
+    pub blank: usize,
+    /// Whether the infinite losses are set to zero.
+    pub zero_infinity: bool,
+}
+
+impl ModuleDisplay for CtcLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("blank", &self.blank)
+            .add("zero_infinity", &self.zero_infinity)
+            .optional()
+    }
+}
+
+impl CtcLoss {
+    /// Compute the loss of each sample, then reduce to a single loss value.
+    ///
+    /// `Reduction::Mean` divides the loss of each sample by its target length before averaging
+    /// over the batch. `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[batch_size, seq_length, num_classes]`
+    /// - targets: `[batch_size, max_target_length]`
+    /// - input_lengths: `[batch_size]`
+    /// - target_lengths: `[batch_size]`
+    /// - output: `[1]`
+    pub fn forward<B: Backend>(
+        &self,
+        log_probs: Tensor<B, 3>,
+        targets: Tensor<B, 2, Int>,
+        input_lengths: Tensor<B, 1, Int>,
+        target_lengths: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss =
+            self.forward_no_reduction(log_probs, targets, input_lengths, target_lengths.clone());
+
+        match reduction {
+            Reduction::Mean | Reduction::Auto => {
+                (loss / target_lengths.float().clamp_min(1.0)).mean()
+            }
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss of each sample.
+    ///
+    /// The `log_probs` are the log-probabilities of the classes at each step of the input
+    /// sequences, usually given by a [log_softmax](crate::tensor::activation::log_softmax). Only
+    /// the first `input_lengths` steps of each input sequence and the first `target_lengths`
+    /// classes of each target are used, so the sequences can be padded with any value.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[batch_size, seq_length, num_classes]`
+    /// - targets: `[batch_size, max_target_length]`
+    /// - input_lengths: `[batch_size]`
+    /// - target_lengths: `[batch_size]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction<B: Backend>(
+        &self,
+        log_probs: Tensor<B, 3>,
+        targets: Tensor<B, 2, Int>,
+        input_lengths: Tensor<B, 1, Int>,
+        target_lengths: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let loss = ctc_loss(
+            log_probs,
+            targets,
+            input_lengths,
+            target_lengths,
+            self.blank,
+        );
+
+        if self.zero_infinity {
+            let impossible = loss.clone().equal_elem(f32::INFINITY);
+            loss.mask_fill(impossible, 0.0)
+        } else {
+            loss
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+    use core::f32::consts::LN_2;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    fn log_probs<B: Backend>(device: &B::Device) -> Tensor<B, 3> {
+        Tensor::<B, 3>::from_data(
+            TensorData::from([[[0.3, 0.7], [0.6, 0.4]], [[0.5, 0.5], [0.9, 0.1]]]),
+            device,
+        )
+        .log()
+    }
+
+    #[test]
+    fn test_ctc_loss() {
+        let device = Default::default();
+        let log_probs = log_probs::<TestBackend>(&device);
+        // The padding of the second target is out of the range of the classes.
+        let targets = Tensor::<TestBackend, 2, Int>::from_data([[1, 1], [1, 7]], &device);
+        let input_lengths = Tensor::<TestBackend, 1, Int>::from_data([2, 1], &device);
+        let target_lengths = Tensor::<TestBackend, 1, Int>::from_data([1, 1], &device);
+
+        let loss = CtcLossConfig::new().init();
+        let loss_no_reduction = loss.forward_no_reduction(
+            log_probs.clone(),
+            targets.clone(),
+            input_lengths.clone(),
+            target_lengths.clone(),
+        );
+        let loss_sum = loss.forward(
+            log_probs,
+            targets,
+            input_lengths,
+            target_lengths,
+            Reduction::Sum,
+        );
+
+        // -log(0.7 * 0.4 + 0.3 * 0.4 + 0.7 * 0.6) and -log(0.5).
+        let expected = TensorData::from([0.198451, LN_2]);
+        loss_no_reduction.into_data().assert_approx_eq(&expected, 5);
+
+        let expected = TensorData::from([0.891598]);
+        loss_sum.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn test_ctc_loss_mean_divides_by_target_lengths() {
+        let device = Default::default();
+        let log_probs = TestTensor::<3>::from_data(
+            TensorData::from([[[0.3, 0.5, 0.2], [0.6, 0.1, 0.3]]]),
+            &device,
+        )
+        .log();
+        let targets = Tensor::<TestBackend, 2, Int>::from_data([[1, 2]], &device);
+        let input_lengths = Tensor::<TestBackend, 1, Int>::from_data([2], &device);
+        let target_lengths = Tensor::<TestBackend, 1, Int>::from_data([2], &device);
+
+        let loss = CtcLossConfig::new().init().forward(
+            log_probs,
+            targets,
+            input_lengths,
+            target_lengths,
+            Reduction::Mean,
+        );
+
+        // The only alignment is [1, 2]: -log(0.5 * 0.3) / 2.
+        let expected = TensorData::from([0.948560]);
+        loss.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn test_ctc_loss_impossible_alignment() {
+        let device = Default::default();
+        let log_probs = log_probs::<TestBackend>(&device);
+        // Repeated classes need a blank between them, which doesn't fit in two steps.
+        let targets = Tensor::<TestBackend, 2, Int>::from_data([[1, 1], [0, 0]], &device);
+        let input_lengths = Tensor::<TestBackend, 1, Int>::from_data([2, 2], &device);
+        let target_lengths = Tensor::<TestBackend, 1, Int>::from_data([2, 0], &device);
+
+        let loss = CtcLossConfig::new().init().forward_no_reduction(
+            log_probs.clone(),
+            targets.clone(),
+            input_lengths.clone(),
+            target_lengths.clone(),
+        );
+        let loss_zero_infinity = CtcLossConfig::new()
+            .with_zero_infinity(true)
+            .init()
+            .forward_no_reduction(log_probs, targets, input_lengths, target_lengths);
+
+        // The empty target only matches the all-blank alignment: -log(0.5 * 0.9).
+        let expected = TensorData::from([f32::INFINITY, 0.798508]);
+        loss.into_data().assert_approx_eq(&expected, 5);
+
+        let expected = TensorData::from([0.0, 0.798508]);
+        loss_zero_infinity
+            .into_data()
+            .assert_approx_eq(&expected, 5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ctc_ad_loss() {
+        type TestAutodiffBackend = crate::TestAutodiffBackend;
+
+        let device = Default::default();
+        let log_probs = log_probs::<TestAutodiffBackend>(&device)
+            .slice([0..1, 0..2, 0..2])
+            .detach()
+            .require_grad();
+        let targets = Tensor::<TestAutodiffBackend, 2, Int>::from_data([[1]], &device);
+        let input_lengths = Tensor::<TestAutodiffBackend, 1, Int>::from_data([2], &device);
+        let target_lengths = Tensor::<TestAutodiffBackend, 1, Int>::from_data([1], &device);
+
+        let loss = CtcLossConfig::new().init().forward(
+            log_probs.clone(),
+            targets,
+            input_lengths,
+            target_lengths,
+            Reduction::Sum,
+        );
+        let grads = loss.backward();
+        let grads_log_probs = log_probs.grad(&grads).unwrap();
+
+        // The gradients are the opposite of the posterior probabilities of the classes at each
+        // step, e.g. 0.12 / 0.82 for the blank at the first step.
+        let expected = TensorData::from([[[-0.146341, -0.853659], [-0.512195, -0.487805]]]);
+        grads_log_probs.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn display() {
+        let loss = CtcLossConfig::new().init();
+
+        assert_eq!(
+            alloc::format!("{}", loss),
+            "CtcLoss {blank: 0, zero_infinity: false}"
+        );
+    }
+}
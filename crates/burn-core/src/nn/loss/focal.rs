@@ -0,0 +1,209 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::activation::{log_sigmoid, sigmoid};
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use crate::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create a [Focal loss](FocalLoss).
+#[derive(Config, Debug)]
+pub struct FocalLossConfig {
+    /// The focusing parameter, which reduces the loss of the well classified examples.
+    #[config(default = 2.0)]
+    pub gamma: f32,
+    /// The weight of the positive examples, the negative examples being weighted by `1 - alpha`.
+    /// The examples are not weighted when `None`.
+    #[config(default = "Some(0.25)")]
+    pub alpha: Option<f32>,
+}
+
+impl FocalLossConfig {
+    /// Initialize [Focal loss](FocalLoss).
+    pub fn init(&self) -> FocalLoss {
+        self.assertions();
+        FocalLoss {
+            gamma: self.gamma,
+            alpha: self.alpha,
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            self.gamma >= 0.,
+            "Gamma for Focal loss must be a non-negative number."
+        );
+        if let Some(alpha) = self.alpha {
+            assert!(
+                (0. ..=1.).contains(&alpha),
+                "Alpha for Focal loss must be in the range [0, 1]."
+            );
+        }
+    }
+}
+
+/// Calculate the Focal loss between the logits and the binary targets, as used for dense object
+/// detection.
+///
+/// The loss for each element is given by
+///
+/// ```text
+/// L = -a_t * (1 - p_t)^gamma * log(p_t)
+/// ```
+///
+/// where `p = sigmoid(logits)`, `p_t = p` for the positive targets and `1 - p` for the negative
+/// ones, and `a_t` is `alpha` or `1 - alpha` likewise. With a `gamma` of zero and no `alpha`,
+/// this is equal to the [binary cross entropy](super::BinaryCrossEntropyLoss) with logits.
+///
+/// See also: [Focal Loss for Dense Object Detection](https://arxiv.org/abs/1708.02002)
+#[derive(Module, Debug, Clone)]
+#[module(custom_display)]
+pub struct FocalLoss {
+    /// The focusing parameter.
+    pub gamma: f32,
+    /// The weight of the positive examples.
+    pub alpha: Option<f32>,
+}
+
+impl ModuleDisplay for FocalLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("gamma", &self.gamma)
+            .add("alpha", &self.alpha)
+            .optional()
+    }
+}
+
+impl FocalLoss {
+    /// Compute the loss element-wise for the logits and targets, then reduce to a single loss
+    /// value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: \[...dims\]
+    /// - targets: \[...dims\]
+    /// - output: \[1\]
+    pub fn forward<const D: usize, B: Backend>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(logits, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss element-wise for the logits and targets.
+    ///
+    /// The targets are the probabilities of the positive class, usually `0` or `1`.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: \[...dims\]
+    /// - targets: \[...dims\]
+    /// - output: \[...dims\]
+    pub fn forward_no_reduction<const D: usize, B: Backend>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        let negatives = targets.clone().neg().add_scalar(1.);
+
+        // The cross entropy is computed from the log-sigmoid to stay stable for large logits.
+        let cross_entropy = log_sigmoid(logits.clone())
+            .mul(targets.clone())
+            .add(log_sigmoid(logits.clone().neg()).mul(negatives.clone()))
+            .neg();
+
+        let probs = sigmoid(logits);
+        let probs_t = probs.clone().mul(targets.clone()) + probs.neg().add_scalar(1.) * negatives;
+        let loss = probs_t
+            .neg()
+            .add_scalar(1.)
+            .powf_scalar(self.gamma)
+            .mul(cross_entropy);
+
+        match self.alpha {
+            Some(alpha) => {
+                let alpha_t = targets.mul_scalar(2. * alpha - 1.).add_scalar(1. - alpha);
+                loss.mul(alpha_t)
+            }
+            None => loss,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    #[test]
+    fn test_focal_loss() {
+        let device = Default::default();
+        // The logits of the probabilities 0.8, 0.2, 0.5 and 0.9.
+        let logits = TestTensor::<1>::from_data(
+            TensorData::from([1.386294, -1.386294, 0., 2.197225]),
+            &device,
+        );
+        let targets = TestTensor::<1>::from_data(TensorData::from([1., 1., 0., 0.]), &device);
+
+        let focal = FocalLossConfig::new().init();
+
+        let loss_no_reduction = focal.forward_no_reduction(logits.clone(), targets.clone());
+        let loss = focal.forward(logits.clone(), targets.clone(), Reduction::Auto);
+        let loss_sum = focal.forward(logits, targets, Reduction::Sum);
+
+        // 0.25 * 0.2^2 * -log(0.8), 0.25 * 0.8^2 * -log(0.2),
+        // 0.75 * 0.5^2 * -log(0.5) and 0.75 * 0.9^2 * -log(0.1).
+        let expected = TensorData::from([0.002231, 0.257510, 0.129965, 1.398821]);
+        loss_no_reduction.into_data().assert_approx_eq(&expected, 5);
+
+        let expected = TensorData::from([0.447132]);
+        loss.into_data().assert_approx_eq(&expected, 5);
+
+        let expected = TensorData::from([1.788527]);
+        loss_sum.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn test_focal_loss_without_focusing_is_cross_entropy() {
+        let device = Default::default();
+        let logits = TestTensor::<2>::from_data(TensorData::from([[2., -1.], [0.5, 3.]]), &device);
+        let targets = TestTensor::<2>::from_data(TensorData::from([[1., 0.], [0., 1.]]), &device);
+
+        let loss = FocalLossConfig::new()
+            .with_gamma(0.)
+            .with_alpha(None)
+            .init()
+            .forward_no_reduction(logits, targets);
+
+        let expected = TensorData::from([[0.126928, 0.313262], [0.974077, 0.048587]]);
+        loss.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn display() {
+        let loss = FocalLossConfig::new().init();
+
+        assert_eq!(
+            alloc::format!("{}", loss),
+            "FocalLoss {gamma: 2, alpha: 0.25}"
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use alloc::vec;
+
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::activation::log_softmax;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Int, Tensor};
+use crate::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create an [InfoNCE loss](InfoNceLoss).
+#[derive(Config, Debug)]
+pub struct InfoNceLossConfig {
+    /// The temperature dividing the similarities before the softmax. Lower temperatures
+    /// penalize the hard negatives more.
+    #[config(default = 0.1)]
+    pub temperature: f64,
+}
+
+impl InfoNceLossConfig {
+    /// Initialize [InfoNCE loss](InfoNceLoss).
+    pub fn init(&self) -> InfoNceLoss {
+        self.assertions();
+        InfoNceLoss {
+            temperature: self.temperature,
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            self.temperature > 0.,
+            "Temperature for InfoNCE loss must be a positive number."
+        );
+    }
+}
+
+/// Calculate the InfoNCE contrastive loss, which pulls together the embeddings of the positive
+/// pairs while pushing away the other embeddings of the batch.
+///
+/// The similarity of two embeddings is their cosine similarity divided by the temperature, and
+/// the loss of each query is the cross entropy of finding its positive among the candidates:
+///
+/// ```text
+/// L_i = -log(exp(s(q_i, k_i)) / sum_j exp(s(q_i, k_j)))
+/// ```
+///
+/// The [NT-Xent](InfoNceLoss::forward_nt_xent) variant of SimCLR uses both views of each sample as
+/// queries, with the other embeddings of both views as negatives.
+///
+/// See also: [Representation Learning with Contrastive Predictive Coding](https://arxiv.org/abs/1807.03748)
+/// and [A Simple Framework for Contrastive Learning of Visual Representations](https://arxiv.org/abs/2002.05709)
+#[derive(Module, Debug, Clone)]
+#[module(custom_display)]
+pub struct InfoNceLoss {
+    /// The temperature dividing the similarities.
+    pub temperature: f64,
+}
+
+impl ModuleDisplay for InfoNceLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content.add("temperature", &self.temperature).optional()
+    }
+}
+
+impl InfoNceLoss {
+    /// Compute the loss of each query, where the positive of each query is the key of the same
+    /// index and the other keys are its negatives, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - queries: `[batch_size, embedding_size]`
+    /// - keys: `[batch_size, embedding_size]`
+    /// - output: `[1]`
+    pub fn forward<B: Backend>(
+        &self,
+        queries: Tensor<B, 2>,
+        keys: Tensor<B, 2>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        reduce(self.forward_no_reduction(queries, keys), reduction)
+    }
+
+    /// Compute the loss of each query, where the positive of each query is the key of the same
+    /// index and the other keys are its negatives.
+    ///
+    /// # Shapes
+    ///
+    /// - queries: `[batch_size, embedding_size]`
+    /// - keys: `[batch_size, embedding_size]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction<B: Backend>(
+        &self,
+        queries: Tensor<B, 2>,
+        keys: Tensor<B, 2>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, _] = queries.dims();
+        let device = queries.device();
+
+        let logits = normalize(queries)
+            .matmul(normalize(keys).transpose())
+            .div_scalar(self.temperature);
+        let positives = Tensor::<B, 1, Int>::arange(0..batch_size as i64, &device);
+
+        cross_entropy(logits, positives)
+    }
+
+    /// Compute the NT-Xent loss of the two views of each sample, then reduce to a single loss
+    /// value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - views1: `[batch_size, embedding_size]`
+    /// - views2: `[batch_size, embedding_size]`
+    /// - output: `[1]`
+    pub fn forward_nt_xent<B: Backend>(
+        &self,
+        views1: Tensor<B, 2>,
+        views2: Tensor<B, 2>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        reduce(self.forward_nt_xent_no_reduction(views1, views2), reduction)
+    }
+
+    /// Compute the NT-Xent loss of the two views of each sample.
+    ///
+    /// Each embedding is a query whose positive is the other view of the same sample, and whose
+    /// negatives are all the other embeddings of both views. The losses of the first views are
+    /// followed by the losses of the second views.
+    ///
+    /// # Shapes
+    ///
+    /// - views1: `[batch_size, embedding_size]`
+    /// - views2: `[batch_size, embedding_size]`
+    /// - output: `[2 * batch_size]`
+    pub fn forward_nt_xent_no_reduction<B: Backend>(
+        &self,
+        views1: Tensor<B, 2>,
+        views2: Tensor<B, 2>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, _] = views1.dims();
+        let num_embeddings = 2 * batch_size;
+        let device = views1.device();
+
+        let embeddings = normalize(Tensor::cat(vec![views1, views2], 0));
+        let indices = Tensor::<B, 1, Int>::arange(0..num_embeddings as i64, &device);
+
+        // An embedding is never a candidate for itself.
+        let itself = indices
+            .clone()
+            .reshape([num_embeddings, 1])
+            .expand([num_embeddings, num_embeddings])
+            .equal(
+                indices
+                    .clone()
+                    .reshape([1, num_embeddings])
+                    .expand([num_embeddings, num_embeddings]),
+            );
+        let logits = embeddings
+            .clone()
+            .matmul(embeddings.transpose())
+            .div_scalar(self.temperature)
+            .mask_fill(itself, f32::NEG_INFINITY);
+        let positives = indices
+            .add_scalar(batch_size as i64)
+            .remainder_scalar(num_embeddings as i64);
+
+        cross_entropy(logits, positives)
+    }
+}
+
+fn reduce<B: Backend>(loss: Tensor<B, 1>, reduction: Reduction) -> Tensor<B, 1> {
+    match reduction {
+        Reduction::Mean | Reduction::Auto => loss.mean(),
+        Reduction::Sum => loss.sum(),
+    }
+}
+
+/// Scales the embeddings to a unit norm.
+fn normalize<B: Backend>(embeddings: Tensor<B, 2>) -> Tensor<B, 2> {
+    let norm = embeddings.clone().powi_scalar(2).sum_dim(1).sqrt();
+
+    embeddings / norm.clamp_min(1e-8)
+}
+
+/// Computes the cross entropy of each row of logits for the given target indices.
+fn cross_entropy<B: Backend>(logits: Tensor<B, 2>, targets: Tensor<B, 1, Int>) -> Tensor<B, 1> {
+    let [num_rows, _] = logits.dims();
+
+    log_softmax(logits, 1)
+        .gather(1, targets.reshape([num_rows, 1]))
+        .reshape([num_rows])
+        .neg()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    #[test]
+    fn test_info_nce_loss() {
+        let device = Default::default();
+        let queries = TestTensor::<2>::from_data(TensorData::from([[1., 0.], [0., 2.]]), &device);
+        let keys = TestTensor::<2>::from_data(TensorData::from([[3., 0.], [1., 1.]]), &device);
+
+        let loss = InfoNceLossConfig::new().with_temperature(0.5).init();
+
+        let loss_no_reduction = loss.forward_no_reduction(queries.clone(), keys.clone());
+        let loss_mean = loss.forward(queries, keys, Reduction::Auto);
+
+        // The similarities are [[2, sqrt(2)], [0, sqrt(2)]].
+        let expected = TensorData::from([0.442548, 0.217622]);
+        loss_no_reduction.into_data().assert_approx_eq(&expected, 5);
+
+        let expected = TensorData::from([0.330085]);
+        loss_mean.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn test_nt_xent_loss() {
+        let device = Default::default();
+        let views1 = TestTensor::<2>::from_data(TensorData::from([[1., 0.], [0., 1.]]), &device);
+        let views2 = TestTensor::<2>::from_data(TensorData::from([[2., 0.], [0., -1.]]), &device);
+
+        let loss = InfoNceLossConfig::new()
+            .with_temperature(1.)
+            .init()
+            .forward_nt_xent_no_reduction(views1, views2);
+
+        // The views of the first sample are aligned, while the ones of the second sample are
+        // opposite: log(2 + e) - 1 and 1 + log(2 + 1 / e).
+        let expected = TensorData::from([0.551445, 1.861995, 0.551445, 1.861995]);
+        loss.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn display() {
+        let loss = InfoNceLossConfig::new().init();
+
+        assert_eq!(alloc::format!("{}", loss), "InfoNceLoss {temperature: 0.1}");
+    }
+}
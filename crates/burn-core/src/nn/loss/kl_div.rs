@@ -0,0 +1,176 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use crate::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create a [KL divergence loss](KlDivLoss).
+#[derive(Config, Debug)]
+pub struct KlDivLossConfig {
+    /// If `true`, the targets are expected to be log-probabilities instead of probabilities.
+    #[config(default = false)]
+    pub log_target: bool,
+}
+
+impl KlDivLossConfig {
+    /// Initialize [KL divergence loss](KlDivLoss).
+    pub fn init(&self) -> KlDivLoss {
+        KlDivLoss {
+            log_target: self.log_target,
+        }
+    }
+}
+
+/// Calculate the Kullback-Leibler divergence loss between the predictions and the targets.
+///
+/// The predictions are log-probabilities, and the loss for each element is given by
+///
+/// ```text
+/// L = targets * (log(targets) - predictions)
+/// ```
+///
+/// where the elements with a zero target have a zero loss.
+///
+/// The elements are reduced independently of the distributions they belong to. The divergence
+/// of the distributions along the last dimension is obtained with `Reduction::Sum` divided by the
+/// number of distributions.
+///
+/// See also: <https://en.wikipedia.org/wiki/Kullback%E2%80%93Leibler_divergence>
+#[derive(Module, Debug, Clone)]
+#[module(custom_display)]
+pub struct KlDivLoss {
+    /// If `true`, the targets are expected to be log-probabilities.
+    pub log_target: bool,
+}
+
+impl ModuleDisplay for KlDivLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content.add("log_target", &self.log_target).optional()
+    }
+}
+
+impl KlDivLoss {
+    /// Compute the loss element-wise for the predictions and targets, then reduce to a single
+    /// loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: \[...dims\]
+    /// - targets: \[...dims\]
+    /// - output: \[1\]
+    pub fn forward<const D: usize, B: Backend>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(predictions, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss element-wise for the predictions and targets.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: \[...dims\]
+    /// - targets: \[...dims\]
+    /// - output: \[...dims\]
+    pub fn forward_no_reduction<const D: usize, B: Backend>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        if self.log_target {
+            return targets.clone().exp() * (targets - predictions);
+        }
+
+        // The zero targets are replaced by one before the logarithm, which gives a zero loss and
+        // a zero gradient instead of NaN.
+        let is_zero = targets.clone().lower_equal_elem(0.);
+        let log_targets = targets.clone().mask_fill(is_zero, 1.).log();
+
+        targets * (log_targets - predictions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    #[test]
+    fn test_kl_div_loss() {
+        let device = Default::default();
+        let predictions =
+            TestTensor::<2>::from_data(TensorData::from([[0.5, 0.5], [0.25, 0.75]]), &device).log();
+        let targets = TestTensor::<2>::from_data(TensorData::from([[0.2, 0.8], [0., 1.]]), &device);
+
+        let kl_div = KlDivLossConfig::new().init();
+
+        let loss_no_reduction = kl_div.forward_no_reduction(predictions.clone(), targets.clone());
+        let loss = kl_div.forward(predictions.clone(), targets.clone(), Reduction::Auto);
+        let loss_sum = kl_div.forward(predictions.clone(), targets.clone(), Reduction::Sum);
+
+        let expected = TensorData::from([[-0.183258, 0.376003], [0., 0.287682]]);
+        loss_no_reduction.into_data().assert_approx_eq(&expected, 5);
+
+        let expected = TensorData::from([0.120107]);
+        loss.into_data().assert_approx_eq(&expected, 5);
+
+        let expected = TensorData::from([0.480427]);
+        loss_sum.clone().into_data().assert_approx_eq(&expected, 5);
+
+        let loss_log_target = KlDivLossConfig::new().with_log_target(true).init().forward(
+            predictions,
+            targets.add_scalar(1e-12).log(),
+            Reduction::Sum,
+        );
+        loss_log_target
+            .into_data()
+            .assert_approx_eq(&loss_sum.into_data(), 5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_kl_div_ad_loss_with_zero_targets() {
+        type TestAutodiffTensor = Tensor<crate::TestAutodiffBackend, 1>;
+
+        let device = Default::default();
+        let predictions =
+            TestAutodiffTensor::from_data(TensorData::from([-1., -0.5]), &device).require_grad();
+        let targets = TestAutodiffTensor::from_data(TensorData::from([0., 1.]), &device);
+
+        let loss =
+            KlDivLossConfig::new()
+                .init()
+                .forward(predictions.clone(), targets, Reduction::Sum);
+        let grads = loss.backward();
+        let grads_predictions = predictions.grad(&grads).unwrap();
+
+        let expected = TensorData::from([0., -1.]);
+        grads_predictions.to_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn display() {
+        let loss = KlDivLossConfig::new().init();
+
+        assert_eq!(alloc::format!("{}", loss), "KlDivLoss {log_target: false}");
+    }
+}
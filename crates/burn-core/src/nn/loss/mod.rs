@@ -1,13 +1,25 @@
 mod binary_cross_entropy;
+mod cosine_embedding;
 mod cross_entropy;
+mod ctc;
+mod focal;
 mod huber;
+mod info_nce;
+mod kl_div;
 mod mse;
 mod poisson;
 mod reduction;
+mod triplet_margin;
 
 pub use binary_cross_entropy::*;
+pub use cosine_embedding::*;
 pub use cross_entropy::*;
+pub use ctc::*;
+pub use focal::*;
 pub use huber::*;
+pub use info_nce::*;
+pub use kl_div::*;
 pub use mse::*;
 pub use poisson::*;
 pub use reduction::*;
+pub use triplet_margin::*;
@@ -0,0 +1,211 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use crate::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create a [Triplet margin loss](TripletMarginLoss).
+#[derive(Config, Debug)]
+pub struct TripletMarginLossConfig {
+    /// The margin by which the negative must be further from the anchor than the positive.
+    #[config(default = 1.0)]
+    pub margin: f32,
+    /// The order of the norm used as distance.
+    #[config(default = 2.0)]
+    pub p: f64,
+    /// A small value added to the differences of the embeddings to avoid a zero distance.
+    #[config(default = 1e-6)]
+    pub eps: f64,
+    /// If `true`, the distance to the negative is the minimum of its distances to the anchor and
+    /// to the positive.
+    #[config(default = false)]
+    pub swap: bool,
+}
+
+impl TripletMarginLossConfig {
+    /// Initialize [Triplet margin loss](TripletMarginLoss).
+    pub fn init(&self) -> TripletMarginLoss {
+        self.assertions();
+        TripletMarginLoss {
+            margin: self.margin,
+            p: self.p,
+            eps: self.eps,
+            swap: self.swap,
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            self.margin >= 0.,
+            "Margin for Triplet margin loss must be a non-negative number."
+        );
+        assert!(
+            self.p > 0.,
+            "The norm order for Triplet margin loss must be a positive number."
+        );
+    }
+}
+
+/// Calculate the Triplet margin loss between anchors, positives and negatives embeddings.
+///
+/// The loss for each triplet is given by
+///
+/// ```text
+/// L = max(0, d(a, p) - d(a, n) + margin)
+/// ```
+///
+/// where `d(x, y) = ||x - y + eps||_p` is the distance along the last dimension.
+///
+/// See also: [Learning local feature descriptors with triplets](https://bmva-archive.org.uk/bmvc/2016/papers/paper119/index.html)
+#[derive(Module, Debug, Clone)]
+#[module(custom_display)]
+pub struct TripletMarginLoss {
+    /// The margin between the distances.
+    pub margin: f32,
+    /// The order of the norm.
+    pub p: f64,
+    /// A small value added to the differences of the embeddings.
+    pub eps: f64,
+    /// Whether the distance swap is used.
+    pub swap: bool,
+}
+
+impl ModuleDisplay for TripletMarginLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("margin", &self.margin)
+            .add("p", &self.p)
+            .add("eps", &self.eps)
+            .add("swap", &self.swap)
+            .optional()
+    }
+}
+
+impl TripletMarginLoss {
+    /// Compute the loss of each triplet, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - anchors: `[batch_size, embedding_size]`
+    /// - positives: `[batch_size, embedding_size]`
+    /// - negatives: `[batch_size, embedding_size]`
+    /// - output: `[1]`
+    pub fn forward<B: Backend>(
+        &self,
+        anchors: Tensor<B, 2>,
+        positives: Tensor<B, 2>,
+        negatives: Tensor<B, 2>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(anchors, positives, negatives);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss of each triplet.
+    ///
+    /// # Shapes
+    ///
+    /// - anchors: `[batch_size, embedding_size]`
+    /// - positives: `[batch_size, embedding_size]`
+    /// - negatives: `[batch_size, embedding_size]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction<B: Backend>(
+        &self,
+        anchors: Tensor<B, 2>,
+        positives: Tensor<B, 2>,
+        negatives: Tensor<B, 2>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, _] = anchors.dims();
+
+        let positive_distance = self.distance(anchors.clone(), positives.clone());
+        let mut negative_distance = self.distance(anchors, negatives.clone());
+
+        if self.swap {
+            negative_distance = negative_distance.min_pair(self.distance(positives, negatives));
+        }
+
+        (positive_distance - negative_distance)
+            .add_scalar(self.margin)
+            .clamp_min(0.)
+            .reshape([batch_size])
+    }
+
+    fn distance<B: Backend>(&self, lhs: Tensor<B, 2>, rhs: Tensor<B, 2>) -> Tensor<B, 2> {
+        (lhs - rhs)
+            .add_scalar(self.eps)
+            .abs()
+            .powf_scalar(self.p)
+            .sum_dim(1)
+            .powf_scalar(1. / self.p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    #[test]
+    fn test_triplet_margin_loss() {
+        let device = Default::default();
+        let anchors = TestTensor::<2>::from_data(TensorData::from([[0., 0.], [0., 0.]]), &device);
+        let positives = TestTensor::<2>::from_data(TensorData::from([[3., 4.], [1., 0.]]), &device);
+        let negatives = TestTensor::<2>::from_data(TensorData::from([[0., 1.], [2., 0.]]), &device);
+
+        let loss = TripletMarginLossConfig::new().with_eps(0.).init();
+
+        let loss_no_reduction =
+            loss.forward_no_reduction(anchors.clone(), positives.clone(), negatives.clone());
+        let loss_sum = loss.forward(
+            anchors.clone(),
+            positives.clone(),
+            negatives.clone(),
+            Reduction::Sum,
+        );
+
+        // max(0, 5 - 1 + 1) and max(0, 1 - 2 + 1).
+        let expected = TensorData::from([5., 0.]);
+        loss_no_reduction.into_data().assert_approx_eq(&expected, 5);
+
+        let expected = TensorData::from([5.]);
+        loss_sum.into_data().assert_approx_eq(&expected, 5);
+
+        // With the L1 distance, the negative of the second triplet is closer to the positive than
+        // to the anchor: max(0, 7 - 1 + 1) and max(0, 1 - 1 + 1).
+        let loss_swap = TripletMarginLossConfig::new()
+            .with_eps(0.)
+            .with_p(1.)
+            .with_swap(true)
+            .init()
+            .forward_no_reduction(anchors, positives, negatives);
+
+        let expected = TensorData::from([7., 1.]);
+        loss_swap.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn display() {
+        let loss = TripletMarginLossConfig::new().init();
+
+        assert_eq!(
+            alloc::format!("{}", loss),
+            "TripletMarginLoss {margin: 1, p: 2, eps: 0.000001, swap: false}"
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use crate as burn;
+
+use super::Initializer;
+use crate::config::Config;
+use crate::module::{Module, ModuleMapper, ParamId};
+use crate::tensor::backend::Backend;
+use crate::tensor::{ElementConversion, Tensor};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Configuration of the layer-sequential unit-variance (LSUV) initialization, described in
+/// [All you need is a good init](https://arxiv.org/abs/1511.06422).
+///
+/// The weights of each layer are initialized with an orthogonal matrix, then scaled until the
+/// variance of the outputs of the layer on a batch of data matches the target variance, one layer
+/// after the other.
+#[derive(Config, Debug)]
+pub struct LsuvConfig {
+    /// The variance of the outputs of each layer.
+    #[config(default = 1.0)]
+    pub target_variance: f64,
+    /// The maximum difference between the variance of the outputs and the target variance.
+    #[config(default = 0.1)]
+    pub tolerance: f64,
+    /// The maximum number of forward passes used to scale the weights of each layer.
+    #[config(default = 10)]
+    pub max_iterations: usize,
+    /// If the weights are first initialized with an [orthogonal](Initializer::Orthogonal) matrix.
+    #[config(default = true)]
+    pub orthogonal: bool,
+}
+
+impl LsuvConfig {
+    /// Initializes the weights of the given layers of a module.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module to initialize.
+    /// * `weights` - The ids of the weights of each layer, in the order of the forward pass.
+    /// * `forward` - Runs the forward pass of the module on a batch of data, returning the outputs
+    ///   of each layer in the same order as the weights.
+    ///
+    /// # Returns
+    ///
+    /// The module with its weights initialized.
+    pub fn init_module<B, M, F, const D: usize>(
+        &self,
+        mut module: M,
+        weights: &[ParamId],
+        mut forward: F,
+    ) -> M
+    where
+        B: Backend,
+        M: Module<B>,
+        F: FnMut(&M) -> Vec<Tensor<B, D>>,
+    {
+        for (layer, id) in weights.iter().enumerate() {
+            if self.orthogonal {
+                module = module.map(&mut LsuvMapper {
+                    id: *id,
+                    update: LsuvUpdate::Orthogonal,
+                });
+            }
+
+            for _ in 0..self.max_iterations {
+                let output = forward(&module)
+                    .into_iter()
+                    .nth(layer)
+                    .expect("The forward function should return the output of each layer.");
+                let variance: f64 = output.reshape([-1]).var(0).into_scalar().elem();
+                assert!(
+                    variance.is_finite(),
+                    "The variance of the outputs of layer {layer} isn't finite, got {variance}."
+                );
+
+                if (variance - self.target_variance).abs() <= self.tolerance || variance == 0.0 {
+                    break;
+                }
+
+                module = module.map(&mut LsuvMapper {
+                    id: *id,
+                    update: LsuvUpdate::Scale((self.target_variance / variance).sqrt()),
+                });
+            }
+        }
+
+        module
+    }
+}
+
+enum LsuvUpdate {
+    Orthogonal,
+    Scale(f64),
+}
+
+struct LsuvMapper {
+    id: ParamId,
+    update: LsuvUpdate,
+}
+
+impl<B: Backend> ModuleMapper<B> for LsuvMapper {
+    fn map_float<const D: usize>(&mut self, id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        if id != self.id {
+            return tensor;
+        }
+
+        let is_require_grad = tensor.is_require_grad();
+        let tensor = match self.update {
+            LsuvUpdate::Orthogonal => Initializer::Orthogonal { gain: 1.0 }
+                .init(tensor.shape(), &tensor.device())
+                .into_value(),
+            LsuvUpdate::Scale(factor) => tensor.mul_scalar(factor).detach(),
+        };
+
+        tensor.set_require_grad(is_require_grad)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig, Relu};
+    use crate::tensor::Distribution;
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[derive(Module, Debug)]
+    struct Mlp<B: Backend> {
+        linear_1: Linear<B>,
+        linear_2: Linear<B>,
+        activation: Relu,
+    }
+
+    impl<B: Backend> Mlp<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                linear_1: LinearConfig::new(16, 32).init(device),
+                linear_2: LinearConfig::new(32, 8).init(device),
+                activation: Relu::new(),
+            }
+        }
+
+        fn forward(&self, input: Tensor<B, 2>) -> Vec<Tensor<B, 2>> {
+            let output_1 = self.linear_1.forward(input);
+            let output_2 = self
+                .linear_2
+                .forward(self.activation.forward(output_1.clone()));
+
+            vec![output_1, output_2]
+        }
+    }
+
+    fn assert_unit_variance<B: Backend>(outputs: Vec<Tensor<B, 2>>, tolerance: f64) {
+        for output in outputs {
+            let variance: f64 = output.reshape([-1]).var(0).into_scalar().elem();
+            assert!(
+                (variance - 1.0).abs() <= tolerance,
+                "Expected variance to be 1.0 += {tolerance}, but got {variance}"
+            );
+        }
+    }
+
+    #[test]
+    fn lsuv_should_give_unit_variance_outputs() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let mlp = Mlp::<TestBackend>::new(&device);
+        let input = Tensor::random([64, 16], Distribution::Normal(0.0, 3.0), &device);
+        let weights = [mlp.linear_1.weight.id, mlp.linear_2.weight.id];
+
+        let mlp = LsuvConfig::new()
+            .with_tolerance(0.05)
+            .init_module(mlp, &weights, |mlp| mlp.forward(input.clone()));
+
+        assert_unit_variance(mlp.forward(input), 0.05);
+    }
+
+    #[test]
+    fn lsuv_should_scale_weights_without_orthogonal_init() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let mlp = Mlp::<TestAutodiffBackend>::new(&device);
+        let weight_1 = mlp.linear_1.weight.val();
+        let input = Tensor::random([64, 16], Distribution::Normal(0.0, 3.0), &device);
+        let weights = [mlp.linear_1.weight.id];
+
+        let mlp = LsuvConfig::new()
+            .with_orthogonal(false)
+            .with_tolerance(0.05)
+            .init_module(mlp, &weights, |mlp| mlp.forward(input.clone()));
+
+        let ratio = mlp.linear_1.weight.val() / weight_1;
+        let (min, max) = (ratio.clone().min(), ratio.max());
+        min.into_data().assert_approx_eq(&max.into_data(), 3);
+        assert!(mlp.linear_1.weight.is_require_grad());
+        assert_unit_variance(mlp.forward(input)[..1].to_vec(), 0.05);
+    }
+
+    #[test]
+    #[should_panic(expected = "isn't finite")]
+    fn lsuv_should_panic_when_the_variance_is_not_finite() {
+        let device = Default::default();
+        let mlp = Mlp::<TestBackend>::new(&device);
+        let input = Tensor::full([64, 16], f32::NAN, &device);
+        let weights = [mlp.linear_1.weight.id];
+
+        LsuvConfig::new().init_module(mlp, &weights, |mlp| mlp.forward(input.clone()));
+    }
+}
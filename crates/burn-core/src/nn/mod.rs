@@ -10,24 +10,43 @@ pub mod conv;
 /// Loss module
 pub mod loss;
 
+/// Mixture of experts module
+pub mod moe;
+
+/// Parallel module, splitting models between multiple devices
+pub mod parallel;
+
+/// Parametrization module, computing the weight of a module from underlying parameters
+pub mod parametrization;
+
 /// Pooling module
 pub mod pool;
 
+/// State space model module
+pub mod ssm;
+
 /// Transformer module
 pub mod transformer;
 
+/// Vision backbones and building blocks
+pub mod vision;
+
 /// Interpolate module
 pub mod interpolate;
 
 mod dropout;
 mod embedding;
+mod embedding_bag;
+mod fold;
 mod gelu;
 mod hard_sigmoid;
 mod initializer;
 mod leaky_relu;
 mod linear;
+mod lsuv;
 mod norm;
 mod padding;
+mod pixel_shuffle;
 mod pos_encoding;
 mod prelu;
 mod relu;
@@ -40,13 +59,17 @@ mod unfold;
 
 pub use dropout::*;
 pub use embedding::*;
+pub use embedding_bag::*;
+pub use fold::*;
 pub use gelu::*;
 pub use hard_sigmoid::*;
 pub use initializer::*;
 pub use leaky_relu::*;
 pub use linear::*;
+pub use lsuv::*;
 pub use norm::*;
 pub use padding::*;
+pub use pixel_shuffle::*;
 pub use pos_encoding::*;
 pub use prelu::*;
 pub use relu::*;
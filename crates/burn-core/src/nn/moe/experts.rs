@@ -0,0 +1,263 @@
+use crate as burn;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay, Param};
+use crate::nn::transformer::PositionWiseFeedForward;
+use crate::nn::{Gelu, Initializer, Linear, SwiGlu};
+use crate::tensor::module::grouped_matmul;
+use crate::tensor::{backend::Backend, Int, Tensor};
+
+/// An expert of a [mixture of experts](super::MoE), applied to the tokens routed to it.
+pub trait Expert<B: Backend>: Module<B> + ModuleDisplay {
+    /// Applies the forward pass of the expert.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[num_tokens, d_model]`
+    /// - output: `[num_tokens, d_output]`
+    fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2>;
+}
+
+impl<B: Backend> Expert<B> for Linear<B> {
+    fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+        Linear::forward(self, input)
+    }
+}
+
+impl<B: Backend> Expert<B> for SwiGlu<B> {
+    fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+        SwiGlu::forward(self, input)
+    }
+}
+
+impl<B: Backend> Expert<B> for PositionWiseFeedForward<B> {
+    fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+        PositionWiseFeedForward::forward(self, input)
+    }
+}
+
+/// The experts of a [mixture of experts](super::MoE), applied all at once to the tokens
+/// dispatched to each of them.
+///
+/// A list of [experts](Expert) applies each of them in turn, reading the number of tokens of each
+/// expert, while the [grouped feed-forward](GroupedFeedForward) experts use
+/// [grouped matrix multiplications](grouped_matmul) on the device.
+pub trait Experts<B: Backend>: Module<B> + ModuleDisplay {
+    /// The number of experts.
+    fn num_experts(&self) -> usize;
+
+    /// Applies each expert to the tokens dispatched to it.
+    ///
+    /// The tokens are sorted by expert, `offsets` holding the end of the tokens of each expert.
+    /// The tokens after the last expert are dropped, their output being zero.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[num_tokens, d_model]`
+    /// - offsets: `[num_experts]`
+    /// - output: `[num_tokens, d_output]`
+    fn forward(&self, input: Tensor<B, 2>, offsets: Tensor<B, 1, Int>) -> Tensor<B, 2>;
+}
+
+/// A list of arbitrary experts, applied one after the other.
+///
+/// This is the slow generic path: the offsets are read back from the device, synchronizing it on
+/// every forward pass, and each expert runs its own operations. Prefer the
+/// [grouped feed-forward](GroupedFeedForward) experts when the experts are feed-forward layers.
+impl<B: Backend, M: Expert<B>> Experts<B> for Vec<M> {
+    fn num_experts(&self) -> usize {
+        self.len()
+    }
+
+    fn forward(&self, input: Tensor<B, 2>, offsets: Tensor<B, 1, Int>) -> Tensor<B, 2> {
+        let [num_tokens, _] = input.dims();
+        let device = input.device();
+        let offsets = offsets.into_data().convert::<i64>();
+        let mut outputs = Vec::with_capacity(self.len() + 1);
+        let mut start = 0;
+
+        for (expert, end) in self.iter().zip(offsets.iter::<i64>()) {
+            let end = end as usize;
+            if end > start {
+                outputs.push(expert.forward(input.clone().narrow(0, start, end - start)));
+            }
+            start = end;
+        }
+
+        if start < num_tokens {
+            let d_output = match outputs.first() {
+                Some(output) => output.dims()[1],
+                None => self[0].forward(input.clone().narrow(0, 0, 1)).dims()[1],
+            };
+            outputs.push(Tensor::zeros([num_tokens - start, d_output], &device));
+        }
+
+        Tensor::cat(outputs, 0)
+    }
+}
+
+/// Configuration to create [grouped feed-forward](GroupedFeedForward) experts using the
+/// [init function](GroupedFeedForwardConfig::init).
+#[derive(Config, Debug)]
+pub struct GroupedFeedForwardConfig {
+    /// The number of experts.
+    pub num_experts: usize,
+    /// The size of the input and output features.
+    pub d_model: usize,
+    /// The size of the hidden inner features of each expert.
+    pub d_ff: usize,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// Position-wise feed-forward experts with their weights stacked, so all experts are applied with
+/// a single [grouped matrix multiplication](grouped_matmul) per layer instead of one per expert.
+///
+/// Each expert computes `Linear(GELU(Linear(x)))`.
+///
+/// Should be created using [GroupedFeedForwardConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct GroupedFeedForward<B: Backend> {
+    /// The weights of the inner linear layers, of shape `[num_experts, d_model, d_ff]`.
+    pub weight_inner: Param<Tensor<B, 3>>,
+    /// The biases of the inner linear layers, of shape `[num_experts, d_ff]`.
+    pub bias_inner: Param<Tensor<B, 2>>,
+    /// The weights of the outer linear layers, of shape `[num_experts, d_ff, d_model]`.
+    pub weight_outer: Param<Tensor<B, 3>>,
+    /// The biases of the outer linear layers, of shape `[num_experts, d_model]`.
+    pub bias_outer: Param<Tensor<B, 2>>,
+    /// GELU activation function.
+    pub gelu: Gelu,
+}
+
+impl<B: Backend> ModuleDisplay for GroupedFeedForward<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [num_experts, d_model, d_ff] = self.weight_inner.shape().dims();
+
+        content
+            .add("num_experts", &num_experts)
+            .add("d_model", &d_model)
+            .add("d_ff", &d_ff)
+            .optional()
+    }
+}
+
+impl GroupedFeedForwardConfig {
+    /// Initialize new [grouped feed-forward](GroupedFeedForward) experts.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> GroupedFeedForward<B> {
+        let (d_model, d_ff) = (self.d_model, self.d_ff);
+        let init = |shape: [usize; 3], fan_in, fan_out| {
+            self.initializer
+                .init_with(shape, Some(fan_in), Some(fan_out), device)
+        };
+        let init_bias = |shape: [usize; 2], fan_in, fan_out| {
+            self.initializer
+                .init_with(shape, Some(fan_in), Some(fan_out), device)
+        };
+
+        GroupedFeedForward {
+            weight_inner: init([self.num_experts, d_model, d_ff], d_model, d_ff),
+            bias_inner: init_bias([self.num_experts, d_ff], d_model, d_ff),
+            weight_outer: init([self.num_experts, d_ff, d_model], d_ff, d_model),
+            bias_outer: init_bias([self.num_experts, d_model], d_ff, d_model),
+            gelu: Gelu::new(),
+        }
+    }
+}
+
+impl<B: Backend> Experts<B> for GroupedFeedForward<B> {
+    fn num_experts(&self) -> usize {
+        self.weight_inner.dims()[0]
+    }
+
+    fn forward(&self, input: Tensor<B, 2>, offsets: Tensor<B, 1, Int>) -> Tensor<B, 2> {
+        let [num_tokens, _] = input.dims();
+        let device = input.device();
+
+        // The expert of each token, the dropped tokens having no bias.
+        let tokens = Tensor::arange(0..num_tokens as i64, &device);
+        let experts = offsets.clone().searchsorted(tokens, true);
+        let bias = |bias: &Param<Tensor<B, 2>>| {
+            let bias = bias.val();
+            let [_, d_output] = bias.dims();
+            Tensor::cat(vec![bias, Tensor::zeros([1, d_output], &device)], 0)
+                .select(0, experts.clone())
+        };
+
+        let x = grouped_matmul(input, self.weight_inner.val(), offsets.clone())
+            + bias(&self.bias_inner);
+        let x = self.gelu.forward(x);
+
+        grouped_matmul(x, self.weight_outer.val(), offsets) + bias(&self.bias_outer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::{Distribution, TensorData};
+    use crate::TestBackend;
+
+    #[test]
+    fn grouped_feed_forward_should_apply_each_expert_to_its_tokens() {
+        let device = Default::default();
+        let experts = GroupedFeedForwardConfig::new(3, 4, 6).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 2>::random([6, 4], Distribution::Default, &device);
+        // The second expert has no token and the last token is dropped.
+        let offsets = Tensor::<TestBackend, 1, Int>::from_ints([2, 2, 5], &device);
+        let token_experts = [0, 0, 2, 2, 2];
+
+        let output = Experts::forward(&experts, input.clone(), offsets);
+
+        for (token, expert) in token_experts.into_iter().enumerate() {
+            let linear = |weight: &Param<Tensor<TestBackend, 3>>,
+                          bias: &Param<Tensor<TestBackend, 2>>,
+                          x: Tensor<TestBackend, 2>| {
+                let weight = weight.val().narrow(0, expert, 1).squeeze::<2>(0);
+                let bias = bias.val().narrow(0, expert, 1);
+                x.matmul(weight) + bias
+            };
+            let x = input.clone().narrow(0, token, 1);
+            let hidden =
+                experts
+                    .gelu
+                    .forward(linear(&experts.weight_inner, &experts.bias_inner, x));
+            let expected = linear(&experts.weight_outer, &experts.bias_outer, hidden);
+
+            output
+                .clone()
+                .narrow(0, token, 1)
+                .into_data()
+                .assert_approx_eq(&expected.into_data(), 3);
+        }
+
+        output
+            .narrow(0, 5, 1)
+            .into_data()
+            .assert_eq(&TensorData::zeros::<f32, _>([1, 4]), false);
+    }
+
+    #[test]
+    fn display() {
+        let experts =
+            GroupedFeedForwardConfig::new(8, 4, 16).init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", experts),
+            "GroupedFeedForward {num_experts: 8, d_model: 4, d_ff: 16, params: 1184}"
+        );
+    }
+}
@@ -0,0 +1,385 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::nn::Initializer;
+use crate::tensor::{backend::Backend, Int, Tensor};
+
+use super::{
+    Experts, GroupedFeedForward, GroupedFeedForwardConfig, RouterOutput, TopKRouter,
+    TopKRouterConfig,
+};
+
+/// Configuration to create a [mixture of experts](MoE) layer using the [init function](MoEConfig::init).
+#[derive(Config, Debug)]
+pub struct MoEConfig {
+    /// The size of the input and output features.
+    pub d_model: usize,
+    /// The size of the hidden inner features of each expert.
+    pub d_ff: usize,
+    /// The number of experts.
+    pub num_experts: usize,
+    /// The number of experts each token is routed to.
+    #[config(default = 2)]
+    pub top_k: usize,
+    /// The number of tokens each expert can process, relative to an even split of the routed
+    /// tokens between the experts. The tokens routed to an expert over its capacity are dropped.
+    ///
+    /// No token is dropped when `None`.
+    #[config(default = "Some(1.25)")]
+    pub capacity_factor: Option<f64>,
+    /// If the weights of the selected experts are normalized to sum to one.
+    #[config(default = true)]
+    pub normalize_gates: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// Sparse mixture of experts layer, where each token is processed by the `top_k` experts chosen
+/// by a [router](TopKRouter), their outputs being weighted by the router probabilities.
+///
+/// The routed tokens are sorted by expert on the device, so all experts are applied at once with
+/// [grouped experts](GroupedFeedForward). When more tokens than its capacity are routed to an
+/// expert, the first choices of the tokens have priority and the extra tokens skip the expert.
+///
+/// Any module implementing [Expert](super::Expert) can be used as an expert with a list of
+/// experts, see [init_with_experts](MoEConfig::init_with_experts).
+///
+/// Should be created using [MoEConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct MoE<B: Backend, E> {
+    /// The router selecting the experts of each token.
+    pub router: TopKRouter<B>,
+    /// The experts.
+    pub experts: E,
+    /// The capacity of the experts relative to an even split of the tokens.
+    pub capacity_factor: Option<f64>,
+}
+
+/// The output of a [mixture of experts](MoE) layer.
+pub struct MoEOutput<B: Backend> {
+    /// The output tensor, of shape `[batch_size, seq_length, d_output]`.
+    pub output: Tensor<B, 3>,
+    /// The auxiliary loss encouraging the tokens to be evenly split between the experts.
+    ///
+    /// It is `num_experts * sum(f_i * p_i)`, where `f_i` is the fraction of the routed tokens
+    /// sent to the expert `i` and `p_i` its mean router probability, reaching 1 when the routing
+    /// is balanced.
+    pub load_balancing_loss: Tensor<B, 1>,
+    /// The auxiliary loss keeping the router logits small for stability, being the mean of the
+    /// squared log-sum-exp of the logits of each token.
+    pub router_z_loss: Tensor<B, 1>,
+    /// The number of routed tokens dropped because their expert was over capacity.
+    pub num_dropped: Tensor<B, 1, Int>,
+}
+
+impl<B: Backend, E: Module<B> + ModuleDisplay> ModuleDisplay for MoE<B, E> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [_, num_experts] = self.router.gate.weight.shape().dims();
+
+        content
+            .add("num_experts", &num_experts)
+            .add("top_k", &self.router.top_k)
+            .add(
+                "capacity_factor",
+                &alloc::format!("{:?}", self.capacity_factor),
+            )
+            .optional()
+    }
+}
+
+impl MoEConfig {
+    /// Initialize a new [mixture of experts](MoE) layer with
+    /// [grouped feed-forward](GroupedFeedForward) experts.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> MoE<B, GroupedFeedForward<B>> {
+        let experts = GroupedFeedForwardConfig::new(self.num_experts, self.d_model, self.d_ff)
+            .with_initializer(self.initializer.clone())
+            .init(device);
+
+        self.init_with_experts(experts, device)
+    }
+
+    /// Initialize a new [mixture of experts](MoE) layer with the given experts.
+    ///
+    /// The `d_ff` of the configuration is ignored.
+    pub fn init_with_experts<B: Backend, E: Experts<B>>(
+        &self,
+        experts: E,
+        device: &B::Device,
+    ) -> MoE<B, E> {
+        assert_eq!(
+            experts.num_experts(),
+            self.num_experts,
+            "The number of experts should match the configuration."
+        );
+
+        let router = TopKRouterConfig::new(self.d_model, self.num_experts)
+            .with_top_k(self.top_k)
+            .with_normalize_gates(self.normalize_gates)
+            .with_initializer(self.initializer.clone())
+            .init(device);
+
+        MoE {
+            router,
+            experts,
+            capacity_factor: self.capacity_factor,
+        }
+    }
+}
+
+impl<B: Backend, E: Experts<B>> MoE<B, E> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, seq_length, d_model]`
+    /// - output: `[batch_size, seq_length, d_output]`
+    pub fn forward(&self, input: Tensor<B, 3>) -> MoEOutput<B> {
+        let [batch_size, seq_length, d_model] = input.dims();
+        let num_tokens = batch_size * seq_length;
+        let num_experts = self.experts.num_experts();
+        let top_k = self.router.top_k;
+        let device = input.device();
+
+        let tokens = input.reshape([num_tokens, d_model]);
+        let RouterOutput {
+            logits,
+            probs,
+            gates,
+            indices,
+        } = self.router.forward(tokens.clone());
+
+        let capacity = self.capacity(num_tokens);
+        let num_routed = top_k * num_tokens;
+
+        // The routed tokens are ordered by choice then by token, so sorting them by expert then
+        // by routed index gives the first choices the lowest positions in their expert.
+        let experts = indices.swap_dims(0, 1).reshape([num_routed]);
+        let routed = Tensor::<B, 1, Int>::arange(0..num_routed as i64, &device);
+        let expert_ids = Tensor::<B, 1, Int>::arange(0..num_experts as i64, &device);
+
+        let order = sort_by_group(experts.clone(), routed.clone());
+        let sorted_experts = experts.clone().select(0, order.clone());
+        let starts = sorted_experts
+            .clone()
+            .searchsorted(expert_ids.clone(), false);
+        let positions = Tensor::zeros([num_routed], &device).scatter(
+            0,
+            order,
+            routed.clone() - starts.select(0, sorted_experts),
+        );
+        let dropped = positions.greater_equal_elem(capacity as i64);
+
+        // The dropped tokens are moved after the tokens of the last expert.
+        let groups = experts
+            .clone()
+            .mask_fill(dropped.clone(), num_experts as i64);
+        let order = sort_by_group(groups.clone(), routed);
+        let offsets = groups
+            .select(0, order.clone())
+            .searchsorted(expert_ids, true);
+        let token_ids = order.clone().remainder_scalar(num_tokens as i64);
+
+        let output = self
+            .experts
+            .forward(tokens.select(0, token_ids.clone()), offsets);
+        let [_, d_output] = output.dims();
+
+        let weights = gates
+            .swap_dims(0, 1)
+            .reshape([num_routed])
+            .mask_fill(dropped.clone(), 0.0)
+            .select(0, order);
+        let output = Tensor::zeros([num_tokens, d_output], &device)
+            .select_assign(0, token_ids, output * weights.unsqueeze_dim(1))
+            .reshape([batch_size, seq_length, d_output]);
+
+        let fractions = experts
+            .bincount(num_experts)
+            .float()
+            .div_scalar(num_routed as f64)
+            .unsqueeze::<2>();
+        let load_balancing_loss = (fractions * probs.mean_dim(0))
+            .sum()
+            .mul_scalar(num_experts as f64);
+
+        let max = logits.clone().max_dim(1).detach();
+        let log_sum_exp = (logits - max.clone()).exp().sum_dim(1).log() + max;
+        let router_z_loss = log_sum_exp.powf_scalar(2.0).mean();
+
+        MoEOutput {
+            output,
+            load_balancing_loss,
+            router_z_loss,
+            num_dropped: dropped.int().sum(),
+        }
+    }
+
+    /// The number of tokens each expert can process for a number of input tokens.
+    pub fn capacity(&self, num_tokens: usize) -> usize {
+        let num_experts = self.experts.num_experts();
+
+        match self.capacity_factor {
+            Some(factor) => {
+                let capacity =
+                    factor * (num_tokens * self.router.top_k) as f64 / num_experts as f64;
+                (capacity.ceil() as usize).clamp(1, num_tokens)
+            }
+            None => num_tokens,
+        }
+    }
+}
+
+/// The indices sorting the routed tokens by group then by routed index.
+///
+/// The routed indices make the keys unique, so the order doesn't depend on the stability of the
+/// sort.
+fn sort_by_group<B: Backend>(
+    groups: Tensor<B, 1, Int>,
+    routed: Tensor<B, 1, Int>,
+) -> Tensor<B, 1, Int> {
+    let [num_routed] = routed.dims();
+
+    (groups.mul_scalar(num_routed as i64) + routed).argsort(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::tensor::{Distribution, ElementConversion, Shape, TensorData};
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    fn linear_experts(device: &<TestBackend as Backend>::Device) -> Vec<Linear<TestBackend>> {
+        (0..4)
+            .map(|_| LinearConfig::new(6, 6).with_bias(false).init(device))
+            .collect()
+    }
+
+    #[test]
+    fn forward_should_match_the_weighted_sum_of_the_selected_experts() {
+        let device = Default::default();
+        let moe = MoEConfig::new(6, 12, 4)
+            .with_capacity_factor(None)
+            .init_with_experts(linear_experts(&device), &device);
+        let input = Tensor::<TestBackend, 3>::random([2, 3, 6], Distribution::Default, &device);
+
+        let output = moe.forward(input.clone()).output;
+
+        let tokens = input.reshape([6, 6]);
+        let routing = moe.router.forward(tokens.clone());
+        let indices = routing
+            .indices
+            .into_data()
+            .convert::<i64>()
+            .to_vec::<i64>()
+            .unwrap();
+        let gates = routing.gates.into_data().to_vec::<f32>().unwrap();
+        let expected = (0..6)
+            .map(|token| {
+                let x = tokens.clone().narrow(0, token, 1);
+                (0..2)
+                    .map(|choice| {
+                        let expert = &moe.experts[indices[token * 2 + choice] as usize];
+                        expert
+                            .forward(x.clone())
+                            .mul_scalar(gates[token * 2 + choice])
+                    })
+                    .reduce(|a, b| a + b)
+                    .unwrap()
+            })
+            .collect();
+        let expected = Tensor::cat(expected, 0).reshape([2, 3, 6]);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn tokens_over_capacity_should_be_dropped() {
+        let device = Default::default();
+        let moe = MoEConfig::new(6, 12, 4)
+            .with_top_k(1)
+            .with_capacity_factor(Some(0.5))
+            .init_with_experts(linear_experts(&device), &device);
+        let input = Tensor::<TestBackend, 3>::random([1, 8, 6], Distribution::Default, &device);
+
+        assert_eq!(moe.capacity(8), 1);
+
+        let output = moe.forward(input);
+        let num_dropped = output.num_dropped.into_scalar().elem::<i64>() as usize;
+        let zero_rows = output
+            .output
+            .abs()
+            .sum_dim(2)
+            .into_data()
+            .to_vec::<f32>()
+            .unwrap()
+            .into_iter()
+            .filter(|&sum| sum == 0.0)
+            .count();
+
+        // At most one token per expert is processed.
+        assert!(num_dropped >= 4);
+        assert_eq!(zero_rows, num_dropped);
+    }
+
+    #[test]
+    fn uniform_routing_should_have_a_unit_load_balancing_loss() {
+        let device = Default::default();
+        let moe = MoEConfig::new(6, 12, 4)
+            .with_initializer(Initializer::Zeros)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([2, 5, 6], Distribution::Default, &device);
+
+        let output = moe.forward(input);
+
+        output
+            .load_balancing_loss
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0f32]), 3);
+        // The logits are all zeros, so log-sum-exp is `ln(4)`.
+        output
+            .router_z_loss
+            .into_data()
+            .assert_approx_eq(&TensorData::from([4.0f32.ln().powi(2)]), 3);
+    }
+
+    #[test]
+    fn gradients_should_flow_to_the_router_and_experts() {
+        let device = Default::default();
+        let moe = MoEConfig::new(6, 12, 4).init::<TestAutodiffBackend>(&device);
+        let input =
+            Tensor::<TestAutodiffBackend, 3>::random([2, 4, 6], Distribution::Default, &device);
+
+        let output = moe.forward(input);
+        let loss = output.output.powf_scalar(2.0).mean() + output.load_balancing_loss;
+        let grads = loss.backward();
+
+        let router_grad = moe.router.gate.weight.grad(&grads).unwrap();
+        let experts_grad = moe.experts.weight_inner.grad(&grads).unwrap();
+        assert_eq!(router_grad.shape(), Shape::new([6, 4]));
+        assert_eq!(experts_grad.shape(), Shape::new([4, 6, 12]));
+    }
+
+    #[test]
+    fn display() {
+        let moe = MoEConfig::new(6, 12, 4).init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", moe),
+            "MoE {num_experts: 4, top_k: 2, capacity_factor: Some(1.25), params: 672}"
+        );
+    }
+}
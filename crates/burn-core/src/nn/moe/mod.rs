@@ -0,0 +1,7 @@
+mod experts;
+mod layer;
+mod router;
+
+pub use experts::*;
+pub use layer::*;
+pub use router::*;
@@ -0,0 +1,119 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::nn::{Initializer, Linear, LinearConfig};
+use crate::tensor::activation::softmax;
+use crate::tensor::{backend::Backend, Int, Tensor};
+
+/// Configuration to create a [top-k router](TopKRouter) using the [init function](TopKRouterConfig::init).
+#[derive(Config, Debug)]
+pub struct TopKRouterConfig {
+    /// The size of the input features.
+    pub d_model: usize,
+    /// The number of experts.
+    pub num_experts: usize,
+    /// The number of experts each token is routed to.
+    #[config(default = 2)]
+    pub top_k: usize,
+    /// If the weights of the selected experts are normalized to sum to one.
+    #[config(default = true)]
+    pub normalize_gates: bool,
+    /// The type of function used to initialize the gating layer parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// Routes each token to the `top_k` experts with the highest probabilities, given by a linear
+/// gating layer followed by a softmax.
+///
+/// Should be created using [TopKRouterConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct TopKRouter<B: Backend> {
+    /// The gating layer, computing the logits of the experts for each token.
+    pub gate: Linear<B>,
+    /// The number of experts each token is routed to.
+    pub top_k: usize,
+    /// If the weights of the selected experts are normalized to sum to one.
+    pub normalize_gates: bool,
+}
+
+/// The routing of the tokens computed by a [top-k router](TopKRouter).
+pub struct RouterOutput<B: Backend> {
+    /// The logits of the experts, of shape `[num_tokens, num_experts]`.
+    pub logits: Tensor<B, 2>,
+    /// The probabilities of the experts, of shape `[num_tokens, num_experts]`.
+    pub probs: Tensor<B, 2>,
+    /// The weights of the selected experts, of shape `[num_tokens, top_k]`.
+    pub gates: Tensor<B, 2>,
+    /// The indices of the selected experts, of shape `[num_tokens, top_k]`, in decreasing order
+    /// of probability.
+    pub indices: Tensor<B, 2, Int>,
+}
+
+impl<B: Backend> ModuleDisplay for TopKRouter<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [d_model, num_experts] = self.gate.weight.shape().dims();
+
+        content
+            .add("d_model", &d_model)
+            .add("num_experts", &num_experts)
+            .add("top_k", &self.top_k)
+            .add("normalize_gates", &self.normalize_gates)
+            .optional()
+    }
+}
+
+impl TopKRouterConfig {
+    /// Initialize a new [top-k router](TopKRouter).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> TopKRouter<B> {
+        assert!(
+            self.top_k > 0 && self.top_k <= self.num_experts,
+            "The number of selected experts should be between 1 and the number of experts, got {}.",
+            self.top_k
+        );
+
+        TopKRouter {
+            gate: LinearConfig::new(self.d_model, self.num_experts)
+                .with_bias(false)
+                .with_initializer(self.initializer.clone())
+                .init(device),
+            top_k: self.top_k,
+            normalize_gates: self.normalize_gates,
+        }
+    }
+}
+
+impl<B: Backend> TopKRouter<B> {
+    /// Computes the experts selected for each token, with their weights.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[num_tokens, d_model]`
+    pub fn forward(&self, input: Tensor<B, 2>) -> RouterOutput<B> {
+        let logits = self.gate.forward(input);
+        let probs = softmax(logits.clone(), 1);
+        let (gates, indices) = probs.clone().topk_with_indices(self.top_k, 1);
+
+        let gates = match self.normalize_gates {
+            true => gates.clone() / gates.sum_dim(1),
+            false => gates,
+        };
+
+        RouterOutput {
+            logits,
+            probs,
+            gates,
+            indices,
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use crate as burn;
+
+use alloc::vec::Vec;
+
+use super::linear::{first_device, untracked};
+use super::shard_ranges;
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay, Param};
+use crate::nn::{Embedding, EmbeddingConfig, Initializer};
+use crate::tensor::{backend::Backend, Int, Tensor};
+
+/// Configuration to create a [VocabParallelEmbedding](VocabParallelEmbedding) layer using the
+/// [init function](VocabParallelEmbeddingConfig::init).
+#[derive(Config)]
+pub struct VocabParallelEmbeddingConfig {
+    /// The number of embedding vectors.
+    pub n_embedding: usize,
+    /// The size of each vector.
+    pub d_model: usize,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::Normal{mean:0.0, std:1.0}")]
+    pub initializer: Initializer,
+}
+
+/// [Embedding](Embedding) layer with its vocabulary split between multiple devices.
+///
+/// Each device looks up the indices falling in its range of the vocabulary, with zeros for the
+/// other indices, and the partial results are summed on the device of the input.
+///
+/// Should be created with [VocabParallelEmbeddingConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct VocabParallelEmbedding<B: Backend> {
+    /// The embedding of each device, holding a contiguous range of the vocabulary.
+    pub shards: Vec<Embedding<B>>,
+}
+
+impl VocabParallelEmbeddingConfig {
+    /// Initialize a new [vocab parallel embedding](VocabParallelEmbedding) module with one shard
+    /// per device.
+    pub fn init<B: Backend>(&self, devices: &[B::Device]) -> VocabParallelEmbedding<B> {
+        let embedding = EmbeddingConfig::new(self.n_embedding, self.d_model)
+            .with_initializer(self.initializer.clone())
+            .init(first_device::<B>(devices));
+
+        VocabParallelEmbedding::from_embedding(embedding, devices)
+    }
+}
+
+impl<B: Backend> VocabParallelEmbedding<B> {
+    /// Split the vocabulary of an existing [embedding](Embedding) layer between the given devices.
+    pub fn from_embedding(embedding: Embedding<B>, devices: &[B::Device]) -> Self {
+        let [n_embedding, _] = embedding.weight.shape().dims();
+        let weight = untracked(embedding.weight.val());
+
+        let shards = shard_ranges(n_embedding, devices.len())
+            .into_iter()
+            .zip(devices)
+            .map(|(range, device)| {
+                let weight = weight
+                    .clone()
+                    .narrow(0, range.start, range.end - range.start)
+                    .to_device(device);
+
+                Embedding {
+                    weight: Param::from_tensor(weight),
+                    sparse_grad: embedding.sparse_grad,
+                }
+            })
+            .collect();
+
+        Self { shards }
+    }
+
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// The lookups of the shards are issued in turn on the calling thread, so they run in parallel
+    /// only when the backend executes its operations asynchronously.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, seq_length]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward(&self, input: Tensor<B, 2, Int>) -> Tensor<B, 3> {
+        let device = input.device();
+        let mut start = 0;
+        let mut output: Option<Tensor<B, 3>> = None;
+
+        for shard in self.shards.iter() {
+            let [length, _] = shard.weight.shape().dims();
+            let end = start + length;
+            let indices = input.clone().to_device(&shard.weight.device());
+
+            let mask = indices
+                .clone()
+                .greater_equal_elem(start as i64)
+                .bool_and(indices.clone().lower_elem(end as i64));
+            let indices = indices
+                .sub_scalar(start as i64)
+                .mask_fill(mask.clone().bool_not(), 0);
+
+            let partial = shard.forward(indices) * mask.float().unsqueeze_dim(2);
+            let partial = partial.to_device(&device);
+            start = end;
+
+            output = Some(match output {
+                Some(output) => output.add(partial),
+                None => partial,
+            });
+        }
+
+        output.expect("At least one shard.")
+    }
+}
+
+impl<B: Backend> ModuleDisplay for VocabParallelEmbedding<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [_, d_model] = self.shards[0].weight.shape().dims();
+        let n_embedding: usize = self
+            .shards
+            .iter()
+            .map(|shard| shard.weight.shape().dims[0])
+            .sum();
+
+        content
+            .add("n_embedding", &n_embedding)
+            .add("d_model", &d_model)
+            .add("num_shards", &self.shards.len())
+            .optional()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+
+    #[test]
+    fn vocab_parallel_should_match_embedding() {
+        let device = Default::default();
+        let devices = [device, device, device];
+        let embedding = EmbeddingConfig::new(8, 4).init::<TestBackend>(&device);
+        let parallel = VocabParallelEmbedding::from_embedding(embedding.clone(), &devices);
+        let input = Tensor::<TestBackend, 2, Int>::from_data(
+            TensorData::from([[0, 3, 7], [2, 5, 6]]),
+            &device,
+        );
+
+        let expected = embedding.forward(input.clone());
+        let output = parallel.forward(input);
+
+        assert_eq!(parallel.shards.len(), 3);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+}
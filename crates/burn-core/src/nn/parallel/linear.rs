@@ -0,0 +1,333 @@
+use crate as burn;
+
+use alloc::vec::Vec;
+
+use super::shard_ranges;
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay, Param};
+use crate::nn::{Initializer, Linear, LinearConfig};
+use crate::tensor::{backend::Backend, Tensor};
+
+/// Configuration to create a [ColumnParallelLinear](ColumnParallelLinear) layer using the
+/// [init function](ColumnParallelLinearConfig::init).
+#[derive(Config, Debug)]
+pub struct ColumnParallelLinearConfig {
+    /// The size of the input features.
+    pub d_input: usize,
+    /// The size of the output features.
+    pub d_output: usize,
+    /// If a bias should be applied during the linear transformation.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// Configuration to create a [RowParallelLinear](RowParallelLinear) layer using the
+/// [init function](RowParallelLinearConfig::init).
+#[derive(Config, Debug)]
+pub struct RowParallelLinearConfig {
+    /// The size of the input features.
+    pub d_input: usize,
+    /// The size of the output features.
+    pub d_output: usize,
+    /// If a bias should be applied during the linear transformation.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// [Linear](Linear) layer with its weight split by columns between multiple devices.
+///
+/// Each device computes a slice of the output features, which are concatenated on the device of
+/// the input. This is normally followed by a [row parallel linear](RowParallelLinear) layer, as in
+/// the feed-forward blocks of transformers.
+///
+/// Should be created with [ColumnParallelLinearConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct ColumnParallelLinear<B: Backend> {
+    /// The linear layer of each device, computing a slice of the output features.
+    pub shards: Vec<Linear<B>>,
+}
+
+/// [Linear](Linear) layer with its weight split by rows between multiple devices.
+///
+/// Each device multiplies a slice of the input features, and the partial results are summed on
+/// the device of the input. The bias is kept on the first device and added once.
+///
+/// Should be created with [RowParallelLinearConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct RowParallelLinear<B: Backend> {
+    /// The linear layer of each device, without bias, multiplying a slice of the input features.
+    pub shards: Vec<Linear<B>>,
+    /// Vector of size `d_output`.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+}
+
+impl ColumnParallelLinearConfig {
+    /// Initialize a new [column parallel linear](ColumnParallelLinear) module with one shard per
+    /// device.
+    pub fn init<B: Backend>(&self, devices: &[B::Device]) -> ColumnParallelLinear<B> {
+        let linear = LinearConfig::new(self.d_input, self.d_output)
+            .with_bias(self.bias)
+            .with_initializer(self.initializer.clone())
+            .init(first_device::<B>(devices));
+
+        ColumnParallelLinear::from_linear(linear, devices)
+    }
+}
+
+impl RowParallelLinearConfig {
+    /// Initialize a new [row parallel linear](RowParallelLinear) module with one shard per device.
+    pub fn init<B: Backend>(&self, devices: &[B::Device]) -> RowParallelLinear<B> {
+        let linear = LinearConfig::new(self.d_input, self.d_output)
+            .with_bias(self.bias)
+            .with_initializer(self.initializer.clone())
+            .init(first_device::<B>(devices));
+
+        RowParallelLinear::from_linear(linear, devices)
+    }
+}
+
+impl<B: Backend> ColumnParallelLinear<B> {
+    /// Split the weights of an existing [linear](Linear) layer by columns between the given
+    /// devices.
+    pub fn from_linear(linear: Linear<B>, devices: &[B::Device]) -> Self {
+        let [_, d_output] = linear.weight.shape().dims();
+        let weight = untracked(linear.weight.val());
+        let bias = linear.bias.map(|bias| untracked(bias.val()));
+
+        let shards = shard_ranges(d_output, devices.len())
+            .into_iter()
+            .zip(devices)
+            .map(|(range, device)| {
+                let length = range.end - range.start;
+                let weight = weight.clone().narrow(1, range.start, length);
+                let bias = bias.clone().map(|bias| {
+                    Param::from_tensor(bias.narrow(0, range.start, length).to_device(device))
+                });
+
+                Linear {
+                    weight: Param::from_tensor(weight.to_device(device)),
+                    bias,
+                }
+            })
+            .collect();
+
+        Self { shards }
+    }
+
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// The shards are launched one after the other from the calling thread. They only execute
+    /// concurrently on backends with asynchronous execution, such as the GPU backends, while the
+    /// shards of a synchronous backend, like ndarray, run sequentially.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let device = input.device();
+        let outputs = self
+            .shards
+            .iter()
+            .map(|shard| {
+                let input = input.clone().to_device(&shard_device(shard));
+                shard.forward(input).to_device(&device)
+            })
+            .collect();
+
+        Tensor::cat(outputs, D - 1)
+    }
+}
+
+impl<B: Backend> RowParallelLinear<B> {
+    /// Split the weights of an existing [linear](Linear) layer by rows between the given devices.
+    pub fn from_linear(linear: Linear<B>, devices: &[B::Device]) -> Self {
+        let [d_input, _] = linear.weight.shape().dims();
+        let weight = untracked(linear.weight.val());
+        let bias = linear.bias.map(|bias| {
+            Param::from_tensor(untracked(bias.val()).to_device(first_device::<B>(devices)))
+        });
+
+        let shards = shard_ranges(d_input, devices.len())
+            .into_iter()
+            .zip(devices)
+            .map(|(range, device)| {
+                let weight = weight
+                    .clone()
+                    .narrow(0, range.start, range.end - range.start);
+
+                Linear {
+                    weight: Param::from_tensor(weight.to_device(device)),
+                    bias: None,
+                }
+            })
+            .collect();
+
+        Self { shards, bias }
+    }
+
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// Like the [column parallel](ColumnParallelLinear::forward) layer, the shards are launched
+    /// sequentially from the calling thread, overlapping only with asynchronous backends.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let device = input.device();
+        let mut start = 0;
+        let mut output: Option<Tensor<B, D>> = None;
+
+        for shard in self.shards.iter() {
+            let [length, _] = shard.weight.shape().dims();
+            let input = input
+                .clone()
+                .narrow(D - 1, start, length)
+                .to_device(&shard_device(shard));
+            let partial = shard.forward(input).to_device(&device);
+            start += length;
+
+            output = Some(match output {
+                Some(output) => output.add(partial),
+                None => partial,
+            });
+        }
+
+        let output = output.expect("At least one shard.");
+
+        match &self.bias {
+            Some(bias) => output + bias.val().to_device(&device).unsqueeze(),
+            None => output,
+        }
+    }
+}
+
+impl<B: Backend> ModuleDisplay for ColumnParallelLinear<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [d_input, _] = self.shards[0].weight.shape().dims();
+        let d_output: usize = self
+            .shards
+            .iter()
+            .map(|shard| shard.weight.shape().dims[1])
+            .sum();
+
+        content
+            .add("d_input", &d_input)
+            .add("d_output", &d_output)
+            .add("num_shards", &self.shards.len())
+            .optional()
+    }
+}
+
+impl<B: Backend> ModuleDisplay for RowParallelLinear<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [_, d_output] = self.shards[0].weight.shape().dims();
+        let d_input: usize = self
+            .shards
+            .iter()
+            .map(|shard| shard.weight.shape().dims[0])
+            .sum();
+
+        content
+            .add("d_input", &d_input)
+            .add("d_output", &d_output)
+            .add("bias", &self.bias.is_some())
+            .add("num_shards", &self.shards.len())
+            .optional()
+    }
+}
+
+pub(crate) fn first_device<B: Backend>(devices: &[B::Device]) -> &B::Device {
+    devices.first().expect("At least one device is required.")
+}
+
+/// The tensor without gradient tracking, so it can be split into new parameters.
+pub(crate) fn untracked<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    tensor.set_require_grad(false)
+}
+
+fn shard_device<B: Backend>(shard: &Linear<B>) -> B::Device {
+    shard.weight.device()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Distribution;
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[test]
+    fn column_parallel_should_match_linear() {
+        let device = Default::default();
+        let devices = [device, device, device];
+        let linear = LinearConfig::new(4, 5).init::<TestBackend>(&device);
+        let parallel = ColumnParallelLinear::from_linear(linear.clone(), &devices);
+        let input = Tensor::<TestBackend, 3>::random([2, 3, 4], Distribution::Default, &device);
+
+        let expected = linear.forward(input.clone());
+        let output = parallel.forward(input);
+
+        assert_eq!(parallel.shards.len(), 3);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn row_parallel_should_match_linear() {
+        let device = Default::default();
+        let devices = [device, device];
+        let linear = LinearConfig::new(5, 3).init::<TestBackend>(&device);
+        let parallel = RowParallelLinear::from_linear(linear.clone(), &devices);
+        let input = Tensor::<TestBackend, 2>::random([2, 5], Distribution::Default, &device);
+
+        let expected = linear.forward(input.clone());
+        let output = parallel.forward(input);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn parallel_linear_should_have_gradients_on_all_shards() {
+        let device = Default::default();
+        let devices = [device, device];
+        let column = ColumnParallelLinearConfig::new(4, 6).init::<TestAutodiffBackend>(&devices);
+        let row = RowParallelLinearConfig::new(6, 2).init::<TestAutodiffBackend>(&devices);
+        let input = Tensor::<TestAutodiffBackend, 2>::ones([3, 4], &device);
+
+        let grads = row.forward(column.forward(input)).sum().backward();
+
+        for shard in column.shards.iter().chain(row.shards.iter()) {
+            assert!(shard.weight.grad(&grads).is_some());
+        }
+        assert!(row.bias.unwrap().grad(&grads).is_some());
+    }
+}
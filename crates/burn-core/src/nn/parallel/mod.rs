@@ -0,0 +1,35 @@
+mod embedding;
+mod linear;
+#[cfg(feature = "std")]
+mod pipeline;
+
+pub use embedding::*;
+pub use linear::*;
+#[cfg(feature = "std")]
+pub use pipeline::*;
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Split `size` elements into `num_shards` contiguous ranges, the first ones being one element
+/// bigger when the size isn't divisible by the number of shards.
+fn shard_ranges(size: usize, num_shards: usize) -> Vec<Range<usize>> {
+    assert!(num_shards > 0, "At least one device is required.");
+    assert!(
+        size >= num_shards,
+        "Can't split {size} elements into {num_shards} shards."
+    );
+
+    let shard_size = size / num_shards;
+    let remainder = size % num_shards;
+    let mut start = 0;
+
+    (0..num_shards)
+        .map(|i| {
+            let length = shard_size + usize::from(i < remainder);
+            let range = start..start + length;
+            start += length;
+            range
+        })
+        .collect()
+}
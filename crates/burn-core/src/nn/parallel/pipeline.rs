@@ -0,0 +1,443 @@
+use crate as burn;
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use std::sync::mpsc;
+
+use crate::module::{AutodiffModule, Module, ModuleDisplay};
+use crate::nn::Linear;
+use crate::optim::{GradientsAccumulator, GradientsParams};
+use crate::tensor::backend::{AutodiffBackend, Backend};
+use crate::tensor::Tensor;
+
+/// A stage of a [pipeline](Pipeline), transforming the output of the previous stage.
+pub trait PipelineStage<B: Backend, const D: usize>: Module<B> {
+    /// Applies the forward pass of the stage.
+    fn forward(&self, input: Tensor<B, D>) -> Tensor<B, D>;
+}
+
+impl<B: Backend, const D: usize> PipelineStage<B, D> for Linear<B> {
+    fn forward(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        Linear::forward(self, input)
+    }
+}
+
+/// The stages of a [pipeline](Pipeline).
+///
+/// Implemented for vectors of stages of the same type and for tuples of stages of different
+/// types.
+pub trait PipelineStages<B: Backend>: Module<B> {
+    /// The number of stages.
+    fn num_stages(&self) -> usize;
+
+    /// Place each stage on its device.
+    fn fork_stages(self, devices: &[B::Device]) -> Self;
+}
+
+/// The forward pass of the [stages](PipelineStages) of a [pipeline](Pipeline).
+pub trait PipelineForward<B: Backend, const D: usize>: PipelineStages<B> {
+    /// Applies the forward pass of the stage at the given index, moving the input to the device
+    /// of the stage first.
+    fn forward_stage(&self, index: usize, input: Tensor<B, D>) -> Tensor<B, D>;
+}
+
+fn forward_on_device<B, S, const D: usize>(stage: &S, input: Tensor<B, D>) -> Tensor<B, D>
+where
+    B: Backend,
+    S: PipelineStage<B, D>,
+{
+    let input = match stage.devices().first() {
+        Some(device) => input.to_device(device),
+        None => input,
+    };
+
+    stage.forward(input)
+}
+
+fn check_num_devices(num_stages: usize, num_devices: usize) {
+    assert_eq!(
+        num_stages, num_devices,
+        "Each stage of the pipeline should have a device."
+    );
+}
+
+impl<B: Backend, M: Module<B>> PipelineStages<B> for Vec<M> {
+    fn num_stages(&self) -> usize {
+        self.len()
+    }
+
+    fn fork_stages(self, devices: &[B::Device]) -> Self {
+        check_num_devices(self.len(), devices.len());
+
+        self.into_iter()
+            .zip(devices)
+            .map(|(stage, device)| stage.fork(device))
+            .collect()
+    }
+}
+
+impl<B: Backend, M: PipelineStage<B, D>, const D: usize> PipelineForward<B, D> for Vec<M> {
+    fn forward_stage(&self, index: usize, input: Tensor<B, D>) -> Tensor<B, D> {
+        forward_on_device(&self[index], input)
+    }
+}
+
+/// A macro for generating the pipeline implementations of tuples of stages.
+/// For example: `impl_pipeline_tuple!([L0, L1][0, 1])`.
+macro_rules! impl_pipeline_tuple {
+    ([$($l:ident),*][$($i:tt),*]) => {
+        impl<B, $($l,)*> PipelineStages<B> for ($($l,)*)
+        where
+            B: Backend,
+            $($l: Module<B>,)*
+        {
+            fn num_stages(&self) -> usize {
+                [$($i,)*].len()
+            }
+
+            fn fork_stages(self, devices: &[B::Device]) -> Self {
+                check_num_devices(self.num_stages(), devices.len());
+
+                ($(self.$i.fork(&devices[$i]),)*)
+            }
+        }
+
+        impl<B, $($l,)* const D: usize> PipelineForward<B, D> for ($($l,)*)
+        where
+            B: Backend,
+            $($l: PipelineStage<B, D>,)*
+        {
+            fn forward_stage(&self, index: usize, input: Tensor<B, D>) -> Tensor<B, D> {
+                match index {
+                    $($i => forward_on_device(&self.$i, input),)*
+                    _ => panic!("The pipeline has no stage {index}."),
+                }
+            }
+        }
+    };
+}
+
+impl_pipeline_tuple!([L0, L1][0, 1]);
+impl_pipeline_tuple!([L0, L1, L2][0, 1, 2]);
+impl_pipeline_tuple!([L0, L1, L2, L3][0, 1, 2, 3]);
+impl_pipeline_tuple!([L0, L1, L2, L3, L4][0, 1, 2, 3, 4]);
+impl_pipeline_tuple!([L0, L1, L2, L3, L4, L5][0, 1, 2, 3, 4, 5]);
+impl_pipeline_tuple!([L0, L1, L2, L3, L4, L5, L6][0, 1, 2, 3, 4, 5, 6]);
+impl_pipeline_tuple!([L0, L1, L2, L3, L4, L5, L6, L7][0, 1, 2, 3, 4, 5, 6, 7]);
+
+/// The order in which the micro-batches go through the forward and backward passes of a
+/// [pipeline](Pipeline).
+///
+/// With both schedules, each stage runs on its own thread, so a stage works on a micro-batch
+/// while the next stage works on the previous one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PipelineSchedule {
+    /// All micro-batches do their forward pass before a single backward pass.
+    ///
+    /// The activations of all micro-batches are kept in memory until the backward pass.
+    GPipe,
+    /// The backward pass of each micro-batch is executed as soon as its forward pass is done,
+    /// while the stages work on the forward pass of the next micro-batches.
+    ///
+    /// At most one micro-batch per stage is in flight, so only the activations of those
+    /// micro-batches are kept in memory.
+    OneForwardOneBackward,
+}
+
+/// The result of a [pipeline training step](Pipeline::train_step).
+pub struct PipelineOutput<B: AutodiffBackend> {
+    /// The gradients of the whole batch, averaged over the micro-batches.
+    pub grads: GradientsParams,
+    /// The loss of the whole batch, averaged over the micro-batches.
+    pub loss: Tensor<B::InnerBackend, 1>,
+}
+
+/// A sequence of stages, each one possibly on a different device, so a model too big for a
+/// single device can be split between multiple devices.
+///
+/// The stages are either a vector of modules of the same type or a tuple of modules of different
+/// types. The output of each stage is moved to the device of the next stage, the gradients
+/// flowing back across the device boundaries during the backward pass. A batch can be split into
+/// micro-batches with [train_step](Pipeline::train_step), the stages working on different
+/// micro-batches at the same time.
+#[derive(Module, Debug)]
+pub struct Pipeline<B: Backend, M> {
+    stages: M,
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend, M: PipelineStages<B>> Pipeline<B, M> {
+    /// Create a new pipeline, placing each stage on its device.
+    pub fn new(stages: M, devices: &[B::Device]) -> Self {
+        Self {
+            stages: stages.fork_stages(devices),
+            _backend: PhantomData,
+        }
+    }
+
+    /// The stages of the pipeline.
+    pub fn stages(&self) -> &M {
+        &self.stages
+    }
+
+    /// Applies the forward pass of all stages on the input tensor.
+    ///
+    /// The output is on the device of the last stage.
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D>
+    where
+        M: PipelineForward<B, D>,
+    {
+        (0..self.stages.num_stages()).fold(input, |output, index| {
+            self.stages.forward_stage(index, output)
+        })
+    }
+}
+
+impl<B, M> Pipeline<B, M>
+where
+    B: AutodiffBackend,
+    M: PipelineStages<B> + AutodiffModule<B> + ModuleDisplay,
+    M::InnerModule: ModuleDisplay,
+{
+    /// Executes the forward and backward passes of a batch split into micro-batches.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The batch, split along its first dimension.
+    /// * `num_micro_batches` - The number of micro-batches.
+    /// * `schedule` - The order of the forward and backward passes.
+    /// * `loss` - Computes the loss of a micro-batch given its index and the pipeline output.
+    ///
+    /// # Returns
+    ///
+    /// The gradients and the loss averaged over the micro-batches.
+    pub fn train_step<const D: usize, L>(
+        &self,
+        input: Tensor<B, D>,
+        num_micro_batches: usize,
+        schedule: PipelineSchedule,
+        mut loss: L,
+    ) -> PipelineOutput<B>
+    where
+        M: PipelineForward<B, D>,
+        L: FnMut(usize, Tensor<B, D>) -> Tensor<B, 1>,
+    {
+        let mut micro_batches = input.chunk(num_micro_batches, 0).into_iter().enumerate();
+        let count = micro_batches.len();
+        let scale = count as f64;
+        let num_stages = self.stages.num_stages();
+
+        std::thread::scope(|scope| {
+            // Each stage receives the micro-batches from the previous one and sends its outputs
+            // to the next one, the outputs of the last stage being received here.
+            let (sender, mut receiver) = mpsc::channel::<(usize, Tensor<B, D>)>();
+
+            for index in 0..num_stages {
+                let (sender_next, receiver_next) = mpsc::channel();
+                let receiver = core::mem::replace(&mut receiver, receiver_next);
+                let stages = self.stages.clone();
+
+                scope.spawn(move || {
+                    for (micro_batch, input) in receiver {
+                        let output = stages.forward_stage(index, input);
+
+                        if sender_next.send((micro_batch, output)).is_err() {
+                            return;
+                        }
+                    }
+                });
+            }
+
+            let mut micro_batch_loss =
+                |(index, output): (usize, Tensor<B, D>)| loss(index, output).div_scalar(scale);
+            let receive = || {
+                receiver
+                    .recv()
+                    .expect("A stage of the pipeline failed during the step.")
+            };
+
+            // The losses of all micro-batches are on the device of the last stage.
+            match schedule {
+                PipelineSchedule::GPipe => {
+                    for micro_batch in micro_batches {
+                        sender.send(micro_batch).unwrap();
+                    }
+                    drop(sender);
+
+                    let loss = (0..count)
+                        .map(|_| micro_batch_loss(receive()))
+                        .reduce(|a, b| a.add(b))
+                        .expect("At least one micro-batch.");
+
+                    let grads = GradientsParams::from_grads(loss.backward(), self);
+
+                    PipelineOutput {
+                        grads,
+                        loss: loss.inner(),
+                    }
+                }
+                PipelineSchedule::OneForwardOneBackward => {
+                    let mut accumulator = GradientsAccumulator::new();
+                    let mut total: Option<Tensor<B::InnerBackend, 1>> = None;
+                    let mut in_flight = 0;
+
+                    for micro_batch in micro_batches.by_ref().take(num_stages) {
+                        sender.send(micro_batch).unwrap();
+                        in_flight += 1;
+                    }
+
+                    while in_flight > 0 {
+                        let output = receive();
+                        in_flight -= 1;
+
+                        // The next micro-batch enters the pipeline before the backward pass, so
+                        // the stages aren't idle during the backward pass.
+                        if let Some(micro_batch) = micro_batches.next() {
+                            sender.send(micro_batch).unwrap();
+                            in_flight += 1;
+                        }
+
+                        let loss = micro_batch_loss(output);
+                        let grads = GradientsParams::from_grads(loss.backward(), self);
+                        accumulator.accumulate(self, grads);
+
+                        let loss = loss.inner();
+                        total = Some(match total {
+                            Some(total) => total.add(loss),
+                            None => loss,
+                        });
+                    }
+                    drop(sender);
+
+                    PipelineOutput {
+                        grads: accumulator.grads(),
+                        loss: total.expect("At least one micro-batch."),
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{LinearConfig, Relu};
+    use crate::tensor::{Device, Distribution};
+    use burn_router::{duo::MultiDevice, Router};
+
+    /// Two backends on the CPU, so the stages of the pipeline are on different devices.
+    type B = burn_autodiff::Autodiff<Router<(crate::TestBackend, crate::TestBackend)>>;
+    type InnerBackend = <B as AutodiffBackend>::InnerBackend;
+
+    fn devices() -> [Device<B>; 2] {
+        [
+            MultiDevice::B1(Default::default()),
+            MultiDevice::B2(Default::default()),
+        ]
+    }
+
+    #[derive(Module, Debug)]
+    struct ActivatedLinear<B: Backend> {
+        linear: Linear<B>,
+        activation: Relu,
+    }
+
+    impl<B: Backend, const D: usize> PipelineStage<B, D> for ActivatedLinear<B> {
+        fn forward(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+            self.activation.forward(self.linear.forward(input))
+        }
+    }
+
+    fn pipeline() -> Pipeline<B, (ActivatedLinear<B>, Linear<B>)> {
+        let device = Default::default();
+        let stages = (
+            ActivatedLinear {
+                linear: LinearConfig::new(4, 8).init(&device),
+                activation: Relu::new(),
+            },
+            LinearConfig::new(8, 2).init(&device),
+        );
+
+        Pipeline::new(stages, &devices())
+    }
+
+    #[test]
+    fn stages_should_be_on_their_devices() {
+        let [first, second] = devices();
+        let pipeline = pipeline();
+        let input = Tensor::<B, 2>::random([8, 4], Distribution::Default, &first);
+
+        assert_eq!(pipeline.stages().0.devices(), [first]);
+        assert_eq!(
+            pipeline.stages().1.devices(),
+            core::slice::from_ref(&second)
+        );
+        assert_eq!(pipeline.forward(input).device(), second);
+    }
+
+    #[test]
+    fn gradients_should_flow_across_devices() {
+        let [first, second] = devices();
+        let pipeline = pipeline();
+        let input = Tensor::<B, 2>::random([8, 4], Distribution::Default, &first);
+        let loss = |_index, output: Tensor<B, 2>| output.powf_scalar(2.0).mean();
+
+        let mut output = pipeline.train_step(input.clone(), 4, PipelineSchedule::GPipe, loss);
+
+        // The same model on a single device, without micro-batches.
+        let reference = Pipeline::new(pipeline.stages().clone(), &[first.clone(), first]);
+        let reference = reference.train_step(input, 1, PipelineSchedule::GPipe, loss);
+        let mut reference_grads = reference.grads;
+
+        assert_eq!(output.loss.device(), second);
+        output
+            .loss
+            .into_data()
+            .assert_approx_eq(&reference.loss.into_data(), 4);
+
+        let first_weight = pipeline.stages().0.linear.weight.id;
+        let second_weight = pipeline.stages().1.weight.id;
+
+        for id in [first_weight, second_weight] {
+            let grad = output.grads.remove::<InnerBackend, 2>(id).unwrap();
+            let expected = reference_grads.remove::<InnerBackend, 2>(id).unwrap();
+
+            grad.into_data().assert_approx_eq(&expected.into_data(), 4);
+        }
+    }
+
+    #[test]
+    fn schedules_should_compute_the_same_gradients() {
+        let [first, second] = devices();
+        let pipeline = Pipeline::new(
+            vec![
+                LinearConfig::new(4, 4).init(&first),
+                LinearConfig::new(4, 4).init(&first),
+                LinearConfig::new(4, 4).init(&first),
+            ],
+            &[first.clone(), second.clone(), first.clone()],
+        );
+        let input = Tensor::<B, 2>::random([8, 4], Distribution::Default, &first);
+        let loss = |_index, output: Tensor<B, 2>| output.powf_scalar(2.0).mean();
+
+        let gpipe = pipeline.train_step(input.clone(), 4, PipelineSchedule::GPipe, loss);
+        let mut one_f_one_b =
+            pipeline.train_step(input, 4, PipelineSchedule::OneForwardOneBackward, loss);
+
+        gpipe
+            .loss
+            .into_data()
+            .assert_approx_eq(&one_f_one_b.loss.into_data(), 4);
+
+        let mut gpipe_grads = gpipe.grads;
+        for (stage, device) in pipeline.stages().iter().zip([&first, &second, &first]) {
+            let id = stage.weight.id;
+            let expected = gpipe_grads.remove::<InnerBackend, 2>(id).unwrap();
+            let grad = one_f_one_b.grads.remove::<InnerBackend, 2>(id).unwrap();
+
+            assert_eq!(&grad.device(), device);
+            grad.into_data().assert_approx_eq(&expected.into_data(), 4);
+        }
+    }
+}
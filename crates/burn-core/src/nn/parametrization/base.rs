@@ -0,0 +1,228 @@
+use crate as burn;
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::module::{Module, ModuleDisplay, ModuleMapper, ModuleVisitor, Param, ParamId};
+use crate::nn::conv::{Conv1d, Conv2d, Conv3d, ConvTranspose1d, ConvTranspose2d, ConvTranspose3d};
+use crate::nn::Linear;
+use crate::tensor::{backend::Backend, Tensor};
+
+/// A function computing the weight of a [parametrized](Parametrized) module from its underlying
+/// parameters, on each forward pass.
+///
+/// The weight is given as a matrix of shape `[num_outputs, num_inputs]`, where `num_outputs` is
+/// the number of output features of the module and `num_inputs` the product of the other
+/// dimensions of the weight.
+pub trait Parametrization<B: Backend>: Module<B> + ModuleDisplay {
+    /// Computes the weight from the original weight stored in the parametrized module.
+    fn weight(&self, original: Tensor<B, 2>) -> Tensor<B, 2>;
+}
+
+/// A module with a weight that can be [parametrized](Parametrized).
+pub trait Parametrizable<B: Backend>: Module<B> + ModuleDisplay {
+    /// The weight of the module as a matrix of shape `[num_outputs, num_inputs]`.
+    fn weight_matrix(&self) -> Tensor<B, 2>;
+
+    /// Returns a copy of the module with the weight replaced by the given matrix of shape
+    /// `[num_outputs, num_inputs]`.
+    fn with_weight_matrix(&self, matrix: Tensor<B, 2>) -> Self;
+}
+
+/// A module whose weight is computed by a [parametrization](Parametrization) from the weight
+/// stored in the module on each forward pass.
+///
+/// The gradients flow through the parametrization to the original weight and the parameters of
+/// the parametrization.
+#[derive(Module, Debug)]
+pub struct Parametrized<B: Backend, M, P> {
+    /// The module, storing the original weight.
+    pub module: M,
+    /// The parametrization computing the weight used by the module.
+    pub parametrization: P,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend, M: Parametrizable<B>, P: Parametrization<B>> Parametrized<B, M, P> {
+    /// Create a new parametrized module.
+    pub fn new(module: M, parametrization: P) -> Self {
+        Self {
+            module,
+            parametrization,
+            backend: PhantomData,
+        }
+    }
+
+    /// Returns the module with the weight computed by the parametrization.
+    pub fn parametrized_module(&self) -> M {
+        let weight = self.parametrization.weight(self.module.weight_matrix());
+
+        self.module.with_weight_matrix(weight)
+    }
+
+    /// Removes the parametrization, returning the module with the weight it computes.
+    pub fn into_module(self) -> M {
+        let mut trainable = Trainable::default();
+        self.module.visit(&mut trainable);
+
+        self.parametrized_module().map(&mut Detach {
+            trainable: trainable.ids,
+        })
+    }
+}
+
+impl<B: Backend, P: Parametrization<B>> Parametrized<B, Linear<B>, P> {
+    /// Applies the forward pass of the linear module with the parametrized weight.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        self.parametrized_module().forward(input)
+    }
+}
+
+impl<B: Backend> Parametrizable<B> for Linear<B> {
+    fn weight_matrix(&self) -> Tensor<B, 2> {
+        self.weight.val().transpose()
+    }
+
+    fn with_weight_matrix(&self, matrix: Tensor<B, 2>) -> Self {
+        let mut module = self.clone();
+        module.weight = Param::initialized(self.weight.id, matrix.transpose());
+        module
+    }
+}
+
+macro_rules! parametrizable_conv {
+    ($module:ident, $rank:expr, $output_dim:expr) => {
+        impl<B: Backend> Parametrizable<B> for $module<B> {
+            fn weight_matrix(&self) -> Tensor<B, 2> {
+                weight_to_matrix(self.weight.val(), $output_dim)
+            }
+
+            fn with_weight_matrix(&self, matrix: Tensor<B, 2>) -> Self {
+                let weight = matrix_to_weight(matrix, self.weight.dims(), $output_dim);
+                let mut module = self.clone();
+                module.weight = Param::initialized(self.weight.id, weight);
+                module
+            }
+        }
+
+        impl<B: Backend, P: Parametrization<B>> Parametrized<B, $module<B>, P> {
+            /// Applies the forward pass of the convolution with the parametrized weight.
+            pub fn forward(&self, input: Tensor<B, $rank>) -> Tensor<B, $rank> {
+                self.parametrized_module().forward(input)
+            }
+        }
+    };
+}
+
+parametrizable_conv!(Conv1d, 3, 0);
+parametrizable_conv!(Conv2d, 4, 0);
+parametrizable_conv!(Conv3d, 5, 0);
+parametrizable_conv!(ConvTranspose1d, 3, 1);
+parametrizable_conv!(ConvTranspose2d, 4, 1);
+parametrizable_conv!(ConvTranspose3d, 5, 1);
+
+/// Collects the ids of the parameters requiring gradients.
+#[derive(Default)]
+struct Trainable {
+    ids: Vec<ParamId>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for Trainable {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        if tensor.is_require_grad() {
+            self.ids.push(id);
+        }
+    }
+}
+
+/// Detaches the parameters from the graph of the parametrization, keeping the trainable ones
+/// requiring gradients.
+struct Detach {
+    trainable: Vec<ParamId>,
+}
+
+impl<B: Backend> ModuleMapper<B> for Detach {
+    fn map_float<const D: usize>(&mut self, id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        tensor
+            .detach()
+            .set_require_grad(self.trainable.contains(&id))
+    }
+}
+
+/// Reshapes the weight to a matrix of shape `[num_outputs, num_inputs]`.
+fn weight_to_matrix<B: Backend, const D: usize>(
+    weight: Tensor<B, D>,
+    output_dim: usize,
+) -> Tensor<B, 2> {
+    weight.swap_dims(0, output_dim).flatten(1, D - 1)
+}
+
+/// Reshapes the matrix of shape `[num_outputs, num_inputs]` back to the weight shape.
+fn matrix_to_weight<B: Backend, const D: usize>(
+    matrix: Tensor<B, 2>,
+    mut shape: [usize; D],
+    output_dim: usize,
+) -> Tensor<B, D> {
+    shape.swap(0, output_dim);
+
+    matrix.reshape(shape).swap_dims(0, output_dim)
+}
+
+/// Normalizes each column of the matrix to a unit norm.
+pub(crate) fn normalize_columns<B: Backend>(matrix: Tensor<B, 2>, epsilon: f64) -> Tensor<B, 2> {
+    let norm = matrix
+        .clone()
+        .powf_scalar(2.0)
+        .sum_dim(0)
+        .sqrt()
+        .clamp_min(epsilon);
+
+    matrix / norm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::parametrization::WeightNormConfig;
+    use crate::nn::LinearConfig;
+    use crate::tensor::Distribution;
+    use crate::TestAutodiffBackend;
+
+    #[test]
+    fn parametrized_should_propagate_gradients_to_all_parameters() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 3).init::<TestAutodiffBackend>(&device);
+        let linear = WeightNormConfig::new().init(linear);
+        let input =
+            Tensor::<TestAutodiffBackend, 2>::random([2, 4], Distribution::Default, &device);
+
+        let grads = linear.forward(input).sum().backward();
+
+        assert!(linear.module.weight.grad(&grads).is_some());
+        assert!(linear.module.bias.as_ref().unwrap().grad(&grads).is_some());
+        assert!(linear.parametrization.magnitude.grad(&grads).is_some());
+    }
+
+    #[test]
+    fn into_module_should_keep_the_parametrized_weight() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 3).init::<TestAutodiffBackend>(&device);
+        let mut linear = WeightNormConfig::new().init(linear);
+        linear.parametrization.magnitude = Param::from_data([1.0, 2.0, 3.0], &device);
+        let expected = linear.parametrized_module().weight.val();
+
+        let module = linear.into_module();
+
+        module
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+        assert!(module.weight.is_require_grad());
+    }
+}
@@ -0,0 +1,9 @@
+mod base;
+mod orthogonal;
+mod spectral_norm;
+mod weight_norm;
+
+pub use base::*;
+pub use orthogonal::*;
+pub use spectral_norm::*;
+pub use weight_norm::*;
@@ -0,0 +1,143 @@
+use crate as burn;
+
+use alloc::vec;
+
+use crate::module::Module;
+use crate::tensor::{backend::Backend, ElementConversion, Tensor};
+
+use super::Parametrization;
+
+/// Orthogonal parametrization, constraining the weight to have orthonormal rows or columns,
+/// whichever are fewer.
+///
+/// The original weight of the module is an unconstrained parameter: the strictly lower triangular
+/// part of its tall orientation `X`, padded to a square matrix, defines the skew-symmetric matrix
+/// `A = tril(X) - tril(X)^T`, and the weight is made of the first columns of the orthogonal matrix
+/// `exp(A)`.
+///
+/// Can be applied to a module with [Parametrized::new](super::Parametrized::new).
+#[derive(Module, Clone, Debug, Default)]
+pub struct Orthogonal;
+
+impl Orthogonal {
+    /// Create the parametrization.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<B: Backend> Parametrization<B> for Orthogonal {
+    fn weight(&self, original: Tensor<B, 2>) -> Tensor<B, 2> {
+        let [num_outputs, num_inputs] = original.dims();
+        let transposed = num_outputs < num_inputs;
+        let matrix = match transposed {
+            true => original.transpose(),
+            false => original,
+        };
+
+        let [rows, columns] = matrix.dims();
+        let square = match columns < rows {
+            true => {
+                let padding = Tensor::zeros([rows, rows - columns], &matrix.device());
+                Tensor::cat(vec![matrix, padding], 1)
+            }
+            false => matrix,
+        };
+
+        let lower = square.tril(-1);
+        let skew = lower.clone() - lower.transpose();
+        let orthogonal = matrix_exp(skew).narrow(1, 0, columns);
+
+        match transposed {
+            true => orthogonal.transpose(),
+            false => orthogonal,
+        }
+    }
+}
+
+/// The number of terms of the Taylor series of the matrix exponential.
+const TAYLOR_TERMS: usize = 12;
+
+/// Computes the matrix exponential with the scaling and squaring method: the Taylor series is
+/// evaluated on the matrix scaled to a norm lower than one, and the result is squared back.
+fn matrix_exp<B: Backend>(matrix: Tensor<B, 2>) -> Tensor<B, 2> {
+    let [size, _] = matrix.dims();
+    let device = matrix.device();
+
+    let norm = matrix
+        .clone()
+        .detach()
+        .powf_scalar(2.0)
+        .sum()
+        .sqrt()
+        .into_scalar()
+        .elem::<f64>();
+    let squarings = match norm > 1.0 {
+        true => norm.log2().ceil() as usize,
+        false => 0,
+    };
+    let matrix = matrix.div_scalar(2.0f64.powi(squarings as i32));
+
+    let identity = Tensor::eye(size, &device);
+    let mut term = identity.clone();
+    let mut result = identity;
+
+    for i in 1..=TAYLOR_TERMS {
+        term = term.matmul(matrix.clone()).div_scalar(i as f64);
+        result = result + term.clone();
+    }
+
+    for _ in 0..squarings {
+        result = result.clone().matmul(result);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::parametrization::Parametrized;
+    use crate::nn::LinearConfig;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+
+    #[test]
+    fn orthogonal_should_have_orthonormal_columns() {
+        let device = Default::default();
+        let linear = LinearConfig::new(3, 5).init::<TestBackend>(&device);
+        let linear = Parametrized::new(linear, Orthogonal::new());
+
+        // The weight of shape `[3, 5]` has orthonormal rows.
+        let weight = linear.parametrized_module().weight.val();
+        let gram = weight.clone().matmul(weight.transpose());
+
+        gram.into_data()
+            .assert_approx_eq(&Tensor::<TestBackend, 2>::eye(3, &device).into_data(), 3);
+    }
+
+    #[test]
+    fn orthogonal_should_use_the_exponential_of_the_skew_symmetric_matrix() {
+        let device = Default::default();
+        // The skew-symmetric matrix `[[0, -t], [t, 0]]` has the rotation of angle `t` as exponential.
+        let angle = 2.5;
+        let weight = Tensor::<TestBackend, 2>::from_data([[0.0, 0.0], [angle, 0.0]], &device);
+
+        let rotation = Parametrization::<TestBackend>::weight(&Orthogonal::new(), weight);
+
+        rotation.into_data().assert_approx_eq(
+            &TensorData::from([
+                [f64::cos(angle), -f64::sin(angle)],
+                [f64::sin(angle), f64::cos(angle)],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn display() {
+        let layer = Orthogonal::new();
+
+        assert_eq!(alloc::format!("{}", layer), "Orthogonal");
+    }
+}
@@ -0,0 +1,175 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay, RunningState};
+use crate::tensor::{backend::Backend, Distribution, Tensor};
+
+use super::{normalize_columns, Parametrizable, Parametrization, Parametrized};
+
+/// Configuration to apply [spectral normalization](SpectralNorm) to a module using the
+/// [init function](SpectralNormConfig::init).
+#[derive(Config, Debug)]
+pub struct SpectralNormConfig {
+    /// The number of power iterations performed on each forward pass during training.
+    #[config(default = 1)]
+    pub n_power_iterations: usize,
+    /// A value required for numerical stability.
+    #[config(default = 1e-12)]
+    pub epsilon: f64,
+}
+
+/// Spectral normalization from the paper
+/// [Spectral Normalization for Generative Adversarial Networks](https://arxiv.org/abs/1802.05957).
+///
+/// The weight is divided by its largest singular value `sigma`, estimated with the power
+/// iteration method:
+///
+/// `W = V / sigma(V)`
+///
+/// The left singular vector estimate `u` is kept as a running state, refined by the power
+/// iterations on each forward pass when autodiff is enabled, and used as is otherwise.
+///
+/// Should be created using [SpectralNormConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct SpectralNorm<B: Backend> {
+    /// The estimate of the left singular vector, of shape `[num_outputs]`.
+    pub u: RunningState<Tensor<B, 1>>,
+    /// The number of power iterations performed on each forward pass during training.
+    pub n_power_iterations: usize,
+    /// A value required for numerical stability.
+    pub epsilon: f64,
+}
+
+impl<B: Backend> ModuleDisplay for SpectralNorm<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("n_power_iterations", &self.n_power_iterations)
+            .add("epsilon", &self.epsilon)
+            .optional()
+    }
+}
+
+impl SpectralNormConfig {
+    /// Apply spectral normalization to the module.
+    pub fn init<B: Backend, M: Parametrizable<B>>(
+        &self,
+        module: M,
+    ) -> Parametrized<B, M, SpectralNorm<B>> {
+        let weight = module.weight_matrix();
+        let [num_outputs, _] = weight.dims();
+        let u = Tensor::random(
+            [num_outputs, 1],
+            Distribution::Normal(0.0, 1.0),
+            &weight.device(),
+        );
+
+        let spectral_norm = SpectralNorm {
+            u: RunningState::new(normalize_columns(u, self.epsilon).squeeze(1)),
+            n_power_iterations: self.n_power_iterations,
+            epsilon: self.epsilon,
+        };
+
+        Parametrized::new(module, spectral_norm)
+    }
+}
+
+impl<B: Backend> Parametrization<B> for SpectralNorm<B> {
+    fn weight(&self, original: Tensor<B, 2>) -> Tensor<B, 2> {
+        let device = original.device();
+        let matrix = original.clone().detach();
+
+        let (u, v) = match B::ad_enabled() {
+            true => {
+                let mut u = self.u.value_sync().to_device(&device).unsqueeze_dim(1);
+                let mut v = self.right_vector(&matrix, u.clone());
+
+                for _ in 0..self.n_power_iterations {
+                    u = normalize_columns(matrix.clone().matmul(v), self.epsilon);
+                    v = self.right_vector(&matrix, u.clone());
+                }
+
+                self.u.update(u.clone().squeeze(1).detach());
+                (u, v)
+            }
+            false => {
+                let u = self.u.value().to_device(&device).unsqueeze_dim(1);
+                let v = self.right_vector(&matrix, u.clone());
+                (u, v)
+            }
+        };
+
+        let sigma = u.transpose().matmul(original.clone()).matmul(v);
+
+        original / sigma
+    }
+}
+
+impl<B: Backend> SpectralNorm<B> {
+    /// Computes the estimate of the right singular vector from the left one.
+    fn right_vector(&self, matrix: &Tensor<B, 2>, u: Tensor<B, 2>) -> Tensor<B, 2> {
+        normalize_columns(matrix.clone().transpose().matmul(u), self.epsilon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::LinearConfig;
+    use crate::tensor::TensorData;
+    use crate::TestAutodiffBackend;
+
+    #[test]
+    fn spectral_norm_should_converge_to_the_largest_singular_value() {
+        let device = Default::default();
+        let mut linear = LinearConfig::new(2, 3).init::<TestAutodiffBackend>(&device);
+        // The weight `[[3, 0, 0], [0, 1, 0]]` has singular values 3 and 1.
+        linear.weight =
+            crate::module::Param::from_data([[3.0, 0.0, 0.0], [0.0, 1.0, 0.0]], &device);
+        let linear = SpectralNormConfig::new()
+            .with_n_power_iterations(10)
+            .init(linear);
+
+        let weight = linear.parametrized_module().weight.val();
+
+        weight.into_data().assert_approx_eq(
+            &TensorData::from([[1.0, 0.0, 0.0], [0.0, 1.0 / 3.0, 0.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn spectral_norm_should_update_the_running_vector_during_training() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 3).init::<TestAutodiffBackend>(&device);
+        let linear = SpectralNormConfig::new().init(linear);
+        let u = linear.parametrization.u.value_sync();
+
+        let _ = linear.parametrized_module();
+        let u_updated = linear.parametrization.u.value_sync();
+
+        assert_ne!(u.into_data(), u_updated.clone().into_data());
+        u_updated
+            .powf_scalar(2.0)
+            .sum()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0]), 3);
+    }
+
+    #[test]
+    fn display() {
+        let linear = LinearConfig::new(4, 3).init::<TestAutodiffBackend>(&Default::default());
+        let spectral_norm = SpectralNormConfig::new().init(linear).parametrization;
+
+        assert_eq!(
+            alloc::format!("{}", spectral_norm),
+            "SpectralNorm {n_power_iterations: 1, epsilon: 0.000000000001, params: 3}"
+        );
+    }
+}
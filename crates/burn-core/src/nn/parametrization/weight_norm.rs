@@ -0,0 +1,138 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay, Param};
+use crate::tensor::{backend::Backend, Tensor};
+
+use super::{Parametrizable, Parametrization, Parametrized};
+
+/// Configuration to apply [weight normalization](WeightNorm) to a module using the
+/// [init function](WeightNormConfig::init).
+#[derive(Config, Debug)]
+pub struct WeightNormConfig {
+    /// A value required for numerical stability.
+    #[config(default = 1e-12)]
+    pub epsilon: f64,
+}
+
+/// Weight normalization from the paper
+/// [Weight Normalization: A Simple Reparameterization to Accelerate Training of Deep Neural Networks](https://arxiv.org/abs/1602.07868).
+///
+/// The weight of each output feature is decoupled into a direction, the original weight of the
+/// module, and a learned magnitude `g`:
+///
+/// `W = g * V / ||V||`
+///
+/// Should be created using [WeightNormConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct WeightNorm<B: Backend> {
+    /// The magnitude of the weight of each output feature, of shape `[num_outputs]`.
+    pub magnitude: Param<Tensor<B, 1>>,
+    /// A value required for numerical stability.
+    pub epsilon: f64,
+}
+
+impl<B: Backend> ModuleDisplay for WeightNorm<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [num_outputs] = self.magnitude.shape().dims();
+
+        content
+            .add("num_outputs", &num_outputs)
+            .add("epsilon", &self.epsilon)
+            .optional()
+    }
+}
+
+impl WeightNormConfig {
+    /// Apply weight normalization to the module.
+    ///
+    /// The magnitudes are initialized to the norms of the current weight, so the parametrized
+    /// module computes the same output as the original one.
+    pub fn init<B: Backend, M: Parametrizable<B>>(
+        &self,
+        module: M,
+    ) -> Parametrized<B, M, WeightNorm<B>> {
+        let magnitude = row_norms(module.weight_matrix().detach());
+        let weight_norm = WeightNorm {
+            magnitude: Param::from_tensor(magnitude),
+            epsilon: self.epsilon,
+        };
+
+        Parametrized::new(module, weight_norm)
+    }
+}
+
+impl<B: Backend> Parametrization<B> for WeightNorm<B> {
+    fn weight(&self, original: Tensor<B, 2>) -> Tensor<B, 2> {
+        let norms = row_norms(original.clone()).clamp_min(self.epsilon);
+        let scale = self.magnitude.val() / norms;
+
+        original * scale.unsqueeze_dim(1)
+    }
+}
+
+fn row_norms<B: Backend>(matrix: Tensor<B, 2>) -> Tensor<B, 1> {
+    matrix.powf_scalar(2.0).sum_dim(1).sqrt().squeeze(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::conv::Conv2dConfig;
+    use crate::nn::LinearConfig;
+    use crate::tensor::{Distribution, TensorData};
+    use crate::TestBackend;
+
+    #[test]
+    fn weight_norm_should_preserve_the_output_at_initialization() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 3).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 2>::random([2, 4], Distribution::Default, &device);
+        let expected = linear.forward(input.clone());
+
+        let linear = WeightNormConfig::new().init(linear);
+
+        linear
+            .forward(input)
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn weight_norm_should_scale_each_output_filter_to_its_magnitude() {
+        let device = Default::default();
+        let conv = Conv2dConfig::new([2, 3], [3, 3]).init::<TestBackend>(&device);
+        let mut conv = WeightNormConfig::new().init(conv);
+        conv.parametrization.magnitude = Param::from_data([1.0, 2.0, 3.0], &device);
+
+        let weight = conv.parametrized_module().weight.val();
+        let norms = weight
+            .flatten::<2>(1, 3)
+            .powf_scalar(2.0)
+            .sum_dim(1)
+            .sqrt()
+            .squeeze::<1>(1);
+
+        norms
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0, 2.0, 3.0]), 3);
+    }
+
+    #[test]
+    fn display() {
+        let linear = LinearConfig::new(4, 3).init::<TestBackend>(&Default::default());
+        let weight_norm = WeightNormConfig::new().init(linear).parametrization;
+
+        assert_eq!(
+            alloc::format!("{}", weight_norm),
+            "WeightNorm {num_outputs: 3, epsilon: 0.000000000001, params: 3}"
+        );
+    }
+}
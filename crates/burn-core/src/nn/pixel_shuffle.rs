@@ -0,0 +1,162 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+
+use burn_tensor::backend::Backend;
+use burn_tensor::module::{pixel_shuffle, pixel_unshuffle};
+use burn_tensor::Tensor;
+
+/// Configuration to create a [pixel shuffle](PixelShuffle) layer using the
+/// [init function](PixelShuffleConfig::init).
+#[derive(Config, Debug)]
+pub struct PixelShuffleConfig {
+    /// The factor by which the spatial dimensions are increased.
+    pub upscale_factor: usize,
+}
+
+/// Configuration to create a [pixel unshuffle](PixelUnshuffle) layer using the
+/// [init function](PixelUnshuffleConfig::init).
+#[derive(Config, Debug)]
+pub struct PixelUnshuffleConfig {
+    /// The factor by which the spatial dimensions are reduced.
+    pub downscale_factor: usize,
+}
+
+/// Rearranges the channels into blocks of pixels, as used by the sub-pixel convolution from the
+/// paper [Real-Time Single Image and Video Super-Resolution Using an Efficient Sub-Pixel Convolutional Neural Network](https://arxiv.org/abs/1609.05158).
+///
+/// This is the `DepthToSpace` operation of ONNX in the `CRD` mode.
+///
+/// Should be created with [PixelShuffleConfig].
+#[derive(Module, Clone, Debug)]
+#[module(custom_display)]
+pub struct PixelShuffle {
+    /// The factor by which the spatial dimensions are increased.
+    pub upscale_factor: usize,
+}
+
+/// Rearranges the blocks of pixels into the channels, the inverse of the
+/// [pixel shuffle](PixelShuffle).
+///
+/// Should be created with [PixelUnshuffleConfig].
+#[derive(Module, Clone, Debug)]
+#[module(custom_display)]
+pub struct PixelUnshuffle {
+    /// The factor by which the spatial dimensions are reduced.
+    pub downscale_factor: usize,
+}
+
+impl ModuleDisplay for PixelShuffle {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("upscale_factor", &self.upscale_factor)
+            .optional()
+    }
+}
+
+impl ModuleDisplay for PixelUnshuffle {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("downscale_factor", &self.downscale_factor)
+            .optional()
+    }
+}
+
+impl PixelShuffleConfig {
+    /// Initializes a new [PixelShuffle] module.
+    pub fn init(&self) -> PixelShuffle {
+        PixelShuffle {
+            upscale_factor: self.upscale_factor,
+        }
+    }
+}
+
+impl PixelUnshuffleConfig {
+    /// Initializes a new [PixelUnshuffle] module.
+    pub fn init(&self) -> PixelUnshuffle {
+        PixelUnshuffle {
+            downscale_factor: self.downscale_factor,
+        }
+    }
+}
+
+impl PixelShuffle {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [pixel_shuffle](crate::tensor::module::pixel_shuffle) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// input:   `[batch_size, channels * upscale_factor^2, height, width]`
+    /// returns: `[batch_size, channels, height * upscale_factor, width * upscale_factor]`
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        pixel_shuffle(input, self.upscale_factor)
+    }
+}
+
+impl PixelUnshuffle {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [pixel_unshuffle](crate::tensor::module::pixel_unshuffle) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// input:   `[batch_size, channels, height * downscale_factor, width * downscale_factor]`
+    /// returns: `[batch_size, channels * downscale_factor^2, height, width]`
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        pixel_unshuffle(input, self.downscale_factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn pixel_shuffle_shape() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 4>::random([2, 12, 4, 5], Distribution::Default, &device);
+
+        let output = PixelShuffleConfig::new(2).init().forward(input);
+
+        assert_eq!(output.dims(), [2, 3, 8, 10]);
+    }
+
+    #[test]
+    fn pixel_unshuffle_should_invert_pixel_shuffle() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 4>::random([2, 3, 6, 9], Distribution::Default, &device);
+
+        let unshuffled = PixelUnshuffleConfig::new(3).init().forward(input.clone());
+        let output = PixelShuffleConfig::new(3).init().forward(unshuffled);
+
+        output.into_data().assert_eq(&input.into_data(), true);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            alloc::format!("{}", PixelShuffleConfig::new(2).init()),
+            "PixelShuffle {upscale_factor: 2}"
+        );
+        assert_eq!(
+            alloc::format!("{}", PixelUnshuffleConfig::new(2).init()),
+            "PixelUnshuffle {downscale_factor: 2}"
+        );
+    }
+}
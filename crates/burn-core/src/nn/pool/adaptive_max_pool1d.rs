@@ -0,0 +1,95 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::{Int, Tensor};
+
+use crate::tensor::module::{adaptive_max_pool1d, adaptive_max_pool1d_with_indices};
+
+/// Configuration to create a [1D adaptive max pooling](AdaptiveMaxPool1d) layer using the [init function](AdaptiveMaxPool1dConfig::init).
+#[derive(Config)]
+pub struct AdaptiveMaxPool1dConfig {
+    /// The size of the output.
+    pub output_size: usize,
+}
+
+/// Applies a 1D adaptive max pooling over input tensors.
+///
+/// Should be created with [AdaptiveMaxPool1dConfig].
+#[derive(Module, Clone, Debug)]
+#[module(custom_display)]
+pub struct AdaptiveMaxPool1d {
+    /// The size of the output.
+    pub output_size: usize,
+}
+
+impl ModuleDisplay for AdaptiveMaxPool1d {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content.add("output_size", &self.output_size).optional()
+    }
+}
+
+impl AdaptiveMaxPool1dConfig {
+    /// Initialize a new [adaptive max pool 1d](AdaptiveMaxPool1d) module.
+    pub fn init(&self) -> AdaptiveMaxPool1d {
+        AdaptiveMaxPool1d {
+            output_size: self.output_size,
+        }
+    }
+}
+
+impl AdaptiveMaxPool1d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [adaptive_max_pool1d](crate::tensor::module::adaptive_max_pool1d) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, length]`
+    /// - output: `[batch_size, channels, length_out]`
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        adaptive_max_pool1d(input, self.output_size)
+    }
+
+    /// Applies the forward pass on the input tensor, also returning the indices of the maximums
+    /// in the flattened spatial dimensions of the input.
+    ///
+    /// See [adaptive_max_pool1d_with_indices](crate::tensor::module::adaptive_max_pool1d_with_indices)
+    /// for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, length]`
+    /// - output: `[batch_size, channels, length_out]`
+    /// - indices: `[batch_size, channels, length_out]`
+    pub fn forward_with_indices<B: Backend>(
+        &self,
+        input: Tensor<B, 3>,
+    ) -> (Tensor<B, 3>, Tensor<B, 3, Int>) {
+        adaptive_max_pool1d_with_indices(input, self.output_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let config = AdaptiveMaxPool1dConfig::new(3);
+        let layer = config.init();
+
+        assert_eq!(
+            alloc::format!("{}", layer),
+            "AdaptiveMaxPool1d {output_size: 3}"
+        );
+    }
+}
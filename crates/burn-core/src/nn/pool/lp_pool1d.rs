@@ -0,0 +1,123 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+use crate::tensor::module::avg_pool1d;
+
+/// Configuration to create a [1D power-average pooling](LpPool1d) layer using the [init function](LpPool1dConfig::init).
+#[derive(Config, Debug)]
+pub struct LpPool1dConfig {
+    /// The order `p` of the norm computed over each window.
+    pub norm_type: f64,
+    /// The size of the kernel.
+    pub kernel_size: usize,
+    /// The stride.
+    #[config(default = "1")]
+    pub stride: usize,
+}
+
+/// Applies a 1D power-average pooling over input tensors.
+///
+/// Each output is the `p`-norm of its window, `(sum(x^p))^(1/p)`, which is the sum pooling when
+/// `p` is one and tends to the max pooling as `p` grows.
+///
+/// Should be created with [LpPool1dConfig](LpPool1dConfig).
+#[derive(Module, Clone, Debug)]
+#[module(custom_display)]
+pub struct LpPool1d {
+    /// The order of the norm.
+    pub norm_type: f64,
+    /// The size of the kernel.
+    pub kernel_size: usize,
+    /// The stride.
+    pub stride: usize,
+}
+
+impl ModuleDisplay for LpPool1d {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("norm_type", &self.norm_type)
+            .add("kernel_size", &self.kernel_size)
+            .add("stride", &self.stride)
+            .optional()
+    }
+}
+
+impl LpPool1dConfig {
+    /// Initialize a new [lp pool 1d](LpPool1d) module.
+    pub fn init(&self) -> LpPool1d {
+        assert!(
+            self.norm_type > 0.,
+            "The norm type of LpPool1d must be a positive number."
+        );
+        LpPool1d {
+            norm_type: self.norm_type,
+            kernel_size: self.kernel_size,
+            stride: self.stride,
+        }
+    }
+}
+
+impl LpPool1d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, length_in]`
+    /// - output: `[batch_size, channels, length_out]`
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let output = avg_pool1d(
+            input.powf_scalar(self.norm_type),
+            self.kernel_size,
+            self.stride,
+            0,
+            true,
+        );
+
+        output
+            .mul_scalar(self.kernel_size as f64)
+            .powf_scalar(1. / self.norm_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+
+    #[test]
+    fn forward() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 3>::from_floats([[[1., 2., 3., 4., 0.]]], &device);
+
+        let output = LpPool1dConfig::new(2., 2)
+            .with_stride(2)
+            .init()
+            .forward(input);
+
+        let expected = TensorData::from([[[5f32.sqrt(), 5.]]]);
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn display() {
+        let config = LpPool1dConfig::new(2., 3);
+        let layer = config.init();
+
+        assert_eq!(
+            alloc::format!("{}", layer),
+            "LpPool1d {norm_type: 2, kernel_size: 3, stride: 1}"
+        );
+    }
+}
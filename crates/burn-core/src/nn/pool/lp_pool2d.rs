@@ -0,0 +1,133 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+use crate::tensor::module::avg_pool2d;
+
+/// Configuration to create a [2D power-average pooling](LpPool2d) layer using the [init function](LpPool2dConfig::init).
+#[derive(Config, Debug)]
+pub struct LpPool2dConfig {
+    /// The order `p` of the norm computed over each window.
+    pub norm_type: f64,
+    /// The size of the kernel.
+    pub kernel_size: [usize; 2],
+    /// The strides.
+    #[config(default = "[1, 1]")]
+    pub strides: [usize; 2],
+}
+
+/// Applies a 2D power-average pooling over input tensors.
+///
+/// Each output is the `p`-norm of its window, `(sum(x^p))^(1/p)`, which is the sum pooling when
+/// `p` is one and tends to the max pooling as `p` grows.
+///
+/// Should be created with [LpPool2dConfig](LpPool2dConfig).
+#[derive(Module, Clone, Debug)]
+#[module(custom_display)]
+pub struct LpPool2d {
+    /// The order of the norm.
+    pub norm_type: f64,
+    /// The size of the kernel.
+    pub kernel_size: [usize; 2],
+    /// The strides.
+    pub stride: [usize; 2],
+}
+
+impl ModuleDisplay for LpPool2d {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("norm_type", &self.norm_type)
+            .add("kernel_size", &alloc::format!("{:?}", &self.kernel_size))
+            .add("stride", &alloc::format!("{:?}", &self.stride))
+            .optional()
+    }
+}
+
+impl LpPool2dConfig {
+    /// Initialize a new [lp pool 2d](LpPool2d) module.
+    pub fn init(&self) -> LpPool2d {
+        assert!(
+            self.norm_type > 0.,
+            "The norm type of LpPool2d must be a positive number."
+        );
+        LpPool2d {
+            norm_type: self.norm_type,
+            kernel_size: self.kernel_size,
+            stride: self.strides,
+        }
+    }
+}
+
+impl LpPool2d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, height_in, width_in]`
+    /// - output: `[batch_size, channels, height_out, width_out]`
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [kernel_height, kernel_width] = self.kernel_size;
+        let output = avg_pool2d(
+            input.powf_scalar(self.norm_type),
+            self.kernel_size,
+            self.stride,
+            [0, 0],
+            true,
+        );
+
+        output
+            .mul_scalar((kernel_height * kernel_width) as f64)
+            .powf_scalar(1. / self.norm_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+
+    #[test]
+    fn forward() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 4>::from_floats(
+            [[[[1., 2., 1., 1.], [2., 4., 1., 1.], [0., 0., 0., 0.]]]],
+            &device,
+        );
+
+        let output = LpPool2dConfig::new(1., [2, 2])
+            .with_strides([1, 2])
+            .init()
+            .forward(input.clone());
+        let expected = TensorData::from([[[[9., 4.], [6., 2.]]]]);
+        output.into_data().assert_approx_eq(&expected, 5);
+
+        let output = LpPool2dConfig::new(2., [2, 2])
+            .with_strides([2, 2])
+            .init()
+            .forward(input);
+        let expected = TensorData::from([[[[5., 2.]]]]);
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn display() {
+        let config = LpPool2dConfig::new(2., [3, 3]);
+        let layer = config.init();
+
+        assert_eq!(
+            alloc::format!("{}", layer),
+            "LpPool2d {norm_type: 2, kernel_size: [3, 3], stride: [1, 1]}"
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay, Param};
+use crate::nn::conv::{Conv1d, Conv1dConfig};
+use crate::nn::{Initializer, Linear, LinearConfig, PaddingConfig1d};
+use crate::tensor::activation::{silu, softplus};
+use crate::tensor::module::linear_recurrence;
+use crate::tensor::{backend::Backend, Distribution, Tensor};
+
+use super::state_matrix_log_init;
+
+/// Configuration to create a [Mamba](Mamba) block using the [init function](MambaConfig::init).
+#[derive(Config, Debug)]
+pub struct MambaConfig {
+    /// The size of the input and output features.
+    pub d_model: usize,
+    /// The size of the state of each inner feature.
+    #[config(default = 16)]
+    pub d_state: usize,
+    /// The kernel size of the causal depthwise convolution.
+    #[config(default = 4)]
+    pub d_conv: usize,
+    /// The expansion factor of the inner features relative to `d_model`.
+    #[config(default = 2)]
+    pub expand: usize,
+    /// The rank of the discretization step projection, `ceil(d_model / 16)` if not provided.
+    #[config(default = "None")]
+    pub dt_rank: Option<usize>,
+    /// The minimum value of the initial discretization step.
+    #[config(default = 0.001)]
+    pub dt_min: f64,
+    /// The maximum value of the initial discretization step.
+    #[config(default = 0.1)]
+    pub dt_max: f64,
+    /// If bias should be added to the input and output projections.
+    #[config(default = false)]
+    pub bias: bool,
+    /// If bias should be added to the convolution.
+    #[config(default = true)]
+    pub conv_bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// The Mamba block, a selective state space model from the paper
+/// [Mamba: Linear-Time Sequence Modeling with Selective State Spaces](https://arxiv.org/abs/2312.00752).
+///
+/// The input is projected to the inner features and a gate. The inner features go through a causal
+/// depthwise convolution and a selective state space model, whose discretization step `dt` and
+/// matrices `B` and `C` depend on the input, before being gated and projected back to `d_model`.
+///
+/// The recurrence over the sequence is computed in parallel with
+/// [linear_recurrence](crate::tensor::module::linear_recurrence), on a state of shape
+/// `[batch_size, seq_length, d_inner, d_state]`.
+///
+/// Should be created using [MambaConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct Mamba<B: Backend> {
+    /// The projection of the input to the inner features and the gate.
+    pub in_proj: Linear<B>,
+    /// The causal depthwise convolution over the sequence.
+    pub conv1d: Conv1d<B>,
+    /// The projection of the inner features to the discretization step, `B` and `C`.
+    pub x_proj: Linear<B>,
+    /// The projection of the low rank discretization step to the inner features.
+    pub dt_proj: Linear<B>,
+    /// The logarithm of the negated diagonal of the state matrix, of shape `[d_inner, d_state]`.
+    pub a_log: Param<Tensor<B, 2>>,
+    /// The skip connection weights, of shape `[d_inner]`.
+    pub d: Param<Tensor<B, 1>>,
+    /// The projection of the inner features back to `d_model`.
+    pub out_proj: Linear<B>,
+}
+
+impl<B: Backend> ModuleDisplay for Mamba<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [d_inner, d_model] = self.out_proj.weight.shape().dims();
+        let [_, d_state] = self.a_log.shape().dims();
+        let [dt_rank, _] = self.dt_proj.weight.shape().dims();
+
+        content
+            .add("d_model", &d_model)
+            .add("d_inner", &d_inner)
+            .add("d_state", &d_state)
+            .add("d_conv", &self.conv1d.kernel_size)
+            .add("dt_rank", &dt_rank)
+            .optional()
+    }
+}
+
+impl MambaConfig {
+    /// Initialize a new [Mamba](Mamba) block.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> Mamba<B> {
+        let d_inner = self.expand * self.d_model;
+        let dt_rank = self.dt_rank.unwrap_or(self.d_model.div_ceil(16));
+        let linear = |d_input, d_output, bias| {
+            LinearConfig::new(d_input, d_output)
+                .with_bias(bias)
+                .with_initializer(self.initializer.clone())
+                .init(device)
+        };
+
+        let mut dt_proj = linear(dt_rank, d_inner, false);
+        // The bias is initialized so the discretization step, after the softplus, is sampled
+        // between `dt_min` and `dt_max` in log scale.
+        let dt: Tensor<B, 1> = Tensor::random(
+            [d_inner],
+            Distribution::Uniform(self.dt_min.ln(), self.dt_max.ln()),
+            device,
+        )
+        .exp();
+        let inverse_softplus = dt.clone() + (-dt.neg().exp() + 1.0).log();
+        dt_proj.bias = Some(Param::from_tensor(inverse_softplus));
+
+        Mamba {
+            in_proj: linear(self.d_model, 2 * d_inner, self.bias),
+            conv1d: Conv1dConfig::new(d_inner, d_inner, self.d_conv)
+                .with_groups(d_inner)
+                .with_padding(PaddingConfig1d::Explicit(self.d_conv - 1))
+                .with_bias(self.conv_bias)
+                .with_initializer(self.initializer.clone())
+                .init(device),
+            x_proj: linear(d_inner, dt_rank + 2 * self.d_state, false),
+            dt_proj,
+            a_log: Param::from_tensor(state_matrix_log_init(d_inner, self.d_state, device)),
+            d: Initializer::Ones.init([d_inner], device),
+            out_proj: linear(d_inner, self.d_model, self.bias),
+        }
+    }
+}
+
+impl<B: Backend> Mamba<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, seq_length, d_model]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let [_, seq_length, _] = input.dims();
+        let [d_inner, d_state] = self.a_log.dims();
+        let [dt_rank, _] = self.dt_proj.weight.dims();
+
+        let projected = self.in_proj.forward(input);
+        let x = projected.clone().narrow(2, 0, d_inner);
+        let gate = projected.narrow(2, d_inner, d_inner);
+
+        // The convolution is padded on both sides, so keeping the first outputs makes it causal.
+        let x = self
+            .conv1d
+            .forward(x.swap_dims(1, 2))
+            .narrow(2, 0, seq_length)
+            .swap_dims(1, 2);
+        let x = silu(x);
+
+        let x_dbl = self.x_proj.forward(x.clone());
+        let dt = x_dbl.clone().narrow(2, 0, dt_rank);
+        let b = x_dbl.clone().narrow(2, dt_rank, d_state);
+        let c = x_dbl.narrow(2, dt_rank + d_state, d_state);
+
+        let y = self.selective_scan(x, dt, b, c);
+
+        self.out_proj.forward(y * silu(gate))
+    }
+
+    /// Computes the state space model with the input dependent discretization step `dt` and
+    /// matrices `B` and `C`.
+    fn selective_scan(
+        &self,
+        x: Tensor<B, 3>,
+        dt: Tensor<B, 3>,
+        b: Tensor<B, 3>,
+        c: Tensor<B, 3>,
+    ) -> Tensor<B, 3> {
+        let dt = softplus(self.dt_proj.forward(dt), 1.0).unsqueeze_dim::<4>(3);
+        let a = self.a_log.val().exp().neg();
+
+        // Zero-order hold discretization of `A`, with the simplified Euler discretization of `B`.
+        let a_bar = (dt.clone() * a.unsqueeze()).exp();
+        let b_bar_x = dt * b.unsqueeze_dim(2) * x.clone().unsqueeze_dim(3);
+
+        let states = linear_recurrence(a_bar, b_bar_x, 1);
+        let y = (states * c.unsqueeze_dim(2)).sum_dim(3).squeeze(3);
+
+        y + x * self.d.val().unsqueeze()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn initializer_default() {
+        let config = MambaConfig::new(32);
+
+        assert_eq!(config.d_state, 16);
+        assert_eq!(config.d_conv, 4);
+        assert_eq!(config.expand, 2);
+        assert_eq!(config.dt_rank, None);
+    }
+
+    #[test]
+    fn forward_shape() {
+        let device = Default::default();
+        let mamba = MambaConfig::new(8)
+            .with_d_state(4)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([2, 7, 8], Distribution::Default, &device);
+
+        let output = mamba.forward(input);
+
+        assert_eq!(output.dims(), [2, 7, 8]);
+    }
+
+    #[test]
+    fn forward_should_be_causal() {
+        let device = Default::default();
+        let mamba = MambaConfig::new(8)
+            .with_d_state(4)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([1, 6, 8], Distribution::Default, &device);
+        let future = Tensor::<TestBackend, 3>::random([1, 2, 8], Distribution::Default, &device);
+        let modified = Tensor::cat(vec![input.clone().narrow(1, 0, 4), future], 1);
+
+        let output = mamba.forward(input).narrow(1, 0, 4);
+        let output_modified = mamba.forward(modified).narrow(1, 0, 4);
+
+        output
+            .into_data()
+            .assert_approx_eq(&output_modified.into_data(), 3);
+    }
+
+    #[test]
+    fn display() {
+        let mamba = MambaConfig::new(8)
+            .with_d_state(4)
+            .init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", mamba),
+            "Mamba {d_model: 8, d_inner: 16, d_state: 4, d_conv: 4, dt_rank: 1, params: 720}"
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod mamba;
+mod s4d;
+
+pub use mamba::*;
+pub use s4d::*;
@@ -0,0 +1,199 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay, Param};
+use crate::nn::Initializer;
+use crate::tensor::module::linear_recurrence;
+use crate::tensor::{backend::Backend, Distribution, Tensor};
+
+/// Configuration to create a [diagonal state space](S4d) layer using the [init function](S4dConfig::init).
+#[derive(Config, Debug)]
+pub struct S4dConfig {
+    /// The size of the input and output features.
+    pub d_model: usize,
+    /// The size of the state of each feature.
+    #[config(default = 64)]
+    pub d_state: usize,
+    /// The minimum value of the initial discretization step.
+    #[config(default = 0.001)]
+    pub dt_min: f64,
+    /// The maximum value of the initial discretization step.
+    #[config(default = 0.1)]
+    pub dt_max: f64,
+    /// The type of function used to initialize the output projection of the state.
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// Applies a diagonal state space model (S4D) independently to each feature of the input
+/// sequences.
+///
+/// Each feature has its own state of size `d_state`, following the continuous system
+/// `h'(t) = A h(t) + B x(t)` and `y(t) = C h(t) + D x(t)`, where `A` is a real diagonal matrix.
+/// The system is discretized with the zero-order hold method using a learned step `dt`, and the
+/// resulting linear recurrence is computed in parallel over the sequence with
+/// [linear_recurrence](crate::tensor::module::linear_recurrence).
+///
+/// `A` is initialized with the S4D-Real values `-(n + 1)`.
+///
+/// Should be created using [S4dConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct S4d<B: Backend> {
+    /// The logarithm of the negated diagonal of the state matrix, of shape `[d_model, d_state]`.
+    pub a_log: Param<Tensor<B, 2>>,
+    /// The input projection to the state, of shape `[d_model, d_state]`.
+    pub b: Param<Tensor<B, 2>>,
+    /// The output projection of the state, of shape `[d_model, d_state]`.
+    pub c: Param<Tensor<B, 2>>,
+    /// The skip connection weights, of shape `[d_model]`.
+    pub d: Param<Tensor<B, 1>>,
+    /// The logarithm of the discretization step, of shape `[d_model]`.
+    pub log_dt: Param<Tensor<B, 1>>,
+}
+
+impl<B: Backend> ModuleDisplay for S4d<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [d_model, d_state] = self.a_log.shape().dims();
+
+        content
+            .add("d_model", &d_model)
+            .add("d_state", &d_state)
+            .optional()
+    }
+}
+
+impl S4dConfig {
+    /// Initialize a new [diagonal state space](S4d) layer.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> S4d<B> {
+        let (d_model, d_state) = (self.d_model, self.d_state);
+        let log_dt = Tensor::random(
+            [d_model],
+            Distribution::Uniform(self.dt_min.ln(), self.dt_max.ln()),
+            device,
+        );
+
+        S4d {
+            a_log: Param::from_tensor(state_matrix_log_init(d_model, d_state, device)),
+            b: Initializer::Ones.init([d_model, d_state], device),
+            c: self
+                .initializer
+                .init_with([d_model, d_state], Some(d_state), Some(1), device),
+            d: Initializer::Ones.init([d_model], device),
+            log_dt: Param::from_tensor(log_dt),
+        }
+    }
+}
+
+impl<B: Backend> S4d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, seq_length, d_model]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let [batch_size, seq_length, d_model] = input.dims();
+        let [_, d_state] = self.a_log.dims();
+
+        let a = self.a_log.val().exp().neg();
+        let dt = self.log_dt.val().exp().unsqueeze_dim(1);
+
+        // Zero-order hold discretization of the diagonal system.
+        let a_bar = (a.clone() * dt).exp();
+        let b_bar = (a_bar.clone() - 1.0) / a * self.b.val();
+
+        let a_bar = a_bar
+            .unsqueeze::<4>()
+            .expand([batch_size, seq_length, d_model, d_state]);
+        let b_bar_x = input.clone().unsqueeze_dim::<4>(3) * b_bar.unsqueeze();
+
+        let states = linear_recurrence(a_bar, b_bar_x, 1);
+        let output = (states * self.c.val().unsqueeze()).sum_dim(3).squeeze(3);
+
+        output + input * self.d.val().unsqueeze()
+    }
+}
+
+/// Initializes the logarithm of the negated diagonal state matrix with the S4D-Real values
+/// `-(n + 1)`, shared by all features.
+pub(crate) fn state_matrix_log_init<B: Backend>(
+    d_model: usize,
+    d_state: usize,
+    device: &B::Device,
+) -> Tensor<B, 2> {
+    Tensor::arange(1..d_state as i64 + 1, device)
+        .float()
+        .log()
+        .unsqueeze::<2>()
+        .repeat_dim(0, d_model)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn initializer_default() {
+        let config = S4dConfig::new(4);
+
+        assert_eq!(config.d_state, 64);
+        assert_eq!(config.dt_min, 0.001);
+        assert_eq!(config.dt_max, 0.1);
+    }
+
+    #[test]
+    fn forward_should_match_sequential_recurrence() {
+        let device = Default::default();
+        let layer = S4dConfig::new(3)
+            .with_d_state(4)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([2, 5, 3], Distribution::Default, &device);
+
+        let output = layer.forward(input.clone());
+
+        let a = layer.a_log.val().exp().neg();
+        let dt = layer.log_dt.val().exp().unsqueeze_dim(1);
+        let a_bar = (a.clone() * dt).exp();
+        let b_bar = (a_bar.clone() - 1.0) / a * layer.b.val();
+        let mut state = Tensor::<TestBackend, 3>::zeros([2, 3, 4], &device);
+        let mut expected = Vec::new();
+        for t in 0..5 {
+            let x = input.clone().narrow(1, t, 1).squeeze::<2>(1);
+            state = state * a_bar.clone().unsqueeze()
+                + x.clone().unsqueeze_dim(2) * b_bar.clone().unsqueeze();
+            let y = (state.clone() * layer.c.val().unsqueeze())
+                .sum_dim(2)
+                .squeeze::<2>(2)
+                + x * layer.d.val().unsqueeze();
+            expected.push(y.unsqueeze_dim::<3>(1));
+        }
+        let expected = Tensor::cat(expected, 1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn display() {
+        let layer = S4dConfig::new(4)
+            .with_d_state(8)
+            .init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", layer),
+            "S4d {d_model: 4, d_state: 8, params: 104}"
+        );
+    }
+}
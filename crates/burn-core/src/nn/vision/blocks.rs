@@ -0,0 +1,326 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay};
+use crate::nn::conv::{Conv2d, Conv2dConfig};
+use crate::nn::{BatchNorm, BatchNormConfig, Initializer, PaddingConfig2d};
+use crate::tensor::activation::{gelu, relu, sigmoid, silu};
+use crate::tensor::{backend::Backend, Tensor};
+
+/// The activation functions of the vision building blocks.
+#[derive(Config, Debug, PartialEq)]
+pub enum ConvActivation {
+    /// Rectified linear unit.
+    Relu,
+    /// Sigmoid linear unit, also known as swish.
+    Silu,
+    /// Gaussian error linear unit.
+    Gelu,
+}
+
+impl ConvActivation {
+    /// Applies the activation function on the input tensor.
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        match self {
+            Self::Relu => relu(input),
+            Self::Silu => silu(input),
+            Self::Gelu => gelu(input),
+        }
+    }
+}
+
+/// Configuration to create a [convolution, normalization and activation](ConvNormActivation)
+/// block using the [init function](ConvNormActivationConfig::init).
+#[derive(Config, Debug)]
+pub struct ConvNormActivationConfig {
+    /// The number of channels.
+    pub channels: [usize; 2],
+    /// The size of the kernel.
+    pub kernel_size: usize,
+    /// The stride of the convolution.
+    #[config(default = 1)]
+    pub stride: usize,
+    /// Controls the connections between input and output channels.
+    #[config(default = 1)]
+    pub groups: usize,
+    /// The activation applied after the normalization, none if not provided.
+    #[config(default = "Some(ConvActivation::Relu)")]
+    pub activation: Option<ConvActivation>,
+    /// The epsilon of the batch normalization.
+    #[config(default = 1e-5)]
+    pub norm_epsilon: f64,
+    /// The type of function used to initialize the convolution weight.
+    #[config(
+        default = "Initializer::KaimingNormal{gain:num_traits::Float::sqrt(2.0), fan_out_only:true}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// A convolution without bias padded to keep the spatial size with a unit stride, followed by a
+/// batch normalization and an optional activation.
+///
+/// Should be created using [ConvNormActivationConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct ConvNormActivation<B: Backend> {
+    /// The convolution.
+    pub conv: Conv2d<B>,
+    /// The batch normalization.
+    pub norm: BatchNorm<B, 2>,
+    /// The activation applied after the normalization.
+    pub activation: Ignored<Option<ConvActivation>>,
+}
+
+impl<B: Backend> ModuleDisplay for ConvNormActivation<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [channels_out, channels_in, _, _] = self.conv.weight.shape().dims();
+
+        content
+            .add("channels_in", &(channels_in * self.conv.groups))
+            .add("channels_out", &channels_out)
+            .add("kernel_size", &self.conv.kernel_size[0])
+            .add("stride", &self.conv.stride[0])
+            .add("activation", &alloc::format!("{:?}", self.activation.0))
+            .optional()
+    }
+}
+
+impl ConvNormActivationConfig {
+    /// Initialize a new [convolution, normalization and activation](ConvNormActivation) block.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> ConvNormActivation<B> {
+        let padding = (self.kernel_size - 1) / 2;
+
+        ConvNormActivation {
+            conv: Conv2dConfig::new(self.channels, [self.kernel_size, self.kernel_size])
+                .with_stride([self.stride, self.stride])
+                .with_padding(PaddingConfig2d::Explicit(padding, padding))
+                .with_groups(self.groups)
+                .with_bias(false)
+                .with_initializer(self.initializer.clone())
+                .init(device),
+            norm: BatchNormConfig::new(self.channels[1])
+                .with_epsilon(self.norm_epsilon)
+                .init(device),
+            activation: Ignored(self.activation.clone()),
+        }
+    }
+}
+
+impl<B: Backend> ConvNormActivation<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, height_in, width_in]`
+    /// - output: `[batch_size, channels_out, height_out, width_out]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let x = self.norm.forward(self.conv.forward(input));
+
+        match &self.activation.0 {
+            Some(activation) => activation.forward(x),
+            None => x,
+        }
+    }
+}
+
+/// Configuration to create a [squeeze-and-excitation](SqueezeExcitation) block using the
+/// [init function](SqueezeExcitationConfig::init).
+#[derive(Config, Debug)]
+pub struct SqueezeExcitationConfig {
+    /// The number of input and output channels.
+    pub channels: usize,
+    /// The number of channels of the squeezed representation.
+    pub squeeze_channels: usize,
+    /// The activation applied on the squeezed representation.
+    #[config(default = "ConvActivation::Relu")]
+    pub activation: ConvActivation,
+}
+
+/// The squeeze-and-excitation block from the paper
+/// [Squeeze-and-Excitation Networks](https://arxiv.org/abs/1709.01507).
+///
+/// Each channel is scaled by a gate computed from the spatial averages of all channels.
+///
+/// Should be created using [SqueezeExcitationConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct SqueezeExcitation<B: Backend> {
+    /// The pointwise convolution squeezing the channels.
+    pub fc1: Conv2d<B>,
+    /// The pointwise convolution computing the gate of each channel.
+    pub fc2: Conv2d<B>,
+    /// The activation applied on the squeezed representation.
+    pub activation: Ignored<ConvActivation>,
+}
+
+impl<B: Backend> ModuleDisplay for SqueezeExcitation<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [squeeze_channels, channels, _, _] = self.fc1.weight.shape().dims();
+
+        content
+            .add("channels", &channels)
+            .add("squeeze_channels", &squeeze_channels)
+            .add("activation", &alloc::format!("{:?}", self.activation.0))
+            .optional()
+    }
+}
+
+impl SqueezeExcitationConfig {
+    /// Initialize a new [squeeze-and-excitation](SqueezeExcitation) block.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> SqueezeExcitation<B> {
+        SqueezeExcitation {
+            fc1: Conv2dConfig::new([self.channels, self.squeeze_channels], [1, 1]).init(device),
+            fc2: Conv2dConfig::new([self.squeeze_channels, self.channels], [1, 1]).init(device),
+            activation: Ignored(self.activation.clone()),
+        }
+    }
+}
+
+impl<B: Backend> SqueezeExcitation<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, height, width]`
+    /// - output: `[batch_size, channels, height, width]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let scale = input.clone().mean_dim(2).mean_dim(3);
+        let scale = self.activation.forward(self.fc1.forward(scale));
+        let scale = sigmoid(self.fc2.forward(scale));
+
+        input * scale
+    }
+}
+
+/// Configuration to create a [patch embedding](PatchEmbedding) layer using the
+/// [init function](PatchEmbeddingConfig::init).
+#[derive(Config, Debug)]
+pub struct PatchEmbeddingConfig {
+    /// The number of channels of the images.
+    pub in_channels: usize,
+    /// The size of the square patches.
+    pub patch_size: usize,
+    /// The size of the embeddings.
+    pub d_model: usize,
+}
+
+/// Splits the images into non-overlapping square patches and projects each of them to an
+/// embedding, as in [An Image is Worth 16x16 Words](https://arxiv.org/abs/2010.11929).
+///
+/// Should be created using [PatchEmbeddingConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct PatchEmbedding<B: Backend> {
+    /// The projection of the patches, a convolution with a stride equal to its kernel size.
+    pub proj: Conv2d<B>,
+}
+
+impl<B: Backend> ModuleDisplay for PatchEmbedding<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [d_model, in_channels, patch_size, _] = self.proj.weight.shape().dims();
+
+        content
+            .add("in_channels", &in_channels)
+            .add("patch_size", &patch_size)
+            .add("d_model", &d_model)
+            .optional()
+    }
+}
+
+impl PatchEmbeddingConfig {
+    /// Initialize a new [patch embedding](PatchEmbedding) layer.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> PatchEmbedding<B> {
+        let patch_size = [self.patch_size, self.patch_size];
+
+        PatchEmbedding {
+            proj: Conv2dConfig::new([self.in_channels, self.d_model], patch_size)
+                .with_stride(patch_size)
+                .init(device),
+        }
+    }
+}
+
+impl<B: Backend> PatchEmbedding<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, in_channels, height, width]`
+    /// - output: `[batch_size, num_patches, d_model]`, with
+    ///   `num_patches = (height / patch_size) * (width / patch_size)`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 3> {
+        self.proj.forward(input).flatten::<3>(2, 3).swap_dims(1, 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Distribution;
+    use crate::TestBackend;
+
+    #[test]
+    fn conv_norm_activation_should_keep_the_spatial_size() {
+        let device = Default::default();
+        let block = ConvNormActivationConfig::new([3, 8], 3).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 4>::random([2, 3, 7, 7], Distribution::Default, &device);
+
+        assert_eq!(block.forward(input).dims(), [2, 8, 7, 7]);
+    }
+
+    #[test]
+    fn squeeze_excitation_should_scale_each_channel() {
+        let device = Default::default();
+        let block = SqueezeExcitationConfig::new(4, 2).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 4>::ones([1, 4, 3, 3], &device);
+
+        let output = block.forward(input);
+        let first = output.clone().narrow(2, 0, 1).narrow(3, 0, 1);
+
+        // The gate is the same at every position of a channel.
+        output
+            .into_data()
+            .assert_approx_eq(&first.expand([1, 4, 3, 3]).into_data(), 3);
+    }
+
+    #[test]
+    fn patch_embedding_shape() {
+        let device = Default::default();
+        let layer = PatchEmbeddingConfig::new(3, 4, 8).init::<TestBackend>(&device);
+        let input =
+            Tensor::<TestBackend, 4>::random([2, 3, 16, 12], Distribution::Default, &device);
+
+        assert_eq!(layer.forward(input).dims(), [2, 12, 8]);
+    }
+
+    #[test]
+    fn display() {
+        let block = ConvNormActivationConfig::new([3, 8], 3)
+            .with_stride(2)
+            .init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", block),
+            "ConvNormActivation {channels_in: 3, channels_out: 8, kernel_size: 3, stride: 2, \
+             activation: Some(Relu), params: 248}"
+        );
+    }
+}
@@ -0,0 +1,318 @@
+use crate as burn;
+
+use alloc::vec::Vec;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay, Param};
+use crate::nn::conv::{Conv2d, Conv2dConfig};
+use crate::nn::{Initializer, LayerNorm, LayerNormConfig, Linear, LinearConfig, PaddingConfig2d};
+use crate::tensor::activation::gelu;
+use crate::tensor::{backend::Backend, Tensor};
+
+/// The key remapping from the torchvision ConvNeXt weights to the [ConvNext] module, to be
+/// applied when loading PyTorch weights with `burn-import`.
+pub const CONVNEXT_KEY_REMAP: &[(&str, &str)] = &[
+    (r"^features\.0\.0\.", "stem.conv."),
+    (r"^features\.0\.1\.", "stem.norm."),
+    (r"^features\.1\.", "stages.0."),
+    (r"^features\.3\.", "stages.1."),
+    (r"^features\.5\.", "stages.2."),
+    (r"^features\.7\.", "stages.3."),
+    (r"^features\.2\.0\.", "downsamples.0.norm."),
+    (r"^features\.2\.1\.", "downsamples.0.conv."),
+    (r"^features\.4\.0\.", "downsamples.1.norm."),
+    (r"^features\.4\.1\.", "downsamples.1.conv."),
+    (r"^features\.6\.0\.", "downsamples.2.norm."),
+    (r"^features\.6\.1\.", "downsamples.2.conv."),
+    (r"\.block\.0\.", ".dwconv."),
+    (r"\.block\.2\.", ".norm."),
+    (r"\.block\.3\.", ".pwconv1."),
+    (r"\.block\.5\.", ".pwconv2."),
+    (r"^classifier\.0\.", "norm."),
+    (r"^classifier\.2\.", "head."),
+];
+
+/// Configuration to create a [ConvNeXt](ConvNext) using the [init function](ConvNextConfig::init).
+#[derive(Config, Debug)]
+pub struct ConvNextConfig {
+    /// The number of blocks of each of the four stages.
+    pub depths: [usize; 4],
+    /// The number of channels of each of the four stages.
+    pub dims: [usize; 4],
+    /// The number of classes.
+    pub num_classes: usize,
+    /// The number of channels of the images.
+    #[config(default = 3)]
+    pub in_channels: usize,
+    /// The initial value of the scale of the residual branches.
+    #[config(default = 1e-6)]
+    pub layer_scale: f64,
+}
+
+/// The ConvNeXt network from the paper
+/// [A ConvNet for the 2020s](https://arxiv.org/abs/2201.03545), following the torchvision
+/// implementation.
+///
+/// The parameters are named after the torchvision ones, so pretrained weights can be loaded with
+/// the [CONVNEXT_KEY_REMAP] key remapping.
+///
+/// Should be created using [ConvNextConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct ConvNext<B: Backend> {
+    /// The patchify stem.
+    pub stem: ConvNextStem<B>,
+    /// The blocks of each stage.
+    pub stages: Vec<Vec<ConvNextBlock<B>>>,
+    /// The downsampling layers between the stages.
+    pub downsamples: Vec<ConvNextDownsample<B>>,
+    /// The layer normalization of the pooled features.
+    pub norm: LayerNorm<B>,
+    /// The classification layer.
+    pub head: Linear<B>,
+}
+
+/// The stem of a [ConvNeXt](ConvNext), a 4x4 convolution with a stride of 4 followed by a layer
+/// normalization.
+#[derive(Module, Debug)]
+pub struct ConvNextStem<B: Backend> {
+    /// The patchify convolution.
+    pub conv: Conv2d<B>,
+    /// The layer normalization over the channels.
+    pub norm: LayerNorm<B>,
+}
+
+/// The downsampling layer of a [ConvNeXt](ConvNext), a layer normalization followed by a 2x2
+/// convolution with a stride of 2.
+#[derive(Module, Debug)]
+pub struct ConvNextDownsample<B: Backend> {
+    /// The layer normalization over the channels.
+    pub norm: LayerNorm<B>,
+    /// The downsampling convolution.
+    pub conv: Conv2d<B>,
+}
+
+/// A block of a [ConvNeXt](ConvNext): a 7x7 depthwise convolution followed by an inverted
+/// bottleneck, with a scaled residual connection.
+#[derive(Module, Debug)]
+pub struct ConvNextBlock<B: Backend> {
+    /// The depthwise convolution.
+    pub dwconv: Conv2d<B>,
+    /// The layer normalization over the channels.
+    pub norm: LayerNorm<B>,
+    /// The pointwise expansion.
+    pub pwconv1: Linear<B>,
+    /// The pointwise projection.
+    pub pwconv2: Linear<B>,
+    /// The scale of the residual branch, of shape `[channels, 1, 1]`.
+    pub layer_scale: Param<Tensor<B, 3>>,
+}
+
+impl<B: Backend> ModuleDisplay for ConvNext<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let depths: Vec<_> = self.stages.iter().map(|stage| stage.len()).collect();
+        let [_, num_classes] = self.head.weight.shape().dims();
+
+        content
+            .add("depths", &alloc::format!("{:?}", depths))
+            .add("num_classes", &num_classes)
+            .optional()
+    }
+}
+
+impl ConvNextConfig {
+    /// The configuration of ConvNeXt-T.
+    pub fn convnext_tiny(num_classes: usize) -> Self {
+        Self::new([3, 3, 9, 3], [96, 192, 384, 768], num_classes)
+    }
+
+    /// The configuration of ConvNeXt-S.
+    pub fn convnext_small(num_classes: usize) -> Self {
+        Self::new([3, 3, 27, 3], [96, 192, 384, 768], num_classes)
+    }
+
+    /// The configuration of ConvNeXt-B.
+    pub fn convnext_base(num_classes: usize) -> Self {
+        Self::new([3, 3, 27, 3], [128, 256, 512, 1024], num_classes)
+    }
+
+    /// The configuration of ConvNeXt-L.
+    pub fn convnext_large(num_classes: usize) -> Self {
+        Self::new([3, 3, 27, 3], [192, 384, 768, 1536], num_classes)
+    }
+
+    /// Initialize a new [ConvNeXt](ConvNext).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> ConvNext<B> {
+        let initializer = Initializer::Normal {
+            mean: 0.0,
+            std: 0.02,
+        };
+        let layer_norm = |channels| {
+            LayerNormConfig::new(channels)
+                .with_epsilon(1e-6)
+                .init(device)
+        };
+        let conv = |channels, kernel_size: usize, groups| {
+            let padding = (kernel_size - 1) / 2;
+            Conv2dConfig::new(channels, [kernel_size, kernel_size])
+                .with_padding(PaddingConfig2d::Explicit(padding, padding))
+                .with_groups(groups)
+                .with_initializer(initializer.clone())
+        };
+        let linear = |d_input, d_output| {
+            LinearConfig::new(d_input, d_output)
+                .with_initializer(initializer.clone())
+                .init(device)
+        };
+
+        let stages = self
+            .depths
+            .iter()
+            .zip(self.dims)
+            .map(|(&depth, dim)| {
+                (0..depth)
+                    .map(|_| ConvNextBlock {
+                        dwconv: conv([dim, dim], 7, dim).init(device),
+                        norm: layer_norm(dim),
+                        pwconv1: linear(dim, 4 * dim),
+                        pwconv2: linear(4 * dim, dim),
+                        layer_scale: Initializer::Constant {
+                            value: self.layer_scale,
+                        }
+                        .init([dim, 1, 1], device),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let downsamples = self
+            .dims
+            .windows(2)
+            .map(|dims| ConvNextDownsample {
+                norm: layer_norm(dims[0]),
+                conv: conv([dims[0], dims[1]], 2, 1)
+                    .with_stride([2, 2])
+                    .with_padding(PaddingConfig2d::Valid)
+                    .init(device),
+            })
+            .collect();
+
+        ConvNext {
+            stem: ConvNextStem {
+                conv: conv([self.in_channels, self.dims[0]], 4, 1)
+                    .with_stride([4, 4])
+                    .with_padding(PaddingConfig2d::Valid)
+                    .init(device),
+                norm: layer_norm(self.dims[0]),
+            },
+            stages,
+            downsamples,
+            norm: layer_norm(self.dims[3]),
+            head: linear(self.dims[3], self.num_classes),
+        }
+    }
+}
+
+impl<B: Backend> ConvNext<B> {
+    /// Computes the class logits of the images.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, in_channels, height, width]`
+    /// - output: `[batch_size, num_classes]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 2> {
+        let features = self.forward_features(input);
+        let pooled = features.mean_dim(2).mean_dim(3).flatten(1, 3);
+
+        self.head.forward(self.norm.forward(pooled))
+    }
+
+    /// Computes the feature maps of the last stage, before the global pooling.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, in_channels, height, width]`
+    /// - output: `[batch_size, channels, height / 32, width / 32]`
+    pub fn forward_features(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let x = self.stem.conv.forward(input);
+        let mut x = layer_norm_channels(&self.stem.norm, x);
+
+        for (i, stage) in self.stages.iter().enumerate() {
+            if i > 0 {
+                let downsample = &self.downsamples[i - 1];
+                x = downsample
+                    .conv
+                    .forward(layer_norm_channels(&downsample.norm, x));
+            }
+
+            x = stage.iter().fold(x, |x, block| block.forward(x));
+        }
+
+        x
+    }
+}
+
+impl<B: Backend> ConvNextBlock<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, height, width]`
+    /// - output: `[batch_size, channels, height, width]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let x = self.dwconv.forward(input.clone()).permute([0, 2, 3, 1]);
+        let x = gelu(self.pwconv1.forward(self.norm.forward(x)));
+        let x = self.pwconv2.forward(x).permute([0, 3, 1, 2]);
+
+        x * self.layer_scale.val().unsqueeze() + input
+    }
+}
+
+/// Applies the layer normalization over the channels of the feature maps.
+fn layer_norm_channels<B: Backend>(norm: &LayerNorm<B>, input: Tensor<B, 4>) -> Tensor<B, 4> {
+    norm.forward(input.permute([0, 2, 3, 1]))
+        .permute([0, 3, 1, 2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Distribution;
+    use crate::TestBackend;
+
+    #[test]
+    fn convnext_forward_shape() {
+        let device = Default::default();
+        let model =
+            ConvNextConfig::new([1, 1, 2, 1], [8, 16, 32, 64], 10).init::<TestBackend>(&device);
+        let input =
+            Tensor::<TestBackend, 4>::random([2, 3, 64, 64], Distribution::Default, &device);
+
+        assert_eq!(model.forward_features(input.clone()).dims(), [2, 64, 2, 2]);
+        assert_eq!(model.forward(input).dims(), [2, 10]);
+    }
+
+    #[test]
+    fn convnext_tiny_num_params() {
+        let config = ConvNextConfig::convnext_tiny(1000);
+        let model = config.init::<TestBackend>(&Default::default());
+
+        // Same number of parameters as the torchvision ConvNeXt-T.
+        assert_eq!(model.num_params(), 28_589_128);
+    }
+
+    #[test]
+    fn display() {
+        let model = ConvNextConfig::new([1, 1, 2, 1], [8, 16, 32, 64], 10)
+            .init::<TestBackend>(&Default::default());
+
+        assert!(alloc::format!("{}", model)
+            .starts_with("ConvNext {depths: [1, 1, 2, 1], num_classes: 10"));
+    }
+}
@@ -0,0 +1,371 @@
+use crate as burn;
+
+use alloc::vec::Vec;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::nn::{Dropout, DropoutConfig, Linear, LinearConfig};
+use crate::tensor::{backend::Backend, Tensor};
+
+use super::{
+    ConvActivation, ConvNormActivation, ConvNormActivationConfig, SqueezeExcitation,
+    SqueezeExcitationConfig,
+};
+
+/// The key remapping from the torchvision EfficientNet weights to the [EfficientNet] module, to
+/// be applied when loading PyTorch weights with `burn-import`.
+pub const EFFICIENTNET_KEY_REMAP: &[(&str, &str)] = &[
+    (r"^features\.0\.", "stem."),
+    (r"^features\.8\.", "head."),
+    (r"^features\.1\.", "stages.0."),
+    (r"^features\.2\.", "stages.1."),
+    (r"^features\.3\.", "stages.2."),
+    (r"^features\.4\.", "stages.3."),
+    (r"^features\.5\.", "stages.4."),
+    (r"^features\.6\.", "stages.5."),
+    (r"^features\.7\.", "stages.6."),
+    // The blocks of the first stage have no expansion.
+    (r"^stages\.0\.(\d+)\.block\.0\.", "stages.0.$1.depthwise."),
+    (r"^stages\.0\.(\d+)\.block\.1\.", "stages.0.$1.se."),
+    (r"^stages\.0\.(\d+)\.block\.2\.", "stages.0.$1.project."),
+    (
+        r"^stages\.([1-6])\.(\d+)\.block\.0\.",
+        "stages.$1.$2.expand.",
+    ),
+    (
+        r"^stages\.([1-6])\.(\d+)\.block\.1\.",
+        "stages.$1.$2.depthwise.",
+    ),
+    (r"^stages\.([1-6])\.(\d+)\.block\.2\.", "stages.$1.$2.se."),
+    (
+        r"^stages\.([1-6])\.(\d+)\.block\.3\.",
+        "stages.$1.$2.project.",
+    ),
+    (
+        r"^(stem|head|.*\.expand|.*\.depthwise|.*\.project)\.0\.",
+        "$1.conv.",
+    ),
+    (
+        r"^(stem|head|.*\.expand|.*\.depthwise|.*\.project)\.1\.",
+        "$1.norm.",
+    ),
+    (r"^classifier\.1\.", "classifier."),
+];
+
+/// The expansion ratio, kernel size, stride, input channels, output channels and number of
+/// blocks of each stage of EfficientNet-B0.
+const STAGES: [(usize, usize, usize, usize, usize, usize); 7] = [
+    (1, 3, 1, 32, 16, 1),
+    (6, 3, 2, 16, 24, 2),
+    (6, 5, 2, 24, 40, 2),
+    (6, 3, 2, 40, 80, 3),
+    (6, 5, 1, 80, 112, 3),
+    (6, 5, 2, 112, 192, 4),
+    (6, 3, 1, 192, 320, 1),
+];
+
+/// Configuration to create an [EfficientNet](EfficientNet) using the
+/// [init function](EfficientNetConfig::init).
+#[derive(Config, Debug)]
+pub struct EfficientNetConfig {
+    /// The multiplier of the number of channels of EfficientNet-B0.
+    pub width_mult: f64,
+    /// The multiplier of the number of blocks of EfficientNet-B0.
+    pub depth_mult: f64,
+    /// The number of classes.
+    pub num_classes: usize,
+    /// The dropout rate before the classification layer.
+    #[config(default = 0.2)]
+    pub dropout: f64,
+    /// The number of channels of the images.
+    #[config(default = 3)]
+    pub in_channels: usize,
+    /// The epsilon of the batch normalizations.
+    #[config(default = 1e-5)]
+    pub norm_epsilon: f64,
+}
+
+/// The EfficientNet network from the paper
+/// [EfficientNet: Rethinking Model Scaling for Convolutional Neural Networks](https://arxiv.org/abs/1905.11946),
+/// following the torchvision implementation, without stochastic depth.
+///
+/// The parameters are named after the torchvision ones, so pretrained weights can be loaded with
+/// the [EFFICIENTNET_KEY_REMAP] key remapping.
+///
+/// Should be created using [EfficientNetConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct EfficientNet<B: Backend> {
+    /// The stem convolution.
+    pub stem: ConvNormActivation<B>,
+    /// The inverted residual blocks of each stage.
+    pub stages: Vec<Vec<MbConv<B>>>,
+    /// The pointwise convolution after the last stage.
+    pub head: ConvNormActivation<B>,
+    /// The dropout before the classification layer.
+    pub dropout: Dropout,
+    /// The classification layer.
+    pub classifier: Linear<B>,
+}
+
+/// The mobile inverted bottleneck block of an [EfficientNet](EfficientNet).
+#[derive(Module, Debug)]
+pub struct MbConv<B: Backend> {
+    /// The pointwise expansion, absent when the expansion ratio is one.
+    pub expand: Option<ConvNormActivation<B>>,
+    /// The depthwise convolution.
+    pub depthwise: ConvNormActivation<B>,
+    /// The squeeze-and-excitation.
+    pub se: SqueezeExcitation<B>,
+    /// The pointwise projection, without activation.
+    pub project: ConvNormActivation<B>,
+    /// If the input is added to the output.
+    pub residual: bool,
+}
+
+impl<B: Backend> ModuleDisplay for EfficientNet<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let depths: Vec<_> = self.stages.iter().map(|stage| stage.len()).collect();
+        let [_, num_classes] = self.classifier.weight.shape().dims();
+
+        content
+            .add("depths", &alloc::format!("{:?}", depths))
+            .add("num_classes", &num_classes)
+            .optional()
+    }
+}
+
+impl EfficientNetConfig {
+    /// The configuration of EfficientNet-B0.
+    pub fn efficientnet_b0(num_classes: usize) -> Self {
+        Self::new(1.0, 1.0, num_classes)
+    }
+
+    /// The configuration of EfficientNet-B1.
+    pub fn efficientnet_b1(num_classes: usize) -> Self {
+        Self::new(1.0, 1.1, num_classes)
+    }
+
+    /// The configuration of EfficientNet-B2.
+    pub fn efficientnet_b2(num_classes: usize) -> Self {
+        Self::new(1.1, 1.2, num_classes).with_dropout(0.3)
+    }
+
+    /// The configuration of EfficientNet-B3.
+    pub fn efficientnet_b3(num_classes: usize) -> Self {
+        Self::new(1.2, 1.4, num_classes).with_dropout(0.3)
+    }
+
+    /// The configuration of EfficientNet-B4.
+    pub fn efficientnet_b4(num_classes: usize) -> Self {
+        Self::new(1.4, 1.8, num_classes).with_dropout(0.4)
+    }
+
+    /// The configuration of EfficientNet-B5.
+    pub fn efficientnet_b5(num_classes: usize) -> Self {
+        Self::new(1.6, 2.2, num_classes)
+            .with_dropout(0.4)
+            .with_norm_epsilon(1e-3)
+    }
+
+    /// The configuration of EfficientNet-B6.
+    pub fn efficientnet_b6(num_classes: usize) -> Self {
+        Self::new(1.8, 2.6, num_classes)
+            .with_dropout(0.5)
+            .with_norm_epsilon(1e-3)
+    }
+
+    /// The configuration of EfficientNet-B7.
+    pub fn efficientnet_b7(num_classes: usize) -> Self {
+        Self::new(2.0, 3.1, num_classes)
+            .with_dropout(0.5)
+            .with_norm_epsilon(1e-3)
+    }
+
+    /// Initialize a new [EfficientNet](EfficientNet).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> EfficientNet<B> {
+        let conv = |channels, kernel_size| {
+            ConvNormActivationConfig::new(channels, kernel_size)
+                .with_activation(Some(ConvActivation::Silu))
+                .with_norm_epsilon(self.norm_epsilon)
+        };
+
+        let stages: Vec<Vec<MbConv<B>>> = STAGES
+            .iter()
+            .map(
+                |&(expand_ratio, kernel_size, stride, channels_in, channels_out, depth)| {
+                    let channels_in = self.adjust_channels(channels_in);
+                    let channels_out = self.adjust_channels(channels_out);
+                    let depth = (depth as f64 * self.depth_mult).ceil() as usize;
+
+                    (0..depth)
+                        .map(|i| {
+                            let (channels_in, stride) = match i {
+                                0 => (channels_in, stride),
+                                _ => (channels_out, 1),
+                            };
+                            let expanded = make_divisible(channels_in as f64 * expand_ratio as f64);
+
+                            MbConv {
+                                expand: (expanded != channels_in)
+                                    .then(|| conv([channels_in, expanded], 1).init(device)),
+                                depthwise: conv([expanded, expanded], kernel_size)
+                                    .with_stride(stride)
+                                    .with_groups(expanded)
+                                    .init(device),
+                                se: SqueezeExcitationConfig::new(
+                                    expanded,
+                                    usize::max(1, channels_in / 4),
+                                )
+                                .with_activation(ConvActivation::Silu)
+                                .init(device),
+                                project: conv([expanded, channels_out], 1)
+                                    .with_activation(None)
+                                    .init(device),
+                                residual: stride == 1 && channels_in == channels_out,
+                            }
+                        })
+                        .collect()
+                },
+            )
+            .collect();
+
+        let channels_stem = self.adjust_channels(STAGES[0].3);
+        let channels_last = self.adjust_channels(STAGES[6].4);
+
+        EfficientNet {
+            stem: conv([self.in_channels, channels_stem], 3)
+                .with_stride(2)
+                .init(device),
+            stages,
+            head: conv([channels_last, 4 * channels_last], 1).init(device),
+            dropout: DropoutConfig::new(self.dropout).init(),
+            classifier: LinearConfig::new(4 * channels_last, self.num_classes).init(device),
+        }
+    }
+
+    fn adjust_channels(&self, channels: usize) -> usize {
+        make_divisible(channels as f64 * self.width_mult)
+    }
+}
+
+/// Rounds the number of channels to the nearest multiple of 8, without going more than 10% down.
+fn make_divisible(channels: f64) -> usize {
+    let divisor = 8;
+    let rounded = usize::max(
+        divisor,
+        (channels + divisor as f64 / 2.0) as usize / divisor * divisor,
+    );
+
+    match (rounded as f64) < 0.9 * channels {
+        true => rounded + divisor,
+        false => rounded,
+    }
+}
+
+impl<B: Backend> EfficientNet<B> {
+    /// Computes the class logits of the images.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, in_channels, height, width]`
+    /// - output: `[batch_size, num_classes]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 2> {
+        let features = self.forward_features(input);
+        let pooled = features.mean_dim(2).mean_dim(3).flatten(1, 3);
+
+        self.classifier.forward(self.dropout.forward(pooled))
+    }
+
+    /// Computes the feature maps of the last convolution, before the global pooling.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, in_channels, height, width]`
+    /// - output: `[batch_size, channels, height / 32, width / 32]`
+    pub fn forward_features(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let x = self.stem.forward(input);
+        let x = self
+            .stages
+            .iter()
+            .flatten()
+            .fold(x, |x, block| block.forward(x));
+
+        self.head.forward(x)
+    }
+}
+
+impl<B: Backend> MbConv<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, height_in, width_in]`
+    /// - output: `[batch_size, channels_out, height_out, width_out]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let x = match &self.expand {
+            Some(expand) => expand.forward(input.clone()),
+            None => input.clone(),
+        };
+        let x = self.se.forward(self.depthwise.forward(x));
+        let x = self.project.forward(x);
+
+        match self.residual {
+            true => x + input,
+            false => x,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Distribution;
+    use crate::TestBackend;
+
+    #[test]
+    fn efficientnet_forward_shape() {
+        let device = Default::default();
+        let model = EfficientNetConfig::new(0.25, 0.3, 10).init::<TestBackend>(&device);
+        let input =
+            Tensor::<TestBackend, 4>::random([2, 3, 64, 64], Distribution::Default, &device);
+
+        assert_eq!(model.forward_features(input.clone()).dims(), [2, 320, 2, 2]);
+        assert_eq!(model.forward(input).dims(), [2, 10]);
+    }
+
+    #[test]
+    fn efficientnet_b0_num_params() {
+        let model =
+            EfficientNetConfig::efficientnet_b0(1000).init::<TestBackend>(&Default::default());
+        let depths: Vec<_> = model.stages.iter().map(|stage| stage.len()).collect();
+
+        assert_eq!(depths, [1, 2, 2, 3, 3, 4, 1]);
+        assert!(model.stages[0][0].expand.is_none());
+        // The torchvision EfficientNet-B0 has 5,288,548 parameters, and 42,016 batch
+        // normalization running statistics.
+        assert_eq!(model.num_params(), 5_288_548 + 42_016);
+    }
+
+    #[test]
+    fn make_divisible_should_round_to_multiple_of_eight() {
+        assert_eq!(make_divisible(32.0 * 1.1), 32);
+        assert_eq!(make_divisible(40.0 * 1.4), 56);
+        assert_eq!(make_divisible(320.0 * 1.2), 384);
+        assert_eq!(make_divisible(4.0), 8);
+    }
+
+    #[test]
+    fn display() {
+        let model = EfficientNetConfig::new(0.25, 0.3, 10).init::<TestBackend>(&Default::default());
+
+        assert!(alloc::format!("{}", model)
+            .starts_with("EfficientNet {depths: [1, 1, 1, 1, 1, 2, 1], num_classes: 10"));
+    }
+}
@@ -0,0 +1,13 @@
+mod blocks;
+mod convnext;
+mod efficientnet;
+mod resnet;
+mod unet;
+mod vit;
+
+pub use blocks::*;
+pub use convnext::*;
+pub use efficientnet::*;
+pub use resnet::*;
+pub use unet::*;
+pub use vit::*;
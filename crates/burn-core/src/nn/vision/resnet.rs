@@ -0,0 +1,355 @@
+use crate as burn;
+
+use alloc::vec::Vec;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::nn::conv::{Conv2d, Conv2dConfig};
+use crate::nn::pool::{MaxPool2d, MaxPool2dConfig};
+use crate::nn::{BatchNorm, BatchNormConfig, Initializer, Linear, LinearConfig, PaddingConfig2d};
+use crate::tensor::activation::relu;
+use crate::tensor::{backend::Backend, Tensor};
+
+/// The key remapping from the torchvision ResNet weights to the [ResNet] module, to be applied
+/// when loading PyTorch weights with `burn-import`.
+pub const RESNET_KEY_REMAP: &[(&str, &str)] = &[
+    (r"\.downsample\.0\.", ".downsample.conv."),
+    (r"\.downsample\.1\.", ".downsample.bn."),
+];
+
+/// Configuration to create a [ResNet](ResNet) using the [init function](ResNetConfig::init).
+#[derive(Config, Debug)]
+pub struct ResNetConfig {
+    /// The number of residual blocks of each of the four layers.
+    pub blocks: [usize; 4],
+    /// The number of classes.
+    pub num_classes: usize,
+    /// If the residual blocks are bottleneck blocks, with an expansion of 4, instead of basic
+    /// blocks.
+    #[config(default = false)]
+    pub bottleneck: bool,
+    /// The number of channels of the images.
+    #[config(default = 3)]
+    pub in_channels: usize,
+}
+
+/// The residual network from the paper
+/// [Deep Residual Learning for Image Recognition](https://arxiv.org/abs/1512.03385), following
+/// the torchvision implementation, with the stride of the bottleneck blocks on their 3x3
+/// convolution.
+///
+/// The parameters are named after the torchvision ones, so pretrained weights can be loaded with
+/// the [RESNET_KEY_REMAP] key remapping.
+///
+/// Should be created using [ResNetConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct ResNet<B: Backend> {
+    /// The stem convolution.
+    pub conv1: Conv2d<B>,
+    /// The stem batch normalization.
+    pub bn1: BatchNorm<B, 2>,
+    /// The stem max pooling.
+    pub maxpool: MaxPool2d,
+    /// The first layer of residual blocks.
+    pub layer1: Vec<ResidualBlock<B>>,
+    /// The second layer of residual blocks.
+    pub layer2: Vec<ResidualBlock<B>>,
+    /// The third layer of residual blocks.
+    pub layer3: Vec<ResidualBlock<B>>,
+    /// The fourth layer of residual blocks.
+    pub layer4: Vec<ResidualBlock<B>>,
+    /// The classification layer.
+    pub fc: Linear<B>,
+}
+
+/// A residual block of a [ResNet], either a basic block with two 3x3 convolutions or a bottleneck
+/// block with a 3x3 convolution between two 1x1 convolutions.
+#[derive(Module, Debug)]
+pub struct ResidualBlock<B: Backend> {
+    /// The first convolution.
+    pub conv1: Conv2d<B>,
+    /// The first batch normalization.
+    pub bn1: BatchNorm<B, 2>,
+    /// The second convolution.
+    pub conv2: Conv2d<B>,
+    /// The second batch normalization.
+    pub bn2: BatchNorm<B, 2>,
+    /// The third convolution of bottleneck blocks.
+    pub conv3: Option<Conv2d<B>>,
+    /// The third batch normalization of bottleneck blocks.
+    pub bn3: Option<BatchNorm<B, 2>>,
+    /// The projection of the identity when the shape changes.
+    pub downsample: Option<Downsample<B>>,
+}
+
+/// The projection of the identity of a [residual block](ResidualBlock).
+#[derive(Module, Debug)]
+pub struct Downsample<B: Backend> {
+    /// The pointwise convolution.
+    pub conv: Conv2d<B>,
+    /// The batch normalization.
+    pub bn: BatchNorm<B, 2>,
+}
+
+impl<B: Backend> ModuleDisplay for ResNet<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let blocks = [
+            self.layer1.len(),
+            self.layer2.len(),
+            self.layer3.len(),
+            self.layer4.len(),
+        ];
+        let [_, num_classes] = self.fc.weight.shape().dims();
+
+        content
+            .add("blocks", &alloc::format!("{:?}", blocks))
+            .add("bottleneck", &self.layer1[0].conv3.is_some())
+            .add("num_classes", &num_classes)
+            .optional()
+    }
+}
+
+impl ResNetConfig {
+    /// The configuration of ResNet-18.
+    pub fn resnet18(num_classes: usize) -> Self {
+        Self::new([2, 2, 2, 2], num_classes)
+    }
+
+    /// The configuration of ResNet-34.
+    pub fn resnet34(num_classes: usize) -> Self {
+        Self::new([3, 4, 6, 3], num_classes)
+    }
+
+    /// The configuration of ResNet-50.
+    pub fn resnet50(num_classes: usize) -> Self {
+        Self::new([3, 4, 6, 3], num_classes).with_bottleneck(true)
+    }
+
+    /// The configuration of ResNet-101.
+    pub fn resnet101(num_classes: usize) -> Self {
+        Self::new([3, 4, 23, 3], num_classes).with_bottleneck(true)
+    }
+
+    /// The configuration of ResNet-152.
+    pub fn resnet152(num_classes: usize) -> Self {
+        Self::new([3, 8, 36, 3], num_classes).with_bottleneck(true)
+    }
+
+    /// Initialize a new [ResNet](ResNet).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> ResNet<B> {
+        let expansion = match self.bottleneck {
+            true => 4,
+            false => 1,
+        };
+        let mut channels_in = 64;
+        let mut layers = Vec::with_capacity(4);
+
+        for ((channels, stride), num_blocks) in [64, 128, 256, 512]
+            .into_iter()
+            .zip([1, 2, 2, 2])
+            .zip(self.blocks)
+        {
+            let mut layer = Vec::with_capacity(num_blocks);
+
+            for i in 0..num_blocks {
+                let stride = if i == 0 { stride } else { 1 };
+                layer.push(self.init_block(channels_in, channels, stride, device));
+                channels_in = channels * expansion;
+            }
+
+            layers.push(layer);
+        }
+        let [layer1, layer2, layer3, layer4] = layers.try_into().unwrap();
+
+        ResNet {
+            conv1: conv(self.in_channels, 64, 7, 2, device),
+            bn1: BatchNormConfig::new(64).init(device),
+            maxpool: MaxPool2dConfig::new([3, 3])
+                .with_strides([2, 2])
+                .with_padding(PaddingConfig2d::Explicit(1, 1))
+                .init(),
+            layer1,
+            layer2,
+            layer3,
+            layer4,
+            fc: LinearConfig::new(512 * expansion, self.num_classes).init(device),
+        }
+    }
+
+    fn init_block<B: Backend>(
+        &self,
+        channels_in: usize,
+        channels: usize,
+        stride: usize,
+        device: &B::Device,
+    ) -> ResidualBlock<B> {
+        let bn = |channels| BatchNormConfig::new(channels).init(device);
+        let channels_out = match self.bottleneck {
+            true => channels * 4,
+            false => channels,
+        };
+        let downsample = (stride != 1 || channels_in != channels_out).then(|| Downsample {
+            conv: conv(channels_in, channels_out, 1, stride, device),
+            bn: bn(channels_out),
+        });
+
+        match self.bottleneck {
+            true => ResidualBlock {
+                conv1: conv(channels_in, channels, 1, 1, device),
+                bn1: bn(channels),
+                conv2: conv(channels, channels, 3, stride, device),
+                bn2: bn(channels),
+                conv3: Some(conv(channels, channels_out, 1, 1, device)),
+                bn3: Some(bn(channels_out)),
+                downsample,
+            },
+            false => ResidualBlock {
+                conv1: conv(channels_in, channels, 3, stride, device),
+                bn1: bn(channels),
+                conv2: conv(channels, channels, 3, 1, device),
+                bn2: bn(channels),
+                conv3: None,
+                bn3: None,
+                downsample,
+            },
+        }
+    }
+}
+
+/// A convolution without bias, padded to keep the spatial size with a unit stride.
+fn conv<B: Backend>(
+    channels_in: usize,
+    channels_out: usize,
+    kernel_size: usize,
+    stride: usize,
+    device: &B::Device,
+) -> Conv2d<B> {
+    let padding = (kernel_size - 1) / 2;
+
+    Conv2dConfig::new([channels_in, channels_out], [kernel_size, kernel_size])
+        .with_stride([stride, stride])
+        .with_padding(PaddingConfig2d::Explicit(padding, padding))
+        .with_bias(false)
+        .with_initializer(Initializer::KaimingNormal {
+            gain: core::f64::consts::SQRT_2,
+            fan_out_only: true,
+        })
+        .init(device)
+}
+
+impl<B: Backend> ResNet<B> {
+    /// Computes the class logits of the images.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, in_channels, height, width]`
+    /// - output: `[batch_size, num_classes]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 2> {
+        let features = self.forward_features(input);
+        let pooled = features.mean_dim(2).mean_dim(3).flatten(1, 3);
+
+        self.fc.forward(pooled)
+    }
+
+    /// Computes the feature maps of the last layer, before the global pooling.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, in_channels, height, width]`
+    /// - output: `[batch_size, channels, height / 32, width / 32]`
+    pub fn forward_features(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let x = relu(self.bn1.forward(self.conv1.forward(input)));
+        let x = self.maxpool.forward(x);
+
+        [&self.layer1, &self.layer2, &self.layer3, &self.layer4]
+            .into_iter()
+            .flatten()
+            .fold(x, |x, block| block.forward(x))
+    }
+}
+
+impl<B: Backend> ResidualBlock<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, height_in, width_in]`
+    /// - output: `[batch_size, channels_out, height_out, width_out]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let identity = match &self.downsample {
+            Some(downsample) => downsample
+                .bn
+                .forward(downsample.conv.forward(input.clone())),
+            None => input.clone(),
+        };
+
+        let x = relu(self.bn1.forward(self.conv1.forward(input)));
+        let mut x = self.bn2.forward(self.conv2.forward(x));
+
+        if let (Some(conv3), Some(bn3)) = (&self.conv3, &self.bn3) {
+            x = bn3.forward(conv3.forward(relu(x)));
+        }
+
+        relu(x + identity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Distribution;
+    use crate::TestBackend;
+
+    #[test]
+    fn resnet_basic_forward_shape() {
+        let device = Default::default();
+        let model = ResNetConfig::new([1, 1, 1, 1], 10).init::<TestBackend>(&device);
+        let input =
+            Tensor::<TestBackend, 4>::random([2, 3, 32, 32], Distribution::Default, &device);
+
+        assert_eq!(model.forward_features(input.clone()).dims(), [2, 512, 1, 1]);
+        assert_eq!(model.forward(input).dims(), [2, 10]);
+    }
+
+    #[test]
+    fn resnet_bottleneck_forward_shape() {
+        let device = Default::default();
+        let model = ResNetConfig::new([1, 2, 1, 1], 5)
+            .with_bottleneck(true)
+            .init::<TestBackend>(&device);
+        let input =
+            Tensor::<TestBackend, 4>::random([1, 3, 64, 64], Distribution::Default, &device);
+
+        assert_eq!(
+            model.forward_features(input.clone()).dims(),
+            [1, 2048, 2, 2]
+        );
+        assert_eq!(model.forward(input).dims(), [1, 5]);
+        assert!(model.layer2[0].downsample.is_some());
+        assert!(model.layer2[1].downsample.is_none());
+    }
+
+    #[test]
+    fn resnet18_num_params() {
+        let model = ResNetConfig::resnet18(1000).init::<TestBackend>(&Default::default());
+
+        // The torchvision ResNet-18 has 11,689,512 parameters, and 9,600 batch normalization
+        // running statistics.
+        assert_eq!(model.num_params(), 11_689_512 + 9_600);
+    }
+
+    #[test]
+    fn display() {
+        let model = ResNetConfig::new([1, 1, 1, 1], 10).init::<TestBackend>(&Default::default());
+
+        assert!(alloc::format!("{}", model)
+            .starts_with("ResNet {blocks: [1, 1, 1, 1], bottleneck: false, num_classes: 10"));
+    }
+}
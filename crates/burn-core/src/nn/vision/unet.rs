@@ -0,0 +1,232 @@
+use crate as burn;
+
+use alloc::vec::Vec;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::nn::conv::{Conv2d, Conv2dConfig, ConvTranspose2d, ConvTranspose2dConfig};
+use crate::nn::pool::{MaxPool2d, MaxPool2dConfig};
+use crate::tensor::{backend::Backend, Tensor};
+
+use super::{ConvNormActivation, ConvNormActivationConfig};
+
+/// Configuration to create a [U-Net](UNet) using the [init function](UNetConfig::init).
+#[derive(Config, Debug)]
+pub struct UNetConfig {
+    /// The number of channels of the images.
+    pub in_channels: usize,
+    /// The number of classes predicted for each pixel.
+    pub num_classes: usize,
+    /// The number of channels of the first level, doubled at each following level.
+    #[config(default = 64)]
+    pub base_channels: usize,
+    /// The number of downsampling steps.
+    #[config(default = 4)]
+    pub depth: usize,
+}
+
+/// The U-Net from the paper
+/// [U-Net: Convolutional Networks for Biomedical Image Segmentation](https://arxiv.org/abs/1505.04597),
+/// with padded convolutions and batch normalizations, so the output has the size of the input.
+///
+/// The encoder halves the spatial size and doubles the channels at each level with a max pooling
+/// followed by two 3x3 convolutions. The decoder upsamples with transposed convolutions and
+/// concatenates the feature maps of the matching encoder level before its two convolutions.
+///
+/// Should be created using [UNetConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct UNet<B: Backend> {
+    /// The convolutions of the first level.
+    pub inc: DoubleConv<B>,
+    /// The convolutions of each downsampling level.
+    pub down: Vec<DoubleConv<B>>,
+    /// The blocks of each upsampling level, from the deepest one.
+    pub up: Vec<UNetUp<B>>,
+    /// The pointwise convolution computing the class logits.
+    pub outc: Conv2d<B>,
+    /// The max pooling halving the spatial size.
+    pub pool: MaxPool2d,
+}
+
+/// Two 3x3 convolutions, each followed by a batch normalization and a ReLU.
+#[derive(Module, Debug)]
+pub struct DoubleConv<B: Backend> {
+    /// The first convolution.
+    pub conv1: ConvNormActivation<B>,
+    /// The second convolution.
+    pub conv2: ConvNormActivation<B>,
+}
+
+/// An upsampling level of a [U-Net](UNet).
+#[derive(Module, Debug)]
+pub struct UNetUp<B: Backend> {
+    /// The transposed convolution doubling the spatial size and halving the channels.
+    pub up: ConvTranspose2d<B>,
+    /// The convolutions applied on the upsampled and skipped feature maps.
+    pub conv: DoubleConv<B>,
+}
+
+impl<B: Backend> ModuleDisplay for UNet<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [num_classes, base_channels, _, _] = self.outc.weight.shape().dims();
+
+        content
+            .add("base_channels", &base_channels)
+            .add("depth", &self.down.len())
+            .add("num_classes", &num_classes)
+            .optional()
+    }
+}
+
+impl UNetConfig {
+    /// Initialize a new [U-Net](UNet).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> UNet<B> {
+        let double_conv = |channels_in, channels_out| DoubleConv {
+            conv1: ConvNormActivationConfig::new([channels_in, channels_out], 3).init(device),
+            conv2: ConvNormActivationConfig::new([channels_out, channels_out], 3).init(device),
+        };
+        let channels = |level: usize| self.base_channels << level;
+
+        UNet {
+            inc: double_conv(self.in_channels, channels(0)),
+            down: (1..=self.depth)
+                .map(|level| double_conv(channels(level - 1), channels(level)))
+                .collect(),
+            up: (1..=self.depth)
+                .rev()
+                .map(|level| UNetUp {
+                    up: ConvTranspose2dConfig::new([channels(level), channels(level - 1)], [2, 2])
+                        .with_stride([2, 2])
+                        .init(device),
+                    conv: double_conv(channels(level), channels(level - 1)),
+                })
+                .collect(),
+            outc: Conv2dConfig::new([channels(0), self.num_classes], [1, 1]).init(device),
+            pool: MaxPool2dConfig::new([2, 2]).with_strides([2, 2]).init(),
+        }
+    }
+}
+
+impl<B: Backend> UNet<B> {
+    /// Computes the class logits of each pixel.
+    ///
+    /// The sizes not divisible by `2^depth` are handled by padding the upsampled feature maps to
+    /// the size of the skipped ones.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, in_channels, height, width]`
+    /// - output: `[batch_size, num_classes, height, width]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let mut x = self.inc.forward(input);
+        let mut skips = Vec::with_capacity(self.down.len());
+
+        for down in self.down.iter() {
+            skips.push(x.clone());
+            x = down.forward(self.pool.forward(x));
+        }
+
+        for (up, skip) in self.up.iter().zip(skips.into_iter().rev()) {
+            x = up.forward(x, skip);
+        }
+
+        self.outc.forward(x)
+    }
+}
+
+impl<B: Backend> DoubleConv<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, height, width]`
+    /// - output: `[batch_size, channels_out, height, width]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        self.conv2.forward(self.conv1.forward(input))
+    }
+}
+
+impl<B: Backend> UNetUp<B> {
+    /// Upsamples the input and applies the convolutions on its concatenation with the skipped
+    /// feature maps.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, height / 2, width / 2]`
+    /// - skip: `[batch_size, channels_in / 2, height, width]`
+    /// - output: `[batch_size, channels_in / 2, height, width]`
+    pub fn forward(&self, input: Tensor<B, 4>, skip: Tensor<B, 4>) -> Tensor<B, 4> {
+        let x = self.up.forward(input);
+        let [_, _, height, width] = x.dims();
+        let [_, _, height_skip, width_skip] = skip.dims();
+
+        let x = match height < height_skip || width < width_skip {
+            true => {
+                let (pad_height, pad_width) = (height_skip - height, width_skip - width);
+                x.pad(
+                    (
+                        pad_width / 2,
+                        pad_width - pad_width / 2,
+                        pad_height / 2,
+                        pad_height - pad_height / 2,
+                    ),
+                    0.0,
+                )
+            }
+            false => x,
+        };
+
+        self.conv.forward(Tensor::cat(alloc::vec![skip, x], 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Distribution;
+    use crate::TestBackend;
+
+    #[test]
+    fn unet_forward_shape() {
+        let device = Default::default();
+        let model = UNetConfig::new(3, 2)
+            .with_base_channels(4)
+            .with_depth(3)
+            .init::<TestBackend>(&device);
+        let input =
+            Tensor::<TestBackend, 4>::random([2, 3, 16, 24], Distribution::Default, &device);
+
+        assert_eq!(model.forward(input).dims(), [2, 2, 16, 24]);
+    }
+
+    #[test]
+    fn unet_should_handle_sizes_not_divisible_by_the_depth() {
+        let device = Default::default();
+        let model = UNetConfig::new(1, 3)
+            .with_base_channels(4)
+            .with_depth(2)
+            .init::<TestBackend>(&device);
+        let input =
+            Tensor::<TestBackend, 4>::random([1, 1, 13, 10], Distribution::Default, &device);
+
+        assert_eq!(model.forward(input).dims(), [1, 3, 13, 10]);
+    }
+
+    #[test]
+    fn display() {
+        let model = UNetConfig::new(3, 2)
+            .with_base_channels(4)
+            .with_depth(3)
+            .init::<TestBackend>(&Default::default());
+
+        assert!(alloc::format!("{}", model)
+            .starts_with("UNet {base_channels: 4, depth: 3, num_classes: 2"));
+    }
+}
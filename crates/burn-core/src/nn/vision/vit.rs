@@ -0,0 +1,451 @@
+use crate as burn;
+
+use alloc::vec::Vec;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay, Param};
+use crate::nn::{
+    Dropout, DropoutConfig, Initializer, LayerNorm, LayerNormConfig, Linear, LinearConfig,
+};
+use crate::tensor::activation::{gelu, softmax};
+use crate::tensor::{backend::Backend, Tensor};
+
+use super::{PatchEmbedding, PatchEmbeddingConfig};
+
+/// The key remapping from the torchvision ViT weights to the [VisionTransformer] module, to be
+/// applied when loading PyTorch weights with `burn-import`.
+pub const VIT_KEY_REMAP: &[(&str, &str)] = &[
+    (r"^conv_proj\.", "patch_embedding.proj."),
+    (
+        r"^encoder\.layers\.encoder_layer_(\d+)\.",
+        "encoder.layers.$1.",
+    ),
+    (
+        r"\.self_attention\.in_proj_(weight|bias)$",
+        ".self_attention.in_proj.$1",
+    ),
+    (r"\.mlp\.0\.", ".mlp.linear_1."),
+    (r"\.mlp\.3\.", ".mlp.linear_2."),
+    (r"^heads\.head\.", "head."),
+];
+
+/// Configuration to create a [vision transformer](VisionTransformer) using the
+/// [init function](VisionTransformerConfig::init).
+#[derive(Config, Debug)]
+pub struct VisionTransformerConfig {
+    /// The size of the square patches.
+    pub patch_size: usize,
+    /// The number of encoder layers.
+    pub num_layers: usize,
+    /// The number of attention heads.
+    pub num_heads: usize,
+    /// The size of the embeddings.
+    pub d_model: usize,
+    /// The size of the hidden features of the feed-forward networks.
+    pub d_mlp: usize,
+    /// The number of classes.
+    pub num_classes: usize,
+    /// The size of the square images.
+    #[config(default = 224)]
+    pub image_size: usize,
+    /// The number of channels of the images.
+    #[config(default = 3)]
+    pub in_channels: usize,
+    /// The dropout rate.
+    #[config(default = 0.0)]
+    pub dropout: f64,
+    /// The dropout rate of the attention weights.
+    #[config(default = 0.0)]
+    pub attention_dropout: f64,
+    /// The epsilon of the layer normalizations.
+    #[config(default = 1e-6)]
+    pub layer_norm_eps: f64,
+}
+
+/// The vision transformer from the paper
+/// [An Image is Worth 16x16 Words: Transformers for Image Recognition at Scale](https://arxiv.org/abs/2010.11929),
+/// following the torchvision implementation.
+///
+/// The images are split into patches, embedded with a learned position embedding and a class
+/// token, and encoded by pre-norm transformer layers. The classes are predicted from the encoded
+/// class token.
+///
+/// The parameters are named after the torchvision ones, so pretrained weights can be loaded with
+/// the [VIT_KEY_REMAP] key remapping.
+///
+/// Should be created using [VisionTransformerConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct VisionTransformer<B: Backend> {
+    /// The embedding of the patches.
+    pub patch_embedding: PatchEmbedding<B>,
+    /// The class token, of shape `[1, 1, d_model]`.
+    pub class_token: Param<Tensor<B, 3>>,
+    /// The transformer encoder.
+    pub encoder: VitEncoder<B>,
+    /// The classification layer.
+    pub head: Linear<B>,
+}
+
+/// The encoder of a [vision transformer](VisionTransformer).
+#[derive(Module, Debug)]
+pub struct VitEncoder<B: Backend> {
+    /// The position embedding, of shape `[1, num_patches + 1, d_model]`.
+    pub pos_embedding: Param<Tensor<B, 3>>,
+    /// The encoder layers.
+    pub layers: Vec<VitEncoderLayer<B>>,
+    /// The final layer normalization.
+    pub ln: LayerNorm<B>,
+    /// The dropout applied to the embeddings.
+    pub dropout: Dropout,
+}
+
+/// A pre-norm transformer layer of a [vision transformer](VisionTransformer).
+#[derive(Module, Debug)]
+pub struct VitEncoderLayer<B: Backend> {
+    /// The layer normalization before the attention.
+    pub ln_1: LayerNorm<B>,
+    /// The self-attention.
+    pub self_attention: VitAttention<B>,
+    /// The layer normalization before the feed-forward network.
+    pub ln_2: LayerNorm<B>,
+    /// The feed-forward network.
+    pub mlp: VitMlp<B>,
+    /// The dropout applied to the attention output.
+    pub dropout: Dropout,
+}
+
+/// The multi-head self-attention of a [vision transformer](VisionTransformer), with the query,
+/// key and value projections packed in a single linear layer.
+#[derive(Module, Debug)]
+pub struct VitAttention<B: Backend> {
+    /// The packed query, key and value projections.
+    pub in_proj: Linear<B>,
+    /// The output projection.
+    pub out_proj: Linear<B>,
+    /// The dropout applied to the attention weights.
+    pub dropout: Dropout,
+    /// The number of attention heads.
+    pub num_heads: usize,
+}
+
+/// The feed-forward network of a [vision transformer](VisionTransformer).
+#[derive(Module, Debug)]
+pub struct VitMlp<B: Backend> {
+    /// The first linear layer.
+    pub linear_1: Linear<B>,
+    /// The second linear layer.
+    pub linear_2: Linear<B>,
+    /// The dropout applied after each linear layer.
+    pub dropout: Dropout,
+}
+
+impl<B: Backend> ModuleDisplay for VisionTransformer<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [_, seq_length, d_model] = self.encoder.pos_embedding.shape().dims();
+        let [_, num_classes] = self.head.weight.shape().dims();
+
+        content
+            .add("num_patches", &(seq_length - 1))
+            .add("num_layers", &self.encoder.layers.len())
+            .add("d_model", &d_model)
+            .add("num_classes", &num_classes)
+            .optional()
+    }
+}
+
+impl VisionTransformerConfig {
+    /// The configuration of ViT-B/16.
+    pub fn vit_b_16(num_classes: usize) -> Self {
+        Self::new(16, 12, 12, 768, 3072, num_classes)
+    }
+
+    /// The configuration of ViT-B/32.
+    pub fn vit_b_32(num_classes: usize) -> Self {
+        Self::new(32, 12, 12, 768, 3072, num_classes)
+    }
+
+    /// The configuration of ViT-L/16.
+    pub fn vit_l_16(num_classes: usize) -> Self {
+        Self::new(16, 24, 16, 1024, 4096, num_classes)
+    }
+
+    /// The configuration of ViT-L/32.
+    pub fn vit_l_32(num_classes: usize) -> Self {
+        Self::new(32, 24, 16, 1024, 4096, num_classes)
+    }
+
+    /// The configuration of ViT-H/14.
+    pub fn vit_h_14(num_classes: usize) -> Self {
+        Self::new(14, 32, 16, 1280, 5120, num_classes)
+    }
+
+    /// Initialize a new [vision transformer](VisionTransformer).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> VisionTransformer<B> {
+        assert_eq!(
+            self.image_size % self.patch_size,
+            0,
+            "The image size {} should be divisible by the patch size {}.",
+            self.image_size,
+            self.patch_size
+        );
+        assert_eq!(
+            self.d_model % self.num_heads,
+            0,
+            "The embedding size {} should be divisible by the number of heads {}.",
+            self.d_model,
+            self.num_heads
+        );
+
+        let d_model = self.d_model;
+        let num_patches = (self.image_size / self.patch_size).pow(2);
+        let layer_norm = || {
+            LayerNormConfig::new(d_model)
+                .with_epsilon(self.layer_norm_eps)
+                .init(device)
+        };
+        let dropout = || DropoutConfig::new(self.dropout).init();
+
+        let layers = (0..self.num_layers)
+            .map(|_| VitEncoderLayer {
+                ln_1: layer_norm(),
+                self_attention: VitAttention {
+                    in_proj: LinearConfig::new(d_model, 3 * d_model).init(device),
+                    out_proj: LinearConfig::new(d_model, d_model).init(device),
+                    dropout: DropoutConfig::new(self.attention_dropout).init(),
+                    num_heads: self.num_heads,
+                },
+                ln_2: layer_norm(),
+                mlp: VitMlp {
+                    linear_1: LinearConfig::new(d_model, self.d_mlp).init(device),
+                    linear_2: LinearConfig::new(self.d_mlp, d_model).init(device),
+                    dropout: dropout(),
+                },
+                dropout: dropout(),
+            })
+            .collect();
+
+        VisionTransformer {
+            patch_embedding: PatchEmbeddingConfig::new(self.in_channels, self.patch_size, d_model)
+                .init(device),
+            class_token: Initializer::Zeros.init([1, 1, d_model], device),
+            encoder: VitEncoder {
+                pos_embedding: Initializer::Normal {
+                    mean: 0.0,
+                    std: 0.02,
+                }
+                .init([1, num_patches + 1, d_model], device),
+                layers,
+                ln: layer_norm(),
+                dropout: dropout(),
+            },
+            head: LinearConfig::new(d_model, self.num_classes)
+                .with_initializer(Initializer::Zeros)
+                .init(device),
+        }
+    }
+}
+
+impl<B: Backend> VisionTransformer<B> {
+    /// Computes the class logits of the images.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, in_channels, image_size, image_size]`
+    /// - output: `[batch_size, num_classes]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 2> {
+        let encoded = self.forward_features(input);
+        let class_token = encoded.narrow(1, 0, 1).squeeze(1);
+
+        self.head.forward(class_token)
+    }
+
+    /// Computes the encoded class token followed by the encoded patches.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, in_channels, image_size, image_size]`
+    /// - output: `[batch_size, num_patches + 1, d_model]`
+    pub fn forward_features(&self, input: Tensor<B, 4>) -> Tensor<B, 3> {
+        let patches = self.patch_embedding.forward(input);
+        let [batch_size, _, d_model] = patches.dims();
+        let class_token = self.class_token.val().expand([batch_size, 1, d_model]);
+
+        self.encoder
+            .forward(Tensor::cat(alloc::vec![class_token, patches], 1))
+    }
+}
+
+impl<B: Backend> VitEncoder<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, num_patches + 1, d_model]`
+    /// - output: `[batch_size, num_patches + 1, d_model]`
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let x = self.dropout.forward(input + self.pos_embedding.val());
+        let x = self.layers.iter().fold(x, |x, layer| layer.forward(x));
+
+        self.ln.forward(x)
+    }
+}
+
+impl<B: Backend> VitEncoderLayer<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, seq_length, d_model]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let x = self
+            .self_attention
+            .forward(self.ln_1.forward(input.clone()));
+        let x = self.dropout.forward(x) + input;
+
+        self.mlp.forward(self.ln_2.forward(x.clone())) + x
+    }
+}
+
+impl<B: Backend> VitAttention<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, seq_length, d_model]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let [batch_size, seq_length, d_model] = input.dims();
+        let d_head = d_model / self.num_heads;
+
+        let qkv = self.in_proj.forward(input).reshape([
+            batch_size,
+            seq_length,
+            3,
+            self.num_heads,
+            d_head,
+        ]);
+        let heads = |index| {
+            qkv.clone()
+                .narrow(2, index, 1)
+                .reshape([batch_size, seq_length, self.num_heads, d_head])
+                .swap_dims(1, 2)
+        };
+        let (query, key, value) = (heads(0), heads(1), heads(2));
+
+        let scores = query
+            .matmul(key.transpose())
+            .div_scalar((d_head as f64).sqrt());
+        let weights = self.dropout.forward(softmax(scores, 3));
+        let output = weights
+            .matmul(value)
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length, d_model]);
+
+        self.out_proj.forward(output)
+    }
+}
+
+impl<B: Backend> VitMlp<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, seq_length, d_model]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let x = self.dropout.forward(gelu(self.linear_1.forward(input)));
+
+        self.dropout.forward(self.linear_2.forward(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::attention::MultiHeadAttentionConfig;
+    use crate::tensor::Distribution;
+    use crate::TestBackend;
+
+    #[test]
+    fn vit_forward_shape() {
+        let device = Default::default();
+        let model = VisionTransformerConfig::new(4, 2, 2, 16, 32, 10)
+            .with_image_size(16)
+            .init::<TestBackend>(&device);
+        let input =
+            Tensor::<TestBackend, 4>::random([2, 3, 16, 16], Distribution::Default, &device);
+
+        assert_eq!(model.forward_features(input.clone()).dims(), [2, 17, 16]);
+        assert_eq!(model.forward(input).dims(), [2, 10]);
+    }
+
+    #[test]
+    fn vit_attention_should_match_multi_head_attention() {
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(8, 2).init::<TestBackend>(&device);
+        let weight = Tensor::cat(
+            alloc::vec![
+                mha.query.weight.val(),
+                mha.key.weight.val(),
+                mha.value.weight.val()
+            ],
+            1,
+        );
+        let bias = Tensor::cat(
+            alloc::vec![
+                mha.query.bias.as_ref().unwrap().val(),
+                mha.key.bias.as_ref().unwrap().val(),
+                mha.value.bias.as_ref().unwrap().val()
+            ],
+            0,
+        );
+        let mut in_proj = LinearConfig::new(8, 24).init(&device);
+        in_proj.weight = Param::from_tensor(weight);
+        in_proj.bias = Some(Param::from_tensor(bias));
+        let attention = VitAttention {
+            in_proj,
+            out_proj: mha.output.clone(),
+            dropout: DropoutConfig::new(0.0).init(),
+            num_heads: 2,
+        };
+        let input = Tensor::<TestBackend, 3>::random([2, 5, 8], Distribution::Default, &device);
+
+        let expected = mha
+            .forward(crate::nn::attention::MhaInput::self_attn(input.clone()))
+            .context;
+
+        attention
+            .forward(input)
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn vit_b_16_num_params() {
+        let mut config = VisionTransformerConfig::vit_b_16(1000);
+        config.num_layers = 1;
+        let model = config.init::<TestBackend>(&Default::default());
+
+        // The torchvision ViT-B/16 has 86,567,656 parameters, 7,087,872 per encoder layer.
+        assert_eq!(model.num_params(), 86_567_656 - 11 * 7_087_872);
+    }
+
+    #[test]
+    fn display() {
+        let model = VisionTransformerConfig::new(4, 2, 2, 16, 32, 10)
+            .with_image_size(16)
+            .init::<TestBackend>(&Default::default());
+
+        assert!(alloc::format!("{}", model).starts_with(
+            "VisionTransformer {num_patches: 16, num_layers: 2, d_model: 16, num_classes: 10"
+        ));
+    }
+}
@@ -10,7 +10,7 @@ use super::{
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
 use crate::tensor::{backend::AutodiffBackend, Tensor};
-use burn_tensor::{backend::Backend, ops::Device, ElementConversion};
+use burn_tensor::{backend::Backend, ops::Device, ElementConversion, Int};
 
 #[cfg(not(feature = "std"))]
 use num_traits::Float;
@@ -75,6 +75,31 @@ impl<B: Backend> SimpleOptimizer<B> for Adam {
         (tensor - delta, Some(state))
     }
 
+    fn step_rows<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        rows: Tensor<B, 1, Int>,
+        mut grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        if let Some(weight_decay) = &self.weight_decay {
+            grad = weight_decay.transform(grad, tensor.clone().select(0, rows.clone()));
+        }
+
+        let (grad, state_momentum) = self.momentum.transform_rows(
+            grad,
+            rows.clone(),
+            tensor.dims(),
+            state.map(|state| state.momentum),
+        );
+
+        let state = AdamState::new(state_momentum);
+        let delta = grad.mul_scalar(lr);
+
+        (tensor.select_assign(0, rows, delta.neg()), Some(state))
+    }
+
     fn to_device<const D: usize>(mut state: Self::State<D>, device: &Device<B>) -> Self::State<D> {
         state.momentum = state.momentum.to_device(device);
         state
@@ -169,6 +194,49 @@ impl AdaptiveMomentum {
 
         (grad, state)
     }
+
+    /// Transforms the gradient of some rows of a tensor, only updating the moments of those rows.
+    ///
+    /// Like the lazy variant of Adam, the moments of the other rows are not decayed, while the
+    /// number of iterations used for the bias correction is shared by all rows.
+    pub fn transform_rows<B: Backend, const D: usize>(
+        &self,
+        grad: Tensor<B, D>,
+        rows: Tensor<B, 1, Int>,
+        dims: [usize; D],
+        momentum_state: Option<AdaptiveMomentumState<B, D>>,
+    ) -> (Tensor<B, D>, AdaptiveMomentumState<B, D>) {
+        let (time, moment_1, moment_2) = match momentum_state {
+            Some(state) => (state.time, state.moment_1, state.moment_2),
+            None => {
+                let device = grad.device();
+                (
+                    0,
+                    Tensor::zeros(dims, &device),
+                    Tensor::zeros(dims, &device),
+                )
+            }
+        };
+        let moment_1_rows = moment_1.clone().select(0, rows.clone());
+        let moment_2_rows = moment_2.clone().select(0, rows.clone());
+
+        let (grad, state_rows) = self.transform(
+            grad,
+            Some(AdaptiveMomentumState::new(
+                time,
+                moment_1_rows.clone(),
+                moment_2_rows.clone(),
+            )),
+        );
+
+        let moment_1 = moment_1.select_assign(0, rows.clone(), state_rows.moment_1 - moment_1_rows);
+        let moment_2 = moment_2.select_assign(0, rows, state_rows.moment_2 - moment_2_rows);
+
+        (
+            grad,
+            AdaptiveMomentumState::new(state_rows.time, moment_1, moment_2),
+        )
+    }
 }
 
 impl<B: Backend, const D: usize> AdaptiveMomentumState<B, D> {
@@ -357,6 +425,54 @@ mod tests {
         assert!(!state_updated.weight.to_data().as_slice::<f32>().unwrap()[0].is_nan());
     }
 
+    #[test]
+    fn test_adam_optimizer_sparse_grad_only_updates_used_rows() {
+        let device = Default::default();
+        let config = nn::EmbeddingConfig::new(5, 3);
+        let embedding_dense = config.init::<TestAutodiffBackend>(&device);
+        let embedding_sparse = nn::Embedding {
+            weight: embedding_dense.weight.clone(),
+            sparse_grad: true,
+        };
+        let mut optim_dense = AdamConfig::new().init();
+        let mut optim_sparse = AdamConfig::new().init();
+        let input_1 = Tensor::from_data([[1, 3, 1]], &device);
+        let input_2 = Tensor::from_data([[0, 3]], &device);
+
+        let grads = embedding_dense.forward(input_1.clone()).sum().backward();
+        let grads = GradientsParams::from_grads(grads, &embedding_dense);
+        let embedding_dense = optim_dense.step(LEARNING_RATE, embedding_dense, grads);
+
+        let grads = embedding_sparse.forward(input_1).sum().backward();
+        let grads = GradientsParams::from_grads(grads, &embedding_sparse);
+        let embedding_sparse = optim_sparse.step(LEARNING_RATE, embedding_sparse, grads);
+
+        // Unused rows have a zero update during the first dense step.
+        let weight_1 = embedding_sparse.weight.val();
+        weight_1
+            .clone()
+            .into_data()
+            .assert_approx_eq(&embedding_dense.weight.val().into_data(), ASSERT_PRECISION);
+
+        let grads = embedding_sparse.forward(input_2).sum().backward();
+        let grads = GradientsParams::from_grads(grads, &embedding_sparse);
+        let embedding_sparse = optim_sparse.step(LEARNING_RATE, embedding_sparse, grads);
+
+        let weight_2 = embedding_sparse.weight.val();
+        let unused = Tensor::from_data([1, 2, 4], &device);
+        let used = Tensor::from_data([0, 3], &device);
+        weight_2
+            .clone()
+            .select(0, unused.clone())
+            .into_data()
+            .assert_eq(&weight_1.clone().select(0, unused).into_data(), true);
+        assert!(weight_2
+            .select(0, used.clone())
+            .not_equal(weight_1.select(0, used))
+            .all()
+            .into_scalar());
+    }
+
     fn given_linear_layer(weight: TensorData, bias: TensorData) -> nn::Linear<TestAutodiffBackend> {
         let device = Default::default();
         let record = nn::LinearRecord {
@@ -0,0 +1,457 @@
+use core::marker::PhantomData;
+
+use crate as burn;
+use crate::config::Config;
+use crate::module::{Module, ModuleMapper, ModuleVisitor, ParamId};
+use crate::record::Record;
+
+use burn_tensor::{backend::Backend, container::TensorContainer, Tensor};
+
+/// Configuration to create a [model exponential moving average](ModelEma).
+#[derive(Config, Debug)]
+pub struct ModelEmaConfig {
+    /// The decay of the moving average, the closer to one the slower the average changes.
+    #[config(default = 0.9999)]
+    pub decay: f64,
+    /// When enabled, the decay used for an update is `min(decay, (1 + step) / (10 + step))`, so the
+    /// average is not dominated by the initial weights during the first updates.
+    #[config(default = true)]
+    pub warmup: bool,
+}
+
+impl ModelEmaConfig {
+    /// Initialize a new [model exponential moving average](ModelEma) starting from the given model.
+    pub fn init<B: Backend, M: Module<B>>(&self, model: &M) -> ModelEma<B, M> {
+        ModelEma {
+            model: model.clone(),
+            decay: self.decay,
+            warmup: self.warmup,
+            step: 0,
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// Exponential moving average (EMA) of the weights of a model.
+///
+/// A shadow copy of the model is kept and each [update](ModelEma::update) moves its float tensors
+/// toward the ones of the trained model:
+///
+/// `shadow = decay * shadow + (1 - decay) * weight`
+///
+/// Running states, such as the batch norm statistics, are averaged the same way as parameters.
+///
+/// The model can be configured with [ModelEmaConfig](ModelEmaConfig).
+#[derive(Clone, Debug)]
+pub struct ModelEma<B: Backend, M: Module<B>> {
+    model: M,
+    decay: f64,
+    warmup: bool,
+    step: usize,
+    phantom: PhantomData<B>,
+}
+
+impl<B: Backend, M: Module<B>> ModelEma<B, M> {
+    /// Update the moving average with the current weights of the given model.
+    pub fn update(&mut self, model: &M) {
+        let decay = self.decay();
+        let mut mapper = AveragingMapper::<B>::new(collect_tensors(model), decay);
+
+        self.model = self.model.clone().map(&mut mapper);
+        self.step += 1;
+    }
+
+    /// The decay that will be used for the next update.
+    pub fn decay(&self) -> f64 {
+        match self.warmup {
+            true => {
+                let step = self.step as f64;
+                f64::min(self.decay, (1.0 + step) / (10.0 + step))
+            }
+            false => self.decay,
+        }
+    }
+
+    /// The number of updates done so far.
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// The averaged model.
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// Consume the moving average and returns the averaged model.
+    pub fn into_model(self) -> M {
+        self.model
+    }
+
+    /// Get the current state of the moving average as a [record](ModelEmaRecord).
+    pub fn to_record(&self) -> ModelEmaRecord<B, M> {
+        ModelEmaRecord {
+            model: self.model.clone().into_record(),
+            step: self.step,
+        }
+    }
+
+    /// Load the state of the moving average from a [record](ModelEmaRecord).
+    pub fn load_record(mut self, record: ModelEmaRecord<B, M>) -> Self {
+        self.model = self.model.load_record(record.model);
+        self.step = record.step;
+        self
+    }
+}
+
+/// [Record](Record) of a [model exponential moving average](ModelEma), containing the averaged
+/// weights and the number of updates, which is needed to resume the decay warmup.
+#[derive(Record)]
+pub struct ModelEmaRecord<B: Backend, M: Module<B>> {
+    /// The record of the averaged model.
+    pub model: M::Record,
+    /// The number of updates done so far.
+    pub step: usize,
+}
+
+/// Stochastic weight averaging (SWA) of the weights of a model.
+///
+/// Contrary to the [exponential moving average](ModelEma), every
+/// [update](ModelSwa::update) has the same weight in the average, which is normally
+/// done once per epoch or every few iterations near the end of the training.
+///
+/// Since the averaged weights produce different activations than any of the averaged models, the
+/// running statistics (e.g. batch norm) should be recomputed with
+/// [update_statistics](ModelSwa::update_statistics) before using the averaged model.
+#[derive(Clone, Debug)]
+pub struct ModelSwa<B: Backend, M: Module<B>> {
+    model: M,
+    num_averaged: usize,
+    phantom: PhantomData<B>,
+}
+
+impl<B: Backend, M: Module<B>> ModelSwa<B, M> {
+    /// Create a new stochastic weight averaging, the given model being the first averaged one.
+    pub fn new(model: &M) -> Self {
+        Self {
+            model: model.clone(),
+            num_averaged: 1,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Add the current weights of the given model to the average.
+    pub fn update(&mut self, model: &M) {
+        self.num_averaged += 1;
+        let decay = (self.num_averaged - 1) as f64 / self.num_averaged as f64;
+        let mut mapper = AveragingMapper::<B>::new(collect_tensors(model), decay);
+
+        self.model = self.model.clone().map(&mut mapper);
+    }
+
+    /// The number of models averaged so far.
+    pub fn num_averaged(&self) -> usize {
+        self.num_averaged
+    }
+
+    /// The averaged model.
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// Recompute the running statistics of the averaged model and returns it.
+    ///
+    /// The `forward` function should execute a forward pass in training mode on a batch, which
+    /// updates the running states of layers such as [batch norm](crate::nn::BatchNorm) with the
+    /// given `momentum`, the one used by those layers. The backend must be an autodiff backend for
+    /// modules to be in training mode.
+    ///
+    /// The running states are reset to the cumulative average of the statistics of all the batches,
+    /// as if the layers used a momentum of `1 / n` for the `n`-th batch. The forward passes are
+    /// executed on a copy of the averaged model, which is left unchanged.
+    pub fn update_statistics<I, F>(&self, momentum: f64, batches: I, mut forward: F) -> M
+    where
+        I: IntoIterator,
+        F: FnMut(&M, I::Item),
+    {
+        assert!(
+            momentum > 0.0 && momentum <= 1.0,
+            "The momentum should be in (0, 1], got {momentum}."
+        );
+
+        // Mapping the running states creates new ones, not shared with the averaged model.
+        let mut model = self.model.clone().map(&mut IdentityMapper);
+
+        for (index, batch) in batches.into_iter().enumerate() {
+            let before = collect_tensors(&model);
+            forward(&model, batch);
+
+            let mut mapper = StatisticsMapper::<B>::new(before, momentum, index + 1);
+            model = model.map(&mut mapper);
+        }
+
+        model
+    }
+
+    /// Consume the averaging and returns the averaged model.
+    pub fn into_model(self) -> M {
+        self.model
+    }
+}
+
+/// Collect the float tensors of a module without tracking gradients.
+fn collect_tensors<B: Backend, M: Module<B>>(module: &M) -> TensorContainer<ParamId> {
+    let mut collector = TensorCollector::<B>::new(TensorContainer::new());
+    module.visit(&mut collector);
+    collector.tensors
+}
+
+#[derive(new)]
+struct TensorCollector<B: Backend> {
+    tensors: TensorContainer<ParamId>,
+    #[new(default)]
+    phantom: PhantomData<B>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for TensorCollector<B> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        self.tensors
+            .register::<B>(id, tensor.clone().set_require_grad(false).into_primitive());
+    }
+}
+
+struct IdentityMapper;
+
+impl<B: Backend> ModuleMapper<B> for IdentityMapper {}
+
+/// Replaces the running states updated by a forward pass with the cumulative average of the
+/// statistics of the batches.
+///
+/// A layer updates its state with `state = (1 - momentum) * before + momentum * statistic`, so the
+/// statistic of the batch is recovered from the state before and after the forward pass.
+///
+/// The running states are the float tensors that don't require gradients. Frozen parameters are
+/// mapped the same way, but since the forward pass doesn't update them, they keep their value.
+#[derive(new)]
+struct StatisticsMapper<B: Backend> {
+    before: TensorContainer<ParamId>,
+    momentum: f64,
+    num_batches: usize,
+    #[new(default)]
+    phantom: PhantomData<B>,
+}
+
+impl<B: Backend> ModuleMapper<B> for StatisticsMapper<B> {
+    fn map_float<const D: usize>(&mut self, id: ParamId, state: Tensor<B, D>) -> Tensor<B, D> {
+        let Some(before) = self.before.remove::<B>(&id) else {
+            return state;
+        };
+        if state.is_require_grad() {
+            return state;
+        }
+        let before = Tensor::<B, D>::from_primitive(before);
+
+        // With `before` being the average of the statistics of the previous batches, the
+        // cumulative average is `before + (statistic - before) / num_batches`, where
+        // `statistic - before = (state - before) / momentum`.
+        state
+            .sub(before.clone())
+            .div_scalar(self.momentum * self.num_batches as f64)
+            .add(before)
+    }
+}
+
+/// Computes `decay * average + (1 - decay) * tensor` for every tensor of the module.
+#[derive(new)]
+struct AveragingMapper<B: Backend> {
+    tensors: TensorContainer<ParamId>,
+    decay: f64,
+    #[new(default)]
+    phantom: PhantomData<B>,
+}
+
+impl<B: Backend> ModuleMapper<B> for AveragingMapper<B> {
+    fn map_float<const D: usize>(&mut self, id: ParamId, average: Tensor<B, D>) -> Tensor<B, D> {
+        let Some(tensor) = self.tensors.remove::<B>(&id) else {
+            return average;
+        };
+
+        let is_require_grad = average.is_require_grad();
+        let device = average.device();
+        let tensor = Tensor::<B, D>::from_primitive(tensor);
+
+        let average = average
+            .set_require_grad(false)
+            .mul_scalar(self.decay)
+            .add(tensor.to_device(&device).mul_scalar(1.0 - self.decay));
+
+        average.set_require_grad(is_require_grad)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        nn::{BatchNormConfig, Linear, LinearConfig},
+        test_utils::Fill,
+        TestAutodiffBackend, TestBackend,
+    };
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_ema_decay_warmup() {
+        let device = Default::default();
+        let layer = LinearConfig::new(2, 2).init::<TestBackend>(&device);
+        let mut ema = ModelEmaConfig::new().with_decay(0.99).init(&layer);
+
+        assert_eq!(ema.decay(), 0.1);
+        ema.update(&layer);
+        assert_eq!(ema.decay(), 2.0 / 11.0);
+
+        ema.step = 2000;
+        assert_eq!(ema.decay(), 0.99);
+    }
+
+    #[test]
+    fn test_ema_update() {
+        let device = Default::default();
+        let layer = layer_with_value::<TestAutodiffBackend>(1.0, &device);
+        let mut ema = ModelEmaConfig::new()
+            .with_decay(0.9)
+            .with_warmup(false)
+            .init(&layer);
+
+        let layer = layer.map(&mut Fill(3.0));
+        ema.update(&layer);
+        ema.update(&layer);
+
+        // 1.0 * 0.9^2 + 3.0 * (1 - 0.9^2)
+        let expected = TensorData::from([[1.38f32, 1.38], [1.38, 1.38]]);
+        let weight = ema.model().weight.val();
+        assert!(weight.is_require_grad());
+        weight.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_ema_record() {
+        let device = Default::default();
+        let layer = layer_with_value::<TestBackend>(1.0, &device);
+        let mut ema = ModelEmaConfig::new().init(&layer);
+        ema.update(&layer.clone().map(&mut Fill(2.0)));
+        ema.update(&layer.clone().map(&mut Fill(2.0)));
+
+        let restored = ModelEmaConfig::new()
+            .init(&layer)
+            .load_record(ema.to_record());
+
+        assert_eq!(restored.step(), 2);
+        assert_eq!(restored.decay(), ema.decay());
+        restored
+            .model()
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&ema.model().weight.val().into_data(), true);
+    }
+
+    #[test]
+    fn test_swa_update() {
+        let device = Default::default();
+        let layer = layer_with_value::<TestBackend>(1.0, &device);
+        let mut swa = ModelSwa::new(&layer);
+
+        swa.update(&layer.clone().map(&mut Fill(2.0)));
+        swa.update(&layer.map(&mut Fill(6.0)));
+
+        assert_eq!(swa.num_averaged(), 3);
+        swa.model()
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[3.0f32, 3.0], [3.0, 3.0]]), 3);
+    }
+
+    #[test]
+    fn test_swa_update_statistics() {
+        let device = Default::default();
+        let norm = BatchNormConfig::new(2).init::<TestAutodiffBackend, 0>(&device);
+        let swa = ModelSwa::new(&norm);
+        let batches = [
+            TensorData::from([[1.0f32, 2.0], [3.0, 6.0]]),
+            TensorData::from([[5.0f32, 0.0], [7.0, 0.0]]),
+        ];
+
+        let model = swa.update_statistics(0.1, batches, |norm, batch| {
+            norm.forward(Tensor::<_, 2>::from_data(batch, &device));
+        });
+
+        // Cumulative averages of the batch means [2, 4], [6, 0] and variances [1, 4], [1, 0].
+        model
+            .running_mean
+            .value()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([4.0f32, 2.0]), 3);
+        model
+            .running_var
+            .value()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0f32, 2.0]), 3);
+        model
+            .gamma
+            .val()
+            .into_data()
+            .assert_eq(&norm.gamma.val().into_data(), true);
+        swa.model()
+            .running_mean
+            .value()
+            .into_data()
+            .assert_eq(&TensorData::from([0.0f32, 0.0]), true);
+    }
+
+    #[test]
+    fn test_swa_update_statistics_with_unchanged_first_batch() {
+        let device = Default::default();
+        let norm = BatchNormConfig::new(2)
+            .init::<TestAutodiffBackend, 0>(&device)
+            .no_grad();
+        let swa = ModelSwa::new(&norm);
+        // The statistics of the first batch are the initial ones, mean [0, 0] and variance [1, 1].
+        let batches = [
+            TensorData::from([[-1.0f32, -1.0], [1.0, 1.0]]),
+            TensorData::from([[1.0f32, 2.0], [3.0, 6.0]]),
+        ];
+
+        let model = swa.update_statistics(0.1, batches, |norm, batch| {
+            norm.forward(Tensor::<_, 2>::from_data(batch, &device));
+        });
+
+        model
+            .running_mean
+            .value()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0f32, 2.0]), 3);
+        model
+            .running_var
+            .value()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0f32, 2.5]), 3);
+        // The frozen parameters aren't updated by the forward passes.
+        model
+            .gamma
+            .val()
+            .into_data()
+            .assert_eq(&norm.gamma.val().into_data(), true);
+        // The running states of the averaged model are not shared with the given one.
+        norm.running_mean
+            .value()
+            .into_data()
+            .assert_eq(&TensorData::from([0.0f32, 0.0]), true);
+    }
+
+    fn layer_with_value<B: Backend>(value: f64, device: &B::Device) -> Linear<B> {
+        LinearConfig::new(2, 2)
+            .with_bias(false)
+            .init::<B>(device)
+            .map(&mut Fill(value))
+    }
+}
@@ -1,17 +1,53 @@
+use alloc::boxed::Box;
+use core::any::Any;
+
 use burn_tensor::{
     backend::{AutodiffBackend, Backend},
     container::TensorContainer,
-    Tensor,
+    Int, Tensor,
 };
+use hashbrown::HashMap;
 
 use crate::module::{AutodiffModule, ParamId};
 
 use super::visitor::{GradientsParamsChangeDevice, GradientsParamsConverter};
 
 /// Data type that contains gradients for parameters.
+///
+/// The gradients of a parameter are either dense or row-sparse, only containing the rows of the
+/// parameter that received a gradient, like the weights of an [embedding](crate::nn::Embedding)
+/// with sparse gradients. Row-sparse gradients are made dense when accessed with
+/// [get](GradientsParams::get) or [remove](GradientsParams::remove).
 #[derive(Default, Debug)]
 pub struct GradientsParams {
     container: TensorContainer<ParamId>,
+    rows: HashMap<ParamId, Box<dyn Any + Send>>,
+}
+
+/// Gradients of a subset of the rows of a parameter.
+struct RowGradients<B: Backend, const D: usize> {
+    rows: Tensor<B, 1, Int>,
+    values: Tensor<B, D>,
+    num_rows: usize,
+}
+
+impl<B: Backend, const D: usize> Clone for RowGradients<B, D> {
+    fn clone(&self) -> Self {
+        Self {
+            rows: self.rows.clone(),
+            values: self.values.clone(),
+            num_rows: self.num_rows,
+        }
+    }
+}
+
+impl<B: Backend, const D: usize> RowGradients<B, D> {
+    fn into_dense(self) -> Tensor<B, D> {
+        let mut dims = self.values.dims();
+        dims[0] = self.num_rows;
+
+        Tensor::zeros(dims, &self.values.device()).select_assign(0, self.rows, self.values)
+    }
 }
 
 impl GradientsParams {
@@ -66,7 +102,12 @@ impl GradientsParams {
     where
         B: Backend,
     {
-        self.container.get(&id).map(Tensor::from_primitive)
+        match self.rows.get(&id) {
+            Some(grad) => grad
+                .downcast_ref::<RowGradients<B, D>>()
+                .map(|grad| grad.clone().into_dense()),
+            None => self.container.get(&id).map(Tensor::from_primitive),
+        }
     }
 
     /// Remove the gradients for the given [parameter id](ParamId).
@@ -74,7 +115,33 @@ impl GradientsParams {
     where
         B: Backend,
     {
-        self.container.remove(&id).map(Tensor::from_primitive)
+        match self.rows.remove(&id) {
+            Some(grad) => grad
+                .downcast::<RowGradients<B, D>>()
+                .ok()
+                .map(|grad| grad.into_dense()),
+            None => self.container.remove(&id).map(Tensor::from_primitive),
+        }
+    }
+
+    /// Remove the row-sparse gradients for the given [parameter id](ParamId).
+    ///
+    /// Returns `None` if the gradients of the parameter are dense.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the unique indices of the rows and the gradients of those rows.
+    pub fn remove_rows<B, const D: usize>(
+        &mut self,
+        id: ParamId,
+    ) -> Option<(Tensor<B, 1, Int>, Tensor<B, D>)>
+    where
+        B: Backend,
+    {
+        let grad = self.rows.remove(&id)?;
+        let grad = grad.downcast::<RowGradients<B, D>>().ok()?;
+
+        Some((grad.rows, grad.values))
     }
 
     /// Register a gradients tensor for the given [parameter id](ParamId).
@@ -86,12 +153,53 @@ impl GradientsParams {
     where
         B: Backend,
     {
+        self.rows.remove(&id);
         self.container.register(id, value.into_primitive())
     }
 
+    /// Register row-sparse gradients for the given [parameter id](ParamId).
+    ///
+    /// Duplicated rows are summed, so that each row appears only once.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The parameter id.
+    /// * `rows` - The indices of the rows along the first dimension of the parameter.
+    /// * `values` - The gradients of each row, its first dimension matching the number of rows.
+    /// * `num_rows` - The size of the first dimension of the parameter.
+    ///
+    /// # Notes
+    ///
+    /// If gradients are already registered for the given [parameter id](ParamId), they will be
+    /// replaced.
+    pub fn register_rows<B, const D: usize>(
+        &mut self,
+        id: ParamId,
+        rows: Tensor<B, 1, Int>,
+        values: Tensor<B, D>,
+        num_rows: usize,
+    ) where
+        B: Backend,
+    {
+        let (rows, inverse, _counts) = rows.unique_with_inverse_and_counts();
+        let mut dims = values.dims();
+        dims[0] = rows.dims()[0];
+        let values = Tensor::zeros(dims, &values.device()).select_assign(0, inverse, values);
+
+        self.container.remove::<B>(&id);
+        self.rows.insert(
+            id,
+            Box::new(RowGradients {
+                rows,
+                values,
+                num_rows,
+            }),
+        );
+    }
+
     /// The number of gradients tensors registered.
     pub fn len(&self) -> usize {
-        self.container.len()
+        self.container.len() + self.rows.len()
     }
 
     /// If any tensor is contained.
@@ -105,7 +213,21 @@ impl GradientsParams {
         device: &B::Device,
         module: &M,
     ) -> Self {
-        let mut visitor = GradientsParamsChangeDevice::<M, B>::new(device, &mut self);
+        let mut visitor =
+            GradientsParamsChangeDevice::<M, B>::new(core::slice::from_ref(device), &mut self);
+        module.visit(&mut visitor);
+        self
+    }
+
+    /// Move the gradient of each parameter of the given [module](AutodiffModule) to the device of
+    /// the participant [owning](crate::optim::ParamSharding::owner) it, where `devices` holds the
+    /// device of every participant ordered by rank.
+    pub fn to_sharded_devices<B: AutodiffBackend, M: AutodiffModule<B>>(
+        mut self,
+        devices: &[B::Device],
+        module: &M,
+    ) -> Self {
+        let mut visitor = GradientsParamsChangeDevice::<M, B>::new(devices, &mut self);
         module.visit(&mut visitor);
         self
     }
@@ -116,10 +238,10 @@ mod tests {
     use super::*;
     use crate::{
         module::{list_param_ids, Module},
-        nn::{Linear, LinearConfig},
-        TestAutodiffBackend,
+        nn::{EmbeddingConfig, Linear, LinearConfig},
+        TestAutodiffBackend, TestBackend,
     };
-    use burn_tensor::{backend::Backend, Distribution};
+    use burn_tensor::{backend::Backend, Distribution, TensorData};
 
     #[test]
     fn test_convert_grads() {
@@ -140,6 +262,31 @@ mod tests {
         assert_eq!(grads_2.len(), param_ids_2.len());
     }
 
+    #[test]
+    fn test_convert_sparse_grads() {
+        let device = Default::default();
+        let embedding = EmbeddingConfig::new(4, 2)
+            .with_sparse_grad(true)
+            .init::<TestAutodiffBackend>(&device);
+        let input = Tensor::from_data([[3, 1, 3]], &device);
+        let grads = embedding.forward(input).sum().backward();
+
+        let mut grads = GradientsParams::from_grads(grads, &embedding);
+        let id = embedding.weight.id;
+        let dense = grads.get::<TestBackend, 2>(id).unwrap();
+        let (rows, values) = grads.remove_rows::<TestBackend, 2>(id).unwrap();
+
+        dense.into_data().assert_eq(
+            &TensorData::from([[0.0, 0.0], [1.0, 1.0], [0.0, 0.0], [2.0, 2.0]]),
+            false,
+        );
+        rows.into_data().assert_eq(&TensorData::from([1, 3]), false);
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 1.0], [2.0, 2.0]]), false);
+        assert!(grads.is_empty());
+    }
+
     fn layer<B: Backend>(device: &B::Device) -> Linear<B> {
         LinearConfig::new(20, 20).with_bias(true).init(device)
     }
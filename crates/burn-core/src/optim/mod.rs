@@ -7,20 +7,24 @@ pub mod momentum;
 mod adagrad;
 mod adam;
 mod adamw;
+mod averaging;
 mod base;
 mod grad_accum;
 mod grads;
 mod rmsprop;
 mod sgd;
+mod sharding;
 mod simple;
 mod visitor;
 
 pub use adagrad::*;
 pub use adam::*;
 pub use adamw::*;
+pub use averaging::*;
 pub use base::*;
 pub use grad_accum::*;
 pub use grads::*;
 pub use rmsprop::*;
 pub use sgd::*;
+pub use sharding::*;
 pub use simple::*;
@@ -2,7 +2,7 @@ use crate as burn;
 
 use crate::config::Config;
 use crate::record::Record;
-use crate::tensor::{ElementConversion, Tensor};
+use crate::tensor::{ElementConversion, Int, Tensor};
 use burn_tensor::backend::Backend;
 
 /// Configuration to create [momentum](Momentum).
@@ -75,6 +75,45 @@ impl<B: Backend> Momentum<B> {
 
         (grad, MomentumState::new(velocity))
     }
+
+    /// Transforms the gradient of some rows of a tensor, only updating the state of those rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `grad` - Gradient of each row to transform.
+    /// * `rows` - The unique indices of the rows along the first dimension of the tensor.
+    /// * `dims` - The dimensions of the tensor.
+    /// * `state` - State of the optimizer.
+    ///
+    /// # Returns
+    ///
+    /// * `grad` - Transformed gradient of each row.
+    /// * `state` - State of the optimizer.
+    pub fn transform_rows<const D: usize>(
+        &self,
+        grad: Tensor<B, D>,
+        rows: Tensor<B, 1, Int>,
+        dims: [usize; D],
+        state: Option<MomentumState<B, D>>,
+    ) -> (Tensor<B, D>, MomentumState<B, D>) {
+        let device = grad.device();
+        let (velocity, state_rows) = match state {
+            Some(state) => {
+                let velocity_rows = state.velocity.clone().select(0, rows.clone());
+                (state.velocity, Some(MomentumState::new(velocity_rows)))
+            }
+            None => (Tensor::zeros(dims, &device), None),
+        };
+        let velocity_rows_old = state_rows
+            .as_ref()
+            .map(|state| state.velocity.clone())
+            .unwrap_or_else(|| Tensor::zeros(grad.dims(), &device));
+
+        let (grad, state_rows) = self.transform(grad, state_rows);
+        let velocity = velocity.select_assign(0, rows, state_rows.velocity - velocity_rows_old);
+
+        (grad, MomentumState::new(velocity))
+    }
 }
 
 impl<B: Backend, const D: usize> MomentumState<B, D> {
@@ -10,6 +10,7 @@ use crate::optim::adaptor::OptimizerAdaptor;
 use crate::record::Record;
 use crate::tensor::Tensor;
 use burn_tensor::backend::{AutodiffBackend, Backend};
+use burn_tensor::Int;
 
 /// Configuration to create the [Sgd](Sgd) optimizer.
 #[derive(Config)]
@@ -89,6 +90,33 @@ impl<B: Backend> SimpleOptimizer<B> for Sgd<B> {
         (tensor - delta, Some(state))
     }
 
+    fn step_rows<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        rows: Tensor<B, 1, Int>,
+        mut grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let mut state_momemtum = state.and_then(|state| state.momentum);
+
+        if let Some(weight_decay) = &self.weight_decay {
+            grad = weight_decay.transform(grad, tensor.clone().select(0, rows.clone()));
+        }
+
+        if let Some(momentum) = &self.momentum {
+            let (grad_out, state) =
+                momentum.transform_rows(grad, rows.clone(), tensor.dims(), state_momemtum);
+            state_momemtum = Some(state);
+            grad = grad_out;
+        }
+
+        let state = SgdState::new(state_momemtum);
+        let delta = grad.mul_scalar(lr);
+
+        (tensor.select_assign(0, rows, delta.neg()), Some(state))
+    }
+
     fn to_device<const D: usize>(mut state: Self::State<D>, device: &B::Device) -> Self::State<D> {
         state.momentum = state.momentum.map(|state| state.to_device(device));
         state
@@ -100,7 +128,7 @@ mod tests {
     use super::*;
     use crate::{
         grad_clipping::GradientClipping,
-        nn::{Linear, LinearConfig},
+        nn::{EmbeddingConfig, Linear, LinearConfig},
         optim::{GradientsParams, Optimizer},
         tensor::{Distribution, Shape},
         TestAutodiffBackend, TestBackend,
@@ -156,6 +184,37 @@ mod tests {
         assert_eq!(record.len(), state_restored.len());
     }
 
+    #[test]
+    fn sparse_grad_should_only_update_used_rows() {
+        let device = Default::default();
+        let embedding = EmbeddingConfig::new(4, 2)
+            .with_sparse_grad(true)
+            .init::<TestAutodiffBackend>(&device);
+        let weight = embedding.weight.val();
+        let mut optim = SgdConfig::new()
+            .with_weight_decay(Some(WeightDecayConfig { penalty: 0.05 }))
+            .with_momentum(Some(MomentumConfig::new()))
+            .init();
+        let input = Tensor::from_data([[2, 0, 2]], &device);
+
+        let grads = embedding.forward(input).sum().backward();
+        let grads = GradientsParams::from_grads(grads, &embedding);
+        let embedding = optim.step(LEARNING_RATE, embedding, grads);
+
+        let expected = weight.clone() - weight.clone().mul_scalar(0.05 * LEARNING_RATE);
+        let expected = expected
+            - Tensor::from_data([[1.0, 1.0], [0.0, 0.0], [2.0, 2.0], [0.0, 0.0]], &device)
+                .mul_scalar(LEARNING_RATE);
+        let expected = expected.slice_assign([1..2, 0..2], weight.clone().slice([1..2, 0..2]));
+        let expected = expected.slice_assign([3..4, 0..2], weight.slice([3..4, 0..2]));
+
+        embedding
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+
     fn random_tensor<B: Backend>(device: &B::Device) -> Tensor<B, 2> {
         Tensor::<B, 2>::random(Shape::new([2, 20]), Distribution::Default, device)
     }
@@ -0,0 +1,209 @@
+use crate::module::{Module, ModuleMapper, ParamId};
+use burn_tensor::{backend::Backend, Tensor};
+use core::marker::PhantomData;
+use hashbrown::HashMap;
+
+/// Partition of the parameters of a model between the participants of a sharded training.
+///
+/// Each parameter is owned by exactly one participant, identified by its rank, which is the only
+/// one keeping the optimizer state of the parameter and updating it. Since the partition only
+/// depends on the [parameter id](ParamId), all replicas of a model agree on the owners without
+/// communicating.
+///
+/// Parameters are distributed by count, not by size, so the memory is only balanced when the
+/// model has many parameters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParamSharding {
+    rank: usize,
+    world_size: usize,
+}
+
+impl ParamSharding {
+    /// Create the sharding of the participant with the given rank.
+    pub fn new(rank: usize, world_size: usize) -> Self {
+        assert!(
+            rank < world_size,
+            "The rank ({rank}) should be smaller than the world size ({world_size})."
+        );
+
+        Self { rank, world_size }
+    }
+
+    /// The rank of the current participant.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// The number of participants.
+    pub fn world_size(&self) -> usize {
+        self.world_size
+    }
+
+    /// The rank of the participant owning the given parameter.
+    pub fn owner(&self, id: &ParamId) -> usize {
+        (id.val() % self.world_size as u64) as usize
+    }
+
+    /// True if the given parameter is owned by the current participant.
+    pub fn is_owned(&self, id: &ParamId) -> bool {
+        self.owner(id) == self.rank
+    }
+}
+
+/// Merge the optimizer records saved by every participant of a sharded training into a single
+/// record, which can be loaded by an optimizer that isn't sharded.
+///
+/// Since every parameter has a single owner, the records don't overlap.
+pub fn merge_sharded_records<R>(
+    shards: impl IntoIterator<Item = HashMap<ParamId, R>>,
+) -> HashMap<ParamId, R> {
+    let mut record = HashMap::new();
+
+    for shard in shards {
+        record.extend(shard);
+    }
+
+    record
+}
+
+/// Move every float tensor of the module to the device of the participant owning it, where
+/// `devices` holds the device of every participant ordered by rank.
+///
+/// This partitions the parameters of a model between multiple devices of the same process, the
+/// optimizer state of each parameter then being created on its device.
+pub fn shard_module<B: Backend, M: Module<B>>(module: M, devices: &[B::Device]) -> M {
+    let mut mapper = ModuleShard::<B>::new(devices);
+    module.map(&mut mapper)
+}
+
+/// The device of the participant owning the given parameter, from the devices of every
+/// participant ordered by rank.
+pub(crate) fn owner_device<'a, D>(id: &ParamId, devices: &'a [D]) -> &'a D {
+    &devices[ParamSharding::new(0, devices.len()).owner(id)]
+}
+
+#[derive(new)]
+struct ModuleShard<'a, B: Backend> {
+    devices: &'a [B::Device],
+    #[new(default)]
+    phantom: PhantomData<B>,
+}
+
+impl<B: Backend> ModuleMapper<B> for ModuleShard<'_, B> {
+    fn map_float<const D: usize>(&mut self, id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let is_require_grad = tensor.is_require_grad();
+        let tensor = tensor
+            .set_require_grad(false)
+            .to_device(owner_device(&id, self.devices));
+
+        tensor.set_require_grad(is_require_grad)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        module::Param,
+        nn::{Linear, LinearConfig},
+        optim::{momentum::MomentumConfig, GradientsParams, Optimizer, SgdConfig},
+        tensor::{backend::Backend, Tensor},
+        TestAutodiffBackend,
+    };
+
+    const WORLD_SIZE: usize = 2;
+
+    #[test]
+    fn params_should_have_a_single_owner() {
+        let shardings = (0..WORLD_SIZE)
+            .map(|rank| ParamSharding::new(rank, WORLD_SIZE))
+            .collect::<Vec<_>>();
+
+        for _ in 0..100 {
+            let id = ParamId::new();
+            let owners = shardings.iter().filter(|s| s.is_owned(&id)).count();
+            assert_eq!(owners, 1);
+        }
+    }
+
+    #[test]
+    fn sharded_optimizer_should_only_update_owned_params() {
+        let device = Default::default();
+        let layer = layer(&device);
+        let mut records = Vec::new();
+
+        for rank in 0..WORLD_SIZE {
+            let sharding = ParamSharding::new(rank, WORLD_SIZE);
+            let mut optim = SgdConfig::new()
+                .with_momentum(Some(MomentumConfig::new()))
+                .init::<TestAutodiffBackend, Linear<TestAutodiffBackend>>()
+                .with_sharding(sharding);
+
+            let output = layer.forward(Tensor::<_, 2>::ones([2, 4], &device));
+            let grads = GradientsParams::from_grads(output.backward(), &layer);
+            let updated = optim.step(0.1, layer.clone(), grads);
+
+            let record = optim.to_record();
+            assert!(record.keys().all(|id| sharding.is_owned(id)));
+
+            assert_updated(&layer.weight, &updated.weight, sharding);
+            let bias = layer.bias.as_ref().unwrap();
+            assert_updated(bias, updated.bias.as_ref().unwrap(), sharding);
+
+            records.push(record);
+        }
+
+        let record = merge_sharded_records(records);
+        assert_eq!(record.len(), 2);
+
+        // An unsharded record is filtered when loaded by a sharded optimizer.
+        let sharding = ParamSharding::new(0, WORLD_SIZE);
+        let optim = SgdConfig::new()
+            .with_momentum(Some(MomentumConfig::new()))
+            .init::<TestAutodiffBackend, Linear<TestAutodiffBackend>>()
+            .with_sharding(sharding)
+            .load_record(record);
+        assert!(optim.to_record().keys().all(|id| sharding.is_owned(id)));
+    }
+
+    #[test]
+    fn sharded_module_should_keep_trainable_params() {
+        let device = Default::default();
+        let devices = [device; WORLD_SIZE];
+        let layer = layer(&device);
+
+        let sharded = shard_module(layer.clone(), &devices);
+        assert!(sharded.weight.val().is_require_grad());
+        sharded
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&layer.weight.val().into_data(), true);
+
+        let output = sharded.forward(Tensor::<_, 2>::ones([2, 4], &device));
+        let grads = GradientsParams::from_grads(output.backward(), &sharded)
+            .to_sharded_devices(&devices, &sharded);
+        assert_eq!(grads.len(), 2);
+    }
+
+    fn assert_updated<const D: usize>(
+        before: &Param<Tensor<TestAutodiffBackend, D>>,
+        after: &Param<Tensor<TestAutodiffBackend, D>>,
+        sharding: ParamSharding,
+    ) {
+        let unchanged = before.val().equal(after.val()).all().into_scalar();
+        assert_eq!(unchanged, !sharding.is_owned(&before.id));
+    }
+
+    fn layer(device: &<TestAutodiffBackend as Backend>::Device) -> Linear<TestAutodiffBackend> {
+        // Each participant owns one of the parameters.
+        let layer = LinearConfig::new(4, 2).init(device);
+        let weight = layer.weight.val().set_require_grad(false);
+        let bias = layer.bias.unwrap().val().set_require_grad(false);
+
+        Linear {
+            weight: Param::initialized(ParamId::from(0), weight.require_grad()),
+            bias: Some(Param::initialized(ParamId::from(1), bias.require_grad())),
+        }
+    }
+}
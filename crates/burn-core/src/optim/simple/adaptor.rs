@@ -2,7 +2,7 @@ use super::{record::AdaptorRecord, SimpleOptimizer};
 use crate::{
     grad_clipping::GradientClipping,
     module::{AutodiffModule, ModuleMapper, ParamId},
-    optim::{GradientsParams, Optimizer},
+    optim::{GradientsParams, Optimizer, ParamSharding},
     LearningRate,
 };
 use burn_tensor::{backend::AutodiffBackend, Tensor};
@@ -22,6 +22,7 @@ where
     records: HashMap<ParamId, AdaptorRecord<O, B>>,
     module: PhantomData<M>,
     grad_clipping: Option<GradientClipping>,
+    sharding: Option<ParamSharding>,
 }
 
 impl<O, B, M> From<O> for OptimizerAdaptor<O, M, B>
@@ -36,6 +37,7 @@ where
             records: HashMap::new(),
             module: PhantomData,
             grad_clipping: None,
+            sharding: None,
         }
     }
 }
@@ -60,6 +62,30 @@ where
         self
     }
 
+    /// Sets the sharding of the parameters, used to partition the optimizer state between the
+    /// participants of a distributed training.
+    ///
+    /// Only the parameters [owned](ParamSharding::is_owned) by the current participant are updated
+    /// and have a state, the other parameters are returned unchanged and their gradients ignored.
+    /// The updated parameters should then be sent from their owners to the other participants.
+    ///
+    /// The [record](Optimizer::to_record) only contains the state of the owned parameters, the
+    /// records of all participants can be combined with
+    /// [merge_sharded_records](crate::optim::merge_sharded_records) to be loaded without sharding.
+    /// When loading a record, the state of the parameters not owned is discarded.
+    ///
+    /// # Arguments
+    ///
+    /// * `sharding` - The sharding of the current participant.
+    ///
+    /// # Returns
+    ///
+    /// The optimizer.
+    pub fn with_sharding(mut self, sharding: ParamSharding) -> Self {
+        self.sharding = Some(sharding);
+        self
+    }
+
     #[cfg(test)]
     pub(crate) fn has_gradient_clipping(&self) -> bool {
         self.grad_clipping.is_some()
@@ -81,6 +107,7 @@ where
             &mut grads,
             lr,
             self.grad_clipping.as_ref(),
+            self.sharding.as_ref(),
         );
         module.map(&mut mapper)
     }
@@ -89,7 +116,10 @@ where
         self.records.clone()
     }
 
-    fn load_record(mut self, record: Self::Record) -> Self {
+    fn load_record(mut self, mut record: Self::Record) -> Self {
+        if let Some(sharding) = &self.sharding {
+            record.retain(|id, _| sharding.is_owned(id));
+        }
         self.records = record;
         self
     }
@@ -108,6 +138,7 @@ where
     lr: LearningRate,
     phantom: PhantomData<M>,
     grad_clipping: Option<&'a GradientClipping>,
+    sharding: Option<&'a ParamSharding>,
 }
 
 impl<M, B, O> ModuleMapper<B> for SimpleOptimizerMapper<'_, M, B, O>
@@ -117,9 +148,18 @@ where
     O: SimpleOptimizer<B::InnerBackend>,
 {
     fn map_float<const D: usize>(&mut self, id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
-        let grad = self.grads.remove(id);
+        let grad = match self.grads.remove_rows(id) {
+            Some((rows, grad)) => Some((Some(rows), grad)),
+            None => self.grads.remove(id).map(|grad| (None, grad)),
+        };
+
+        if let Some(sharding) = self.sharding {
+            if !sharding.is_owned(&id) {
+                return tensor;
+            }
+        }
 
-        if let Some(grad) = grad {
+        if let Some((rows, grad)) = grad {
             let device = grad.device();
             let is_require_grad = tensor.is_require_grad();
             let (key, record) = self.records.remove_entry(&id).unzip();
@@ -130,12 +170,16 @@ where
                 grad
             };
 
-            let (tensor, state) = self.optimizer.step(
-                self.lr,
-                tensor.inner(),
-                clipped_grad,
-                record.map(|record| O::to_device(record.into_state(), &device)),
-            );
+            let state = record.map(|record| O::to_device(record.into_state(), &device));
+            let (tensor, state) = match rows {
+                Some(rows) => {
+                    self.optimizer
+                        .step_rows(self.lr, tensor.inner(), rows, clipped_grad, state)
+                }
+                None => self
+                    .optimizer
+                    .step(self.lr, tensor.inner(), clipped_grad, state),
+            };
 
             if let Some(state) = state {
                 self.records
@@ -1,5 +1,5 @@
 use crate::{record::Record, LearningRate};
-use burn_tensor::{backend::Backend, Tensor};
+use burn_tensor::{backend::Backend, Int, Tensor};
 
 /// Simple optimizer is an opinionated trait to simplify the process of implementing an
 /// optimizer.
@@ -25,6 +25,36 @@ where
         state: Option<Self::State<D>>,
     ) -> (Tensor<B, D>, Option<Self::State<D>>);
 
+    /// The optimizer step performed with row-sparse gradients, only containing the rows of the
+    /// tensor that received a gradient.
+    ///
+    /// The default implementation makes the gradient dense and calls [step](SimpleOptimizer::step).
+    /// Implementations can override it to only update the given rows of the tensor and of its
+    /// state, which is much faster for large embedding tables where few rows are used at each
+    /// step.
+    ///
+    /// # Arguments
+    ///
+    /// * `lr` - The learning rate.
+    /// * `tensor` - The tensor to update.
+    /// * `rows` - The unique indices of the rows along the first dimension of the tensor.
+    /// * `grad` - The gradient of each row, its first dimension matching the number of rows.
+    /// * `state` - The state of the tensor.
+    fn step_rows<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        rows: Tensor<B, 1, Int>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let mut dims = grad.dims();
+        dims[0] = tensor.dims()[0];
+        let grad = Tensor::zeros(dims, &grad.device()).select_assign(0, rows, grad);
+
+        self.step(lr, tensor, grad, state)
+    }
+
     /// Change the device of the state.
     ///
     /// This function will be called accordindly to have the state on the same device as the
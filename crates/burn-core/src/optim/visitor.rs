@@ -1,4 +1,4 @@
-use super::GradientsParams;
+use super::{sharding::owner_device, GradientsParams};
 use crate::module::{AutodiffModule, ModuleVisitor, ParamId};
 use burn_tensor::{backend::AutodiffBackend, Tensor};
 use core::marker::PhantomData;
@@ -16,7 +16,9 @@ pub struct GradientsParamsConverter<'a, M: AutodiffModule<B>, B: AutodiffBackend
 
 #[derive(new)]
 pub struct GradientsParamsChangeDevice<'a, M: AutodiffModule<B>, B: AutodiffBackend> {
-    device: &'a B::Device,
+    /// The devices of the participants of a sharding, ordered by rank, each gradient being moved
+    /// to the device owning its parameter.
+    devices: &'a [B::Device],
     grads: &'a mut GradientsParams,
     phatom: PhantomData<M>,
 }
@@ -32,6 +34,12 @@ where
                 return;
             }
         }
+        if let Some((rows, values)) = tensor.grad_remove_rows(self.grads) {
+            let num_rows = tensor.dims()[0];
+            self.grads_params
+                .register_rows::<B::InnerBackend, D>(id, rows, values, num_rows);
+            return;
+        }
         let Some(grad) = tensor.grad_remove(self.grads) else {
             return;
         };
@@ -45,12 +53,23 @@ where
     B: AutodiffBackend,
     M: AutodiffModule<B>,
 {
-    fn visit_float<const D: usize>(&mut self, id: ParamId, _tensor: &Tensor<B, D>) {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        let device = owner_device(&id, self.devices);
+
+        if let Some((rows, values)) = self.grads.remove_rows::<B::InnerBackend, D>(id) {
+            self.grads.register_rows::<B::InnerBackend, D>(
+                id,
+                rows.to_device(device),
+                values.to_device(device),
+                tensor.dims()[0],
+            );
+            return;
+        }
         let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) else {
             return;
         };
 
         self.grads
-            .register::<B::InnerBackend, D>(id, grad.to_device(self.device));
+            .register::<B::InnerBackend, D>(id, grad.to_device(device));
     }
 }
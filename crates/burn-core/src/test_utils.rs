@@ -0,0 +1,13 @@
+use crate::module::{ModuleMapper, ParamId};
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Replaces every float tensor of a module with a tensor of the same shape filled with the value.
+pub struct Fill(pub f64);
+
+impl<B: Backend> ModuleMapper<B> for Fill {
+    fn map_float<const D: usize>(&mut self, _id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let is_require_grad = tensor.is_require_grad();
+
+        Tensor::full(tensor.shape(), self.0, &tensor.device()).set_require_grad(is_require_grad)
+    }
+}
@@ -285,6 +285,58 @@ impl FuseBuilder {
             FloatOperationIr::Recip(desc) => self.register_unary_ops(desc, |input, out| {
                 ElemwiseOp::Recip(UnaryElemwiseArgs { input, out })
             }),
+            FloatOperationIr::Asin(desc) => self.register_unary_ops(desc, |input, out| {
+                ElemwiseOp::Asin(UnaryElemwiseArgs { input, out })
+            }),
+            FloatOperationIr::Acos(desc) => self.register_unary_ops(desc, |input, out| {
+                ElemwiseOp::Acos(UnaryElemwiseArgs { input, out })
+            }),
+            FloatOperationIr::Atan(desc) => self.register_unary_ops(desc, |input, out| {
+                ElemwiseOp::Atan(UnaryElemwiseArgs { input, out })
+            }),
+            FloatOperationIr::Sinh(desc) => self.register_unary_ops(desc, |input, out| {
+                ElemwiseOp::Sinh(UnaryElemwiseArgs { input, out })
+            }),
+            FloatOperationIr::Cosh(desc) => self.register_unary_ops(desc, |input, out| {
+                ElemwiseOp::Cosh(UnaryElemwiseArgs { input, out })
+            }),
+            FloatOperationIr::Asinh(desc) => self.register_unary_ops(desc, |input, out| {
+                ElemwiseOp::Asinh(UnaryElemwiseArgs { input, out })
+            }),
+            FloatOperationIr::Acosh(desc) => self.register_unary_ops(desc, |input, out| {
+                ElemwiseOp::Acosh(UnaryElemwiseArgs { input, out })
+            }),
+            FloatOperationIr::Atanh(desc) => self.register_unary_ops(desc, |input, out| {
+                ElemwiseOp::Atanh(UnaryElemwiseArgs { input, out })
+            }),
+            FloatOperationIr::Expm1(desc) => self.register_unary_ops(desc, |input, out| {
+                ElemwiseOp::Expm1(UnaryElemwiseArgs { input, out })
+            }),
+            FloatOperationIr::ErfInv(desc) => self.register_unary_ops(desc, |input, out| {
+                ElemwiseOp::ErfInv(UnaryElemwiseArgs { input, out })
+            }),
+            FloatOperationIr::Lgamma(desc) => self.register_unary_ops(desc, |input, out| {
+                ElemwiseOp::Lgamma(UnaryElemwiseArgs { input, out })
+            }),
+            FloatOperationIr::Digamma(desc) => self.register_unary_ops(desc, |input, out| {
+                ElemwiseOp::Digamma(UnaryElemwiseArgs { input, out })
+            }),
+            FloatOperationIr::Trunc(desc) => self.register_unary_ops(desc, |input, out| {
+                ElemwiseOp::Trunc(UnaryElemwiseArgs { input, out })
+            }),
+            FloatOperationIr::Atan2(desc) => self.register_binary_ops(desc, |lhs, rhs, out| {
+                ElemwiseOp::Atan2(BinaryElemwiseArgs { lhs, rhs, out })
+            }),
+            FloatOperationIr::Hypot(desc) => self.register_binary_ops(desc, |lhs, rhs, out| {
+                ElemwiseOp::Hypot(BinaryElemwiseArgs { lhs, rhs, out })
+            }),
+            FloatOperationIr::Fmod(desc) => self.register_binary_ops(desc, |lhs, rhs, out| {
+                ElemwiseOp::Fmod(BinaryElemwiseArgs { lhs, rhs, out })
+            }),
+            FloatOperationIr::FmodScalar(desc) => self
+                .register_scalar_ops(desc, |lhs, rhs, out| {
+                    ElemwiseOp::Fmod(BinaryElemwiseArgs { lhs, rhs, out })
+                }),
             _ => false,
         }
     }
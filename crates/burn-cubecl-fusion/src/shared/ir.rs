@@ -89,6 +89,22 @@ pub enum ElemwiseOp {
     Tanh(UnaryElemwiseArgs),
     Erf(UnaryElemwiseArgs),
     Recip(UnaryElemwiseArgs),
+    Asin(UnaryElemwiseArgs),
+    Acos(UnaryElemwiseArgs),
+    Atan(UnaryElemwiseArgs),
+    Sinh(UnaryElemwiseArgs),
+    Cosh(UnaryElemwiseArgs),
+    Asinh(UnaryElemwiseArgs),
+    Acosh(UnaryElemwiseArgs),
+    Atanh(UnaryElemwiseArgs),
+    Expm1(UnaryElemwiseArgs),
+    ErfInv(UnaryElemwiseArgs),
+    Lgamma(UnaryElemwiseArgs),
+    Digamma(UnaryElemwiseArgs),
+    Trunc(UnaryElemwiseArgs),
+    Atan2(BinaryElemwiseArgs),
+    Hypot(BinaryElemwiseArgs),
+    Fmod(BinaryElemwiseArgs),
     Assign(UnaryElemwiseArgs),
     Equal(BinaryElemwiseArgs),
     Lower(BinaryElemwiseArgs),
@@ -141,6 +157,22 @@ impl ElemwiseOp {
             ElemwiseOp::Tanh(op) => op.out.precision().into_elem(),
             ElemwiseOp::Erf(op) => op.out.precision().into_elem(),
             ElemwiseOp::Recip(op) => op.out.precision().into_elem(),
+            ElemwiseOp::Asin(op) => op.out.precision().into_elem(),
+            ElemwiseOp::Acos(op) => op.out.precision().into_elem(),
+            ElemwiseOp::Atan(op) => op.out.precision().into_elem(),
+            ElemwiseOp::Sinh(op) => op.out.precision().into_elem(),
+            ElemwiseOp::Cosh(op) => op.out.precision().into_elem(),
+            ElemwiseOp::Asinh(op) => op.out.precision().into_elem(),
+            ElemwiseOp::Acosh(op) => op.out.precision().into_elem(),
+            ElemwiseOp::Atanh(op) => op.out.precision().into_elem(),
+            ElemwiseOp::Expm1(op) => op.out.precision().into_elem(),
+            ElemwiseOp::ErfInv(op) => op.out.precision().into_elem(),
+            ElemwiseOp::Lgamma(op) => op.out.precision().into_elem(),
+            ElemwiseOp::Digamma(op) => op.out.precision().into_elem(),
+            ElemwiseOp::Trunc(op) => op.out.precision().into_elem(),
+            ElemwiseOp::Atan2(op) => op.lhs.precision().into_elem(),
+            ElemwiseOp::Hypot(op) => op.lhs.precision().into_elem(),
+            ElemwiseOp::Fmod(op) => op.lhs.precision().into_elem(),
             ElemwiseOp::Assign(op) => op.out.precision().into_elem(),
             ElemwiseOp::Equal(op) => op.lhs.precision().into_elem(),
             ElemwiseOp::Lower(op) => op.lhs.precision().into_elem(),
@@ -192,6 +192,54 @@ fn fuse(
             ElemwiseOp::Recip(op) => {
                 recip::<NumericExpand<DYN_ELEM_ID>>(inputs, outputs, locals, pos, op, config)
             }
+            ElemwiseOp::Asin(op) => {
+                asin::<NumericExpand<DYN_ELEM_ID>>(inputs, outputs, locals, pos, op, config)
+            }
+            ElemwiseOp::Acos(op) => {
+                acos::<NumericExpand<DYN_ELEM_ID>>(inputs, outputs, locals, pos, op, config)
+            }
+            ElemwiseOp::Atan(op) => {
+                atan::<NumericExpand<DYN_ELEM_ID>>(inputs, outputs, locals, pos, op, config)
+            }
+            ElemwiseOp::Sinh(op) => {
+                sinh::<NumericExpand<DYN_ELEM_ID>>(inputs, outputs, locals, pos, op, config)
+            }
+            ElemwiseOp::Cosh(op) => {
+                cosh::<NumericExpand<DYN_ELEM_ID>>(inputs, outputs, locals, pos, op, config)
+            }
+            ElemwiseOp::Asinh(op) => {
+                asinh::<NumericExpand<DYN_ELEM_ID>>(inputs, outputs, locals, pos, op, config)
+            }
+            ElemwiseOp::Acosh(op) => {
+                acosh::<NumericExpand<DYN_ELEM_ID>>(inputs, outputs, locals, pos, op, config)
+            }
+            ElemwiseOp::Atanh(op) => {
+                atanh::<NumericExpand<DYN_ELEM_ID>>(inputs, outputs, locals, pos, op, config)
+            }
+            ElemwiseOp::Expm1(op) => {
+                expm1::<NumericExpand<DYN_ELEM_ID>>(inputs, outputs, locals, pos, op, config)
+            }
+            ElemwiseOp::ErfInv(op) => {
+                erfinv::<NumericExpand<DYN_ELEM_ID>>(inputs, outputs, locals, pos, op, config)
+            }
+            ElemwiseOp::Lgamma(op) => {
+                lgamma::<NumericExpand<DYN_ELEM_ID>>(inputs, outputs, locals, pos, op, config)
+            }
+            ElemwiseOp::Digamma(op) => {
+                digamma::<NumericExpand<DYN_ELEM_ID>>(inputs, outputs, locals, pos, op, config)
+            }
+            ElemwiseOp::Trunc(op) => {
+                trunc::<NumericExpand<DYN_ELEM_ID>>(inputs, outputs, locals, pos, op, config)
+            }
+            ElemwiseOp::Atan2(op) => {
+                atan2::<NumericExpand<DYN_ELEM_ID>>(inputs, outputs, locals, pos, op, config)
+            }
+            ElemwiseOp::Hypot(op) => {
+                hypot::<NumericExpand<DYN_ELEM_ID>>(inputs, outputs, locals, pos, op, config)
+            }
+            ElemwiseOp::Fmod(op) => {
+                fmod::<NumericExpand<DYN_ELEM_ID>>(inputs, outputs, locals, pos, op, config)
+            }
             ElemwiseOp::Assign(op) => {
                 assign::<NumericExpand<DYN_ELEM_ID>>(inputs, outputs, locals, pos, op, config)
             }
@@ -596,6 +644,9 @@ comparison_op!(lower, <);
 comparison_op!(lower_equal, <=);
 
 binary_func!(powf, Line::<C>::powf, Float);
+binary_func!(atan2, atan2_line::<C>, Float);
+binary_func!(hypot, hypot_line::<C>, Float);
+binary_func!(fmod, fmod_line::<C>, Float);
 
 unary_func!(exp, Line::<C>::exp, Float);
 unary_func!(log, Line::<C>::log, Float);
@@ -605,4 +656,273 @@ unary_func!(sin, Line::<C>::sin, Float);
 unary_func!(tanh, Line::<C>::tanh, Float);
 unary_func!(erf, Line::<C>::erf, Float);
 unary_func!(recip, Line::<C>::recip, Float);
+unary_func!(asin, asin_line::<C>, Float);
+unary_func!(acos, acos_line::<C>, Float);
+unary_func!(atan, atan_line::<C>, Float);
+unary_func!(sinh, sinh_line::<C>, Float);
+unary_func!(cosh, cosh_line::<C>, Float);
+unary_func!(asinh, asinh_line::<C>, Float);
+unary_func!(acosh, acosh_line::<C>, Float);
+unary_func!(atanh, atanh_line::<C>, Float);
+unary_func!(expm1, expm1_line::<C>, Float);
+unary_func!(erfinv, erfinv_line::<C>, Float);
+unary_func!(lgamma, lgamma_line::<C>, Float);
+unary_func!(digamma, digamma_line::<C>, Float);
+unary_func!(trunc, trunc_line::<C>, Float);
 unary_func!(abs, Line::<C>::abs, Numeric);
+
+#[cube]
+fn trunc_line<C: Float>(x: Line<C>) -> Line<C> {
+    let zero = Line::new(C::new(0.0));
+    let negative = Line::new(x < zero);
+
+    select_many(negative, Line::ceil(x), Line::floor(x))
+}
+
+#[cube]
+fn fmod_line<C: Float>(lhs: Line<C>, rhs: Line<C>) -> Line<C> {
+    lhs - trunc_line::<C>(lhs / rhs) * rhs
+}
+
+#[cube]
+fn hypot_line<C: Float>(lhs: Line<C>, rhs: Line<C>) -> Line<C> {
+    let one = Line::new(C::new(1.0));
+    let lhs = Line::abs(lhs);
+    let rhs = Line::abs(rhs);
+    let max = Line::max(lhs, rhs);
+    let min = Line::min(lhs, rhs);
+
+    // Scaling by the largest value avoids the overflow of the squares, equal values (including
+    // zeros and infinities) having a ratio of one.
+    let equal = Line::new(min == max);
+    let ratio = select_many(equal, one, min / max);
+
+    max * Line::sqrt(one + ratio * ratio)
+}
+
+#[cube]
+fn atan_line<C: Float>(x: Line<C>) -> Line<C> {
+    let zero = Line::new(C::new(0.0));
+    let one = Line::new(C::new(1.0));
+    let half_pi = Line::new(C::new(1.570_796_3));
+
+    // atan(x) = sign(x) * pi / 2 - atan(1 / x) brings the values in [-1, 1].
+    let inverted = Line::new(Line::abs(x) > one);
+    let mut t = select_many(inverted, Line::recip(x), x);
+
+    // atan(t) = 2 * atan(t / (1 + sqrt(1 + t^2))) brings the values in [-0.2, 0.2].
+    #[unroll]
+    for _ in 0..2 {
+        t = t / (one + Line::sqrt(one + t * t));
+    }
+
+    let t2 = t * t;
+    let mut series = Line::new(C::new(0.076_923_08));
+    series = series * t2 + Line::new(C::new(-0.090_909_09));
+    series = series * t2 + Line::new(C::new(0.111_111_11));
+    series = series * t2 + Line::new(C::new(-0.142_857_14));
+    series = series * t2 + Line::new(C::new(0.2));
+    series = series * t2 + Line::new(C::new(-0.333_333_34));
+    series = series * t2 + one;
+    let output = t * series * Line::new(C::new(4.0));
+
+    let negative = Line::new(x < zero);
+    let sign_half_pi = select_many(negative, zero - half_pi, half_pi);
+
+    select_many(inverted, sign_half_pi - output, output)
+}
+
+#[cube]
+fn atan2_line<C: Float>(lhs: Line<C>, rhs: Line<C>) -> Line<C> {
+    let zero = Line::new(C::new(0.0));
+    let pi = Line::new(C::new(core::f32::consts::PI));
+
+    let output = atan_line::<C>(lhs / rhs);
+
+    // Adds pi in the second quadrant and removes it in the third.
+    let lhs_positive = Line::new(lhs >= zero);
+    let correction = select_many(lhs_positive, output + pi, output - pi);
+    let rhs_negative = Line::new(rhs < zero);
+    let output = select_many(rhs_negative, correction, output);
+
+    // The angle of the origin is zero.
+    let origin = Line::new(Line::abs(lhs) + Line::abs(rhs) == zero);
+    select_many(origin, zero, output)
+}
+
+#[cube]
+fn asin_line<C: Float>(x: Line<C>) -> Line<C> {
+    let one = Line::new(C::new(1.0));
+
+    atan_line::<C>(x / Line::sqrt(one - x * x))
+}
+
+#[cube]
+fn acos_line<C: Float>(x: Line<C>) -> Line<C> {
+    let half_pi = Line::new(C::new(1.570_796_3));
+
+    half_pi - asin_line::<C>(x)
+}
+
+#[cube]
+fn expm1_line<C: Float>(x: Line<C>) -> Line<C> {
+    let one = Line::new(C::new(1.0));
+    let two = Line::new(C::new(2.0));
+
+    // exp(x) - 1 = 2 * t / (1 - t) with t = tanh(x / 2) avoids the cancellation near zero.
+    let t = Line::tanh(x / two);
+    let small = Line::new(Line::abs(x) < one);
+
+    select_many(small, two * t / (one - t), Line::exp(x) - one)
+}
+
+#[cube]
+fn sinh_line<C: Float>(x: Line<C>) -> Line<C> {
+    let zero = Line::new(C::new(0.0));
+    let one = Line::new(C::new(1.0));
+    let two = Line::new(C::new(2.0));
+
+    let u = expm1_line::<C>(Line::abs(x));
+    let output = (u + u / (u + one)) / two;
+    let negative = Line::new(x < zero);
+
+    select_many(negative, zero - output, output)
+}
+
+#[cube]
+fn cosh_line<C: Float>(x: Line<C>) -> Line<C> {
+    let two = Line::new(C::new(2.0));
+    let exp = Line::exp(Line::abs(x));
+
+    (exp + Line::recip(exp)) / two
+}
+
+#[cube]
+fn asinh_line<C: Float>(x: Line<C>) -> Line<C> {
+    let zero = Line::new(C::new(0.0));
+    let one = Line::new(C::new(1.0));
+
+    let abs = Line::abs(x);
+    let square = abs * abs;
+    let output = Line::log1p(abs + square / (one + Line::sqrt(square + one)));
+    let negative = Line::new(x < zero);
+
+    select_many(negative, zero - output, output)
+}
+
+#[cube]
+fn acosh_line<C: Float>(x: Line<C>) -> Line<C> {
+    let one = Line::new(C::new(1.0));
+    let recip = Line::recip(x);
+
+    Line::log(x) + Line::log1p(Line::sqrt(one - recip * recip))
+}
+
+#[cube]
+fn atanh_line<C: Float>(x: Line<C>) -> Line<C> {
+    let zero = Line::new(C::new(0.0));
+    let two = Line::new(C::new(2.0));
+
+    (Line::log1p(x) - Line::log1p(zero - x)) / two
+}
+
+#[cube]
+fn erfinv_line<C: Float>(x: Line<C>) -> Line<C> {
+    let one = Line::new(C::new(1.0));
+
+    // Initial approximation from M. Giles, "Approximating the erfinv function".
+    let w = Line::new(C::new(0.0)) - Line::log((one - x) * (one + x));
+
+    let c = w - Line::new(C::new(2.5));
+    let mut central = Line::new(C::new(2.810_226_4e-8));
+    central = central * c + Line::new(C::new(3.432_739_4e-7));
+    central = central * c + Line::new(C::new(-3.523_387_7e-6));
+    central = central * c + Line::new(C::new(-4.391_506_5e-6));
+    central = central * c + Line::new(C::new(0.000_218_580_87));
+    central = central * c + Line::new(C::new(-0.001_253_725));
+    central = central * c + Line::new(C::new(-0.004_177_681_6));
+    central = central * c + Line::new(C::new(0.246_640_73));
+    central = central * c + Line::new(C::new(1.501_409_4));
+
+    let t = Line::sqrt(w) - Line::new(C::new(3.0));
+    let mut tail = Line::new(C::new(-0.000_200_214_26));
+    tail = tail * t + Line::new(C::new(0.000_100_950_56));
+    tail = tail * t + Line::new(C::new(0.001_349_343_2));
+    tail = tail * t + Line::new(C::new(-0.003_673_428_4));
+    tail = tail * t + Line::new(C::new(0.005_739_507_7));
+    tail = tail * t + Line::new(C::new(-0.007_622_461));
+    tail = tail * t + Line::new(C::new(0.009_438_870_5));
+    tail = tail * t + Line::new(C::new(1.001_674_1));
+    tail = tail * t + Line::new(C::new(2.832_976_8));
+
+    let is_tail = Line::new(w >= Line::new(C::new(5.0)));
+    let output = select_many(is_tail, tail, central) * x;
+
+    // A Newton step refines the approximation using the error function.
+    let derivative =
+        Line::new(C::new(1.128_379_2)) * Line::exp(Line::new(C::new(0.0)) - output * output);
+
+    output - (Line::erf(output) - x) / derivative
+}
+
+#[cube]
+fn lgamma_line<C: Float>(x: Line<C>) -> Line<C> {
+    let one = Line::new(C::new(1.0));
+    let half = Line::new(C::new(0.5));
+    let pi = Line::new(C::new(core::f32::consts::PI));
+
+    // The reflection formula gives the values below 0.5 from the values above.
+    let reflected = Line::new(x < half);
+    let z = select_many(reflected, one - x, x) - one;
+
+    // Lanczos approximation with g = 7.
+    let mut sum = Line::new(C::new(0.999_999_999_999_809_9));
+    sum += Line::new(C::new(676.520_37)) / (z + Line::new(C::new(1.0)));
+    sum += Line::new(C::new(-1_259.139_2)) / (z + Line::new(C::new(2.0)));
+    sum += Line::new(C::new(771.323_43)) / (z + Line::new(C::new(3.0)));
+    sum += Line::new(C::new(-176.615_03)) / (z + Line::new(C::new(4.0)));
+    sum += Line::new(C::new(12.507_343)) / (z + Line::new(C::new(5.0)));
+    sum += Line::new(C::new(-0.138_571_1)) / (z + Line::new(C::new(6.0)));
+    sum += Line::new(C::new(9.984_369_6e-6)) / (z + Line::new(C::new(7.0)));
+    sum += Line::new(C::new(1.505_632_7e-7)) / (z + Line::new(C::new(8.0)));
+
+    let t = z + Line::new(C::new(7.5));
+    let output = Line::new(C::new(0.918_938_5)) + (z + half) * Line::log(t) - t + Line::log(sum);
+
+    // lgamma(x) = log(pi / |sin(pi * x)|) - lgamma(1 - x)
+    let sin = Line::abs(Line::sin(pi * x));
+    let reflection = Line::log(pi / sin) - output;
+
+    select_many(reflected, reflection, output)
+}
+
+#[cube]
+fn digamma_line<C: Float>(x: Line<C>) -> Line<C> {
+    let one = Line::new(C::new(1.0));
+    let half = Line::new(C::new(0.5));
+    let pi = Line::new(C::new(core::f32::consts::PI));
+
+    // The reflection formula gives the values below 0.5 from the values above.
+    let reflected = Line::new(x < half);
+    let mut z = select_many(reflected, one - x, x);
+
+    // digamma(z) = digamma(z + 1) - 1 / z moves the values in the asymptotic range.
+    let mut shift = Line::new(C::new(0.0));
+    #[unroll]
+    for _ in 0..6 {
+        shift += Line::recip(z);
+        z += one;
+    }
+
+    let z2 = Line::recip(z * z);
+    let mut series = Line::new(C::new(-0.003_968_254));
+    series = series * z2 + Line::new(C::new(0.008_333_334));
+    series = series * z2 + Line::new(C::new(-0.083_333_336));
+    let output = Line::log(z) - half / z + series * z2 - shift;
+
+    // digamma(x) = digamma(1 - x) - pi / tan(pi * x)
+    let angle = pi * x;
+    let reflection = output - pi * Line::cos(angle) / Line::sin(angle);
+
+    select_many(reflected, reflection, output)
+}
@@ -502,6 +502,86 @@ impl FuseTraceBuilder {
                 &mut local_tensor_ids_input,
                 &mut local_tensor_ids_output,
             ),
+            ElemwiseOp::Asin(op) => mark_unary(
+                op,
+                &mut local_tensor_ids_input,
+                &mut local_tensor_ids_output,
+            ),
+            ElemwiseOp::Acos(op) => mark_unary(
+                op,
+                &mut local_tensor_ids_input,
+                &mut local_tensor_ids_output,
+            ),
+            ElemwiseOp::Atan(op) => mark_unary(
+                op,
+                &mut local_tensor_ids_input,
+                &mut local_tensor_ids_output,
+            ),
+            ElemwiseOp::Sinh(op) => mark_unary(
+                op,
+                &mut local_tensor_ids_input,
+                &mut local_tensor_ids_output,
+            ),
+            ElemwiseOp::Cosh(op) => mark_unary(
+                op,
+                &mut local_tensor_ids_input,
+                &mut local_tensor_ids_output,
+            ),
+            ElemwiseOp::Asinh(op) => mark_unary(
+                op,
+                &mut local_tensor_ids_input,
+                &mut local_tensor_ids_output,
+            ),
+            ElemwiseOp::Acosh(op) => mark_unary(
+                op,
+                &mut local_tensor_ids_input,
+                &mut local_tensor_ids_output,
+            ),
+            ElemwiseOp::Atanh(op) => mark_unary(
+                op,
+                &mut local_tensor_ids_input,
+                &mut local_tensor_ids_output,
+            ),
+            ElemwiseOp::Expm1(op) => mark_unary(
+                op,
+                &mut local_tensor_ids_input,
+                &mut local_tensor_ids_output,
+            ),
+            ElemwiseOp::ErfInv(op) => mark_unary(
+                op,
+                &mut local_tensor_ids_input,
+                &mut local_tensor_ids_output,
+            ),
+            ElemwiseOp::Lgamma(op) => mark_unary(
+                op,
+                &mut local_tensor_ids_input,
+                &mut local_tensor_ids_output,
+            ),
+            ElemwiseOp::Digamma(op) => mark_unary(
+                op,
+                &mut local_tensor_ids_input,
+                &mut local_tensor_ids_output,
+            ),
+            ElemwiseOp::Trunc(op) => mark_unary(
+                op,
+                &mut local_tensor_ids_input,
+                &mut local_tensor_ids_output,
+            ),
+            ElemwiseOp::Atan2(op) => mark_binary(
+                op,
+                &mut local_tensor_ids_input,
+                &mut local_tensor_ids_output,
+            ),
+            ElemwiseOp::Hypot(op) => mark_binary(
+                op,
+                &mut local_tensor_ids_input,
+                &mut local_tensor_ids_output,
+            ),
+            ElemwiseOp::Fmod(op) => mark_binary(
+                op,
+                &mut local_tensor_ids_input,
+                &mut local_tensor_ids_output,
+            ),
             ElemwiseOp::Assign(op) => mark_unary(
                 op,
                 &mut local_tensor_ids_input,
@@ -0,0 +1,53 @@
+use std::marker::PhantomData;
+
+use cubecl::prelude::*;
+
+/// Atomically adds a float value, either with the intrinsic of a float type or with a
+/// compare-and-swap loop on the bits of an `f32`.
+#[cube]
+pub(crate) trait FloatAtomicAdd: Send + Sync + 'static {
+    type ProxyType: Numeric;
+
+    fn float_atomic_add<F: Float>(ptr: &mut Atomic<Self::ProxyType>, value: F);
+}
+
+/// Float atomic add with the intrinsic of the float type.
+#[derive(CubeType)]
+pub(crate) struct IntrinsicFloatAtomicAdd<F: Float> {
+    _ty: PhantomData<F>,
+}
+
+/// Float atomic add with a compare-and-swap loop, for the devices without float atomics.
+#[derive(CubeType)]
+pub(crate) struct CASFloatAtomicAdd;
+
+#[cube]
+impl<FAdd: Float> FloatAtomicAdd for IntrinsicFloatAtomicAdd<FAdd> {
+    type ProxyType = FAdd;
+
+    fn float_atomic_add<F: Float>(ptr: &mut Atomic<FAdd>, value: F) {
+        let value = FAdd::cast_from(value);
+        Atomic::add(ptr, value);
+    }
+}
+
+#[cube]
+impl FloatAtomicAdd for CASFloatAtomicAdd {
+    type ProxyType = u32;
+
+    fn float_atomic_add<F: Float>(ptr: &mut Atomic<Self::ProxyType>, value: F) {
+        let value = f32::cast_from(value);
+        if value != 0.0 {
+            let mut v = Atomic::load(ptr);
+            loop {
+                let prev = v;
+                let v_float = f32::bitcast_from(v);
+                let new = u32::bitcast_from(v_float + value);
+                v = Atomic::compare_and_swap(ptr, v, new);
+                if prev == v {
+                    break;
+                }
+            }
+        }
+    }
+}
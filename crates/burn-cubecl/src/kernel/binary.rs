@@ -8,6 +8,7 @@ use cubecl::{
 };
 
 use super::into_contiguous;
+use super::special::{atan2_line, fmod_line, hypot_line};
 
 pub(crate) trait BinaryOpFamily: Send + Sync + 'static {
     type BinaryOp<C: Numeric>: BinaryOp<C>;
@@ -37,6 +38,22 @@ pub(crate) struct PowOp<F: Float> {
     _f: PhantomData<F>,
 }
 
+/// Four-quadrant arctangent, casting the inputs to a valid float like [PowOp].
+pub(crate) struct Atan2Op<F: Float> {
+    _f: PhantomData<F>,
+}
+
+/// Hypotenuse, casting the inputs to a valid float like [PowOp].
+pub(crate) struct HypotOp<F: Float> {
+    _f: PhantomData<F>,
+}
+
+/// Remainder of the division truncated towards zero, casting the inputs to a valid float like
+/// [PowOp].
+pub(crate) struct FmodOp<F: Float> {
+    _f: PhantomData<F>,
+}
+
 impl BinaryOpFamily for AddOp {
     type BinaryOp<C: Numeric> = Self;
 }
@@ -61,6 +78,18 @@ impl<F: Float> BinaryOpFamily for PowOp<F> {
     type BinaryOp<C: Numeric> = Self;
 }
 
+impl<F: Float> BinaryOpFamily for Atan2Op<F> {
+    type BinaryOp<C: Numeric> = Self;
+}
+
+impl<F: Float> BinaryOpFamily for HypotOp<F> {
+    type BinaryOp<C: Numeric> = Self;
+}
+
+impl<F: Float> BinaryOpFamily for FmodOp<F> {
+    type BinaryOp<C: Numeric> = Self;
+}
+
 impl BinaryOpFamily for AndOp {
     type BinaryOp<C: Numeric> = Self;
 }
@@ -115,6 +144,36 @@ impl<N: Numeric, F: Float> BinaryOp<N> for PowOp<F> {
     }
 }
 
+#[cube]
+impl<N: Numeric, F: Float> BinaryOp<N> for Atan2Op<F> {
+    fn execute(lhs: Line<N>, rhs: Line<N>) -> Line<N> {
+        let lhs = Line::<F>::cast_from(lhs);
+        let rhs = Line::<F>::cast_from(rhs);
+
+        Line::cast_from(atan2_line::<F>(lhs, rhs))
+    }
+}
+
+#[cube]
+impl<N: Numeric, F: Float> BinaryOp<N> for HypotOp<F> {
+    fn execute(lhs: Line<N>, rhs: Line<N>) -> Line<N> {
+        let lhs = Line::<F>::cast_from(lhs);
+        let rhs = Line::<F>::cast_from(rhs);
+
+        Line::cast_from(hypot_line::<F>(lhs, rhs))
+    }
+}
+
+#[cube]
+impl<N: Numeric, F: Float> BinaryOp<N> for FmodOp<F> {
+    fn execute(lhs: Line<N>, rhs: Line<N>) -> Line<N> {
+        let lhs = Line::<F>::cast_from(lhs);
+        let rhs = Line::<F>::cast_from(rhs);
+
+        Line::cast_from(fmod_line::<F>(lhs, rhs))
+    }
+}
+
 #[cube]
 impl<N: Numeric> BinaryOp<N> for AndOp {
     fn execute(lhs: Line<N>, rhs: Line<N>) -> Line<N> {
@@ -1,5 +1,3 @@
-use std::marker::PhantomData;
-
 use burn_tensor::{
     ops::{DeformConv2dBackward, DeformConvOptions, FloatTensorOps as _},
     Shape,
@@ -12,6 +10,7 @@ use cubecl::{
 use crate::{
     element::BoolElement,
     kernel::{
+        atomic::{CASFloatAtomicAdd, FloatAtomicAdd, IntrinsicFloatAtomicAdd},
         cast, into_contiguous,
         matmul::{matmul, MatmulStrategy},
         slice_assign,
@@ -626,49 +625,3 @@ fn deform_col2img_kernel<F: Float, FAdd: FloatAtomicAdd>(
         }
     }
 }
-
-#[cube]
-trait FloatAtomicAdd: Send + Sync + 'static {
-    type ProxyType: Numeric;
-
-    fn float_atomic_add<F: Float>(ptr: &mut Atomic<Self::ProxyType>, value: F);
-}
-
-#[derive(CubeType)]
-struct IntrinsicFloatAtomicAdd<F: Float> {
-    _ty: PhantomData<F>,
-}
-
-#[derive(CubeType)]
-struct CASFloatAtomicAdd;
-
-#[cube]
-impl<FAdd: Float> FloatAtomicAdd for IntrinsicFloatAtomicAdd<FAdd> {
-    type ProxyType = FAdd;
-
-    fn float_atomic_add<F: Float>(ptr: &mut Atomic<FAdd>, value: F) {
-        let value = FAdd::cast_from(value);
-        Atomic::add(ptr, value);
-    }
-}
-
-#[cube]
-impl FloatAtomicAdd for CASFloatAtomicAdd {
-    type ProxyType = u32;
-
-    fn float_atomic_add<F: Float>(ptr: &mut Atomic<Self::ProxyType>, value: F) {
-        let value = f32::cast_from(value);
-        if value != 0.0 {
-            let mut v = Atomic::load(ptr);
-            loop {
-                let prev = v;
-                let v_float = f32::bitcast_from(v);
-                let new = u32::bitcast_from(v_float + value);
-                v = Atomic::compare_and_swap(ptr, v, new);
-                if prev == v {
-                    break;
-                }
-            }
-        }
-    }
-}
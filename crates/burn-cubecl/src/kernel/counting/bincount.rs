@@ -0,0 +1,193 @@
+use burn_tensor::Shape;
+use cubecl::{calculate_cube_count_elemwise, ir::Elem, prelude::*, AtomicFeature, Feature};
+
+use crate::{
+    kernel::{
+        atomic::{CASFloatAtomicAdd, FloatAtomicAdd, IntrinsicFloatAtomicAdd},
+        cast, into_contiguous,
+    },
+    ops::numeric::zeros_device,
+    tensor::CubeTensor,
+    CubeRuntime, FloatElement, IntElement,
+};
+
+/// Each unit counts one element of the tensor in the bin of its value.
+#[cube(launch_unchecked)]
+fn bincount_kernel<I: Int>(tensor: &Tensor<I>, counts: &mut Tensor<Atomic<u32>>) {
+    if ABSOLUTE_POS >= tensor.len() {
+        terminate!();
+    }
+
+    // The negative values are rejected before the launch, the guard keeps the writes in bounds.
+    let bin = u32::cast_from(tensor[ABSOLUTE_POS]);
+
+    if bin < counts.len() {
+        Atomic::add(&counts[bin], 1u32);
+    }
+}
+
+/// Each unit adds the weight of one element of the tensor to the bin of its value.
+#[cube(launch_unchecked)]
+fn bincount_weighted_kernel<F: Float, I: Int, FAdd: FloatAtomicAdd>(
+    tensor: &Tensor<I>,
+    weights: &Tensor<F>,
+    sums: &mut Tensor<Atomic<FAdd::ProxyType>>,
+) {
+    if ABSOLUTE_POS >= tensor.len() {
+        terminate!();
+    }
+
+    let bin = u32::cast_from(tensor[ABSOLUTE_POS]);
+
+    if bin < sums.len() {
+        FAdd::float_atomic_add::<F>(&mut sums[bin], weights[ABSOLUTE_POS]);
+    }
+}
+
+/// Each unit counts one element of the tensor in its bin, the elements outside of the bounds
+/// being ignored.
+#[cube(launch_unchecked)]
+fn histc_kernel<F: Float>(
+    tensor: &Tensor<F>,
+    counts: &mut Tensor<Atomic<u32>>,
+    min: f32,
+    max: f32,
+    scale: f32,
+) {
+    if ABSOLUTE_POS >= tensor.len() {
+        terminate!();
+    }
+
+    let value = f32::cast_from(tensor[ABSOLUTE_POS]);
+
+    if value >= min && value <= max {
+        // The elements equal to the upper bound are part of the last bin.
+        let bin = u32::cast_from(Floor::floor((value - min) * scale));
+        let bin = Min::min(bin, counts.len() - 1);
+
+        Atomic::add(&counts[bin], 1u32);
+    }
+}
+
+/// Counts the number of occurrences of each value of a 1D tensor of non-negative integers, the
+/// `length` of the output being greater than the maximum value.
+pub fn bincount<R: CubeRuntime, I: IntElement>(
+    tensor: CubeTensor<R>,
+    length: usize,
+) -> CubeTensor<R> {
+    let tensor = into_contiguous(tensor);
+    let counts = zeros_device::<R, u32>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        Shape::new([length]),
+    );
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(tensor.shape.num_elements(), cube_dim);
+
+    unsafe {
+        bincount_kernel::launch_unchecked::<I, R>(
+            &tensor.client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<I>(1),
+            counts.as_tensor_arg::<u32>(1),
+        )
+    };
+
+    cast::<R, u32, I>(counts)
+}
+
+/// Sums the weights of each value of a 1D tensor of non-negative integers, the `length` of the
+/// output being greater than the maximum value.
+pub fn bincount_weighted<R: CubeRuntime, F: FloatElement, I: IntElement>(
+    tensor: CubeTensor<R>,
+    weights: CubeTensor<R>,
+    length: usize,
+) -> CubeTensor<R> {
+    let tensor = into_contiguous(tensor);
+    let weights = into_contiguous(weights);
+    let client = tensor.client.clone();
+    let device = tensor.device.clone();
+
+    let kind = match F::as_elem_native_unchecked() {
+        Elem::Float(kind) => kind,
+        _ => unreachable!("Should be float"),
+    };
+    let props = client.properties();
+    let supports_fadd = props.feature_enabled(Feature::AtomicFloat(AtomicFeature::Add));
+    let supports_same_type = props.feature_enabled(Feature::Type(Elem::AtomicFloat(kind)));
+
+    // The sums are accumulated in `f32` when the float type has no atomic add.
+    let sums = match supports_fadd && supports_same_type {
+        true => zeros_device::<R, F>(client.clone(), device, Shape::new([length])),
+        false => zeros_device::<R, f32>(client.clone(), device, Shape::new([length])),
+    };
+    let sums_arg = match supports_fadd && supports_same_type {
+        true => sums.as_tensor_arg::<F>(1),
+        false => sums.as_tensor_arg::<f32>(1),
+    };
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(tensor.shape.num_elements(), cube_dim);
+
+    let launch = match (supports_fadd, supports_same_type) {
+        (true, true) => {
+            bincount_weighted_kernel::launch_unchecked::<F, I, IntrinsicFloatAtomicAdd<F>, R>
+        }
+        (true, false) => {
+            bincount_weighted_kernel::launch_unchecked::<F, I, IntrinsicFloatAtomicAdd<f32>, R>
+        }
+        _ => bincount_weighted_kernel::launch_unchecked::<F, I, CASFloatAtomicAdd, R>,
+    };
+
+    unsafe {
+        launch(
+            &client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<I>(1),
+            weights.as_tensor_arg::<F>(1),
+            sums_arg,
+        )
+    };
+
+    match supports_fadd && supports_same_type {
+        true => sums,
+        false => cast::<R, f32, F>(sums),
+    }
+}
+
+/// Computes the histogram of the tensor, with bins of equal width between `min` and `max`.
+pub fn histc<R: CubeRuntime, F: FloatElement>(
+    tensor: CubeTensor<R>,
+    bins: usize,
+    min: f64,
+    max: f64,
+) -> CubeTensor<R> {
+    let tensor = into_contiguous(tensor);
+    let counts = zeros_device::<R, u32>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        Shape::new([bins]),
+    );
+    let scale = bins as f64 / (max - min);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(tensor.shape.num_elements(), cube_dim);
+
+    unsafe {
+        histc_kernel::launch_unchecked::<F, R>(
+            &tensor.client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<F>(1),
+            counts.as_tensor_arg::<u32>(1),
+            ScalarArg::new(min as f32),
+            ScalarArg::new(max as f32),
+            ScalarArg::new(scale as f32),
+        )
+    };
+
+    cast::<R, u32, F>(counts)
+}
@@ -0,0 +1,5 @@
+mod bincount;
+mod searchsorted;
+
+pub use bincount::*;
+pub use searchsorted::*;
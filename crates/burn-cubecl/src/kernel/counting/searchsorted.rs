@@ -0,0 +1,80 @@
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{
+    element::CubeElement, kernel::into_contiguous, ops::numeric::empty_device, tensor::CubeTensor,
+    CubeRuntime, IntElement,
+};
+
+/// Each unit finds the insertion index of one value with a binary search in its sorted sequence.
+#[cube(launch_unchecked)]
+fn searchsorted_kernel<N: Numeric, I: Int>(
+    sorted: &Tensor<N>,
+    values: &Tensor<N>,
+    output: &mut Tensor<I>,
+    #[comptime] right: bool,
+) {
+    if ABSOLUTE_POS >= values.len() {
+        terminate!();
+    }
+
+    let rank = values.rank();
+    let size_sorted = sorted.shape(rank - 1);
+    let offset = ABSOLUTE_POS / values.shape(rank - 1) * size_sorted;
+    let value = values[ABSOLUTE_POS];
+
+    // The elements before the insertion index are the ones lower than the value, or equal to it
+    // when searching from the right.
+    let mut low = 0;
+    let mut high = size_sorted;
+
+    while low < high {
+        let middle = (low + high) / 2;
+        let element = sorted[offset + middle];
+
+        let mut before = element < value;
+        if right {
+            before = element <= value;
+        }
+
+        if before {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+
+    output[ABSOLUTE_POS] = I::cast_from(low);
+}
+
+/// Finds the indices where the values should be inserted in the sequences sorted along the last
+/// dimension to keep them sorted.
+pub fn searchsorted<R: CubeRuntime, E: CubeElement, I: IntElement>(
+    sorted: CubeTensor<R>,
+    values: CubeTensor<R>,
+    right: bool,
+) -> CubeTensor<R> {
+    let sorted = into_contiguous(sorted);
+    let values = into_contiguous(values);
+    let output = empty_device::<R, I>(
+        values.client.clone(),
+        values.device.clone(),
+        values.shape.clone(),
+    );
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(values.shape.num_elements(), cube_dim);
+
+    unsafe {
+        searchsorted_kernel::launch_unchecked::<E, I, R>(
+            &values.client,
+            cube_count,
+            cube_dim,
+            sorted.as_tensor_arg::<E>(1),
+            values.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<I>(1),
+            right,
+        )
+    };
+
+    output
+}
@@ -0,0 +1,313 @@
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{
+    kernel::into_contiguous,
+    ops::numeric::{empty_device, zeros_device},
+    tensor::CubeTensor,
+    CubeRuntime, FloatElement, IntElement,
+};
+
+/// The class of a state of the extended target, which interleaves the target with blanks.
+#[cube]
+fn label<I: Int>(targets: &Tensor<I>, offset: u32, state: u32, blank: u32) -> u32 {
+    let mut label = blank;
+    if state % 2 == 1 {
+        label = u32::cast_from(targets[offset + (state - 1) / 2]);
+    }
+    label
+}
+
+/// Whether the state can be reached from the state two positions before.
+#[cube]
+fn can_skip<I: Int>(targets: &Tensor<I>, offset: u32, state: u32, blank: u32) -> bool {
+    let mut can_skip = false;
+    if state >= 2 {
+        let current = label::<I>(targets, offset, state, blank);
+        let previous = label::<I>(targets, offset, state - 2, blank);
+        can_skip = current != blank && current != previous;
+    }
+    can_skip
+}
+
+/// Computes `log(exp(a) + exp(b))`, which is negative infinity when both terms are.
+#[cube]
+fn log_add_exp<F: Float>(a: F, b: F) -> F {
+    let max = Max::max(a, b);
+    let mut result = max;
+    if max > F::new(f32::NEG_INFINITY) {
+        result = max + F::log(F::exp(a - max) + F::exp(b - max));
+    }
+    result
+}
+
+/// Each unit runs the forward recursion of one sample, storing the forward variables of every
+/// step and the loss of the sample.
+#[cube(launch_unchecked)]
+fn ctc_forward_kernel<F: Float, I: Int>(
+    log_probs: &Tensor<F>,
+    targets: &Tensor<I>,
+    input_lengths: &Tensor<I>,
+    target_lengths: &Tensor<I>,
+    alphas: &mut Tensor<F>,
+    loss: &mut Tensor<F>,
+    blank: u32,
+) {
+    let batch = ABSOLUTE_POS;
+    if batch >= loss.len() {
+        terminate!();
+    }
+
+    let seq_length = log_probs.shape(1);
+    let num_classes = log_probs.shape(2);
+    let num_states = alphas.shape(2);
+    let input_length = Min::min(u32::cast_from(input_lengths[batch]), seq_length);
+    let states = 2 * u32::cast_from(target_lengths[batch]) + 1;
+
+    let offset_probs = batch * seq_length * num_classes;
+    let offset_targets = batch * targets.shape(1);
+    let offset_alphas = batch * seq_length * num_states;
+    let neg_inf = F::new(f32::NEG_INFINITY);
+
+    for state in 0..states {
+        alphas[offset_alphas + state] = neg_inf;
+    }
+
+    if input_length > 0 {
+        alphas[offset_alphas] = log_probs[offset_probs + blank];
+        if states > 1 {
+            let class = label::<I>(targets, offset_targets, 1, blank);
+            alphas[offset_alphas + 1] = log_probs[offset_probs + class];
+        }
+    }
+
+    for t in 1..input_length {
+        let previous = offset_alphas + (t - 1) * num_states;
+
+        for state in 0..states {
+            let mut sum = alphas[previous + state];
+            if state >= 1 {
+                sum = log_add_exp::<F>(sum, alphas[previous + state - 1]);
+            }
+            if can_skip::<I>(targets, offset_targets, state, blank) {
+                sum = log_add_exp::<F>(sum, alphas[previous + state - 2]);
+            }
+
+            let class = label::<I>(targets, offset_targets, state, blank);
+            alphas[offset_alphas + t * num_states + state] =
+                sum + log_probs[offset_probs + t * num_classes + class];
+        }
+    }
+
+    let mut log_likelihood = neg_inf;
+    if input_length > 0 {
+        let last = offset_alphas + (input_length - 1) * num_states;
+        log_likelihood = alphas[last + states - 1];
+        if states > 1 {
+            log_likelihood = log_add_exp::<F>(log_likelihood, alphas[last + states - 2]);
+        }
+    }
+
+    loss[batch] = -log_likelihood;
+}
+
+/// Each unit runs the backward recursion of one sample, and accumulates the posterior
+/// probabilities of its states on their classes as the gradients of the log-probabilities.
+#[cube(launch_unchecked)]
+fn ctc_backward_kernel<F: Float, I: Int>(
+    log_probs: &Tensor<F>,
+    targets: &Tensor<I>,
+    input_lengths: &Tensor<I>,
+    target_lengths: &Tensor<I>,
+    alphas: &Tensor<F>,
+    betas: &mut Tensor<F>,
+    loss: &Tensor<F>,
+    grad: &Tensor<F>,
+    output: &mut Tensor<F>,
+    blank: u32,
+) {
+    let batch = ABSOLUTE_POS;
+    if batch >= loss.len() {
+        terminate!();
+    }
+
+    let loss_value = loss[batch];
+    // The impossible targets have no alignment, so their gradients are zero.
+    if loss_value == F::new(f32::INFINITY) {
+        terminate!();
+    }
+
+    let seq_length = log_probs.shape(1);
+    let num_classes = log_probs.shape(2);
+    let num_states = alphas.shape(2);
+    let input_length = Min::min(u32::cast_from(input_lengths[batch]), seq_length);
+    let states = 2 * u32::cast_from(target_lengths[batch]) + 1;
+    let grad_value = grad[batch];
+
+    let offset_probs = batch * seq_length * num_classes;
+    let offset_targets = batch * targets.shape(1);
+    let offset_betas = batch * seq_length * num_states;
+    let neg_inf = F::new(f32::NEG_INFINITY);
+
+    for step in 0..input_length {
+        let t = input_length - 1 - step;
+        let current = offset_betas + t * num_states;
+        let next = current + num_states;
+
+        for state in 0..states {
+            // The alignments end with the last class of the target or with the blank after it.
+            let mut sum = neg_inf;
+            if t == input_length - 1 {
+                if state + 2 >= states {
+                    sum = F::new(0.0);
+                }
+            } else {
+                let class = label::<I>(targets, offset_targets, state, blank);
+                let emission = offset_probs + (t + 1) * num_classes;
+                sum = betas[next + state] + log_probs[emission + class];
+
+                if state + 1 < states {
+                    let class = label::<I>(targets, offset_targets, state + 1, blank);
+                    sum = log_add_exp::<F>(
+                        sum,
+                        betas[next + state + 1] + log_probs[emission + class],
+                    );
+                }
+                if state + 2 < states && can_skip::<I>(targets, offset_targets, state + 2, blank) {
+                    let class = label::<I>(targets, offset_targets, state + 2, blank);
+                    sum = log_add_exp::<F>(
+                        sum,
+                        betas[next + state + 2] + log_probs[emission + class],
+                    );
+                }
+            }
+            betas[current + state] = sum;
+
+            let class = label::<I>(targets, offset_targets, state, blank);
+            let posterior = F::exp(alphas[current + state] + sum + loss_value);
+            let index = offset_probs + t * num_classes + class;
+            output[index] = output[index] - posterior * grad_value;
+        }
+    }
+}
+
+fn launch_forward<R: CubeRuntime, E: FloatElement, I: IntElement>(
+    log_probs: &CubeTensor<R>,
+    targets: &CubeTensor<R>,
+    input_lengths: &CubeTensor<R>,
+    target_lengths: &CubeTensor<R>,
+    blank: usize,
+) -> (CubeTensor<R>, CubeTensor<R>) {
+    let [batch_size, seq_length, _] = log_probs.shape.dims();
+    let [_, max_target_length] = targets.shape.dims();
+    let num_states = 2 * max_target_length + 1;
+    let client = log_probs.client.clone();
+    let device = log_probs.device.clone();
+
+    let alphas = empty_device::<R, E>(
+        client.clone(),
+        device.clone(),
+        [batch_size, seq_length, num_states].into(),
+    );
+    let loss = empty_device::<R, E>(client.clone(), device, [batch_size].into());
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(batch_size, cube_dim);
+
+    unsafe {
+        ctc_forward_kernel::launch_unchecked::<E, I, R>(
+            &client,
+            cube_count,
+            cube_dim,
+            log_probs.as_tensor_arg::<E>(1),
+            targets.as_tensor_arg::<I>(1),
+            input_lengths.as_tensor_arg::<I>(1),
+            target_lengths.as_tensor_arg::<I>(1),
+            alphas.as_tensor_arg::<E>(1),
+            loss.as_tensor_arg::<E>(1),
+            ScalarArg::new(blank as u32),
+        )
+    };
+
+    (alphas, loss)
+}
+
+/// Computes the negative log-likelihood of the targets of the Connectionist Temporal
+/// Classification for each sample.
+pub fn ctc_loss<R: CubeRuntime, E: FloatElement, I: IntElement>(
+    log_probs: CubeTensor<R>,
+    targets: CubeTensor<R>,
+    input_lengths: CubeTensor<R>,
+    target_lengths: CubeTensor<R>,
+    blank: usize,
+) -> CubeTensor<R> {
+    let [_, _, num_classes] = log_probs.shape.dims();
+    assert!(
+        blank < num_classes,
+        "The blank index {blank} must be lower than the number of classes {num_classes}."
+    );
+
+    let (_, loss) = launch_forward::<R, E, I>(
+        &into_contiguous(log_probs),
+        &into_contiguous(targets),
+        &into_contiguous(input_lengths),
+        &into_contiguous(target_lengths),
+        blank,
+    );
+
+    loss
+}
+
+/// Computes the gradient of the [CTC loss](ctc_loss) with respect to the log-probabilities.
+pub fn ctc_loss_backward<R: CubeRuntime, E: FloatElement, I: IntElement>(
+    log_probs: CubeTensor<R>,
+    targets: CubeTensor<R>,
+    input_lengths: CubeTensor<R>,
+    target_lengths: CubeTensor<R>,
+    loss: CubeTensor<R>,
+    grad: CubeTensor<R>,
+    blank: usize,
+) -> CubeTensor<R> {
+    let log_probs = into_contiguous(log_probs);
+    let targets = into_contiguous(targets);
+    let input_lengths = into_contiguous(input_lengths);
+    let target_lengths = into_contiguous(target_lengths);
+    let loss = into_contiguous(loss);
+    let grad = into_contiguous(grad);
+
+    let (alphas, _) =
+        launch_forward::<R, E, I>(&log_probs, &targets, &input_lengths, &target_lengths, blank);
+    let betas = empty_device::<R, E>(
+        alphas.client.clone(),
+        alphas.device.clone(),
+        alphas.shape.clone(),
+    );
+    let output = zeros_device::<R, E>(
+        log_probs.client.clone(),
+        log_probs.device.clone(),
+        log_probs.shape.clone(),
+    );
+
+    let [batch_size, _, _] = log_probs.shape.dims();
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(batch_size, cube_dim);
+
+    unsafe {
+        ctc_backward_kernel::launch_unchecked::<E, I, R>(
+            &output.client,
+            cube_count,
+            cube_dim,
+            log_probs.as_tensor_arg::<E>(1),
+            targets.as_tensor_arg::<I>(1),
+            input_lengths.as_tensor_arg::<I>(1),
+            target_lengths.as_tensor_arg::<I>(1),
+            alphas.as_tensor_arg::<E>(1),
+            betas.as_tensor_arg::<E>(1),
+            loss.as_tensor_arg::<E>(1),
+            grad.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(blank as u32),
+        )
+    };
+
+    output
+}
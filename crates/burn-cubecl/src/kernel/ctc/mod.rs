@@ -0,0 +1,3 @@
+mod ctc_loss;
+
+pub use ctc_loss::*;
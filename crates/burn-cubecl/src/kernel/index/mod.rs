@@ -2,6 +2,7 @@ mod flip;
 mod gather;
 mod repeat_dim;
 mod scatter;
+mod scatter_reduce;
 mod select;
 mod select_assign;
 mod slice;
@@ -16,3 +17,4 @@ pub(crate) use slice_assign::*;
 
 pub(crate) use gather::*;
 pub(crate) use scatter::*;
+pub(crate) use scatter_reduce::*;
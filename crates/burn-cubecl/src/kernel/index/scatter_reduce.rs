@@ -0,0 +1,220 @@
+use crate::{
+    element::CubeElement,
+    kernel::{self},
+    ops::numeric::zeros_device,
+    tensor::CubeTensor,
+    CubeRuntime, IntElement,
+};
+use burn_tensor::Reduce;
+use cubecl::prelude::*;
+use cubecl::{calculate_cube_count_elemwise, CubeDim};
+
+#[cube]
+trait ScatterReduceOp<T: Numeric>: 'static + Send + Sync {
+    /// Combine the accumulated value with a scattered one.
+    fn execute(acc: T, value: T) -> T;
+}
+
+struct SumOp;
+struct ProdOp;
+struct MaxOp;
+struct MinOp;
+
+#[cube]
+impl<T: Numeric> ScatterReduceOp<T> for SumOp {
+    fn execute(acc: T, value: T) -> T {
+        acc + value
+    }
+}
+
+#[cube]
+impl<T: Numeric> ScatterReduceOp<T> for ProdOp {
+    fn execute(acc: T, value: T) -> T {
+        acc * value
+    }
+}
+
+#[cube]
+impl<T: Numeric> ScatterReduceOp<T> for MaxOp {
+    fn execute(acc: T, value: T) -> T {
+        Max::max(acc, value)
+    }
+}
+
+#[cube]
+impl<T: Numeric> ScatterReduceOp<T> for MinOp {
+    fn execute(acc: T, value: T) -> T {
+        Min::min(acc, value)
+    }
+}
+
+/// Each unit owns one line of the input along `dim` and walks the matching values sequentially,
+/// so no two units ever write to the same output element and no atomics are required.
+#[cube(launch_unchecked)]
+fn scatter_reduce_kernel<T: Numeric, I: Int, O: ScatterReduceOp<T>>(
+    input: &mut Tensor<T>,
+    indices: &Tensor<I>,
+    value: &Tensor<T>,
+    counts: &mut Tensor<u32>,
+    dim: &u32,
+    #[comptime] include_self: bool,
+    #[comptime] mean: bool,
+) {
+    let stride_input = input.stride(*dim);
+    let shape_input = input.shape(*dim);
+    let shape_value = value.shape(*dim);
+
+    let mut offset_input = 0;
+    let mut offset_value = 0;
+    let mut num_elems = 1;
+
+    for i in 0..value.rank() {
+        let shouldnt_skip = i != *dim;
+        if shouldnt_skip {
+            let shape_input_loop = input.shape(i);
+            let shape_value_loop = value.shape(i);
+
+            let stride_value_loop = value.stride(i);
+            let stride_input_loop = input.stride(i);
+            let stride_tmp = indices.stride(i);
+
+            let mut num_blocks = ABSOLUTE_POS / stride_tmp;
+            num_blocks %= shape_input_loop;
+
+            let mut offset_tmp = num_blocks * stride_input_loop;
+            offset_input += offset_tmp;
+
+            offset_tmp = num_blocks * stride_value_loop;
+            offset_value += offset_tmp;
+
+            num_elems *= shape_value_loop;
+        }
+    }
+
+    let should_stop = ABSOLUTE_POS >= num_elems;
+    if should_stop {
+        terminate!();
+    }
+
+    for i in 0..shape_value {
+        let mut idx = stride_input * i;
+        idx += offset_value;
+
+        let result_value = value[idx];
+        let result_indices = u32::cast_from(indices[idx]);
+
+        let mut index_input = stride_input * result_indices;
+        index_input += offset_input;
+
+        let count = counts[index_input];
+
+        if comptime![include_self] {
+            input[index_input] = O::execute(input[index_input], result_value);
+        } else if count == 0 {
+            input[index_input] = result_value;
+        } else {
+            input[index_input] = O::execute(input[index_input], result_value);
+        }
+
+        counts[index_input] = count + 1;
+    }
+
+    if comptime![mean] {
+        for i in 0..shape_input {
+            let mut index_input = stride_input * i;
+            index_input += offset_input;
+
+            let mut count = counts[index_input];
+            if comptime![include_self] {
+                count += 1;
+            }
+
+            if count > 1 {
+                input[index_input] = input[index_input] / T::cast_from(count);
+            }
+        }
+    }
+}
+
+pub(crate) fn scatter_reduce<R: CubeRuntime, E: CubeElement, I: IntElement>(
+    dim: usize,
+    tensor: CubeTensor<R>,
+    indices: CubeTensor<R>,
+    value: CubeTensor<R>,
+    reduce: Reduce,
+    include_self: bool,
+) -> CubeTensor<R> {
+    match reduce {
+        Reduce::Sum => launch::<R, E, I, SumOp>(dim, tensor, indices, value, include_self, false),
+        Reduce::Mean => launch::<R, E, I, SumOp>(dim, tensor, indices, value, include_self, true),
+        Reduce::Prod => launch::<R, E, I, ProdOp>(dim, tensor, indices, value, include_self, false),
+        Reduce::Max => launch::<R, E, I, MaxOp>(dim, tensor, indices, value, include_self, false),
+        Reduce::Min => launch::<R, E, I, MinOp>(dim, tensor, indices, value, include_self, false),
+    }
+}
+
+fn launch<R: CubeRuntime, E: CubeElement, I: IntElement, O: ScatterReduceOp<E>>(
+    dim: usize,
+    tensor: CubeTensor<R>,
+    indices: CubeTensor<R>,
+    value: CubeTensor<R>,
+    include_self: bool,
+    mean: bool,
+) -> CubeTensor<R> {
+    let ndims = tensor.shape.num_dims();
+    let mut indices = kernel::into_contiguous(indices);
+    let tensor = kernel::into_contiguous(tensor);
+    let value = kernel::into_contiguous(value);
+
+    let tensor = match tensor.can_mut() {
+        true => tensor,
+        false => tensor.copy(),
+    };
+
+    // Number of values scattered into each output element, used to detect the first write when
+    // the input is excluded and to divide the sum for the mean.
+    let counts = zeros_device::<R, u32>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+    );
+
+    let mut strides = vec![0; ndims];
+    let mut current = 1;
+    let mut num_elems = 1;
+
+    tensor
+        .shape
+        .dims
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|(index, _val)| *index != dim)
+        .for_each(|(index, val)| {
+            strides[index] = current;
+            current *= val;
+            num_elems *= tensor.shape.dims[index];
+        });
+
+    // Fake strides of the virtual output where the strides of dim is hardcoded to one.
+    indices.strides = strides;
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    unsafe {
+        scatter_reduce_kernel::launch_unchecked::<E, I, O, R>(
+            &indices.client.clone(),
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<E>(1),
+            indices.as_tensor_arg::<I>(1),
+            value.as_tensor_arg::<E>(1),
+            counts.as_tensor_arg::<u32>(1),
+            ScalarArg::new(dim as u32),
+            include_self,
+            mean,
+        )
+    }
+    tensor
+}
@@ -29,8 +29,15 @@ pub fn interpolate<R: CubeRuntime, E: FloatElement>(
 
     match options.mode {
         InterpolateMode::Nearest => interpolate_nearest_launch::<R, E>(input, output),
-        InterpolateMode::Bilinear => interpolate_bilinear_launch::<R, E>(input, output),
-        InterpolateMode::Bicubic => interpolate_bicubic_launch::<R, E>(input, output),
+        InterpolateMode::Linear | InterpolateMode::Bilinear => {
+            interpolate_bilinear_launch::<R, E>(input, output, options.align_corners)
+        }
+        InterpolateMode::Bicubic => {
+            interpolate_bicubic_launch::<R, E>(input, output, options.align_corners)
+        }
+        InterpolateMode::Trilinear => {
+            panic!("trilinear interpolation is only supported by the 3D interpolation")
+        }
     }
 }
 
@@ -57,11 +64,14 @@ pub fn interpolate_backward<R: CubeRuntime, E: FloatElement>(
 
     match options.mode {
         InterpolateMode::Nearest => interpolate_nearest_backward_launch::<R, E>(out_grad, output),
-        InterpolateMode::Bilinear => {
+        InterpolateMode::Linear | InterpolateMode::Bilinear => {
             panic!("bilinear interpolation backward is not supported by JIT backend")
         }
         InterpolateMode::Bicubic => {
             panic!("bicubic interpolation backward is not supported by JIT backend")
         }
+        InterpolateMode::Trilinear => {
+            panic!("trilinear interpolation is only supported by the 3D interpolation")
+        }
     }
 }
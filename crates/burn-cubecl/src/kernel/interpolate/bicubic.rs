@@ -2,8 +2,14 @@ use cubecl::{calculate_cube_count_elemwise, prelude::*};
 
 use crate::{tensor::CubeTensor, CubeRuntime, FloatElement};
 
+use super::bilinear::source_position;
+
 #[cube(launch)]
-fn interpolate_bicubic_kernel<F: Float>(input: &Tensor<F>, output: &mut Tensor<F>) {
+fn interpolate_bicubic_kernel<F: Float>(
+    input: &Tensor<F>,
+    output: &mut Tensor<F>,
+    #[comptime] align_corners: bool,
+) {
     if ABSOLUTE_POS >= output.len() {
         terminate!();
     }
@@ -14,31 +20,26 @@ fn interpolate_bicubic_kernel<F: Float>(input: &Tensor<F>, output: &mut Tensor<F
     let x = ABSOLUTE_POS / output.stride(3) % output.shape(3);
 
     let input_height = input.shape(2) - 1;
-    let output_height = F::cast_from(Max::max(output.shape(2) - 1, 1));
-    let numerator = F::cast_from(y * input_height);
-
-    let frac = numerator / output_height;
+    let frac = source_position::<F>(y, input.shape(2), output.shape(2), align_corners);
     let y_in_f = Floor::floor(frac);
-    let y_in = u32::cast_from(y_in_f);
+    let y_in = i32::cast_from(y_in_f);
     let yw = frac - y_in_f;
 
-    let y0 = select(y_in != 0, y_in - 1, 0);
-    let y1 = y_in;
-    let y2 = Min::min(y_in + 1, input_height);
-    let y3 = Min::min(y_in + 2, input_height);
+    let y0 = clamp_index(y_in - 1, input_height);
+    let y1 = clamp_index(y_in, input_height);
+    let y2 = clamp_index(y_in + 1, input_height);
+    let y3 = clamp_index(y_in + 2, input_height);
 
     let input_width = input.shape(3) - 1;
-    let output_width = F::cast_from(Max::max(output.shape(3) - 1, 1));
-    let numerator = F::cast_from(x * input_width);
-    let frac = numerator / output_width;
+    let frac = source_position::<F>(x, input.shape(3), output.shape(3), align_corners);
     let x_in_f = Floor::floor(frac);
-    let x_in = u32::cast_from(x_in_f);
+    let x_in = i32::cast_from(x_in_f);
     let xw = frac - x_in_f;
 
-    let x0 = select(x_in != 0, x_in - 1, 0);
-    let x1 = x_in;
-    let x2 = Min::min(x_in + 1, input_width);
-    let x3 = Min::min(x_in + 2, input_width);
+    let x0 = clamp_index(x_in - 1, input_width);
+    let x1 = clamp_index(x_in, input_width);
+    let x2 = clamp_index(x_in + 1, input_width);
+    let x3 = clamp_index(x_in + 2, input_width);
 
     let index_base = batch * input.stride(0) + channel * input.stride(1);
     let in_stride_y = input.stride(2);
@@ -92,6 +93,11 @@ fn interpolate_bicubic_kernel<F: Float>(input: &Tensor<F>, output: &mut Tensor<F
     output[ABSOLUTE_POS] = val;
 }
 
+#[cube]
+fn clamp_index(index: i32, last: u32) -> u32 {
+    u32::cast_from(Max::max(Min::min(index, i32::cast_from(last)), 0))
+}
+
 #[cube]
 fn cubic_interp_1d<F: Float>(x0: F, x1: F, x2: F, x3: F, t: F) -> F {
     let a = F::new(-0.75);
@@ -124,6 +130,7 @@ fn cubic_convolution_2<F: Float>(x: F, a: F) -> F {
 pub(crate) fn interpolate_bicubic_launch<R: CubeRuntime, E: FloatElement>(
     input: CubeTensor<R>,
     output: CubeTensor<R>,
+    align_corners: bool,
 ) -> CubeTensor<R> {
     let cube_dim = CubeDim::default();
     let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
@@ -134,6 +141,7 @@ pub(crate) fn interpolate_bicubic_launch<R: CubeRuntime, E: FloatElement>(
         cube_dim,
         input.as_tensor_arg::<E>(1),
         output.as_tensor_arg::<E>(1),
+        align_corners,
     );
 
     output
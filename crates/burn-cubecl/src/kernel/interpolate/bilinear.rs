@@ -3,7 +3,11 @@ use cubecl::{calculate_cube_count_elemwise, prelude::*};
 use crate::{tensor::CubeTensor, CubeRuntime, FloatElement};
 
 #[cube(launch)]
-fn interpolate_bilinear_kernel<F: Float>(input: &Tensor<F>, output: &mut Tensor<F>) {
+fn interpolate_bilinear_kernel<F: Float>(
+    input: &Tensor<F>,
+    output: &mut Tensor<F>,
+    #[comptime] align_corners: bool,
+) {
     if ABSOLUTE_POS >= output.len() {
         terminate!();
     }
@@ -13,31 +17,24 @@ fn interpolate_bilinear_kernel<F: Float>(input: &Tensor<F>, output: &mut Tensor<
     let y = ABSOLUTE_POS / output.stride(2) % output.shape(2);
     let x = ABSOLUTE_POS / output.stride(3) % output.shape(3);
 
-    let numerator = F::cast_from(input.shape(2) - 1);
-    let denominator = F::cast_from(Max::max(output.shape(2) - 1, 1));
-    let factor = F::cast_from(y);
-
-    let frac = factor * (numerator / denominator);
+    let height = input.shape(2);
+    let width = input.shape(3);
 
+    let frac = source_position::<F>(y, height, output.shape(2), align_corners);
+    let frac = Max::max(frac, F::new(0.0));
     let v0 = Floor::floor(frac);
-    let v1: F = Ceil::ceil(frac);
     let yw = frac - v0;
     let yw_ = F::new(1.0) - yw;
-    let y0_ok = v0 >= F::new(0.0);
-    let y0 = u32::cast_from(v0);
-    let y1 = u32::cast_from(v1);
+    let y0 = Min::min(u32::cast_from(v0), height - 1);
+    let y1 = Min::min(y0 + 1, height - 1);
 
-    let numerator = F::cast_from(input.shape(3) - 1);
-    let denominator = F::cast_from(Max::max(output.shape(3) - 1, 1));
-    let factor = F::cast_from(x);
-    let frac = factor * (numerator / denominator);
+    let frac = source_position::<F>(x, width, output.shape(3), align_corners);
+    let frac = Max::max(frac, F::new(0.0));
     let v0 = Floor::floor(frac);
-    let v1: F = Ceil::ceil(frac);
     let xw = frac - v0;
     let xw_ = F::new(1.0) - xw;
-    let x0_ok = v0 >= F::new(0.0);
-    let x0 = u32::cast_from(v0);
-    let x1 = u32::cast_from(v1);
+    let x0 = Min::min(u32::cast_from(v0), width - 1);
+    let x1 = Min::min(x0 + 1, width - 1);
 
     let index_base = batch * input.stride(0) + channel * input.stride(1);
 
@@ -49,39 +46,37 @@ fn interpolate_bilinear_kernel<F: Float>(input: &Tensor<F>, output: &mut Tensor<
     let x0_stride = x0 * in_stride_x;
     let x1_stride = x1 * in_stride_x;
 
-    let height = input.shape(2);
-    let width = input.shape(3);
-
-    let y1_ok = y1 < height;
-    let x1_ok = x1 < width;
-
-    let p_a = select(
-        x0_ok && y0_ok,
-        input[index_base + y0_stride + x0_stride] * xw_ * yw_,
-        F::new(0.0),
-    );
-    let p_b = select(
-        x1_ok && y0_ok,
-        input[index_base + y0_stride + x1_stride] * xw * yw_,
-        F::new(0.0),
-    );
-    let p_c = select(
-        x0_ok && y1_ok,
-        input[index_base + y1_stride + x0_stride] * xw_ * yw,
-        F::new(0.0),
-    );
-    let p_d = select(
-        x1_ok && y1_ok,
-        input[index_base + y1_stride + x1_stride] * xw * yw,
-        F::new(0.0),
-    );
+    let p_a = input[index_base + y0_stride + x0_stride] * xw_ * yw_;
+    let p_b = input[index_base + y0_stride + x1_stride] * xw * yw_;
+    let p_c = input[index_base + y1_stride + x0_stride] * xw_ * yw;
+    let p_d = input[index_base + y1_stride + x1_stride] * xw * yw;
 
     output[ABSOLUTE_POS] = p_a + p_b + p_c + p_d;
 }
 
+/// The position in the input corresponding to an output position, where the centers of the
+/// corner elements are aligned with `align_corners` and their outer corners otherwise.
+#[cube]
+pub(crate) fn source_position<F: Float>(
+    index: u32,
+    input_size: u32,
+    output_size: u32,
+    #[comptime] align_corners: bool,
+) -> F {
+    if comptime![align_corners] {
+        let numerator = F::cast_from(input_size - 1);
+        let denominator = F::cast_from(Max::max(output_size - 1, 1));
+        F::cast_from(index) * (numerator / denominator)
+    } else {
+        let scale = F::cast_from(input_size) / F::cast_from(output_size);
+        (F::cast_from(index) + F::new(0.5)) * scale - F::new(0.5)
+    }
+}
+
 pub(crate) fn interpolate_bilinear_launch<R: CubeRuntime, F: FloatElement>(
     input: CubeTensor<R>,
     output: CubeTensor<R>,
+    align_corners: bool,
 ) -> CubeTensor<R> {
     let cube_dim = CubeDim::default();
     let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
@@ -92,6 +87,7 @@ pub(crate) fn interpolate_bilinear_launch<R: CubeRuntime, F: FloatElement>(
         cube_dim,
         input.as_tensor_arg::<F>(1),
         output.as_tensor_arg::<F>(1),
+        align_corners,
     );
 
     output
@@ -0,0 +1,158 @@
+use burn_tensor::Shape;
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use super::{matmul, MatmulStrategy};
+use crate::{
+    kernel::{into_contiguous, slice},
+    ops::numeric::empty_device,
+    tensor::CubeTensor,
+    CubeRuntime, FloatElement,
+};
+
+/// Each unit writes one element of the real matrix `[[a, -b], [b, a]]` representing the complex
+/// matrix `a + ib`.
+#[cube(launch_unchecked)]
+fn complex_lhs_kernel<F: Float>(real: &Tensor<F>, imag: &Tensor<F>, output: &mut Tensor<F>) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let m = real.shape(real.rank() - 2);
+    let k = real.shape(real.rank() - 1);
+    let batch = ABSOLUTE_POS / (4 * m * k);
+    let row = (ABSOLUTE_POS / (2 * k)) % (2 * m);
+    let col = ABSOLUTE_POS % (2 * k);
+    let index = batch * m * k + (row % m) * k + col % k;
+
+    if row < m {
+        if col < k {
+            output[ABSOLUTE_POS] = real[index];
+        } else {
+            output[ABSOLUTE_POS] = -imag[index];
+        }
+    } else if col < k {
+        output[ABSOLUTE_POS] = imag[index];
+    } else {
+        output[ABSOLUTE_POS] = real[index];
+    }
+}
+
+/// Each unit writes one element of the real matrix `[[c], [d]]` stacking the parts of the complex
+/// matrix `c + id`.
+#[cube(launch_unchecked)]
+fn complex_rhs_kernel<F: Float>(real: &Tensor<F>, imag: &Tensor<F>, output: &mut Tensor<F>) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let k = real.shape(real.rank() - 2);
+    let n = real.shape(real.rank() - 1);
+    let batch = ABSOLUTE_POS / (2 * k * n);
+    let row = (ABSOLUTE_POS / n) % (2 * k);
+    let col = ABSOLUTE_POS % n;
+    let index = batch * k * n + (row % k) * n + col;
+
+    if row < k {
+        output[ABSOLUTE_POS] = real[index];
+    } else {
+        output[ABSOLUTE_POS] = imag[index];
+    }
+}
+
+/// Multiplies the complex matrices `a + ib` and `c + id` given by their real and imaginary parts,
+/// returning the parts of the product.
+///
+/// The product is computed with a single real matmul, `[[a, -b], [b, a]] x [[c], [d]]` holding
+/// the real part `ac - bd` in its top rows and the imaginary part `bc + ad` in its bottom rows.
+pub fn complex_matmul<R: CubeRuntime, E: FloatElement>(
+    lhs_real: CubeTensor<R>,
+    lhs_imag: CubeTensor<R>,
+    rhs_real: CubeTensor<R>,
+    rhs_imag: CubeTensor<R>,
+    strategy: MatmulStrategy,
+) -> (CubeTensor<R>, CubeTensor<R>) {
+    let ndims = lhs_real.shape.num_dims();
+    let m = lhs_real.shape.dims[ndims - 2];
+
+    let lhs = complex_lhs::<R, E>(lhs_real, lhs_imag);
+    let rhs = complex_rhs::<R, E>(rhs_real, rhs_imag);
+    let output = matmul::<R, E>(lhs, rhs, None, strategy).unwrap();
+
+    let mut ranges = output
+        .shape
+        .dims
+        .iter()
+        .map(|&dim| 0..dim)
+        .collect::<Vec<_>>();
+    ranges[ndims - 2] = 0..m;
+    let real = slice::<R, E>(output.clone(), &ranges);
+    ranges[ndims - 2] = m..2 * m;
+    let imag = slice::<R, E>(output, &ranges);
+
+    (real, imag)
+}
+
+/// Writes the real block matrix `[[a, -b], [b, a]]` of the complex matrix `a + ib`.
+fn complex_lhs<R: CubeRuntime, E: FloatElement>(
+    real: CubeTensor<R>,
+    imag: CubeTensor<R>,
+) -> CubeTensor<R> {
+    let real = into_contiguous(real);
+    let imag = into_contiguous(imag);
+    let output = empty_block::<R, E>(&real, 2, 2);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        complex_lhs_kernel::launch_unchecked::<E, R>(
+            &output.client,
+            cube_count,
+            cube_dim,
+            real.as_tensor_arg::<E>(1),
+            imag.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+        )
+    };
+
+    output
+}
+
+/// Writes the real block matrix `[[c], [d]]` of the complex matrix `c + id`.
+fn complex_rhs<R: CubeRuntime, E: FloatElement>(
+    real: CubeTensor<R>,
+    imag: CubeTensor<R>,
+) -> CubeTensor<R> {
+    let real = into_contiguous(real);
+    let imag = into_contiguous(imag);
+    let output = empty_block::<R, E>(&real, 2, 1);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        complex_rhs_kernel::launch_unchecked::<E, R>(
+            &output.client,
+            cube_count,
+            cube_dim,
+            real.as_tensor_arg::<E>(1),
+            imag.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+        )
+    };
+
+    output
+}
+
+fn empty_block<R: CubeRuntime, E: FloatElement>(
+    part: &CubeTensor<R>,
+    row_factor: usize,
+    col_factor: usize,
+) -> CubeTensor<R> {
+    let ndims = part.shape.num_dims();
+    let mut dims = part.shape.dims.clone();
+    dims[ndims - 2] *= row_factor;
+    dims[ndims - 1] *= col_factor;
+
+    empty_device::<R, E>(part.client.clone(), part.device.clone(), Shape::from(dims))
+}
@@ -0,0 +1,151 @@
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{
+    kernel::into_contiguous, ops::numeric::empty_device, tensor::CubeTensor, CubeRuntime,
+    FloatElement, IntElement,
+};
+
+/// Each unit computes one element of the output, the group of its row being the number of groups
+/// ending before the row.
+#[cube(launch_unchecked)]
+fn grouped_matmul_kernel<F: Float, I: Int>(
+    lhs: &Tensor<F>,
+    rhs: &Tensor<F>,
+    offsets: &Tensor<I>,
+    output: &mut Tensor<F>,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let k = lhs.shape(1);
+    let n = output.shape(1);
+    let num_groups = offsets.len();
+    let row = ABSOLUTE_POS / n;
+    let col = ABSOLUTE_POS % n;
+
+    let mut group = 0;
+    for g in 0..num_groups {
+        if u32::cast_from(offsets[g]) <= row {
+            group = g + 1;
+        }
+    }
+
+    // The rows after the last group aren't multiplied.
+    let mut sum = F::new(0.0);
+    if group < num_groups {
+        let offset_lhs = row * k;
+        let offset_rhs = group * k * n + col;
+
+        for i in 0..k {
+            sum += lhs[offset_lhs + i] * rhs[offset_rhs + i * n];
+        }
+    }
+
+    output[ABSOLUTE_POS] = sum;
+}
+
+/// Each unit computes one element of the gradient of a group matrix, summing the products of
+/// the rows of the group.
+#[cube(launch_unchecked)]
+fn grouped_matmul_rhs_backward_kernel<F: Float, I: Int>(
+    lhs: &Tensor<F>,
+    grad: &Tensor<F>,
+    offsets: &Tensor<I>,
+    output: &mut Tensor<F>,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let k = output.shape(1);
+    let n = output.shape(2);
+    let group = ABSOLUTE_POS / (k * n);
+    let i = (ABSOLUTE_POS / n) % k;
+    let j = ABSOLUTE_POS % n;
+
+    let mut start = 0;
+    if group > 0 {
+        start = u32::cast_from(offsets[group - 1]);
+    }
+    let end = u32::cast_from(offsets[group]);
+
+    let mut sum = F::new(0.0);
+    for row in start..end {
+        sum += lhs[row * k + i] * grad[row * n + j];
+    }
+
+    output[ABSOLUTE_POS] = sum;
+}
+
+/// Multiplies each row of `lhs` with the matrix of its group in `rhs`, the rows being sorted by
+/// group and `offsets` holding the end of the rows of each group.
+///
+/// All groups are computed with a single kernel launch, without reading the offsets.
+pub fn grouped_matmul<R: CubeRuntime, E: FloatElement, I: IntElement>(
+    lhs: CubeTensor<R>,
+    rhs: CubeTensor<R>,
+    offsets: CubeTensor<R>,
+) -> CubeTensor<R> {
+    let lhs = into_contiguous(lhs);
+    let rhs = into_contiguous(rhs);
+    let offsets = into_contiguous(offsets);
+
+    let [num_rows, _] = lhs.shape.dims();
+    let [_, _, n] = rhs.shape.dims();
+    let output = empty_device::<R, E>(lhs.client.clone(), lhs.device.clone(), [num_rows, n].into());
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        grouped_matmul_kernel::launch_unchecked::<E, I, R>(
+            &output.client,
+            cube_count,
+            cube_dim,
+            lhs.as_tensor_arg::<E>(1),
+            rhs.as_tensor_arg::<E>(1),
+            offsets.as_tensor_arg::<I>(1),
+            output.as_tensor_arg::<E>(1),
+        )
+    };
+
+    output
+}
+
+/// Computes the gradient of the group matrices of the [grouped matmul](grouped_matmul).
+pub fn grouped_matmul_rhs_backward<R: CubeRuntime, E: FloatElement, I: IntElement>(
+    lhs: CubeTensor<R>,
+    grad: CubeTensor<R>,
+    offsets: CubeTensor<R>,
+) -> CubeTensor<R> {
+    let lhs = into_contiguous(lhs);
+    let grad = into_contiguous(grad);
+    let offsets = into_contiguous(offsets);
+
+    let [_, k] = lhs.shape.dims();
+    let [_, n] = grad.shape.dims();
+    let [num_groups] = offsets.shape.dims();
+    let output = empty_device::<R, E>(
+        lhs.client.clone(),
+        lhs.device.clone(),
+        [num_groups, k, n].into(),
+    );
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        grouped_matmul_rhs_backward_kernel::launch_unchecked::<E, I, R>(
+            &output.client,
+            cube_count,
+            cube_dim,
+            lhs.as_tensor_arg::<E>(1),
+            grad.as_tensor_arg::<E>(1),
+            offsets.as_tensor_arg::<I>(1),
+            output.as_tensor_arg::<E>(1),
+        )
+    };
+
+    output
+}
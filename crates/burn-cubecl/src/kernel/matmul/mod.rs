@@ -1,9 +1,13 @@
 mod base;
+mod complex;
+mod grouped;
 mod tune;
 
 /// Contains utilitary for matmul operation
 pub mod utils;
 
 pub use base::*;
+pub use complex::*;
+pub use grouped::*;
 pub use tune::*;
 pub use utils::*;
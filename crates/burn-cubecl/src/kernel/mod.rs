@@ -1,3 +1,4 @@
+mod atomic;
 mod binary;
 mod binary_int;
 mod cast;
@@ -6,6 +7,7 @@ mod comparison;
 mod contiguous;
 mod index;
 mod mask;
+mod special;
 mod unary_float;
 mod unary_int;
 mod unary_numeric;
@@ -24,6 +26,10 @@ pub use cubecl::Kernel;
 
 /// Convolution kernels
 pub mod conv;
+/// Counting kernels
+pub mod counting;
+/// Connectionist Temporal Classification kernels
+pub mod ctc;
 /// Interpolation kernels
 pub mod interpolate;
 /// Matmul kernels
@@ -36,6 +42,10 @@ pub mod prng;
 pub mod quantization;
 /// Reduction algorithms
 pub mod reduce;
+/// Scan kernels
+pub mod scan;
+/// Pixel shuffle kernels
+pub mod shuffle;
 
 pub(crate) use clamp::*;
 pub(crate) use comparison::*;
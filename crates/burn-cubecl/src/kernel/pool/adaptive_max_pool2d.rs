@@ -0,0 +1,166 @@
+use crate::{
+    element::CubeElement, kernel::into_contiguous, ops::numeric::empty_device, tensor::CubeTensor,
+    CubeRuntime, IntElement,
+};
+use burn_tensor::Shape;
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+#[cube(launch)]
+fn adaptive_max_pool2d_with_indices_direct<E: Numeric, I: Int>(
+    input: &Tensor<E>,
+    output: &mut Tensor<E>,
+    indices: &mut Tensor<I>,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let b = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let c = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let oh = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let ow = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+
+    let (input_shape_2, input_shape_3) = (input.shape(2), input.shape(3));
+
+    let ih_start = start_index(oh, output.shape(2), input_shape_2);
+    let ih_end = end_index(oh, output.shape(2), input_shape_2);
+    let iw_start = start_index(ow, output.shape(3), input_shape_3);
+    let iw_end = end_index(ow, output.shape(3), input_shape_3);
+
+    let index_base = b * input.stride(0) + c * input.stride(1);
+
+    let mut max = E::min_value();
+    let mut max_index = ih_start * input_shape_3 + iw_start;
+
+    for ih in ih_start..ih_end {
+        for iw in iw_start..iw_end {
+            let value = input[index_base + ih * input.stride(2) + iw * input.stride(3)];
+
+            if value > max {
+                max = value;
+                max_index = ih * input_shape_3 + iw;
+            }
+        }
+    }
+
+    output[ABSOLUTE_POS] = max;
+    indices[ABSOLUTE_POS] = I::cast_from(max_index);
+}
+
+#[cube(launch)]
+fn adaptive_max_pool2d_backward_direct<E: Numeric, I: Int>(
+    grad: &Tensor<E>,
+    indices: &Tensor<I>,
+    output: &mut Tensor<E>,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let b = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let c = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let ih = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let iw = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+
+    let (output_shape_2, output_shape_3) = (output.shape(2), output.shape(3));
+    let (grad_shape_2, grad_shape_3) = (grad.shape(2), grad.shape(3));
+
+    let index_current = ih * output_shape_3 + iw;
+
+    // The windows containing the current position are among the windows of the adaptive pooling
+    // from the output to the input.
+    let oh_start = start_index(ih, output_shape_2, grad_shape_2);
+    let oh_end = end_index(ih, output_shape_2, grad_shape_2);
+    let ow_start = start_index(iw, output_shape_3, grad_shape_3);
+    let ow_end = end_index(iw, output_shape_3, grad_shape_3);
+
+    let index_base = b * grad.stride(0) + c * grad.stride(1);
+    let mut grad_acc = E::from_int(0);
+
+    for oh in oh_start..oh_end {
+        for ow in ow_start..ow_end {
+            let index = index_base + oh * grad.stride(2) + ow * grad.stride(3);
+            let index_max = u32::cast_from(indices[index]);
+
+            grad_acc += select(index_max == index_current, grad[index], E::from_int(0));
+        }
+    }
+
+    output[ABSOLUTE_POS] = grad_acc;
+}
+
+#[cube]
+fn start_index(output_size_index: u32, output_size: u32, input_size: u32) -> u32 {
+    (output_size_index * input_size) / output_size
+}
+
+#[allow(unknown_lints)] // `manual_div_ceil` only appeared in 1.83
+#[allow(clippy::manual_div_ceil)]
+#[cube]
+fn end_index(output_size_index: u32, output_size: u32, input_size: u32) -> u32 {
+    let index = (output_size_index + 1) * input_size;
+    let index = (index + output_size - 1) / output_size;
+
+    if input_size < index {
+        input_size
+    } else {
+        index
+    }
+}
+
+pub(crate) fn adaptive_max_pool2d_with_indices<R: CubeRuntime, E: CubeElement, I: CubeElement>(
+    input: CubeTensor<R>,
+    output_size: [usize; 2],
+) -> (CubeTensor<R>, CubeTensor<R>) {
+    let [batch_size, channels, _, _] = input.shape.dims();
+
+    let output_shape = Shape::new([batch_size, channels, output_size[0], output_size[1]]);
+    let output = empty_device::<R, E>(
+        input.client.clone(),
+        input.device.clone(),
+        output_shape.clone(),
+    );
+    let indices = empty_device::<R, I>(input.client.clone(), input.device.clone(), output_shape);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    adaptive_max_pool2d_with_indices_direct::launch::<E, I, R>(
+        &input.client,
+        cube_count,
+        cube_dim,
+        input.as_tensor_arg::<E>(1),
+        output.as_tensor_arg::<E>(1),
+        indices.as_tensor_arg::<I>(1),
+    );
+
+    (output, indices)
+}
+
+pub(crate) fn adaptive_max_pool2d_with_indices_backward<
+    R: CubeRuntime,
+    E: CubeElement,
+    I: IntElement,
+>(
+    x: CubeTensor<R>,
+    grad: CubeTensor<R>,
+    indices: CubeTensor<R>,
+) -> CubeTensor<R> {
+    let grad = into_contiguous(grad);
+    let indices = into_contiguous(indices);
+
+    let output = empty_device::<R, E>(x.client.clone(), x.device.clone(), x.shape.clone());
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    adaptive_max_pool2d_backward_direct::launch::<E, I, R>(
+        &x.client,
+        cube_count,
+        cube_dim,
+        grad.as_tensor_arg::<E>(1),
+        indices.as_tensor_arg::<I>(1),
+        output.as_tensor_arg::<E>(1),
+    );
+
+    output
+}
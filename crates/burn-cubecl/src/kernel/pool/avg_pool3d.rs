@@ -0,0 +1,221 @@
+use super::pool3d::{
+    input_range, kernel_size_comptime, output_range, pool3d_args, pool3d_output_shape, Pool3dArgs,
+};
+use crate::{
+    element::CubeElement, kernel::into_contiguous, ops::numeric::empty_device, tensor::CubeTensor,
+    CubeRuntime,
+};
+use cubecl::{calculate_cube_count_elemwise, prelude::*, CubeDim};
+
+#[cube(launch_unchecked)]
+fn avg_pool3d_kernel<E: Numeric>(
+    input: &Tensor<E>,
+    output: &mut Tensor<E>,
+    args: &Pool3dArgs,
+    #[comptime] kernel_size: (u32, u32, u32),
+    #[comptime] count_include_pad: bool,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let b = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let c = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let od = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let oh = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+    let ow = ABSOLUTE_POS / output.stride(4) % output.shape(4);
+
+    let (id_start, id_end) = input_range(
+        od,
+        input.shape(2),
+        args.stride_0,
+        args.padding_0,
+        kernel_size.0,
+    );
+    let (ih_start, ih_end) = input_range(
+        oh,
+        input.shape(3),
+        args.stride_1,
+        args.padding_1,
+        kernel_size.1,
+    );
+    let (iw_start, iw_end) = input_range(
+        ow,
+        input.shape(4),
+        args.stride_2,
+        args.padding_2,
+        kernel_size.2,
+    );
+
+    let index_base = b * input.stride(0) + c * input.stride(1);
+    let mut sum = E::from_int(0);
+
+    for id in id_start..id_end {
+        for ih in ih_start..ih_end {
+            for iw in iw_start..iw_end {
+                let index =
+                    index_base + id * input.stride(2) + ih * input.stride(3) + iw * input.stride(4);
+                sum += input[index];
+            }
+        }
+    }
+
+    if comptime![count_include_pad] {
+        let kernel_volume = comptime![kernel_size.0 * kernel_size.1 * kernel_size.2];
+        output[ABSOLUTE_POS] = sum / E::cast_from(kernel_volume);
+    } else {
+        let count = (id_end - id_start) * (ih_end - ih_start) * (iw_end - iw_start);
+        output[ABSOLUTE_POS] = sum / E::cast_from(count);
+    }
+}
+
+#[cube(launch_unchecked)]
+fn avg_pool3d_backward_kernel<E: Numeric>(
+    grad: &Tensor<E>,
+    output: &mut Tensor<E>,
+    args: &Pool3dArgs,
+    #[comptime] kernel_size: (u32, u32, u32),
+    #[comptime] count_include_pad: bool,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let b = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let c = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let id = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let ih = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+    let iw = ABSOLUTE_POS / output.stride(4) % output.shape(4);
+
+    let (od_start, od_end) = output_range(
+        id,
+        grad.shape(2),
+        args.stride_0,
+        1u32,
+        args.padding_0,
+        kernel_size.0,
+    );
+    let (oh_start, oh_end) = output_range(
+        ih,
+        grad.shape(3),
+        args.stride_1,
+        1u32,
+        args.padding_1,
+        kernel_size.1,
+    );
+    let (ow_start, ow_end) = output_range(
+        iw,
+        grad.shape(4),
+        args.stride_2,
+        1u32,
+        args.padding_2,
+        kernel_size.2,
+    );
+
+    let index_base = b * grad.stride(0) + c * grad.stride(1);
+    let mut grad_acc = E::from_int(0);
+
+    // Without dilation, every window in the ranges contains the current input position.
+    for od in od_start..od_end {
+        let (id_start, id_end) = input_range(
+            od,
+            output.shape(2),
+            args.stride_0,
+            args.padding_0,
+            kernel_size.0,
+        );
+
+        for oh in oh_start..oh_end {
+            let (ih_start, ih_end) = input_range(
+                oh,
+                output.shape(3),
+                args.stride_1,
+                args.padding_1,
+                kernel_size.1,
+            );
+
+            for ow in ow_start..ow_end {
+                let (iw_start, iw_end) = input_range(
+                    ow,
+                    output.shape(4),
+                    args.stride_2,
+                    args.padding_2,
+                    kernel_size.2,
+                );
+
+                let index =
+                    index_base + od * grad.stride(2) + oh * grad.stride(3) + ow * grad.stride(4);
+
+                if comptime![count_include_pad] {
+                    let kernel_volume = comptime![kernel_size.0 * kernel_size.1 * kernel_size.2];
+                    grad_acc += grad[index] / E::cast_from(kernel_volume);
+                } else {
+                    let count = (id_end - id_start) * (ih_end - ih_start) * (iw_end - iw_start);
+                    grad_acc += grad[index] / E::cast_from(count);
+                }
+            }
+        }
+    }
+
+    output[ABSOLUTE_POS] = grad_acc;
+}
+
+pub(crate) fn avg_pool3d<R: CubeRuntime, E: CubeElement>(
+    x: CubeTensor<R>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> CubeTensor<R> {
+    let dilation = [1; 3];
+    let shape_out = pool3d_output_shape(&x, kernel_size, stride, padding, dilation);
+    let output = empty_device::<R, E>(x.client.clone(), x.device.clone(), shape_out);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        avg_pool3d_kernel::launch_unchecked::<E, R>(
+            &x.client,
+            cube_count,
+            cube_dim,
+            x.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            pool3d_args(stride, padding, dilation),
+            kernel_size_comptime(kernel_size),
+            count_include_pad,
+        )
+    };
+
+    output
+}
+
+pub(crate) fn avg_pool3d_backward<R: CubeRuntime, E: CubeElement>(
+    x: CubeTensor<R>,
+    grad: CubeTensor<R>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> CubeTensor<R> {
+    let grad = into_contiguous(grad);
+
+    let output = empty_device::<R, E>(x.client.clone(), x.device.clone(), x.shape.clone());
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        avg_pool3d_backward_kernel::launch_unchecked::<E, R>(
+            &grad.client,
+            cube_count,
+            cube_dim,
+            grad.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            pool3d_args(stride, padding, [1; 3]),
+            kernel_size_comptime(kernel_size),
+            count_include_pad,
+        )
+    };
+
+    output
+}
@@ -0,0 +1,251 @@
+use super::pool3d::{
+    kernel_size_comptime, output_range, pool3d_args, pool3d_output_shape, Pool3dArgs,
+};
+use crate::{
+    element::CubeElement, kernel::into_contiguous, ops::numeric::empty_device, tensor::CubeTensor,
+    CubeRuntime, IntElement,
+};
+use cubecl::{calculate_cube_count_elemwise, prelude::*, CubeDim};
+
+#[cube(launch_unchecked)]
+fn max_pool3d_kernel<E: Numeric>(
+    input: &Tensor<E>,
+    output: &mut Tensor<E>,
+    args: &Pool3dArgs,
+    #[comptime] kernel_size: (u32, u32, u32),
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let (max, _index) = max_window(input, output, args, kernel_size);
+
+    output[ABSOLUTE_POS] = max;
+}
+
+#[cube(launch_unchecked)]
+fn max_pool3d_with_indices_kernel<E: Numeric, I: Int>(
+    input: &Tensor<E>,
+    output: &mut Tensor<E>,
+    indices: &mut Tensor<I>,
+    args: &Pool3dArgs,
+    #[comptime] kernel_size: (u32, u32, u32),
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let (max, index) = max_window(input, output, args, kernel_size);
+
+    output[ABSOLUTE_POS] = max;
+    indices[ABSOLUTE_POS] = I::cast_from(index);
+}
+
+/// Finds the maximum of the window of the current output position, with its index in the
+/// flattened spatial dimensions of the input.
+#[cube]
+fn max_window<E: Numeric>(
+    input: &Tensor<E>,
+    output: &Tensor<E>,
+    args: &Pool3dArgs,
+    #[comptime] kernel_size: (u32, u32, u32),
+) -> (E, u32) {
+    let b = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let c = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let od = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let oh = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+    let ow = ABSOLUTE_POS / output.stride(4) % output.shape(4);
+
+    let (depth, height, width) = (input.shape(2), input.shape(3), input.shape(4));
+    let index_base = b * input.stride(0) + c * input.stride(1);
+
+    let mut max = E::min_value();
+    let mut max_index = 0u32;
+
+    for kd in 0..kernel_size.0 {
+        let id = od * args.stride_0 + kd * args.dilation_0;
+        let within_padding_d = id >= args.padding_0 && id < depth + args.padding_0;
+
+        for kh in 0..kernel_size.1 {
+            let ih = oh * args.stride_1 + kh * args.dilation_1;
+            let within_padding_h = ih >= args.padding_1 && ih < height + args.padding_1;
+
+            for kw in 0..kernel_size.2 {
+                let iw = ow * args.stride_2 + kw * args.dilation_2;
+                let within_padding_w = iw >= args.padding_2 && iw < width + args.padding_2;
+
+                if within_padding_d && within_padding_h && within_padding_w {
+                    let id_pad = id - args.padding_0;
+                    let ih_pad = ih - args.padding_1;
+                    let iw_pad = iw - args.padding_2;
+
+                    let index_input = index_base
+                        + id_pad * input.stride(2)
+                        + ih_pad * input.stride(3)
+                        + iw_pad * input.stride(4);
+                    let value = input[index_input];
+
+                    if value > max {
+                        max = value;
+                        max_index = (id_pad * height + ih_pad) * width + iw_pad;
+                    }
+                }
+            }
+        }
+    }
+
+    (max, max_index)
+}
+
+#[cube(launch_unchecked)]
+fn max_pool3d_with_indices_backward_kernel<E: Numeric, I: Int>(
+    grad: &Tensor<E>,
+    indices: &Tensor<I>,
+    output: &mut Tensor<E>,
+    args: &Pool3dArgs,
+    #[comptime] kernel_size: (u32, u32, u32),
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let b = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let c = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let id = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let ih = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+    let iw = ABSOLUTE_POS / output.stride(4) % output.shape(4);
+
+    let index_current = (id * output.shape(3) + ih) * output.shape(4) + iw;
+
+    let (od_start, od_end) = output_range(
+        id,
+        grad.shape(2),
+        args.stride_0,
+        args.dilation_0,
+        args.padding_0,
+        kernel_size.0,
+    );
+    let (oh_start, oh_end) = output_range(
+        ih,
+        grad.shape(3),
+        args.stride_1,
+        args.dilation_1,
+        args.padding_1,
+        kernel_size.1,
+    );
+    let (ow_start, ow_end) = output_range(
+        iw,
+        grad.shape(4),
+        args.stride_2,
+        args.dilation_2,
+        args.padding_2,
+        kernel_size.2,
+    );
+
+    let mut grad_acc = E::from_int(0);
+    let index_base = b * grad.stride(0) + c * grad.stride(1);
+
+    for od in od_start..od_end {
+        for oh in oh_start..oh_end {
+            for ow in ow_start..ow_end {
+                let index =
+                    index_base + od * grad.stride(2) + oh * grad.stride(3) + ow * grad.stride(4);
+                let index_max = u32::cast_from(indices[index]);
+
+                grad_acc += select(index_max == index_current, grad[index], E::from_int(0));
+            }
+        }
+    }
+
+    output[ABSOLUTE_POS] = grad_acc;
+}
+
+pub(crate) fn max_pool3d<R: CubeRuntime, E: CubeElement>(
+    x: CubeTensor<R>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> CubeTensor<R> {
+    let shape_out = pool3d_output_shape(&x, kernel_size, stride, padding, dilation);
+    let output = empty_device::<R, E>(x.client.clone(), x.device.clone(), shape_out);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        max_pool3d_kernel::launch_unchecked::<E, R>(
+            &x.client,
+            cube_count,
+            cube_dim,
+            x.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            pool3d_args(stride, padding, dilation),
+            kernel_size_comptime(kernel_size),
+        )
+    };
+
+    output
+}
+
+pub(crate) fn max_pool3d_with_indices<R: CubeRuntime, E: CubeElement, I: CubeElement>(
+    x: CubeTensor<R>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> (CubeTensor<R>, CubeTensor<R>) {
+    let shape_out = pool3d_output_shape(&x, kernel_size, stride, padding, dilation);
+    let output = empty_device::<R, E>(x.client.clone(), x.device.clone(), shape_out.clone());
+    let indices = empty_device::<R, I>(x.client.clone(), x.device.clone(), shape_out);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        max_pool3d_with_indices_kernel::launch_unchecked::<E, I, R>(
+            &x.client,
+            cube_count,
+            cube_dim,
+            x.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            indices.as_tensor_arg::<I>(1),
+            pool3d_args(stride, padding, dilation),
+            kernel_size_comptime(kernel_size),
+        )
+    };
+
+    (output, indices)
+}
+
+pub(crate) fn max_pool3d_with_indices_backward<R: CubeRuntime, E: CubeElement, I: IntElement>(
+    x: CubeTensor<R>,
+    grad: CubeTensor<R>,
+    indices: CubeTensor<R>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> CubeTensor<R> {
+    let grad = into_contiguous(grad);
+    let indices = into_contiguous(indices);
+
+    let output = empty_device::<R, E>(x.client.clone(), x.device.clone(), x.shape.clone());
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        max_pool3d_with_indices_backward_kernel::launch_unchecked::<E, I, R>(
+            &x.client,
+            cube_count,
+            cube_dim,
+            grad.as_tensor_arg::<E>(1),
+            indices.as_tensor_arg::<I>(1),
+            output.as_tensor_arg::<E>(1),
+            pool3d_args(stride, padding, dilation),
+            kernel_size_comptime(kernel_size),
+        )
+    };
+
+    output
+}
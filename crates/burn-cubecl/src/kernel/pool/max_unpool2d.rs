@@ -0,0 +1,146 @@
+use crate::{
+    element::CubeElement,
+    kernel::into_contiguous,
+    ops::numeric::{empty_device, zeros_device},
+    tensor::CubeTensor,
+    CubeRuntime, IntElement,
+};
+use burn_tensor::Shape;
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+/// Writes each value at its index in the flattened spatial dimensions of the output.
+///
+/// The values written at the same position come from overlapping pooling windows selecting the
+/// same maximum, so they are equal and the order of the writes doesn't matter.
+#[cube(launch_unchecked)]
+fn max_unpool2d_kernel<E: Numeric, I: Int>(
+    input: &Tensor<E>,
+    indices: &Tensor<I>,
+    output: &mut Tensor<E>,
+) {
+    if ABSOLUTE_POS >= input.len() {
+        terminate!();
+    }
+
+    let index_output = output_index(indices, output, ABSOLUTE_POS);
+
+    output[index_output] = input[ABSOLUTE_POS];
+}
+
+/// Counts the values written at each position of the output.
+#[cube(launch_unchecked)]
+fn max_unpool2d_counts_kernel<I: Int>(indices: &Tensor<I>, counts: &mut Tensor<Atomic<u32>>) {
+    if ABSOLUTE_POS >= indices.len() {
+        terminate!();
+    }
+
+    let index_output = output_index(indices, counts, ABSOLUTE_POS);
+
+    Atomic::add(&counts[index_output], 1u32);
+}
+
+/// Gathers the gradient of each value, shared between the values written at the same position.
+#[cube(launch_unchecked)]
+fn max_unpool2d_backward_kernel<E: Numeric, I: Int>(
+    grad: &Tensor<E>,
+    indices: &Tensor<I>,
+    counts: &Tensor<u32>,
+    output: &mut Tensor<E>,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let index_grad = output_index(indices, grad, ABSOLUTE_POS);
+    let count = Max::max(counts[index_grad], 1u32);
+
+    output[ABSOLUTE_POS] = grad[index_grad] / E::cast_from(count);
+}
+
+/// Returns the position in the unpooled tensor of the value at the given position of the
+/// contiguous indices.
+#[cube]
+fn output_index<I: Int, T: CubeType>(
+    indices: &Tensor<I>,
+    output: &Tensor<T>,
+    position: u32,
+) -> u32 {
+    let b = position / indices.stride(0) % indices.shape(0);
+    let c = position / indices.stride(1) % indices.shape(1);
+    let index = u32::cast_from(indices[position]);
+
+    let oh = index / output.shape(3);
+    let ow = index % output.shape(3);
+
+    b * output.stride(0) + c * output.stride(1) + oh * output.stride(2) + ow * output.stride(3)
+}
+
+pub(crate) fn max_unpool2d<R: CubeRuntime, E: CubeElement, I: IntElement>(
+    x: CubeTensor<R>,
+    indices: CubeTensor<R>,
+    output_size: [usize; 2],
+) -> CubeTensor<R> {
+    let x = into_contiguous(x);
+    let indices = into_contiguous(indices);
+    let [batch_size, channels, _, _] = x.shape.dims();
+
+    let shape_out = Shape::new([batch_size, channels, output_size[0], output_size[1]]);
+    let output = zeros_device::<R, E>(x.client.clone(), x.device.clone(), shape_out);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(x.shape.num_elements(), cube_dim);
+
+    unsafe {
+        max_unpool2d_kernel::launch_unchecked::<E, I, R>(
+            &x.client,
+            cube_count,
+            cube_dim,
+            x.as_tensor_arg::<E>(1),
+            indices.as_tensor_arg::<I>(1),
+            output.as_tensor_arg::<E>(1),
+        )
+    };
+
+    output
+}
+
+pub(crate) fn max_unpool2d_backward<R: CubeRuntime, E: CubeElement, I: IntElement>(
+    indices: CubeTensor<R>,
+    grad: CubeTensor<R>,
+) -> CubeTensor<R> {
+    let indices = into_contiguous(indices);
+    // The counts share the strides of the gradient.
+    let grad = into_contiguous(grad);
+
+    let counts =
+        zeros_device::<R, u32>(grad.client.clone(), grad.device.clone(), grad.shape.clone());
+    let output = empty_device::<R, E>(
+        grad.client.clone(),
+        grad.device.clone(),
+        indices.shape.clone(),
+    );
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(indices.shape.num_elements(), cube_dim);
+
+    unsafe {
+        max_unpool2d_counts_kernel::launch_unchecked::<I, R>(
+            &indices.client,
+            cube_count.clone(),
+            cube_dim,
+            indices.as_tensor_arg::<I>(1),
+            counts.as_tensor_arg::<u32>(1),
+        );
+        max_unpool2d_backward_kernel::launch_unchecked::<E, I, R>(
+            &indices.client,
+            cube_count,
+            cube_dim,
+            grad.as_tensor_arg::<E>(1),
+            indices.as_tensor_arg::<I>(1),
+            counts.as_tensor_arg::<u32>(1),
+            output.as_tensor_arg::<E>(1),
+        );
+    }
+
+    output
+}
@@ -1,15 +1,24 @@
 mod adaptive_avg_pool2d;
 mod adaptive_avg_pool2d_backward;
+mod adaptive_max_pool2d;
 mod avg_pool2d;
 mod avg_pool2d_backward;
+mod avg_pool3d;
 mod max_pool2d;
 mod max_pool2d_backward;
+mod max_pool3d;
+mod max_unpool2d;
 
 pub(super) mod pool2d;
+pub(super) mod pool3d;
 
 pub(crate) use adaptive_avg_pool2d::*;
 pub(crate) use adaptive_avg_pool2d_backward::*;
+pub(crate) use adaptive_max_pool2d::*;
 pub(crate) use avg_pool2d::*;
 pub(crate) use avg_pool2d_backward::*;
+pub(crate) use avg_pool3d::*;
 pub(crate) use max_pool2d::*;
 pub(crate) use max_pool2d_backward::*;
+pub(crate) use max_pool3d::*;
+pub(crate) use max_unpool2d::*;
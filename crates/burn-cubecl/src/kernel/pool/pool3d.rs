@@ -0,0 +1,104 @@
+use crate::{tensor::CubeTensor, CubeRuntime};
+use burn_tensor::{ops::conv::calculate_pool_output_size, Shape};
+use cubecl::prelude::*;
+
+#[derive(CubeLaunch)]
+pub(crate) struct Pool3dArgs {
+    pub stride_0: u32,
+    pub stride_1: u32,
+    pub stride_2: u32,
+    pub dilation_0: u32,
+    pub dilation_1: u32,
+    pub dilation_2: u32,
+    pub padding_0: u32,
+    pub padding_1: u32,
+    pub padding_2: u32,
+}
+
+pub(crate) fn pool3d_args<'a, R: Runtime>(
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> Pool3dArgsLaunch<'a, R> {
+    Pool3dArgsLaunch::new(
+        ScalarArg::new(stride[0] as u32),
+        ScalarArg::new(stride[1] as u32),
+        ScalarArg::new(stride[2] as u32),
+        ScalarArg::new(dilation[0] as u32),
+        ScalarArg::new(dilation[1] as u32),
+        ScalarArg::new(dilation[2] as u32),
+        ScalarArg::new(padding[0] as u32),
+        ScalarArg::new(padding[1] as u32),
+        ScalarArg::new(padding[2] as u32),
+    )
+}
+
+pub(crate) fn pool3d_output_shape<R: CubeRuntime>(
+    x: &CubeTensor<R>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> Shape {
+    let [batch_size, channels, _, _, _] = x.shape.dims();
+    let size = |i: usize| {
+        calculate_pool_output_size(
+            kernel_size[i],
+            stride[i],
+            padding[i],
+            dilation[i],
+            x.shape.dims[i + 2],
+        )
+    };
+
+    Shape::new([batch_size, channels, size(0), size(1), size(2)])
+}
+
+pub(crate) fn kernel_size_comptime(kernel_size: [usize; 3]) -> (u32, u32, u32) {
+    (
+        kernel_size[0] as u32,
+        kernel_size[1] as u32,
+        kernel_size[2] as u32,
+    )
+}
+
+/// Returns the range of the output positions whose windows can contain the input position,
+/// along a single dimension.
+#[cube]
+pub(crate) fn output_range(
+    position: u32,
+    output_size: u32,
+    stride: u32,
+    dilation: u32,
+    padding: u32,
+    #[comptime] kernel_size: u32,
+) -> (u32, u32) {
+    let position = position + padding;
+    let extent = dilation * comptime![kernel_size - 1];
+
+    let start = if position >= extent {
+        (position - extent + stride - 1) / stride
+    } else {
+        0u32
+    };
+    let end = Min::min(position / stride + 1, output_size);
+
+    (start, end)
+}
+
+/// Returns the range of the input covered by a window along a single dimension, excluding the
+/// padding.
+#[cube]
+pub(crate) fn input_range(
+    position: u32,
+    input_size: u32,
+    stride: u32,
+    padding: u32,
+    #[comptime] kernel_size: u32,
+) -> (u32, u32) {
+    let start = position * stride;
+    let end = Min::min(start + kernel_size, input_size + padding);
+    let start = Max::max(start, padding);
+
+    (start - padding, Max::max(end, start) - padding)
+}
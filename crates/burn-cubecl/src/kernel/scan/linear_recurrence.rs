@@ -0,0 +1,65 @@
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{
+    kernel::into_contiguous, ops::numeric::empty_device, tensor::CubeTensor, CubeRuntime,
+    FloatElement,
+};
+
+#[cube(launch_unchecked)]
+fn linear_recurrence_kernel<F: Float>(
+    a: &Tensor<F>,
+    b: &Tensor<F>,
+    output: &mut Tensor<F>,
+    dim: u32,
+) {
+    let length = output.shape(dim);
+    let stride = output.stride(dim);
+    let num_sequences = output.len() / length;
+
+    if ABSOLUTE_POS >= num_sequences {
+        terminate!();
+    }
+
+    // Each unit computes the recurrence sequentially over one sequence.
+    let outer = ABSOLUTE_POS / stride;
+    let inner = ABSOLUTE_POS % stride;
+    let offset = outer * stride * length + inner;
+
+    let mut state = F::new(0.0);
+
+    for t in 0..length {
+        let index = offset + t * stride;
+        state = a[index] * state + b[index];
+        output[index] = state;
+    }
+}
+
+/// Computes the linear recurrence `h[t] = a[t] * h[t - 1] + b[t]` along the given dimension,
+/// with `h[-1] = 0`.
+pub fn linear_recurrence<R: CubeRuntime, E: FloatElement>(
+    a: CubeTensor<R>,
+    b: CubeTensor<R>,
+    dim: usize,
+) -> CubeTensor<R> {
+    let a = into_contiguous(a);
+    let b = into_contiguous(b);
+    let output = empty_device::<R, E>(b.client.clone(), b.device.clone(), b.shape.clone());
+
+    let num_sequences = output.shape.num_elements() / output.shape.dims[dim];
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_sequences, cube_dim);
+
+    unsafe {
+        linear_recurrence_kernel::launch_unchecked::<E, R>(
+            &output.client,
+            cube_count,
+            cube_dim,
+            a.as_tensor_arg::<E>(1),
+            b.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(dim as u32),
+        )
+    };
+
+    output
+}
@@ -0,0 +1,3 @@
+mod linear_recurrence;
+
+pub use linear_recurrence::*;
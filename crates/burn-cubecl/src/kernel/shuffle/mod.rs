@@ -0,0 +1,3 @@
+mod pixel_shuffle;
+
+pub use pixel_shuffle::*;
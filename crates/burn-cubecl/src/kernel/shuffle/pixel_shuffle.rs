@@ -0,0 +1,113 @@
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{ops::numeric::empty_device, tensor::CubeTensor, CubeRuntime, FloatElement};
+use burn_tensor::Shape;
+
+#[cube(launch_unchecked)]
+fn pixel_shuffle_kernel<F: Float>(input: &Tensor<F>, output: &mut Tensor<F>, factor: u32) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let batch = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let channel = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let y = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let x = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+
+    let channel_in = (channel * factor + y % factor) * factor + x % factor;
+
+    let index = batch * input.stride(0)
+        + channel_in * input.stride(1)
+        + (y / factor) * input.stride(2)
+        + (x / factor) * input.stride(3);
+
+    output[ABSOLUTE_POS] = input[index];
+}
+
+#[cube(launch_unchecked)]
+fn pixel_unshuffle_kernel<F: Float>(input: &Tensor<F>, output: &mut Tensor<F>, factor: u32) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let batch = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let channel = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let y = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let x = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+
+    let block = channel % (factor * factor);
+    let channel_in = channel / (factor * factor);
+    let y_in = y * factor + block / factor;
+    let x_in = x * factor + block % factor;
+
+    let index = batch * input.stride(0)
+        + channel_in * input.stride(1)
+        + y_in * input.stride(2)
+        + x_in * input.stride(3);
+
+    output[ABSOLUTE_POS] = input[index];
+}
+
+/// Rearranges the channels into blocks of `upscale_factor * upscale_factor` pixels.
+pub fn pixel_shuffle<R: CubeRuntime, E: FloatElement>(
+    input: CubeTensor<R>,
+    upscale_factor: usize,
+) -> CubeTensor<R> {
+    let [batch_size, channels, height, width] = input.shape.dims();
+    let factor = upscale_factor;
+    let shape_out = Shape::new([
+        batch_size,
+        channels / (factor * factor),
+        height * factor,
+        width * factor,
+    ]);
+    let output = empty_device::<R, E>(input.client.clone(), input.device.clone(), shape_out);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        pixel_shuffle_kernel::launch_unchecked::<E, R>(
+            &input.client,
+            cube_count,
+            cube_dim,
+            input.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(factor as u32),
+        )
+    };
+
+    output
+}
+
+/// Rearranges the blocks of `downscale_factor * downscale_factor` pixels into the channels.
+pub fn pixel_unshuffle<R: CubeRuntime, E: FloatElement>(
+    input: CubeTensor<R>,
+    downscale_factor: usize,
+) -> CubeTensor<R> {
+    let [batch_size, channels, height, width] = input.shape.dims();
+    let factor = downscale_factor;
+    let shape_out = Shape::new([
+        batch_size,
+        channels * factor * factor,
+        height / factor,
+        width / factor,
+    ]);
+    let output = empty_device::<R, E>(input.client.clone(), input.device.clone(), shape_out);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        pixel_unshuffle_kernel::launch_unchecked::<E, R>(
+            &input.client,
+            cube_count,
+            cube_dim,
+            input.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(factor as u32),
+        )
+    };
+
+    output
+}
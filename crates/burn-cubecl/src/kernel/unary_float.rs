@@ -112,6 +112,7 @@ pub(crate) mod unary_basic {
     use crate::execute_with_dtype;
 
     use super::*;
+    use crate::kernel::special::*;
 
     pub(crate) fn launch<R, Args>(tensor: CubeTensor<R>, args: Args) -> CubeTensor<R>
     where
@@ -142,6 +143,19 @@ pub(crate) mod unary_basic {
         Ceil,
         Erf,
         Recip,
+        Trunc,
+        Asin,
+        Acos,
+        Atan,
+        Sinh,
+        Cosh,
+        Asinh,
+        Acosh,
+        Atanh,
+        Expm1,
+        Erfinv,
+        Lgamma,
+        Digamma,
     }
 
     #[derive(CubeLaunch)]
@@ -170,6 +184,19 @@ pub(crate) mod unary_basic {
                 BasicFloatUnaryKind::Ceil => Line::ceil(input),
                 BasicFloatUnaryKind::Erf => Line::erf(input),
                 BasicFloatUnaryKind::Recip => Line::recip(input),
+                BasicFloatUnaryKind::Trunc => trunc_line::<F>(input),
+                BasicFloatUnaryKind::Asin => asin_line::<F>(input),
+                BasicFloatUnaryKind::Acos => acos_line::<F>(input),
+                BasicFloatUnaryKind::Atan => atan_line::<F>(input),
+                BasicFloatUnaryKind::Sinh => sinh_line::<F>(input),
+                BasicFloatUnaryKind::Cosh => cosh_line::<F>(input),
+                BasicFloatUnaryKind::Asinh => asinh_line::<F>(input),
+                BasicFloatUnaryKind::Acosh => acosh_line::<F>(input),
+                BasicFloatUnaryKind::Atanh => atanh_line::<F>(input),
+                BasicFloatUnaryKind::Expm1 => expm1_line::<F>(input),
+                BasicFloatUnaryKind::Erfinv => erfinv_line::<F>(input),
+                BasicFloatUnaryKind::Lgamma => lgamma_line::<F>(input),
+                BasicFloatUnaryKind::Digamma => digamma_line::<F>(input),
             }
         }
     }
@@ -6,12 +6,12 @@ use crate::kernel::{
 };
 use crate::{
     element::BoolElement,
-    kernel::matmul::{matmul, MatmulStrategy},
+    kernel::matmul::{complex_matmul, matmul, MatmulStrategy},
 };
 use crate::{execute_with_dtype, CubeBackend};
 use crate::{CubeRuntime, FloatElement, IntElement};
 use burn_tensor::ops::{BoolTensor, Device, FloatElem, FloatTensor, IntTensor};
-use burn_tensor::{ops::FloatTensorOps, Distribution, Shape, TensorData};
+use burn_tensor::{ops::FloatTensorOps, Distribution, Reduce, Shape, TensorData};
 use burn_tensor::{DType, ElementConversion, FloatDType};
 use cubecl::prelude::*;
 use half::{bf16, f16};
@@ -174,6 +174,25 @@ where
         )
     }
 
+    fn float_complex_matmul(
+        lhs_real: FloatTensor<Self>,
+        lhs_imag: FloatTensor<Self>,
+        rhs_real: FloatTensor<Self>,
+        rhs_imag: FloatTensor<Self>,
+    ) -> (FloatTensor<Self>, FloatTensor<Self>) {
+        execute_with_dtype!(
+            float(lhs_real.dtype, rhs_real.dtype),
+            E,
+            complex_matmul::<R, E>(
+                lhs_real,
+                lhs_imag,
+                rhs_real,
+                rhs_imag,
+                MatmulStrategy::default()
+            )
+        )
+    }
+
     fn float_swap_dims(tensor: FloatTensor<Self>, dim1: usize, dim2: usize) -> FloatTensor<Self> {
         super::swap_dims(tensor, dim1, dim2)
     }
@@ -207,6 +226,21 @@ where
         )
     }
 
+    fn float_scatter_reduce(
+        dim: usize,
+        tensor: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+        value: FloatTensor<Self>,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> FloatTensor<Self> {
+        execute_with_dtype!(
+            float(tensor.dtype, value.dtype),
+            E,
+            kernel::scatter_reduce::<R, E, I>(dim, tensor, indices, value, reduce, include_self)
+        )
+    }
+
     fn float_select(
         tensor: FloatTensor<Self>,
         dim: usize,
@@ -468,6 +502,90 @@ where
         unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Erf)
     }
 
+    fn float_asin(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Asin)
+    }
+
+    fn float_acos(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Acos)
+    }
+
+    fn float_atan(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Atan)
+    }
+
+    fn float_sinh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Sinh)
+    }
+
+    fn float_cosh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Cosh)
+    }
+
+    fn float_asinh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Asinh)
+    }
+
+    fn float_acosh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Acosh)
+    }
+
+    fn float_atanh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Atanh)
+    }
+
+    fn float_expm1(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Expm1)
+    }
+
+    fn float_erfinv(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Erfinv)
+    }
+
+    fn float_lgamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Lgamma)
+    }
+
+    fn float_digamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Digamma)
+    }
+
+    fn float_trunc(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Trunc)
+    }
+
+    fn float_atan2(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_dtype!(
+            float(lhs.dtype, rhs.dtype),
+            E,
+            numeric::atan2::<R, E>(lhs, rhs)
+        )
+    }
+
+    fn float_hypot(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_dtype!(
+            float(lhs.dtype, rhs.dtype),
+            E,
+            numeric::hypot::<R, E>(lhs, rhs)
+        )
+    }
+
+    fn float_fmod(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_dtype!(
+            float(lhs.dtype, rhs.dtype),
+            E,
+            numeric::fmod::<R, E>(lhs, rhs)
+        )
+    }
+
+    fn float_fmod_scalar(lhs: FloatTensor<Self>, rhs: FloatElem<Self>) -> FloatTensor<Self> {
+        execute_with_dtype!(
+            float(lhs.dtype),
+            E,
+            numeric::fmod_scalar::<R, E>(lhs, rhs.elem())
+        )
+    }
+
     fn float_argmax(tensor: FloatTensor<Self>, dim: usize) -> IntTensor<Self> {
         execute_with_dtype!(
             float(tensor.dtype),
@@ -532,6 +650,31 @@ where
         )
     }
 
+    fn float_searchsorted(
+        sorted: FloatTensor<Self>,
+        values: FloatTensor<Self>,
+        right: bool,
+    ) -> IntTensor<Self> {
+        execute_with_dtype!(
+            float(sorted.dtype, values.dtype),
+            E,
+            kernel::counting::searchsorted::<R, E, I>(sorted, values, right)
+        )
+    }
+
+    fn float_histc(
+        tensor: FloatTensor<Self>,
+        bins: usize,
+        min: f64,
+        max: f64,
+    ) -> FloatTensor<Self> {
+        execute_with_dtype!(
+            float(tensor.dtype),
+            E,
+            kernel::counting::histc::<R, E>(tensor, bins, min, max)
+        )
+    }
+
     fn float_cast(tensor: FloatTensor<Self>, dtype: FloatDType) -> FloatTensor<Self> {
         match (tensor.dtype, dtype) {
             (DType::F64, FloatDType::F64)
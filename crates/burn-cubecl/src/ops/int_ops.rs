@@ -9,10 +9,10 @@ use crate::{
     element::BoolElement,
     kernel::prng::{random_bernoulli, random_normal, random_uniform},
 };
-use crate::{kernel, CubeBackend, CubeRuntime, FloatElement, IntElement};
+use crate::{execute_with_dtype, kernel, CubeBackend, CubeRuntime, FloatElement, IntElement};
 use burn_tensor::ops::{BoolTensor, Device, FloatTensor, IntElem, IntTensor};
 use burn_tensor::DType;
-use burn_tensor::{ops::IntTensorOps, Distribution, ElementConversion, Shape, TensorData};
+use burn_tensor::{ops::IntTensorOps, Distribution, ElementConversion, Reduce, Shape, TensorData};
 use cubecl::frontend::Numeric;
 use cubecl::prelude::*;
 use std::ops::Range;
@@ -98,6 +98,17 @@ where
         kernel::scatter::<R, I, I>(dim, tensor, indices, value)
     }
 
+    fn int_scatter_reduce(
+        dim: usize,
+        tensor: IntTensor<Self>,
+        indices: IntTensor<Self>,
+        value: IntTensor<Self>,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> IntTensor<Self> {
+        kernel::scatter_reduce::<R, I, I>(dim, tensor, indices, value, reduce, include_self)
+    }
+
     fn int_select(
         tensor: IntTensor<Self>,
         dim: usize,
@@ -305,6 +316,34 @@ where
         kernel::flip::<R, I, BT>(tensor, axes)
     }
 
+    fn int_searchsorted(
+        sorted: IntTensor<Self>,
+        values: IntTensor<Self>,
+        right: bool,
+    ) -> IntTensor<Self> {
+        kernel::counting::searchsorted::<R, I, I>(sorted, values, right)
+    }
+
+    fn int_bincount(tensor: IntTensor<Self>, min_length: usize) -> IntTensor<Self> {
+        let length = burn_tensor::bincount_length::<Self>(tensor.clone(), min_length);
+
+        kernel::counting::bincount::<R, I>(tensor, length)
+    }
+
+    fn int_bincount_weighted(
+        tensor: IntTensor<Self>,
+        weights: FloatTensor<Self>,
+        min_length: usize,
+    ) -> FloatTensor<Self> {
+        let length = burn_tensor::bincount_length::<Self>(tensor.clone(), min_length);
+
+        execute_with_dtype!(
+            float(weights.dtype),
+            E,
+            kernel::counting::bincount_weighted::<R, E, I>(tensor, weights, length)
+        )
+    }
+
     fn bitwise_and(lhs: IntTensor<Self>, rhs: IntTensor<Self>) -> IntTensor<Self> {
         numeric::bitwise_and::<R, I>(lhs, rhs)
     }
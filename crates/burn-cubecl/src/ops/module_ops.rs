@@ -7,8 +7,9 @@ use crate::{
     CubeBackend, CubeRuntime, FloatElement, IntElement,
 };
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, DeformConv2dBackward, DeformConvOptions, InterpolateOptions,
-    MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
+    AdaptiveMaxPool2dBackward, AdaptiveMaxPool2dWithIndices, ConvOptions, ConvTransposeOptions,
+    DeformConv2dBackward, DeformConvOptions, InterpolateOptions, MaxPool2dBackward,
+    MaxPool2dWithIndices, MaxPool3dBackward, MaxPool3dWithIndices, ModuleOps,
 };
 use burn_tensor::ops::{FloatTensor, IntTensor};
 
@@ -181,6 +182,119 @@ where
         kernel::pool::adaptive_avg_pool2d_backward::<R, F>(x, grad)
     }
 
+    fn max_pool3d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> FloatTensor<Self> {
+        kernel::pool::max_pool3d::<R, F>(x, kernel_size, stride, padding, dilation)
+    }
+
+    fn max_pool3d_with_indices(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<Self> {
+        let (output, indices) = kernel::pool::max_pool3d_with_indices::<R, F, I>(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+        );
+
+        MaxPool3dWithIndices::new(output, indices)
+    }
+
+    fn max_pool3d_with_indices_backward(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        output_grad: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+    ) -> MaxPool3dBackward<Self> {
+        MaxPool3dBackward::new(kernel::pool::max_pool3d_with_indices_backward::<R, F, I>(
+            x,
+            output_grad,
+            indices,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+        ))
+    }
+
+    fn avg_pool3d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self> {
+        kernel::pool::avg_pool3d::<R, F>(x, kernel_size, stride, padding, count_include_pad)
+    }
+
+    fn avg_pool3d_backward(
+        x: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self> {
+        kernel::pool::avg_pool3d_backward::<R, F>(
+            x,
+            grad,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+        )
+    }
+
+    fn adaptive_max_pool2d_with_indices(
+        x: FloatTensor<Self>,
+        output_size: [usize; 2],
+    ) -> AdaptiveMaxPool2dWithIndices<Self> {
+        let (output, indices) =
+            kernel::pool::adaptive_max_pool2d_with_indices::<R, F, I>(x, output_size);
+
+        AdaptiveMaxPool2dWithIndices::new(output, indices)
+    }
+
+    fn adaptive_max_pool2d_with_indices_backward(
+        x: FloatTensor<Self>,
+        output_grad: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+    ) -> AdaptiveMaxPool2dBackward<Self> {
+        AdaptiveMaxPool2dBackward::new(kernel::pool::adaptive_max_pool2d_with_indices_backward::<
+            R,
+            F,
+            I,
+        >(x, output_grad, indices))
+    }
+
+    fn max_unpool2d(
+        x: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+        output_size: [usize; 2],
+    ) -> FloatTensor<Self> {
+        kernel::pool::max_unpool2d::<R, F, I>(x, indices, output_size)
+    }
+
+    fn max_unpool2d_backward(
+        indices: IntTensor<Self>,
+        grad: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        kernel::pool::max_unpool2d_backward::<R, F, I>(indices, grad)
+    }
+
     fn interpolate(
         x: FloatTensor<Self>,
         output_size: [usize; 2],
@@ -197,4 +311,65 @@ where
     ) -> FloatTensor<Self> {
         kernel::interpolate::interpolate_backward::<R, F>(x, grad, output_size, options)
     }
+    fn linear_recurrence(
+        a: FloatTensor<Self>,
+        b: FloatTensor<Self>,
+        dim: usize,
+    ) -> FloatTensor<Self> {
+        kernel::scan::linear_recurrence::<R, F>(a, b, dim)
+    }
+
+    fn ctc_loss(
+        log_probs: FloatTensor<Self>,
+        targets: IntTensor<Self>,
+        input_lengths: IntTensor<Self>,
+        target_lengths: IntTensor<Self>,
+        blank: usize,
+    ) -> FloatTensor<Self> {
+        kernel::ctc::ctc_loss::<R, F, I>(log_probs, targets, input_lengths, target_lengths, blank)
+    }
+
+    fn ctc_loss_backward(
+        log_probs: FloatTensor<Self>,
+        targets: IntTensor<Self>,
+        input_lengths: IntTensor<Self>,
+        target_lengths: IntTensor<Self>,
+        loss: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+        blank: usize,
+    ) -> FloatTensor<Self> {
+        kernel::ctc::ctc_loss_backward::<R, F, I>(
+            log_probs,
+            targets,
+            input_lengths,
+            target_lengths,
+            loss,
+            grad,
+            blank,
+        )
+    }
+
+    fn grouped_matmul(
+        lhs: FloatTensor<Self>,
+        rhs: FloatTensor<Self>,
+        offsets: IntTensor<Self>,
+    ) -> FloatTensor<Self> {
+        kernel::matmul::grouped_matmul::<R, F, I>(lhs, rhs, offsets)
+    }
+
+    fn grouped_matmul_rhs_backward(
+        lhs: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+        offsets: IntTensor<Self>,
+    ) -> FloatTensor<Self> {
+        kernel::matmul::grouped_matmul_rhs_backward::<R, F, I>(lhs, grad, offsets)
+    }
+
+    fn pixel_shuffle(x: FloatTensor<Self>, upscale_factor: usize) -> FloatTensor<Self> {
+        kernel::shuffle::pixel_shuffle::<R, F>(x, upscale_factor)
+    }
+
+    fn pixel_unshuffle(x: FloatTensor<Self>, downscale_factor: usize) -> FloatTensor<Self> {
+        kernel::shuffle::pixel_unshuffle::<R, F>(x, downscale_factor)
+    }
 }
@@ -1,6 +1,7 @@
 use crate::kernel::{
-    launch_binop, launch_binop_int, launch_scalar_binop, launch_scalar_binop_int, AddOp,
-    BitwiseAndOp, BitwiseOrOp, BitwiseXorOp, DivOp, MulOp, PowOp, RemainderOp, SubOp,
+    launch_binop, launch_binop_int, launch_scalar_binop, launch_scalar_binop_int, AddOp, Atan2Op,
+    BitwiseAndOp, BitwiseOrOp, BitwiseXorOp, DivOp, FmodOp, HypotOp, MulOp, PowOp, RemainderOp,
+    SubOp,
 };
 use crate::{element::CubeElement, tensor::CubeTensor};
 use crate::{CubeRuntime, FloatElement, IntElement};
@@ -177,6 +178,35 @@ pub fn pow<R: CubeRuntime, E: FloatElement>(
     launch_binop::<R, E, PowOp<E>>(lhs, rhs)
 }
 
+/// Calculate the four-quadrant arctangent of two tensors
+pub fn atan2<R: CubeRuntime, E: FloatElement>(
+    lhs: CubeTensor<R>,
+    rhs: CubeTensor<R>,
+) -> CubeTensor<R> {
+    launch_binop::<R, E, Atan2Op<E>>(lhs, rhs)
+}
+
+/// Calculate the hypotenuse of two tensors
+pub fn hypot<R: CubeRuntime, E: FloatElement>(
+    lhs: CubeTensor<R>,
+    rhs: CubeTensor<R>,
+) -> CubeTensor<R> {
+    launch_binop::<R, E, HypotOp<E>>(lhs, rhs)
+}
+
+/// Calculate the remainder of two tensors, truncated towards zero
+pub fn fmod<R: CubeRuntime, E: FloatElement>(
+    lhs: CubeTensor<R>,
+    rhs: CubeTensor<R>,
+) -> CubeTensor<R> {
+    launch_binop::<R, E, FmodOp<E>>(lhs, rhs)
+}
+
+/// Calculate the remainder of a tensor with a scalar, truncated towards zero
+pub fn fmod_scalar<R: CubeRuntime, E: FloatElement>(lhs: CubeTensor<R>, rhs: E) -> CubeTensor<R> {
+    launch_scalar_binop::<R, E, FmodOp<E>>(lhs, rhs)
+}
+
 /// Bitwise and two tensors
 pub fn bitwise_and<R: CubeRuntime, E: IntElement>(
     lhs: CubeTensor<R>,
@@ -10,7 +10,7 @@ use crate::{
 use burn_ir::*;
 use burn_tensor::{
     ops::{binary_ops_shape, BoolTensor, FloatElem, FloatTensor, FloatTensorOps, IntTensor},
-    Device, Distribution, Element, ElementConversion, Shape, TensorData, TensorMetadata,
+    Device, Distribution, Element, ElementConversion, Reduce, Shape, TensorData, TensorMetadata,
 };
 use std::{marker::PhantomData, ops::Range};
 
@@ -535,6 +535,51 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         out
     }
 
+    fn float_fmod(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        binary_float_ops!(FmodOps, B::float_fmod);
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let dtype = lhs.dtype;
+        let out = lhs
+            .client
+            .tensor_uninitialized(binary_ops_shape(&lhs.shape, &rhs.shape), lhs.dtype);
+
+        let desc = BinaryOpIr {
+            lhs: lhs.into_ir(),
+            rhs: rhs.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationIr::Float(dtype, FloatOperationIr::Fmod(desc.clone())),
+            FmodOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_fmod_scalar(lhs: FloatTensor<Self>, rhs: FloatElem<Self>) -> FloatTensor<Self> {
+        scalar_float_ops!(FmodOps, B::float_fmod_scalar);
+
+        let stream = lhs.stream;
+        let dtype = lhs.dtype;
+        let out = lhs.client.tensor_uninitialized(lhs.shape.clone(), dtype);
+
+        let desc = ScalarOpIr {
+            lhs: lhs.into_ir(),
+            rhs: rhs.elem(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::Float(dtype, FloatOperationIr::FmodScalar(desc.clone())),
+            FmodOps::<B>::new(desc),
+        );
+
+        out
+    }
+
     fn float_matmul(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
         binary_float_ops!(MatmulOps, B::float_matmul);
 
@@ -725,6 +770,66 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         out
     }
 
+    fn float_scatter_reduce(
+        dim: usize,
+        tensor: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+        value: FloatTensor<Self>,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> FloatTensor<Self> {
+        #[derive(new)]
+        struct ScatterReduceOps<B: FusionBackend> {
+            desc: ScatterReduceOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for ScatterReduceOps<B> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let tensor = handles.get_float_tensor::<B>(&self.desc.tensor);
+                let indices = handles.get_int_tensor::<B>(&self.desc.indices);
+                let value = handles.get_float_tensor::<B>(&self.desc.value);
+
+                let output = B::float_scatter_reduce(
+                    self.desc.dim,
+                    tensor,
+                    indices,
+                    value,
+                    self.desc.reduce.into(),
+                    self.desc.include_self,
+                );
+
+                handles.register_float_tensor::<B>(&self.desc.out.id, output);
+            }
+        }
+
+        let stream_1 = tensor.stream;
+        let stream_2 = indices.stream;
+        let stream_3 = value.stream;
+        let dtype = tensor.dtype;
+        let shape: Vec<usize> = tensor.shape.clone();
+        let out = tensor.client.tensor_uninitialized(shape, dtype);
+
+        let desc = ScatterReduceOpIr {
+            tensor: tensor.into_ir(),
+            dim,
+            indices: indices.into_ir(),
+            value: value.into_ir(),
+            reduce: reduce.into(),
+            include_self,
+            out: out.to_ir_out(),
+        };
+        // Check that both float tensors have the same type
+        check_binary_op_types(&desc.tensor, &desc.value).unwrap();
+        out.client.register(
+            vec![stream_1, stream_2, stream_3],
+            OperationIr::NumericFloat(dtype, NumericOperationIr::ScatterReduce(desc.clone())),
+            ScatterReduceOps::<B>::new(desc),
+        );
+
+        out
+    }
+
     fn float_select(
         tensor: FloatTensor<Self>,
         dim: usize,
@@ -1602,6 +1707,388 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         out
     }
 
+    fn float_asin(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(AsinOps, B::float_asin);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::Float(dtype, FloatOperationIr::Asin(desc.clone())),
+            AsinOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_acos(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(AcosOps, B::float_acos);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::Float(dtype, FloatOperationIr::Acos(desc.clone())),
+            AcosOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_atan(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(AtanOps, B::float_atan);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::Float(dtype, FloatOperationIr::Atan(desc.clone())),
+            AtanOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_sinh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(SinhOps, B::float_sinh);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::Float(dtype, FloatOperationIr::Sinh(desc.clone())),
+            SinhOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_cosh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(CoshOps, B::float_cosh);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::Float(dtype, FloatOperationIr::Cosh(desc.clone())),
+            CoshOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_asinh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(AsinhOps, B::float_asinh);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::Float(dtype, FloatOperationIr::Asinh(desc.clone())),
+            AsinhOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_acosh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(AcoshOps, B::float_acosh);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::Float(dtype, FloatOperationIr::Acosh(desc.clone())),
+            AcoshOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_atanh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(AtanhOps, B::float_atanh);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::Float(dtype, FloatOperationIr::Atanh(desc.clone())),
+            AtanhOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_expm1(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(Expm1Ops, B::float_expm1);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::Float(dtype, FloatOperationIr::Expm1(desc.clone())),
+            Expm1Ops::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_erfinv(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(ErfInvOps, B::float_erfinv);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::Float(dtype, FloatOperationIr::ErfInv(desc.clone())),
+            ErfInvOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_lgamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(LgammaOps, B::float_lgamma);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::Float(dtype, FloatOperationIr::Lgamma(desc.clone())),
+            LgammaOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_digamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(DigammaOps, B::float_digamma);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::Float(dtype, FloatOperationIr::Digamma(desc.clone())),
+            DigammaOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_trunc(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(TruncOps, B::float_trunc);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::Float(dtype, FloatOperationIr::Trunc(desc.clone())),
+            TruncOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_atan2(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        binary_float_ops!(Atan2Ops, B::float_atan2);
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let dtype = lhs.dtype;
+        let out = lhs
+            .client
+            .tensor_uninitialized(binary_ops_shape(&lhs.shape, &rhs.shape), lhs.dtype);
+
+        let desc = BinaryOpIr {
+            lhs: lhs.into_ir(),
+            rhs: rhs.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationIr::Float(dtype, FloatOperationIr::Atan2(desc.clone())),
+            Atan2Ops::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_hypot(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        binary_float_ops!(HypotOps, B::float_hypot);
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let dtype = lhs.dtype;
+        let out = lhs
+            .client
+            .tensor_uninitialized(binary_ops_shape(&lhs.shape, &rhs.shape), lhs.dtype);
+
+        let desc = BinaryOpIr {
+            lhs: lhs.into_ir(),
+            rhs: rhs.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationIr::Float(dtype, FloatOperationIr::Hypot(desc.clone())),
+            HypotOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_nan_to_num(
+        tensor: FloatTensor<Self>,
+        nan: FloatElem<Self>,
+        posinf: FloatElem<Self>,
+        neginf: FloatElem<Self>,
+    ) -> FloatTensor<Self> {
+        #[derive(new)]
+        struct NanToNumOps<B: FusionBackend> {
+            desc: NanToNumOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for NanToNumOps<B> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let input = handles.get_float_tensor::<B>(&self.desc.input);
+                let output = B::float_nan_to_num(
+                    input,
+                    self.desc.nan.elem(),
+                    self.desc.posinf.elem(),
+                    self.desc.neginf.elem(),
+                );
+
+                handles.register_float_tensor::<B>(&self.desc.out.id, output);
+            }
+        }
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = NanToNumOpIr {
+            input: tensor.into_ir(),
+            nan: nan.elem(),
+            posinf: posinf.elem(),
+            neginf: neginf.elem(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::Float(dtype, FloatOperationIr::NanToNum(desc.clone())),
+            NanToNumOps::<B>::new(desc),
+        );
+
+        out
+    }
+
     fn float_cat(tensors: Vec<FloatTensor<Self>>, dim: usize) -> FloatTensor<Self> {
         #[derive(new)]
         struct CatOps<B: FusionBackend> {
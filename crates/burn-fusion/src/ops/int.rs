@@ -8,7 +8,7 @@ use crate::{
 use burn_ir::*;
 use burn_tensor::{
     ops::{binary_ops_shape, BoolTensor, FloatTensor, IntElem, IntTensor, IntTensorOps},
-    Device, Distribution, Element, ElementConversion, Shape, TensorData, TensorMetadata,
+    Device, Distribution, Element, ElementConversion, Reduce, Shape, TensorData, TensorMetadata,
 };
 use core::ops::Range;
 use std::marker::PhantomData;
@@ -399,6 +399,67 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
         out
     }
 
+    fn int_scatter_reduce(
+        dim: usize,
+        tensor: IntTensor<Self>,
+        indices: IntTensor<Self>,
+        value: IntTensor<Self>,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> IntTensor<Self> {
+        #[derive(new)]
+        struct ScatterReduceOps<B: FusionBackend> {
+            desc: ScatterReduceOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for ScatterReduceOps<B> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let tensor = handles.get_int_tensor::<B>(&self.desc.tensor);
+                let indices = handles.get_int_tensor::<B>(&self.desc.indices);
+                let value = handles.get_int_tensor::<B>(&self.desc.value);
+
+                let output = B::int_scatter_reduce(
+                    self.desc.dim,
+                    tensor,
+                    indices,
+                    value,
+                    self.desc.reduce.into(),
+                    self.desc.include_self,
+                );
+
+                handles.register_int_tensor::<B>(&self.desc.out.id, output);
+            }
+        }
+
+        let stream_1 = tensor.stream;
+        let stream_2 = indices.stream;
+        let stream_3 = value.stream;
+        let shape: Vec<usize> = tensor.shape.clone();
+        let out = tensor
+            .client
+            .tensor_uninitialized(shape, B::IntElem::dtype());
+        let desc = ScatterReduceOpIr {
+            tensor: tensor.into_ir(),
+            dim,
+            indices: indices.into_ir(),
+            value: value.into_ir(),
+            reduce: reduce.into(),
+            include_self,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2, stream_3],
+            OperationIr::NumericInt(
+                IntElem::<Self>::dtype(),
+                NumericOperationIr::ScatterReduce(desc.clone()),
+            ),
+            ScatterReduceOps::<B>::new(desc),
+        );
+
+        out
+    }
+
     fn int_select(
         tensor: IntTensor<Self>,
         dim: usize,
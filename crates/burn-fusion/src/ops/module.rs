@@ -1371,4 +1371,86 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         );
         out
     }
+
+    fn grouped_matmul(
+        lhs: FloatTensor<Self>,
+        rhs: FloatTensor<Self>,
+        offsets: IntTensor<Self>,
+    ) -> FloatTensor<Self> {
+        make_ops!(
+            GroupedMatmulOps,
+            GroupedMatmulOpIr,
+            |args: GroupedMatmulOpIr, handles: &mut HandleContainer<B::Handle>| {
+                let lhs = handles.get_float_tensor::<B>(&args.lhs);
+                let rhs = handles.get_float_tensor::<B>(&args.rhs);
+                let offsets = handles.get_int_tensor::<B>(&args.offsets);
+                let output = B::grouped_matmul(lhs, rhs, offsets);
+
+                handles.register_float_tensor::<B>(&args.out.id, output);
+            }
+        );
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let stream_3 = offsets.stream;
+        let shape = vec![lhs.shape[0], rhs.shape[2]];
+        let out = lhs
+            .client
+            .tensor_uninitialized(shape, B::FloatElem::dtype());
+
+        let desc = GroupedMatmulOpIr {
+            lhs: lhs.into_ir(),
+            rhs: rhs.into_ir(),
+            offsets: offsets.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2, stream_3],
+            OperationIr::Module(ModuleOperationIr::GroupedMatmul(desc.clone())),
+            GroupedMatmulOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn grouped_matmul_rhs_backward(
+        lhs: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+        offsets: IntTensor<Self>,
+    ) -> FloatTensor<Self> {
+        make_ops!(
+            GroupedMatmulRhsBackwardOps,
+            GroupedMatmulRhsBackwardOpIr,
+            |args: GroupedMatmulRhsBackwardOpIr, handles: &mut HandleContainer<B::Handle>| {
+                let lhs = handles.get_float_tensor::<B>(&args.lhs);
+                let grad = handles.get_float_tensor::<B>(&args.grad);
+                let offsets = handles.get_int_tensor::<B>(&args.offsets);
+                let output = B::grouped_matmul_rhs_backward(lhs, grad, offsets);
+
+                handles.register_float_tensor::<B>(&args.out.id, output);
+            }
+        );
+
+        let stream_1 = lhs.stream;
+        let stream_2 = grad.stream;
+        let stream_3 = offsets.stream;
+        let shape = vec![offsets.shape[0], lhs.shape[1], grad.shape[1]];
+        let out = lhs
+            .client
+            .tensor_uninitialized(shape, B::FloatElem::dtype());
+
+        let desc = GroupedMatmulRhsBackwardOpIr {
+            lhs: lhs.into_ir(),
+            grad: grad.into_ir(),
+            offsets: offsets.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2, stream_3],
+            OperationIr::Module(ModuleOperationIr::GroupedMatmulRhsBackward(desc.clone())),
+            GroupedMatmulRhsBackwardOps::<B>::new(desc),
+        );
+
+        out
+    }
 }
@@ -518,6 +518,22 @@ impl RelativeOps for ModuleOperationIr {
                     out: desc.out.to_relative(converter),
                 })
             }
+            ModuleOperationIr::GroupedMatmul(desc) => {
+                ModuleOperationIr::GroupedMatmul(GroupedMatmulOpIr {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs.to_relative(converter),
+                    offsets: desc.offsets.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            ModuleOperationIr::GroupedMatmulRhsBackward(desc) => {
+                ModuleOperationIr::GroupedMatmulRhsBackward(GroupedMatmulRhsBackwardOpIr {
+                    lhs: desc.lhs.to_relative(converter),
+                    grad: desc.grad.to_relative(converter),
+                    offsets: desc.offsets.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
         }
     }
 }
@@ -611,6 +627,85 @@ impl RelativeOpsScalar<f32> for FloatOperationIr {
                 input: desc.input.to_relative(converter),
                 out: desc.out.to_relative(converter),
             }),
+            FloatOperationIr::Asin(desc) => FloatOperationIr::Asin(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Acos(desc) => FloatOperationIr::Acos(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Atan(desc) => FloatOperationIr::Atan(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Sinh(desc) => FloatOperationIr::Sinh(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Cosh(desc) => FloatOperationIr::Cosh(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Asinh(desc) => FloatOperationIr::Asinh(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Acosh(desc) => FloatOperationIr::Acosh(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Atanh(desc) => FloatOperationIr::Atanh(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Expm1(desc) => FloatOperationIr::Expm1(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::ErfInv(desc) => FloatOperationIr::ErfInv(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Lgamma(desc) => FloatOperationIr::Lgamma(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Digamma(desc) => FloatOperationIr::Digamma(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Trunc(desc) => FloatOperationIr::Trunc(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Atan2(desc) => FloatOperationIr::Atan2(BinaryOpIr {
+                lhs: desc.lhs.to_relative(converter),
+                rhs: desc.rhs.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Hypot(desc) => FloatOperationIr::Hypot(BinaryOpIr {
+                lhs: desc.lhs.to_relative(converter),
+                rhs: desc.rhs.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Fmod(desc) => FloatOperationIr::Fmod(BinaryOpIr {
+                lhs: desc.lhs.to_relative(converter),
+                rhs: desc.rhs.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::FmodScalar(desc) => FloatOperationIr::FmodScalar(ScalarOpIr {
+                lhs: desc.lhs.to_relative(converter),
+                rhs: local_elem(converter, &desc.rhs.elem()),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::NanToNum(desc) => FloatOperationIr::NanToNum(NanToNumOpIr {
+                input: desc.input.to_relative(converter),
+                nan: local_elem(converter, &desc.nan.elem()),
+                posinf: local_elem(converter, &desc.posinf.elem()),
+                neginf: local_elem(converter, &desc.neginf.elem()),
+                out: desc.out.to_relative(converter),
+            }),
         }
     }
 }
@@ -822,6 +917,17 @@ impl<E: Element> RelativeOpsScalar<E> for NumericOperationIr<E> {
                 value: desc.value.to_relative(converter),
                 out: desc.out.to_relative(converter),
             }),
+            NumericOperationIr::ScatterReduce(desc) => {
+                NumericOperationIr::ScatterReduce(ScatterReduceOpIr {
+                    tensor: desc.tensor.to_relative(converter),
+                    dim: desc.dim,
+                    indices: desc.indices.to_relative(converter),
+                    value: desc.value.to_relative(converter),
+                    reduce: desc.reduce,
+                    include_self: desc.include_self,
+                    out: desc.out.to_relative(converter),
+                })
+            }
             NumericOperationIr::Select(desc) => NumericOperationIr::Select(SelectOpIr {
                 tensor: desc.tensor.to_relative(converter),
                 dim: desc.dim,
@@ -987,11 +987,11 @@ mod tests {
             &device,
         );
 
-        // The sizes are [1, 1, 2, 3]
+        // The sizes are [1, 1, 2, 3], with the default `half_pixel` coordinate transformation
         let output = model.forward(input);
-        let expected = TensorData::from([[[[0.0f32, 1.5, 3.0], [12.0, 13.5, 15.0]]]]);
+        let expected = TensorData::from([[[[2.1667f32, 3.5, 4.8333], [10.1667, 11.5, 12.8333]]]]);
 
-        output.to_data().assert_eq(&expected, true);
+        output.to_data().assert_approx_eq(&expected, 3);
     }
 
     #[test]
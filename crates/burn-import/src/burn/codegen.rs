@@ -4,6 +4,7 @@ use quote::quote;
 use burn::nn::PaddingConfig1d;
 use burn::nn::PaddingConfig2d;
 use burn::nn::PaddingConfig3d;
+use burn::tensor::PadMode;
 
 fn convert_primitive<T: core::fmt::Debug>(primitive: T) -> TokenStream {
     let value = format!("{:?}", primitive);
@@ -116,3 +117,18 @@ impl ToTokens for PaddingConfig3d {
         }
     }
 }
+
+/// Padding mode
+impl ToTokens for PadMode {
+    fn to_tokens(&self) -> TokenStream {
+        match self {
+            Self::Constant(value) => {
+                let value = value.to_tokens();
+                quote! { PadMode::Constant(#value) }
+            }
+            Self::Reflect => quote! { PadMode::Reflect },
+            Self::Replicate => quote! { PadMode::Replicate },
+            Self::Circular => quote! { PadMode::Circular },
+        }
+    }
+}
@@ -2,19 +2,21 @@ use std::marker::PhantomData;
 
 use super::{
     argmax::ArgMaxNode, avg_pool1d::AvgPool1dNode, avg_pool2d::AvgPool2dNode,
-    batch_norm::BatchNormNode, binary::BinaryNode, clip::ClipNode, concat::ConcatNode,
-    constant::ConstantNode, constant_of_shape::ConstantOfShapeNode, conv1d::Conv1dNode,
-    conv2d::Conv2dNode, conv3d::Conv3dNode, conv_transpose_1d::ConvTranspose1dNode,
-    conv_transpose_2d::ConvTranspose2dNode, conv_transpose_3d::ConvTranspose3dNode,
-    dropout::DropoutNode, expand::ExpandNode, floor::FloorNode, gather::GatherNode,
-    gather_elements::GatherElementsNode, gemm::GemmNode, global_avg_pool::GlobalAvgPoolNode,
-    layer_norm::LayerNormNode, linear::LinearNode, mask_where::WhereNode, matmul::MatmulNode,
-    max_pool1d::MaxPool1dNode, max_pool2d::MaxPool2dNode, mean::MeanNode, one_hot::OneHotNode,
+    avg_pool3d::AvgPool3dNode, batch_norm::BatchNormNode, binary::BinaryNode, clip::ClipNode,
+    concat::ConcatNode, constant::ConstantNode, constant_of_shape::ConstantOfShapeNode,
+    conv1d::Conv1dNode, conv2d::Conv2dNode, conv3d::Conv3dNode,
+    conv_transpose_1d::ConvTranspose1dNode, conv_transpose_2d::ConvTranspose2dNode,
+    conv_transpose_3d::ConvTranspose3dNode, depth_to_space::DepthToSpaceNode, dropout::DropoutNode,
+    expand::ExpandNode, floor::FloorNode, gather::GatherNode, gather_elements::GatherElementsNode,
+    gemm::GemmNode, global_avg_pool::GlobalAvgPoolNode, layer_norm::LayerNormNode,
+    linear::LinearNode, mask_where::WhereNode, matmul::MatmulNode, max_pool1d::MaxPool1dNode,
+    max_pool2d::MaxPool2dNode, max_pool3d::MaxPool3dNode, mean::MeanNode, one_hot::OneHotNode,
     pad::PadNode, prelu::PReluNode, random_normal::RandomNormalNode,
     random_normal_like::RandomNormalLikeNode, random_uniform::RandomUniformNode,
     random_uniform_like::RandomUniformLikeNode, range::RangeNode, reshape::ReshapeNode,
-    resize::ResizeNode, slice::SliceNode, split::SplitNode, squeeze::SqueezeNode, sum::SumNode,
-    tile::TileNode, top_k::TopKNode, trilu::TriluNode, unary::UnaryNode, unsqueeze::UnsqueezeNode,
+    resize::ResizeNode, slice::SliceNode, space_to_depth::SpaceToDepthNode, split::SplitNode,
+    squeeze::SqueezeNode, sum::SumNode, tile::TileNode, top_k::TopKNode, trilu::TriluNode,
+    unary::UnaryNode, unsqueeze::UnsqueezeNode,
 };
 use crate::burn::{BurnImports, Scope, Type};
 use burn::record::PrecisionSettings;
@@ -86,6 +88,7 @@ pub enum Node<PS: PrecisionSettings> {
     ArgMax(ArgMaxNode),
     AvgPool1d(AvgPool1dNode),
     AvgPool2d(AvgPool2dNode),
+    AvgPool3d(AvgPool3dNode),
     BatchNorm(BatchNormNode),
     Binary(BinaryNode),
     Clip(ClipNode),
@@ -98,6 +101,7 @@ pub enum Node<PS: PrecisionSettings> {
     ConvTranspose2d(ConvTranspose2dNode),
     ConvTranspose3d(ConvTranspose3dNode),
     PRelu(PReluNode),
+    DepthToSpace(DepthToSpaceNode),
     Dropout(DropoutNode),
     Expand(ExpandNode),
     Floor(FloorNode),
@@ -110,6 +114,7 @@ pub enum Node<PS: PrecisionSettings> {
     Matmul(MatmulNode),
     MaxPool1d(MaxPool1dNode),
     MaxPool2d(MaxPool2dNode),
+    MaxPool3d(MaxPool3dNode),
     Mean(MeanNode),
     OneHot(OneHotNode),
     Pad(PadNode),
@@ -117,6 +122,7 @@ pub enum Node<PS: PrecisionSettings> {
     Reshape(ReshapeNode),
     Resize(ResizeNode),
     Slice(SliceNode),
+    SpaceToDepth(SpaceToDepthNode),
     Squeeze(SqueezeNode),
     Split(SplitNode),
     Sum(SumNode),
@@ -143,6 +149,7 @@ macro_rules! match_all {
             Node::ArgMax(node) => $func(node),
             Node::AvgPool1d(node) => $func(node),
             Node::AvgPool2d(node) => $func(node),
+            Node::AvgPool3d(node) => $func(node),
             Node::BatchNorm(node) => $func(node),
             Node::Binary(node) => $func(node),
             Node::Clip(node) => $func(node),
@@ -155,6 +162,7 @@ macro_rules! match_all {
             Node::ConvTranspose2d(node) => $func(node),
             Node::ConvTranspose3d(node) => $func(node),
             Node::PRelu(node) => $func(node),
+            Node::DepthToSpace(node) => $func(node),
             Node::Dropout(node) => $func(node),
             Node::Expand(node) => $func(node),
             Node::Floor(node) => $func(node),
@@ -167,6 +175,7 @@ macro_rules! match_all {
             Node::Matmul(node) => $func(node),
             Node::MaxPool1d(node) => $func(node),
             Node::MaxPool2d(node) => $func(node),
+            Node::MaxPool3d(node) => $func(node),
             Node::Mean(node) => $func(node),
             Node::OneHot(node) => $func(node),
             Node::Pad(node) => $func(node),
@@ -174,6 +183,7 @@ macro_rules! match_all {
             Node::Reshape(node) => $func(node),
             Node::Resize(node) => $func(node),
             Node::Slice(node) => $func(node),
+            Node::SpaceToDepth(node) => $func(node),
             Node::Squeeze(node) => $func(node),
             Node::Sum(node) => $func(node),
             Node::Tile(node) => $func(node),
@@ -208,6 +218,7 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::ArgMax(_) => "argmax",
             Node::AvgPool1d(_) => "avg_pool1d",
             Node::AvgPool2d(_) => "avg_pool2d",
+            Node::AvgPool3d(_) => "avg_pool3d",
             Node::BatchNorm(_) => "batch_norm",
             Node::Binary(binary) => binary.binary_type.as_str(),
             Node::Concat(_) => "concat",
@@ -220,6 +231,7 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::ConvTranspose2d(_) => "conv_transpose2d",
             Node::ConvTranspose3d(_) => "conv_transpose3d",
             Node::PRelu(_) => "prelu",
+            Node::DepthToSpace(_) => "depth_to_space",
             Node::Dropout(_) => "dropout",
             Node::Expand(_) => "expand",
             Node::Floor(_) => "floor",
@@ -232,6 +244,7 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Matmul(_) => "matmul",
             Node::MaxPool1d(_) => "max_pool1d",
             Node::MaxPool2d(_) => "max_pool2d",
+            Node::MaxPool3d(_) => "max_pool3d",
             Node::Mean(_) => "mean",
             Node::OneHot(_) => "one_hot",
             Node::Pad(_) => "pad",
@@ -239,6 +252,7 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Reshape(_) => "reshape",
             Node::Resize(_) => "resize",
             Node::Slice(_) => "slice",
+            Node::SpaceToDepth(_) => "space_to_depth",
             Node::Squeeze(_) => "squeeze",
             Node::Sum(_) => "add",
             Node::Tile(_) => "tile",
@@ -100,6 +100,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for Conv2dNode {
             dilation: [ConstantRecord::new(); 2],
             groups: ConstantRecord::new(),
             padding: ConstantRecord::new(),
+            padding_mode: ConstantRecord::new(),
         };
 
         let item = Record::into_item::<PS>(record);
@@ -0,0 +1,167 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::config::Config;
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The order in which the channels are rearranged into blocks.
+#[derive(Config, Debug, PartialEq)]
+pub enum DepthToSpaceMode {
+    /// Depth-column-row order, the channels being split into `[block_size, block_size, channels]`.
+    Dcr,
+    /// Column-row-depth order, the channels being split into `[channels, block_size, block_size]`,
+    /// which is the pixel shuffle.
+    Crd,
+}
+
+#[derive(Config, Debug)]
+pub struct DepthToSpaceConfig {
+    pub block_size: usize,
+    pub mode: DepthToSpaceMode,
+}
+
+#[derive(Debug, Clone, new)]
+pub struct DepthToSpaceNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub config: DepthToSpaceConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for DepthToSpaceNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let block_size = self.config.block_size.to_tokens();
+
+        match self.config.mode {
+            DepthToSpaceMode::Crd => quote! {
+                let #output = burn::tensor::module::pixel_shuffle(#input, #block_size);
+            },
+            DepthToSpaceMode::Dcr => quote! {
+                let #output = {
+                    let [batch_size, channels, height, width] = #input.dims();
+                    let channels = channels / (#block_size * #block_size);
+                    #input
+                        .reshape([batch_size, #block_size, #block_size, channels, height, width])
+                        .permute([0, 3, 4, 1, 5, 2])
+                        .reshape([batch_size, channels, height * #block_size, width * #block_size])
+                };
+            },
+        }
+    }
+    fn into_node(self) -> super::Node<PS> {
+        Node::DepthToSpace(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{
+            depth_to_space::{DepthToSpaceConfig, DepthToSpaceMode, DepthToSpaceNode},
+            test::assert_tokens,
+        },
+        TensorType,
+    };
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen_depth_to_space_crd() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let config = DepthToSpaceConfig::new(2, DepthToSpaceMode::Crd);
+        graph.register(DepthToSpaceNode::new(
+            TensorType::new_float("input", 4),
+            TensorType::new_float("output", 4),
+            config,
+        ));
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = burn::tensor::module::pixel_shuffle(input, 2);
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_depth_to_space_dcr() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let config = DepthToSpaceConfig::new(2, DepthToSpaceMode::Dcr);
+        graph.register(DepthToSpaceNode::new(
+            TensorType::new_float("input", 4),
+            TensorType::new_float("output", 4),
+            config,
+        ));
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = {
+                        let [batch_size, channels, height, width] = input.dims();
+                        let channels = channels / (2 * 2);
+                        input
+                            .reshape([batch_size, 2, 2, channels, height, width])
+                            .permute([0, 3, 4, 1, 5, 2])
+                            .reshape([batch_size, channels, height * 2, width * 2])
+                    };
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,159 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use burn::{nn::pool::MaxPool3dConfig, record::PrecisionSettings};
+
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+
+#[derive(Debug, Clone)]
+pub struct MaxPool3dNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub output: TensorType,
+    pub config: MaxPool3dConfig,
+}
+
+impl MaxPool3dNode {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        output: TensorType,
+        config: MaxPool3dConfig,
+    ) -> Self {
+        Self {
+            field: OtherType::new(
+                name,
+                quote! {
+                    MaxPool3d
+                },
+            ),
+            input,
+            output,
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for MaxPool3dNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let kernel_size = self.config.kernel_size.to_tokens();
+        let strides = self.config.strides.to_tokens();
+        let padding = self.config.padding.to_tokens();
+        let dilation = self.config.dilation.to_tokens();
+        let tokens = quote! {
+            let #name = MaxPool3dConfig::new(#kernel_size)
+                .with_strides(#strides)
+                .with_padding(#padding)
+                .with_dilation(#dilation)
+                .init();
+        };
+
+        Some(tokens)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let field = &self.field.name;
+
+        quote! {
+            let #output = self.#field.forward(#input);
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::PaddingConfig3d");
+        imports.register("burn::nn::pool::MaxPool3d");
+        imports.register("burn::nn::pool::MaxPool3dConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::MaxPool3d(self)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        S::serialize_none(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{max_pool3d::MaxPool3dNode, test::assert_tokens},
+        TensorType,
+    };
+    use burn::{nn::pool::MaxPool3dConfig, nn::PaddingConfig3d, record::FullPrecisionSettings};
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MaxPool3dNode::new(
+            "max_pool3d",
+            TensorType::new_float("input", 5),
+            TensorType::new_float("output", 5),
+            MaxPool3dConfig::new([3, 3, 3])
+                .with_strides([1, 1, 1])
+                .with_padding(PaddingConfig3d::Valid)
+                .with_dilation([1, 1, 1]),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::PaddingConfig3d;
+            use burn::nn::pool::MaxPool3d;
+            use burn::nn::pool::MaxPool3dConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                max_pool3d: MaxPool3d,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let max_pool3d = MaxPool3dConfig::new([3, 3, 3])
+                        .with_strides([1, 1, 1])
+                        .with_padding(PaddingConfig3d::Valid)
+                        .with_dilation([1, 1, 1])
+                        .init();
+
+                    Self {
+                        max_pool3d,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 5>) -> Tensor<B, 5> {
+                    let output = self.max_pool3d.forward(input);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
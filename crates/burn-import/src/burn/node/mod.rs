@@ -3,6 +3,7 @@ mod base;
 pub(crate) mod argmax;
 pub(crate) mod avg_pool1d;
 pub(crate) mod avg_pool2d;
+pub(crate) mod avg_pool3d;
 pub(crate) mod batch_norm;
 pub(crate) mod binary;
 pub(crate) mod clip;
@@ -15,6 +16,7 @@ pub(crate) mod conv3d;
 pub(crate) mod conv_transpose_1d;
 pub(crate) mod conv_transpose_2d;
 pub(crate) mod conv_transpose_3d;
+pub(crate) mod depth_to_space;
 pub(crate) mod dropout;
 pub(crate) mod expand;
 pub(crate) mod floor;
@@ -28,6 +30,7 @@ pub(crate) mod mask_where;
 pub(crate) mod matmul;
 pub(crate) mod max_pool1d;
 pub(crate) mod max_pool2d;
+pub(crate) mod max_pool3d;
 pub(crate) mod mean;
 pub(crate) mod one_hot;
 pub(crate) mod pad;
@@ -40,6 +43,7 @@ pub(crate) mod range;
 pub(crate) mod reshape;
 pub(crate) mod resize;
 pub(crate) mod slice;
+pub(crate) mod space_to_depth;
 pub(crate) mod split;
 pub(crate) mod squeeze;
 pub(crate) mod sum;
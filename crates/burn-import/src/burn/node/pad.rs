@@ -1,16 +1,17 @@
-use std::str::FromStr;
-
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, TensorType, ToTokens, Type};
+use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
 use burn::config::Config;
 use burn::record::PrecisionSettings;
+use burn::tensor::PadMode;
 use proc_macro2::TokenStream;
 use quote::quote;
 
 #[derive(Config, Debug)]
 pub struct PadConfig {
-    pub pads: Vec<usize>,
-    pub constant_value: f32,
+    /// The padding before and after each dimension of the input.
+    pub pads: Vec<(usize, usize)>,
+    /// The values of the padded elements.
+    pub mode: PadMode,
 }
 
 #[derive(Debug, Clone, new)]
@@ -31,14 +32,20 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for PadNode {
         let input = scope.tensor_use_owned(&self.input, node_position);
         let output = &self.output.name;
 
-        let pads = self.config.pads.iter().map(|p| p.to_tokens());
-        let constant_value_string = format!("{}_f32", self.config.constant_value);
-        let constant_value = TokenStream::from_str(&constant_value_string).unwrap();
+        let pads = self.config.pads.iter().map(|(before, after)| {
+            let before = before.to_tokens();
+            let after = after.to_tokens();
+            quote! { (#before, #after) }
+        });
+        let mode = self.config.mode.to_tokens();
 
         quote! {
-            let #output = #input.pad((#(#pads),*), #constant_value);
+            let #output = #input.pad_with(&[#(#pads),*], #mode);
         }
     }
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::tensor::PadMode");
+    }
     fn into_node(self) -> Node<PS> {
         Node::Pad(self)
     }
@@ -58,7 +65,7 @@ mod tests {
     #[test]
     fn test_codegen_pad() {
         let mut graph = BurnGraph::<FullPrecisionSettings>::default();
-        let config = PadConfig::new(vec![1, 2, 3, 4], -1.0);
+        let config = PadConfig::new(vec![(3, 4), (1, 2)], PadMode::Constant(-1.0));
         graph.register(PadNode::new(
             TensorType::new_float("input", 2),
             TensorType::new_float("output", 2),
@@ -71,6 +78,7 @@ mod tests {
                 module::Module,
                 tensor::{backend::Backend, Tensor},
             };
+            use burn::tensor::PadMode;
 
             #[derive(Module, Debug)]
             pub struct Model<B: Backend> {
@@ -88,7 +96,7 @@ mod tests {
                 }
                 #[allow(clippy::let_and_return, clippy::approx_constant)]
                 pub fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
-                    let output = input.pad((1, 2, 3, 4), -1_f32);
+                    let output = input.pad_with(&[(3, 4), (1, 2)], PadMode::Constant(-1.0));
                     output
                 }
             }
@@ -12,6 +12,7 @@ pub struct ResizeNode {
     mode: String,
     scales: Vec<f32>,
     sizes: Vec<usize>,
+    align_corners: bool,
 }
 
 impl ResizeNode {
@@ -22,6 +23,7 @@ impl ResizeNode {
         mode: String,
         scales: Vec<f32>,
         sizes: Vec<usize>,
+        align_corners: bool,
     ) -> Self {
         let ty = if input.rank == 3 {
             quote! {
@@ -31,6 +33,10 @@ impl ResizeNode {
             quote! {
                 Interpolate2d
             }
+        } else if input.rank == 5 {
+            quote! {
+                Interpolate3d
+            }
         } else {
             panic!("Unsupported input rank for resize node");
         };
@@ -42,6 +48,7 @@ impl ResizeNode {
             mode,
             scales,
             sizes,
+            align_corners,
         }
     }
 }
@@ -68,6 +75,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ResizeNode {
             "cubic" => quote! { InterpolateMode::Cubic },
             _ => panic!("Unsupported mode for resize node"),
         };
+        let align_corners = self.align_corners;
 
         let tokens = if self.input.rank == 3 {
             let size = if let Some(size) = self.sizes.first() {
@@ -89,6 +97,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ResizeNode {
                     .with_output_size(#size)
                     .with_scale_factor(#scale_factor)
                     .with_mode(#mode)
+                    .with_align_corners(#align_corners)
                     .init();
             }
         } else if self.input.rank == 4 {
@@ -113,6 +122,34 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ResizeNode {
                     .with_output_size(#size)
                     .with_scale_factor(#scale_factor)
                     .with_mode(#mode)
+                    .with_align_corners(#align_corners)
+                    .init();
+            }
+        } else if self.input.rank == 5 {
+            let size = if self.sizes.len() == 3 {
+                let d = self.sizes[0].to_tokens();
+                let h = self.sizes[1].to_tokens();
+                let w = self.sizes[2].to_tokens();
+                quote! { Some([#d, #h, #w]) }
+            } else {
+                quote! { None }
+            };
+
+            let scale_factor = if self.scales.len() == 3 {
+                let d = self.scales[0].to_tokens();
+                let h = self.scales[1].to_tokens();
+                let w = self.scales[2].to_tokens();
+                quote! { Some([#d, #h, #w]) }
+            } else {
+                quote! { None }
+            };
+
+            quote! {
+                let #name = Interpolate3dConfig::new()
+                    .with_output_size(#size)
+                    .with_scale_factor(#scale_factor)
+                    .with_mode(#mode)
+                    .with_align_corners(#align_corners)
                     .init();
             }
         } else {
@@ -130,6 +167,9 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ResizeNode {
         } else if self.input.rank == 4 {
             imports.register("burn::nn::interpolate::Interpolate2dConfig");
             imports.register("burn::nn::interpolate::Interpolate2d");
+        } else if self.input.rank == 5 {
+            imports.register("burn::nn::interpolate::Interpolate3dConfig");
+            imports.register("burn::nn::interpolate::Interpolate3d");
         } else {
             panic!("Unsupported input rank for resize node");
         }
@@ -176,6 +216,7 @@ mod tests {
             "nearest".to_string(),
             vec![0.5, 0.5],
             vec![],
+            true,
         ));
 
         graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
@@ -201,6 +242,7 @@ mod tests {
                         .with_output_size(None)
                         .with_scale_factor(Some([0.5, 0.5]))
                         .with_mode(InterpolateMode::Nearest)
+                        .with_align_corners(true)
                         .init();
                     Self {
                         resize,
@@ -230,6 +272,7 @@ mod tests {
             "cubic".to_string(),
             vec![2.0],
             vec![20],
+            false,
         ));
 
         graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
@@ -255,6 +298,7 @@ mod tests {
                         .with_output_size(Some(20))
                         .with_scale_factor(Some(2.0))
                         .with_mode(InterpolateMode::Cubic)
+                        .with_align_corners(false)
                         .init();
                     Self {
                         resize,
@@ -272,4 +316,60 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_nodes_3d() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ResizeNode::new(
+            "resize",
+            TensorType::new_float("tensor1", 5),
+            TensorType::new_float("tensor2", 5),
+            "linear".to_string(),
+            vec![],
+            vec![4, 8, 8],
+            false,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::nn::interpolate::Interpolate3d;
+            use burn::nn::interpolate::Interpolate3dConfig;
+            use burn::nn::interpolate::InterpolateMode;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                resize: Interpolate3d,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let resize = Interpolate3dConfig::new()
+                        .with_output_size(Some([4, 8, 8]))
+                        .with_scale_factor(None)
+                        .with_mode(InterpolateMode::Linear)
+                        .with_align_corners(false)
+                        .init();
+                    Self {
+                        resize,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 5>) -> Tensor<B, 5> {
+                    let tensor2 = self.resize.forward(tensor1);
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
@@ -0,0 +1,112 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::config::Config;
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Config, Debug)]
+pub struct SpaceToDepthConfig {
+    pub block_size: usize,
+}
+
+#[derive(Debug, Clone, new)]
+pub struct SpaceToDepthNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub config: SpaceToDepthConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for SpaceToDepthNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let block_size = self.config.block_size.to_tokens();
+
+        // The blocks are moved in front of the channels, unlike the pixel unshuffle.
+        quote! {
+            let #output = {
+                let [batch_size, channels, height, width] = #input.dims();
+                let height = height / #block_size;
+                let width = width / #block_size;
+                #input
+                    .reshape([batch_size, channels, height, #block_size, width, #block_size])
+                    .permute([0, 3, 5, 1, 2, 4])
+                    .reshape([batch_size, channels * #block_size * #block_size, height, width])
+            };
+        }
+    }
+    fn into_node(self) -> super::Node<PS> {
+        Node::SpaceToDepth(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{
+            space_to_depth::{SpaceToDepthConfig, SpaceToDepthNode},
+            test::assert_tokens,
+        },
+        TensorType,
+    };
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen_space_to_depth() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let config = SpaceToDepthConfig::new(2);
+        graph.register(SpaceToDepthNode::new(
+            TensorType::new_float("input", 4),
+            TensorType::new_float("output", 4),
+            config,
+        ));
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = {
+                        let [batch_size, channels, height, width] = input.dims();
+                        let height = height / 2;
+                        let width = width / 2;
+                        input
+                            .reshape([batch_size, channels, height, 2, width, 2])
+                            .permute([0, 3, 5, 1, 2, 4])
+                            .reshape([batch_size, channels * 2 * 2, height, width])
+                    };
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
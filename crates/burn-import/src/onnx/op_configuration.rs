@@ -3,13 +3,23 @@ use burn::nn::{
         Conv1dConfig, Conv2dConfig, Conv3dConfig, ConvTranspose1dConfig, ConvTranspose2dConfig,
         ConvTranspose3dConfig,
     },
-    pool::{AvgPool1dConfig, AvgPool2dConfig, MaxPool1dConfig, MaxPool2dConfig},
+    pool::{
+        AvgPool1dConfig, AvgPool2dConfig, AvgPool3dConfig, MaxPool1dConfig, MaxPool2dConfig,
+        MaxPool3dConfig,
+    },
     BatchNormConfig, DropoutConfig, LayerNormConfig, LinearConfig, PaddingConfig1d,
     PaddingConfig2d, PaddingConfig3d,
 };
+use burn::tensor::PadMode;
 
 use crate::burn::node::{
-    expand::ExpandShape, pad::PadConfig, split::SplitConfig, tile::TileConfig, top_k::TopKConfig,
+    depth_to_space::{DepthToSpaceConfig, DepthToSpaceMode},
+    expand::ExpandShape,
+    pad::PadConfig,
+    space_to_depth::SpaceToDepthConfig,
+    split::SplitConfig,
+    tile::TileConfig,
+    top_k::TopKConfig,
     trilu::TriluConfig,
 };
 use onnx_ir::ir::{ArgType, AttributeValue, Data, ElementType, Node};
@@ -224,6 +234,43 @@ pub fn max_pool2d_config(curr: &Node) -> MaxPool2dConfig {
         .with_dilation([dilations[0] as usize, dilations[1] as usize])
 }
 
+/// Create a MaxPool3dConfig from the attributes of the node
+pub fn max_pool3d_config(curr: &Node) -> MaxPool3dConfig {
+    let mut kernel_shape = Vec::new();
+    let mut strides = vec![1, 1, 1];
+    let mut pads = vec![0, 0, 0, 0, 0, 0];
+    let mut dilations = vec![1, 1, 1];
+
+    for (key, value) in curr.attrs.iter() {
+        match key.as_str() {
+            "kernel_shape" => kernel_shape = value.clone().into_i64s(),
+            "strides" => strides = value.clone().into_i64s(),
+            "pads" => pads = value.clone().into_i64s(),
+            "dilations" => dilations = value.clone().into_i64s(),
+            _ => {}
+        }
+    }
+
+    let padding = padding_config_3d(&pads);
+
+    MaxPool3dConfig::new([
+        kernel_shape[0] as usize,
+        kernel_shape[1] as usize,
+        kernel_shape[2] as usize,
+    ])
+    .with_strides([
+        strides[0] as usize,
+        strides[1] as usize,
+        strides[2] as usize,
+    ])
+    .with_padding(padding)
+    .with_dilation([
+        dilations[0] as usize,
+        dilations[1] as usize,
+        dilations[2] as usize,
+    ])
+}
+
 pub fn conv_transpose1d_config(curr: &Node) -> ConvTranspose1dConfig {
     let mut attrs = curr.attrs.clone();
 
@@ -502,6 +549,45 @@ pub fn avg_pool2d_config(curr: &Node) -> AvgPool2dConfig {
         .with_count_include_pad(count_include_pad == 1)
 }
 
+/// Create a AvgPool3dConfig from the attributes of the node
+pub fn avg_pool3d_config(curr: &Node) -> AvgPool3dConfig {
+    let mut kernel_shape = Vec::new();
+    let mut strides = vec![1, 1, 1];
+    let mut pads = vec![0, 0, 0, 0, 0, 0];
+    let mut count_include_pad: i64 = 0;
+    let mut ceil_mode: i64 = 0;
+
+    for (key, value) in curr.attrs.iter() {
+        match key.as_str() {
+            "kernel_shape" => kernel_shape = value.clone().into_i64s(),
+            "strides" => strides = value.clone().into_i64s(),
+            "pads" => pads = value.clone().into_i64s(),
+            "count_include_pad" => count_include_pad = value.clone().into_i64(),
+            "ceil_mode" => ceil_mode = value.clone().into_i64(),
+            _ => {}
+        }
+    }
+
+    if ceil_mode == 1 {
+        panic!("ceil_mode is not supported");
+    }
+
+    let padding = padding_config_3d(&pads);
+
+    AvgPool3dConfig::new([
+        kernel_shape[0] as usize,
+        kernel_shape[1] as usize,
+        kernel_shape[2] as usize,
+    ])
+    .with_strides([
+        strides[0] as usize,
+        strides[1] as usize,
+        strides[2] as usize,
+    ])
+    .with_padding(padding)
+    .with_count_include_pad(count_include_pad == 1)
+}
+
 pub fn expand_config(node: &Node) -> ExpandShape {
     let input_value = &node.inputs[1].value;
     match &node.inputs[1].ty {
@@ -967,6 +1053,40 @@ pub fn trilu_config(node: &Node) -> TriluConfig {
     TriluConfig::new(upper, diagonal)
 }
 
+/// Create a DepthToSpaceConfig from the attributes of the node
+pub fn depth_to_space_config(node: &Node) -> DepthToSpaceConfig {
+    let mut block_size = None;
+    let mut mode = DepthToSpaceMode::Dcr;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "blocksize" => block_size = Some(value.clone().into_i64() as usize),
+            "mode" => {
+                mode = match value.clone().into_string().as_str() {
+                    "DCR" => DepthToSpaceMode::Dcr,
+                    "CRD" => DepthToSpaceMode::Crd,
+                    mode => panic!("DepthToSpace: unsupported mode {mode}"),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let block_size = block_size.expect("DepthToSpace: blocksize must be present");
+    DepthToSpaceConfig::new(block_size, mode)
+}
+
+/// Create a SpaceToDepthConfig from the attributes of the node
+pub fn space_to_depth_config(node: &Node) -> SpaceToDepthConfig {
+    let block_size = node
+        .attrs
+        .get("blocksize")
+        .map(|value| value.clone().into_i64() as usize)
+        .expect("SpaceToDepth: blocksize must be present");
+
+    SpaceToDepthConfig::new(block_size)
+}
+
 /// Create a PadConfig from the attributes of the node
 pub fn pad_config(node: &Node) -> PadConfig {
     fn get_pads_input(node: &Node) -> Vec<i64> {
@@ -980,7 +1100,7 @@ pub fn pad_config(node: &Node) -> PadConfig {
             _ => panic!("Tensor data type must be int64"),
         }
     }
-    fn get_pads(node: &Node) -> Vec<usize> {
+    fn get_pads(node: &Node) -> Vec<(usize, usize)> {
         if node.inputs.is_empty() {
             panic!("Pad: must provide data as input")
         }
@@ -993,36 +1113,23 @@ pub fn pad_config(node: &Node) -> PadConfig {
             _ => panic!("Pad: Only tensor input is valid"),
         };
 
-        //TODO : handle more possible attributes
         let mut pads: Vec<usize> = get_pads_input(node)
             .into_iter()
             .map(|x| x as usize)
             .collect();
 
-        for (key, value) in node.attrs.iter() {
-            match key.as_str() {
-                "pads" => {
-                    pads = value
-                        .clone()
-                        .into_i64s()
-                        .iter()
-                        .map(|&x| {
-                            if x < 0 {
-                                panic!("Pad: Negative pad is not supported");
-                            }
-                            x as usize
-                        })
-                        .collect()
-                }
-                "mode" => {
-                    let mode = value.clone().into_string();
-                    if mode != "constant" {
-                        panic!("only constant mode is supported, given mode is {}", mode);
+        if let Some(value) = node.attrs.get("pads") {
+            pads = value
+                .clone()
+                .into_i64s()
+                .iter()
+                .map(|&x| {
+                    if x < 0 {
+                        panic!("Pad: Negative pad is not supported");
                     }
-                }
-
-                _ => {}
-            }
+                    x as usize
+                })
+                .collect()
         }
 
         if pads.is_empty() {
@@ -1032,28 +1139,26 @@ pub fn pad_config(node: &Node) -> PadConfig {
         if pads.len() != input_dim * 2 {
             panic!("Pad: pads should be a 1D tensor of shape [2 * num_axes]");
         }
-        // TODO: Burn's pad should support 1D tensor
-        if input_dim < 2 {
-            panic!("Pad: input tensor should be rank 2 or higher");
-        }
-
-        let left_index = input_dim - 1;
-        let top_index = input_dim - 2;
-        let right_index = pads.len() - 1;
-        let bottom_index = pads.len() - 2;
-        let index_list = [left_index, top_index, right_index, bottom_index];
 
-        for (index, &item) in pads.iter().enumerate() {
-            if !index_list.contains(&index) && item != 0 {
-                panic!("Pad: padding will only be applied to the last two dimensions but found non zero padding for other dimensions");
-            }
+        // The pads are given as [x1_begin, x2_begin, ..., x1_end, x2_end, ...].
+        (0..input_dim)
+            .map(|axis| (pads[axis], pads[axis + input_dim]))
+            .collect()
+    }
+    fn get_mode(node: &Node, constant_value: f32) -> PadMode {
+        let mode = node
+            .attrs
+            .get("mode")
+            .map(|value| value.clone().into_string())
+            .unwrap_or_else(|| "constant".to_string());
+
+        match mode.as_str() {
+            "constant" => PadMode::Constant(constant_value as f64),
+            "reflect" => PadMode::Reflect,
+            "edge" => PadMode::Replicate,
+            "wrap" => PadMode::Circular,
+            _ => panic!("Pad: unsupported mode {}", mode),
         }
-
-        let left = pads[left_index];
-        let top = pads[top_index];
-        let right = pads[right_index];
-        let bottom = pads[bottom_index];
-        vec![left, right, top, bottom]
     }
     fn get_constant_value(node: &Node) -> f32 {
         // TODO: support int, boolean
@@ -1086,9 +1191,9 @@ pub fn pad_config(node: &Node) -> PadConfig {
     }
 
     let pads = get_pads(node);
-    let constant_value = get_constant_value(node);
+    let mode = get_mode(node, get_constant_value(node));
 
-    PadConfig::new(pads, constant_value)
+    PadConfig::new(pads, mode)
 }
 
 /// Calculate the padding configuration for a 1D operations such as Convolution and Pooling.
@@ -1272,8 +1377,10 @@ pub fn reshape_config(node: &Node) -> Vec<i64> {
     }
 }
 
-pub fn resize_config(node: &Node) -> (String, Vec<f32>, Vec<usize>) {
+pub fn resize_config(node: &Node) -> (String, Vec<f32>, Vec<usize>, bool) {
     let mut mode: String = "".to_string();
+    // The default coordinate transformation mode is `half_pixel`
+    let mut align_corners = false;
 
     let mut scales: Vec<f32>;
     let mut sizes: Vec<usize>;
@@ -1303,7 +1410,17 @@ pub fn resize_config(node: &Node) -> (String, Vec<f32>, Vec<usize>) {
             ),
             "axes" => panic!("Resize: custom axes attribute is not supported"),
             "coordinate_transformation_mode" => {
-                log::warn!("Resize: coordinate_transformation_mode is ignored")
+                align_corners = match value.clone().into_string().to_lowercase().as_str() {
+                    "align_corners" => true,
+                    "half_pixel" | "pytorch_half_pixel" => false,
+                    other => {
+                        log::warn!(
+                            "Resize: coordinate_transformation_mode {other} is not supported, \
+                            half_pixel is used instead"
+                        );
+                        false
+                    }
+                }
             }
 
             "cubic_coeff_a" => log::warn!("Resize: cubic_coeff_a is ignored"),
@@ -1397,7 +1514,7 @@ pub fn resize_config(node: &Node) -> (String, Vec<f32>, Vec<usize>) {
         sizes = sizes.iter().skip(2).cloned().collect();
     }
 
-    (mode, scales, sizes)
+    (mode, scales, sizes, align_corners)
 }
 
 //Note this function should only execute if the second input is a constant
@@ -18,6 +18,7 @@ use crate::{
             argmax::ArgMaxNode,
             avg_pool1d::AvgPool1dNode,
             avg_pool2d::AvgPool2dNode,
+            avg_pool3d::AvgPool3dNode,
             batch_norm::BatchNormNode,
             binary::BinaryNode,
             clip::ClipNode,
@@ -30,6 +31,7 @@ use crate::{
             conv_transpose_1d::ConvTranspose1dNode,
             conv_transpose_2d::ConvTranspose2dNode,
             conv_transpose_3d::ConvTranspose3dNode,
+            depth_to_space::DepthToSpaceNode,
             dropout::DropoutNode,
             expand::{ExpandNode, ExpandShape},
             floor::FloorNode,
@@ -43,6 +45,7 @@ use crate::{
             matmul::MatmulNode,
             max_pool1d::MaxPool1dNode,
             max_pool2d::MaxPool2dNode,
+            max_pool3d::MaxPool3dNode,
             one_hot::OneHotNode,
             pad::PadNode,
             prelu::PReluNode,
@@ -54,6 +57,7 @@ use crate::{
             reshape::ReshapeNode,
             resize::ResizeNode,
             slice::SliceNode,
+            space_to_depth::SpaceToDepthNode,
             split::SplitNode,
             squeeze::SqueezeNode,
             sum::SumNode,
@@ -70,14 +74,15 @@ use crate::{
 };
 
 use super::op_configuration::{
-    argmax_config, avg_pool1d_config, avg_pool2d_config, batch_norm_config, clip_config,
-    concat_config, conv1d_config, conv2d_config, conv3d_config, conv_transpose1d_config,
-    conv_transpose2d_config, conv_transpose3d_config, dropout_config, expand_config,
-    flatten_config, gather_config, gemm_config, hard_sigmoid_config, layer_norm_config,
-    leaky_relu_config, linear_config, log_softmax_config, max_pool1d_config, max_pool2d_config,
-    one_hot_config, pad_config, reduce_max_config, reduce_mean_config, reduce_min_config,
-    reduce_prod_config, reduce_sum_config, reshape_config, resize_config, shape_config,
-    slice_config, softmax_config, split_config, squeeze_config, tile_config, top_k_config,
+    argmax_config, avg_pool1d_config, avg_pool2d_config, avg_pool3d_config, batch_norm_config,
+    clip_config, concat_config, conv1d_config, conv2d_config, conv3d_config,
+    conv_transpose1d_config, conv_transpose2d_config, conv_transpose3d_config,
+    depth_to_space_config, dropout_config, expand_config, flatten_config, gather_config,
+    gemm_config, hard_sigmoid_config, layer_norm_config, leaky_relu_config, linear_config,
+    log_softmax_config, max_pool1d_config, max_pool2d_config, max_pool3d_config, one_hot_config,
+    pad_config, reduce_max_config, reduce_mean_config, reduce_min_config, reduce_prod_config,
+    reduce_sum_config, reshape_config, resize_config, shape_config, slice_config, softmax_config,
+    space_to_depth_config, split_config, squeeze_config, tile_config, top_k_config,
     transpose_config, trilu_config, unsqueeze_config,
 };
 use onnx_ir::{
@@ -282,10 +287,12 @@ impl ParsedOnnxGraph {
                 NodeType::Max => graph.register(Self::max_conversion(node)),
                 NodeType::MaxPool1d => graph.register(Self::max_pool1d_conversion(node)),
                 NodeType::MaxPool2d => graph.register(Self::max_pool2d_conversion(node)),
+                NodeType::MaxPool3d => graph.register(Self::max_pool3d_conversion(node)),
                 NodeType::Mean => graph.register(Self::mean_conversion(node)),
                 NodeType::PRelu => graph.register(Self::prelu_conversion::<PS>(node)),
                 NodeType::AveragePool1d => graph.register(Self::avg_pool_1d_conversion(node)),
                 NodeType::AveragePool2d => graph.register(Self::avg_pool_2d_conversion(node)),
+                NodeType::AveragePool3d => graph.register(Self::avg_pool_3d_conversion(node)),
                 NodeType::MatMul => graph.register(Self::matmul_conversion(node)),
                 NodeType::Neg => graph.register(Self::neg_conversion(node)),
                 NodeType::Not => graph.register(Self::not_conversion(node)),
@@ -359,6 +366,8 @@ impl ParsedOnnxGraph {
                 NodeType::Tile => graph.register(Self::tile_conversion(node)),
                 NodeType::TopK => graph.register(Self::top_k_conversion(node)),
                 NodeType::Trilu => graph.register(Self::trilu_conversion(node)),
+                NodeType::DepthToSpace => graph.register(Self::depth_to_space_conversion(node)),
+                NodeType::SpaceToDepth => graph.register(Self::space_to_depth_conversion(node)),
                 NodeType::RandomNormal => graph.register(Self::random_normal_conversion(node)),
                 NodeType::RandomNormalLike => {
                     graph.register(Self::random_normal_like_conversion(node))
@@ -723,9 +732,9 @@ impl ParsedOnnxGraph {
 
         let output = TensorType::from(node.outputs.first().unwrap());
 
-        let (mode, scales, sizes) = resize_config(&node);
+        let (mode, scales, sizes, align_corners) = resize_config(&node);
 
-        ResizeNode::new(name, input, output, mode, scales, sizes)
+        ResizeNode::new(name, input, output, mode, scales, sizes, align_corners)
     }
 
     fn min_conversion(node: Node) -> BinaryNode {
@@ -1053,6 +1062,15 @@ impl ParsedOnnxGraph {
         MaxPool2dNode::new(name, input, output, config)
     }
 
+    fn max_pool3d_conversion(node: Node) -> MaxPool3dNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = max_pool3d_config(&node);
+
+        let name = &node.name;
+        MaxPool3dNode::new(name, input, output, config)
+    }
+
     fn mean_conversion(node: Node) -> MeanNode {
         let inputs = node.inputs.iter().map(TensorType::from).collect();
         let output = TensorType::from(node.outputs.first().unwrap());
@@ -1143,6 +1161,15 @@ impl ParsedOnnxGraph {
         AvgPool2dNode::new(name, input, output, config)
     }
 
+    fn avg_pool_3d_conversion(node: Node) -> AvgPool3dNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = avg_pool3d_config(&node);
+
+        let name = &node.name;
+        AvgPool3dNode::new(name, input, output, config)
+    }
+
     fn global_avg_pool_conversion(node: Node) -> GlobalAvgPoolNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
@@ -1296,6 +1323,20 @@ impl ParsedOnnxGraph {
         TriluNode::new(input, output, config)
     }
 
+    fn depth_to_space_conversion(node: Node) -> DepthToSpaceNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = depth_to_space_config(&node);
+        DepthToSpaceNode::new(input, output, config)
+    }
+
+    fn space_to_depth_conversion(node: Node) -> SpaceToDepthNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = space_to_depth_config(&node);
+        SpaceToDepthNode::new(input, output, config)
+    }
+
     fn split_conversion(node: Node) -> SplitNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let outputs = node.outputs.iter().map(TensorType::from).collect();
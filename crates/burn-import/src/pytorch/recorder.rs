@@ -130,6 +130,20 @@ impl LoadArgs {
         self
     }
 
+    /// Sets multiple key remappings, applied in order.
+    ///
+    /// This is useful with the key remappings provided for the vision backbones, such as
+    /// `burn::nn::vision::RESNET_KEY_REMAP`.
+    ///
+    /// # Arguments
+    ///
+    /// * `remaps` - The `(pattern, replacement)` pairs, see [with_key_remap](Self::with_key_remap).
+    pub fn with_key_remaps(self, remaps: &[(&str, &str)]) -> Self {
+        remaps.iter().fold(self, |args, (pattern, replacement)| {
+            args.with_key_remap(pattern, replacement)
+        })
+    }
+
     /// Sets the top-level key to load state_dict from the file.
     /// Sometimes the state_dict is nested under a top-level key in a dict.
     ///
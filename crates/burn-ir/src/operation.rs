@@ -11,7 +11,7 @@ use burn_tensor::{
         ConvOptions, ConvTransposeOptions, DeformConvOptions, InterpolateMode, InterpolateOptions,
     },
     quantization::QuantizationScheme,
-    DType, Distribution, Element,
+    DType, Distribution, Element, Reduce,
 };
 
 use crate::TensorIr;
@@ -110,6 +110,42 @@ pub enum FloatOperationIr {
     Floor(UnaryOpIr),
     /// Operation corresponding to [ceil](burn_tensor::ops::FloatTensorOps::float_ceil).
     Ceil(UnaryOpIr),
+    /// Operation corresponding to [trunc](burn_tensor::ops::FloatTensorOps::float_trunc).
+    Trunc(UnaryOpIr),
+    /// Operation corresponding to [asin](burn_tensor::ops::FloatTensorOps::float_asin).
+    Asin(UnaryOpIr),
+    /// Operation corresponding to [acos](burn_tensor::ops::FloatTensorOps::float_acos).
+    Acos(UnaryOpIr),
+    /// Operation corresponding to [atan](burn_tensor::ops::FloatTensorOps::float_atan).
+    Atan(UnaryOpIr),
+    /// Operation corresponding to [sinh](burn_tensor::ops::FloatTensorOps::float_sinh).
+    Sinh(UnaryOpIr),
+    /// Operation corresponding to [cosh](burn_tensor::ops::FloatTensorOps::float_cosh).
+    Cosh(UnaryOpIr),
+    /// Operation corresponding to [asinh](burn_tensor::ops::FloatTensorOps::float_asinh).
+    Asinh(UnaryOpIr),
+    /// Operation corresponding to [acosh](burn_tensor::ops::FloatTensorOps::float_acosh).
+    Acosh(UnaryOpIr),
+    /// Operation corresponding to [atanh](burn_tensor::ops::FloatTensorOps::float_atanh).
+    Atanh(UnaryOpIr),
+    /// Operation corresponding to [expm1](burn_tensor::ops::FloatTensorOps::float_expm1).
+    Expm1(UnaryOpIr),
+    /// Operation corresponding to [erfinv](burn_tensor::ops::FloatTensorOps::float_erfinv).
+    ErfInv(UnaryOpIr),
+    /// Operation corresponding to [lgamma](burn_tensor::ops::FloatTensorOps::float_lgamma).
+    Lgamma(UnaryOpIr),
+    /// Operation corresponding to [digamma](burn_tensor::ops::FloatTensorOps::float_digamma).
+    Digamma(UnaryOpIr),
+    /// Operation corresponding to [atan2](burn_tensor::ops::FloatTensorOps::float_atan2).
+    Atan2(BinaryOpIr),
+    /// Operation corresponding to [hypot](burn_tensor::ops::FloatTensorOps::float_hypot).
+    Hypot(BinaryOpIr),
+    /// Operation corresponding to [fmod](burn_tensor::ops::FloatTensorOps::float_fmod).
+    Fmod(BinaryOpIr),
+    /// Operation corresponding to [fmod_scalar](burn_tensor::ops::FloatTensorOps::float_fmod_scalar).
+    FmodScalar(ScalarOpIr<f32>),
+    /// Operation corresponding to [nan_to_num](burn_tensor::ops::FloatTensorOps::float_nan_to_num).
+    NanToNum(NanToNumOpIr),
     /// Operation corresponding to [into_int](burn_tensor::ops::FloatTensorOps::float_into_int).
     IntoInt(UnaryOpIr),
     /// Operation corresponding to [matmul](burn_tensor::ops::FloatTensorOps::float_matmul).
@@ -191,6 +227,11 @@ pub enum ModuleOperationIr {
     Interpolate(InterpolateOpIr),
     /// Operation corresponding to [interpolate backward](burn_tensor::ops::ModuleOps::interpolate_backward).
     InterpolateBackward(InterpolateBackwardOpIr),
+    /// Operation corresponding to [grouped matmul](burn_tensor::ops::ModuleOps::grouped_matmul).
+    GroupedMatmul(GroupedMatmulOpIr),
+    /// Operation corresponding to
+    /// [grouped matmul rhs backward](burn_tensor::ops::ModuleOps::grouped_matmul_rhs_backward).
+    GroupedMatmulRhsBackward(GroupedMatmulRhsBackwardOpIr),
 }
 
 /// Basic operations that can be done on any tensor type.
@@ -362,6 +403,11 @@ pub enum NumericOperationIr<E> {
     Scatter(ScatterOpIr),
     /// Operation corresponding to:
     ///
+    /// Float => [scatter reduce](burn_tensor::ops::FloatTensorOps::float_scatter_reduce).
+    /// Int => [scatter reduce](burn_tensor::ops::IntTensorOps::int_scatter_reduce).
+    ScatterReduce(ScatterReduceOpIr),
+    /// Operation corresponding to:
+    ///
     /// Float => [select](burn_tensor::ops::FloatTensorOps::float_select).
     /// Int => [select](burn_tensor::ops::IntTensorOps::int_select).
     Select(SelectOpIr),
@@ -692,6 +738,52 @@ pub struct ScatterOpIr {
     pub out: TensorIr,
 }
 
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub enum ReduceIr {
+    Sum,
+    Prod,
+    Mean,
+    Max,
+    Min,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct ScatterReduceOpIr {
+    pub tensor: TensorIr,
+    pub dim: usize,
+    pub indices: TensorIr,
+    pub value: TensorIr,
+    pub reduce: ReduceIr,
+    pub include_self: bool,
+    pub out: TensorIr,
+}
+
+impl From<ReduceIr> for Reduce {
+    fn from(val: ReduceIr) -> Self {
+        match val {
+            ReduceIr::Sum => Self::Sum,
+            ReduceIr::Prod => Self::Prod,
+            ReduceIr::Mean => Self::Mean,
+            ReduceIr::Max => Self::Max,
+            ReduceIr::Min => Self::Min,
+        }
+    }
+}
+
+impl From<Reduce> for ReduceIr {
+    fn from(val: Reduce) -> Self {
+        match val {
+            Reduce::Sum => Self::Sum,
+            Reduce::Prod => Self::Prod,
+            Reduce::Mean => Self::Mean,
+            Reduce::Max => Self::Max,
+            Reduce::Min => Self::Min,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct SelectOpIr {
@@ -755,6 +847,16 @@ pub struct ClampOpIr<E> {
     pub out: TensorIr,
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct NanToNumOpIr {
+    pub input: TensorIr,
+    pub nan: f32,
+    pub posinf: f32,
+    pub neginf: f32,
+    pub out: TensorIr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct RepeatDimOpIr {
@@ -798,6 +900,24 @@ pub struct EmbeddingBackwardOpIr {
     pub out: TensorIr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct GroupedMatmulOpIr {
+    pub lhs: TensorIr,
+    pub rhs: TensorIr,
+    pub offsets: TensorIr,
+    pub out: TensorIr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct GroupedMatmulRhsBackwardOpIr {
+    pub lhs: TensorIr,
+    pub grad: TensorIr,
+    pub offsets: TensorIr,
+    pub out: TensorIr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct Conv1dOpIr {
@@ -1295,7 +1415,9 @@ pub struct MaxPool2dWithIndicesBackwardOpIr {
 #[allow(missing_docs)]
 pub enum InterpolateModeIr {
     Nearest,
+    Linear,
     Bilinear,
+    Trilinear,
     Bicubic,
 }
 
@@ -1303,6 +1425,7 @@ pub enum InterpolateModeIr {
 #[allow(missing_docs)]
 pub struct InterpolateOptionsIr {
     pub mode: InterpolateModeIr,
+    pub align_corners: bool,
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
@@ -1318,7 +1441,9 @@ impl From<InterpolateModeIr> for InterpolateMode {
     fn from(val: InterpolateModeIr) -> Self {
         match val {
             InterpolateModeIr::Nearest => Self::Nearest,
+            InterpolateModeIr::Linear => Self::Linear,
             InterpolateModeIr::Bilinear => Self::Bilinear,
+            InterpolateModeIr::Trilinear => Self::Trilinear,
             InterpolateModeIr::Bicubic => Self::Bicubic,
         }
     }
@@ -1328,6 +1453,7 @@ impl From<InterpolateOptionsIr> for InterpolateOptions {
     fn from(val: InterpolateOptionsIr) -> Self {
         Self {
             mode: val.mode.into(),
+            align_corners: val.align_corners,
         }
     }
 }
@@ -1336,7 +1462,9 @@ impl From<InterpolateMode> for InterpolateModeIr {
     fn from(val: InterpolateMode) -> Self {
         match val {
             InterpolateMode::Nearest => Self::Nearest,
+            InterpolateMode::Linear => Self::Linear,
             InterpolateMode::Bilinear => Self::Bilinear,
+            InterpolateMode::Trilinear => Self::Trilinear,
             InterpolateMode::Bicubic => Self::Bicubic,
         }
     }
@@ -1346,6 +1474,7 @@ impl From<InterpolateOptions> for InterpolateOptionsIr {
     fn from(val: InterpolateOptions) -> Self {
         Self {
             mode: val.mode.into(),
+            align_corners: val.align_corners,
         }
     }
 }
@@ -1459,6 +1588,9 @@ impl<E: Element> NumericOperationIr<E> {
             NumericOperationIr::Scatter(repr) => {
                 vec![&repr.tensor, &repr.indices, &repr.value, &repr.out]
             }
+            NumericOperationIr::ScatterReduce(repr) => {
+                vec![&repr.tensor, &repr.indices, &repr.value, &repr.out]
+            }
             NumericOperationIr::Select(repr) => {
                 vec![&repr.tensor, &repr.indices, &repr.out]
             }
@@ -1578,6 +1710,30 @@ impl FloatOperationIr {
             FloatOperationIr::Round(repr) => vec![&repr.input, &repr.out],
             FloatOperationIr::Floor(repr) => vec![&repr.input, &repr.out],
             FloatOperationIr::Ceil(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Trunc(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Asin(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Acos(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Atan(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Sinh(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Cosh(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Asinh(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Acosh(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Atanh(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Expm1(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::ErfInv(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Lgamma(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Digamma(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Atan2(repr) => {
+                vec![&repr.lhs, &repr.rhs, &repr.out]
+            }
+            FloatOperationIr::Hypot(repr) => {
+                vec![&repr.lhs, &repr.rhs, &repr.out]
+            }
+            FloatOperationIr::Fmod(repr) => {
+                vec![&repr.lhs, &repr.rhs, &repr.out]
+            }
+            FloatOperationIr::FmodScalar(repr) => vec![&repr.lhs, &repr.out],
+            FloatOperationIr::NanToNum(repr) => vec![&repr.input, &repr.out],
             FloatOperationIr::IntoInt(repr) => vec![&repr.input, &repr.out],
             FloatOperationIr::Quantize(repr) => {
                 if let Some(offset) = &repr.qparams.offset {
@@ -1757,6 +1913,12 @@ impl ModuleOperationIr {
             ModuleOperationIr::InterpolateBackward(repr) => {
                 vec![&repr.x, &repr.out, &repr.grad]
             }
+            ModuleOperationIr::GroupedMatmul(repr) => {
+                vec![&repr.lhs, &repr.rhs, &repr.offsets, &repr.out]
+            }
+            ModuleOperationIr::GroupedMatmulRhsBackward(repr) => {
+                vec![&repr.lhs, &repr.grad, &repr.offsets, &repr.out]
+            }
         }
     }
 }
@@ -1801,6 +1963,13 @@ impl<E> core::hash::Hash for MaskFillOpIr<E> {
     }
 }
 
+impl core::hash::Hash for NanToNumOpIr {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.input.hash(state);
+        self.out.hash(state);
+    }
+}
+
 impl<E> core::hash::Hash for ClampOpIr<E> {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.tensor.hash(state);
@@ -1827,6 +1996,7 @@ impl<E> core::hash::Hash for NumericOperationIr<E> {
             NumericOperationIr::Full(repr) => repr.0.hash(state),
             NumericOperationIr::Gather(repr) => repr.hash(state),
             NumericOperationIr::Scatter(repr) => repr.hash(state),
+            NumericOperationIr::ScatterReduce(repr) => repr.hash(state),
             NumericOperationIr::Select(repr) => repr.hash(state),
             NumericOperationIr::SelectAssign(repr) => repr.hash(state),
             NumericOperationIr::MaskWhere(repr) => repr.hash(state),
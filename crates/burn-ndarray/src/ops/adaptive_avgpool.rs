@@ -91,11 +91,15 @@ pub(crate) fn adaptive_avg_pool2d_backward<E: FloatNdArrayElement>(
     NdArrayTensor::new(output_grad.into_dyn().into_shared())
 }
 
-fn start_index(output_size_index: usize, output_size: usize, input_size: usize) -> usize {
+pub(super) fn start_index(
+    output_size_index: usize,
+    output_size: usize,
+    input_size: usize,
+) -> usize {
     ((output_size_index as f32 * input_size as f32) / output_size as f32).floor() as usize
 }
 
-fn end_index(output_size_index: usize, output_size: usize, input_size: usize) -> usize {
+pub(super) fn end_index(output_size_index: usize, output_size: usize, input_size: usize) -> usize {
     let index =
         (((output_size_index + 1) as f32 * input_size as f32) / output_size as f32).ceil() as usize;
 
@@ -0,0 +1,101 @@
+use crate::{
+    element::{FloatNdArrayElement, IntNdArrayElement},
+    ops::adaptive_avgpool::{end_index, start_index},
+    sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor,
+};
+use burn_common::{iter_range_par, run_par};
+use burn_tensor::{ElementConversion, TensorMetadata};
+use ndarray::Array4;
+
+pub(crate) fn adaptive_max_pool2d_with_indices<E: FloatNdArrayElement, I: IntNdArrayElement>(
+    x: NdArrayTensor<E>,
+    output_size: [usize; 2],
+) -> (NdArrayTensor<E>, NdArrayTensor<I>) {
+    let [batch_size, channels, input_height, input_width] = x.shape().dims();
+    let inf = (-f32::INFINITY).elem::<E>();
+
+    let x = x.array;
+    let shape_out = (batch_size, channels, output_size[0], output_size[1]);
+
+    let mut output = Array4::from_elem(shape_out, inf);
+    let mut indices = Array4::<I>::zeros(shape_out);
+
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+    let unsafe_shared_indices = UnsafeSharedRef::new(&mut indices);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+            let indices = unsafe_shared_indices.get();
+
+            for h in 0..output_size[0] {
+                for w in 0..output_size[1] {
+                    let ih_start = start_index(h, output_size[0], input_height);
+                    let ih_end = end_index(h, output_size[0], input_height);
+                    let iw_start = start_index(w, output_size[1], input_width);
+                    let iw_end = end_index(w, output_size[1], input_width);
+
+                    let mut max_val = inf;
+                    let mut index = ih_start * input_width + iw_start;
+
+                    for ih in ih_start..ih_end {
+                        for iw in iw_start..iw_end {
+                            let val = x[[b, c, ih, iw]];
+
+                            if val > max_val {
+                                max_val = val;
+                                index = ih * input_width + iw;
+                            }
+                        }
+                    }
+
+                    output[[b, c, h, w]] = max_val;
+                    indices[[b, c, h, w]] = (index as i64).elem();
+                }
+            }
+        })
+    });
+
+    let output = NdArrayTensor::new(output.into_dyn().into_shared());
+    let indices = NdArrayTensor::new(indices.into_dyn().into_shared());
+
+    (output, indices)
+}
+
+pub(crate) fn adaptive_max_pool2d_backward<E: FloatNdArrayElement, I: IntNdArrayElement>(
+    x: NdArrayTensor<E>,
+    output_grad: NdArrayTensor<E>,
+    indices: NdArrayTensor<I>,
+) -> NdArrayTensor<E> {
+    let [_batch_size, _channels, height, width] = output_grad.shape().dims();
+    let [batch_size, channels, height_x, width_x] = x.shape().dims();
+
+    let output_grad = output_grad.array;
+    let indices = indices.array;
+
+    let mut output = Array4::zeros((batch_size, channels, height_x, width_x));
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+
+            for h in 0..height {
+                for w in 0..width {
+                    let index = indices[[b, c, h, w]].elem::<i64>() as usize;
+
+                    output[[b, c, index / width_x, index % width_x]] += output_grad[[b, c, h, w]];
+                }
+            }
+        });
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
@@ -2,7 +2,7 @@ use crate::{element::FloatNdArrayElement, sharing::UnsafeSharedRef, tensor::NdAr
 use burn_common::{iter_range_par, run_par};
 
 use burn_tensor::{ElementConversion, TensorMetadata};
-use ndarray::Array4;
+use ndarray::{Array4, Array5};
 
 pub(crate) fn avg_pool2d<E: FloatNdArrayElement>(
     x: NdArrayTensor<E>,
@@ -131,3 +131,150 @@ pub(crate) fn avg_pool2d_backward<E: FloatNdArrayElement>(
 
     NdArrayTensor::new(output_grad.into_dyn().into_shared())
 }
+
+pub(crate) fn avg_pool3d<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> NdArrayTensor<E> {
+    let [kernel_depth, kernel_height, kernel_width] = kernel_size;
+    let [padding_depth, padding_height, padding_width] = padding;
+    let [stride_depth, stride_height, stride_width] = stride;
+    let [batch_size, channels, x_depth, x_height, x_width] = x.shape().dims();
+
+    let out_depth = ((x_depth + 2 * padding_depth - kernel_depth) / stride_depth) + 1;
+    let out_height = ((x_height + 2 * padding_height - kernel_height) / stride_height) + 1;
+    let out_width = ((x_width + 2 * padding_width - kernel_width) / stride_width) + 1;
+
+    let x = x.array;
+
+    let mut output = Array5::from_elem(
+        (batch_size, channels, out_depth, out_height, out_width),
+        0.elem(),
+    );
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+
+            for od in 0..out_depth {
+                for oh in 0..out_height {
+                    for ow in 0..out_width {
+                        let [id_start, id_end] =
+                            window(od, stride_depth, kernel_depth, padding_depth, x_depth);
+                        let [ih_start, ih_end] =
+                            window(oh, stride_height, kernel_height, padding_height, x_height);
+                        let [iw_start, iw_end] =
+                            window(ow, stride_width, kernel_width, padding_width, x_width);
+
+                        let mut sum_val: E = 0.elem();
+
+                        for id in id_start..id_end {
+                            for ih in ih_start..ih_end {
+                                for iw in iw_start..iw_end {
+                                    sum_val += x[[b, c, id, ih, iw]];
+                                }
+                            }
+                        }
+
+                        let count = match count_include_pad {
+                            true => kernel_depth * kernel_height * kernel_width,
+                            false => {
+                                (id_end - id_start) * (ih_end - ih_start) * (iw_end - iw_start)
+                            }
+                        };
+
+                        output[[b, c, od, oh, ow]] = sum_val / (count as i32).elem();
+                    }
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+pub(crate) fn avg_pool3d_backward<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E>,
+    grad: NdArrayTensor<E>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> NdArrayTensor<E> {
+    let [kernel_depth, kernel_height, kernel_width] = kernel_size;
+    let [stride_depth, stride_height, stride_width] = stride;
+    let [padding_depth, padding_height, padding_width] = padding;
+    let [batch_size, channels, x_depth, x_height, x_width] = x.shape().dims();
+    let [_batch_size, _channels, out_depth, out_height, out_width] = grad.shape().dims();
+
+    let grad = grad.array;
+
+    let mut output_grad =
+        Array5::from_elem((batch_size, channels, x_depth, x_height, x_width), 0.elem());
+    let unsafe_shared_grad = UnsafeSharedRef::new(&mut output_grad);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output_grad = unsafe_shared_grad.get();
+
+            for od in 0..out_depth {
+                for oh in 0..out_height {
+                    for ow in 0..out_width {
+                        let [id_start, id_end] =
+                            window(od, stride_depth, kernel_depth, padding_depth, x_depth);
+                        let [ih_start, ih_end] =
+                            window(oh, stride_height, kernel_height, padding_height, x_height);
+                        let [iw_start, iw_end] =
+                            window(ow, stride_width, kernel_width, padding_width, x_width);
+
+                        let count = match count_include_pad {
+                            true => kernel_depth * kernel_height * kernel_width,
+                            false => {
+                                (id_end - id_start) * (ih_end - ih_start) * (iw_end - iw_start)
+                            }
+                        };
+                        let grad = grad[[b, c, od, oh, ow]] / (count as i32).elem();
+
+                        for id in id_start..id_end {
+                            for ih in ih_start..ih_end {
+                                for iw in iw_start..iw_end {
+                                    output_grad[[b, c, id, ih, iw]] += grad;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output_grad.into_dyn().into_shared())
+}
+
+/// Returns the range of the input covered by the window of an output position, excluding the
+/// padding.
+fn window(
+    position: usize,
+    stride: usize,
+    kernel_size: usize,
+    padding: usize,
+    size: usize,
+) -> [usize; 2] {
+    let start = position * stride;
+    let end = start + kernel_size;
+
+    let start = usize::max(start, padding) - padding;
+    let end = usize::min(end, size + padding).saturating_sub(padding);
+
+    [start, usize::max(start, end)]
+}
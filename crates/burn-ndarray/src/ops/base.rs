@@ -1,5 +1,7 @@
 use alloc::{vec, vec::Vec};
 use burn_tensor::ElementConversion;
+use burn_tensor::Reduce;
+use burn_tensor::Slice;
 use burn_tensor::TensorData;
 use burn_tensor::TensorMetadata;
 use core::fmt::Debug;
@@ -61,6 +63,26 @@ where
         NdArrayTensor { array }
     }
 
+    pub fn slice_with_steps(tensor: NdArrayTensor<E>, slices: &[Slice]) -> NdArrayTensor<E> {
+        let slices = Self::to_slice_args_with_steps(slices, tensor.shape().num_dims());
+        let array = tensor.array.slice_move(slices.as_slice()).into_shared();
+
+        NdArrayTensor { array }
+    }
+
+    pub fn slice_assign_with_steps(
+        tensor: NdArrayTensor<E>,
+        slices: &[Slice],
+        value: NdArrayTensor<E>,
+    ) -> NdArrayTensor<E> {
+        let slices = Self::to_slice_args_with_steps(slices, tensor.shape().num_dims());
+        let mut array = tensor.array.into_owned();
+        array.slice_mut(slices.as_slice()).assign(&value.array);
+        let array = array.into_shared();
+
+        NdArrayTensor { array }
+    }
+
     pub fn reshape(tensor: NdArrayTensor<E>, shape: Shape) -> NdArrayTensor<E> {
         reshape!(
             ty E,
@@ -108,6 +130,23 @@ where
         slices
     }
 
+    fn to_slice_args_with_steps(slices: &[Slice], ndims: usize) -> Vec<SliceInfoElem> {
+        (0..ndims)
+            .map(|i| match slices.get(i) {
+                Some(slice) => SliceInfoElem::Slice {
+                    start: slice.start,
+                    end: slice.end,
+                    step: slice.step,
+                },
+                None => SliceInfoElem::Slice {
+                    start: 0,
+                    end: None,
+                    step: 1,
+                },
+            })
+            .collect()
+    }
+
     pub fn swap_dims(tensor: NdArrayTensor<E>, dim1: usize, dim2: usize) -> NdArrayTensor<E> {
         let mut array = tensor.array;
         array.swap_axes(dim1, dim2);
@@ -376,6 +415,88 @@ where
         output
     }
 
+    pub fn scatter_reduce<I: NdArrayElement>(
+        dim: usize,
+        mut tensor: NdArrayTensor<E>,
+        mut indices: NdArrayTensor<I>,
+        mut value: NdArrayTensor<E>,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> NdArrayTensor<E> {
+        let ndims = tensor.shape().num_dims();
+        if dim != ndims - 1 {
+            tensor.array.swap_axes(ndims - 1, dim);
+            indices.array.swap_axes(ndims - 1, dim);
+            value.array.swap_axes(ndims - 1, dim);
+        }
+
+        let (shape_tensor, shape_indices, shape_value) =
+            (tensor.shape(), indices.shape(), value.shape());
+        let (size_tensor, size_index, size_value) = (
+            shape_tensor.dims[ndims - 1],
+            shape_indices.dims[ndims - 1],
+            shape_value.dims[ndims - 1],
+        );
+        let batch_size = Self::gather_batch_size(&shape_tensor, &shape_indices);
+
+        if shape_value != shape_indices {
+            panic!(
+                "Invalid dimension: the shape of the index tensor should be the same as the value \
+                 tensor: Index {:?} value {:?}",
+                shape_indices.dims, shape_value.dims
+            );
+        }
+
+        let indices = NdArrayOps::reshape(indices, Shape::new([batch_size, size_index])).array;
+        let value = NdArrayOps::reshape(value, Shape::new([batch_size, size_value])).array;
+        let mut tensor = NdArrayOps::reshape(tensor, Shape::new([batch_size, size_tensor])).array;
+
+        // Number of elements reduced at each position, the elements of the tensor included.
+        let mut counts =
+            Array2::from_elem((batch_size, size_tensor), usize::from(include_self)).into_dyn();
+
+        for b in 0..batch_size {
+            let indices = indices.slice(s!(b, ..));
+
+            for (i, index) in indices.iter().enumerate() {
+                let index = index.elem::<i64>() as usize;
+                let value = value[[b, i]];
+                let current = tensor[[b, index]];
+
+                tensor[[b, index]] = match counts[[b, index]] {
+                    0 => value,
+                    _ => match reduce {
+                        Reduce::Sum | Reduce::Mean => current + value,
+                        Reduce::Prod => current * value,
+                        Reduce::Max if value > current => value,
+                        Reduce::Min if value < current => value,
+                        Reduce::Max | Reduce::Min => current,
+                    },
+                };
+                counts[[b, index]] += 1;
+            }
+        }
+
+        if reduce == Reduce::Mean {
+            Zip::from(&mut tensor)
+                .and(&counts)
+                .for_each(|output, &count| {
+                    if count > 1 {
+                        *output = *output / E::from_usize(count).unwrap();
+                    }
+                });
+        }
+
+        let mut output = NdArrayOps::reshape(
+            NdArrayTensor::<E>::new(tensor.into_shared().into_dyn()),
+            shape_tensor,
+        );
+        if dim != ndims - 1 {
+            output.array.swap_axes(ndims - 1, dim);
+        }
+        output
+    }
+
     pub fn mask_where(
         tensor: NdArrayTensor<E>,
         mask: NdArrayTensor<bool>,
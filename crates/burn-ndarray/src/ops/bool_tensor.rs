@@ -12,7 +12,7 @@ use crate::{new_tensor_float, NdArrayDevice};
 use crate::{tensor::NdArrayTensor, NdArray};
 
 // Workspace crates
-use burn_tensor::{backend::Backend, Shape, TensorData};
+use burn_tensor::{backend::Backend, Shape, Slice, TensorData};
 
 use super::NdArrayOps;
 
@@ -68,6 +68,18 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> BoolTensorOp
         NdArrayOps::slice_assign(tensor, ranges, value)
     }
 
+    fn bool_slice_with_steps(tensor: NdArrayTensor<bool>, slices: &[Slice]) -> NdArrayTensor<bool> {
+        NdArrayOps::slice_with_steps(tensor, slices)
+    }
+
+    fn bool_slice_assign_with_steps(
+        tensor: NdArrayTensor<bool>,
+        slices: &[Slice],
+        value: NdArrayTensor<bool>,
+    ) -> NdArrayTensor<bool> {
+        NdArrayOps::slice_assign_with_steps(tensor, slices, value)
+    }
+
     fn bool_cat(tensors: Vec<NdArrayTensor<bool>>, dim: usize) -> NdArrayTensor<bool> {
         NdArrayOps::cat(tensors, dim)
     }
@@ -0,0 +1,116 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_tensor::{ElementConversion, Shape, TensorMetadata};
+use ndarray::{Array1, Array2, Axis};
+
+use super::NdArrayOps;
+use crate::{element::NdArrayElement, tensor::NdArrayTensor};
+
+pub(crate) fn searchsorted<E: NdArrayElement, I: NdArrayElement>(
+    sorted: NdArrayTensor<E>,
+    values: NdArrayTensor<E>,
+    right: bool,
+) -> NdArrayTensor<I> {
+    let shape = values.shape();
+    let size_sorted = sorted.shape().dims[shape.num_dims() - 1];
+    let size_values = shape.dims[shape.num_dims() - 1];
+    let batch_size = shape.num_elements() / usize::max(size_values, 1);
+
+    let sorted = NdArrayOps::reshape(sorted, Shape::new([batch_size, size_sorted])).array;
+    let values = NdArrayOps::reshape(values, Shape::new([batch_size, size_values])).array;
+    let mut output = Array2::zeros((batch_size, size_values));
+
+    for (b, row) in sorted.axis_iter(Axis(0)).enumerate() {
+        let row = row.iter().copied().collect::<Vec<_>>();
+
+        for (i, value) in values.index_axis(Axis(0), b).iter().enumerate() {
+            // Number of elements before the insertion position, found with a binary search.
+            let index = match right {
+                true => row.partition_point(|x| x <= value),
+                false => row.partition_point(|x| x < value),
+            };
+            output[[b, i]] = (index as i64).elem();
+        }
+    }
+
+    NdArrayOps::reshape(NdArrayTensor::new(output.into_shared().into_dyn()), shape)
+}
+
+pub(crate) fn bincount<I: NdArrayElement>(
+    tensor: NdArrayTensor<I>,
+    min_length: usize,
+) -> NdArrayTensor<I> {
+    let counts = bin_values(&tensor, min_length, |_| 1i64)
+        .into_iter()
+        .map(|count| count.elem())
+        .collect::<Vec<I>>();
+
+    NdArrayTensor::new(Array1::from_vec(counts).into_shared().into_dyn())
+}
+
+pub(crate) fn bincount_weighted<I: NdArrayElement, E: NdArrayElement>(
+    tensor: NdArrayTensor<I>,
+    weights: NdArrayTensor<E>,
+    min_length: usize,
+) -> NdArrayTensor<E> {
+    let weights = weights.array.iter().copied().collect::<Vec<_>>();
+    let sums = bin_values(&tensor, min_length, |i| weights[i]);
+
+    NdArrayTensor::new(Array1::from_vec(sums).into_shared().into_dyn())
+}
+
+pub(crate) fn histc<E: NdArrayElement>(
+    tensor: NdArrayTensor<E>,
+    bins: usize,
+    min: f64,
+    max: f64,
+) -> NdArrayTensor<E> {
+    let mut counts = vec![0.elem::<E>(); bins];
+    let scale = bins as f64 / (max - min);
+
+    for value in tensor.array.iter() {
+        let value = value.elem::<f64>();
+
+        if value < min || value > max {
+            continue;
+        }
+
+        // The elements equal to the upper bound are part of the last bin.
+        let index = usize::min(((value - min) * scale) as usize, bins - 1);
+        counts[index] += 1.elem();
+    }
+
+    NdArrayTensor::new(Array1::from_vec(counts).into_shared().into_dyn())
+}
+
+/// Sums the value of each element of the tensor in the bin of its index.
+fn bin_values<I: NdArrayElement, T: NdArrayElement>(
+    tensor: &NdArrayTensor<I>,
+    min_length: usize,
+    value: impl Fn(usize) -> T,
+) -> Vec<T> {
+    let indices = tensor
+        .array
+        .iter()
+        .map(|index| {
+            let index = index.elem::<i64>();
+            assert!(
+                index >= 0,
+                "Bincount only supports non-negative integers, got {index}."
+            );
+            index as usize
+        })
+        .collect::<Vec<_>>();
+    let length = indices
+        .iter()
+        .map(|index| index + 1)
+        .fold(min_length, usize::max);
+
+    let mut bins = vec![0.elem::<T>(); length];
+
+    for (i, index) in indices.into_iter().enumerate() {
+        bins[index] += value(i);
+    }
+
+    bins
+}
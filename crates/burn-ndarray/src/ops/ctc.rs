@@ -0,0 +1,249 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_common::{iter_range_par, run_par};
+use burn_tensor::{ElementConversion, TensorMetadata};
+use ndarray::{Array1, Array3, ArrayView2, Axis, Ix3};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+use crate::{element::NdArrayElement, FloatNdArrayElement, NdArrayTensor, UnsafeSharedRef};
+
+/// The lengths and the extended target of a sample, which interleaves its target with blanks:
+/// `[blank, y1, blank, ..., blank]`.
+struct Sample {
+    labels: Vec<usize>,
+    input_length: usize,
+    blank: usize,
+}
+
+impl Sample {
+    fn new<I: NdArrayElement>(
+        targets: &NdArrayTensor<I>,
+        input_lengths: &NdArrayTensor<I>,
+        target_lengths: &NdArrayTensor<I>,
+        index: usize,
+        seq_length: usize,
+        blank: usize,
+    ) -> Self {
+        let target_length = target_lengths.array[[index]].elem::<i64>() as usize;
+        let mut labels = vec![blank; 2 * target_length + 1];
+
+        for i in 0..target_length {
+            labels[2 * i + 1] = targets.array[[index, i]].elem::<i64>() as usize;
+        }
+
+        Self {
+            labels,
+            input_length: usize::min(
+                input_lengths.array[[index]].elem::<i64>() as usize,
+                seq_length,
+            ),
+            blank,
+        }
+    }
+
+    /// Whether the state can be reached from the state two positions before, which requires a
+    /// class different from the blank and from the class of that state.
+    fn can_skip(&self, state: usize) -> bool {
+        state >= 2
+            && self.labels[state] != self.blank
+            && self.labels[state] != self.labels[state - 2]
+    }
+
+    /// Computes the log-probabilities of the prefixes of the alignments ending in each state at
+    /// each step, `[input_length, num_states]`.
+    fn forward_variables(&self, log_probs: &ArrayView2<'_, f64>) -> Vec<f64> {
+        let num_states = self.labels.len();
+        let mut alphas = vec![f64::NEG_INFINITY; self.input_length * num_states];
+
+        if self.input_length == 0 {
+            return alphas;
+        }
+
+        for state in 0..num_states.min(2) {
+            alphas[state] = log_probs[[0, self.labels[state]]];
+        }
+
+        for t in 1..self.input_length {
+            for state in 0..num_states {
+                let previous = &alphas[(t - 1) * num_states..t * num_states];
+                let mut sum = previous[state];
+
+                if state >= 1 {
+                    sum = log_add_exp(sum, previous[state - 1]);
+                }
+                if self.can_skip(state) {
+                    sum = log_add_exp(sum, previous[state - 2]);
+                }
+
+                alphas[t * num_states + state] = sum + log_probs[[t, self.labels[state]]];
+            }
+        }
+
+        alphas
+    }
+
+    /// Computes the log-probabilities of the suffixes of the alignments following each state at
+    /// each step, `[input_length, num_states]`, excluding the emission of the state itself.
+    fn backward_variables(&self, log_probs: &ArrayView2<'_, f64>) -> Vec<f64> {
+        let num_states = self.labels.len();
+        let mut betas = vec![f64::NEG_INFINITY; self.input_length * num_states];
+
+        if self.input_length == 0 {
+            return betas;
+        }
+
+        let last = (self.input_length - 1) * num_states;
+        for state in num_states.saturating_sub(2)..num_states {
+            betas[last + state] = 0.0;
+        }
+
+        for t in (0..self.input_length - 1).rev() {
+            for state in 0..num_states {
+                let next = |state: usize| {
+                    betas[(t + 1) * num_states + state] + log_probs[[t + 1, self.labels[state]]]
+                };
+                let mut sum = next(state);
+
+                if state + 1 < num_states {
+                    sum = log_add_exp(sum, next(state + 1));
+                }
+                if state + 2 < num_states && self.can_skip(state + 2) {
+                    sum = log_add_exp(sum, next(state + 2));
+                }
+
+                betas[t * num_states + state] = sum;
+            }
+        }
+
+        betas
+    }
+
+    fn log_likelihood(&self, alphas: &[f64]) -> f64 {
+        let num_states = self.labels.len();
+
+        if self.input_length == 0 {
+            return f64::NEG_INFINITY;
+        }
+
+        let last = &alphas[(self.input_length - 1) * num_states..];
+        match num_states {
+            1 => last[0],
+            _ => log_add_exp(last[num_states - 1], last[num_states - 2]),
+        }
+    }
+}
+
+pub(crate) fn ctc_loss<E: FloatNdArrayElement, I: NdArrayElement>(
+    log_probs: NdArrayTensor<E>,
+    targets: NdArrayTensor<I>,
+    input_lengths: NdArrayTensor<I>,
+    target_lengths: NdArrayTensor<I>,
+    blank: usize,
+) -> NdArrayTensor<E> {
+    let [batch_size, seq_length, num_classes] = log_probs.shape().dims();
+    assert!(
+        blank < num_classes,
+        "The blank index {blank} must be lower than the number of classes {num_classes}."
+    );
+
+    let log_probs = log_probs
+        .array
+        .into_dimensionality::<Ix3>()
+        .unwrap()
+        .mapv(|value| value.elem::<f64>());
+    let mut output = Array1::from_elem(batch_size, 0.elem::<E>());
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size).for_each(|b| unsafe {
+            let sample = Sample::new(
+                &targets,
+                &input_lengths,
+                &target_lengths,
+                b,
+                seq_length,
+                blank,
+            );
+            let log_probs = log_probs.index_axis(Axis(0), b);
+            let alphas = sample.forward_variables(&log_probs);
+
+            let output = unsafe_shared_out.get();
+            output[b] = (-sample.log_likelihood(&alphas)).elem();
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+pub(crate) fn ctc_loss_backward<E: FloatNdArrayElement, I: NdArrayElement>(
+    log_probs: NdArrayTensor<E>,
+    targets: NdArrayTensor<I>,
+    input_lengths: NdArrayTensor<I>,
+    target_lengths: NdArrayTensor<I>,
+    loss: NdArrayTensor<E>,
+    grad: NdArrayTensor<E>,
+    blank: usize,
+) -> NdArrayTensor<E> {
+    let [batch_size, seq_length, num_classes] = log_probs.shape().dims();
+
+    let log_probs = log_probs
+        .array
+        .into_dimensionality::<Ix3>()
+        .unwrap()
+        .mapv(|value| value.elem::<f64>());
+    let mut output = Array3::from_elem((batch_size, seq_length, num_classes), 0.elem::<E>());
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size).for_each(|b| unsafe {
+            let loss = loss.array[[b]].elem::<f64>();
+            // The impossible targets have no alignment, so their gradients are zero.
+            if !loss.is_finite() {
+                return;
+            }
+
+            let sample = Sample::new(
+                &targets,
+                &input_lengths,
+                &target_lengths,
+                b,
+                seq_length,
+                blank,
+            );
+            let log_probs = log_probs.index_axis(Axis(0), b);
+            let alphas = sample.forward_variables(&log_probs);
+            let betas = sample.backward_variables(&log_probs);
+            let grad = grad.array[[b]].elem::<f64>();
+            let num_states = sample.labels.len();
+
+            let output = unsafe_shared_out.get();
+            for t in 0..sample.input_length {
+                // The posterior probability of each state, accumulated on its class, is the
+                // opposite of the gradient with respect to the log-probability of the class.
+                let mut posteriors = vec![0.0; num_classes];
+                for (state, &label) in sample.labels.iter().enumerate() {
+                    let index = t * num_states + state;
+                    posteriors[label] += (alphas[index] + betas[index] + loss).exp();
+                }
+
+                for (class, posterior) in posteriors.into_iter().enumerate() {
+                    output[[b, t, class]] = (-posterior * grad).elem();
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+/// Computes `log(exp(a) + exp(b))`, which is negative infinity when both terms are.
+fn log_add_exp(a: f64, b: f64) -> f64 {
+    let max = a.max(b);
+
+    if max == f64::NEG_INFINITY {
+        return max;
+    }
+
+    max + ((a - max).exp() + (b - max).exp()).ln()
+}
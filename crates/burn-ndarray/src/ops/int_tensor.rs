@@ -4,6 +4,7 @@ use burn_common::rand::get_seeded_rng;
 use burn_tensor::ops::FloatTensor;
 use burn_tensor::ops::IntTensorOps;
 use burn_tensor::Distribution;
+use burn_tensor::Reduce;
 
 use burn_tensor::ElementConversion;
 use core::ops::Range;
@@ -20,9 +21,9 @@ use crate::{tensor::NdArrayTensor, NdArray};
 use crate::{NdArrayDevice, SEED};
 
 // Workspace crates
-use burn_tensor::{backend::Backend, DType, Shape, TensorData};
+use burn_tensor::{backend::Backend, DType, Shape, Slice, TensorData};
 
-use super::{NdArrayMathOps, NdArrayOps};
+use super::{counting, NdArrayMathOps, NdArrayOps};
 
 impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> IntTensorOps<Self>
     for NdArray<E, I, Q>
@@ -82,6 +83,18 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> IntTensorOps
         NdArrayOps::slice_assign(tensor, ranges, value)
     }
 
+    fn int_slice_with_steps(tensor: NdArrayTensor<I>, slices: &[Slice]) -> NdArrayTensor<I> {
+        NdArrayOps::slice_with_steps(tensor, slices)
+    }
+
+    fn int_slice_assign_with_steps(
+        tensor: NdArrayTensor<I>,
+        slices: &[Slice],
+        value: NdArrayTensor<I>,
+    ) -> NdArrayTensor<I> {
+        NdArrayOps::slice_assign_with_steps(tensor, slices, value)
+    }
+
     fn int_cat(tensors: Vec<NdArrayTensor<I>>, dim: usize) -> NdArrayTensor<I> {
         NdArrayOps::cat(tensors, dim)
     }
@@ -240,6 +253,39 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> IntTensorOps
         NdArrayMathOps::scatter(dim, tensor, indices, value)
     }
 
+    fn int_scatter_reduce(
+        dim: usize,
+        tensor: NdArrayTensor<I>,
+        indices: NdArrayTensor<I>,
+        value: NdArrayTensor<I>,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> NdArrayTensor<I> {
+        NdArrayMathOps::scatter_reduce(dim, tensor, indices, value, reduce, include_self)
+    }
+
+    fn int_searchsorted(
+        sorted: NdArrayTensor<I>,
+        values: NdArrayTensor<I>,
+        right: bool,
+    ) -> NdArrayTensor<I> {
+        counting::searchsorted(sorted, values, right)
+    }
+
+    fn int_bincount(tensor: NdArrayTensor<I>, min_length: usize) -> NdArrayTensor<I> {
+        counting::bincount(tensor, min_length)
+    }
+
+    fn int_bincount_weighted(
+        tensor: NdArrayTensor<I>,
+        weights: FloatTensor<Self>,
+        min_length: usize,
+    ) -> FloatTensor<Self> {
+        execute_with_float_dtype!(weights, |weights| counting::bincount_weighted(
+            tensor, weights, min_length
+        ))
+    }
+
     fn int_select(
         tensor: NdArrayTensor<I>,
         dim: usize,
@@ -70,6 +70,86 @@ where
     NdArrayOps::reshape(out, out_shape)
 }
 
+pub(crate) fn complex_matmul<E>(
+    lhs_real: NdArrayTensor<E>,
+    lhs_imag: NdArrayTensor<E>,
+    rhs_real: NdArrayTensor<E>,
+    rhs_imag: NdArrayTensor<E>,
+) -> (NdArrayTensor<E>, NdArrayTensor<E>)
+where
+    E: FloatNdArrayElement,
+{
+    let shape_lhs = lhs_real.shape();
+    let shape_rhs = rhs_real.shape();
+    let ndims = shape_lhs.num_dims();
+    let m = shape_lhs.dims[ndims - 2]; // # of left rows
+    let k = shape_rhs.dims[ndims - 2]; // # of left cols and right rows
+    let n = shape_rhs.dims[ndims - 1]; // # of right cols
+
+    let (out_shape, strides_lhs, strides_rhs, strides_out) = output_shape(&shape_lhs, &shape_rhs);
+    let l_mat_size = m * k; // size of matrix component of left array
+    let r_mat_size = k * n; // size of matrix component of right array
+    let out_mat_size = m * n; // size of matrix component of output array
+
+    let num_l_batches = shape_lhs.num_elements() / l_mat_size;
+    let num_r_batches = shape_rhs.num_elements() / r_mat_size;
+    let num_out_batches = out_shape.num_elements() / out_mat_size;
+
+    let one: E = 1.0.elem();
+    let zero: E = 0.0.elem();
+
+    let (out_real, out_imag) = run_par!(|| {
+        let mut out_real = ndarray::Array3::<E>::zeros((num_out_batches, m, n));
+        let mut out_imag = ndarray::Array3::<E>::zeros((num_out_batches, m, n));
+        let unsafe_shared_out_real = UnsafeSharedRef::new(&mut out_real);
+        let unsafe_shared_out_imag = UnsafeSharedRef::new(&mut out_imag);
+
+        let reshape_lhs = |tensor| NdArrayOps::reshape(tensor, Shape::new([num_l_batches, m, k]));
+        let reshape_rhs = |tensor| NdArrayOps::reshape(tensor, Shape::new([num_r_batches, k, n]));
+        let lhs_real = reshape_lhs(lhs_real).array;
+        let lhs_imag = reshape_lhs(lhs_imag).array;
+        let rhs_real = reshape_rhs(rhs_real).array;
+        let rhs_imag = reshape_rhs(rhs_imag).array;
+
+        iter_range_par!(0, num_out_batches).for_each(|out_batch| {
+            let out_index = strides_out.unflatten(out_batch);
+            let l_batch = strides_lhs.flatten(&out_index);
+            let r_batch = strides_rhs.flatten(&out_index);
+
+            let lhs_real = lhs_real.slice(s!(l_batch, .., ..));
+            let lhs_imag = lhs_imag.slice(s!(l_batch, .., ..));
+            let rhs_real = rhs_real.slice(s!(r_batch, .., ..));
+            let rhs_imag = rhs_imag.slice(s!(r_batch, .., ..));
+
+            // The products of the parts are accumulated in place into the output parts:
+            // (a + ib)(c + id) = (ac - bd) + i(ad + bc).
+            unsafe {
+                let mut out_real = unsafe_shared_out_real
+                    .get()
+                    .slice_mut(s!(out_batch, .., ..));
+                ndarray::linalg::general_mat_mul(one, &lhs_real, &rhs_real, zero, &mut out_real);
+                ndarray::linalg::general_mat_mul(-one, &lhs_imag, &rhs_imag, one, &mut out_real);
+
+                let mut out_imag = unsafe_shared_out_imag
+                    .get()
+                    .slice_mut(s!(out_batch, .., ..));
+                ndarray::linalg::general_mat_mul(one, &lhs_real, &rhs_imag, zero, &mut out_imag);
+                ndarray::linalg::general_mat_mul(one, &lhs_imag, &rhs_real, one, &mut out_imag);
+            }
+        });
+
+        (
+            NdArrayTensor::new(out_real.into_shared().into_dyn()),
+            NdArrayTensor::new(out_imag.into_shared().into_dyn()),
+        )
+    });
+
+    (
+        NdArrayOps::reshape(out_real, out_shape.clone()),
+        NdArrayOps::reshape(out_imag, out_shape),
+    )
+}
+
 #[derive(Debug, PartialEq)]
 struct Strides {
     strides: Vec<usize>,
@@ -5,9 +5,11 @@ use crate::{
     tensor::NdArrayTensor,
 };
 
+use alloc::vec;
+use alloc::vec::Vec;
 use burn_common::{iter_range_par, run_par};
 use burn_tensor::{ElementConversion, TensorMetadata};
-use ndarray::Array4;
+use ndarray::{ArcArray, Array4, Array5, IxDyn};
 
 pub(crate) fn max_pool2d<E: FloatNdArrayElement>(
     x: NdArrayTensor<E>,
@@ -124,7 +126,7 @@ pub(crate) fn max_pool2d_with_indices<E: FloatNdArrayElement, I: IntNdArrayEleme
                                 let ih = ih as i64 - padding_height as i64;
                                 let iw = iw as i64 - padding_width as i64;
 
-                                index = ih * x_height as i64 + iw;
+                                index = ih * x_width as i64 + iw;
                             }
                         }
                     }
@@ -184,3 +186,244 @@ pub(crate) fn max_pool2d_backward<E: FloatNdArrayElement, I: IntNdArrayElement>(
 
     NdArrayTensor::new(output.into_dyn().into_shared())
 }
+
+pub(crate) fn max_pool3d<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> NdArrayTensor<E> {
+    let (output, _indices) =
+        max_pool3d_with_indices::<E, i64>(x, kernel_size, stride, padding, dilation);
+
+    output
+}
+
+pub(crate) fn max_pool3d_with_indices<E: FloatNdArrayElement, I: IntNdArrayElement>(
+    x: NdArrayTensor<E>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> (NdArrayTensor<E>, NdArrayTensor<I>) {
+    let [kernel_depth, kernel_height, kernel_width] = kernel_size;
+    let [padding_depth, padding_height, padding_width] = padding;
+    let [stride_depth, stride_height, stride_width] = stride;
+    let [dilation_depth, dilation_height, dilation_width] = dilation;
+    let [batch_size, channels, x_depth, x_height, x_width] = x.shape().dims();
+    let inf = (-f32::INFINITY).elem::<E>();
+
+    let out_depth = ((x_depth + 2 * padding_depth - dilation_depth * (kernel_depth - 1) - 1)
+        / stride_depth)
+        + 1;
+    let out_height = ((x_height + 2 * padding_height - dilation_height * (kernel_height - 1) - 1)
+        / stride_height)
+        + 1;
+    let out_width = ((x_width + 2 * padding_width - dilation_width * (kernel_width - 1) - 1)
+        / stride_width)
+        + 1;
+
+    let x = x.array;
+    let shape_out = (batch_size, channels, out_depth, out_height, out_width);
+
+    let mut output = Array5::from_elem(shape_out, inf);
+    let mut indices = Array5::<I>::zeros(shape_out);
+
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+    let unsafe_shared_indices = UnsafeSharedRef::new(&mut indices);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+            let indices = unsafe_shared_indices.get();
+
+            for od in 0..out_depth {
+                for oh in 0..out_height {
+                    for ow in 0..out_width {
+                        let mut max_val = inf;
+                        let mut index = 0;
+
+                        for kd in 0..kernel_depth {
+                            let id = od * stride_depth + kd * dilation_depth;
+                            if id < padding_depth || id >= x_depth + padding_depth {
+                                continue;
+                            }
+                            let id = id - padding_depth;
+
+                            for kh in 0..kernel_height {
+                                let ih = oh * stride_height + kh * dilation_height;
+                                if ih < padding_height || ih >= x_height + padding_height {
+                                    continue;
+                                }
+                                let ih = ih - padding_height;
+
+                                for kw in 0..kernel_width {
+                                    let iw = ow * stride_width + kw * dilation_width;
+                                    if iw < padding_width || iw >= x_width + padding_width {
+                                        continue;
+                                    }
+                                    let iw = iw - padding_width;
+
+                                    let val = x[[b, c, id, ih, iw]];
+
+                                    if val > max_val {
+                                        max_val = val;
+                                        index = (id * x_height + ih) * x_width + iw;
+                                    }
+                                }
+                            }
+                        }
+
+                        output[[b, c, od, oh, ow]] = max_val;
+                        indices[[b, c, od, oh, ow]] = (index as i64).elem();
+                    }
+                }
+            }
+        })
+    });
+
+    let output = NdArrayTensor::new(output.into_dyn().into_shared());
+    let indices = NdArrayTensor::new(indices.into_dyn().into_shared());
+
+    (output, indices)
+}
+
+pub(crate) fn max_pool3d_backward<E: FloatNdArrayElement, I: IntNdArrayElement>(
+    x: NdArrayTensor<E>,
+    output_grad: NdArrayTensor<E>,
+    indices: NdArrayTensor<I>,
+) -> NdArrayTensor<E> {
+    let [_batch_size, _channels, depth, height, width] = output_grad.shape().dims();
+    let [batch_size, channels, depth_x, height_x, width_x] = x.shape().dims();
+
+    let output_grad = output_grad.array;
+    let indices = indices.array;
+
+    let mut output = Array5::zeros((batch_size, channels, depth_x, height_x, width_x));
+
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+
+            for d in 0..depth {
+                for h in 0..height {
+                    for w in 0..width {
+                        let index = indices[[b, c, d, h, w]].elem::<i64>() as usize;
+                        let grad = output_grad[[b, c, d, h, w]];
+
+                        let index_d = index / (height_x * width_x);
+                        let index_h = index / width_x % height_x;
+                        let index_w = index % width_x;
+
+                        output[[b, c, index_d, index_h, index_w]] += grad;
+                    }
+                }
+            }
+        });
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+pub(crate) fn max_unpool2d<E: FloatNdArrayElement, I: IntNdArrayElement>(
+    x: NdArrayTensor<E>,
+    indices: NdArrayTensor<I>,
+    output_size: [usize; 2],
+) -> NdArrayTensor<E> {
+    let [batch_size, channels, height, width] = x.shape().dims();
+    let [height_out, width_out] = output_size;
+
+    let x = x.array;
+    let indices = indices.array;
+
+    let mut output = Array4::zeros((batch_size, channels, height_out, width_out));
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+            let counts = unpool_counts(&indices, b, c, [height, width], output_size);
+
+            for h in 0..height {
+                for w in 0..width {
+                    let index = indices[[b, c, h, w]].elem::<i64>() as usize;
+                    let count: E = (counts[index] as i32).elem();
+
+                    output[[b, c, index / width_out, index % width_out]] += x[[b, c, h, w]] / count;
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+pub(crate) fn max_unpool2d_backward<E: FloatNdArrayElement, I: IntNdArrayElement>(
+    indices: NdArrayTensor<I>,
+    grad: NdArrayTensor<E>,
+) -> NdArrayTensor<E> {
+    let [batch_size, channels, height, width] = indices.shape().dims();
+    let [_, _, height_out, width_out] = grad.shape().dims();
+
+    let grad = grad.array;
+    let indices = indices.array;
+
+    let mut output = Array4::zeros((batch_size, channels, height, width));
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+            let counts = unpool_counts(&indices, b, c, [height, width], [height_out, width_out]);
+
+            for h in 0..height {
+                for w in 0..width {
+                    let index = indices[[b, c, h, w]].elem::<i64>() as usize;
+                    let count: E = (counts[index] as i32).elem();
+
+                    output[[b, c, h, w]] =
+                        grad[[b, c, index / width_out, index % width_out]] / count;
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+/// Counts the values written at each position of a channel by max unpooling.
+///
+/// Overlapping pooling windows can select the same maximum more than once, in which case the
+/// value is written once, so the values written at the same position are averaged.
+fn unpool_counts<I: IntNdArrayElement>(
+    indices: &ArcArray<I, IxDyn>,
+    b: usize,
+    c: usize,
+    size: [usize; 2],
+    output_size: [usize; 2],
+) -> Vec<usize> {
+    let mut counts = vec![0; output_size[0] * output_size[1]];
+
+    for h in 0..size[0] {
+        for w in 0..size[1] {
+            counts[indices[[b, c, h, w]].elem::<i64>() as usize] += 1;
+        }
+    }
+
+    counts
+}
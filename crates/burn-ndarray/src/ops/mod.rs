@@ -8,13 +8,17 @@ mod tensor;
 mod transaction;
 
 pub(crate) mod adaptive_avgpool;
+pub(crate) mod adaptive_maxpool;
 pub(crate) mod avgpool;
 pub(crate) mod conv;
+pub(crate) mod counting;
+pub(crate) mod ctc;
 pub(crate) mod deform_conv;
 pub(crate) mod interpolate;
 pub(crate) mod macros;
 pub(crate) mod matmul;
 pub(crate) mod maxpool;
 pub(crate) mod padding;
+pub(crate) mod special;
 
 pub(crate) use base::*;
@@ -1,10 +1,15 @@
 use super::{
     adaptive_avgpool::{adaptive_avg_pool2d, adaptive_avg_pool2d_backward},
-    avgpool::{avg_pool2d, avg_pool2d_backward},
+    adaptive_maxpool::{adaptive_max_pool2d_backward, adaptive_max_pool2d_with_indices},
+    avgpool::{avg_pool2d, avg_pool2d_backward, avg_pool3d, avg_pool3d_backward},
     conv::{conv2d, conv3d, conv_transpose2d, conv_transpose3d},
+    ctc::{ctc_loss, ctc_loss_backward},
     deform_conv::{backward::deform_conv2d_backward, deform_conv2d},
     interpolate::{bicubic_interpolate, bilinear_interpolate, nearest_interpolate},
-    maxpool::{max_pool2d, max_pool2d_backward, max_pool2d_with_indices},
+    maxpool::{
+        max_pool2d, max_pool2d_backward, max_pool2d_with_indices, max_pool3d, max_pool3d_backward,
+        max_pool3d_with_indices, max_unpool2d, max_unpool2d_backward,
+    },
 };
 use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArray, NdArrayTensorFloat};
 use crate::{
@@ -223,6 +228,132 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> ModuleOps<Se
         })
     }
 
+    fn max_pool3d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> FloatTensor<Self> {
+        module_op!(inp(x), opt(), E, |x| max_pool3d::<E>(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            dilation
+        )
+        .into())
+    }
+
+    fn max_pool3d_with_indices(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<NdArray<E, I, Q>> {
+        module_op!(inp(x), opt(), E, |x| {
+            let (output, indices) =
+                max_pool3d_with_indices::<E, I>(x, kernel_size, stride, padding, dilation);
+            MaxPool3dWithIndices::new(output.into(), indices)
+        })
+    }
+
+    fn max_pool3d_with_indices_backward(
+        x: FloatTensor<Self>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        _dilation: [usize; 3],
+        output_grad: FloatTensor<Self>,
+        indices: NdArrayTensor<I>,
+    ) -> MaxPool3dBackward<NdArray<E, I, Q>> {
+        module_op!(inp(x, output_grad), opt(), E, |x, output_grad| {
+            let output = max_pool3d_backward::<E, I>(x, output_grad, indices);
+            MaxPool3dBackward::new(output.into())
+        })
+    }
+
+    fn avg_pool3d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self> {
+        module_op!(inp(x), opt(), E, |x| avg_pool3d::<E>(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad
+        )
+        .into())
+    }
+
+    fn avg_pool3d_backward(
+        x: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self> {
+        module_op!(inp(x, grad), opt(), E, |x, grad| avg_pool3d_backward::<E>(
+            x,
+            grad,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad
+        )
+        .into())
+    }
+
+    fn adaptive_max_pool2d_with_indices(
+        x: FloatTensor<Self>,
+        output_size: [usize; 2],
+    ) -> AdaptiveMaxPool2dWithIndices<NdArray<E, I, Q>> {
+        module_op!(inp(x), opt(), E, |x| {
+            let (output, indices) = adaptive_max_pool2d_with_indices::<E, I>(x, output_size);
+            AdaptiveMaxPool2dWithIndices::new(output.into(), indices)
+        })
+    }
+
+    fn adaptive_max_pool2d_with_indices_backward(
+        x: FloatTensor<Self>,
+        output_grad: FloatTensor<Self>,
+        indices: NdArrayTensor<I>,
+    ) -> AdaptiveMaxPool2dBackward<NdArray<E, I, Q>> {
+        module_op!(inp(x, output_grad), opt(), E, |x, output_grad| {
+            let output = adaptive_max_pool2d_backward::<E, I>(x, output_grad, indices);
+            AdaptiveMaxPool2dBackward::new(output.into())
+        })
+    }
+
+    fn max_unpool2d(
+        x: FloatTensor<Self>,
+        indices: NdArrayTensor<I>,
+        output_size: [usize; 2],
+    ) -> FloatTensor<Self> {
+        module_op!(inp(x), opt(), E, |x| max_unpool2d::<E, I>(
+            x,
+            indices,
+            output_size
+        )
+        .into())
+    }
+
+    fn max_unpool2d_backward(
+        indices: NdArrayTensor<I>,
+        grad: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        module_op!(inp(grad), opt(), E, |grad| max_unpool2d_backward::<E, I>(
+            indices, grad
+        )
+        .into())
+    }
+
     fn interpolate(
         x: FloatTensor<Self>,
         output_size: [usize; 2],
@@ -236,20 +367,25 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> ModuleOps<Se
                 )
                 .into())
             }
-            InterpolateMode::Bilinear => {
+            InterpolateMode::Linear | InterpolateMode::Bilinear => {
                 module_op!(inp(x), opt(), E, |x| bilinear_interpolate::<E>(
                     x,
-                    output_size
+                    output_size,
+                    options.align_corners
                 )
                 .into())
             }
             InterpolateMode::Bicubic => {
                 module_op!(inp(x), opt(), E, |x| bicubic_interpolate::<E>(
                     x,
-                    output_size
+                    output_size,
+                    options.align_corners
                 )
                 .into())
             }
+            InterpolateMode::Trilinear => {
+                panic!("trilinear interpolation is only supported by the 3D interpolation")
+            }
         }
     }
 
@@ -263,12 +399,15 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> ModuleOps<Se
             InterpolateMode::Nearest => module_op!(inp(x, grad), opt(), E, |x, grad| {
                 nearest_interpolate_backward::<E>(x, grad, output_size).into()
             }),
-            InterpolateMode::Bilinear => {
+            InterpolateMode::Linear | InterpolateMode::Bilinear => {
                 panic!("bilinear interpolation backward is not supported for ndarray backend")
             }
             InterpolateMode::Bicubic => {
                 panic!("bicubic interpolation backward is not supported for ndarray backend")
             }
+            InterpolateMode::Trilinear => {
+                panic!("trilinear interpolation is only supported by the 3D interpolation")
+            }
         }
     }
 
@@ -294,4 +433,47 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> ModuleOps<Se
             conv_transpose3d::<E>(x, weight, bias, options).into()
         })
     }
+
+    fn ctc_loss(
+        log_probs: FloatTensor<Self>,
+        targets: IntTensor<Self>,
+        input_lengths: IntTensor<Self>,
+        target_lengths: IntTensor<Self>,
+        blank: usize,
+    ) -> FloatTensor<Self> {
+        module_op!(inp(log_probs), opt(), E, |log_probs| ctc_loss::<E, I>(
+            log_probs,
+            targets,
+            input_lengths,
+            target_lengths,
+            blank
+        )
+        .into())
+    }
+
+    fn ctc_loss_backward(
+        log_probs: FloatTensor<Self>,
+        targets: IntTensor<Self>,
+        input_lengths: IntTensor<Self>,
+        target_lengths: IntTensor<Self>,
+        loss: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+        blank: usize,
+    ) -> FloatTensor<Self> {
+        module_op!(
+            inp(log_probs, loss, grad),
+            opt(),
+            E,
+            |log_probs, loss, grad| ctc_loss_backward::<E, I>(
+                log_probs,
+                targets,
+                input_lengths,
+                target_lengths,
+                loss,
+                grad,
+                blank
+            )
+            .into()
+        )
+    }
 }
@@ -0,0 +1,84 @@
+use core::f64::consts::PI;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float;
+
+/// Inverse error function.
+pub(crate) fn erfinv(x: f64) -> f64 {
+    if x.is_nan() || x.abs() > 1.0 {
+        return f64::NAN;
+    }
+    if x.abs() == 1.0 {
+        return x * f64::INFINITY;
+    }
+
+    // Initial approximation from M. Giles, "Approximating the erfinv function".
+    let w = -((1.0 - x) * (1.0 + x)).ln();
+    let mut y = if w < 5.0 {
+        let w = w - 2.5;
+        [
+            3.432_739_39e-07,
+            -3.523_387_7e-06,
+            -4.391_506_54e-06,
+            0.000_218_580_87,
+            -0.001_253_725_03,
+            -0.004_177_681_64,
+            0.246_640_727,
+            1.501_409_41,
+        ]
+        .iter()
+        .fold(2.810_226_36e-08, |p, c| p * w + c)
+    } else {
+        let w = w.sqrt() - 3.0;
+        [
+            0.000_100_950_558,
+            0.001_349_343_22,
+            -0.003_673_428_44,
+            0.005_739_507_73,
+            -0.007_622_461_3,
+            0.009_438_870_47,
+            1.001_674_06,
+            2.832_976_82,
+        ]
+        .iter()
+        .fold(-0.000_200_214_257, |p, c| p * w + c)
+    } * x;
+
+    // Newton steps refine the approximation to double precision.
+    for _ in 0..2 {
+        let derivative = 2.0 / PI.sqrt() * (-y * y).exp();
+        y -= (libm::erf(y) - x) / derivative;
+    }
+
+    y
+}
+
+/// Logarithmic derivative of the gamma function.
+pub(crate) fn digamma(x: f64) -> f64 {
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if x < 0.0 && x == x.floor() {
+        return f64::NAN;
+    }
+    if x < 0.5 {
+        // digamma(x) = digamma(1 - x) - pi / tan(pi * x)
+        return digamma(1.0 - x) - PI / (PI * x).tan();
+    }
+
+    // digamma(x) = digamma(x + 1) - 1 / x moves the value in the asymptotic range.
+    let mut x = x;
+    let mut output = 0.0;
+    while x < 6.0 {
+        output -= 1.0 / x;
+        x += 1.0;
+    }
+
+    let x2 = 1.0 / (x * x);
+    let series = x2
+        * (-1.0 / 12.0
+            + x2 * (1.0 / 120.0 + x2 * (-1.0 / 252.0 + x2 * (1.0 / 240.0 - x2 / 132.0))));
+
+    output + x.ln() - 0.5 / x + series
+}
@@ -6,7 +6,11 @@ use core::ops::Range;
 use ndarray::Zip;
 
 // Current crate
-use super::{matmul::matmul, NdArrayMathOps, NdArrayOps};
+use super::{
+    counting,
+    matmul::{complex_matmul, matmul},
+    special, NdArrayMathOps, NdArrayOps,
+};
 use crate::element::{ExpElement, FloatNdArrayElement, IntNdArrayElement, QuantElement};
 use crate::{execute_with_float_dtype, NdArrayDevice, NdArrayTensorFloat, SEED};
 use crate::{tensor::NdArrayTensor, NdArray};
@@ -14,13 +18,13 @@ use crate::{tensor::NdArrayTensor, NdArray};
 // Workspace crates
 use burn_common::rand::get_seeded_rng;
 use burn_tensor::{backend::Backend, ops::FloatTensorOps, ElementConversion, Shape, TensorData};
-use burn_tensor::{DType, Distribution, FloatDType};
+use burn_tensor::{DType, Distribution, FloatDType, Reduce, Slice};
 
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports)]
 use num_traits::Float;
 
-use libm::erf;
+use libm::{erf, lgamma};
 
 #[cfg(feature = "std")]
 #[allow(dead_code)]
@@ -131,6 +135,35 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FloatTensorO
         execute_with_float_dtype!((lhs, rhs), matmul)
     }
 
+    fn float_complex_matmul(
+        lhs_real: FloatTensor<Self>,
+        lhs_imag: FloatTensor<Self>,
+        rhs_real: FloatTensor<Self>,
+        rhs_imag: FloatTensor<Self>,
+    ) -> (FloatTensor<Self>, FloatTensor<Self>) {
+        match (lhs_real, lhs_imag, rhs_real, rhs_imag) {
+            (
+                NdArrayTensorFloat::F32(lhs_real),
+                NdArrayTensorFloat::F32(lhs_imag),
+                NdArrayTensorFloat::F32(rhs_real),
+                NdArrayTensorFloat::F32(rhs_imag),
+            ) => {
+                let (real, imag) = complex_matmul(lhs_real, lhs_imag, rhs_real, rhs_imag);
+                (NdArrayTensorFloat::F32(real), NdArrayTensorFloat::F32(imag))
+            }
+            (
+                NdArrayTensorFloat::F64(lhs_real),
+                NdArrayTensorFloat::F64(lhs_imag),
+                NdArrayTensorFloat::F64(rhs_real),
+                NdArrayTensorFloat::F64(rhs_imag),
+            ) => {
+                let (real, imag) = complex_matmul(lhs_real, lhs_imag, rhs_real, rhs_imag);
+                (NdArrayTensorFloat::F64(real), NdArrayTensorFloat::F64(imag))
+            }
+            _ => panic!("Data type mismatch"),
+        }
+    }
+
     fn float_neg(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
         Self::float_mul_scalar(tensor, (-1f32).elem::<E>())
     }
@@ -168,6 +201,38 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FloatTensorO
         ))
     }
 
+    fn float_scatter_reduce(
+        dim: usize,
+        tensor: FloatTensor<Self>,
+        indices: NdArrayTensor<I>,
+        value: FloatTensor<Self>,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> FloatTensor<Self> {
+        execute_with_float_dtype!((tensor, value), |tensor, value| {
+            NdArrayMathOps::scatter_reduce(dim, tensor, indices, value, reduce, include_self)
+        })
+    }
+
+    fn float_searchsorted(
+        sorted: FloatTensor<Self>,
+        values: FloatTensor<Self>,
+        right: bool,
+    ) -> NdArrayTensor<I> {
+        execute_with_float_dtype!((sorted, values) => |sorted, values| counting::searchsorted(
+            sorted, values, right
+        ))
+    }
+
+    fn float_histc(
+        tensor: FloatTensor<Self>,
+        bins: usize,
+        min: f64,
+        max: f64,
+    ) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, |tensor| counting::histc(tensor, bins, min, max))
+    }
+
     fn float_select(
         tensor: FloatTensor<Self>,
         dim: usize,
@@ -203,6 +268,22 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FloatTensorO
         })
     }
 
+    fn float_slice_with_steps(tensor: FloatTensor<Self>, slices: &[Slice]) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, |tensor| NdArrayOps::slice_with_steps(
+            tensor, slices
+        ))
+    }
+
+    fn float_slice_assign_with_steps(
+        tensor: FloatTensor<Self>,
+        slices: &[Slice],
+        value: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        execute_with_float_dtype!((tensor, value), |tensor, value| {
+            NdArrayOps::slice_assign_with_steps(tensor, slices, value)
+        })
+    }
+
     fn float_mask_where(
         tensor: FloatTensor<Self>,
         mask: NdArrayTensor<bool>,
@@ -472,6 +553,210 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FloatTensorO
         })
     }
 
+    fn float_asin(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
+            let array = tensor
+                .array
+                .mapv_into(|a| a.to_f64().asin().elem())
+                .into_shared();
+
+            NdArrayTensor::new(array)
+        })
+    }
+
+    fn float_acos(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
+            let array = tensor
+                .array
+                .mapv_into(|a| a.to_f64().acos().elem())
+                .into_shared();
+
+            NdArrayTensor::new(array)
+        })
+    }
+
+    fn float_atan(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
+            let array = tensor
+                .array
+                .mapv_into(|a| a.to_f64().atan().elem())
+                .into_shared();
+
+            NdArrayTensor::new(array)
+        })
+    }
+
+    fn float_sinh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
+            let array = tensor
+                .array
+                .mapv_into(|a| a.to_f64().sinh().elem())
+                .into_shared();
+
+            NdArrayTensor::new(array)
+        })
+    }
+
+    fn float_cosh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
+            let array = tensor
+                .array
+                .mapv_into(|a| a.to_f64().cosh().elem())
+                .into_shared();
+
+            NdArrayTensor::new(array)
+        })
+    }
+
+    fn float_asinh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
+            let array = tensor
+                .array
+                .mapv_into(|a| a.to_f64().asinh().elem())
+                .into_shared();
+
+            NdArrayTensor::new(array)
+        })
+    }
+
+    fn float_acosh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
+            let array = tensor
+                .array
+                .mapv_into(|a| a.to_f64().acosh().elem())
+                .into_shared();
+
+            NdArrayTensor::new(array)
+        })
+    }
+
+    fn float_atanh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
+            let array = tensor
+                .array
+                .mapv_into(|a| a.to_f64().atanh().elem())
+                .into_shared();
+
+            NdArrayTensor::new(array)
+        })
+    }
+
+    fn float_expm1(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
+            let array = tensor
+                .array
+                .mapv_into(|a| a.to_f64().exp_m1().elem())
+                .into_shared();
+
+            NdArrayTensor::new(array)
+        })
+    }
+
+    fn float_erfinv(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
+            let array = tensor
+                .array
+                .mapv_into(|a| special::erfinv(a.to_f64()).elem())
+                .into_shared();
+
+            NdArrayTensor::new(array)
+        })
+    }
+
+    fn float_lgamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
+            let array = tensor
+                .array
+                .mapv_into(|a| lgamma(a.to_f64()).elem())
+                .into_shared();
+
+            NdArrayTensor::new(array)
+        })
+    }
+
+    fn float_digamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
+            let array = tensor
+                .array
+                .mapv_into(|a| special::digamma(a.to_f64()).elem())
+                .into_shared();
+
+            NdArrayTensor::new(array)
+        })
+    }
+
+    fn float_trunc(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
+            let array = tensor
+                .array
+                .mapv_into(|a| a.to_f64().trunc().elem())
+                .into_shared();
+
+            NdArrayTensor::new(array)
+        })
+    }
+
+    fn float_atan2(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_float_dtype!((lhs, rhs), E, |lhs, rhs| NdArrayMathOps::elementwise_op(
+            lhs,
+            rhs,
+            |a: &E, b: &E| (a.to_f64().atan2(b.to_f64())).elem()
+        ))
+    }
+
+    fn float_hypot(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_float_dtype!((lhs, rhs), E, |lhs, rhs| NdArrayMathOps::elementwise_op(
+            lhs,
+            rhs,
+            |a: &E, b: &E| (a.to_f64().hypot(b.to_f64())).elem()
+        ))
+    }
+
+    fn float_fmod(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_float_dtype!((lhs, rhs), E, |lhs, rhs| NdArrayMathOps::elementwise_op(
+            lhs,
+            rhs,
+            |a: &E, b: &E| (a.to_f64() % b.to_f64()).elem()
+        ))
+    }
+
+    fn float_fmod_scalar(lhs: FloatTensor<Self>, rhs: E) -> FloatTensor<Self> {
+        let rhs = rhs.to_f64();
+        execute_with_float_dtype!(lhs, E, |tensor: NdArrayTensor<E>| {
+            let array = tensor
+                .array
+                .mapv_into(|a| (a.to_f64() % rhs).elem())
+                .into_shared();
+
+            NdArrayTensor::new(array)
+        })
+    }
+
+    fn float_nan_to_num(
+        tensor: FloatTensor<Self>,
+        nan: E,
+        posinf: E,
+        neginf: E,
+    ) -> FloatTensor<Self> {
+        let (nan, posinf, neginf) = (nan.to_f64(), posinf.to_f64(), neginf.to_f64());
+        execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
+            let array = tensor
+                .array
+                .mapv_into(|a| {
+                    let a = a.to_f64();
+                    match a {
+                        _ if a.is_nan() => nan.elem(),
+                        f64::INFINITY => posinf.elem(),
+                        f64::NEG_INFINITY => neginf.elem(),
+                        _ => a.elem(),
+                    }
+                })
+                .into_shared();
+
+            NdArrayTensor::new(array)
+        })
+    }
+
     fn float_cat(tensors: Vec<FloatTensor<Self>>, dim: usize) -> FloatTensor<Self> {
         match &tensors[0] {
             NdArrayTensorFloat::F32(_) => {
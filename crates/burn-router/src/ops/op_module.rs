@@ -4,10 +4,10 @@ use burn_ir::{
     AdaptiveAvgPool1dBackwardOpIr, AdaptiveAvgPool1dOpIr, AdaptiveAvgPool2dBackwardOpIr,
     AdaptiveAvgPool2dOpIr, AvgPool1dBackwardOpIr, AvgPool1dOpIr, AvgPool2dBackwardOpIr,
     AvgPool2dOpIr, Conv1dOpIr, Conv2dOpIr, Conv3dOpIr, ConvTranspose1dOpIr, ConvTranspose2dOpIr,
-    ConvTranspose3dOpIr, DeformConv2dBackwardOpIr, DeformConv2dOpIr, InterpolateBackwardOpIr,
-    InterpolateOpIr, MaxPool1dOpIr, MaxPool1dWithIndicesBackwardOpIr, MaxPool1dWithIndicesOpIr,
-    MaxPool2dOpIr, MaxPool2dWithIndicesBackwardOpIr, MaxPool2dWithIndicesOpIr, ModuleOperationIr,
-    OperationIr,
+    ConvTranspose3dOpIr, DeformConv2dBackwardOpIr, DeformConv2dOpIr, GroupedMatmulOpIr,
+    GroupedMatmulRhsBackwardOpIr, InterpolateBackwardOpIr, InterpolateOpIr, MaxPool1dOpIr,
+    MaxPool1dWithIndicesBackwardOpIr, MaxPool1dWithIndicesOpIr, MaxPool2dOpIr,
+    MaxPool2dWithIndicesBackwardOpIr, MaxPool2dWithIndicesOpIr, ModuleOperationIr, OperationIr,
 };
 use burn_tensor::ops::conv::{
     calculate_conv_output_size, calculate_conv_transpose_output_size, calculate_pool_output_size,
@@ -796,4 +796,50 @@ impl<R: RunnerChannel> ModuleOps<Self> for BackendRouter<R> {
 
         DeformConv2dBackward::new(input_grad, offset_grad, weight_grad, mask_grad, bias_grad)
     }
+
+    fn grouped_matmul(
+        lhs: FloatTensor<Self>,
+        rhs: FloatTensor<Self>,
+        offsets: IntTensor<Self>,
+    ) -> FloatTensor<Self> {
+        let shape = vec![lhs.shape[0], rhs.shape[2]];
+
+        let client = lhs.client.clone();
+        let out = client.register_empty_tensor(shape, lhs.dtype);
+
+        let desc = GroupedMatmulOpIr {
+            lhs: lhs.into_ir(),
+            rhs: rhs.into_ir(),
+            offsets: offsets.into_ir(),
+            out: out.to_ir_out(),
+        };
+
+        client.register(OperationIr::Module(ModuleOperationIr::GroupedMatmul(desc)));
+
+        out
+    }
+
+    fn grouped_matmul_rhs_backward(
+        lhs: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+        offsets: IntTensor<Self>,
+    ) -> FloatTensor<Self> {
+        let shape = vec![offsets.shape[0], lhs.shape[1], grad.shape[1]];
+
+        let client = lhs.client.clone();
+        let out = client.register_empty_tensor(shape, lhs.dtype);
+
+        let desc = GroupedMatmulRhsBackwardOpIr {
+            lhs: lhs.into_ir(),
+            grad: grad.into_ir(),
+            offsets: offsets.into_ir(),
+            out: out.to_ir_out(),
+        };
+
+        client.register(OperationIr::Module(
+            ModuleOperationIr::GroupedMatmulRhsBackward(desc),
+        ));
+
+        out
+    }
 }
@@ -452,6 +452,21 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                     let output = B::float_scatter(desc.dim, tensor, indices, value);
                     handles.register_float_tensor::<B>(&desc.out.id, output);
                 }
+                NumericOperationIr::ScatterReduce(desc) => {
+                    let tensor = handles.get_float_tensor::<B>(&desc.tensor);
+                    let indices = handles.get_int_tensor::<B>(&desc.indices);
+                    let value = handles.get_float_tensor::<B>(&desc.value);
+
+                    let output = B::float_scatter_reduce(
+                        desc.dim,
+                        tensor,
+                        indices,
+                        value,
+                        desc.reduce.into(),
+                        desc.include_self,
+                    );
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
                 NumericOperationIr::Select(desc) => {
                     let tensor = handles.get_float_tensor::<B>(&desc.tensor);
                     let indices = handles.get_int_tensor::<B>(&desc.indices);
@@ -634,6 +649,21 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                     let output = B::int_scatter(desc.dim, tensor, indices, value);
                     handles.register_int_tensor::<B>(&desc.out.id, output);
                 }
+                NumericOperationIr::ScatterReduce(desc) => {
+                    let tensor = handles.get_int_tensor::<B>(&desc.tensor);
+                    let indices = handles.get_int_tensor::<B>(&desc.indices);
+                    let value = handles.get_int_tensor::<B>(&desc.value);
+
+                    let output = B::int_scatter_reduce(
+                        desc.dim,
+                        tensor,
+                        indices,
+                        value,
+                        desc.reduce.into(),
+                        desc.include_self,
+                    );
+                    handles.register_int_tensor::<B>(&desc.out.id, output);
+                }
                 NumericOperationIr::Select(desc) => {
                     let tensor = handles.get_int_tensor::<B>(&desc.tensor);
                     let indices = handles.get_int_tensor::<B>(&desc.indices);
@@ -865,6 +895,68 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                 FloatOperationIr::Ceil(desc) => {
                     unary_float_ops!(handles, desc, B::float_ceil)
                 }
+                FloatOperationIr::Asin(desc) => {
+                    unary_float_ops!(handles, desc, B::float_asin)
+                }
+                FloatOperationIr::Acos(desc) => {
+                    unary_float_ops!(handles, desc, B::float_acos)
+                }
+                FloatOperationIr::Atan(desc) => {
+                    unary_float_ops!(handles, desc, B::float_atan)
+                }
+                FloatOperationIr::Sinh(desc) => {
+                    unary_float_ops!(handles, desc, B::float_sinh)
+                }
+                FloatOperationIr::Cosh(desc) => {
+                    unary_float_ops!(handles, desc, B::float_cosh)
+                }
+                FloatOperationIr::Asinh(desc) => {
+                    unary_float_ops!(handles, desc, B::float_asinh)
+                }
+                FloatOperationIr::Acosh(desc) => {
+                    unary_float_ops!(handles, desc, B::float_acosh)
+                }
+                FloatOperationIr::Atanh(desc) => {
+                    unary_float_ops!(handles, desc, B::float_atanh)
+                }
+                FloatOperationIr::Expm1(desc) => {
+                    unary_float_ops!(handles, desc, B::float_expm1)
+                }
+                FloatOperationIr::ErfInv(desc) => {
+                    unary_float_ops!(handles, desc, B::float_erfinv)
+                }
+                FloatOperationIr::Lgamma(desc) => {
+                    unary_float_ops!(handles, desc, B::float_lgamma)
+                }
+                FloatOperationIr::Digamma(desc) => {
+                    unary_float_ops!(handles, desc, B::float_digamma)
+                }
+                FloatOperationIr::Trunc(desc) => {
+                    unary_float_ops!(handles, desc, B::float_trunc)
+                }
+                FloatOperationIr::Atan2(desc) => {
+                    binary_float_ops!(handles, desc, B::float_atan2)
+                }
+                FloatOperationIr::Hypot(desc) => {
+                    binary_float_ops!(handles, desc, B::float_hypot)
+                }
+                FloatOperationIr::Fmod(desc) => {
+                    binary_float_ops!(handles, desc, B::float_fmod)
+                }
+                FloatOperationIr::FmodScalar(desc) => {
+                    scalar_float_ops!(handles, desc, B::float_fmod_scalar)
+                }
+                FloatOperationIr::NanToNum(desc) => {
+                    let tensor = handles.get_float_tensor::<B>(&desc.input);
+
+                    let output = B::float_nan_to_num(
+                        tensor,
+                        desc.nan.elem(),
+                        desc.posinf.elem(),
+                        desc.neginf.elem(),
+                    );
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
                 FloatOperationIr::IntoInt(desc) => {
                     let tensor = handles.get_float_tensor::<B>(&desc.input);
 
@@ -1205,6 +1297,22 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                     );
                     handles.register_float_tensor::<B>(&desc.out.id, output);
                 }
+                ModuleOperationIr::GroupedMatmul(desc) => {
+                    let lhs = handles.get_float_tensor::<B>(&desc.lhs);
+                    let rhs = handles.get_float_tensor::<B>(&desc.rhs);
+                    let offsets = handles.get_int_tensor::<B>(&desc.offsets);
+
+                    let output = B::grouped_matmul(lhs, rhs, offsets);
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
+                ModuleOperationIr::GroupedMatmulRhsBackward(desc) => {
+                    let lhs = handles.get_float_tensor::<B>(&desc.lhs);
+                    let grad = handles.get_float_tensor::<B>(&desc.grad);
+                    let offsets = handles.get_int_tensor::<B>(&desc.offsets);
+
+                    let output = B::grouped_matmul_rhs_backward(lhs, grad, offsets);
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
             },
             OperationIr::Custom(_) => {
                 panic!("Can't execute custom operation here")
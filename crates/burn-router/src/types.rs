@@ -68,12 +68,35 @@ macro_rules! impl_multi_backend_types {
 
             impl<$DefaultBackend: Backend, $($OtherBackend: Backend),+> DeviceOps for MultiDevice<$DefaultBackend, $($OtherBackend),+> {
                 fn id(&self) -> DeviceId {
-                    match self {
-                        Self::$DefaultBackend(device) => device.id(),
-                        $(
-                            Self::$OtherBackend(device) => device.id(),
-                        )+
+                    // The devices of different backends can have the same id (e.g. the first device
+                    // of each backend), so the position of the backend is interleaved in the type id.
+                    let num_backends =
+                        [stringify!($DefaultBackend), $(stringify!($OtherBackend)),+].len() as u16;
+                    let encode = |position: u16, id: DeviceId| {
+                        let type_id = id
+                            .type_id
+                            .checked_mul(num_backends)
+                            .and_then(|type_id| type_id.checked_add(position))
+                            .unwrap_or_else(|| {
+                                panic!("The device type id {} is too large to be routed.", id.type_id)
+                            });
+
+                        DeviceId::new(type_id, id.index_id)
+                    };
+
+                    if let Self::$DefaultBackend(device) = self {
+                        return encode(0, device.id());
                     }
+
+                    let mut position: u16 = 0;
+                    $(
+                        position += 1;
+                        if let Self::$OtherBackend(device) = self {
+                            return encode(position, device.id());
+                        }
+                    )+
+
+                    unreachable!()
                 }
             }
 
@@ -394,4 +417,25 @@ mod tests {
         let tensor2_1 = tensor2.clone().to_device(&device1);
         tensor2.into_data().assert_eq(&tensor2_1.into_data(), true);
     }
+
+    #[test]
+    fn should_have_distinct_ids_for_distinct_devices() {
+        type WgpuDevice = <TestBackend2 as Backend>::Device;
+        let ndarray = <TestBackend1 as Backend>::Device::default();
+        let devices = [
+            trio::MultiDevice::<TestBackend1, TestBackend2, TestBackend1>::B1(ndarray),
+            trio::MultiDevice::B3(ndarray),
+            trio::MultiDevice::B2(WgpuDevice::DiscreteGpu(0)),
+            trio::MultiDevice::B2(WgpuDevice::DiscreteGpu(1)),
+            trio::MultiDevice::B2(WgpuDevice::IntegratedGpu(0)),
+            trio::MultiDevice::B2(WgpuDevice::Cpu),
+            trio::MultiDevice::B2(WgpuDevice::Existing(3)),
+        ];
+
+        let ids = devices
+            .iter()
+            .map(|device| device.id())
+            .collect::<hashbrown::HashSet<_>>();
+        assert_eq!(ids.len(), devices.len());
+    }
 }
@@ -1,4 +1,4 @@
-use burn_tensor::{Shape, TensorMetadata};
+use burn_tensor::{Reduce, Shape, TensorMetadata};
 use tch::Scalar;
 
 use crate::{LibTorchDevice, TchShape, TchTensor};
@@ -89,6 +89,33 @@ impl TchOps {
         TchTensor::from_existing(tensor, storage)
     }
 
+    pub fn scatter_reduce(
+        dim: usize,
+        tensor: TchTensor,
+        indices: TchTensor,
+        value: TchTensor,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> TchTensor {
+        let reduce = match reduce {
+            Reduce::Sum => "sum",
+            Reduce::Prod => "prod",
+            Reduce::Mean => "mean",
+            Reduce::Max => "amax",
+            Reduce::Min => "amin",
+        };
+        let storage = tensor.storage.clone();
+        let tensor = tensor.tensor.scatter_reduce(
+            dim as i64,
+            &indices.tensor,
+            &value.tensor,
+            reduce,
+            include_self,
+        );
+
+        TchTensor::from_existing(tensor, storage)
+    }
+
     pub fn index_select_dim(tensor: TchTensor, dim: usize, indices: TchTensor) -> TchTensor {
         let storage = tensor.storage.clone();
         let tensor = tensor.tensor.index_select(dim as i64, &indices.tensor);
@@ -3,7 +3,7 @@ use std::ops::Range;
 use burn_tensor::{
     backend::Backend,
     ops::{IntTensor, IntTensorOps},
-    Distribution, Shape, TensorData, TensorMetadata,
+    Distribution, Reduce, Shape, TensorData, TensorMetadata,
 };
 
 use crate::{element::TchElement, LibTorch, LibTorchDevice, QuantElement, TchShape, TchTensor};
@@ -261,6 +261,17 @@ impl<E: TchElement, Q: QuantElement> IntTensorOps<Self> for LibTorch<E, Q> {
         TchOps::scatter(dim, tensor, indices, value)
     }
 
+    fn int_scatter_reduce(
+        dim: usize,
+        tensor: TchTensor,
+        indices: TchTensor,
+        value: TchTensor,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> TchTensor {
+        TchOps::scatter_reduce(dim, tensor, indices, value, reduce, include_self)
+    }
+
     fn int_select(tensor: TchTensor, dim: usize, indices: TchTensor) -> TchTensor {
         TchOps::index_select_dim(tensor, dim, indices)
     }
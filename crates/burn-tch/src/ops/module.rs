@@ -357,11 +357,24 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
             InterpolateMode::Nearest => {
                 tch::Tensor::upsample_nearest2d(&x.tensor, output_size, None, None)
             }
-            InterpolateMode::Bilinear => {
-                tch::Tensor::upsample_bilinear2d(&x.tensor, output_size, true, None, None)
+            InterpolateMode::Linear | InterpolateMode::Bilinear => {
+                tch::Tensor::upsample_bilinear2d(
+                    &x.tensor,
+                    output_size,
+                    options.align_corners,
+                    None,
+                    None,
+                )
             }
-            InterpolateMode::Bicubic => {
-                tch::Tensor::upsample_bicubic2d(&x.tensor, output_size, true, None, None)
+            InterpolateMode::Bicubic => tch::Tensor::upsample_bicubic2d(
+                &x.tensor,
+                output_size,
+                options.align_corners,
+                None,
+                None,
+            ),
+            InterpolateMode::Trilinear => {
+                panic!("trilinear interpolation is only supported by the 3D interpolation")
             }
         };
 
@@ -386,22 +399,27 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
                 None,
                 None,
             ),
-            InterpolateMode::Bilinear => tch::Tensor::upsample_bilinear2d_backward(
-                &grad.tensor,
-                output_size,
-                input_size,
-                true,
-                None,
-                None,
-            ),
+            InterpolateMode::Linear | InterpolateMode::Bilinear => {
+                tch::Tensor::upsample_bilinear2d_backward(
+                    &grad.tensor,
+                    output_size,
+                    input_size,
+                    options.align_corners,
+                    None,
+                    None,
+                )
+            }
             InterpolateMode::Bicubic => tch::Tensor::upsample_bicubic2d_backward(
                 &grad.tensor,
                 output_size,
                 input_size,
-                true,
+                options.align_corners,
                 None,
                 None,
             ),
+            InterpolateMode::Trilinear => {
+                panic!("trilinear interpolation is only supported by the 3D interpolation")
+            }
         };
 
         TchTensor::new(tensor)
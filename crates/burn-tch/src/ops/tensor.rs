@@ -3,7 +3,7 @@ use crate::{element::TchElement, LibTorch, LibTorchDevice, QuantElement, TchShap
 use burn_tensor::{
     backend::Backend,
     ops::{FloatTensorOps, IntTensor},
-    DType, Distribution, ElementConversion, FloatDType, Shape, TensorData, TensorMetadata,
+    DType, Distribution, ElementConversion, FloatDType, Reduce, Shape, TensorData, TensorMetadata,
 };
 use half::{bf16, f16};
 use std::ops::Range;
@@ -203,6 +203,17 @@ impl<E: TchElement, Q: QuantElement> FloatTensorOps<Self> for LibTorch<E, Q> {
         TchOps::scatter(dim, tensor, indices, value)
     }
 
+    fn float_scatter_reduce(
+        dim: usize,
+        tensor: TchTensor,
+        indices: TchTensor,
+        value: TchTensor,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> TchTensor {
+        TchOps::scatter_reduce(dim, tensor, indices, value, reduce, include_self)
+    }
+
     fn float_select(tensor: TchTensor, dim: usize, indices: TchTensor) -> TchTensor {
         TchOps::index_select_dim(tensor, dim, indices)
     }
@@ -47,6 +47,9 @@ mod cube {
                 crate::DType::U16 => Elem::UInt(UIntKind::U16),
                 crate::DType::U8 => Elem::UInt(UIntKind::U8),
                 crate::DType::Bool => Elem::Bool,
+                crate::DType::Complex32 | crate::DType::Complex64 => {
+                    panic!("complex type is not supported yet.")
+                }
                 crate::DType::QFloat(_) => panic!("quantized type is not supported yet."),
             }
         }
@@ -38,6 +38,23 @@ impl<const D: usize, B: AutodiffBackend> Tensor<B, D> {
         }
     }
 
+    /// Remove the row-sparse gradients from the [grads](AutodiffBackend::Gradients) struct
+    /// returning the indices of the rows and their gradients.
+    ///
+    /// See [grad_remove_rows](AutodiffBackend::grad_remove_rows) for more information.
+    #[allow(clippy::type_complexity)]
+    pub fn grad_remove_rows(
+        &self,
+        grads: &mut B::Gradients,
+    ) -> Option<(Tensor<B::InnerBackend, 1, Int>, Tensor<B::InnerBackend, D>)> {
+        B::grad_remove_rows(&self.primitive.clone().tensor(), grads).map(|(rows, values)| {
+            (
+                Tensor::new(rows),
+                Tensor::new(TensorPrimitive::Float(values)),
+            )
+        })
+    }
+
     /// Replace the grad tensor from the [grads](AutodiffBackend::Gradients) struct with the provided
     /// gradient.
     pub fn grad_replace(&self, grads: &mut B::Gradients, grad: Tensor<B::InnerBackend, D>) {
@@ -16,7 +16,8 @@ use serde::{Serialize, Serializer};
 
 use crate::tensor::api::narrow::narrow;
 use crate::{
-    backend::Backend, check, ops::Device, Bool, Float, Int, Shape, TensorData, TensorKind,
+    backend::Backend, check, ops::Device, slice_assign_with_steps, slice_with_steps, Bool, Float,
+    Int, Shape, Slice, TensorData, TensorKind,
 };
 use crate::{cast::ToElement, check::TensorCheck};
 use crate::{DType, Element, TensorPrimitive};
@@ -458,6 +459,151 @@ where
         Tensor::new(K::flip(self.primitive, &transformed_axes))
     }
 
+    /// Roll the elements of the tensor along the given dimensions.
+    ///
+    /// The elements shifted beyond the last position of a dimension are re-introduced at the first
+    /// position, so `output[(i + shift) % size] = input[i]` along each dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `shifts` - The number of positions each dimension is rolled by, which can be negative.
+    /// * `dims` - The dimensions to roll, negative dimensions being counted from the end.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    ///
+    ///     let rolled = tensor.roll([1], [1]);
+    ///     println!("{rolled}");
+    ///     // [[3.0, 1.0, 2.0], [6.0, 4.0, 5.0]]
+    /// }
+    /// ```
+    pub fn roll<const N: usize>(self, shifts: [i64; N], dims: [isize; N]) -> Self {
+        let dims = dims.map(|dim| match dim < 0 {
+            true => (D as isize + dim) as usize,
+            false => dim as usize,
+        });
+
+        check!(TensorCheck::roll(D, &dims));
+
+        shifts
+            .into_iter()
+            .zip(dims)
+            .fold(self, |tensor, (shift, dim)| tensor.roll_dim(shift, dim))
+    }
+
+    /// Roll the elements of the tensor along a single dimension.
+    ///
+    /// See [roll](Tensor::roll).
+    pub fn roll_dim(self, shift: i64, dim: usize) -> Self {
+        check!(TensorCheck::roll(D, &[dim]));
+
+        let size = self.dims()[dim];
+        if size == 0 {
+            return self;
+        }
+
+        let shift = shift.rem_euclid(size as i64) as usize;
+        if shift == 0 {
+            return self;
+        }
+
+        let end = self.clone().narrow(dim, size - shift, shift);
+        let start = self.narrow(dim, 0, size - shift);
+
+        Self::cat(vec![end, start], dim)
+    }
+
+    /// Returns the diagonal of the matrices formed by the two given dimensions.
+    ///
+    /// The two dimensions are removed and the diagonal is appended as the last dimension, so the
+    /// output has one dimension less than the input.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The offset of the diagonal, above the main diagonal when positive and below
+    ///   when negative.
+    /// * `dim1` - The dimension of the rows.
+    /// * `dim2` - The dimension of the columns.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2>::from_data(
+    ///         [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]],
+    ///         &device,
+    ///     );
+    ///
+    ///     let diagonal: Tensor<B, 1> = tensor.clone().diagonal(0, 0, 1);
+    ///     println!("{diagonal}");
+    ///     // [1.0, 5.0, 9.0]
+    ///
+    ///     let diagonal: Tensor<B, 1> = tensor.diagonal(1, 0, 1);
+    ///     println!("{diagonal}");
+    ///     // [2.0, 6.0]
+    /// }
+    /// ```
+    pub fn diagonal<const D2: usize>(
+        self,
+        offset: i64,
+        dim1: usize,
+        dim2: usize,
+    ) -> Tensor<B, D2, K> {
+        check!(TensorCheck::diagonal::<D, D2>(
+            &self.shape(),
+            offset,
+            dim1,
+            dim2
+        ));
+
+        // Move the two dimensions to the end.
+        let mut axes = (0..D)
+            .filter(|dim| *dim != dim1 && *dim != dim2)
+            .collect::<Vec<_>>();
+        axes.extend([dim1, dim2]);
+        let tensor = Tensor::<B, D, K>::new(K::permute(self.primitive, &axes));
+
+        let tensor = match offset < 0 {
+            true => {
+                let rows = tensor.dims()[D - 2];
+                tensor.narrow(
+                    D - 2,
+                    offset.unsigned_abs() as usize,
+                    rows - offset.unsigned_abs() as usize,
+                )
+            }
+            false => {
+                let columns = tensor.dims()[D - 1];
+                tensor.narrow(D - 1, offset as usize, columns - offset as usize)
+            }
+        };
+
+        // The diagonal elements of a flattened `[length, columns]` matrix are `columns + 1` apart.
+        let [rows, columns] = [tensor.dims()[D - 2], tensor.dims()[D - 1]];
+        let length = usize::min(rows, columns);
+        let tensor = tensor.narrow(D - 2, 0, length);
+
+        let mut dims = tensor.dims()[..D - 2].to_vec();
+        dims.push(length * columns);
+        let tensor = tensor.reshape::<D2, _>(Shape::from(dims));
+
+        let mut slices = [Slice::full(); D2];
+        slices[D2 - 1] = Slice::full().with_step(columns as isize + 1);
+
+        tensor.slice(slices)
+    }
+
     /// Flatten the tensor along a given range of dimensions.
     ///
     /// This function collapses the specified range of dimensions into a single dimension,
@@ -808,6 +954,7 @@ where
     ///   - An array of `core::ops::Range<usize>`
     ///   - An array of `Option<(i64, i64)>`
     ///   - An array of `(i64, i64)` tuples
+    ///   - An array of [slices](Slice) with steps, usually created with the [s](crate::s) macro
     ///
     /// # Behavior
     ///
@@ -816,6 +963,8 @@ where
     /// - Handles negative indices by wrapping around from the end of the dimension.
     /// - Clamps ranges to the tensor's dimensions if they exceed the bounds.
     /// - For `Option<(i64, i64)>` ranges, `None` selects the full range of that dimension.
+    /// - For [slices](Slice), every `step` element of the range is selected, in reverse order with a
+    ///   negative step.
     ///
     /// # Panics
     ///
@@ -850,6 +999,11 @@ where
     ///     let tensor = Tensor::<B, 1, burn_tensor::Int>::arange(0..12, &device).reshape([3, 4]);
     ///     let slice = tensor.slice([Some((1, -1)), None]); // Select rows 1 and 2, all columns
     ///     assert_eq!(slice.dims(), [2, 4]);
+    ///
+    ///     // Using steps
+    ///     let tensor = Tensor::<B, 1, burn_tensor::Int>::arange(0..6, &device);
+    ///     let slice = tensor.slice(burn_tensor::s![1..;2]);
+    ///     assert_eq!(slice.into_data().to_vec::<i32>().unwrap(), vec![1i32, 3, 5]);
     /// }
     /// ```
     ///
@@ -859,15 +1013,27 @@ where
     /// handles the conversion of various range formats and applies clamping and negative
     /// index handling internally.
     pub fn slice<const D2: usize, R: RangesArg<D2>>(self, ranges: R) -> Self {
-        let ranges = ranges.into_ranges(self.shape());
+        let slices = ranges.into_slices(self.shape());
+        let ranges = slices_to_ranges(&self.shape(), &slices);
 
         check!(TensorCheck::slice::<D, D2>(&self.shape(), &ranges));
-        Self::new(K::slice(self.primitive, &ranges))
+        check!(TensorCheck::slice_steps("Slice", &slices));
+
+        match slices.iter().all(|slice| slice.step == 1) {
+            true => Self::new(K::slice(self.primitive, &ranges)),
+            false => {
+                let slices = normalize_slices(&ranges, &slices);
+                Self::new(K::slice_with_steps(self.primitive, &slices))
+            }
+        }
     }
 
     /// Returns a copy of the current tensor with the selected elements changed to the new ones at
     /// the selected indices.
     ///
+    /// The ranges can be given as any type implementing [RangesArg], including [slices](Slice) with
+    /// steps to assign every `step` element of the ranges.
+    ///
     /// # Panics
     ///
     /// - If a range exceeds the number of elements on a dimension.
@@ -887,17 +1053,29 @@ where
     ///     println!("{:?}", tensor_sliced.dims()); // [2, 3, 3]
     /// }
     /// ```
-    pub fn slice_assign<const D2: usize>(
-        self,
-        ranges: [core::ops::Range<usize>; D2],
-        values: Self,
-    ) -> Self {
+    pub fn slice_assign<const D2: usize, R: RangesArg<D2>>(self, ranges: R, values: Self) -> Self {
+        let slices = ranges.into_slices(self.shape());
+        let ranges = slices_to_ranges(&self.shape(), &slices);
+
         check!(TensorCheck::slice_assign::<D, D2>(
             &self.shape(),
             &values.shape(),
-            &ranges
+            &ranges,
+            &slices
         ));
-        Self::new(K::slice_assign(self.primitive, &ranges, values.primitive))
+        check!(TensorCheck::slice_steps("Slice Assign", &slices));
+
+        match slices.iter().all(|slice| slice.step == 1) {
+            true => Self::new(K::slice_assign(self.primitive, &ranges, values.primitive)),
+            false => {
+                let slices = normalize_slices(&ranges, &slices);
+                Self::new(K::slice_assign_with_steps(
+                    self.primitive,
+                    &slices,
+                    values.primitive,
+                ))
+            }
+        }
     }
 
     /// Returns the device of the current tensor.
@@ -2071,6 +2249,53 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
         value: Self::Primitive,
     ) -> Self::Primitive;
 
+    /// Selects the elements of the tensor given by slices with steps.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `slices` - The slices of the elements to select, within the bounds of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// The selected elements.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For selecting elements of a tensor, users should prefer the [Tensor::slice](Tensor::slice) function,
+    /// which is more high-level and designed for public use.
+    fn slice_with_steps(tensor: Self::Primitive, slices: &[Slice]) -> Self::Primitive;
+
+    /// Assigns the given value to the tensor elements given by slices with steps.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `slices` - The slices of the elements to select, within the bounds of the tensor.
+    /// * `value` - The value to assign.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the assigned values.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For assigning values to elements of a tensor, users should prefer the [Tensor::slice_assign](Tensor::slice_assign) function,
+    /// which is more high-level and designed for public use.
+    fn slice_assign_with_steps(
+        tensor: Self::Primitive,
+        slices: &[Slice],
+        value: Self::Primitive,
+    ) -> Self::Primitive;
+
     /// Returns the device on which the tensor is allocated.
     ///
     /// # Arguments
@@ -2492,6 +2717,28 @@ impl<B: Backend> BasicOps<B> for Float {
         }
     }
 
+    fn slice_with_steps(tensor: Self::Primitive, slices: &[Slice]) -> Self::Primitive {
+        match tensor {
+            TensorPrimitive::Float(tensor) => {
+                TensorPrimitive::Float(B::float_slice_with_steps(tensor, slices))
+            }
+            tensor => slice_with_steps::<B, Self>(tensor, slices),
+        }
+    }
+
+    fn slice_assign_with_steps(
+        tensor: Self::Primitive,
+        slices: &[Slice],
+        value: Self::Primitive,
+    ) -> Self::Primitive {
+        match (tensor, value) {
+            (TensorPrimitive::Float(tensor), TensorPrimitive::Float(value)) => {
+                TensorPrimitive::Float(B::float_slice_assign_with_steps(tensor, slices, value))
+            }
+            (tensor, value) => slice_assign_with_steps::<B, Self>(tensor, slices, value),
+        }
+    }
+
     fn device(tensor: &Self::Primitive) -> Device<B> {
         match tensor {
             TensorPrimitive::Float(tensor) => B::float_device(tensor),
@@ -2692,6 +2939,18 @@ impl<B: Backend> BasicOps<B> for Int {
         B::int_slice_assign(tensor, ranges, value)
     }
 
+    fn slice_with_steps(tensor: Self::Primitive, slices: &[Slice]) -> Self::Primitive {
+        B::int_slice_with_steps(tensor, slices)
+    }
+
+    fn slice_assign_with_steps(
+        tensor: Self::Primitive,
+        slices: &[Slice],
+        value: Self::Primitive,
+    ) -> Self::Primitive {
+        B::int_slice_assign_with_steps(tensor, slices, value)
+    }
+
     fn device(tensor: &Self::Primitive) -> Device<B> {
         B::int_device(tensor)
     }
@@ -2812,6 +3071,18 @@ impl<B: Backend> BasicOps<B> for Bool {
         B::bool_slice_assign(tensor, ranges, value)
     }
 
+    fn slice_with_steps(tensor: Self::Primitive, slices: &[Slice]) -> Self::Primitive {
+        B::bool_slice_with_steps(tensor, slices)
+    }
+
+    fn slice_assign_with_steps(
+        tensor: Self::Primitive,
+        slices: &[Slice],
+        value: Self::Primitive,
+    ) -> Self::Primitive {
+        B::bool_slice_assign_with_steps(tensor, slices, value)
+    }
+
     fn device(tensor: &Self::Primitive) -> Device<B> {
         B::bool_device(tensor)
     }
@@ -2963,6 +3234,15 @@ pub trait RangesArg<const D2: usize> {
     /// Converts into a set of ranges to `[core::ops::Range<usize>; D2]` for the `tensor.slice()` function
     fn into_ranges(self, shape: Shape) -> [core::ops::Range<usize>; D2];
 
+    /// Converts into a set of [slices](Slice), which can have steps.
+    fn into_slices(self, shape: Shape) -> [Slice; D2]
+    where
+        Self: Sized,
+    {
+        self.into_ranges(shape)
+            .map(|range| Slice::new(range.start as isize, Some(range.end as isize), 1))
+    }
+
     /// Handles negative index values
     fn handle_negative_index(start: i64, end: i64, dim: usize) -> (usize, usize) {
         let start = if start < 0 {
@@ -3036,6 +3316,36 @@ impl<const D2: usize> RangesArg<D2> for [(i64, i64); D2] {
     }
 }
 
+impl<const D2: usize> RangesArg<D2> for [Slice; D2] {
+    fn into_ranges(self, shape: Shape) -> [core::ops::Range<usize>; D2] {
+        slices_to_ranges(&shape, &self).try_into().unwrap()
+    }
+
+    fn into_slices(self, _shape: Shape) -> [Slice; D2] {
+        self
+    }
+}
+
+/// The ranges covered by the slices, with negative indices resolved and clamped to the shape.
+fn slices_to_ranges(shape: &Shape, slices: &[Slice]) -> Vec<core::ops::Range<usize>> {
+    slices
+        .iter()
+        .enumerate()
+        .map(|(i, slice)| slice.to_range(shape.dims[i]))
+        .collect()
+}
+
+/// The slices with their ranges resolved, as expected by the backends.
+fn normalize_slices(ranges: &[core::ops::Range<usize>], slices: &[Slice]) -> Vec<Slice> {
+    ranges
+        .iter()
+        .zip(slices)
+        .map(|(range, slice)| {
+            Slice::new(range.start as isize, Some(range.end as isize), slice.step)
+        })
+        .collect()
+}
+
 impl RangesArg<1> for core::ops::Range<usize> {
     fn into_ranges(self, shape: Shape) -> [core::ops::Range<usize>; 1] {
         let (start, end) = Self::clamp_range(self.start, self.end, shape.dims[0]);
@@ -1,4 +1,7 @@
-use crate::{backend::Backend, cast::ToElement, BasicOps, Numeric, Shape, Tensor};
+use crate::ops::InterpolateMode;
+use crate::{
+    backend::Backend, cast::ToElement, BasicOps, Int, Numeric, PadMode, Shape, Slice, Tensor,
+};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec;
@@ -568,6 +571,71 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn roll(rank: usize, dims: &[usize]) -> Self {
+        let check = Self::Ok;
+
+        if let Some(dim) = dims.iter().find(|&dim| *dim >= rank) {
+            return check.register(
+                "Roll",
+                TensorError::new("The dimensions must be smaller than the tensor dimension.")
+                    .details(format!(
+                        "The '{dim}' dimension is greater than {rank} dimensions."
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn diagonal<const D: usize, const D2: usize>(
+        shape: &Shape,
+        offset: i64,
+        dim1: usize,
+        dim2: usize,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if D2 + 1 != D {
+            check = check.register(
+                "Diagonal",
+                TensorError::new("The output should have one dimension less than the input.")
+                    .details(format!(
+                        "The input has ({D}) dimensions, but the output has ({D2}) dimensions."
+                    )),
+            );
+        }
+
+        if dim1 >= D || dim2 >= D || dim1 == dim2 {
+            return check.register(
+                "Diagonal",
+                TensorError::new(
+                    "The dimensions must be distinct and smaller than the tensor dimension.",
+                )
+                .details(format!(
+                    "Provided dimensions ({dim1}, {dim2}) for a tensor with ({D}) dimensions."
+                )),
+            );
+        }
+
+        let size = match offset < 0 {
+            true => shape.dims[dim1],
+            false => shape.dims[dim2],
+        };
+
+        if offset.unsigned_abs() as usize >= size {
+            check = check.register(
+                "Diagonal",
+                TensorError::new("The offset selects an empty diagonal.").details(format!(
+                    "The offset ({offset}) is out of bounds for the dimensions ({dim1}, {dim2}) \
+                     of the tensor with shape {:?}.",
+                    shape.dims
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn matmul<B: Backend, const D: usize>(
         lhs: &Tensor<B, D>,
         rhs: &Tensor<B, D>,
@@ -713,7 +781,7 @@ impl TensorCheck {
 
     pub(crate) fn slice<const D1: usize, const D2: usize>(
         shape: &Shape,
-        ranges: &[Range<usize>; D2],
+        ranges: &[Range<usize>],
     ) -> Self {
         let mut check = Self::Ok;
         let n_dims_tensor = D1;
@@ -772,10 +840,26 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn slice_steps(ops: &str, slices: &[Slice]) -> Self {
+        let mut check = Self::Ok;
+
+        if let Some(dim) = slices.iter().position(|slice| slice.step == 0) {
+            check = check.register(
+                ops,
+                TensorError::new("The provided slices have a step of zero.").details(format!(
+                    "The slice at dimension '{dim}' has a step of zero. Provided slices {slices:?}."
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn slice_assign<const D1: usize, const D2: usize>(
         shape: &Shape,
         shape_value: &Shape,
-        ranges: &[Range<usize>; D2],
+        ranges: &[Range<usize>],
+        slices: &[Slice; D2],
     ) -> Self {
         let mut check = Self::Ok;
 
@@ -813,7 +897,12 @@ impl TensorCheck {
                 );
             }
 
-            if range.end - range.start != d_tensor_value {
+            let num_elements = match slices.get(i).unwrap().step {
+                0 => range.len(),
+                step => range.len().div_ceil(step.unsigned_abs()),
+            };
+
+            if range.end > range.start && num_elements != d_tensor_value {
                 check = check.register(
                     "Slice Assign",
                     TensorError::new(
@@ -855,6 +944,297 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn take_along_dim<const D: usize>(
+        dim: usize,
+        shape: &Shape,
+        shape_indices: &Shape,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            return check.register(
+                "Take Along Dim",
+                TensorError::new(format!(
+                    "Can't index a tensor with ({D}) dimensions on axis ({dim})"
+                )),
+            );
+        }
+
+        for d in (0..D).filter(|d| *d != dim) {
+            let size = shape.dims[d];
+            let size_indices = shape_indices.dims[d];
+
+            if size != size_indices && size != 1 && size_indices != 1 {
+                check = check.register(
+                    "Take Along Dim",
+                    TensorError::new("The tensor and the indices can't be broadcast together.")
+                        .details(format!(
+                            "The dimension ({d}) has size ({size}) for the tensor and \
+                             ({size_indices}) for the indices. Tensor shape {:?}, indices shape \
+                             {:?}.",
+                            shape.dims, shape_indices.dims
+                        )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn take<const D: usize, const DI: usize, const DO: usize>(dim: usize) -> Self {
+        let mut check = Self::check_select_basic::<D>(Self::Ok, "take", dim);
+
+        if DO + 1 != D + DI {
+            check = check.register(
+                "Take",
+                TensorError::new("The output rank doesn't match the tensor and indices ranks.")
+                    .details(format!(
+                        "The output should have ({}) dimensions for a tensor with ({D}) \
+                         dimensions and indices with ({DI}) dimensions, got ({DO}).",
+                        D + DI - 1
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn searchsorted<const D: usize>(shape_sorted: &Shape, shape_values: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_sorted.dims[..D - 1] != shape_values.dims[..D - 1] {
+            check = check.register(
+                "Searchsorted",
+                TensorError::new(
+                    "The sorted sequences and the values should have the same leading dimensions.",
+                )
+                .details(format!(
+                    "Sorted shape {:?}, values shape {:?}.",
+                    shape_sorted.dims, shape_values.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn bincount(shape: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if shape.num_dims() != 1 {
+            check = check.register(
+                "Bincount",
+                TensorError::new("The tensor should have a single dimension.")
+                    .details(format!("Tensor shape {:?}.", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn bincount_weighted(shape: &Shape, shape_weights: &Shape) -> Self {
+        let mut check = Self::bincount(shape);
+
+        if shape != shape_weights {
+            check = check.register(
+                "Bincount",
+                TensorError::new("The weights should have the same shape as the tensor.").details(
+                    format!(
+                        "Tensor shape {:?}, weights shape {:?}.",
+                        shape.dims, shape_weights.dims
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn histc(bins: usize, min: f64, max: f64) -> Self {
+        let mut check = Self::Ok;
+
+        if bins == 0 {
+            check = check.register(
+                "Histc",
+                TensorError::new("The number of bins should be greater than zero."),
+            );
+        }
+
+        if min > max {
+            check = check.register(
+                "Histc",
+                TensorError::new("The lower bound should not be greater than the upper bound.")
+                    .details(format!("Lower bound ({min}), upper bound ({max}).")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn interpolate(ops: &str, mode: &InterpolateMode, shape: &Shape) -> Self {
+        let mut check = Self::Ok;
+        let num_spatial_dims = shape.num_dims() - 2;
+
+        let supported = match mode {
+            InterpolateMode::Nearest | InterpolateMode::Bicubic => true,
+            InterpolateMode::Linear | InterpolateMode::Bilinear => num_spatial_dims == 2,
+            InterpolateMode::Trilinear => num_spatial_dims == 3,
+        };
+
+        if !supported {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The {mode:?} mode doesn't support {num_spatial_dims} spatial dimensions."
+                )),
+            );
+        }
+
+        if shape.dims[2..].contains(&0) {
+            check = check.register(
+                ops,
+                TensorError::new("The spatial dimensions of the input should not be empty.")
+                    .details(format!("Input shape {:?}.", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn grouped_matmul(lhs: &Shape, rhs: &Shape, offsets: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if lhs.dims[1] != rhs.dims[1] {
+            check = check.register(
+                "Grouped Matmul",
+                TensorError::new("The inner dimension of lhs and rhs should be the same.").details(
+                    format!("Lhs shape {:?}, rhs shape {:?}.", lhs.dims, rhs.dims),
+                ),
+            );
+        }
+
+        if offsets.dims[0] != rhs.dims[0] {
+            check = check.register(
+                "Grouped Matmul",
+                TensorError::new("Each group should have an offset.").details(format!(
+                    "{} groups, {} offsets.",
+                    rhs.dims[0], offsets.dims[0]
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn sparse_coo<const D: usize>(
+        shape_indices: &Shape,
+        shape_values: &Shape,
+        shape: &Shape,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_indices.dims[0] != D || shape.num_dims() != D {
+            check = check.register(
+                "Sparse",
+                TensorError::new(
+                    "The COO indices should have one row per dimension of the sparse tensor.",
+                )
+                .details(format!(
+                    "Indices shape {:?}, sparse shape {:?}, expected {D} dimensions.",
+                    shape_indices.dims, shape.dims
+                )),
+            );
+        }
+
+        if shape_indices.dims[1] != shape_values.dims[0] {
+            check = check.register(
+                "Sparse",
+                TensorError::new("The COO indices should have one column per value.").details(
+                    format!(
+                        "Indices shape {:?}, values shape {:?}.",
+                        shape_indices.dims, shape_values.dims
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn sparse_csr(
+        shape_crow_indices: &Shape,
+        shape_col_indices: &Shape,
+        shape_values: &Shape,
+        shape: &Shape,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if shape.num_dims() != 2 {
+            check = check.register(
+                "Sparse",
+                TensorError::new("The CSR layout is only supported for matrices.")
+                    .details(format!("Sparse shape {:?}.", shape.dims)),
+            );
+
+            return check;
+        }
+
+        if shape_crow_indices.dims[0] != shape.dims[0] + 1 {
+            check = check.register(
+                "Sparse",
+                TensorError::new("The CSR row offsets should have one more element than rows.")
+                    .details(format!(
+                        "Row offsets shape {:?}, sparse shape {:?}.",
+                        shape_crow_indices.dims, shape.dims
+                    )),
+            );
+        }
+
+        if shape_col_indices.dims[0] != shape_values.dims[0] {
+            check = check.register(
+                "Sparse",
+                TensorError::new("The CSR column indices should have one element per value.")
+                    .details(format!(
+                        "Column indices shape {:?}, values shape {:?}.",
+                        shape_col_indices.dims, shape_values.dims
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn same_shape(ops: &str, lhs: &Shape, rhs: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if lhs != rhs {
+            check = check.register(
+                ops,
+                TensorError::new("The tensors should have the same shape.").details(format!(
+                    "Lhs shape {:?}, rhs shape {:?}.",
+                    lhs.dims, rhs.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn spmm(lhs: &Shape, rhs: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if lhs.dims[1] != rhs.dims[0] {
+            check = check.register(
+                "Spmm",
+                TensorError::new("The inner dimensions of the operands should match.").details(
+                    format!("Sparse shape {:?}, dense shape {:?}.", lhs.dims, rhs.dims),
+                ),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn gather<const D: usize>(dim: usize, shape: &Shape, shape_indices: &Shape) -> Self {
         Self::check_gather_scatter_indices::<D>(Self::Ok, "Gather", dim, shape, shape_indices)
     }
@@ -894,6 +1274,51 @@ impl TensorCheck {
         Self::check_select_basic::<D>(Self::Ok, "select_assign", dim)
     }
 
+    pub(crate) fn index_put<B: Backend, const D: usize, const DV: usize>(
+        num_indices: usize,
+        indices: &[Tensor<B, 1, Int>],
+        shape: &Shape,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if num_indices == 0 || num_indices > D {
+            check = check.register(
+                "Index Put",
+                TensorError::new(format!(
+                    "Can't index a tensor with ({D}) dimensions using ({num_indices}) index tensors."
+                )),
+            );
+            return check;
+        }
+
+        if DV != D - num_indices + 1 {
+            check = check.register(
+                "Index Put",
+                TensorError::new(format!(
+                    "The values should have ({}) dimensions, got ({DV}).",
+                    D - num_indices + 1
+                ))
+                .details(format!(
+                    "The values of a tensor with ({D}) dimensions indexed with ({num_indices}) \
+                     index tensors have one dimension for the indexed positions followed by the \
+                     ({}) remaining dimensions.",
+                    D - num_indices
+                )),
+            );
+        }
+
+        let num_positions = indices[0].dims()[0];
+        if indices.iter().any(|index| index.dims()[0] != num_positions) {
+            check = check.register(
+                "Index Put",
+                TensorError::new("All index tensors should have the same size.")
+                    .details(format!("Shape of the tensor: {:?}", shape.dims)),
+            );
+        }
+
+        check
+    }
+
     fn check_select_basic<const D: usize>(mut check: Self, ops: &str, dim: usize) -> Self {
         if dim > D {
             check = check.register(
@@ -1002,6 +1427,43 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn pad<const D: usize>(
+        shape: &Shape,
+        padding: &[(usize, usize)],
+        mode: PadMode,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if padding.len() > D {
+            check = check.register(
+                "Pad",
+                TensorError::new("The padding can't have more dimensions than the tensor.")
+                    .details(format!(
+                        "Padding of ({}) dimensions for a tensor with ({D}) dimensions.",
+                        padding.len()
+                    )),
+            );
+
+            return check;
+        }
+
+        let padded_dims = &shape.dims[D - padding.len()..];
+        let is_empty = padding
+            .iter()
+            .zip(padded_dims)
+            .any(|(&padding, &size)| padding != (0, 0) && size == 0);
+
+        if !matches!(mode, PadMode::Constant(_)) && is_empty {
+            check = check.register(
+                "Pad",
+                TensorError::new("Only the constant mode can pad an empty dimension.")
+                    .details(format!("Shape {:?}, padding {padding:?}.", shape.dims)),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn sort_dim<const D: usize>(ops: &str, dim: usize) -> Self {
         let mut check = Self::Ok;
 
@@ -0,0 +1,340 @@
+use crate::{
+    backend::{AutodiffBackend, Backend},
+    check,
+    check::TensorCheck,
+    Device, ReshapeArgs, Shape, Tensor, TensorData, TensorPrimitive,
+};
+
+/// A tensor of complex numbers, stored as the float tensors of its real and imaginary parts.
+///
+/// Since the parts are regular float tensors, every backend supports complex tensors, and the
+/// gradients computed by autodiff flow through both parts. The gradient of a real valued loss
+/// `L` with respect to a complex tensor `z = x + iy` is returned as `dL/dx + i dL/dy`, which is
+/// twice the conjugate Wirtinger derivative `dL/dz*`, the steepest ascent direction of `L`.
+///
+/// The data of a complex tensor uses the [Complex32](crate::DType::Complex32) or the
+/// [Complex64](crate::DType::Complex64) data type, where the parts are interleaved.
+#[derive(Debug, Clone)]
+pub struct ComplexTensor<B: Backend, const D: usize> {
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+}
+
+impl<B: Backend, const D: usize> ComplexTensor<B, D> {
+    /// Creates a complex tensor from its real and imaginary parts.
+    ///
+    /// # Panics
+    ///
+    /// If the parts don't have the same shape.
+    pub fn new(real: Tensor<B, D>, imag: Tensor<B, D>) -> Self {
+        check!(TensorCheck::same_shape(
+            "Complex",
+            &real.shape(),
+            &imag.shape()
+        ));
+
+        Self { real, imag }
+    }
+
+    /// Creates a complex tensor with a zero imaginary part.
+    pub fn from_real(real: Tensor<B, D>) -> Self {
+        let imag = real.zeros_like();
+
+        Self { real, imag }
+    }
+
+    /// Creates a complex tensor from the magnitudes and the phases of its values.
+    pub fn from_polar(magnitude: Tensor<B, D>, phase: Tensor<B, D>) -> Self {
+        let real = magnitude.clone() * phase.clone().cos();
+        let imag = magnitude * phase.sin();
+
+        Self::new(real, imag)
+    }
+
+    /// Creates a complex tensor filled with zeros.
+    pub fn zeros<S: Into<Shape>>(shape: S, device: &Device<B>) -> Self {
+        Self::from_real(Tensor::zeros(shape, device))
+    }
+
+    /// Creates a complex tensor from complex data.
+    ///
+    /// # Panics
+    ///
+    /// If the data type is not [Complex32](crate::DType::Complex32) or
+    /// [Complex64](crate::DType::Complex64).
+    pub fn from_data<T: Into<TensorData>>(data: T, device: &Device<B>) -> Self {
+        let (real, imag) = data.into().into_complex_parts();
+
+        Self::new(
+            Tensor::from_data(real, device),
+            Tensor::from_data(imag, device),
+        )
+    }
+
+    /// Converts the complex tensor into complex data.
+    ///
+    /// The data type is [Complex64](crate::DType::Complex64) when the float elements of the
+    /// backend are `f64`, and [Complex32](crate::DType::Complex32) otherwise.
+    pub fn into_data(self) -> TensorData {
+        TensorData::complex(self.real.into_data(), self.imag.into_data())
+    }
+
+    /// Returns the complex data of the tensor.
+    pub fn to_data(&self) -> TensorData {
+        self.clone().into_data()
+    }
+
+    /// Returns the real part of the tensor.
+    pub fn real(&self) -> Tensor<B, D> {
+        self.real.clone()
+    }
+
+    /// Returns the imaginary part of the tensor.
+    pub fn imag(&self) -> Tensor<B, D> {
+        self.imag.clone()
+    }
+
+    /// Returns the real and the imaginary parts of the tensor.
+    pub fn into_parts(self) -> (Tensor<B, D>, Tensor<B, D>) {
+        (self.real, self.imag)
+    }
+
+    /// Returns the shape of the tensor.
+    pub fn shape(&self) -> Shape {
+        self.real.shape()
+    }
+
+    /// Returns the dimensions of the tensor.
+    pub fn dims(&self) -> [usize; D] {
+        self.real.dims()
+    }
+
+    /// Returns the device of the tensor.
+    pub fn device(&self) -> Device<B> {
+        self.real.device()
+    }
+
+    /// Returns the complex conjugate of the tensor.
+    pub fn conj(self) -> Self {
+        Self {
+            real: self.real,
+            imag: -self.imag,
+        }
+    }
+
+    /// Returns the magnitude of each value.
+    pub fn abs(self) -> Tensor<B, D> {
+        self.real.hypot(self.imag)
+    }
+
+    /// Returns the phase of each value, in the range `[-pi, pi]`.
+    pub fn angle(self) -> Tensor<B, D> {
+        self.imag.atan2(self.real)
+    }
+
+    /// Multiplies each value by a real scalar.
+    pub fn mul_scalar(self, scalar: f64) -> Self {
+        Self {
+            real: self.real.mul_scalar(scalar),
+            imag: self.imag.mul_scalar(scalar),
+        }
+    }
+
+    /// Divides each value by a real scalar.
+    pub fn div_scalar(self, scalar: f64) -> Self {
+        Self {
+            real: self.real.div_scalar(scalar),
+            imag: self.imag.div_scalar(scalar),
+        }
+    }
+
+    /// Multiplies each value by the value of a real tensor.
+    pub fn mul_real(self, other: Tensor<B, D>) -> Self {
+        Self::new(self.real * other.clone(), self.imag * other)
+    }
+
+    /// Returns the complex exponential of each value.
+    pub fn exp(self) -> Self {
+        Self::from_polar(self.real.exp(), self.imag)
+    }
+
+    /// Returns the principal value of the complex logarithm of each value.
+    pub fn log(self) -> Self {
+        let real = self.clone().abs().log();
+        let imag = self.angle();
+
+        Self { real, imag }
+    }
+
+    /// Returns the reciprocal of each value.
+    pub fn recip(self) -> Self {
+        let norm = self.clone().norm_sqr();
+
+        Self {
+            real: self.real / norm.clone(),
+            imag: -self.imag / norm,
+        }
+    }
+
+    /// Returns the squared magnitude of each value.
+    pub fn norm_sqr(self) -> Tensor<B, D> {
+        self.real.powi_scalar(2) + self.imag.powi_scalar(2)
+    }
+
+    /// Performs the complex matrix multiplication between two tensors.
+    ///
+    /// Backends without a complex matrix multiplication compute the product with four real
+    /// matrix multiplications.
+    pub fn matmul(self, other: Self) -> Self {
+        check!(TensorCheck::matmul(&self.real, &other.real));
+
+        let (real, imag) = B::float_complex_matmul(
+            self.real.primitive.tensor(),
+            self.imag.primitive.tensor(),
+            other.real.primitive.tensor(),
+            other.imag.primitive.tensor(),
+        );
+
+        Self {
+            real: Tensor::new(TensorPrimitive::Float(real)),
+            imag: Tensor::new(TensorPrimitive::Float(imag)),
+        }
+    }
+
+    /// Sums all the values of the tensor.
+    pub fn sum(self) -> ComplexTensor<B, 1> {
+        ComplexTensor {
+            real: self.real.sum(),
+            imag: self.imag.sum(),
+        }
+    }
+
+    /// Sums the values of the tensor along the given dimension, keeping the dimension.
+    pub fn sum_dim(self, dim: usize) -> Self {
+        Self {
+            real: self.real.sum_dim(dim),
+            imag: self.imag.sum_dim(dim),
+        }
+    }
+
+    /// Reshapes the tensor.
+    pub fn reshape<const D2: usize, S: ReshapeArgs<D2> + Clone>(
+        self,
+        shape: S,
+    ) -> ComplexTensor<B, D2> {
+        ComplexTensor {
+            real: self.real.reshape(shape.clone()),
+            imag: self.imag.reshape(shape),
+        }
+    }
+
+    /// Swaps two dimensions of the tensor.
+    pub fn swap_dims(self, dim1: usize, dim2: usize) -> Self {
+        Self {
+            real: self.real.swap_dims(dim1, dim2),
+            imag: self.imag.swap_dims(dim1, dim2),
+        }
+    }
+
+    /// Transposes the last two dimensions of the tensor.
+    pub fn transpose(self) -> Self {
+        Self {
+            real: self.real.transpose(),
+            imag: self.imag.transpose(),
+        }
+    }
+
+    /// Returns the conjugate transpose of the tensor.
+    pub fn adjoint(self) -> Self {
+        self.transpose().conj()
+    }
+
+    /// Detaches the tensor from the autodiff graph.
+    pub fn detach(self) -> Self {
+        Self {
+            real: self.real.detach(),
+            imag: self.imag.detach(),
+        }
+    }
+
+    /// Marks both parts of the tensor as requiring gradients.
+    pub fn require_grad(self) -> Self {
+        Self {
+            real: self.real.require_grad(),
+            imag: self.imag.require_grad(),
+        }
+    }
+}
+
+impl<B: AutodiffBackend, const D: usize> ComplexTensor<B, D> {
+    /// Returns the gradient of the tensor, as `dL/dx + i dL/dy` for `z = x + iy`.
+    ///
+    /// A part that doesn't contribute to the loss has a zero gradient, and `None` is returned
+    /// when neither part has a gradient.
+    pub fn grad(&self, grads: &B::Gradients) -> Option<ComplexTensor<B::InnerBackend, D>> {
+        match (self.real.grad(grads), self.imag.grad(grads)) {
+            (None, None) => None,
+            (Some(real), None) => Some(ComplexTensor::from_real(real)),
+            (None, Some(imag)) => Some(ComplexTensor::new(imag.zeros_like(), imag)),
+            (Some(real), Some(imag)) => Some(ComplexTensor::new(real, imag)),
+        }
+    }
+
+    /// Returns the inner tensor without the autodiff information.
+    pub fn inner(self) -> ComplexTensor<B::InnerBackend, D> {
+        ComplexTensor {
+            real: self.real.inner(),
+            imag: self.imag.inner(),
+        }
+    }
+}
+
+impl<B: Backend, const D: usize> core::ops::Add for ComplexTensor<B, D> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.real + rhs.real, self.imag + rhs.imag)
+    }
+}
+
+impl<B: Backend, const D: usize> core::ops::Sub for ComplexTensor<B, D> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.real - rhs.real, self.imag - rhs.imag)
+    }
+}
+
+impl<B: Backend, const D: usize> core::ops::Mul for ComplexTensor<B, D> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let real = self.real.clone() * rhs.real.clone() - self.imag.clone() * rhs.imag.clone();
+        let imag = self.real * rhs.imag + self.imag * rhs.real;
+
+        Self::new(real, imag)
+    }
+}
+
+impl<B: Backend, const D: usize> core::ops::Div for ComplexTensor<B, D> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        let norm = rhs.clone().norm_sqr();
+        let real = self.real.clone() * rhs.real.clone() + self.imag.clone() * rhs.imag.clone();
+        let imag = self.imag * rhs.real - self.real * rhs.imag;
+
+        Self::new(real / norm.clone(), imag / norm)
+    }
+}
+
+impl<B: Backend, const D: usize> core::ops::Neg for ComplexTensor<B, D> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            real: -self.real,
+            imag: -self.imag,
+        }
+    }
+}
@@ -0,0 +1,393 @@
+use crate::{backend::Backend, ops::FloatTensor, Shape, Tensor, TensorMetadata, TensorPrimitive};
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+
+/// An operand of [einsum](einsum), which can be created from a float tensor of any rank.
+///
+/// Operands of different ranks can be given to [einsum](einsum) by converting them first:
+///
+/// ```rust
+/// use burn_tensor::{backend::Backend, einsum, EinsumOperand, Tensor};
+///
+/// fn matvec<B: Backend>(matrix: Tensor<B, 2>, vector: Tensor<B, 1>) -> Tensor<B, 1> {
+///     einsum("ij,j->i", [EinsumOperand::from(matrix), vector.into()])
+/// }
+/// ```
+pub struct EinsumOperand<B: Backend> {
+    tensor: FloatTensor<B>,
+}
+
+impl<B: Backend, const D: usize> From<Tensor<B, D>> for EinsumOperand<B> {
+    fn from(tensor: Tensor<B, D>) -> Self {
+        Self {
+            tensor: tensor.into_primitive().tensor(),
+        }
+    }
+}
+
+/// Evaluates the Einstein summation convention on the operands.
+///
+/// The equation lists the subscripts of each operand separated by commas, optionally followed by
+/// `->` and the subscripts of the output. Each subscript is a letter naming a dimension:
+///
+/// - Labels appearing in the output are kept, in the order of the output subscripts.
+/// - Labels not appearing in the output are summed over.
+/// - A label repeated in the same operand takes the diagonal of those dimensions.
+///
+/// Without `->`, the output is made of the labels appearing only once, in alphabetical order.
+/// Ellipsis (`...`) isn't supported.
+///
+/// With three or more operands, the operands are contracted two at a time, greedily choosing the
+/// pair producing the smallest intermediate tensor. Every contraction is lowered to
+/// `permute`, `reshape`, `sum_dim` and a batched `matmul`, so it is supported by all backends
+/// and autodiff.
+///
+/// # Arguments
+///
+/// * `equation` - The subscripts of the operands and the output, e.g. `"bhqd,bhkd->bhqk"`.
+/// * `operands` - The tensors, in the order of the equation.
+///
+/// # Returns
+///
+/// The result, with a single element when the output has no subscript.
+///
+/// # Panics
+///
+/// If the equation is invalid, doesn't match the number of operands or their ranks, if a label
+/// has different sizes in different dimensions, or if `D` isn't the number of output subscripts.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::{backend::Backend, einsum, Tensor};
+///
+/// fn attention_scores<B: Backend>(q: Tensor<B, 4>, k: Tensor<B, 4>) -> Tensor<B, 4> {
+///     einsum("bhqd,bhkd->bhqk", [q, k])
+/// }
+/// ```
+pub fn einsum<B, const D: usize, I>(equation: &str, operands: I) -> Tensor<B, D>
+where
+    B: Backend,
+    I: IntoIterator,
+    I::Item: Into<EinsumOperand<B>>,
+{
+    let equation = Equation::parse(equation);
+    let operands: Vec<FloatTensor<B>> = operands
+        .into_iter()
+        .map(|operand| operand.into().tensor)
+        .collect();
+
+    assert_eq!(
+        operands.len(),
+        equation.inputs.len(),
+        "Einsum equation has {} operands, but {} tensors were given.",
+        equation.inputs.len(),
+        operands.len()
+    );
+    assert_eq!(
+        D,
+        usize::max(equation.output.len(), 1),
+        "Einsum output has {} dimensions, but the result rank is {D}.",
+        equation.output.len(),
+    );
+
+    let mut sizes = BTreeMap::new();
+    let mut operands = operands
+        .into_iter()
+        .zip(equation.inputs)
+        .map(|(tensor, labels)| Operand::<B>::new(tensor, labels, &mut sizes).diagonal())
+        .collect::<Vec<_>>();
+
+    while operands.len() > 1 {
+        let (lhs, rhs) = best_pair(&operands, &equation.output, &sizes);
+        let rhs = operands.remove(rhs);
+        let lhs = operands.remove(lhs);
+
+        let keep = kept_labels(&operands, &equation.output);
+        operands.push(lhs.contract(rhs, &keep, &sizes));
+    }
+
+    let operand = operands
+        .pop()
+        .unwrap()
+        .sum_labels(|label| equation.output.contains(&label), &sizes);
+    let operand = operand.permute(&equation.output);
+
+    Tensor::from_primitive(TensorPrimitive::Float(operand.tensor))
+}
+
+/// The parsed subscripts of an einsum equation.
+struct Equation {
+    inputs: Vec<Vec<char>>,
+    output: Vec<char>,
+}
+
+impl Equation {
+    fn parse(equation: &str) -> Self {
+        let equation: String = equation.chars().filter(|c| !c.is_whitespace()).collect();
+
+        if equation.contains("...") {
+            panic!("Ellipsis isn't supported in einsum equations: {equation}");
+        }
+
+        let (inputs, output) = match equation.split_once("->") {
+            Some((inputs, output)) => (inputs, Some(output)),
+            None => (equation.as_str(), None),
+        };
+
+        let inputs: Vec<Vec<char>> = inputs
+            .split(',')
+            .map(|subscripts| subscripts.chars().collect())
+            .collect();
+
+        for label in inputs.iter().flatten() {
+            if !label.is_ascii_alphabetic() {
+                panic!("Invalid label '{label}' in einsum equation: {equation}");
+            }
+        }
+
+        let output: Vec<char> = match output {
+            Some(output) => output.chars().collect(),
+            None => {
+                // Implicit output: the labels appearing once, in alphabetical order.
+                let mut counts = BTreeMap::new();
+                for label in inputs.iter().flatten() {
+                    *counts.entry(*label).or_insert(0) += 1;
+                }
+                counts
+                    .into_iter()
+                    .filter(|(_, count)| *count == 1)
+                    .map(|(label, _)| label)
+                    .collect()
+            }
+        };
+
+        for (i, label) in output.iter().enumerate() {
+            if output[..i].contains(label) {
+                panic!("Label '{label}' is repeated in the output of einsum equation: {equation}");
+            }
+            if !inputs.iter().flatten().any(|l| l == label) {
+                panic!(
+                    "Output label '{label}' doesn't appear in the operands of einsum equation: {equation}"
+                );
+            }
+        }
+
+        Self { inputs, output }
+    }
+}
+
+/// A tensor with a label for each of its dimensions.
+///
+/// An operand without labels holds a single element, stored with the shape `[1]` since tensors
+/// can't have a rank of zero.
+struct Operand<B: Backend> {
+    tensor: FloatTensor<B>,
+    labels: Vec<char>,
+}
+
+impl<B: Backend> Operand<B> {
+    fn new(tensor: FloatTensor<B>, labels: Vec<char>, sizes: &mut BTreeMap<char, usize>) -> Self {
+        let dims = tensor.shape().dims;
+        let subscripts: String = labels.iter().collect();
+
+        assert_eq!(
+            dims.len(),
+            labels.len(),
+            "Einsum subscripts '{subscripts}' don't match the rank of the operand {dims:?}."
+        );
+
+        for (label, size) in labels.iter().zip(dims) {
+            let expected = *sizes.entry(*label).or_insert(size);
+            assert_eq!(
+                expected, size,
+                "Einsum label '{label}' has different sizes: {expected} and {size}."
+            );
+        }
+
+        Self { tensor, labels }
+    }
+
+    fn dims(&self, sizes: &BTreeMap<char, usize>) -> Vec<usize> {
+        self.labels.iter().map(|label| sizes[label]).collect()
+    }
+
+    /// Takes the diagonal of the dimensions with the same label.
+    fn diagonal(mut self) -> Self {
+        while let Some((first, second)) = self.repeated_label() {
+            let dims = self.tensor.shape().dims;
+            let size = dims[first];
+            let device = B::float_device(&self.tensor);
+
+            let mut mask_shape = vec![1; dims.len()];
+            mask_shape[first] = size;
+            mask_shape[second] = size;
+
+            let mask = Tensor::<B, 2>::eye(size, &device).into_primitive().tensor();
+            let mask = B::float_reshape(mask, Shape::from(mask_shape));
+
+            let tensor = B::float_mul(self.tensor, mask);
+            let tensor = B::float_sum_dim(tensor, second);
+
+            let mut dims = dims;
+            dims.remove(second);
+            self.tensor = B::float_reshape(tensor, Shape::from(dims));
+            self.labels.remove(second);
+        }
+
+        self
+    }
+
+    fn repeated_label(&self) -> Option<(usize, usize)> {
+        for (second, label) in self.labels.iter().enumerate() {
+            if let Some(first) = self.labels[..second].iter().position(|l| l == label) {
+                return Some((first, second));
+            }
+        }
+
+        None
+    }
+
+    /// Sums over the dimensions whose label isn't kept.
+    fn sum_labels<F: Fn(char) -> bool>(mut self, keep: F, sizes: &BTreeMap<char, usize>) -> Self {
+        if self.labels.iter().all(|label| keep(*label)) {
+            return self;
+        }
+
+        for (dim, label) in self.labels.iter().enumerate() {
+            if !keep(*label) {
+                self.tensor = B::float_sum_dim(self.tensor, dim);
+            }
+        }
+
+        self.labels.retain(|label| keep(*label));
+        let dims = self.dims(sizes);
+        self.tensor = B::float_reshape(self.tensor, shape(dims));
+        self
+    }
+
+    /// Permutes the dimensions to follow the order of the given labels, which must be the same
+    /// labels as the operand.
+    fn permute(mut self, labels: &[char]) -> Self {
+        if self.labels == labels {
+            return self;
+        }
+
+        let axes: Vec<usize> = labels
+            .iter()
+            .map(|label| self.labels.iter().position(|l| l == label).unwrap())
+            .collect();
+
+        self.tensor = B::float_permute(self.tensor, &axes);
+        self.labels = labels.to_vec();
+        self
+    }
+
+    /// Contracts two operands with a batched matrix multiplication, keeping the dimensions whose
+    /// label is in `keep`.
+    fn contract(self, rhs: Self, keep: &[char], sizes: &BTreeMap<char, usize>) -> Self {
+        // Labels only present in one operand and not kept can be summed right away.
+        let lhs = self.sum_labels(|l| keep.contains(&l) || rhs.labels.contains(&l), sizes);
+        let rhs = rhs.sum_labels(|l| keep.contains(&l) || lhs.labels.contains(&l), sizes);
+
+        let shared = |label: &&char| rhs.labels.contains(label);
+        let batch: Vec<char> = lhs
+            .labels
+            .iter()
+            .filter(shared)
+            .filter(|l| keep.contains(l))
+            .copied()
+            .collect();
+        let contracted: Vec<char> = lhs
+            .labels
+            .iter()
+            .filter(shared)
+            .filter(|l| !keep.contains(l))
+            .copied()
+            .collect();
+        let left: Vec<char> = lhs.labels.iter().filter(|l| !shared(l)).copied().collect();
+        let right: Vec<char> = rhs
+            .labels
+            .iter()
+            .filter(|l| !lhs.labels.contains(l))
+            .copied()
+            .collect();
+
+        let size = |labels: &[char]| labels.iter().map(|label| sizes[label]).product::<usize>();
+        let (size_batch, size_left, size_right, size_contracted) =
+            (size(&batch), size(&left), size(&right), size(&contracted));
+
+        let lhs = lhs.permute(&[batch.as_slice(), &left, &contracted].concat());
+        let rhs = rhs.permute(&[batch.as_slice(), &contracted, &right].concat());
+
+        let lhs_tensor = B::float_reshape(
+            lhs.tensor,
+            Shape::new([size_batch, size_left, size_contracted]),
+        );
+        let rhs_tensor = B::float_reshape(
+            rhs.tensor,
+            Shape::new([size_batch, size_contracted, size_right]),
+        );
+
+        let labels = [batch, left, right].concat();
+        let dims = labels.iter().map(|label| sizes[label]).collect();
+        let tensor = B::float_reshape(B::float_matmul(lhs_tensor, rhs_tensor), shape(dims));
+
+        Self { tensor, labels }
+    }
+}
+
+/// The labels that must be kept when contracting two operands: the ones of the output and of
+/// the other operands.
+fn kept_labels<B: Backend>(others: &[Operand<B>], output: &[char]) -> Vec<char> {
+    let mut keep = output.to_vec();
+    keep.extend(others.iter().flat_map(|operand| operand.labels.iter()));
+    keep
+}
+
+/// Chooses the next pair of operands to contract, the one producing the smallest tensor, then
+/// requiring the fewest multiplications.
+fn best_pair<B: Backend>(
+    operands: &[Operand<B>],
+    output: &[char],
+    sizes: &BTreeMap<char, usize>,
+) -> (usize, usize) {
+    let mut best = None;
+
+    for lhs in 0..operands.len() {
+        for rhs in lhs + 1..operands.len() {
+            let others: Vec<&[char]> = operands
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != lhs && *i != rhs)
+                .map(|(_, operand)| operand.labels.as_slice())
+                .collect();
+
+            let mut labels = operands[lhs].labels.clone();
+            labels.extend(
+                operands[rhs]
+                    .labels
+                    .iter()
+                    .filter(|l| !operands[lhs].labels.contains(l)),
+            );
+
+            let kept =
+                |label: &&char| output.contains(label) || others.iter().any(|o| o.contains(label));
+            let size_output: usize = labels.iter().filter(kept).map(|l| sizes[l]).product();
+            let num_operations: usize = labels.iter().map(|l| sizes[l]).product();
+
+            let cost = (size_output, num_operations);
+            if best.map(|(best_cost, _)| cost < best_cost).unwrap_or(true) {
+                best = Some((cost, (lhs, rhs)));
+            }
+        }
+    }
+
+    best.map(|(_, pair)| pair).unwrap()
+}
+
+fn shape(dims: Vec<usize>) -> Shape {
+    match dims.is_empty() {
+        true => Shape::new([1]),
+        false => Shape::from(dims),
+    }
+}
@@ -2,10 +2,11 @@ use crate::check::TensorCheck;
 use crate::quantization::{QuantizationParameters, QuantizationScheme};
 use crate::tensor::backend::Backend;
 use crate::tensor::stats;
-use crate::tensor::{Distribution, TensorData};
+use crate::tensor::{Distribution, Generator, TensorData};
 use crate::Tensor;
-use crate::{check, FloatDType};
-use crate::{Int, TensorPrimitive};
+use crate::{check, DType, FloatDType};
+use crate::{ElementConversion, Int, TensorPrimitive};
+use half::{bf16, f16};
 
 impl<const D: usize, B> Tensor<B, D>
 where
@@ -131,6 +132,215 @@ where
         )))
     }
 
+    /// Applies element wise truncate operation, rounding the values towards zero.
+    pub fn trunc(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_trunc(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise arcsine operation.
+    pub fn asin(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_asin(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise arccosine operation.
+    pub fn acos(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_acos(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise arctangent operation.
+    pub fn atan(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_atan(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise four-quadrant arctangent of `self / other`.
+    ///
+    /// `y = atan2(x1, x2)`
+    ///
+    /// The result is the angle in radians, in `[-pi, pi]`, between the positive x axis and the
+    /// point `(other, self)`.
+    pub fn atan2(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Atan2", &self, &other));
+        Self::new(TensorPrimitive::Float(B::float_atan2(
+            self.primitive.tensor(),
+            other.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise hyperbolic sine operation.
+    pub fn sinh(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_sinh(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise hyperbolic cosine operation.
+    pub fn cosh(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_cosh(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise inverse hyperbolic sine operation.
+    pub fn asinh(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_asinh(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise inverse hyperbolic cosine operation.
+    pub fn acosh(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_acosh(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise inverse hyperbolic tangent operation.
+    pub fn atanh(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_atanh(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies the exponential minus one, element wise.
+    ///
+    /// `y = exp(x) - 1`
+    ///
+    /// The result is accurate for values close to zero, where `exp(x) - 1` loses precision.
+    pub fn expm1(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_expm1(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies the inverse of the [error function](https://en.wikipedia.org/wiki/Error_function)
+    /// element wise.
+    ///
+    /// `y = erfinv(x)`
+    pub fn erfinv(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_erfinv(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies the natural logarithm of the absolute value of the
+    /// [gamma function](https://en.wikipedia.org/wiki/Gamma_function) element wise.
+    ///
+    /// `y = log(|gamma(x)|)`
+    pub fn lgamma(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_lgamma(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies the [digamma function](https://en.wikipedia.org/wiki/Digamma_function) element
+    /// wise, the logarithmic derivative of the gamma function.
+    ///
+    /// `y = d/dx log(gamma(x))`
+    pub fn digamma(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_digamma(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Computes the hypotenuse of the right triangles with legs `self` and `other`, element wise.
+    ///
+    /// `y = sqrt(x1^2 + x2^2)`
+    pub fn hypot(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Hypot", &self, &other));
+        Self::new(TensorPrimitive::Float(B::float_hypot(
+            self.primitive.tensor(),
+            other.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise the floating point remainder operation.
+    ///
+    /// `y = x1 - trunc(x1 / x2) * x2`
+    ///
+    /// Unlike [remainder](Tensor::remainder), the result has the same sign as the dividend,
+    /// like the C `fmod` function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor1 = Tensor::<B, 1>::from_data([-3.0, -1.0, 1.0, 3.0], &device);
+    ///    let tensor2 = Tensor::<B, 1>::from_data([2.0, 2.0, 2.0, 2.0], &device);
+    ///    let tensor = tensor1.fmod(tensor2);
+    ///    println!("{tensor}");
+    ///    // [-1.0, -1.0, 1.0, 1.0]
+    /// }
+    /// ```
+    pub fn fmod(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Fmod", &self, &other));
+        Self::new(TensorPrimitive::Float(B::float_fmod(
+            self.primitive.tensor(),
+            other.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise the floating point remainder operation with a scalar.
+    ///
+    /// `y = x - trunc(x / s) * s`
+    pub fn fmod_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_fmod_scalar(
+            self.primitive.tensor(),
+            other.elem(),
+        )))
+    }
+
+    /// Replaces the NaN, positive infinity and negative infinity values of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `nan` - The value replacing NaN.
+    /// * `posinf` - The value replacing positive infinity, the largest finite value of the
+    ///   data type when `None`.
+    /// * `neginf` - The value replacing negative infinity, the lowest finite value of the
+    ///   data type when `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 1>::from_data([f32::NAN, f32::INFINITY, -1.0], &device);
+    ///    let tensor = tensor.nan_to_num(0.0, Some(1.0), None);
+    ///    println!("{tensor}");
+    ///    // [0.0, 1.0, -1.0]
+    /// }
+    /// ```
+    pub fn nan_to_num(self, nan: f64, posinf: Option<f64>, neginf: Option<f64>) -> Self {
+        let (min, max) = match self.dtype() {
+            DType::F64 => (f64::MIN, f64::MAX),
+            DType::F16 => (f16::MIN.to_f64(), f16::MAX.to_f64()),
+            DType::BF16 => (bf16::MIN.to_f64(), bf16::MAX.to_f64()),
+            _ => (f32::MIN as f64, f32::MAX as f64),
+        };
+
+        Self::new(TensorPrimitive::Float(B::float_nan_to_num(
+            self.primitive.tensor(),
+            nan.elem(),
+            posinf.unwrap_or(max).elem(),
+            neginf.unwrap_or(min).elem(),
+        )))
+    }
+
     /// Create a tensor from floats (f32) on a given device.
     ///
     /// # Example
@@ -178,6 +388,12 @@ where
         )))
     }
 
+    /// Returns a new tensor with the same shape and device as the current tensor filled random
+    /// values sampled from the given distribution with the given [generator](Generator).
+    pub fn random_like_with(&self, distribution: Distribution, generator: &mut Generator) -> Self {
+        Self::random_with(self.shape(), distribution, generator, &self.device())
+    }
+
     /// Applies the matrix multiplication operation.
     ///
     /// `C = AB`
@@ -343,4 +559,97 @@ where
     pub fn dequantize(self) -> Tensor<B, D> {
         Tensor::new(TensorPrimitive::Float(self.primitive.tensor()))
     }
+
+    /// Computes the histogram of the tensor, with bins of equal width between `min` and `max`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bins` - The number of bins.
+    /// * `min` - The lower bound of the first bin.
+    /// * `max` - The upper bound of the last bin.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements in each bin, the elements outside of the bounds being ignored.
+    ///
+    /// # Notes
+    ///
+    /// When `min` and `max` are equal, the minimum and maximum of the tensor are used as bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 1>::from_data([1.0, 2.0, 1.0, 4.0], &device);
+    ///    let histogram = tensor.histc(4, 0.0, 4.0);
+    ///    println!("{histogram}");
+    ///    // [0.0, 2.0, 1.0, 1.0]
+    /// }
+    /// ```
+    pub fn histc(self, bins: usize, min: f64, max: f64) -> Tensor<B, 1> {
+        check!(TensorCheck::histc(bins, min, max));
+        let (min, max) = self.clone().histogram_bounds(min, max);
+
+        Tensor::new(TensorPrimitive::Float(B::float_histc(
+            self.primitive.tensor(),
+            bins,
+            min,
+            max,
+        )))
+    }
+
+    /// Computes the histogram of the tensor, with bins of equal width between `min` and `max`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bins` - The number of bins.
+    /// * `min` - The lower bound of the first bin.
+    /// * `max` - The upper bound of the last bin.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the number of elements in each bin and the `bins + 1` edges of the bins.
+    ///
+    /// # Notes
+    ///
+    /// When `min` and `max` are equal, the minimum and maximum of the tensor are used as bounds.
+    pub fn histogram(self, bins: usize, min: f64, max: f64) -> (Tensor<B, 1>, Tensor<B, 1>) {
+        check!(TensorCheck::histc(bins, min, max));
+        let (min, max) = self.clone().histogram_bounds(min, max);
+        let device = self.device();
+        let width = (max - min) / bins as f64;
+
+        let edges = Tensor::<B, 1, Int>::arange(0..bins as i64 + 1, &device)
+            .float()
+            .mul_scalar(width)
+            .add_scalar(min);
+
+        (self.histc(bins, min, max), edges)
+    }
+
+    /// The bounds of the histogram, which are the minimum and maximum of the tensor when the given
+    /// bounds are equal.
+    fn histogram_bounds(self, min: f64, max: f64) -> (f64, f64) {
+        if min != max {
+            return (min, max);
+        }
+
+        let (min, max) = match self.shape().num_elements() {
+            0 => (min, max),
+            _ => (
+                self.clone().min().into_scalar().elem::<f64>(),
+                self.max().into_scalar().elem::<f64>(),
+            ),
+        };
+
+        // Like an empty range, a single value is centered in a range of width two.
+        match min == max {
+            true => (min - 1.0, max + 1.0),
+            false => (min, max),
+        }
+    }
 }
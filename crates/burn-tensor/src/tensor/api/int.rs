@@ -1,5 +1,6 @@
 use crate::{
-    backend::Backend, cartesian_grid, Float, Int, Shape, Tensor, TensorData, TensorPrimitive,
+    backend::Backend, cartesian_grid, check, check::TensorCheck, Float, Int, Shape, Tensor,
+    TensorData, TensorPrimitive,
 };
 
 use core::ops::Range;
@@ -27,6 +28,63 @@ where
     pub fn arange_step(range: Range<i64>, step: usize, device: &B::Device) -> Self {
         Tensor::new(B::int_arange_step(range, step, device))
     }
+
+    /// Counts the number of occurrences of each value of a tensor of non-negative integers.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_length` - The minimum length of the output.
+    ///
+    /// # Returns
+    ///
+    /// The number of occurrences of each value, with a length of one more than the maximum value
+    /// and at least `min_length`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 1, Int>::from_ints([1, 3, 1, 0], &device);
+    ///    let counts = tensor.bincount(0);
+    ///    println!("{counts}");
+    ///    // [1, 2, 0, 1]
+    /// }
+    /// ```
+    pub fn bincount(self, min_length: usize) -> Self {
+        check!(TensorCheck::bincount(&self.shape()));
+        Tensor::new(B::int_bincount(self.primitive, min_length))
+    }
+
+    /// Sums the weights of each value of a tensor of non-negative integers.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - The weight of each element of the tensor.
+    /// * `min_length` - The minimum length of the output.
+    ///
+    /// # Returns
+    ///
+    /// The sum of the weights of each value, with a length of one more than the maximum value and
+    /// at least `min_length`.
+    pub fn bincount_weighted(
+        self,
+        weights: Tensor<B, 1, Float>,
+        min_length: usize,
+    ) -> Tensor<B, 1> {
+        check!(TensorCheck::bincount_weighted(
+            &self.shape(),
+            &weights.shape()
+        ));
+        Tensor::new(TensorPrimitive::Float(B::int_bincount_weighted(
+            self.primitive,
+            weights.primitive.tensor(),
+            min_length,
+        )))
+    }
 }
 
 impl<const D: usize, B> Tensor<B, D, Int>
@@ -1,4 +1,4 @@
-use crate::{backend::Backend, DType, Shape};
+use crate::{backend::Backend, DType, Shape, SparsePrimitive};
 
 /// A type-level representation of the kind of a float tensor
 #[derive(Clone, Debug)]
@@ -12,6 +12,10 @@ pub struct Int;
 #[derive(Clone, Debug)]
 pub struct Bool;
 
+/// A type-level representation of the kind of a [sparse](crate::SparseTensor) float tensor.
+#[derive(Clone, Debug)]
+pub struct Sparse;
+
 #[derive(Debug, Clone)]
 /// A primitive tensor representation.
 pub enum TensorPrimitive<B: Backend> {
@@ -85,3 +89,10 @@ impl<B: Backend> TensorKind<B> for Bool {
         "Bool"
     }
 }
+
+impl<B: Backend> TensorKind<B> for Sparse {
+    type Primitive = SparsePrimitive<B>;
+    fn name() -> &'static str {
+        "Sparse"
+    }
+}
@@ -6,23 +6,38 @@ mod base;
 mod bool;
 mod cartesian_grid;
 mod chunk;
+mod complex;
+mod einsum;
 mod float;
 mod int;
 mod kind;
 mod narrow;
 mod numeric;
+mod pad;
+mod scatter_reduce;
+mod slice;
 mod sort;
+mod sparse;
 mod split;
 mod transaction;
+mod unique;
 
 pub use argwhere::argwhere_data;
 pub use autodiff::*;
 pub use base::*;
 pub use cartesian_grid::cartesian_grid;
 pub use chunk::chunk;
+pub use complex::*;
+pub use einsum::{einsum, EinsumOperand};
 pub use kind::*;
 pub use narrow::narrow;
 pub use numeric::*;
+pub use pad::{pad, pad_indices, PadMode};
+pub use scatter_reduce::Reduce;
+pub use slice::{slice_assign_with_steps, slice_with_steps, Slice};
 pub use sort::{argsort, sort, sort_with_indices};
+pub use sparse::*;
 pub use split::{split, split_with_sizes};
 pub use transaction::*;
+pub(crate) use unique::searchsorted_shapes;
+pub use unique::{bincount_length, unique};
@@ -1,15 +1,17 @@
-use alloc::vec::Vec;
+use core::future::Future;
 
 use crate::{alloc::borrow::ToOwned, cast::ToElement};
 
+use crate::try_read_sync;
+
 use crate::TensorPrimitive;
 use crate::{
     backend::Backend,
     check,
     check::TensorCheck,
     ops::{Device, IntTensor},
-    BasicOps, Bool, Distribution, Element, ElementConversion, Float, Int, Shape, Tensor,
-    TensorKind,
+    BasicOps, Bool, Distribution, Element, ElementConversion, Float, Generator, Int, PadMode,
+    Reduce, Shape, Tensor, TensorData, TensorKind,
 };
 
 /// Default RTOL value for `is_close` and `all_close`.
@@ -927,6 +929,91 @@ where
         Self::new(K::gather(dim, self.primitive, indices.primitive))
     }
 
+    /// Gather tensor elements corresponding to the given indices along the specified dim, with
+    /// the indices and the tensor broadcast to the same shape in the other dimensions.
+    ///
+    /// This is the same as [gather](Tensor::gather), except the dimensions of size one of the
+    /// tensor or of the indices are expanded, as with the indices returned by
+    /// [argmax](Tensor::argmax) or [argsort](Tensor::argsort).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///   let device = B::Device::default();
+    ///   let tensor = Tensor::<B, 2>::from_data([[1.0, 5.0, 3.0], [4.0, 2.0, 6.0]], &device);
+    ///   let indices = tensor.clone().argmax(1);
+    ///   let tensor = tensor.take_along_dim(indices, 1);
+    ///   println!("{tensor}");
+    ///   // [[5.0], [6.0]]
+    /// }
+    /// ```
+    pub fn take_along_dim(self, indices: Tensor<B, D, Int>, dim: usize) -> Self {
+        check!(TensorCheck::take_along_dim::<D>(
+            dim,
+            &self.shape(),
+            &indices.shape()
+        ));
+
+        let shape = self.shape();
+        let shape_indices = indices.shape();
+        let mut dims = shape.dims.clone();
+        let mut dims_indices = shape_indices.dims.clone();
+
+        for d in (0..D).filter(|d| *d != dim) {
+            let size = usize::max(shape.dims[d], shape_indices.dims[d]);
+            dims[d] = size;
+            dims_indices[d] = size;
+        }
+
+        let tensor = self.expand(Shape::from(dims));
+        let indices = indices.expand(Shape::from(dims_indices));
+
+        tensor.gather(dim, indices)
+    }
+
+    /// Select the tensor elements along the given dimension corresponding to indices of any rank.
+    ///
+    /// The selected dimension is replaced by the dimensions of the indices, so the output has
+    /// `D - 1 + DI` dimensions. With one dimensional indices, this is the same as
+    /// [select](Tensor::select).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///   let device = B::Device::default();
+    ///   let tensor = Tensor::<B, 2>::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+    ///   let indices = Tensor::<B, 2, Int>::from_data([[0, 2], [1, 1]], &device);
+    ///   let tensor: Tensor<B, 3> = tensor.take(0, indices);
+    ///   println!("{:?}", tensor.dims());
+    ///   // [2, 2, 2]
+    /// }
+    /// ```
+    pub fn take<const DI: usize, const DO: usize>(
+        self,
+        dim: usize,
+        indices: Tensor<B, DI, Int>,
+    ) -> Tensor<B, DO, K> {
+        check!(TensorCheck::take::<D, DI, DO>(dim));
+
+        let shape = self.shape();
+        let shape_indices = indices.shape();
+
+        let mut dims = shape.dims[..dim].to_vec();
+        dims.extend_from_slice(&shape_indices.dims);
+        dims.extend_from_slice(&shape.dims[dim + 1..]);
+
+        self.select(dim, indices.flatten::<1>(0, DI - 1))
+            .reshape(Shape::from(dims))
+    }
+
     /// Assign the gathered elements corresponding to the given indices along the specified dimension
     /// from the value tensor to the original tensor using sum reduction.
     ///
@@ -962,6 +1049,67 @@ where
         ))
     }
 
+    /// Assign the gathered elements corresponding to the given indices along the specified dimension
+    /// from the value tensor to the original tensor, reducing the elements assigned to the same
+    /// position.
+    ///
+    /// Example using a 3D tensor with a sum reduction:
+    ///
+    /// `input[indices[i, j, k], j, k] += values[i, j, k]; // dim = 0`
+    /// `input[i, indices[i, j, k], k] += values[i, j, k]; // dim = 1`
+    /// `input[i, j, indices[i, j, k]] += values[i, j, k]; // dim = 2`
+    ///
+    /// When `include_self` is false, the elements of the input tensor receiving values aren't part
+    /// of the reduction, the other elements being left unchanged.
+    ///
+    /// # Notes
+    ///
+    /// The index tensor should have the same shape as the original tensor except for the specified
+    /// dimension. The value and index tensors should have the same shape.
+    ///
+    /// The [mean](Reduce::Mean) of integers is rounded toward zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Reduce, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///   let device = B::Device::default();
+    ///   let tensor = Tensor::<B, 1>::zeros([2], &device);
+    ///   let indices = Tensor::<B, 1, Int>::from_data([0, 1, 0], &device);
+    ///   let values = Tensor::<B, 1>::from_data([1.0, 2.0, 3.0], &device);
+    ///   let tensor = tensor.scatter_reduce(0, indices, values, Reduce::Max, false);
+    ///   println!("{tensor}");
+    ///   // [3.0, 2.0]
+    /// }
+    /// ```
+    pub fn scatter_reduce(
+        self,
+        dim: usize,
+        indices: Tensor<B, D, Int>,
+        values: Self,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> Self {
+        check!(TensorCheck::scatter::<D>(
+            dim,
+            &self.shape(),
+            &indices.shape(),
+            &values.shape()
+        ));
+
+        Self::new(K::scatter_reduce(
+            dim,
+            self.primitive,
+            indices.primitive,
+            values.primitive,
+            reduce,
+            include_self,
+        ))
+    }
+
     /// Select the tensor elements along the given dimension corresponding to the given indices.
     ///
     /// Example using a 3D tensor:
@@ -1022,6 +1170,97 @@ where
         ))
     }
 
+    /// Put the values at the positions given by one index tensor for each of the first `N`
+    /// dimensions.
+    ///
+    /// Example using a 3D tensor with two index tensors:
+    ///
+    /// `input[indices[0][i], indices[1][i], k] = values[i, k]`
+    ///
+    /// When `accumulate` is true, the values are added to the tensor instead of replacing its
+    /// elements. Values put at the same position are always summed.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - One index tensor for each of the first `N` dimensions, all with the same size.
+    /// * `values` - The values to put, of rank `D - N + 1`, the first dimension matching the size of
+    ///   the index tensors and the others the remaining dimensions of the tensor.
+    /// * `accumulate` - If the values are added to the tensor.
+    ///
+    /// # Warning
+    /// Not all backends have runtime bound checks for the indices, so make sure the they are valid.
+    /// Otherwise, out of bounds indices could lead to unexpected results instead of panicking.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///   let device = B::Device::default();
+    ///   let tensor = Tensor::<B, 2>::zeros([2, 3], &device);
+    ///   let rows = Tensor::<B, 1, Int>::from_data([0, 1], &device);
+    ///   let columns = Tensor::<B, 1, Int>::from_data([2, 0], &device);
+    ///   let values = Tensor::<B, 1>::from_data([5.0, 7.0], &device);
+    ///   let tensor = tensor.index_put([rows, columns], values, false);
+    ///   println!("{tensor}");
+    ///   // [[0.0, 0.0, 5.0], [7.0, 0.0, 0.0]]
+    /// }
+    /// ```
+    pub fn index_put<const N: usize, const DV: usize>(
+        self,
+        indices: [Tensor<B, 1, Int>; N],
+        values: Tensor<B, DV, K>,
+        accumulate: bool,
+    ) -> Self {
+        check!(TensorCheck::index_put::<B, D, DV>(
+            N,
+            &indices,
+            &self.shape()
+        ));
+
+        let shape = self.shape();
+        let device = self.device();
+        let num_rows: usize = shape.dims[..N].iter().product();
+
+        // Linear index of the positions in the first `N` dimensions.
+        let mut stride = 1;
+        let mut rows: Option<Tensor<B, 1, Int>> = None;
+        for (index, size) in indices.into_iter().zip(&shape.dims[..N]).rev() {
+            let index = index.mul_scalar(stride as i64);
+            rows = Some(match rows {
+                Some(rows) => rows.add(index),
+                None => index,
+            });
+            stride *= size;
+        }
+        let rows = rows.expect("At least one index tensor.");
+
+        let mut shape_rows = shape.dims[N - 1..].to_vec();
+        shape_rows[0] = num_rows;
+        let shape_rows = Shape::from(shape_rows);
+        let tensor = self.reshape::<DV, _>(shape_rows.clone());
+
+        let tensor = match accumulate {
+            true => tensor,
+            false => {
+                let mut shape_mask = [1; DV];
+                shape_mask[0] = num_rows;
+
+                let mask = Tensor::<B, 1, Int>::zeros([num_rows], &device)
+                    .select_assign(0, rows.clone(), rows.ones_like())
+                    .greater_elem(0)
+                    .reshape::<DV, _>(shape_mask)
+                    .expand(shape_rows);
+
+                tensor.mask_fill(mask, 0)
+            }
+        };
+
+        tensor.select_assign(0, rows, values).reshape::<D, _>(shape)
+    }
+
     /// Applies the argmax function along the given dimension and returns an integer tensor.
     ///
     /// # Example
@@ -1715,6 +1954,36 @@ where
         Self::new(K::random(shape.into(), distribution, device))
     }
 
+    /// Create a random tensor of the given shape on the given device where each element is
+    /// sampled from the given distribution with the given [generator](Generator).
+    ///
+    /// The values are drawn on the host, so the same generator state produces the same tensor on
+    /// every backend with the same element type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Distribution, Generator, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///   let device = B::Device::default();
+    ///   let mut generator = Generator::new(42);
+    ///   let distribution = Distribution::Normal(0.0, 1.0);
+    ///   let tensor = Tensor::<B, 2>::random_with([2, 3], distribution, &mut generator, &device);
+    ///   println!("{tensor}");
+    /// }
+    /// ```
+    pub fn random_with<S: Into<Shape>>(
+        shape: S,
+        distribution: Distribution,
+        generator: &mut Generator,
+        device: &B::Device,
+    ) -> Self {
+        let data = TensorData::random::<K::Elem, _, _>(shape.into().dims, distribution, generator);
+        Self::from_data(data, device)
+    }
+
     /// Sort the elements by value in ascending order along a given dimension.
     ///
     /// This sort is unstable (i.e., may reorder equal elements).
@@ -1907,6 +2176,161 @@ where
         Tensor::new(K::argsort(self.primitive, dim, /*descending*/ true))
     }
 
+    /// Returns the sorted unique elements of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A 1D tensor with the unique elements in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[3.0, 1.0, 3.0], [2.0, 1.0, 5.0]], &device);
+    ///    let tensor = tensor.unique();
+    ///    println!("{tensor}");
+    ///    // [1.0, 2.0, 3.0, 5.0]
+    /// }
+    /// ```
+    pub fn unique(self) -> Tensor<B, 1, K> {
+        self.unique_with_inverse_and_counts().0
+    }
+
+    /// Returns the sorted unique elements of the tensor, along with the index of each element in
+    /// the unique elements and the number of occurrences of each unique element.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the unique elements in ascending order, the inverse indices with the shape of
+    /// the tensor, and the counts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 1>::from_data([3.0, 1.0, 3.0, 2.0], &device);
+    ///    let (values, inverse, counts) = tensor.unique_with_inverse_and_counts();
+    ///    println!("{values}");
+    ///    // [1.0, 2.0, 3.0]
+    ///    println!("{inverse}");
+    ///    // [2, 0, 2, 1]
+    ///    println!("{counts}");
+    ///    // [1, 1, 2]
+    /// }
+    /// ```
+    pub fn unique_with_inverse_and_counts(
+        self,
+    ) -> (Tensor<B, 1, K>, Tensor<B, D, Int>, Tensor<B, 1, Int>) {
+        try_read_sync(self.unique_with_inverse_and_counts_async()).expect(
+            "Failed to read tensor data synchronously. Try using unique_with_inverse_and_counts_async instead.",
+        )
+    }
+
+    /// Returns the sorted unique elements of the tensor, along with the index of each element in
+    /// the unique elements and the number of occurrences of each unique element.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the unique elements in ascending order, the inverse indices with the shape of
+    /// the tensor, and the counts.
+    pub async fn unique_with_inverse_and_counts_async(
+        self,
+    ) -> (Tensor<B, 1, K>, Tensor<B, D, Int>, Tensor<B, 1, Int>) {
+        let (values, inverse, counts) = K::unique(self.primitive).await;
+
+        (
+            Tensor::new(values),
+            Tensor::new(inverse),
+            Tensor::new(counts),
+        )
+    }
+
+    /// Finds the indices where the values should be inserted in the tensor, sorted along the last
+    /// dimension, to keep it sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values to insert, with the same dimensions as the tensor except the last.
+    /// * `right` - If the index after the elements equal to the value is returned instead of the
+    ///   index before them.
+    ///
+    /// # Returns
+    ///
+    /// The insertion indices with the shape of the values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let sorted = Tensor::<B, 1>::from_data([1.0, 3.0, 3.0, 7.0], &device);
+    ///    let values = Tensor::<B, 1>::from_data([0.0, 3.0, 5.0, 9.0], &device);
+    ///    let indices = sorted.clone().searchsorted(values.clone(), false);
+    ///    println!("{indices}");
+    ///    // [0, 1, 3, 4]
+    ///    let indices = sorted.searchsorted(values, true);
+    ///    println!("{indices}");
+    ///    // [0, 3, 3, 4]
+    /// }
+    /// ```
+    pub fn searchsorted(self, values: Self, right: bool) -> Tensor<B, D, Int> {
+        check!(TensorCheck::searchsorted::<D>(
+            &self.shape(),
+            &values.shape()
+        ));
+        Tensor::new(K::searchsorted(self.primitive, values.primitive, right))
+    }
+
+    /// Returns the index of the bucket of each element, the buckets being delimited by the sorted
+    /// boundaries.
+    ///
+    /// # Arguments
+    ///
+    /// * `boundaries` - The boundaries of the buckets in ascending order.
+    /// * `right` - If the elements equal to a boundary are in the bucket after it instead of the
+    ///   bucket before it.
+    ///
+    /// # Returns
+    ///
+    /// The bucket indices with the shape of the tensor, between `0` and the number of boundaries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[0.5, 1.0], [2.5, 4.0]], &device);
+    ///    let boundaries = Tensor::<B, 1>::from_data([1.0, 2.0, 3.0], &device);
+    ///    let buckets = tensor.bucketize(boundaries, false);
+    ///    println!("{buckets}");
+    ///    // [[0, 0], [2, 3]]
+    /// }
+    /// ```
+    pub fn bucketize(self, boundaries: Tensor<B, 1, K>, right: bool) -> Tensor<B, D, Int> {
+        let shape = self.shape();
+        let num_elements = shape.num_elements();
+        let num_boundaries = boundaries.dims()[0];
+
+        let values = self.reshape([1, num_elements]);
+        let boundaries = boundaries.reshape([1, num_boundaries]);
+
+        boundaries.searchsorted(values, right).reshape(shape)
+    }
+
     /// Returns the `k` largest elements of the given input tensor along a given dimension.
     ///
     /// # Arguments
@@ -2014,35 +2438,46 @@ where
     ) -> Tensor<B, D, K> {
         let (left, right, top, bottom) = padding;
 
-        let mut padded_dims: [usize; D] = self.dims();
-
-        // Update the last two dimensions with padding
-        padded_dims[D - 2] += top + bottom;
-        padded_dims[D - 1] += left + right;
-
-        // Create the ranges for the padded tensor
-        let ranges: [core::ops::Range<usize>; D] = padded_dims
-            .iter()
-            .enumerate()
-            .map(|(i, &dim)| {
-                if i == D - 2 {
-                    top..dim - bottom
-                } else if i == D - 1 {
-                    left..dim - right
-                } else {
-                    0..dim
-                }
-            })
-            .collect::<Vec<core::ops::Range<usize>>>()
-            .try_into()
-            .unwrap();
-
-        // Create the padded tensor
-        let padded_tensor = Tensor::full(padded_dims, value, &self.device());
-
-        // Assign the original tensor data to the appropriate slice of the padded tensor
-        padded_tensor.slice_assign(ranges, self)
+        self.pad_with(
+            &[(top, bottom), (left, right)],
+            PadMode::Constant(value.elem()),
+        )
     }
+
+    /// Pad the last dimensions of the tensor with the given [mode](PadMode).
+    ///
+    /// # Arguments
+    ///
+    /// * `padding` - The padding before and after each of the last `padding.len()` dimensions,
+    ///   in the order of the dimensions.
+    /// * `mode` - The values of the padded elements.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with the given padding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{PadMode, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    ///    let tensor = tensor.pad_with(&[(0, 0), (2, 1)], PadMode::Reflect);
+    ///    println!("{tensor}");
+    ///    // [
+    ///    //   [3.0, 2.0, 1.0, 2.0, 3.0, 2.0],
+    ///    //   [6.0, 5.0, 4.0, 5.0, 6.0, 5.0]
+    ///    // ]
+    /// }
+    /// ```
+    pub fn pad_with(self, padding: &[(usize, usize)], mode: PadMode) -> Tensor<B, D, K> {
+        check!(TensorCheck::pad::<D>(&self.shape(), padding, mode));
+        Self::new(K::pad(self.primitive, padding, mode))
+    }
+
     /// Create a one hot tensor.
     ///
     /// # Example
@@ -3021,6 +3456,39 @@ where
         values: Self::Primitive,
     ) -> Self::Primitive;
 
+    /// Scatters elements into a tensor along an axis, reducing the elements scattered at the same
+    /// position.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The axis along which to scatter elements.
+    /// * `tensor` - The tensor to scatter elements into.
+    /// * `indices` - The indices of the elements to scatter.
+    /// * `values` - The values to scatter into the tensor.
+    /// * `reduce` - The reduction applied to the elements at the same position.
+    /// * `include_self` - If the elements of the tensor are part of the reduction.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::scatter_reduce](Tensor::scatter_reduce) function,
+    /// which is more high-level and designed for public use.
+    fn scatter_reduce(
+        dim: usize,
+        tensor: Self::Primitive,
+        indices: B::IntTensorPrimitive,
+        values: Self::Primitive,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> Self::Primitive;
+
     /// Select tensor elements along the given dimension corresponding for the given indices.
     ///
     /// # Arguments
@@ -3384,6 +3852,27 @@ where
     /// which is more high-level and designed for public use.
     fn random(shape: Shape, distribution: Distribution, device: &B::Device) -> Self::Primitive;
 
+    /// Pads the last dimensions of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to pad.
+    /// * `padding` - The padding before and after each of the last `padding.len()` dimensions.
+    /// * `mode` - The padding mode.
+    ///
+    /// # Returns
+    ///
+    /// The padded tensor.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::pad_with](Tensor::pad_with) function,
+    /// which is more high-level and designed for public use.
+    fn pad(tensor: Self::Primitive, padding: &[(usize, usize)], mode: PadMode) -> Self::Primitive;
+
     /// Sort the elements of the input `tensor` by value along a given dimension.
     ///
     /// This sort is unstable (i.e., may reorder equal elements).
@@ -3462,6 +3951,56 @@ where
         dim: usize,
         descending: bool,
     ) -> <Int as TensorKind<B>>::Primitive;
+
+    /// Returns the sorted unique elements of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the unique elements as a 1D tensor, the index of each element of the tensor in
+    /// the unique elements, and the number of occurrences of each unique element.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::unique_with_inverse_and_counts](Tensor::unique_with_inverse_and_counts)
+    /// function, which is more high-level and designed for public use.
+    fn unique(
+        tensor: Self::Primitive,
+    ) -> impl Future<Output = (Self::Primitive, IntTensor<B>, IntTensor<B>)> + 'static + Send;
+
+    /// Finds the indices where the values should be inserted in the sorted sequences to keep them
+    /// sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted` - The sequences sorted along the last dimension.
+    /// * `values` - The values to search.
+    /// * `right` - If the index after the elements equal to the value is returned.
+    ///
+    /// # Returns
+    ///
+    /// The insertion indices with the shape of the values.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::searchsorted](Tensor::searchsorted) function,
+    /// which is more high-level and designed for public use.
+    fn searchsorted(
+        sorted: Self::Primitive,
+        values: Self::Primitive,
+        right: bool,
+    ) -> <Int as TensorKind<B>>::Primitive;
 }
 
 impl<B: Backend> Numeric<B> for Int {
@@ -3618,6 +4157,17 @@ impl<B: Backend> Numeric<B> for Int {
         B::int_scatter(dim, tensor, indices, values)
     }
 
+    fn scatter_reduce(
+        dim: usize,
+        tensor: Self::Primitive,
+        indices: B::IntTensorPrimitive,
+        values: Self::Primitive,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> Self::Primitive {
+        B::int_scatter_reduce(dim, tensor, indices, values, reduce, include_self)
+    }
+
     fn argmax(tensor: Self::Primitive, dim: usize) -> IntTensor<B> {
         B::int_argmax(tensor, dim)
     }
@@ -3699,6 +4249,10 @@ impl<B: Backend> Numeric<B> for Int {
         B::int_sign(tensor)
     }
 
+    fn pad(tensor: Self::Primitive, padding: &[(usize, usize)], mode: PadMode) -> Self::Primitive {
+        B::int_pad(tensor, padding, mode)
+    }
+
     fn sort(tensor: Self::Primitive, dim: usize, descending: bool) -> Self::Primitive {
         B::int_sort(tensor, dim, descending)
     }
@@ -3718,6 +4272,20 @@ impl<B: Backend> Numeric<B> for Int {
     ) -> <Int as TensorKind<B>>::Primitive {
         B::int_argsort(tensor, dim, descending)
     }
+
+    fn unique(
+        tensor: Self::Primitive,
+    ) -> impl Future<Output = (Self::Primitive, IntTensor<B>, IntTensor<B>)> + 'static + Send {
+        B::int_unique(tensor)
+    }
+
+    fn searchsorted(
+        sorted: Self::Primitive,
+        values: Self::Primitive,
+        right: bool,
+    ) -> <Int as TensorKind<B>>::Primitive {
+        B::int_searchsorted(sorted, values, right)
+    }
 }
 
 impl<B: Backend> Numeric<B> for Float {
@@ -4030,6 +4598,24 @@ impl<B: Backend> Numeric<B> for Float {
         }
     }
 
+    fn scatter_reduce(
+        dim: usize,
+        tensor: Self::Primitive,
+        indices: B::IntTensorPrimitive,
+        values: Self::Primitive,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> Self::Primitive {
+        TensorPrimitive::Float(B::float_scatter_reduce(
+            dim,
+            tensor.tensor(),
+            indices,
+            values.tensor(),
+            reduce,
+            include_self,
+        ))
+    }
+
     fn argmax(tensor: Self::Primitive, dim: usize) -> IntTensor<B> {
         match tensor {
             TensorPrimitive::Float(tensor) => B::float_argmax(tensor, dim),
@@ -4194,6 +4780,10 @@ impl<B: Backend> Numeric<B> for Float {
         TensorPrimitive::Float(B::float_sign(tensor.tensor()))
     }
 
+    fn pad(tensor: Self::Primitive, padding: &[(usize, usize)], mode: PadMode) -> Self::Primitive {
+        TensorPrimitive::Float(B::float_pad(tensor.tensor(), padding, mode))
+    }
+
     fn sort(tensor: Self::Primitive, dim: usize, descending: bool) -> Self::Primitive {
         match tensor {
             TensorPrimitive::Float(tensor) => {
@@ -4232,6 +4822,25 @@ impl<B: Backend> Numeric<B> for Float {
             TensorPrimitive::QFloat(tensor) => B::q_argsort(tensor, dim, descending),
         }
     }
+
+    fn unique(
+        tensor: Self::Primitive,
+    ) -> impl Future<Output = (Self::Primitive, IntTensor<B>, IntTensor<B>)> + 'static + Send {
+        let unique = B::float_unique(tensor.tensor());
+
+        async move {
+            let (values, inverse, counts) = unique.await;
+            (TensorPrimitive::Float(values), inverse, counts)
+        }
+    }
+
+    fn searchsorted(
+        sorted: Self::Primitive,
+        values: Self::Primitive,
+        right: bool,
+    ) -> <Int as TensorKind<B>>::Primitive {
+        B::float_searchsorted(sorted.tensor(), values.tensor(), right)
+    }
 }
 
 impl<B, const D: usize, K> core::ops::Add<Self> for Tensor<B, D, K>
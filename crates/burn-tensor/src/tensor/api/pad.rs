@@ -0,0 +1,128 @@
+use alloc::vec::Vec;
+
+use crate::{backend::Backend, Element, Int, Numeric, Shape, Tensor, TensorData, TensorMetadata};
+
+/// The values of the elements added by [pad_with](crate::Tensor::pad_with).
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum PadMode {
+    /// Pads with a constant value.
+    Constant(f64),
+    /// Pads with the reflection of the tensor over its edges, without repeating the edges.
+    ///
+    /// Padding `[1, 2, 3]` by two on both sides gives `[3, 2, 1, 2, 3, 2, 1]`.
+    Reflect,
+    /// Pads by repeating the edges of the tensor.
+    ///
+    /// Padding `[1, 2, 3]` by two on both sides gives `[1, 1, 1, 2, 3, 3, 3]`.
+    Replicate,
+    /// Pads by wrapping the tensor around, as if it was periodic.
+    ///
+    /// Padding `[1, 2, 3]` by two on both sides gives `[2, 3, 1, 2, 3, 1, 2]`.
+    Circular,
+}
+
+impl Default for PadMode {
+    fn default() -> Self {
+        Self::Constant(0.0)
+    }
+}
+
+/// Returns the index of the input element copied to each element of a padded dimension, or
+/// `None` for the [constant](PadMode::Constant) mode.
+///
+/// # Arguments
+///
+/// * `size` - The size of the dimension before padding.
+/// * `padding` - The padding before and after the dimension.
+/// * `mode` - The padding mode.
+pub fn pad_indices(size: usize, padding: (usize, usize), mode: PadMode) -> Option<Vec<i64>> {
+    let (before, after) = padding;
+    let size = size as i64;
+    let positions = -(before as i64)..size + after as i64;
+
+    let indices = match mode {
+        PadMode::Constant(_) => return None,
+        PadMode::Reflect => {
+            let period = 2 * (size - 1);
+            positions
+                .map(|position| {
+                    if period == 0 {
+                        return 0;
+                    }
+                    let index = position.rem_euclid(period);
+                    if index >= size {
+                        period - index
+                    } else {
+                        index
+                    }
+                })
+                .collect()
+        }
+        PadMode::Replicate => positions
+            .map(|position| position.clamp(0, size - 1))
+            .collect(),
+        PadMode::Circular => positions
+            .map(|position| position.rem_euclid(size))
+            .collect(),
+    };
+
+    Some(indices)
+}
+
+/// Pads the last dimensions of the input `tensor`.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `padding` - The padding before and after each of the last `padding.len()` dimensions.
+/// * `mode` - The padding mode.
+///
+/// # Returns
+///
+/// The padded tensor.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn pad<B: Backend, K: Numeric<B>>(
+    tensor: K::Primitive,
+    padding: &[(usize, usize)],
+    mode: PadMode,
+) -> K::Primitive
+where
+    K::Elem: Element,
+{
+    let shape = tensor.shape();
+    let offset = shape.num_dims() - padding.len();
+    let device = K::device(&tensor);
+
+    if let PadMode::Constant(value) = mode {
+        let mut dims = shape.dims.clone();
+        let mut ranges = dims.iter().map(|&size| 0..size).collect::<Vec<_>>();
+
+        for (i, &(before, after)) in padding.iter().enumerate() {
+            dims[offset + i] += before + after;
+            ranges[offset + i] = before..before + shape.dims[offset + i];
+        }
+
+        let output = K::full(Shape::from(dims), value, &device);
+        return K::slice_assign(output, &ranges, tensor);
+    }
+
+    padding
+        .iter()
+        .enumerate()
+        .filter(|(_, &padding)| padding != (0, 0))
+        .fold(tensor, |tensor, (i, &padding)| {
+            let dim = offset + i;
+            let indices = pad_indices(shape.dims[dim], padding, mode).unwrap();
+            let length = indices.len();
+            let indices =
+                Tensor::<B, 1, Int>::from_data(TensorData::new(indices, [length]), &device);
+
+            K::select(tensor, dim, indices)
+        })
+}
@@ -0,0 +1,15 @@
+/// The reduction applied to the elements scattered at the same position by
+/// [scatter_reduce](crate::Tensor::scatter_reduce).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Reduce {
+    /// Sum of the elements.
+    Sum,
+    /// Product of the elements.
+    Prod,
+    /// Mean of the elements.
+    Mean,
+    /// Maximum of the elements.
+    Max,
+    /// Minimum of the elements.
+    Min,
+}
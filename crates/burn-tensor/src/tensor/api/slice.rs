@@ -0,0 +1,365 @@
+use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use crate::{backend::Backend, BasicOps, Shape, TensorMetadata};
+use alloc::vec::Vec;
+
+/// Creates an array of [slices](Slice) to be used with [slice](crate::Tensor::slice) and
+/// [slice_assign](crate::Tensor::slice_assign).
+///
+/// Each slice is a range, optionally followed by a step separated with a semicolon. A single
+/// index selects one element while keeping the dimension.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{s, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let device = B::Device::default();
+///     let tensor = Tensor::<B, 2>::ones([4, 6], &device);
+///
+///     // Every other column.
+///     let slice = tensor.clone().slice(s![.., ..;2]);
+///     assert_eq!(slice.dims(), [4, 3]);
+///
+///     // The rows in reverse order, and the last column.
+///     let slice = tensor.slice(s![..;-1, -1]);
+///     assert_eq!(slice.dims(), [4, 1]);
+/// }
+/// ```
+#[macro_export]
+macro_rules! s {
+    [$($range:expr $(; $step:expr)?),+ $(,)?] => {
+        [$($crate::s!(@slice $range $(; $step)?)),+]
+    };
+    (@slice $range:expr; $step:expr) => {
+        $crate::Slice::from($range).with_step($step)
+    };
+    (@slice $range:expr) => {
+        $crate::Slice::from($range)
+    };
+}
+
+/// A range of a dimension with a step, as used by [slice](crate::Tensor::slice) and
+/// [slice_assign](crate::Tensor::slice_assign).
+///
+/// Negative `start` and `end` are counted from the end of the dimension. The elements of the range
+/// are first selected, then every `step` element is taken. With a negative step, the elements are
+/// taken in reverse order, starting from the end of the range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Slice {
+    /// The start of the range.
+    pub start: isize,
+    /// The exclusive end of the range, the end of the dimension when `None`.
+    pub end: Option<isize>,
+    /// The step between the selected elements, which can't be zero.
+    pub step: isize,
+}
+
+impl Slice {
+    /// Creates a new slice.
+    pub fn new(start: isize, end: Option<isize>, step: isize) -> Self {
+        Self { start, end, step }
+    }
+
+    /// A slice selecting the whole dimension.
+    pub fn full() -> Self {
+        Self::new(0, None, 1)
+    }
+
+    /// A slice selecting a single element, keeping the dimension.
+    pub fn index(index: isize) -> Self {
+        let end = match index {
+            -1 => None,
+            _ => Some(index + 1),
+        };
+
+        Self::new(index, end, 1)
+    }
+
+    /// Returns the same slice with the given step.
+    pub fn with_step(mut self, step: isize) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// The range of the dimension covered by the slice, with the negative indices resolved and
+    /// clamped to the size of the dimension.
+    pub fn to_range(&self, size: usize) -> Range<usize> {
+        let resolve = |index: isize| match index < 0 {
+            true => (size as isize + index).max(0) as usize,
+            false => (index as usize).min(size),
+        };
+
+        let start = resolve(self.start);
+        let end = self.end.map(resolve).unwrap_or(size);
+
+        start..end.max(start)
+    }
+
+    /// The number of elements selected by the slice in a dimension of the given size.
+    pub fn output_size(&self, size: usize) -> usize {
+        self.to_range(size).len().div_ceil(self.step.unsigned_abs())
+    }
+}
+
+impl From<RangeFull> for Slice {
+    fn from(_: RangeFull) -> Self {
+        Self::full()
+    }
+}
+
+macro_rules! impl_slice_from {
+    ($($ty:ty),*) => {
+        $(
+            impl From<Range<$ty>> for Slice {
+                fn from(range: Range<$ty>) -> Self {
+                    Self::new(range.start as isize, Some(range.end as isize), 1)
+                }
+            }
+
+            impl From<RangeInclusive<$ty>> for Slice {
+                fn from(range: RangeInclusive<$ty>) -> Self {
+                    let end = match *range.end() as isize {
+                        -1 => None,
+                        end => Some(end + 1),
+                    };
+                    Self::new(*range.start() as isize, end, 1)
+                }
+            }
+
+            impl From<RangeFrom<$ty>> for Slice {
+                fn from(range: RangeFrom<$ty>) -> Self {
+                    Self::new(range.start as isize, None, 1)
+                }
+            }
+
+            impl From<RangeTo<$ty>> for Slice {
+                fn from(range: RangeTo<$ty>) -> Self {
+                    Self::new(0, Some(range.end as isize), 1)
+                }
+            }
+
+            impl From<RangeToInclusive<$ty>> for Slice {
+                fn from(range: RangeToInclusive<$ty>) -> Self {
+                    let end = match range.end as isize {
+                        -1 => None,
+                        end => Some(end + 1),
+                    };
+                    Self::new(0, end, 1)
+                }
+            }
+
+            impl From<$ty> for Slice {
+                fn from(index: $ty) -> Self {
+                    Self::index(index as isize)
+                }
+            }
+        )*
+    };
+}
+
+impl_slice_from!(i32, i64, isize, usize);
+
+/// Returns the elements selected by the slices, taking every `step` element of each dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to slice.
+/// * `slices` - The slices of the first dimensions, within the bounds of the tensor.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn slice_with_steps<B: Backend, K: BasicOps<B>>(
+    tensor: K::Primitive,
+    slices: &[Slice],
+) -> K::Primitive {
+    let ranges = slice_ranges(&tensor.shape(), slices);
+    let mut tensor = K::slice(tensor, &ranges);
+
+    for (dim, slice) in slices.iter().enumerate() {
+        tensor = take_steps::<B, K>(tensor, dim, slice.step);
+    }
+
+    tensor
+}
+
+/// Returns a copy of the tensor with the elements selected by the slices replaced by the values.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to assign the values to.
+/// * `slices` - The slices of the first dimensions, within the bounds of the tensor.
+/// * `values` - The values, with the shape of the elements selected by the slices.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn slice_assign_with_steps<B: Backend, K: BasicOps<B>>(
+    tensor: K::Primitive,
+    slices: &[Slice],
+    values: K::Primitive,
+) -> K::Primitive {
+    let ranges = slice_ranges(&tensor.shape(), slices);
+    let steps = slices.iter().map(|slice| slice.step).collect::<Vec<_>>();
+
+    let region = K::slice(tensor.clone(), &ranges);
+    let region = assign_steps::<B, K>(region, values, &steps, 0);
+
+    K::slice_assign(tensor, &ranges, region)
+}
+
+fn slice_ranges(shape: &Shape, slices: &[Slice]) -> Vec<Range<usize>> {
+    slices
+        .iter()
+        .zip(shape.dims.iter())
+        .map(|(slice, size)| slice.to_range(*size))
+        .collect()
+}
+
+fn full_ranges(shape: &Shape) -> Vec<Range<usize>> {
+    shape.dims.iter().map(|size| 0..*size).collect()
+}
+
+fn narrow<B: Backend, K: BasicOps<B>>(
+    tensor: K::Primitive,
+    dim: usize,
+    range: Range<usize>,
+) -> K::Primitive {
+    let mut ranges = full_ranges(&tensor.shape());
+    ranges[dim] = range;
+
+    K::slice(tensor, &ranges)
+}
+
+fn narrow_assign<B: Backend, K: BasicOps<B>>(
+    tensor: K::Primitive,
+    dim: usize,
+    range: Range<usize>,
+    values: K::Primitive,
+) -> K::Primitive {
+    let mut ranges = full_ranges(&tensor.shape());
+    ranges[dim] = range;
+
+    K::slice_assign(tensor, &ranges, values)
+}
+
+/// Splits the dimension into `[size / step, step]`, so the elements at every `step` position are
+/// the first column of the new dimension.
+fn split_dim(shape: &Shape, dim: usize, step: usize) -> Shape {
+    let mut dims = shape.dims.clone();
+    dims[dim] /= step;
+    dims.insert(dim + 1, step);
+
+    Shape::from(dims)
+}
+
+fn take_steps<B: Backend, K: BasicOps<B>>(
+    tensor: K::Primitive,
+    dim: usize,
+    step: isize,
+) -> K::Primitive {
+    let tensor = match step < 0 {
+        true => K::flip(tensor, &[dim]),
+        false => tensor,
+    };
+    let step = step.unsigned_abs();
+    let size = tensor.shape().dims[dim];
+
+    if step == 1 || size == 0 {
+        return tensor;
+    }
+
+    // The elements before the last selected one fill complete groups of `step` elements.
+    let num_elements = size.div_ceil(step);
+    let head = (num_elements - 1) * step;
+    let last = narrow::<B, K>(tensor.clone(), dim, head..head + 1);
+
+    if head == 0 {
+        return last;
+    }
+
+    let head = narrow::<B, K>(tensor, dim, 0..head);
+    let shape = head.shape();
+    let head = K::reshape(head, split_dim(&shape, dim, step));
+    let head = narrow::<B, K>(head, dim + 1, 0..1);
+    let mut dims = shape.dims;
+    dims[dim] = num_elements - 1;
+    let head = K::reshape(head, Shape::from(dims));
+
+    K::cat(alloc::vec![head, last], dim)
+}
+
+fn assign_steps<B: Backend, K: BasicOps<B>>(
+    region: K::Primitive,
+    values: K::Primitive,
+    steps: &[isize],
+    dim: usize,
+) -> K::Primitive {
+    let Some(dim) = (dim..steps.len()).find(|dim| steps[*dim] != 1) else {
+        // All remaining elements of the region are replaced.
+        return values;
+    };
+
+    let step = steps[dim];
+    let mut region = match step < 0 {
+        true => K::flip(region, &[dim]),
+        false => region,
+    };
+    let step = step.unsigned_abs();
+    let num_elements = values.shape().dims[dim];
+
+    if num_elements == 0 {
+        return region;
+    }
+
+    let head = (num_elements - 1) * step;
+
+    if head > 0 {
+        let part = narrow::<B, K>(region.clone(), dim, 0..head);
+        let shape = part.shape();
+        let shape_split = split_dim(&shape, dim, step);
+        let part = K::reshape(part, shape_split.clone());
+
+        let mut ranges = full_ranges(&shape_split);
+        ranges[dim + 1] = 0..1;
+        let mut dims_selected = shape_split.dims.clone();
+        dims_selected[dim + 1] = 1;
+        let mut dims = shape.dims.clone();
+        dims[dim] = num_elements - 1;
+
+        let selected = K::reshape(K::slice(part.clone(), &ranges), Shape::from(dims));
+        let selected = assign_steps::<B, K>(
+            selected,
+            narrow::<B, K>(values.clone(), dim, 0..num_elements - 1),
+            steps,
+            dim + 1,
+        );
+        let selected = K::reshape(selected, Shape::from(dims_selected));
+
+        let part = K::reshape(K::slice_assign(part, &ranges, selected), shape);
+        region = narrow_assign::<B, K>(region, dim, 0..head, part);
+    }
+
+    let last = narrow::<B, K>(region.clone(), dim, head..head + 1);
+    let last = assign_steps::<B, K>(
+        last,
+        narrow::<B, K>(values, dim, num_elements - 1..num_elements),
+        steps,
+        dim + 1,
+    );
+    let region = narrow_assign::<B, K>(region, dim, head..head + 1, last);
+
+    match steps[dim] < 0 {
+        true => K::flip(region, &[dim]),
+        false => region,
+    }
+}
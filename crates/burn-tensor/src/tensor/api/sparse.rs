@@ -0,0 +1,723 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::future::Future;
+use core::ops::Range;
+
+use crate::{
+    backend::Backend, check, check::TensorCheck, BasicOps, DType, Device, Float, Int, Shape, Slice,
+    Sparse, Tensor, TensorData, TensorMetadata, TensorPrimitive, Transaction,
+};
+
+/// A tensor that only stores the elements that aren't zero, in the [COO](SparseLayout::Coo) or
+/// the [CSR](SparseLayout::Csr) layout.
+///
+/// The indices and the values are dense tensors of the backend, so the values can be tracked by
+/// autodiff like any other float tensor, and the gradients flow to the values and to the dense
+/// operands of the sparse operations.
+///
+/// # Notes
+///
+/// The basic operations that move the elements, such as [reshape](Tensor::reshape),
+/// [permute](Tensor::permute), [flip](Tensor::flip), [cat](Tensor::cat) or
+/// [repeat_dim](Tensor::repeat_dim), only update the indices. The other ones, such as
+/// [slice](Tensor::slice) or [expand](Tensor::expand), are computed on the dense tensor and the
+/// result is made sparse again, which requires synchronization.
+///
+/// The numeric operations aren't available on the sparse kind, the stored values are instead
+/// updated with [map_values](Tensor::map_values).
+pub type SparseTensor<B, const D: usize> = Tensor<B, D, Sparse>;
+
+/// The memory layout of a [sparse tensor](SparseTensor).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SparseLayout {
+    /// Coordinate format, where each value is stored with the index of each of its dimensions.
+    Coo,
+    /// Compressed sparse row format, where the values of a matrix are sorted by row and the
+    /// row of each value is given by the offsets of the rows.
+    Csr,
+}
+
+/// The primitive of a [sparse tensor](SparseTensor), storing the indices and the values of the
+/// elements that aren't zero.
+#[derive(Debug, Clone)]
+pub struct SparsePrimitive<B: Backend> {
+    storage: SparseStorage<B>,
+    values: Tensor<B, 1>,
+    shape: Shape,
+}
+
+#[derive(Debug, Clone)]
+enum SparseStorage<B: Backend> {
+    Coo {
+        indices: Tensor<B, 2, Int>,
+    },
+    Csr {
+        crow_indices: Tensor<B, 1, Int>,
+        col_indices: Tensor<B, 1, Int>,
+    },
+}
+
+impl<B: Backend> TensorMetadata for SparsePrimitive<B> {
+    fn dtype(&self) -> DType {
+        self.values.dtype()
+    }
+
+    fn shape(&self) -> Shape {
+        self.shape.clone()
+    }
+}
+
+impl<B: Backend> SparsePrimitive<B> {
+    fn coo(indices: Tensor<B, 2, Int>, values: Tensor<B, 1>, shape: Shape) -> Self {
+        Self {
+            storage: SparseStorage::Coo { indices },
+            values,
+            shape,
+        }
+    }
+
+    fn device(&self) -> Device<B> {
+        self.values.device()
+    }
+
+    fn nnz(&self) -> usize {
+        self.values.dims()[0]
+    }
+
+    /// The indices of the values with the shape `[D, nnz]`.
+    fn indices(&self) -> Tensor<B, 2, Int> {
+        match &self.storage {
+            SparseStorage::Coo { indices } => indices.clone(),
+            SparseStorage::Csr {
+                crow_indices,
+                col_indices,
+            } => {
+                let rows = crow_rows(crow_indices.clone(), self.nnz());
+                Tensor::stack(vec![rows, col_indices.clone()], 0)
+            }
+        }
+    }
+
+    /// Makes the sparse tensor dense, where the missing elements are zeros.
+    fn dense(self) -> TensorPrimitive<B> {
+        let device = self.device();
+        let linear = linear_indices(self.indices(), &self.shape, &device);
+        let dense = Tensor::<B, 1>::zeros([self.shape.num_elements()], &device).select_assign(
+            0,
+            linear,
+            self.values,
+        );
+
+        <Float as BasicOps<B>>::reshape(dense.into_primitive(), self.shape)
+    }
+
+    /// Stores the elements of the dense tensor that aren't zero, in the COO layout.
+    fn from_dense(tensor: TensorPrimitive<B>) -> Self {
+        let shape = tensor.shape();
+        let flat = <Float as BasicOps<B>>::reshape(tensor, Shape::new([shape.num_elements()]));
+        let flat = Tensor::<B, 1>::from_primitive(flat);
+
+        let positions = flat.clone().not_equal_elem(0.0).argwhere();
+        let [nnz, _] = positions.dims();
+        let linear = positions.reshape([nnz]);
+        let values = flat.select(0, linear.clone());
+        let indices = unravel_indices(linear, &shape);
+
+        Self::coo(indices, values, shape)
+    }
+
+    /// Applies a function to the dense tensor, then makes the result sparse again.
+    fn map_dense<F>(self, func: F) -> Self
+    where
+        F: FnOnce(TensorPrimitive<B>) -> TensorPrimitive<B>,
+    {
+        Self::from_dense(func(self.dense()))
+    }
+
+    /// Replaces the indices, keeping the values, in the COO layout.
+    fn map_indices<F>(self, shape: Shape, func: F) -> Self
+    where
+        F: FnOnce(Tensor<B, 2, Int>) -> Tensor<B, 2, Int>,
+    {
+        let indices = func(self.indices());
+
+        Self::coo(indices, self.values, shape)
+    }
+
+    /// Selects the rows of the indices in the given order.
+    fn select_dims(self, order: &[usize], shape: Shape) -> Self {
+        let device = self.device();
+        let order = order.iter().map(|dim| *dim as i64).collect::<Vec<_>>();
+        let order = Tensor::from_data(TensorData::new(order, [shape.num_dims()]), &device);
+
+        self.map_indices(shape, |indices| indices.select(0, order))
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D, Sparse> {
+    /// Creates a sparse tensor in the [COO](SparseLayout::Coo) layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - The indices of the values, with the shape `[D, nnz]`.
+    /// * `values` - The values, with the shape `[nnz]`.
+    /// * `shape` - The shape of the dense tensor.
+    ///
+    /// # Notes
+    ///
+    /// Duplicate indices are allowed, their values are summed when the tensor is made dense.
+    pub fn from_coo<S: Into<Shape>>(
+        indices: Tensor<B, 2, Int>,
+        values: Tensor<B, 1>,
+        shape: S,
+    ) -> Self {
+        let shape = shape.into();
+        check!(TensorCheck::sparse_coo::<D>(
+            &indices.shape(),
+            &values.shape(),
+            &shape
+        ));
+
+        Self::new(SparsePrimitive::coo(indices, values, shape))
+    }
+
+    /// Creates a sparse tensor in the [COO](SparseLayout::Coo) layout with the elements of the
+    /// dense tensor that aren't zero.
+    ///
+    /// # Notes
+    ///
+    /// The positions of the values are read from the device, which requires synchronization.
+    pub fn from_dense(tensor: Tensor<B, D>) -> Self {
+        Self::new(SparsePrimitive::from_dense(tensor.into_primitive()))
+    }
+
+    /// Converts the sparse tensor into a dense tensor, where the missing elements are zeros.
+    pub fn into_dense(self) -> Tensor<B, D> {
+        Tensor::new(self.primitive.dense())
+    }
+
+    /// Converts the sparse tensor to the [COO](SparseLayout::Coo) layout.
+    pub fn into_coo(self) -> Self {
+        match self.layout() {
+            SparseLayout::Coo => self,
+            SparseLayout::Csr => Self::from_coo(self.indices(), self.values(), self.shape()),
+        }
+    }
+
+    /// Returns the layout of the sparse tensor.
+    pub fn layout(&self) -> SparseLayout {
+        match self.primitive.storage {
+            SparseStorage::Coo { .. } => SparseLayout::Coo,
+            SparseStorage::Csr { .. } => SparseLayout::Csr,
+        }
+    }
+
+    /// Returns the number of stored values.
+    pub fn nnz(&self) -> usize {
+        self.primitive.nnz()
+    }
+
+    /// Returns the stored values, with the shape `[nnz]`.
+    pub fn values(&self) -> Tensor<B, 1> {
+        self.primitive.values.clone()
+    }
+
+    /// Returns the indices of the stored values in the [COO](SparseLayout::Coo) layout, with the
+    /// shape `[D, nnz]`.
+    pub fn indices(&self) -> Tensor<B, 2, Int> {
+        self.primitive.indices()
+    }
+
+    /// Applies the function to the stored values.
+    ///
+    /// The function should map zero to zero for the sparse tensor to stay equal to the function
+    /// applied to the dense tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, SparseTensor, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let indices = Tensor::<B, 2, Int>::from_ints([[0, 1], [1, 0]], &device);
+    ///     let values = Tensor::<B, 1>::from_floats([-2.0, 3.0], &device);
+    ///     let sparse = SparseTensor::<B, 2>::from_coo(indices, values, [2, 2]);
+    ///
+    ///     let output = sparse.map_values(|values| values.abs().mul_scalar(2.0));
+    ///     println!("{}", output.into_dense());
+    ///     // [[0.0, 4.0], [6.0, 0.0]]
+    /// }
+    /// ```
+    pub fn map_values<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tensor<B, 1>) -> Tensor<B, 1>,
+    {
+        let primitive = self.primitive;
+
+        Self::new(SparsePrimitive {
+            values: func(primitive.values),
+            ..primitive
+        })
+    }
+
+    /// Multiplies the sparse tensor by a dense tensor of the same shape element-wise.
+    ///
+    /// Only the elements of the dense tensor at the positions of the stored values are read, so
+    /// the result keeps the indices of the sparse tensor.
+    pub fn mul_dense(self, other: Tensor<B, D>) -> Self {
+        let shape = self.shape();
+        check!(TensorCheck::same_shape(
+            "Sparse mul",
+            &shape,
+            &other.shape()
+        ));
+
+        let linear = linear_indices(self.indices(), &shape, &self.device());
+        let other = other.reshape([shape.num_elements()]).select(0, linear);
+
+        self.map_values(|values| values * other)
+    }
+
+    /// Adds two sparse tensors of the same shape.
+    ///
+    /// The result is in the [COO](SparseLayout::Coo) layout and stores the values of both
+    /// tensors, where the values sharing the same index are summed when made dense.
+    pub fn add_sparse(self, other: Self) -> Self {
+        let shape = self.shape();
+        check!(TensorCheck::same_shape(
+            "Sparse add",
+            &shape,
+            &other.shape()
+        ));
+
+        let indices = Tensor::cat(vec![self.indices(), other.indices()], 1);
+        let values = Tensor::cat(vec![self.values(), other.values()], 0);
+
+        Self::from_coo(indices, values, shape)
+    }
+}
+
+impl<B: Backend> Tensor<B, 2, Sparse> {
+    /// Creates a sparse matrix in the [CSR](SparseLayout::Csr) layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `crow_indices` - The offset of the first value of each row, followed by the number of
+    ///   values, with the shape `[rows + 1]`.
+    /// * `col_indices` - The column of each value, with the shape `[nnz]`.
+    /// * `values` - The values sorted by row, with the shape `[nnz]`.
+    /// * `shape` - The shape of the dense matrix.
+    pub fn from_csr<S: Into<Shape>>(
+        crow_indices: Tensor<B, 1, Int>,
+        col_indices: Tensor<B, 1, Int>,
+        values: Tensor<B, 1>,
+        shape: S,
+    ) -> Self {
+        let shape = shape.into();
+        check!(TensorCheck::sparse_csr(
+            &crow_indices.shape(),
+            &col_indices.shape(),
+            &values.shape(),
+            &shape
+        ));
+
+        Self::new(SparsePrimitive {
+            storage: SparseStorage::Csr {
+                crow_indices,
+                col_indices,
+            },
+            values,
+            shape,
+        })
+    }
+
+    /// Converts the sparse matrix to the [CSR](SparseLayout::Csr) layout.
+    pub fn into_csr(self) -> Self {
+        if self.layout() == SparseLayout::Csr {
+            return self;
+        }
+
+        let [num_rows, num_cols] = self.dims();
+        let device = self.device();
+        let [rows, cols] = coo_rows_cols(self.indices());
+
+        // Sorting by linear index sorts the values by row, then by column.
+        let order = (rows.clone() * num_cols as i64 + cols.clone()).argsort(0);
+
+        let rows = rows.select(0, order.clone());
+        let cols = cols.select(0, order.clone());
+        let values = self.values().select(0, order);
+        let crow_indices =
+            rows.searchsorted(Tensor::arange(0..num_rows as i64 + 1, &device), false);
+
+        Self::from_csr(crow_indices, cols, values, [num_rows, num_cols])
+    }
+
+    /// Returns the row offsets and the column indices of the [CSR](SparseLayout::Csr) layout.
+    pub fn csr_indices(&self) -> (Tensor<B, 1, Int>, Tensor<B, 1, Int>) {
+        match &self.primitive.storage {
+            SparseStorage::Csr {
+                crow_indices,
+                col_indices,
+            } => (crow_indices.clone(), col_indices.clone()),
+            SparseStorage::Coo { .. } => self.clone().into_csr().csr_indices(),
+        }
+    }
+
+    /// Performs the matrix multiplication of the sparse matrix with a dense matrix.
+    ///
+    /// The gradients flow to the stored values of the sparse matrix and to the dense matrix.
+    ///
+    /// # Shapes
+    ///
+    /// - self: `[m, k]`
+    /// - rhs: `[k, n]`
+    /// - output: `[m, n]`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, SparseTensor, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let indices = Tensor::<B, 2, Int>::from_ints([[0, 1, 1], [1, 0, 2]], &device);
+    ///     let values = Tensor::<B, 1>::from_floats([2.0, 3.0, 4.0], &device);
+    ///     let sparse = SparseTensor::<B, 2>::from_coo(indices, values, [2, 3]);
+    ///
+    ///     let dense = Tensor::<B, 2>::from_floats([[1.0], [2.0], [3.0]], &device);
+    ///     let output = sparse.spmm(dense);
+    ///     println!("{output}");
+    ///     // [[4.0], [15.0]]
+    /// }
+    /// ```
+    pub fn spmm(self, rhs: Tensor<B, 2>) -> Tensor<B, 2> {
+        let shape = self.shape();
+        check!(TensorCheck::spmm(&shape, &rhs.shape()));
+
+        let [num_rows, _] = self.dims();
+        let [_, num_cols] = rhs.dims();
+        let device = self.device();
+        let output = Tensor::zeros([num_rows, num_cols], &device);
+
+        if self.nnz() == 0 {
+            return output;
+        }
+
+        let [rows, cols] = coo_rows_cols(self.indices());
+
+        // Each value scales the row of the dense matrix at its column, which is summed in the
+        // row of the output at its row.
+        let products = rhs.select(0, cols) * self.values().unsqueeze_dim(1);
+
+        output.select_assign(0, rows, products)
+    }
+}
+
+impl<B: Backend> BasicOps<B> for Sparse {
+    type Elem = B::FloatElem;
+
+    fn empty(shape: Shape, device: &B::Device) -> Self::Primitive {
+        let indices = Tensor::empty([shape.num_dims(), 0], device);
+        let values = Tensor::empty([0], device);
+
+        SparsePrimitive::coo(indices, values, shape)
+    }
+
+    fn register_transaction(tr: &mut Transaction<B>, tensor: Self::Primitive) {
+        tr.register_float(tensor.dense());
+    }
+
+    fn reshape(tensor: Self::Primitive, shape: Shape) -> Self::Primitive {
+        let device = tensor.device();
+        let shape_tensor = tensor.shape.clone();
+
+        tensor.map_indices(shape.clone(), |indices| {
+            unravel_indices(linear_indices(indices, &shape_tensor, &device), &shape)
+        })
+    }
+
+    fn transpose(tensor: Self::Primitive) -> Self::Primitive {
+        let num_dims = tensor.shape.num_dims();
+
+        Self::swap_dims(tensor, num_dims - 2, num_dims - 1)
+    }
+
+    fn swap_dims(tensor: Self::Primitive, dim1: usize, dim2: usize) -> Self::Primitive {
+        let mut order = (0..tensor.shape.num_dims()).collect::<Vec<_>>();
+        order.swap(dim1, dim2);
+
+        Self::permute(tensor, &order)
+    }
+
+    fn permute(tensor: Self::Primitive, axes: &[usize]) -> Self::Primitive {
+        let shape = Shape::from(
+            axes.iter()
+                .map(|axis| tensor.shape.dims[*axis])
+                .collect::<Vec<_>>(),
+        );
+
+        tensor.select_dims(axes, shape)
+    }
+
+    fn flip(tensor: Self::Primitive, axes: &[usize]) -> Self::Primitive {
+        let device = tensor.device();
+        let num_dims = tensor.shape.num_dims();
+        let mut signs = vec![1i64; num_dims];
+        let mut offsets = vec![0i64; num_dims];
+
+        // The flipped index is the size of the dimension minus one, minus the index.
+        for axis in axes {
+            signs[*axis] = -1;
+            offsets[*axis] = tensor.shape.dims[*axis] as i64 - 1;
+        }
+
+        let signs = Tensor::<B, 1, Int>::from_data(TensorData::new(signs, [num_dims]), &device);
+        let offsets = Tensor::<B, 1, Int>::from_data(TensorData::new(offsets, [num_dims]), &device);
+        let shape = tensor.shape.clone();
+
+        tensor.map_indices(shape, |indices| {
+            indices * signs.unsqueeze_dim(1) + offsets.unsqueeze_dim(1)
+        })
+    }
+
+    fn slice(tensor: Self::Primitive, ranges: &[Range<usize>]) -> Self::Primitive {
+        tensor.map_dense(|tensor| <Float as BasicOps<B>>::slice(tensor, ranges))
+    }
+
+    fn slice_assign(
+        tensor: Self::Primitive,
+        ranges: &[Range<usize>],
+        value: Self::Primitive,
+    ) -> Self::Primitive {
+        tensor
+            .map_dense(|tensor| <Float as BasicOps<B>>::slice_assign(tensor, ranges, value.dense()))
+    }
+
+    fn slice_with_steps(tensor: Self::Primitive, slices: &[Slice]) -> Self::Primitive {
+        tensor.map_dense(|tensor| <Float as BasicOps<B>>::slice_with_steps(tensor, slices))
+    }
+
+    fn slice_assign_with_steps(
+        tensor: Self::Primitive,
+        slices: &[Slice],
+        value: Self::Primitive,
+    ) -> Self::Primitive {
+        tensor.map_dense(|tensor| {
+            <Float as BasicOps<B>>::slice_assign_with_steps(tensor, slices, value.dense())
+        })
+    }
+
+    fn device(tensor: &Self::Primitive) -> Device<B> {
+        tensor.device()
+    }
+
+    fn to_device(tensor: Self::Primitive, device: &Device<B>) -> Self::Primitive {
+        let storage = match tensor.storage {
+            SparseStorage::Coo { indices } => SparseStorage::Coo {
+                indices: indices.to_device(device),
+            },
+            SparseStorage::Csr {
+                crow_indices,
+                col_indices,
+            } => SparseStorage::Csr {
+                crow_indices: crow_indices.to_device(device),
+                col_indices: col_indices.to_device(device),
+            },
+        };
+
+        SparsePrimitive {
+            storage,
+            values: tensor.values.to_device(device),
+            shape: tensor.shape,
+        }
+    }
+
+    fn into_data_async(
+        tensor: Self::Primitive,
+    ) -> impl Future<Output = TensorData> + 'static + Send {
+        <Float as BasicOps<B>>::into_data_async(tensor.dense())
+    }
+
+    fn from_data(data: TensorData, device: &B::Device) -> Self::Primitive {
+        SparsePrimitive::from_dense(<Float as BasicOps<B>>::from_data(data, device))
+    }
+
+    fn from_data_dtype(data: TensorData, device: &B::Device, dtype: DType) -> Self::Primitive {
+        SparsePrimitive::from_dense(<Float as BasicOps<B>>::from_data_dtype(data, device, dtype))
+    }
+
+    fn repeat_dim(tensor: Self::Primitive, dim: usize, times: usize) -> Self::Primitive {
+        let size = tensor.shape.dims[dim];
+        let mut shape = tensor.shape.clone();
+        shape.dims[dim] *= times;
+
+        // Each repetition is a copy of the values, with the indices moved along the dimension.
+        let repetitions = (0..times)
+            .map(|time| {
+                tensor.clone().map_indices(shape.clone(), |indices| {
+                    shift_indices(indices, dim, time * size)
+                })
+            })
+            .collect();
+
+        Self::cat(repetitions, dim)
+    }
+
+    fn cat(vectors: Vec<Self::Primitive>, dim: usize) -> Self::Primitive {
+        let mut shape = vectors.first().unwrap().shape.clone();
+        shape.dims[dim] = vectors.iter().map(|tensor| tensor.shape.dims[dim]).sum();
+
+        let mut offset = 0;
+        let mut indices = Vec::with_capacity(vectors.len());
+        let mut values = Vec::with_capacity(vectors.len());
+
+        // The indices of each tensor are moved by the sizes of the tensors before it.
+        for tensor in vectors {
+            let size = tensor.shape.dims[dim];
+            indices.push(shift_indices(tensor.indices(), dim, offset));
+            values.push(tensor.values);
+            offset += size;
+        }
+
+        SparsePrimitive::coo(Tensor::cat(indices, 1), Tensor::cat(values, 0), shape)
+    }
+
+    fn equal(lhs: Self::Primitive, rhs: Self::Primitive) -> B::BoolTensorPrimitive {
+        <Float as BasicOps<B>>::equal(lhs.dense(), rhs.dense())
+    }
+
+    fn not_equal(lhs: Self::Primitive, rhs: Self::Primitive) -> B::BoolTensorPrimitive {
+        <Float as BasicOps<B>>::not_equal(lhs.dense(), rhs.dense())
+    }
+
+    fn any(tensor: Self::Primitive) -> B::BoolTensorPrimitive {
+        <Float as BasicOps<B>>::any(tensor.dense())
+    }
+
+    fn any_dim(tensor: Self::Primitive, dim: usize) -> B::BoolTensorPrimitive {
+        <Float as BasicOps<B>>::any_dim(tensor.dense(), dim)
+    }
+
+    fn all(tensor: Self::Primitive) -> B::BoolTensorPrimitive {
+        <Float as BasicOps<B>>::all(tensor.dense())
+    }
+
+    fn all_dim(tensor: Self::Primitive, dim: usize) -> B::BoolTensorPrimitive {
+        <Float as BasicOps<B>>::all_dim(tensor.dense(), dim)
+    }
+
+    fn expand(tensor: Self::Primitive, shape: Shape) -> Self::Primitive {
+        tensor.map_dense(|tensor| <Float as BasicOps<B>>::expand(tensor, shape))
+    }
+
+    fn chunk(tensor: Self::Primitive, chunks: usize, dim: usize) -> Vec<Self::Primitive> {
+        <Float as BasicOps<B>>::chunk(tensor.dense(), chunks, dim)
+            .into_iter()
+            .map(SparsePrimitive::from_dense)
+            .collect()
+    }
+
+    fn split(tensor: Self::Primitive, split_size: usize, dim: usize) -> Vec<Self::Primitive> {
+        <Float as BasicOps<B>>::split(tensor.dense(), split_size, dim)
+            .into_iter()
+            .map(SparsePrimitive::from_dense)
+            .collect()
+    }
+
+    fn split_with_sizes(
+        tensor: Self::Primitive,
+        split_sizes: Vec<usize>,
+        dim: usize,
+    ) -> Vec<Self::Primitive> {
+        <Float as BasicOps<B>>::split_with_sizes(tensor.dense(), split_sizes, dim)
+            .into_iter()
+            .map(SparsePrimitive::from_dense)
+            .collect()
+    }
+}
+
+/// The strides of the dense tensor, in elements.
+fn strides<B: Backend>(shape: &Shape, device: &Device<B>) -> Tensor<B, 1, Int> {
+    let num_dims = shape.num_dims();
+    let mut strides = vec![1i64; num_dims];
+
+    for dim in (0..num_dims.saturating_sub(1)).rev() {
+        strides[dim] = strides[dim + 1] * shape.dims[dim + 1] as i64;
+    }
+
+    Tensor::from_data(TensorData::new(strides, [num_dims]), device)
+}
+
+/// The linear indices of the values in the dense tensor, from their indices of shape `[D, nnz]`.
+fn linear_indices<B: Backend>(
+    indices: Tensor<B, 2, Int>,
+    shape: &Shape,
+    device: &Device<B>,
+) -> Tensor<B, 1, Int> {
+    let [_, nnz] = indices.dims();
+    let strides = strides::<B>(shape, device).unsqueeze_dim(1);
+
+    (indices * strides).sum_dim(0).reshape([nnz])
+}
+
+/// The indices of shape `[D, nnz]` of the values in the dense tensor, from their linear indices.
+fn unravel_indices<B: Backend>(linear: Tensor<B, 1, Int>, shape: &Shape) -> Tensor<B, 2, Int> {
+    let num_dims = shape.num_dims();
+    let device = linear.device();
+    let strides = strides::<B>(shape, &device).unsqueeze_dim(1);
+    let dims = shape.dims.iter().map(|dim| *dim as i64).collect::<Vec<_>>();
+    let dims = Tensor::<B, 1, Int>::from_data(TensorData::new(dims, [num_dims]), &device);
+
+    (linear.unsqueeze_dim(0) / strides).remainder(dims.unsqueeze_dim(1))
+}
+
+/// Moves the indices of a dimension by an offset.
+fn shift_indices<B: Backend>(
+    indices: Tensor<B, 2, Int>,
+    dim: usize,
+    offset: usize,
+) -> Tensor<B, 2, Int> {
+    let [num_dims, _] = indices.dims();
+    let mut offsets = vec![0i64; num_dims];
+    offsets[dim] = offset as i64;
+    let offsets =
+        Tensor::<B, 1, Int>::from_data(TensorData::new(offsets, [num_dims]), &indices.device());
+
+    indices + offsets.unsqueeze_dim(1)
+}
+
+/// The rows and the columns of the values of a matrix, from their indices of shape `[2, nnz]`.
+fn coo_rows_cols<B: Backend>(indices: Tensor<B, 2, Int>) -> [Tensor<B, 1, Int>; 2] {
+    let [_, nnz] = indices.dims();
+    let device = indices.device();
+    let select_row = |row: i64| {
+        indices
+            .clone()
+            .select(0, Tensor::from_ints([row], &device))
+            .reshape([nnz])
+    };
+
+    [select_row(0), select_row(1)]
+}
+
+/// The row of each value of a CSR matrix, which is the number of row offsets after the first
+/// that are lower than or equal to the position of the value.
+fn crow_rows<B: Backend>(crow_indices: Tensor<B, 1, Int>, nnz: usize) -> Tensor<B, 1, Int> {
+    let [size] = crow_indices.dims();
+    let device = crow_indices.device();
+
+    // A matrix without values doesn't have any row to find, even when it has no rows.
+    if nnz == 0 {
+        return Tensor::empty([0], &device);
+    }
+
+    let positions = Tensor::arange(0..nnz as i64, &device);
+
+    crow_indices
+        .narrow(0, 1, size - 1)
+        .searchsorted(positions, true)
+}
@@ -0,0 +1,124 @@
+use core::cmp::Ordering;
+
+use crate::{
+    backend::Backend, ops::IntTensor, BasicOps, Element, Shape, TensorData, TensorKind,
+    TensorMetadata,
+};
+use alloc::{vec, vec::Vec};
+
+/// Returns the sorted unique elements of the tensor, the index of each element of the tensor in
+/// the unique elements and the number of occurrences of each unique element.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+///
+/// # Returns
+///
+/// A tuple with the unique elements as a 1D tensor, the inverse indices with the shape of the
+/// input tensor, and the counts as a 1D tensor.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub async fn unique<B: Backend, K: TensorKind<B> + BasicOps<B>>(
+    tensor: K::Primitive,
+) -> (K::Primitive, IntTensor<B>, IntTensor<B>)
+where
+    <K as BasicOps<B>>::Elem: Element,
+{
+    // The number of unique elements is only known once the data is read.
+    let device = K::device(&tensor);
+    let data = K::into_data_async(tensor).await;
+    let (values, inverse, counts) = unique_data::<K::Elem>(data);
+
+    (
+        K::from_data(values, &device),
+        B::int_from_data(inverse.convert::<B::IntElem>(), &device),
+        B::int_from_data(counts.convert::<B::IntElem>(), &device),
+    )
+}
+
+fn unique_data<E: Element>(data: TensorData) -> (TensorData, TensorData, TensorData) {
+    let shape = data.shape.clone();
+    let elements: Vec<E> = data.to_vec().unwrap();
+
+    let mut order = (0..elements.len()).collect::<Vec<_>>();
+    order.sort_by(|a, b| elements[*a].cmp(&elements[*b]));
+
+    let mut values = Vec::new();
+    let mut counts: Vec<i64> = Vec::new();
+    let mut inverse = vec![0i64; elements.len()];
+
+    for index in order {
+        let element = elements[index];
+        let is_new = match values.last() {
+            Some(last) => element.cmp(last) != Ordering::Equal,
+            None => true,
+        };
+
+        if is_new {
+            values.push(element);
+            counts.push(0);
+        }
+
+        inverse[index] = values.len() as i64 - 1;
+        *counts.last_mut().unwrap() += 1;
+    }
+
+    let num_values = values.len();
+
+    (
+        TensorData::new(values, Shape::new([num_values])),
+        TensorData::new(inverse, shape),
+        TensorData::new(counts, Shape::new([num_values])),
+    )
+}
+
+/// The length of the output of [bincount](crate::Tensor::bincount), one more than the maximum
+/// value of the tensor and at least `min_length`.
+///
+/// The length depends on the values, so the minimum and the maximum values are read together,
+/// and the negative values are rejected.
+pub fn bincount_length<B: Backend>(tensor: IntTensor<B>, min_length: usize) -> usize {
+    if tensor.shape().num_elements() == 0 {
+        return min_length;
+    }
+
+    let bounds = B::int_cat(vec![B::int_min(tensor.clone()), B::int_max(tensor)], 0);
+    let bounds = burn_common::reader::try_read_sync(B::int_into_data(bounds))
+        .expect("Failed to synchronously read the bounds of the values, which give the length of the bincount.")
+        .iter::<i64>()
+        .collect::<Vec<_>>();
+    let [min, max] = [bounds[0], bounds[1]];
+
+    assert!(
+        min >= 0,
+        "Bincount only supports non-negative integers, got {min}."
+    );
+
+    usize::max(max as usize + 1, min_length)
+}
+
+/// The shapes of the sorted sequences `[..., 1, M]`, the values `[..., N, 1]` and their comparison
+/// `[..., N, M]` in [searchsorted](crate::Tensor::searchsorted).
+pub(crate) fn searchsorted_shapes(shape_sorted: &Shape, shape_values: &Shape) -> [Shape; 3] {
+    let rank = shape_values.num_dims();
+    let size = shape_sorted.dims[rank - 1];
+
+    let mut dims_sorted = shape_sorted.dims.clone();
+    dims_sorted.insert(rank - 1, 1);
+    let mut dims_values = shape_values.dims.clone();
+    dims_values.push(1);
+    let mut dims = shape_values.dims.clone();
+    dims.push(size);
+
+    [
+        Shape::from(dims_sorted),
+        Shape::from(dims_values),
+        Shape::from(dims),
+    ]
+}
@@ -173,6 +173,31 @@ pub trait AutodiffBackend: Backend {
         grad: FloatTensor<Self::InnerBackend>,
     );
 
+    /// Pops the row-sparse gradients of a tensor and returns them.
+    ///
+    /// Row-sparse gradients are produced by [embedding_sparse](crate::ops::ModuleOps::embedding_sparse)
+    /// and only contain the rows of the tensor that received a gradient. When a dense gradient
+    /// was also computed for the tensor, both are kept to be returned by
+    /// [grad_remove](AutodiffBackend::grad_remove) instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to pop the gradients from.
+    /// * `grads` - The gradients.
+    ///
+    /// # Returns
+    ///
+    /// An optional tuple with the indices of the rows, which may contain duplicates, and the
+    /// gradients of those rows.
+    #[allow(clippy::type_complexity)]
+    fn grad_remove_rows(
+        tensor: &FloatTensor<Self>,
+        grads: &mut Self::Gradients,
+    ) -> Option<(
+        IntTensor<Self::InnerBackend>,
+        FloatTensor<Self::InnerBackend>,
+    )>;
+
     /// Returns the tensor with inner backend type.
     ///
     /// # Arguments
@@ -75,6 +75,68 @@ impl TensorData {
         }
     }
 
+    /// Creates a new complex tensor data structure from its real and imaginary parts.
+    ///
+    /// The parts are stored interleaved as [Complex64](DType::Complex64) when both are `f64`
+    /// and as [Complex32](DType::Complex32) otherwise.
+    ///
+    /// # Panics
+    ///
+    /// If the parts don't have the same shape.
+    pub fn complex(real: TensorData, imag: TensorData) -> Self {
+        assert_eq!(
+            real.shape, imag.shape,
+            "The real and imaginary parts should have the same shape"
+        );
+
+        if real.dtype == DType::F64 && imag.dtype == DType::F64 {
+            Self::interleave::<f64>(real, imag, DType::Complex64)
+        } else {
+            Self::interleave::<f32>(real, imag, DType::Complex32)
+        }
+    }
+
+    fn interleave<E: Element>(real: TensorData, imag: TensorData, dtype: DType) -> Self {
+        let values = real
+            .iter::<E>()
+            .zip(imag.iter::<E>())
+            .flat_map(|(re, im)| [re, im])
+            .collect::<Vec<E>>();
+
+        Self {
+            bytes: Bytes::from_elems(values),
+            shape: real.shape,
+            dtype,
+        }
+    }
+
+    /// Splits complex tensor data into its real and imaginary parts.
+    ///
+    /// # Panics
+    ///
+    /// If the data type is not complex.
+    pub fn into_complex_parts(self) -> (TensorData, TensorData) {
+        match self.dtype {
+            DType::Complex32 => self.deinterleave::<f32>(),
+            DType::Complex64 => self.deinterleave::<f64>(),
+            _ => panic!("Expected complex data, got {:?}", self.dtype),
+        }
+    }
+
+    fn deinterleave<E: Element>(self) -> (TensorData, TensorData) {
+        let shape = self.shape.clone();
+        let values = self.into_vec_unchecked::<E>().unwrap();
+        let (real, imag): (Vec<E>, Vec<E>) = values
+            .chunks_exact(2)
+            .map(|value| (value[0], value[1]))
+            .unzip();
+
+        (
+            TensorData::new(real, shape.clone()),
+            TensorData::new(imag, shape),
+        )
+    }
+
     /// Creates a new tensor data structure from raw bytes.
     ///
     /// Prefer [`TensorData::new`] or [`TensorData::quantized`] over this method unless you are
@@ -258,6 +320,10 @@ impl TensorData {
                 ),
                 // bool is a byte value equal to either 0 or 1
                 DType::Bool => Box::new(self.bytes.iter().map(|e| e.elem::<E>())),
+                DType::Complex32 | DType::Complex64 => panic!(
+                    "Complex values can't be converted to {:?}, use `into_complex_parts` instead",
+                    E::dtype()
+                ),
                 DType::QFloat(scheme) => match scheme {
                     QuantizationScheme::PerTensor(_mode, QuantizationType::QInt8)
                     | QuantizationScheme::PerBlock(_mode, QuantizationType::QInt8, ..) => {
@@ -358,6 +424,8 @@ impl TensorData {
         } else if dtype.size() == self.dtype.size()
             && !matches!(self.dtype, DType::Bool | DType::QFloat(_))
             && !matches!(dtype, DType::Bool | DType::QFloat(_))
+            && !self.dtype.is_complex()
+            && !dtype.is_complex()
         {
             match self.dtype {
                 DType::F64 => self.convert_inplace_dtype::<f64>(dtype),
@@ -372,7 +440,9 @@ impl TensorData {
                 DType::U32 => self.convert_inplace_dtype::<u32>(dtype),
                 DType::U16 => self.convert_inplace_dtype::<u16>(dtype),
                 DType::U8 => self.convert_inplace_dtype::<u8>(dtype),
-                DType::Bool | DType::QFloat(_) => unreachable!(),
+                DType::Bool | DType::Complex32 | DType::Complex64 | DType::QFloat(_) => {
+                    unreachable!()
+                }
             }
         } else {
             match dtype {
@@ -389,6 +459,11 @@ impl TensorData {
                 DType::U16 => TensorData::new(self.iter::<u16>().collect(), self.shape),
                 DType::U8 => TensorData::new(self.iter::<u8>().collect(), self.shape),
                 DType::Bool => TensorData::new(self.iter::<bool>().collect(), self.shape),
+                DType::Complex32 | DType::Complex64 => {
+                    panic!(
+                        "Real values can't be converted to complex values, use `complex` instead"
+                    )
+                }
                 DType::QFloat(_) => unreachable!(),
             }
         }
@@ -408,7 +483,9 @@ impl TensorData {
             DType::U32 => self.convert_inplace::<Current, u32>(),
             DType::U16 => self.convert_inplace::<Current, u16>(),
             DType::U8 => self.convert_inplace::<Current, u8>(),
-            DType::Bool | DType::QFloat(_) => unreachable!(),
+            DType::Bool | DType::Complex32 | DType::Complex64 | DType::QFloat(_) => {
+                unreachable!()
+            }
         }
     }
 
@@ -528,6 +605,12 @@ impl TensorData {
             DType::U16 => self.assert_eq_elem::<u16>(other),
             DType::U8 => self.assert_eq_elem::<u8>(other),
             DType::Bool => self.assert_eq_elem::<bool>(other),
+            DType::Complex32 | DType::Complex64 => {
+                let (real, imag) = self.clone().into_complex_parts();
+                let (other_real, other_imag) = other.clone().into_complex_parts();
+                real.assert_eq(&other_real, strict);
+                imag.assert_eq(&other_imag, strict);
+            }
             DType::QFloat(q) => {
                 // Strict or not, it doesn't make sense to compare quantized data to not quantized data for equality
                 let q_other = if let DType::QFloat(q_other) = other.dtype {
@@ -600,6 +683,14 @@ impl TensorData {
     /// Panics if the data is not approximately equal.
     #[track_caller]
     pub fn assert_approx_eq_diff(&self, other: &Self, tolerance: f64) {
+        if self.dtype.is_complex() {
+            let (real, imag) = self.clone().into_complex_parts();
+            let (other_real, other_imag) = other.clone().into_complex_parts();
+            real.assert_approx_eq_diff(&other_real, tolerance);
+            imag.assert_approx_eq_diff(&other_imag, tolerance);
+            return;
+        }
+
         let mut message = String::new();
         if self.shape != other.shape {
             message += format!(
@@ -831,6 +922,8 @@ impl core::fmt::Display for TensorData {
             DType::U16 => format!("{:?}", self.as_slice::<u16>().unwrap()),
             DType::U8 => format!("{:?}", self.as_slice::<u8>().unwrap()),
             DType::Bool => format!("{:?}", self.as_slice::<bool>().unwrap()),
+            DType::Complex32 => format!("{:?}", self.try_as_slice::<f32>().unwrap()),
+            DType::Complex64 => format!("{:?}", self.try_as_slice::<f64>().unwrap()),
             DType::QFloat(scheme) => match scheme {
                 QuantizationScheme::PerTensor(_mode, QuantizationType::QInt8)
                 | QuantizationScheme::PerBlock(_mode, QuantizationType::QInt8, ..) => {
@@ -1007,4 +1100,49 @@ mod tests {
 
         output.assert_approx_eq(&TensorData::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]), 4);
     }
+
+    #[test]
+    fn should_support_complex_parts() {
+        let real = TensorData::from([[1.0f32, 2.0], [3.0, 4.0]]);
+        let imag = TensorData::from([[-1.0f32, 0.5], [0.0, 2.0]]);
+
+        let data = TensorData::complex(real.clone(), imag.clone());
+        assert_eq!(data.dtype, DType::Complex32);
+        assert_eq!(data.shape, vec![2, 2]);
+        assert_eq!(data.bytes.len(), 4 * DType::Complex32.size());
+
+        let (output_real, output_imag) = data.into_complex_parts();
+        output_real.assert_eq(&real, true);
+        output_imag.assert_eq(&imag, true);
+    }
+
+    #[test]
+    fn should_keep_complex64_precision() {
+        let real = TensorData::from([1.0f64 + f64::EPSILON, 2.0]);
+        let imag = TensorData::from([3.0f64, -4.0]);
+
+        let data = TensorData::complex(real.clone(), imag.clone());
+        assert_eq!(data.dtype, DType::Complex64);
+
+        let (output_real, output_imag) = data.into_complex_parts();
+        output_real.assert_eq(&real, true);
+        output_imag.assert_eq(&imag, true);
+    }
+
+    #[test]
+    fn should_serialize_complex_data() {
+        let data = TensorData::complex(
+            TensorData::from([1.0f32, -2.0]),
+            TensorData::from([0.5f32, 3.0]),
+        );
+
+        let config = bincode::config::standard();
+        let serialized =
+            bincode::serde::encode_to_vec(&data, config).expect("serialization to succeed");
+        let (output, _): (TensorData, _) = bincode::serde::decode_from_slice(&serialized, config)
+            .expect("deserialization to succeed");
+
+        assert_eq!(output.dtype, DType::Complex32);
+        output.assert_eq(&data, true);
+    }
 }
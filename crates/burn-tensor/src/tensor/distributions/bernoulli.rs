@@ -0,0 +1,60 @@
+use super::{softplus, Distribution, KlDivergence};
+use crate::{activation, backend::Backend, Tensor};
+
+/// The Bernoulli distribution, over the values `0` and `1`.
+#[derive(Clone, Debug)]
+pub struct Bernoulli<B: Backend, const D: usize> {
+    logits: Tensor<B, D>,
+}
+
+impl<B: Backend, const D: usize> Bernoulli<B, D> {
+    /// Creates a Bernoulli distribution with the given probabilities of sampling `1`.
+    pub fn from_probs(probs: Tensor<B, D>) -> Self {
+        // The probabilities are clamped so that the log-odds are finite.
+        let probs = probs.clamp(f32::EPSILON, 1.0 - f32::EPSILON);
+        let logits = probs.clone().log() - probs.neg().log1p();
+
+        Self { logits }
+    }
+
+    /// Creates a Bernoulli distribution with the given log-odds of sampling `1`.
+    pub fn from_logits(logits: Tensor<B, D>) -> Self {
+        Self { logits }
+    }
+
+    /// Returns the probabilities of sampling `1`.
+    pub fn probs(&self) -> Tensor<B, D> {
+        activation::sigmoid(self.logits.clone())
+    }
+
+    /// Returns the log-odds of sampling `1`.
+    pub fn logits(&self) -> Tensor<B, D> {
+        self.logits.clone()
+    }
+}
+
+impl<B: Backend, const D: usize> Distribution<B, D> for Bernoulli<B, D> {
+    fn sample(&self) -> Tensor<B, D> {
+        let probs = self.probs().detach();
+        let uniform = probs.random_like(crate::Distribution::Default);
+
+        uniform.lower(probs).float()
+    }
+
+    fn log_prob(&self, value: Tensor<B, D>) -> Tensor<B, D> {
+        value * self.logits.clone() - softplus(self.logits.clone())
+    }
+
+    fn entropy(&self) -> Tensor<B, D> {
+        softplus(self.logits.clone()) - self.probs() * self.logits.clone()
+    }
+}
+
+impl<B: Backend, const D: usize> KlDivergence<B, D> for Bernoulli<B, D> {
+    fn kl_divergence(&self, other: &Self) -> Tensor<B, D> {
+        let logits_difference = self.logits.clone() - other.logits.clone();
+
+        self.probs() * logits_difference + softplus(other.logits.clone())
+            - softplus(self.logits.clone())
+    }
+}
@@ -0,0 +1,76 @@
+use super::{gamma::sample_standard_gamma, log_beta, Distribution, KlDivergence};
+use crate::{backend::Backend, Tensor};
+
+/// The beta distribution over the interval `(0, 1)`.
+#[derive(Clone, Debug)]
+pub struct Beta<B: Backend, const D: usize> {
+    /// The first concentration parameter, often written `alpha`.
+    pub concentration1: Tensor<B, D>,
+    /// The second concentration parameter, often written `beta`.
+    pub concentration0: Tensor<B, D>,
+}
+
+impl<B: Backend, const D: usize> Beta<B, D> {
+    /// Creates a beta distribution with the given concentrations, which must have the same shape.
+    pub fn new(concentration1: Tensor<B, D>, concentration0: Tensor<B, D>) -> Self {
+        Self {
+            concentration1,
+            concentration0,
+        }
+    }
+
+    /// Returns the mean of the distribution.
+    pub fn mean(&self) -> Tensor<B, D> {
+        let total = self.concentration1.clone() + self.concentration0.clone();
+        self.concentration1.clone() / total
+    }
+
+    /// Returns the variance of the distribution.
+    pub fn variance(&self) -> Tensor<B, D> {
+        let total = self.concentration1.clone() + self.concentration0.clone();
+
+        self.concentration1.clone() * self.concentration0.clone()
+            / (total.clone().powi_scalar(2) * (total + 1))
+    }
+}
+
+impl<B: Backend, const D: usize> Distribution<B, D> for Beta<B, D> {
+    fn sample(&self) -> Tensor<B, D> {
+        // X / (X + Y) follows a beta distribution when X and Y follow gamma distributions.
+        let x = sample_standard_gamma(self.concentration1.clone());
+        let y = sample_standard_gamma(self.concentration0.clone());
+
+        x.clone() / (x + y)
+    }
+
+    fn log_prob(&self, value: Tensor<B, D>) -> Tensor<B, D> {
+        let (a, b) = (self.concentration1.clone(), self.concentration0.clone());
+
+        (a.clone() - 1) * value.clone().log() + (b.clone() - 1) * value.neg().log1p()
+            - log_beta(a, b)
+    }
+
+    fn entropy(&self) -> Tensor<B, D> {
+        let (a, b) = (self.concentration1.clone(), self.concentration0.clone());
+        let total = a.clone() + b.clone();
+
+        log_beta(a.clone(), b.clone())
+            - (a.clone() - 1) * a.digamma()
+            - (b.clone() - 1) * b.digamma()
+            + (total.clone() - 2) * total.digamma()
+    }
+}
+
+impl<B: Backend, const D: usize> KlDivergence<B, D> for Beta<B, D> {
+    fn kl_divergence(&self, other: &Self) -> Tensor<B, D> {
+        let (a_p, b_p) = (self.concentration1.clone(), self.concentration0.clone());
+        let (a_q, b_q) = (other.concentration1.clone(), other.concentration0.clone());
+        let total_p = a_p.clone() + b_p.clone();
+        let total_q = a_q.clone() + b_q.clone();
+
+        log_beta(a_q.clone(), b_q.clone()) - log_beta(a_p.clone(), b_p.clone())
+            + (a_p.clone() - a_q) * a_p.digamma()
+            + (b_p.clone() - b_q) * b_p.digamma()
+            + (total_q - total_p.clone()) * total_p.digamma()
+    }
+}
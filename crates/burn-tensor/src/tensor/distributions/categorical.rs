@@ -0,0 +1,78 @@
+use super::{Distribution, KlDivergence};
+use crate::{activation, backend::Backend, Int, Tensor};
+
+/// The categorical distribution over the indices of the last dimension of its parameters.
+///
+/// The samples are the indices of the categories, with the last dimension of size 1, the same way
+/// as [argmax](Tensor::argmax).
+#[derive(Clone, Debug)]
+pub struct Categorical<B: Backend, const D: usize> {
+    /// The normalized log probabilities.
+    logits: Tensor<B, D>,
+}
+
+impl<B: Backend, const D: usize> Categorical<B, D> {
+    /// Creates a categorical distribution with the given probabilities for each category, which
+    /// are normalized along the last dimension.
+    pub fn from_probs(probs: Tensor<B, D>) -> Self {
+        let probs = probs.clone() / probs.sum_dim(D - 1);
+
+        Self {
+            logits: probs.log(),
+        }
+    }
+
+    /// Creates a categorical distribution with the given unnormalized log probabilities for each
+    /// category.
+    pub fn from_logits(logits: Tensor<B, D>) -> Self {
+        Self {
+            logits: activation::log_softmax(logits, D - 1),
+        }
+    }
+
+    /// Returns the probabilities of each category.
+    pub fn probs(&self) -> Tensor<B, D> {
+        self.logits.clone().exp()
+    }
+
+    /// Returns the normalized log probabilities of each category.
+    pub fn logits(&self) -> Tensor<B, D> {
+        self.logits.clone()
+    }
+
+    /// Returns `probs * (logits - other_logits)`, where the categories with a probability of
+    /// zero don't contribute.
+    fn weighted_log_ratio(&self, other_logits: Tensor<B, D>) -> Tensor<B, D> {
+        let probs = self.probs();
+        let impossible = probs.clone().equal_elem(0.0);
+
+        (probs * (self.logits.clone() - other_logits)).mask_fill(impossible, 0.0)
+    }
+}
+
+impl<B: Backend, const D: usize> Distribution<B, D, Int> for Categorical<B, D> {
+    fn sample(&self) -> Tensor<B, D, Int> {
+        // The Gumbel-max trick: argmax(logits + g) with g ~ Gumbel(0, 1) is a categorical sample.
+        let logits = self.logits.clone().detach();
+        let uniform = logits.random_like(crate::Distribution::Default);
+        let gumbel = uniform.log().neg().log().neg();
+
+        (logits + gumbel).argmax(D - 1)
+    }
+
+    fn log_prob(&self, value: Tensor<B, D, Int>) -> Tensor<B, D> {
+        self.logits.clone().gather(D - 1, value)
+    }
+
+    fn entropy(&self) -> Tensor<B, D> {
+        self.weighted_log_ratio(self.logits.zeros_like())
+            .sum_dim(D - 1)
+            .neg()
+    }
+}
+
+impl<B: Backend, const D: usize> KlDivergence<B, D> for Categorical<B, D> {
+    fn kl_divergence(&self, other: &Self) -> Tensor<B, D> {
+        self.weighted_log_ratio(other.logits.clone()).sum_dim(D - 1)
+    }
+}
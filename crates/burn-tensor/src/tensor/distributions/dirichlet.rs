@@ -0,0 +1,66 @@
+use super::{gamma::sample_standard_gamma, Distribution, KlDivergence};
+use crate::{backend::Backend, Tensor};
+
+/// The Dirichlet distribution over the probability vectors of the last dimension.
+///
+/// The [log_prob](Distribution::log_prob) and the [entropy](Distribution::entropy) keep the
+/// last dimension with a size of 1.
+#[derive(Clone, Debug)]
+pub struct Dirichlet<B: Backend, const D: usize> {
+    /// The concentration of each category, in the last dimension.
+    pub concentration: Tensor<B, D>,
+}
+
+impl<B: Backend, const D: usize> Dirichlet<B, D> {
+    /// Creates a Dirichlet distribution with the given concentrations.
+    pub fn new(concentration: Tensor<B, D>) -> Self {
+        Self { concentration }
+    }
+
+    /// Returns the mean of the distribution.
+    pub fn mean(&self) -> Tensor<B, D> {
+        let total = self.concentration.clone().sum_dim(D - 1);
+        self.concentration.clone() / total
+    }
+}
+
+impl<B: Backend, const D: usize> Distribution<B, D> for Dirichlet<B, D> {
+    fn sample(&self) -> Tensor<B, D> {
+        // Normalized gamma samples follow a Dirichlet distribution.
+        let samples = sample_standard_gamma(self.concentration.clone());
+        samples.clone() / samples.sum_dim(D - 1)
+    }
+
+    fn log_prob(&self, value: Tensor<B, D>) -> Tensor<B, D> {
+        let alpha = self.concentration.clone();
+        let total = alpha.clone().sum_dim(D - 1);
+
+        ((alpha.clone() - 1) * value.log()).sum_dim(D - 1) + total.lgamma()
+            - alpha.lgamma().sum_dim(D - 1)
+    }
+
+    fn entropy(&self) -> Tensor<B, D> {
+        let alpha = self.concentration.clone();
+        let num_categories = alpha.dims()[D - 1] as f64;
+        let total = alpha.clone().sum_dim(D - 1);
+
+        alpha.clone().lgamma().sum_dim(D - 1) - total.clone().lgamma()
+            + (total.clone() - num_categories) * total.digamma()
+            - ((alpha.clone() - 1) * alpha.digamma()).sum_dim(D - 1)
+    }
+}
+
+impl<B: Backend, const D: usize> KlDivergence<B, D> for Dirichlet<B, D> {
+    fn kl_divergence(&self, other: &Self) -> Tensor<B, D> {
+        let alpha = self.concentration.clone();
+        let beta = other.concentration.clone();
+        let total_alpha = alpha.clone().sum_dim(D - 1);
+        let total_beta = beta.clone().sum_dim(D - 1);
+
+        let expected_log = alpha.clone().digamma() - total_alpha.clone().digamma();
+
+        total_alpha.lgamma() - alpha.clone().lgamma().sum_dim(D - 1) - total_beta.lgamma()
+            + beta.clone().lgamma().sum_dim(D - 1)
+            + ((alpha - beta) * expected_log).sum_dim(D - 1)
+    }
+}
@@ -0,0 +1,112 @@
+use super::{Distribution, KlDivergence};
+use crate::{backend::Backend, ElementConversion, Int, Tensor};
+
+/// The gamma distribution, parameterized by its shape (concentration) and its rate.
+#[derive(Clone, Debug)]
+pub struct Gamma<B: Backend, const D: usize> {
+    /// The shape of the distribution, often written `alpha`.
+    pub concentration: Tensor<B, D>,
+    /// The inverse scale of the distribution, often written `beta`.
+    pub rate: Tensor<B, D>,
+}
+
+impl<B: Backend, const D: usize> Gamma<B, D> {
+    /// Creates a gamma distribution with the given concentration and rate, which must have the
+    /// same shape.
+    pub fn new(concentration: Tensor<B, D>, rate: Tensor<B, D>) -> Self {
+        Self {
+            concentration,
+            rate,
+        }
+    }
+
+    /// Returns the mean of the distribution.
+    pub fn mean(&self) -> Tensor<B, D> {
+        self.concentration.clone() / self.rate.clone()
+    }
+
+    /// Returns the variance of the distribution.
+    pub fn variance(&self) -> Tensor<B, D> {
+        self.concentration.clone() / self.rate.clone().powi_scalar(2)
+    }
+}
+
+impl<B: Backend, const D: usize> Distribution<B, D> for Gamma<B, D> {
+    fn sample(&self) -> Tensor<B, D> {
+        sample_standard_gamma(self.concentration.clone()) / self.rate.clone().detach()
+    }
+
+    fn log_prob(&self, value: Tensor<B, D>) -> Tensor<B, D> {
+        let alpha = self.concentration.clone();
+
+        alpha.clone() * self.rate.clone().log() + (alpha.clone() - 1) * value.clone().log()
+            - self.rate.clone() * value
+            - alpha.lgamma()
+    }
+
+    fn entropy(&self) -> Tensor<B, D> {
+        let alpha = self.concentration.clone();
+
+        alpha.clone() - self.rate.clone().log()
+            + alpha.clone().lgamma()
+            + (alpha.clone().neg() + 1) * alpha.digamma()
+    }
+}
+
+impl<B: Backend, const D: usize> KlDivergence<B, D> for Gamma<B, D> {
+    fn kl_divergence(&self, other: &Self) -> Tensor<B, D> {
+        let (alpha_p, beta_p) = (self.concentration.clone(), self.rate.clone());
+        let (alpha_q, beta_q) = (other.concentration.clone(), other.rate.clone());
+
+        (alpha_p.clone() - alpha_q.clone()) * alpha_p.clone().digamma() - alpha_p.clone().lgamma()
+            + alpha_q.clone().lgamma()
+            + alpha_q * (beta_p.clone().log() - beta_q.clone().log())
+            + alpha_p * (beta_q - beta_p.clone()) / beta_p
+    }
+}
+
+/// Samples the gamma distribution with the given concentration and a rate of 1.
+///
+/// The samples are drawn with the rejection method of Marsaglia and Tsang, where the rejected
+/// elements are sampled again until every element is accepted.
+pub(crate) fn sample_standard_gamma<B: Backend, const D: usize>(
+    concentration: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let alpha = concentration.detach();
+
+    // Gamma(alpha) = Gamma(alpha + 1) * U^(1 / alpha) for the concentrations below 1.
+    let boosted = alpha.clone().lower_elem(1.0);
+    let alpha_boosted = alpha.clone().mask_where(boosted.clone(), alpha.clone() + 1);
+
+    let d = alpha_boosted - 1.0 / 3.0;
+    let c = (d.clone() * 9).sqrt().recip();
+
+    let mut output = d.zeros_like();
+    let mut accepted = Tensor::<B, D, Int>::zeros(d.shape(), &d.device()).bool();
+
+    loop {
+        let x = d.random_like(crate::Distribution::Normal(0.0, 1.0));
+        let uniform = d.random_like(crate::Distribution::Default);
+
+        let v = (c.clone() * x.clone() + 1).powi_scalar(3);
+        let bound = x.powi_scalar(2).div_scalar(2.0) + d.clone() - d.clone() * v.clone()
+            + d.clone() * v.clone().log();
+        let accept = v
+            .clone()
+            .greater_elem(0.0)
+            .bool_and(uniform.log().lower(bound))
+            .bool_and(accepted.clone().bool_not());
+
+        output = output.mask_where(accept.clone(), d.clone() * v);
+        accepted = accepted.bool_or(accept);
+
+        if accepted.clone().all().into_scalar().elem::<bool>() {
+            break;
+        }
+    }
+
+    let uniform = output.random_like(crate::Distribution::Default);
+    let boost = uniform.powf(alpha.recip());
+
+    output.clone().mask_where(boosted, output * boost)
+}
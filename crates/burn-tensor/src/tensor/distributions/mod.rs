@@ -0,0 +1,101 @@
+mod bernoulli;
+mod beta;
+mod categorical;
+mod dirichlet;
+mod gamma;
+mod multivariate_normal;
+mod normal;
+mod poisson;
+
+pub use bernoulli::*;
+pub use beta::*;
+pub use categorical::*;
+pub use dirichlet::*;
+pub use gamma::*;
+pub use multivariate_normal::*;
+pub use normal::*;
+pub use poisson::*;
+
+use crate::{backend::Backend, Float, Tensor, TensorKind};
+
+/// A probability distribution whose parameters are tensors.
+///
+/// Each element of the parameters defines an independent distribution, so a distribution with
+/// parameters of shape `[batch_size, num_features]` describes `batch_size * num_features`
+/// distributions. Distributions over vectors, such as [Categorical], [Dirichlet] and
+/// [MultivariateNormal], use the last dimension for the event and keep it with a size of 1 in
+/// the results of [log_prob](Distribution::log_prob) and [entropy](Distribution::entropy).
+///
+/// The computations are made with tensor operations, so the log probabilities and the entropy
+/// can be differentiated with respect to the parameters when using an autodiff backend.
+pub trait Distribution<B: Backend, const D: usize, K: TensorKind<B> = Float> {
+    /// Draws one sample of the distribution for each element of the batch.
+    ///
+    /// The samples are detached from the autodiff graph, see
+    /// [rsample](Reparameterized::rsample) for differentiable samples.
+    fn sample(&self) -> Tensor<B, D, K>;
+
+    /// Computes the logarithm of the probability density, or of the probability mass for
+    /// discrete distributions, of the given value.
+    fn log_prob(&self, value: Tensor<B, D, K>) -> Tensor<B, D>;
+
+    /// Computes the entropy of the distribution.
+    fn entropy(&self) -> Tensor<B, D>;
+}
+
+/// A distribution that can be sampled with the reparameterization trick.
+pub trait Reparameterized<B: Backend, const D: usize>: Distribution<B, D> {
+    /// Draws one sample of the distribution for each element of the batch, as a differentiable
+    /// function of the parameters.
+    fn rsample(&self) -> Tensor<B, D>;
+}
+
+/// The Kullback-Leibler divergence between two distributions.
+pub trait KlDivergence<B: Backend, const D: usize, Rhs = Self> {
+    /// Computes the Kullback-Leibler divergence `KL(self || other)`.
+    fn kl_divergence(&self, other: &Rhs) -> Tensor<B, D>;
+}
+
+/// Computes the Kullback-Leibler divergence `KL(p || q)` between two distributions.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::distributions::{kl_divergence, Normal};
+/// use burn_tensor::Tensor;
+///
+/// fn example<B: Backend>() {
+///     let device = B::Device::default();
+///     let p = Normal::new(
+///         Tensor::<B, 1>::from_floats([0.0, 1.0], &device),
+///         Tensor::from_floats([1.0, 2.0], &device),
+///     );
+///     let q = Normal::new(
+///         Tensor::<B, 1>::from_floats([0.0, 0.0], &device),
+///         Tensor::from_floats([1.0, 1.0], &device),
+///     );
+///
+///     let kl = kl_divergence(&p, &q);
+///     println!("{kl}");
+///     // [0.0, 1.3068528]
+/// }
+/// ```
+pub fn kl_divergence<B, const D: usize, P, Q>(p: &P, q: &Q) -> Tensor<B, D>
+where
+    B: Backend,
+    P: KlDivergence<B, D, Q>,
+{
+    p.kl_divergence(q)
+}
+
+/// Numerically stable `log(1 + exp(x))`.
+fn softplus<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let negative_abs = tensor.clone().abs().neg();
+    tensor.clamp_min(0) + negative_abs.exp().log1p()
+}
+
+/// Logarithm of the beta function, `log(gamma(a) * gamma(b) / gamma(a + b))`.
+fn log_beta<B: Backend, const D: usize>(a: Tensor<B, D>, b: Tensor<B, D>) -> Tensor<B, D> {
+    a.clone().lgamma() + b.clone().lgamma() - (a + b).lgamma()
+}
@@ -0,0 +1,152 @@
+use core::f64::consts::PI;
+
+use super::{Distribution, KlDivergence, Reparameterized};
+use crate::{backend::Backend, Tensor};
+
+/// The multivariate normal distribution over the vectors of the last dimension, parameterized
+/// by its mean and the lower triangular Cholesky factor of its covariance.
+///
+/// The mean has the shape `[batch_size, num_features]` and the Cholesky factor has the shape
+/// `[batch_size, num_features, num_features]`. The [log_prob](Distribution::log_prob) and the
+/// [entropy](Distribution::entropy) have the shape `[batch_size, 1]`.
+#[derive(Clone, Debug)]
+pub struct MultivariateNormal<B: Backend> {
+    /// The mean of the distribution.
+    pub loc: Tensor<B, 2>,
+    /// The lower triangular Cholesky factor of the covariance, with a positive diagonal.
+    pub scale_tril: Tensor<B, 3>,
+}
+
+impl<B: Backend> MultivariateNormal<B> {
+    /// Creates a multivariate normal distribution with the given mean and Cholesky factor of the
+    /// covariance.
+    pub fn new(loc: Tensor<B, 2>, scale_tril: Tensor<B, 3>) -> Self {
+        Self { loc, scale_tril }
+    }
+
+    /// Creates a multivariate normal distribution with the given mean and symmetric positive
+    /// definite covariance.
+    pub fn from_covariance(loc: Tensor<B, 2>, covariance: Tensor<B, 3>) -> Self {
+        Self::new(loc, cholesky(covariance))
+    }
+
+    /// Returns the mean of the distribution.
+    pub fn mean(&self) -> Tensor<B, 2> {
+        self.loc.clone()
+    }
+
+    /// Returns the covariance of the distribution.
+    pub fn covariance(&self) -> Tensor<B, 3> {
+        let scale_tril = self.scale_tril.clone();
+        scale_tril.clone().matmul(scale_tril.swap_dims(1, 2))
+    }
+
+    /// The sum of the logarithms of the diagonal of the Cholesky factor, which is half the
+    /// logarithm of the determinant of the covariance.
+    fn half_log_det(&self) -> Tensor<B, 2> {
+        self.scale_tril
+            .clone()
+            .diagonal::<2>(0, 1, 2)
+            .log()
+            .sum_dim(1)
+    }
+}
+
+impl<B: Backend> Distribution<B, 2> for MultivariateNormal<B> {
+    fn sample(&self) -> Tensor<B, 2> {
+        self.rsample().detach()
+    }
+
+    fn log_prob(&self, value: Tensor<B, 2>) -> Tensor<B, 2> {
+        let [batch_size, num_features] = self.loc.dims();
+        let difference = (value - self.loc.clone()).unsqueeze_dim::<3>(2);
+        let z = solve_lower(self.scale_tril.clone(), difference);
+        let mahalanobis = z.powi_scalar(2).sum_dim(1).reshape([batch_size, 1]);
+
+        mahalanobis.div_scalar(-2.0)
+            - self.half_log_det()
+            - num_features as f64 * 0.5 * (2.0 * PI).ln()
+    }
+
+    fn entropy(&self) -> Tensor<B, 2> {
+        let num_features = self.loc.dims()[1] as f64;
+
+        self.half_log_det() + num_features * 0.5 * (1.0 + (2.0 * PI).ln())
+    }
+}
+
+impl<B: Backend> Reparameterized<B, 2> for MultivariateNormal<B> {
+    fn rsample(&self) -> Tensor<B, 2> {
+        let noise = self
+            .loc
+            .random_like(crate::Distribution::Normal(0.0, 1.0))
+            .unsqueeze_dim::<3>(2);
+
+        self.loc.clone() + self.scale_tril.clone().matmul(noise).squeeze(2)
+    }
+}
+
+impl<B: Backend> KlDivergence<B, 2> for MultivariateNormal<B> {
+    fn kl_divergence(&self, other: &Self) -> Tensor<B, 2> {
+        let [batch_size, num_features] = self.loc.dims();
+
+        // tr(inv(cov_q) cov_p) is the squared Frobenius norm of inv(L_q) L_p.
+        let ratio = solve_lower(other.scale_tril.clone(), self.scale_tril.clone());
+        let trace = ratio
+            .powi_scalar(2)
+            .sum_dim(1)
+            .sum_dim(2)
+            .reshape([batch_size, 1]);
+
+        let difference = (other.loc.clone() - self.loc.clone()).unsqueeze_dim::<3>(2);
+        let z = solve_lower(other.scale_tril.clone(), difference);
+        let mahalanobis = z.powi_scalar(2).sum_dim(1).reshape([batch_size, 1]);
+
+        let log_det_ratio = (other.half_log_det() - self.half_log_det()).mul_scalar(2.0);
+
+        (trace + mahalanobis + log_det_ratio - num_features as f64).div_scalar(2.0)
+    }
+}
+
+/// Computes the lower triangular Cholesky factor of a batch of symmetric positive definite
+/// matrices, one column at a time.
+fn cholesky<B: Backend>(matrix: Tensor<B, 3>) -> Tensor<B, 3> {
+    let [batch_size, size, _] = matrix.dims();
+    let mut output = matrix.zeros_like();
+
+    for j in 0..size {
+        let mut column = matrix.clone().slice([0..batch_size, j..size, j..j + 1]);
+
+        if j > 0 {
+            let previous = output.clone().slice([0..batch_size, j..size, 0..j]);
+            let row = output.clone().slice([0..batch_size, j..j + 1, 0..j]);
+            column = column - previous.matmul(row.swap_dims(1, 2));
+        }
+
+        let diagonal = column.clone().slice([0..batch_size, 0..1, 0..1]).sqrt();
+        output = output.slice_assign([0..batch_size, j..size, j..j + 1], column / diagonal);
+    }
+
+    output
+}
+
+/// Solves `lhs @ x = rhs` for a batch of lower triangular matrices with forward substitution.
+fn solve_lower<B: Backend>(lhs: Tensor<B, 3>, rhs: Tensor<B, 3>) -> Tensor<B, 3> {
+    let [batch_size, size, num_columns] = rhs.dims();
+    let mut output = rhs.zeros_like();
+
+    for i in 0..size {
+        let mut row = rhs.clone().slice([0..batch_size, i..i + 1, 0..num_columns]);
+
+        if i > 0 {
+            let coefficients = lhs.clone().slice([0..batch_size, i..i + 1, 0..i]);
+            let previous = output.clone().slice([0..batch_size, 0..i, 0..num_columns]);
+            row = row - coefficients.matmul(previous);
+        }
+
+        let diagonal = lhs.clone().slice([0..batch_size, i..i + 1, i..i + 1]);
+        output = output.slice_assign([0..batch_size, i..i + 1, 0..num_columns], row / diagonal);
+    }
+
+    output
+}
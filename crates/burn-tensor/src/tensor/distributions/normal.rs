@@ -0,0 +1,65 @@
+use core::f64::consts::PI;
+
+use super::{Distribution, KlDivergence, Reparameterized};
+use crate::{backend::Backend, Tensor};
+
+/// The normal (Gaussian) distribution, parameterized by its mean and standard deviation.
+#[derive(Clone, Debug)]
+pub struct Normal<B: Backend, const D: usize> {
+    /// The mean of the distribution.
+    pub loc: Tensor<B, D>,
+    /// The standard deviation of the distribution.
+    pub scale: Tensor<B, D>,
+}
+
+impl<B: Backend, const D: usize> Normal<B, D> {
+    /// Creates a normal distribution with the given mean and standard deviation, which must have
+    /// the same shape.
+    pub fn new(loc: Tensor<B, D>, scale: Tensor<B, D>) -> Self {
+        Self { loc, scale }
+    }
+
+    /// Returns the mean of the distribution.
+    pub fn mean(&self) -> Tensor<B, D> {
+        self.loc.clone()
+    }
+
+    /// Returns the variance of the distribution.
+    pub fn variance(&self) -> Tensor<B, D> {
+        self.scale.clone().powi_scalar(2)
+    }
+}
+
+impl<B: Backend, const D: usize> Distribution<B, D> for Normal<B, D> {
+    fn sample(&self) -> Tensor<B, D> {
+        self.rsample().detach()
+    }
+
+    fn log_prob(&self, value: Tensor<B, D>) -> Tensor<B, D> {
+        let z = (value - self.loc.clone()) / self.scale.clone();
+
+        z.powi_scalar(2).div_scalar(-2.0) - self.scale.clone().log() - 0.5 * (2.0 * PI).ln()
+    }
+
+    fn entropy(&self) -> Tensor<B, D> {
+        self.scale.clone().log() + 0.5 + 0.5 * (2.0 * PI).ln()
+    }
+}
+
+impl<B: Backend, const D: usize> Reparameterized<B, D> for Normal<B, D> {
+    fn rsample(&self) -> Tensor<B, D> {
+        let noise = self.loc.random_like(crate::Distribution::Normal(0.0, 1.0));
+
+        self.loc.clone() + self.scale.clone() * noise
+    }
+}
+
+impl<B: Backend, const D: usize> KlDivergence<B, D> for Normal<B, D> {
+    fn kl_divergence(&self, other: &Self) -> Tensor<B, D> {
+        let variance_ratio = (self.scale.clone() / other.scale.clone()).powi_scalar(2);
+        let mean_difference =
+            ((self.loc.clone() - other.loc.clone()) / other.scale.clone()).powi_scalar(2);
+
+        (variance_ratio.clone() + mean_difference - variance_ratio.log() - 1).div_scalar(2.0)
+    }
+}
@@ -0,0 +1,173 @@
+use core::f64::consts::PI;
+
+use super::{Distribution, KlDivergence};
+use crate::{backend::Backend, ElementConversion, Int, Tensor};
+
+/// The rate above which the samples are drawn with the transformed rejection method.
+const REJECTION_THRESHOLD: f64 = 10.0;
+/// The rate above which the entropy is computed with its asymptotic expansion.
+const ASYMPTOTIC_THRESHOLD: f64 = 20.0;
+/// The number of terms of the explicit entropy sum used for the small rates.
+const ENTROPY_TERMS: usize = 60;
+
+/// The Poisson distribution over the non-negative integers, parameterized by its rate.
+///
+/// The samples and the values are represented as float tensors.
+#[derive(Clone, Debug)]
+pub struct Poisson<B: Backend, const D: usize> {
+    /// The rate of the distribution, which is also its mean.
+    pub rate: Tensor<B, D>,
+}
+
+impl<B: Backend, const D: usize> Poisson<B, D> {
+    /// Creates a Poisson distribution with the given rate.
+    pub fn new(rate: Tensor<B, D>) -> Self {
+        Self { rate }
+    }
+
+    /// Returns the mean of the distribution.
+    pub fn mean(&self) -> Tensor<B, D> {
+        self.rate.clone()
+    }
+
+    /// Returns the variance of the distribution.
+    pub fn variance(&self) -> Tensor<B, D> {
+        self.rate.clone()
+    }
+}
+
+impl<B: Backend, const D: usize> Distribution<B, D> for Poisson<B, D> {
+    fn sample(&self) -> Tensor<B, D> {
+        let rate = self.rate.clone().detach();
+        let small = rate.clone().lower_elem(REJECTION_THRESHOLD);
+
+        let multiplication = sample_multiplication(rate.clone().clamp_max(REJECTION_THRESHOLD));
+        let rejection = sample_rejection(rate.clamp_min(REJECTION_THRESHOLD));
+
+        rejection.mask_where(small, multiplication)
+    }
+
+    fn log_prob(&self, value: Tensor<B, D>) -> Tensor<B, D> {
+        value.clone() * self.rate.clone().log() - self.rate.clone() - (value + 1).lgamma()
+    }
+
+    fn entropy(&self) -> Tensor<B, D> {
+        let rate = self.rate.clone();
+
+        // The entropy of the small rates is the explicit sum of -p(k) * log(p(k)).
+        let small_rate = rate
+            .clone()
+            .clamp(f32::MIN_POSITIVE as f64, ASYMPTOTIC_THRESHOLD);
+        let log_rate = small_rate.clone().log();
+        let mut log_factorial = 0.0;
+        let mut sum = small_rate.zeros_like();
+
+        for k in 0..=ENTROPY_TERMS {
+            if k > 0 {
+                log_factorial += (k as f64).ln();
+            }
+
+            let log_prob = log_rate.clone() * k as f64 - small_rate.clone() - log_factorial;
+            sum = sum - log_prob.clone().exp() * log_prob;
+        }
+
+        // H = log(2 pi e rate) / 2 - 1 / (12 rate) - 1 / (24 rate^2) - 19 / (360 rate^3) + ...
+        let large_rate = rate.clone().clamp_min(ASYMPTOTIC_THRESHOLD);
+        let inverse = large_rate.clone().recip();
+        let asymptotic = (large_rate * (2.0 * PI))
+            .log()
+            .add_scalar(1.0)
+            .div_scalar(2.0)
+            - inverse.clone().div_scalar(12.0)
+            - inverse.clone().powi_scalar(2).div_scalar(24.0)
+            - inverse.powi_scalar(3).mul_scalar(19.0 / 360.0);
+
+        let output = asymptotic.mask_where(rate.clone().lower_elem(ASYMPTOTIC_THRESHOLD), sum);
+        output.mask_fill(rate.equal_elem(0.0), 0.0)
+    }
+}
+
+impl<B: Backend, const D: usize> KlDivergence<B, D> for Poisson<B, D> {
+    fn kl_divergence(&self, other: &Self) -> Tensor<B, D> {
+        let (rate_p, rate_q) = (self.rate.clone(), other.rate.clone());
+
+        rate_p.clone() * (rate_p.clone().log() - rate_q.clone().log()) - rate_p + rate_q
+    }
+}
+
+/// Samples the Poisson distribution by counting the uniform samples that can be multiplied
+/// before the product goes below `exp(-rate)`, which is efficient for the small rates.
+fn sample_multiplication<B: Backend, const D: usize>(rate: Tensor<B, D>) -> Tensor<B, D> {
+    let limit = rate.neg().exp();
+    let mut product = limit.ones_like();
+    let mut count = limit.zeros_like();
+
+    loop {
+        product = product.clone() * product.random_like(crate::Distribution::Default);
+        let active = product.clone().greater(limit.clone());
+
+        if !active.clone().any().into_scalar().elem::<bool>() {
+            break;
+        }
+
+        count = count + active.float();
+    }
+
+    count
+}
+
+/// Samples the Poisson distribution with the transformed rejection method of Hörmann (PTRS),
+/// where the rejected elements are sampled again until every element is accepted.
+fn sample_rejection<B: Backend, const D: usize>(rate: Tensor<B, D>) -> Tensor<B, D> {
+    let log_rate = rate.clone().log();
+    let b = rate.clone().sqrt().mul_scalar(2.53).add_scalar(0.931);
+    let a = b.clone().mul_scalar(0.02483).sub_scalar(0.059);
+    let log_inverse_alpha = (b.clone().sub_scalar(3.4).recip() * 1.1328 + 1.1239).log();
+    let v_r = b
+        .clone()
+        .sub_scalar(2.0)
+        .recip()
+        .mul_scalar(-3.6224)
+        .add_scalar(0.9277);
+
+    let mut output = rate.zeros_like();
+    let mut accepted = Tensor::<B, D, Int>::zeros(rate.shape(), &rate.device()).bool();
+
+    loop {
+        let u = rate
+            .random_like(crate::Distribution::Default)
+            .sub_scalar(0.5);
+        let v = rate.random_like(crate::Distribution::Default);
+        let us = u.clone().abs().neg().add_scalar(0.5);
+        let k = ((a.clone() * 2 / us.clone() + b.clone()) * u + rate.clone())
+            .add_scalar(0.43)
+            .floor();
+
+        // Most samples are accepted by the squeeze, without computing the density.
+        let squeeze = us
+            .clone()
+            .greater_equal_elem(0.07)
+            .bool_and(v.clone().lower_equal(v_r.clone()));
+
+        let bound = k.clone() * log_rate.clone() - rate.clone() - (k.clone() + 1).lgamma();
+        let density = v.clone().log() + log_inverse_alpha.clone()
+            - (a.clone() / us.clone().powi_scalar(2) + b.clone()).log();
+        let rejected = us.clone().lower_elem(0.013).bool_and(v.greater(us));
+        let accept = k
+            .clone()
+            .greater_equal_elem(0.0)
+            .bool_and(rejected.bool_not())
+            .bool_and(density.lower_equal(bound))
+            .bool_or(squeeze)
+            .bool_and(accepted.clone().bool_not());
+
+        output = output.mask_where(accept.clone(), k);
+        accepted = accepted.bool_or(accept);
+
+        if accepted.clone().all().into_scalar().elem::<bool>() {
+            break;
+        }
+    }
+
+    output
+}
@@ -311,6 +311,10 @@ pub enum DType {
     U16,
     U8,
     Bool,
+    /// Complex number with two interleaved [f32] parts.
+    Complex32,
+    /// Complex number with two interleaved [f64] parts.
+    Complex64,
     QFloat(QuantizationScheme),
 }
 
@@ -331,6 +335,8 @@ impl DType {
             DType::U16 => core::mem::size_of::<u16>(),
             DType::U8 => core::mem::size_of::<u8>(),
             DType::Bool => core::mem::size_of::<bool>(),
+            DType::Complex32 => 2 * core::mem::size_of::<f32>(),
+            DType::Complex64 => 2 * core::mem::size_of::<f64>(),
             DType::QFloat(scheme) => match scheme {
                 QuantizationScheme::PerTensor(_mode, QuantizationType::QInt8)
                 | QuantizationScheme::PerBlock(_mode, QuantizationType::QInt8, ..) => {
@@ -353,6 +359,11 @@ impl DType {
         matches!(self, DType::Bool)
     }
 
+    /// Returns true if the data type is a complex type.
+    pub fn is_complex(&self) -> bool {
+        matches!(self, DType::Complex32 | DType::Complex64)
+    }
+
     /// Returns the data type name.
     pub fn name(&self) -> &'static str {
         match self {
@@ -369,6 +380,8 @@ impl DType {
             DType::U16 => "u16",
             DType::U8 => "u8",
             DType::Bool => "bool",
+            DType::Complex32 => "complex32",
+            DType::Complex64 => "complex64",
             DType::QFloat(_) => "qfloat",
         }
     }
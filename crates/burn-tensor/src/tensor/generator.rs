@@ -0,0 +1,187 @@
+use rand::RngCore;
+
+/// The multipliers of the Philox rounds.
+const PHILOX_M: [u32; 2] = [0xD251_1F53, 0xCD9E_8D57];
+/// The Weyl sequence constants added to the key after each Philox round.
+const PHILOX_W: [u32; 2] = [0x9E37_79B9, 0xBB67_AE85];
+/// The number of Philox rounds.
+const PHILOX_ROUNDS: usize = 10;
+/// The high words of the counters used to derive the keys of the forked generators, which are
+/// never reached by the counter of a stream.
+const FORK_DOMAIN: [u32; 2] = [0xFFFF_FFFF, 0xFFFF_FFFF];
+
+/// A splittable, counter-based random number generator.
+///
+/// The generator implements the Philox 4x32-10 algorithm, where each block of four random words
+/// is a pure function of a key and a counter. A generator created with the same seed always
+/// produces the same stream, independently of the global [seed](crate::backend::Backend::seed)
+/// of the backend and of the other generators.
+///
+/// Independent streams are created with [split](Generator::split), which advances the parent
+/// generator, or with [fork](Generator::fork), which only depends on the parent key and the given
+/// index. Forking by index gives reproducible streams to concurrent workers regardless of the
+/// order in which they are scheduled.
+///
+/// Tensors are sampled with [Tensor::random_with](crate::Tensor::random_with), which draws the
+/// values on the host so that every backend produces the same values for the same element type.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Generator {
+    key: [u32; 2],
+    counter: u128,
+    buffer: [u32; 4],
+    position: usize,
+}
+
+impl Generator {
+    /// Creates a new generator with the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self::from_key([seed as u32, (seed >> 32) as u32])
+    }
+
+    fn from_key(key: [u32; 2]) -> Self {
+        Self {
+            key,
+            counter: 0,
+            buffer: [0; 4],
+            position: 4,
+        }
+    }
+
+    /// Creates a new independent generator from the next values of this generator.
+    pub fn split(&mut self) -> Self {
+        let block = self.next_block();
+        Self::from_key([block[0], block[1]])
+    }
+
+    /// Creates a new independent generator for the given index.
+    ///
+    /// Contrary to [split](Generator::split), the forked generator does not depend on the
+    /// values already drawn from this generator, and this generator is left unchanged.
+    pub fn fork(&self, index: u64) -> Self {
+        let counter = [
+            index as u32,
+            (index >> 32) as u32,
+            FORK_DOMAIN[0],
+            FORK_DOMAIN[1],
+        ];
+        let block = philox(self.key, counter);
+
+        Self::from_key([block[0], block[1]])
+    }
+
+    fn next_block(&mut self) -> [u32; 4] {
+        let counter = [
+            self.counter as u32,
+            (self.counter >> 32) as u32,
+            (self.counter >> 64) as u32,
+            (self.counter >> 96) as u32,
+        ];
+        self.counter = self.counter.wrapping_add(1);
+
+        philox(self.key, counter)
+    }
+}
+
+impl RngCore for Generator {
+    fn next_u32(&mut self) -> u32 {
+        if self.position == self.buffer.len() {
+            self.buffer = self.next_block();
+            self.position = 0;
+        }
+
+        let value = self.buffer[self.position];
+        self.position += 1;
+
+        value
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let low = self.next_u32() as u64;
+        let high = self.next_u32() as u64;
+
+        (high << 32) | low
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for chunk in dst.chunks_mut(4) {
+            let bytes = self.next_u32().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+
+/// Computes the Philox 4x32 block of the given key and counter.
+fn philox(mut key: [u32; 2], mut counter: [u32; 4]) -> [u32; 4] {
+    for round in 0..PHILOX_ROUNDS {
+        if round > 0 {
+            key[0] = key[0].wrapping_add(PHILOX_W[0]);
+            key[1] = key[1].wrapping_add(PHILOX_W[1]);
+        }
+
+        let product_0 = PHILOX_M[0] as u64 * counter[0] as u64;
+        let product_1 = PHILOX_M[1] as u64 * counter[2] as u64;
+
+        counter = [
+            (product_1 >> 32) as u32 ^ counter[1] ^ key[0],
+            product_1 as u32,
+            (product_0 >> 32) as u32 ^ counter[3] ^ key[1],
+            product_0 as u32,
+        ];
+    }
+
+    counter
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn philox_should_match_the_known_answers() {
+        assert_eq!(
+            philox([0, 0], [0, 0, 0, 0]),
+            [0x6627_E8D5, 0xE169_C58D, 0xBC57_AC4C, 0x9B00_DBD8]
+        );
+        assert_eq!(
+            philox([0xFFFF_FFFF; 2], [0xFFFF_FFFF; 4]),
+            [0x408F_276D, 0x41C8_3B0E, 0xA20B_C7C6, 0x6D54_51FD]
+        );
+        assert_eq!(
+            philox(
+                [0xA409_3822, 0x299F_31D0],
+                [0x243F_6A88, 0x85A3_08D3, 0x1319_8A2E, 0x0370_7344]
+            ),
+            [0xD16C_FE09, 0x94FD_CCEB, 0x5001_E420, 0x2412_6EA1]
+        );
+    }
+
+    #[test]
+    fn generator_should_be_reproducible() {
+        let mut generator_1 = Generator::new(42);
+        let mut generator_2 = Generator::new(42);
+
+        for _ in 0..10 {
+            assert_eq!(generator_1.next_u64(), generator_2.next_u64());
+        }
+    }
+
+    #[test]
+    fn split_generators_should_be_independent() {
+        let mut parent = Generator::new(42);
+        let mut child_1 = parent.split();
+        let mut child_2 = parent.split();
+
+        assert_ne!(child_1.next_u64(), child_2.next_u64());
+    }
+
+    #[test]
+    fn fork_should_not_depend_on_the_parent_position() {
+        let mut parent = Generator::new(42);
+        let forked_1 = parent.fork(3);
+        parent.next_u64();
+        let forked_2 = parent.fork(3);
+
+        assert_eq!(forked_1, forked_2);
+        assert_ne!(parent.fork(3), parent.fork(4));
+    }
+}
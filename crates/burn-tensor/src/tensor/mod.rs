@@ -5,6 +5,7 @@ mod bytes;
 mod data;
 mod distribution;
 mod element;
+mod generator;
 mod shape;
 
 pub use api::*;
@@ -12,6 +13,7 @@ pub use bytes::*;
 pub use data::*;
 pub use distribution::*;
 pub use element::*;
+pub use generator::*;
 pub use shape::*;
 
 /// The activation module.
@@ -23,6 +25,9 @@ pub mod backend;
 /// The container module.
 pub mod container;
 
+/// The probability distributions module.
+pub mod distributions;
+
 /// The loss module.
 pub mod loss;
 
@@ -1,6 +1,10 @@
 use crate::{
     backend::Backend,
-    ops::{ConvOptions, ConvTransposeOptions, InterpolateOptions, UnfoldOptions},
+    check,
+    check::TensorCheck,
+    ops::{
+        ConvOptions, ConvTransposeOptions, GridSampleOptions, InterpolateOptions, UnfoldOptions,
+    },
     Int, Tensor, TensorPrimitive,
 };
 
@@ -17,6 +21,18 @@ where
     )))
 }
 
+/// Applies the [embedding module](crate::ops::ModuleOps::embedding_sparse) with row-sparse
+/// gradients for the weights.
+pub fn embedding_sparse<B>(weights: Tensor<B, 2>, indices: Tensor<B, 2, Int>) -> Tensor<B, 3>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::embedding_sparse(
+        weights.primitive.tensor(),
+        indices.primitive,
+    )))
+}
+
 /// Applies a [1D convolution](crate::ops::ModuleOps::conv2d).
 pub fn conv1d<B>(
     x: Tensor<B, 3>,
@@ -159,6 +175,66 @@ where
     )))
 }
 
+/// Applies a [3D to 4D fold](crate::ops::ModuleOps::fold4d).
+pub fn fold4d<B>(
+    x: Tensor<B, 3>,
+    output_size: [usize; 2],
+    kernel_size: [usize; 2],
+    options: UnfoldOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    let [_, channels, _] = x.dims();
+    assert_eq!(
+        channels % (kernel_size[0] * kernel_size[1]),
+        0,
+        "The number of channels {channels} should be divisible by the kernel size {kernel_size:?}."
+    );
+
+    Tensor::new(TensorPrimitive::Float(B::fold4d(
+        x.primitive.tensor(),
+        output_size,
+        kernel_size,
+        options,
+    )))
+}
+
+/// Applies a [pixel shuffle](crate::ops::ModuleOps::pixel_shuffle).
+pub fn pixel_shuffle<B>(x: Tensor<B, 4>, upscale_factor: usize) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    let [_, channels, _, _] = x.dims();
+    assert_eq!(
+        channels % (upscale_factor * upscale_factor),
+        0,
+        "The number of channels {channels} should be divisible by the square of the upscale factor {upscale_factor}."
+    );
+
+    Tensor::new(TensorPrimitive::Float(B::pixel_shuffle(
+        x.primitive.tensor(),
+        upscale_factor,
+    )))
+}
+
+/// Applies a [pixel unshuffle](crate::ops::ModuleOps::pixel_unshuffle).
+pub fn pixel_unshuffle<B>(x: Tensor<B, 4>, downscale_factor: usize) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    let [_, _, height, width] = x.dims();
+    assert!(
+        height % downscale_factor == 0 && width % downscale_factor == 0,
+        "The spatial size [{height}, {width}] should be divisible by the downscale factor {downscale_factor}."
+    );
+
+    Tensor::new(TensorPrimitive::Float(B::pixel_unshuffle(
+        x.primitive.tensor(),
+        downscale_factor,
+    )))
+}
+
 /// Applies a [1D max pooling](crate::ops::ModuleOps::max_pool1d).
 pub fn max_pool1d<B>(
     x: Tensor<B, 3>,
@@ -301,6 +377,148 @@ where
     )))
 }
 
+/// Applies a [3D max pooling](crate::ops::ModuleOps::max_pool3d).
+pub fn max_pool3d<B>(
+    x: Tensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::max_pool3d(
+        x.primitive.tensor(),
+        kernel_size,
+        stride,
+        padding,
+        dilation,
+    )))
+}
+
+/// Applies a [3D max pooling](crate::ops::ModuleOps::max_pool3d_with_indices).
+pub fn max_pool3d_with_indices<B>(
+    x: Tensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> (Tensor<B, 5>, Tensor<B, 5, Int>)
+where
+    B: Backend,
+{
+    let output =
+        B::max_pool3d_with_indices(x.primitive.tensor(), kernel_size, stride, padding, dilation);
+
+    (
+        Tensor::new(TensorPrimitive::Float(output.output)),
+        Tensor::new(output.indices),
+    )
+}
+
+/// Applies a [3D avg pooling](crate::ops::ModuleOps::avg_pool3d).
+pub fn avg_pool3d<B>(
+    x: Tensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::avg_pool3d(
+        x.primitive.tensor(),
+        kernel_size,
+        stride,
+        padding,
+        count_include_pad,
+    )))
+}
+
+/// Applies a [3D adaptive avg pooling](crate::ops::ModuleOps::adaptive_avg_pool3d).
+pub fn adaptive_avg_pool3d<B>(x: Tensor<B, 5>, output_size: [usize; 3]) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::adaptive_avg_pool3d(
+        x.primitive.tensor(),
+        output_size,
+    )))
+}
+
+/// Applies a [1D adaptive max pooling](crate::ops::ModuleOps::adaptive_max_pool1d).
+pub fn adaptive_max_pool1d<B>(x: Tensor<B, 3>, output_size: usize) -> Tensor<B, 3>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::adaptive_max_pool1d(
+        x.primitive.tensor(),
+        output_size,
+    )))
+}
+
+/// Applies a [1D adaptive max pooling](crate::ops::ModuleOps::adaptive_max_pool1d_with_indices).
+pub fn adaptive_max_pool1d_with_indices<B>(
+    x: Tensor<B, 3>,
+    output_size: usize,
+) -> (Tensor<B, 3>, Tensor<B, 3, Int>)
+where
+    B: Backend,
+{
+    let output = B::adaptive_max_pool1d_with_indices(x.primitive.tensor(), output_size);
+
+    (
+        Tensor::new(TensorPrimitive::Float(output.output)),
+        Tensor::new(output.indices),
+    )
+}
+
+/// Applies a [2D adaptive max pooling](crate::ops::ModuleOps::adaptive_max_pool2d).
+pub fn adaptive_max_pool2d<B>(x: Tensor<B, 4>, output_size: [usize; 2]) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::adaptive_max_pool2d(
+        x.primitive.tensor(),
+        output_size,
+    )))
+}
+
+/// Applies a [2D adaptive max pooling](crate::ops::ModuleOps::adaptive_max_pool2d_with_indices).
+pub fn adaptive_max_pool2d_with_indices<B>(
+    x: Tensor<B, 4>,
+    output_size: [usize; 2],
+) -> (Tensor<B, 4>, Tensor<B, 4, Int>)
+where
+    B: Backend,
+{
+    let output = B::adaptive_max_pool2d_with_indices(x.primitive.tensor(), output_size);
+
+    (
+        Tensor::new(TensorPrimitive::Float(output.output)),
+        Tensor::new(output.indices),
+    )
+}
+
+/// Applies a [2D max unpooling](crate::ops::ModuleOps::max_unpool2d), with the indices given by
+/// [max_pool2d_with_indices].
+pub fn max_unpool2d<B>(
+    x: Tensor<B, 4>,
+    indices: Tensor<B, 4, Int>,
+    output_size: [usize; 2],
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::max_unpool2d(
+        x.primitive.tensor(),
+        indices.primitive,
+        output_size,
+    )))
+}
+
 /// Applies a [2D interpolation](crate::ops::ModuleOps::interpolate).
 pub fn interpolate<B>(
     x: Tensor<B, 4>,
@@ -310,9 +528,127 @@ pub fn interpolate<B>(
 where
     B: Backend,
 {
+    check!(TensorCheck::interpolate(
+        "Interpolate",
+        &options.mode,
+        &x.shape()
+    ));
+
     Tensor::new(TensorPrimitive::Float(B::interpolate(
         x.primitive.tensor(),
         output_size,
         options,
     )))
 }
+
+/// Applies a [3D interpolation](crate::ops::ModuleOps::interpolate3d).
+pub fn interpolate3d<B>(
+    x: Tensor<B, 5>,
+    output_size: [usize; 3],
+    options: InterpolateOptions,
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    check!(TensorCheck::interpolate(
+        "Interpolate3d",
+        &options.mode,
+        &x.shape()
+    ));
+
+    Tensor::new(TensorPrimitive::Float(B::interpolate3d(
+        x.primitive.tensor(),
+        output_size,
+        options,
+    )))
+}
+
+/// Applies a [2D grid sample](crate::ops::ModuleOps::grid_sample_2d).
+pub fn grid_sample_2d<B>(
+    x: Tensor<B, 4>,
+    grid: Tensor<B, 4>,
+    options: GridSampleOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::grid_sample_2d(
+        x.primitive.tensor(),
+        grid.primitive.tensor(),
+        options,
+    )))
+}
+
+/// Generates the [2D affine grid](crate::ops::ModuleOps::affine_grid_2d) of the transformations.
+pub fn affine_grid_2d<B>(theta: Tensor<B, 3>, size: [usize; 4], align_corners: bool) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::affine_grid_2d(
+        theta.primitive.tensor(),
+        size,
+        align_corners,
+    )))
+}
+
+/// Computes the [linear recurrence](crate::ops::ModuleOps::linear_recurrence)
+/// `h[t] = a[t] * h[t - 1] + b[t]` along the dimension.
+pub fn linear_recurrence<B, const D: usize>(
+    a: Tensor<B, D>,
+    b: Tensor<B, D>,
+    dim: usize,
+) -> Tensor<B, D>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::linear_recurrence(
+        a.primitive.tensor(),
+        b.primitive.tensor(),
+        dim,
+    )))
+}
+
+/// Computes the negative log-likelihood of the targets of the
+/// [Connectionist Temporal Classification](crate::ops::ModuleOps::ctc_loss) for each sample.
+pub fn ctc_loss<B>(
+    log_probs: Tensor<B, 3>,
+    targets: Tensor<B, 2, Int>,
+    input_lengths: Tensor<B, 1, Int>,
+    target_lengths: Tensor<B, 1, Int>,
+    blank: usize,
+) -> Tensor<B, 1>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::ctc_loss(
+        log_probs.primitive.tensor(),
+        targets.primitive,
+        input_lengths.primitive,
+        target_lengths.primitive,
+        blank,
+    )))
+}
+
+/// Multiplies each row of `lhs` with the matrix of its group in `rhs`, the rows being
+/// [sorted by group](crate::ops::ModuleOps::grouped_matmul) and `offsets` holding the end of the
+/// rows of each group.
+pub fn grouped_matmul<B>(
+    lhs: Tensor<B, 2>,
+    rhs: Tensor<B, 3>,
+    offsets: Tensor<B, 1, Int>,
+) -> Tensor<B, 2>
+where
+    B: Backend,
+{
+    check!(TensorCheck::grouped_matmul(
+        &lhs.shape(),
+        &rhs.shape(),
+        &offsets.shape()
+    ));
+
+    Tensor::new(TensorPrimitive::Float(B::grouped_matmul(
+        lhs.primitive.tensor(),
+        rhs.primitive.tensor(),
+        offsets.primitive,
+    )))
+}
@@ -3,8 +3,9 @@ use super::{
     FloatTensor, IntTensor,
 };
 use crate::{
-    argwhere_data, backend::Backend, chunk, narrow, split, split_with_sizes, tensor::Shape, Bool,
-    ElementConversion, TensorData, TensorMetadata,
+    argwhere_data, backend::Backend, chunk, narrow, slice_assign_with_steps, slice_with_steps,
+    split, split_with_sizes, tensor::Shape, Bool, ElementConversion, Slice, TensorData,
+    TensorMetadata,
 };
 use alloc::{vec, vec::Vec};
 use core::{future::Future, ops::Range};
@@ -124,6 +125,40 @@ pub trait BoolTensorOps<B: Backend> {
         value: BoolTensor<B>,
     ) -> BoolTensor<B>;
 
+    /// Select tensor elements corresponding to the given slices, taking every `step` element of
+    /// each dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to select from.
+    /// * `slices` - The slices to select, within the bounds of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// The selected elements in a new tensor.
+    fn bool_slice_with_steps(tensor: BoolTensor<B>, slices: &[Slice]) -> BoolTensor<B> {
+        slice_with_steps::<B, Bool>(tensor, slices)
+    }
+
+    /// Assign the selected elements corresponding to the given slices to the given value.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to select from.
+    /// * `slices` - The slices to select, within the bounds of the tensor.
+    /// * `value` - The value to assign.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the selected elements assigned to the given value.
+    fn bool_slice_assign_with_steps(
+        tensor: BoolTensor<B>,
+        slices: &[Slice],
+        value: BoolTensor<B>,
+    ) -> BoolTensor<B> {
+        slice_assign_with_steps::<B, Bool>(tensor, slices, value)
+    }
+
     /// Repeats one dimension of the tensor a given number of times along that dimension.
     ///
     /// # Arguments
@@ -8,7 +8,10 @@ use alloc::vec::Vec;
 use core::future::Future;
 use core::ops::Range;
 
-use crate::{argsort, sort, sort_with_indices, TensorMetadata};
+use crate::{
+    argsort, bincount_length, pad, searchsorted_shapes, slice_assign_with_steps, slice_with_steps,
+    sort, sort_with_indices, unique, PadMode, Reduce, Slice, TensorMetadata,
+};
 
 /// Int Tensor API for basic and numeric operations, see [tensor](crate::Tensor)
 /// for documentation on each function.
@@ -102,6 +105,40 @@ pub trait IntTensorOps<B: Backend> {
         value: IntTensor<B>,
     ) -> IntTensor<B>;
 
+    /// Select tensor elements corresponding to the given slices, taking every `step` element of
+    /// each dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to select from.
+    /// * `slices` - The slices to select, within the bounds of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// The selected elements in a new tensor.
+    fn int_slice_with_steps(tensor: IntTensor<B>, slices: &[Slice]) -> IntTensor<B> {
+        slice_with_steps::<B, Int>(tensor, slices)
+    }
+
+    /// Assign the selected elements corresponding to the given slices to the given value.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to select from.
+    /// * `slices` - The slices to select, within the bounds of the tensor.
+    /// * `value` - The value to assign.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the selected elements assigned to the given value.
+    fn int_slice_assign_with_steps(
+        tensor: IntTensor<B>,
+        slices: &[Slice],
+        value: IntTensor<B>,
+    ) -> IntTensor<B> {
+        slice_assign_with_steps::<B, Int>(tensor, slices, value)
+    }
+
     /// Converts int tensor to float tensor.
     ///
     /// # Arguments
@@ -172,6 +209,30 @@ pub trait IntTensorOps<B: Backend> {
         value: IntTensor<B>,
     ) -> IntTensor<B>;
 
+    /// Scatter values to the tensor at the given indices, reducing the values scattered at the
+    /// same position.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to scatter to.
+    /// * `tensor` - The tensor.
+    /// * `indices` - The indices.
+    /// * `value` - The value.
+    /// * `reduce` - The reduction applied to the values at the same position.
+    /// * `include_self` - If the elements of the tensor are part of the reduction.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the values scattered.
+    fn int_scatter_reduce(
+        dim: usize,
+        tensor: IntTensor<B>,
+        indices: IntTensor<B>,
+        value: IntTensor<B>,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> IntTensor<B>;
+
     /// Select tensor elements along the given dimension corresponding to the given indices.
     ///
     /// # Arguments
@@ -1130,6 +1191,21 @@ pub trait IntTensorOps<B: Backend> {
     /// Broadcasts the int `tensor` to the given `shape`.
     fn int_expand(tensor: IntTensor<B>, shape: Shape) -> IntTensor<B>;
 
+    /// Pads the last dimensions of the int `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to pad.
+    /// * `padding` - The padding before and after each of the last `padding.len()` dimensions.
+    /// * `mode` - The padding mode.
+    ///
+    /// # Returns
+    ///
+    /// The padded tensor.
+    fn int_pad(tensor: IntTensor<B>, padding: &[(usize, usize)], mode: PadMode) -> IntTensor<B> {
+        pad::<B, Int>(tensor, padding, mode)
+    }
+
     /// Sort the elements of the input `tensor` by value along a given dimension.
     ///
     /// This sort is unstable (i.e., may reorder equal elements).
@@ -1186,6 +1262,100 @@ pub trait IntTensorOps<B: Backend> {
         argsort::<B, Int>(tensor, dim, descending)
     }
 
+    /// Returns the sorted unique elements of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the unique elements as a 1D tensor, the index of each element of the tensor in
+    /// the unique elements with the shape of the input tensor, and the number of occurrences of
+    /// each unique element as a 1D tensor.
+    fn int_unique(
+        tensor: IntTensor<B>,
+    ) -> impl Future<Output = (IntTensor<B>, IntTensor<B>, IntTensor<B>)> + 'static + Send {
+        // The number of unique elements is variable, so the data has to be read.
+        unique::<B, Int>(tensor)
+    }
+
+    /// Finds the indices where the values should be inserted in the sorted sequence to keep it
+    /// sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted` - The sequences sorted along the last dimension, of shape `[..., M]`.
+    /// * `values` - The values to search, of shape `[..., N]` with the same leading dimensions.
+    /// * `right` - If the index after the elements equal to the value is returned instead of the
+    ///   index before them.
+    ///
+    /// # Returns
+    ///
+    /// The insertion indices with the shape of the values.
+    fn int_searchsorted(sorted: IntTensor<B>, values: IntTensor<B>, right: bool) -> IntTensor<B> {
+        // Counts the elements of the sequence before each value.
+        let shape = values.shape();
+        let [shape_sorted, shape_values, dims] = searchsorted_shapes(&sorted.shape(), &shape);
+        let rank = shape.num_dims();
+
+        let sorted = B::int_expand(B::int_reshape(sorted, shape_sorted), dims.clone());
+        let values = B::int_expand(B::int_reshape(values, shape_values), dims);
+        let before = match right {
+            true => B::int_lower_equal(sorted, values),
+            false => B::int_lower(sorted, values),
+        };
+
+        B::int_reshape(B::int_sum_dim(B::bool_into_int(before), rank), shape)
+    }
+
+    /// Counts the number of occurrences of each value of a 1D tensor of non-negative integers.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `min_length` - The minimum length of the output.
+    ///
+    /// # Returns
+    ///
+    /// The number of occurrences of each value, with a length of one more than the maximum value
+    /// and at least `min_length`.
+    fn int_bincount(tensor: IntTensor<B>, min_length: usize) -> IntTensor<B> {
+        let device = B::int_device(&tensor);
+        let length = bincount_length::<B>(tensor.clone(), min_length);
+        let ones = B::int_ones(tensor.shape(), &device);
+
+        B::int_scatter(0, B::int_zeros(Shape::new([length]), &device), tensor, ones)
+    }
+
+    /// Sums the weights of each value of a 1D tensor of non-negative integers.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `weights` - The weight of each element of the tensor.
+    /// * `min_length` - The minimum length of the output.
+    ///
+    /// # Returns
+    ///
+    /// The sum of the weights of each value, with a length of one more than the maximum value and
+    /// at least `min_length`.
+    fn int_bincount_weighted(
+        tensor: IntTensor<B>,
+        weights: FloatTensor<B>,
+        min_length: usize,
+    ) -> FloatTensor<B> {
+        let device = B::int_device(&tensor);
+        let length = bincount_length::<B>(tensor.clone(), min_length);
+
+        B::float_scatter(
+            0,
+            B::float_zeros(Shape::new([length]), &device),
+            tensor,
+            weights,
+        )
+    }
+
     /// Bitwise AND operation for Int Tensors
     fn bitwise_and(lhs: IntTensor<B>, rhs: IntTensor<B>) -> IntTensor<B>;
 
@@ -1,6 +1,9 @@
 use core::num::NonZeroUsize;
 
-use super::{conv, pool, unfold::unfold4d_using_conv2d};
+use super::{
+    conv, ctc, grid_sample, grouped_matmul, interpolate, pool, scan, shuffle,
+    unfold::{fold4d_using_conv_transpose2d, unfold4d_using_conv2d},
+};
 use crate::{
     backend::Backend,
     ops::{FloatTensor, IntTensor},
@@ -86,6 +89,57 @@ pub struct MaxPool2dWithIndices<B: Backend> {
     pub indices: IntTensor<B>,
 }
 
+/// Gradient computed during the backward pass for each tensor used by [max_pool3d](ModuleOps::max_pool3d).
+#[derive(new)]
+pub struct MaxPool3dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: FloatTensor<B>,
+}
+
+/// Results from [max_pool3d](ModuleOps::max_pool3d_with_indices).
+#[derive(new)]
+pub struct MaxPool3dWithIndices<B: Backend> {
+    /// The output tensor.
+    pub output: FloatTensor<B>,
+
+    /// The indices tensor.
+    pub indices: IntTensor<B>,
+}
+
+/// Gradient computed during the backward pass for each tensor used by [adaptive_max_pool1d](ModuleOps::adaptive_max_pool1d).
+#[derive(new)]
+pub struct AdaptiveMaxPool1dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: FloatTensor<B>,
+}
+
+/// Results from [adaptive_max_pool1d](ModuleOps::adaptive_max_pool1d_with_indices).
+#[derive(new)]
+pub struct AdaptiveMaxPool1dWithIndices<B: Backend> {
+    /// The output tensor.
+    pub output: FloatTensor<B>,
+
+    /// The indices tensor.
+    pub indices: IntTensor<B>,
+}
+
+/// Gradient computed during the backward pass for each tensor used by [adaptive_max_pool2d](ModuleOps::adaptive_max_pool2d).
+#[derive(new)]
+pub struct AdaptiveMaxPool2dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: FloatTensor<B>,
+}
+
+/// Results from [adaptive_max_pool2d](ModuleOps::adaptive_max_pool2d_with_indices).
+#[derive(new)]
+pub struct AdaptiveMaxPool2dWithIndices<B: Backend> {
+    /// The output tensor.
+    pub output: FloatTensor<B>,
+
+    /// The indices tensor.
+    pub indices: IntTensor<B>,
+}
+
 /// Check that the parameter value is non-zero.
 // NOTE: for now we keep usize but we could refactor the parameters to hold `NonZeroUsize`.
 pub(crate) fn check_nonzero(value: usize, msg: &str) -> usize {
@@ -234,20 +288,71 @@ pub enum InterpolateMode {
     /// <https://en.wikipedia.org/wiki/Nearest-neighbor_interpolation>
     Nearest,
 
+    /// Linear interpolation of one dimensional signals, which are interpolated by the
+    /// [2D interpolation](ModuleOps::interpolate) with a height of one.
+    /// <https://en.wikipedia.org/wiki/Linear_interpolation>
+    Linear,
+
     /// Bilinear interpolation.
     /// <https://en.wikipedia.org/wiki/Bilinear_interpolation>
     Bilinear,
 
+    /// Trilinear interpolation, used by the [3D interpolation](ModuleOps::interpolate3d).
+    /// <https://en.wikipedia.org/wiki/Trilinear_interpolation>
+    Trilinear,
+
     /// Bicubic interpolation.
     /// <https://en.wikipedia.org/wiki/Bicubic_interpolation>
     Bicubic,
 }
 
 /// Interpolation options.
-#[derive(new, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub struct InterpolateOptions {
     /// Algorithm used for upsampling.
     pub mode: InterpolateMode,
+    /// If `true`, the centers of the corner elements of the input and output are aligned,
+    /// otherwise their outer corners are aligned. Ignored by the nearest-neighbor interpolation.
+    pub align_corners: bool,
+}
+
+impl InterpolateOptions {
+    /// Create the interpolation options for the given mode, with aligned corners.
+    pub fn new(mode: InterpolateMode) -> Self {
+        Self {
+            mode,
+            align_corners: true,
+        }
+    }
+
+    /// Set whether the corners are aligned.
+    pub fn with_align_corners(mut self, align_corners: bool) -> Self {
+        self.align_corners = align_corners;
+        self
+    }
+}
+
+/// Padding used by [grid sample](ModuleOps::grid_sample_2d) for the positions outside the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum GridSamplePaddingMode {
+    /// The positions outside the input have a value of zero.
+    Zeros,
+    /// The positions outside the input take the value of the closest border.
+    Border,
+    /// The positions outside the input are reflected by the borders.
+    Reflection,
+}
+
+/// Grid sample options.
+#[derive(new, Debug, Clone)]
+pub struct GridSampleOptions {
+    /// Algorithm used to sample the input, either nearest-neighbor or bilinear.
+    pub mode: InterpolateMode,
+    /// Padding used for the positions outside the input.
+    pub padding_mode: GridSamplePaddingMode,
+    /// If `true`, the coordinates `-1` and `1` are the centers of the corner elements of the
+    /// input, otherwise they are their outer corners.
+    pub align_corners: bool,
 }
 
 /// Gradient computed during the backward pass for each tensor used by [interpolate](ModuleOps::interpolate).
@@ -306,6 +411,25 @@ pub trait ModuleOps<B: Backend> {
 
         B::float_select_assign(grad, 0, indices, output_grad)
     }
+
+    /// Embedding operation with row-sparse gradients for the weights.
+    ///
+    /// The output is the same as [embedding](ModuleOps::embedding), but autodiff backends only
+    /// keep the gradients of the rows selected by the indices instead of a dense gradient the
+    /// size of the weights, see [grad_remove_rows](crate::backend::AutodiffBackend::grad_remove_rows).
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - The embedding weights.
+    /// * `indices` - The indices tensor.
+    ///
+    /// # Returns
+    ///
+    /// The output tensor.
+    fn embedding_sparse(weights: FloatTensor<B>, indices: IntTensor<B>) -> FloatTensor<B> {
+        B::embedding(weights, indices)
+    }
+
     /// One dimensional convolution.
     ///
     /// # Shapes
@@ -587,6 +711,44 @@ pub trait ModuleOps<B: Backend> {
         unfold4d_using_conv2d::<B>(x, kernel_size, options)
     }
 
+    /// Four-dimensional folding, combining the blocks extracted by the
+    /// [unfolding](ModuleOps::unfold4d) into an image, where the overlapping values are summed.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels * kernel_size_1 * kernel_size_2, number of blocks]`,
+    /// returns: `[batch_size, channels, output_size_1, output_size_2]`,
+    fn fold4d(
+        x: FloatTensor<B>,
+        output_size: [usize; 2],
+        kernel_size: [usize; 2],
+        options: UnfoldOptions,
+    ) -> FloatTensor<B> {
+        fold4d_using_conv_transpose2d::<B>(x, output_size, kernel_size, options)
+    }
+
+    /// Rearranges the channels into blocks of `upscale_factor * upscale_factor` pixels, as used
+    /// by the sub-pixel convolutions.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels * upscale_factor^2, height, width]`,
+    /// returns: `[batch_size, channels, height * upscale_factor, width * upscale_factor]`,
+    fn pixel_shuffle(x: FloatTensor<B>, upscale_factor: usize) -> FloatTensor<B> {
+        shuffle::pixel_shuffle_from_reshape::<B>(x, upscale_factor)
+    }
+
+    /// Rearranges the blocks of `downscale_factor * downscale_factor` pixels into the channels,
+    /// the inverse of the [pixel shuffle](ModuleOps::pixel_shuffle).
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels, height * downscale_factor, width * downscale_factor]`,
+    /// returns: `[batch_size, channels * downscale_factor^2, height, width]`,
+    fn pixel_unshuffle(x: FloatTensor<B>, downscale_factor: usize) -> FloatTensor<B> {
+        shuffle::pixel_unshuffle_from_reshape::<B>(x, downscale_factor)
+    }
+
     /// One dimensional avg pooling.
     ///
     /// # Shapes
@@ -746,6 +908,187 @@ pub trait ModuleOps<B: Backend> {
         indices: IntTensor<B>,
     ) -> MaxPool2dBackward<B>;
 
+    /// Three dimensional max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn max_pool3d(
+        x: FloatTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> FloatTensor<B> {
+        pool::max_pool3d_from_2d::<B>(x, kernel_size, stride, padding, dilation)
+    }
+
+    /// Three dimensional max pooling with indices.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn max_pool3d_with_indices(
+        x: FloatTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<B> {
+        pool::max_pool3d_with_indices_from_2d::<B>(x, kernel_size, stride, padding, dilation)
+    }
+    /// Backward pass for the [max pooling 3d](ModuleOps::max_pool3d_with_indices) operation.
+    fn max_pool3d_with_indices_backward(
+        x: FloatTensor<B>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        _dilation: [usize; 3],
+        output_grad: FloatTensor<B>,
+        indices: IntTensor<B>,
+    ) -> MaxPool3dBackward<B> {
+        MaxPool3dBackward::new(pool::max_pool_backward_from_indices::<B>(
+            x,
+            output_grad,
+            indices,
+        ))
+    }
+
+    /// Three dimensional avg pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn avg_pool3d(
+        x: FloatTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<B> {
+        pool::avg_pool3d_from_2d::<B>(x, kernel_size, stride, padding, count_include_pad)
+    }
+    /// Backward pass for the [avg pooling 3d](ModuleOps::avg_pool3d) operation.
+    fn avg_pool3d_backward(
+        x: FloatTensor<B>,
+        grad: FloatTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<B> {
+        pool::avg_pool3d_backward_from_2d::<B>(
+            x,
+            grad,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+        )
+    }
+
+    /// Three dimensional adaptive avg pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn adaptive_avg_pool3d(x: FloatTensor<B>, output_size: [usize; 3]) -> FloatTensor<B> {
+        pool::adaptive_avg_pool3d_from_2d::<B>(x, output_size)
+    }
+    /// Backward pass for the [adaptive avg pooling 3d](ModuleOps::adaptive_avg_pool3d) operation.
+    fn adaptive_avg_pool3d_backward(x: FloatTensor<B>, grad: FloatTensor<B>) -> FloatTensor<B> {
+        pool::adaptive_avg_pool3d_backward_from_2d::<B>(x, grad)
+    }
+
+    /// One dimensional adaptive max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, length],
+    fn adaptive_max_pool1d(x: FloatTensor<B>, output_size: usize) -> FloatTensor<B> {
+        B::adaptive_max_pool1d_with_indices(x, output_size).output
+    }
+
+    /// One dimensional adaptive max pooling with indices.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, length],
+    fn adaptive_max_pool1d_with_indices(
+        x: FloatTensor<B>,
+        output_size: usize,
+    ) -> AdaptiveMaxPool1dWithIndices<B> {
+        pool::adaptive_max_pool1d_with_indices_from_2d::<B>(x, output_size)
+    }
+    /// Backward pass for the [adaptive max pooling 1d](ModuleOps::adaptive_max_pool1d_with_indices) operation.
+    fn adaptive_max_pool1d_with_indices_backward(
+        x: FloatTensor<B>,
+        output_grad: FloatTensor<B>,
+        indices: IntTensor<B>,
+    ) -> AdaptiveMaxPool1dBackward<B> {
+        AdaptiveMaxPool1dBackward::new(pool::max_pool_backward_from_indices::<B>(
+            x,
+            output_grad,
+            indices,
+        ))
+    }
+
+    /// Two dimensional adaptive max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    fn adaptive_max_pool2d(x: FloatTensor<B>, output_size: [usize; 2]) -> FloatTensor<B> {
+        B::adaptive_max_pool2d_with_indices(x, output_size).output
+    }
+
+    /// Two dimensional adaptive max pooling with indices.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    fn adaptive_max_pool2d_with_indices(
+        x: FloatTensor<B>,
+        output_size: [usize; 2],
+    ) -> AdaptiveMaxPool2dWithIndices<B> {
+        pool::adaptive_max_pool2d_with_indices_from_slices::<B>(x, output_size)
+    }
+    /// Backward pass for the [adaptive max pooling 2d](ModuleOps::adaptive_max_pool2d_with_indices) operation.
+    fn adaptive_max_pool2d_with_indices_backward(
+        x: FloatTensor<B>,
+        output_grad: FloatTensor<B>,
+        indices: IntTensor<B>,
+    ) -> AdaptiveMaxPool2dBackward<B> {
+        AdaptiveMaxPool2dBackward::new(pool::max_pool_backward_from_indices::<B>(
+            x,
+            output_grad,
+            indices,
+        ))
+    }
+
+    /// Two dimensional max unpooling, the partial inverse of the
+    /// [max pooling 2d](ModuleOps::max_pool2d_with_indices) operation.
+    ///
+    /// Each value is written at its index in the flattened spatial dimensions of the output,
+    /// and the other elements of the output are zero.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    /// indices: [batch_size, channels, height, width],
+    /// returns: [batch_size, channels, output_size[0], output_size[1]],
+    fn max_unpool2d(
+        x: FloatTensor<B>,
+        indices: IntTensor<B>,
+        output_size: [usize; 2],
+    ) -> FloatTensor<B> {
+        pool::max_unpool2d_from_scatter::<B>(x, indices, output_size)
+    }
+    /// Backward pass for the [max unpooling 2d](ModuleOps::max_unpool2d) operation.
+    fn max_unpool2d_backward(indices: IntTensor<B>, grad: FloatTensor<B>) -> FloatTensor<B> {
+        pool::max_unpool2d_backward_from_gather::<B>(indices, grad)
+    }
+
     /// Down/up samples the input.
     ///
     /// # Shapes
@@ -764,6 +1107,166 @@ pub trait ModuleOps<B: Backend> {
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> FloatTensor<B>;
+
+    /// Down/up samples the input along its three spatial dimensions.
+    ///
+    /// The supported modes are the nearest, the trilinear and the bicubic modes, where the
+    /// bicubic mode interpolates along each dimension, which gives the tricubic interpolation.
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels, depth, height, width]`,
+    fn interpolate3d(
+        x: FloatTensor<B>,
+        output_size: [usize; 3],
+        options: InterpolateOptions,
+    ) -> FloatTensor<B> {
+        interpolate::interpolate_separable::<B>(x, &output_size, options)
+    }
+
+    /// Samples the input at the positions of the grid, normalized to `[-1, 1]` where `-1` is the
+    /// left or top of the input and `1` the right or bottom. The last dimension of the grid holds
+    /// the `x` and `y` coordinates of each position.
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels, height, width]`,
+    /// grid: `[batch_size, height_out, width_out, 2]`,
+    fn grid_sample_2d(
+        x: FloatTensor<B>,
+        grid: FloatTensor<B>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<B> {
+        grid_sample::grid_sample_2d_from_gather::<B>(x, grid, options)
+    }
+
+    /// Generates the sampling grid of a batch of affine transformations, to be used with
+    /// [grid sample](ModuleOps::grid_sample_2d).
+    ///
+    /// # Shapes
+    ///
+    /// theta: `[batch_size, 2, 3]`,
+    /// size: `[batch_size, channels, height, width]` of the output,
+    /// output: `[batch_size, height, width, 2]`,
+    fn affine_grid_2d(
+        theta: FloatTensor<B>,
+        size: [usize; 4],
+        align_corners: bool,
+    ) -> FloatTensor<B> {
+        grid_sample::affine_grid_2d_from_matmul::<B>(theta, size, align_corners)
+    }
+
+    /// Computes the linear recurrence `h[t] = a[t] * h[t - 1] + b[t]` along a dimension, with
+    /// `h[-1] = 0`, as used by the state space models.
+    ///
+    /// The default implementation is a parallel associative scan, taking a number of steps
+    /// logarithmic in the length of the sequences.
+    ///
+    /// # Shapes
+    ///
+    /// a: `[..., length, ...]`,
+    /// b: same as `a`,
+    /// output: same as `a`,
+    fn linear_recurrence(a: FloatTensor<B>, b: FloatTensor<B>, dim: usize) -> FloatTensor<B> {
+        scan::linear_recurrence_from_associative_scan::<B>(a, b, dim)
+    }
+
+    /// Computes the negative log-likelihood of the targets of the Connectionist Temporal
+    /// Classification, summed over every alignment of each target to its input sequence.
+    ///
+    /// Only the first `input_lengths` steps of each input sequence and the first
+    /// `target_lengths` classes of each target are used. The loss of a target that has no
+    /// alignment is infinite.
+    ///
+    /// The default implementation runs the forward recursion in log-space, batched over the
+    /// samples and the states of the extended targets.
+    ///
+    /// # Shapes
+    ///
+    /// log_probs: `[batch_size, seq_length, num_classes]`,
+    /// targets: `[batch_size, max_target_length]`,
+    /// input_lengths: `[batch_size]`,
+    /// target_lengths: `[batch_size]`,
+    /// output: `[batch_size]`,
+    fn ctc_loss(
+        log_probs: FloatTensor<B>,
+        targets: IntTensor<B>,
+        input_lengths: IntTensor<B>,
+        target_lengths: IntTensor<B>,
+        blank: usize,
+    ) -> FloatTensor<B> {
+        ctc::ctc_loss_from_recursion::<B>(log_probs, targets, input_lengths, target_lengths, blank)
+    }
+
+    /// Backward pass for the [CTC loss](ModuleOps::ctc_loss) operation, computed from the
+    /// forward and backward variables of the alignments.
+    ///
+    /// The gradient of each log-probability is the opposite of the posterior probability of its
+    /// class at its step, scaled by the gradient of the loss. It is zero for the steps after the
+    /// end of the input sequences and for the targets that have no alignment.
+    fn ctc_loss_backward(
+        log_probs: FloatTensor<B>,
+        targets: IntTensor<B>,
+        input_lengths: IntTensor<B>,
+        target_lengths: IntTensor<B>,
+        loss: FloatTensor<B>,
+        grad: FloatTensor<B>,
+        blank: usize,
+    ) -> FloatTensor<B> {
+        ctc::ctc_loss_backward_from_recursion::<B>(
+            log_probs,
+            targets,
+            input_lengths,
+            target_lengths,
+            loss,
+            grad,
+            blank,
+        )
+    }
+
+    /// Multiplies each row of `lhs` with the matrix of its group in `rhs`, the rows being sorted
+    /// by group.
+    ///
+    /// The rows of the group `g` go from `offsets[g - 1]` (`0` for the first group) to
+    /// `offsets[g]`. The rows after the last group aren't part of any group, their output being
+    /// zero.
+    ///
+    /// The default implementation reads the offsets and multiplies the rows of each group with a
+    /// separate matrix multiplication.
+    ///
+    /// # Shapes
+    ///
+    /// lhs: `[num_rows, k]`,
+    /// rhs: `[num_groups, k, n]`,
+    /// offsets: `[num_groups]`,
+    /// output: `[num_rows, n]`,
+    fn grouped_matmul(
+        lhs: FloatTensor<B>,
+        rhs: FloatTensor<B>,
+        offsets: IntTensor<B>,
+    ) -> FloatTensor<B> {
+        grouped_matmul::grouped_matmul_from_groups::<B>(lhs, rhs, offsets)
+    }
+
+    /// Backward pass for the [grouped matmul](ModuleOps::grouped_matmul) operation, returning the
+    /// gradient for `rhs`.
+    ///
+    /// The gradient of each group matrix is the product of the transposed rows of the group with
+    /// their output gradients.
+    ///
+    /// # Shapes
+    ///
+    /// lhs: `[num_rows, k]`,
+    /// grad: `[num_rows, n]`,
+    /// offsets: `[num_groups]`,
+    /// output: `[num_groups, k, n]`,
+    fn grouped_matmul_rhs_backward(
+        lhs: FloatTensor<B>,
+        grad: FloatTensor<B>,
+        offsets: IntTensor<B>,
+    ) -> FloatTensor<B> {
+        grouped_matmul::grouped_matmul_rhs_backward_from_groups::<B>(lhs, grad, offsets)
+    }
 }
 
 #[cfg(test)]
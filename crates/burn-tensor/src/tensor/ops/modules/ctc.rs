@@ -0,0 +1,374 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor, IntTensor},
+    ElementConversion, Shape, TensorMetadata,
+};
+
+/// The extended targets of a batch, which interleave the targets with blanks:
+/// `[blank, y1, blank, ..., blank]`.
+struct ExtendedTargets<B: Backend> {
+    /// The classes of the states, `[batch_size, num_states]`.
+    labels: IntTensor<B>,
+    /// Whether a state can't be reached from the state two positions before, because it is a
+    /// blank or because both states have the same class, `[batch_size, num_states]`.
+    skip_forbidden: BoolTensor<B>,
+    /// The log-probabilities of the classes of the states at each step,
+    /// `[batch_size, seq_length, num_states]`.
+    emissions: FloatTensor<B>,
+    /// Whether a state ends the alignments, `[batch_size, num_states]`.
+    last: BoolTensor<B>,
+}
+
+impl<B: Backend> ExtendedTargets<B> {
+    fn new(
+        log_probs: FloatTensor<B>,
+        targets: IntTensor<B>,
+        target_lengths: IntTensor<B>,
+        blank: usize,
+    ) -> Self {
+        let [batch_size, seq_length, num_classes] = log_probs.shape().dims();
+        let [_, max_target_length] = targets.shape().dims();
+        let device = B::float_device(&log_probs);
+        let num_states = 2 * max_target_length + 1;
+
+        assert!(
+            blank < num_classes,
+            "The blank index {blank} must be lower than the number of classes {num_classes}."
+        );
+
+        // Replace the padding of the targets by blanks, so that it is always a valid class.
+        let target_lengths = B::int_reshape(target_lengths, Shape::new([batch_size, 1]));
+        let positions = B::int_arange(0..max_target_length as i64, &device);
+        let positions = B::int_reshape(positions, Shape::new([1, max_target_length]));
+        let padding = B::int_greater_equal(
+            B::int_expand(positions, Shape::new([batch_size, max_target_length])),
+            B::int_expand(
+                target_lengths.clone(),
+                Shape::new([batch_size, max_target_length]),
+            ),
+        );
+        let targets = B::int_mask_fill(targets, padding, (blank as i64).elem());
+
+        let blanks = B::int_full(
+            Shape::new([batch_size, max_target_length, 1]),
+            (blank as i64).elem(),
+            &device,
+        );
+        let targets = B::int_reshape(targets, Shape::new([batch_size, max_target_length, 1]));
+        let labels = B::int_reshape(
+            B::int_cat(vec![blanks, targets], 2),
+            Shape::new([batch_size, 2 * max_target_length]),
+        );
+        let labels = B::int_cat(
+            vec![
+                labels,
+                B::int_full(Shape::new([batch_size, 1]), (blank as i64).elem(), &device),
+            ],
+            1,
+        );
+
+        let previous_labels = B::int_cat(
+            vec![
+                B::int_full(Shape::new([batch_size, 2]), (blank as i64).elem(), &device),
+                B::int_slice(labels.clone(), &[0..batch_size, 0..num_states - 2]),
+            ],
+            1,
+        );
+        let skip_forbidden = B::bool_or(
+            B::int_equal_elem(labels.clone(), (blank as i64).elem()),
+            B::int_equal(labels.clone(), previous_labels),
+        );
+
+        let indices = B::int_expand(
+            B::int_reshape(labels.clone(), Shape::new([batch_size, 1, num_states])),
+            Shape::new([batch_size, seq_length, num_states]),
+        );
+        let emissions = B::float_gather(2, log_probs, indices);
+
+        // The alignments end with the last class of the target or with the blank after it.
+        let states = B::int_expand(
+            B::int_reshape(
+                B::int_arange(0..num_states as i64, &device),
+                Shape::new([1, num_states]),
+            ),
+            Shape::new([batch_size, num_states]),
+        );
+        let end = B::int_expand(
+            B::int_mul_scalar(target_lengths, 2.elem()),
+            Shape::new([batch_size, num_states]),
+        );
+        let last = B::bool_or(
+            B::int_equal(states.clone(), end.clone()),
+            B::int_equal(states, B::int_sub_scalar(end, 1.elem())),
+        );
+
+        Self {
+            labels,
+            skip_forbidden,
+            emissions,
+            last,
+        }
+    }
+
+    fn emissions_at(&self, step: usize) -> FloatTensor<B> {
+        let [batch_size, _, num_states] = self.emissions.shape().dims();
+
+        B::float_reshape(
+            B::float_slice(
+                self.emissions.clone(),
+                &[0..batch_size, step..step + 1, 0..num_states],
+            ),
+            Shape::new([batch_size, num_states]),
+        )
+    }
+}
+
+/// Computes the log-probabilities of the prefixes of the alignments ending in each state at each
+/// step, `[batch_size, seq_length, num_states]`, with the forward recursion.
+///
+/// The states of the sequences that already ended keep their last values.
+fn forward_variables<B: Backend>(
+    extended: &ExtendedTargets<B>,
+    input_lengths: IntTensor<B>,
+) -> FloatTensor<B> {
+    let [batch_size, seq_length, num_states] = extended.emissions.shape().dims();
+    let device = B::float_device(&extended.emissions);
+    let shape = Shape::new([batch_size, num_states]);
+    let input_lengths = B::int_reshape(input_lengths, Shape::new([batch_size, 1]));
+
+    // The alignments start with a blank or with the first class of the target.
+    let initial = B::int_lower_elem(
+        B::int_expand(
+            B::int_reshape(
+                B::int_arange(0..num_states as i64, &device),
+                Shape::new([1, num_states]),
+            ),
+            shape.clone(),
+        ),
+        2.elem(),
+    );
+    let mut alpha = B::float_mask_where(
+        B::float_full(shape.clone(), f32::NEG_INFINITY.elem(), &device),
+        initial,
+        extended.emissions_at(0),
+    );
+    let mut alphas = Vec::with_capacity(seq_length);
+    alphas.push(alpha.clone());
+
+    for step in 1..seq_length {
+        let next = shift::<B>(alpha.clone(), 1, false);
+        let skip = B::float_mask_fill(
+            shift::<B>(alpha.clone(), 2, false),
+            extended.skip_forbidden.clone(),
+            f32::NEG_INFINITY.elem(),
+        );
+        let updated = B::float_add(
+            log_sum_exp::<B>(vec![alpha.clone(), next, skip]),
+            extended.emissions_at(step),
+        );
+
+        let active = B::bool_expand(
+            B::int_greater_elem(input_lengths.clone(), (step as i64).elem()),
+            shape.clone(),
+        );
+        alpha = B::float_mask_where(alpha, active, updated);
+        alphas.push(alpha.clone());
+    }
+
+    let alphas = alphas
+        .into_iter()
+        .map(|alpha| B::float_reshape(alpha, Shape::new([batch_size, 1, num_states])))
+        .collect();
+
+    B::float_cat(alphas, 1)
+}
+
+/// Computes the log-probabilities of the suffixes of the alignments following each state at each
+/// step, `[batch_size, seq_length, num_states]`, with the backward recursion.
+///
+/// The emission of the state itself is excluded, and the steps after the end of the sequences
+/// are impossible.
+fn backward_variables<B: Backend>(
+    extended: &ExtendedTargets<B>,
+    input_lengths: IntTensor<B>,
+) -> FloatTensor<B> {
+    let [batch_size, seq_length, num_states] = extended.emissions.shape().dims();
+    let device = B::float_device(&extended.emissions);
+    let shape = Shape::new([batch_size, num_states]);
+    let input_lengths = B::int_expand(
+        B::int_reshape(input_lengths, Shape::new([batch_size, 1])),
+        shape.clone(),
+    );
+
+    let impossible = B::float_full(shape.clone(), f32::NEG_INFINITY.elem(), &device);
+    let end = B::float_mask_fill(impossible.clone(), extended.last.clone(), 0.elem());
+    let mut beta = impossible.clone();
+    let mut betas = Vec::with_capacity(seq_length);
+
+    for step in (0..seq_length).rev() {
+        let updated = if step + 1 < seq_length {
+            let next = B::float_add(beta.clone(), extended.emissions_at(step + 1));
+            // The skips are masked on the state they lead to, before shifting it back.
+            let skip = shift::<B>(
+                B::float_mask_fill(
+                    next.clone(),
+                    extended.skip_forbidden.clone(),
+                    f32::NEG_INFINITY.elem(),
+                ),
+                2,
+                true,
+            );
+            log_sum_exp::<B>(vec![next.clone(), shift::<B>(next, 1, true), skip])
+        } else {
+            impossible.clone()
+        };
+
+        let is_last = B::int_equal_elem(input_lengths.clone(), (step as i64 + 1).elem());
+        let is_inside = B::int_greater_elem(input_lengths.clone(), (step as i64 + 1).elem());
+        beta = B::float_mask_where(
+            B::float_mask_where(impossible.clone(), is_inside, updated),
+            is_last,
+            end.clone(),
+        );
+        betas.push(B::float_reshape(
+            beta.clone(),
+            Shape::new([batch_size, 1, num_states]),
+        ));
+    }
+
+    betas.reverse();
+    B::float_cat(betas, 1)
+}
+
+pub(crate) fn ctc_loss_from_recursion<B: Backend>(
+    log_probs: FloatTensor<B>,
+    targets: IntTensor<B>,
+    input_lengths: IntTensor<B>,
+    target_lengths: IntTensor<B>,
+    blank: usize,
+) -> FloatTensor<B> {
+    let [batch_size, seq_length, _] = log_probs.shape().dims();
+    let extended = ExtendedTargets::<B>::new(log_probs, targets, target_lengths, blank);
+    let [_, num_states] = extended.labels.shape().dims();
+    let alphas = forward_variables(&extended, input_lengths);
+
+    let alpha = B::float_reshape(
+        B::float_slice(
+            alphas,
+            &[0..batch_size, seq_length - 1..seq_length, 0..num_states],
+        ),
+        Shape::new([batch_size, num_states]),
+    );
+    let alpha = B::float_mask_fill(alpha, B::bool_not(extended.last), f32::NEG_INFINITY.elem());
+    let log_likelihood = B::float_reshape(log_sum_exp_dim::<B>(alpha, 1), Shape::new([batch_size]));
+
+    B::float_neg(log_likelihood)
+}
+
+pub(crate) fn ctc_loss_backward_from_recursion<B: Backend>(
+    log_probs: FloatTensor<B>,
+    targets: IntTensor<B>,
+    input_lengths: IntTensor<B>,
+    target_lengths: IntTensor<B>,
+    loss: FloatTensor<B>,
+    grad: FloatTensor<B>,
+    blank: usize,
+) -> FloatTensor<B> {
+    let shape = log_probs.shape();
+    let [batch_size, seq_length, _] = shape.dims();
+    let device = B::float_device(&log_probs);
+    let extended = ExtendedTargets::<B>::new(log_probs, targets, target_lengths, blank);
+    let [_, num_states] = extended.labels.shape().dims();
+    let alphas = forward_variables(&extended, input_lengths.clone());
+    let betas = backward_variables(&extended, input_lengths);
+
+    // The impossible targets have no alignment, so their gradients are zero.
+    let loss = B::float_mask_fill(
+        loss.clone(),
+        B::float_equal_elem(loss, f32::INFINITY.elem()),
+        f32::NEG_INFINITY.elem(),
+    );
+    let loss = B::float_expand(
+        B::float_reshape(loss, Shape::new([batch_size, 1, 1])),
+        Shape::new([batch_size, seq_length, num_states]),
+    );
+
+    // The posterior probability of each state at each step, accumulated on its class, is the
+    // opposite of the gradient of the loss with respect to the log-probability of the class.
+    let posteriors = B::float_exp(B::float_add(B::float_add(alphas, betas), loss));
+    let indices = B::int_expand(
+        B::int_reshape(extended.labels, Shape::new([batch_size, 1, num_states])),
+        Shape::new([batch_size, seq_length, num_states]),
+    );
+    let posteriors = B::float_scatter(2, B::float_zeros(shape, &device), indices, posteriors);
+
+    let grad = B::float_reshape(grad, Shape::new([batch_size, 1, 1]));
+    B::float_neg(B::float_mul(posteriors, grad))
+}
+
+/// Shifts the values of the last dimension by the given amount, to the right or to the left,
+/// filling the new positions with negative infinity.
+fn shift<B: Backend>(tensor: FloatTensor<B>, amount: usize, left: bool) -> FloatTensor<B> {
+    let [batch_size, num_states] = tensor.shape().dims();
+    let device = B::float_device(&tensor);
+    let fill = B::float_full(
+        Shape::new([batch_size, amount]),
+        f32::NEG_INFINITY.elem(),
+        &device,
+    );
+
+    match left {
+        true => B::float_cat(
+            vec![
+                B::float_slice(tensor, &[0..batch_size, amount..num_states]),
+                fill,
+            ],
+            1,
+        ),
+        false => B::float_cat(
+            vec![
+                fill,
+                B::float_slice(tensor, &[0..batch_size, 0..num_states - amount]),
+            ],
+            1,
+        ),
+    }
+}
+
+/// Computes the logarithm of the sum of the exponentials of the given tensors.
+fn log_sum_exp<B: Backend>(tensors: Vec<FloatTensor<B>>) -> FloatTensor<B> {
+    let [batch_size, num_states] = tensors[0].shape().dims();
+    let tensors = tensors
+        .into_iter()
+        .map(|tensor| B::float_reshape(tensor, Shape::new([batch_size, num_states, 1])))
+        .collect();
+
+    B::float_reshape(
+        log_sum_exp_dim::<B>(B::float_cat(tensors, 2), 2),
+        Shape::new([batch_size, num_states]),
+    )
+}
+
+/// Computes the logarithm of the sum of the exponentials along the dimension.
+///
+/// The maximum is subtracted for stability, except when it is negative infinity, so that the sum
+/// of impossible terms stays negative infinity in every float precision instead of becoming NaN.
+fn log_sum_exp_dim<B: Backend>(tensor: FloatTensor<B>, dim: usize) -> FloatTensor<B> {
+    let max = B::float_max_dim(tensor.clone(), dim);
+    let max = B::float_mask_fill(
+        max.clone(),
+        B::float_equal_elem(max, f32::NEG_INFINITY.elem()),
+        0.elem(),
+    );
+
+    B::float_add(
+        B::float_log(B::float_sum_dim(
+            B::float_exp(B::float_sub(tensor, max.clone())),
+            dim,
+        )),
+        max,
+    )
+}
@@ -0,0 +1,211 @@
+use alloc::vec::Vec;
+
+use crate::{
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor},
+    ElementConversion, Shape, TensorData, TensorMetadata,
+};
+
+use super::{GridSampleOptions, GridSamplePaddingMode, InterpolateMode};
+
+pub(crate) fn grid_sample_2d_from_gather<B: Backend>(
+    x: FloatTensor<B>,
+    grid: FloatTensor<B>,
+    options: GridSampleOptions,
+) -> FloatTensor<B> {
+    let [batch_size, channels, height, width] = x.shape().dims();
+    let [_, height_out, width_out, _] = grid.shape().dims();
+    let num_positions = height_out * width_out;
+
+    let coordinate = |index: usize, size: usize| {
+        let coordinate = B::float_slice(
+            grid.clone(),
+            &[0..batch_size, 0..height_out, 0..width_out, index..index + 1],
+        );
+        let coordinate = B::float_reshape(coordinate, Shape::from([batch_size, num_positions]));
+        let coordinate = unnormalize::<B>(coordinate, size, options.align_corners);
+
+        pad_coordinate::<B>(
+            coordinate,
+            size,
+            options.padding_mode,
+            options.align_corners,
+        )
+    };
+    let ix = coordinate(0, width);
+    let iy = coordinate(1, height);
+
+    let x = B::float_reshape(x, Shape::from([batch_size, channels, height * width]));
+    let sample = |ix: FloatTensor<B>, iy: FloatTensor<B>| {
+        sample_positions::<B>(x.clone(), ix, iy, [channels, height, width])
+    };
+
+    let output = match options.mode {
+        InterpolateMode::Nearest => sample(B::float_round(ix), B::float_round(iy)),
+        InterpolateMode::Bilinear => {
+            let x0 = B::float_floor(ix.clone());
+            let y0 = B::float_floor(iy.clone());
+            let x1 = B::float_add_scalar(x0.clone(), 1.elem());
+            let y1 = B::float_add_scalar(y0.clone(), 1.elem());
+
+            // The weights are differentiable with respect to the grid.
+            let wx1 = B::float_sub(ix, x0.clone());
+            let wy1 = B::float_sub(iy, y0.clone());
+            let wx0 = B::float_add_scalar(B::float_neg(wx1.clone()), 1.elem());
+            let wy0 = B::float_add_scalar(B::float_neg(wy1.clone()), 1.elem());
+
+            [
+                (
+                    x0.clone(),
+                    y0.clone(),
+                    B::float_mul(wx0.clone(), wy0.clone()),
+                ),
+                (x1.clone(), y0, B::float_mul(wx1.clone(), wy0)),
+                (x0, y1.clone(), B::float_mul(wx0, wy1.clone())),
+                (x1, y1, B::float_mul(wx1, wy1)),
+            ]
+            .into_iter()
+            .map(|(ix, iy, weight)| {
+                let weight = B::float_reshape(weight, Shape::from([batch_size, 1, num_positions]));
+                B::float_mul(sample(ix, iy), weight)
+            })
+            .reduce(B::float_add)
+            .unwrap()
+        }
+        InterpolateMode::Bicubic => panic!("Bicubic grid sampling is not supported"),
+        InterpolateMode::Linear | InterpolateMode::Trilinear => {
+            panic!("2D grid sampling only supports the nearest and bilinear modes")
+        }
+    };
+
+    B::float_reshape(
+        output,
+        Shape::from([batch_size, channels, height_out, width_out]),
+    )
+}
+
+pub(crate) fn affine_grid_2d_from_matmul<B: Backend>(
+    theta: FloatTensor<B>,
+    size: [usize; 4],
+    align_corners: bool,
+) -> FloatTensor<B> {
+    let [batch_size, _, height, width] = size;
+    let device = B::float_device(&theta);
+
+    let xs = normalized_positions(width, align_corners);
+    let ys = normalized_positions(height, align_corners);
+    let base = ys
+        .iter()
+        .flat_map(|&y| xs.iter().flat_map(move |&x| [x, y, 1.]))
+        .map(|value| value.elem())
+        .collect::<Vec<B::FloatElem>>();
+
+    let base = B::float_from_data(TensorData::new(base, [1, height * width, 3]), &device);
+    let base = B::float_expand(base, Shape::from([batch_size, height * width, 3]));
+    let grid = B::float_matmul(base, B::float_swap_dims(theta, 1, 2));
+
+    B::float_reshape(grid, Shape::from([batch_size, height, width, 2]))
+}
+
+/// The normalized coordinates in `[-1, 1]` of the positions of a dimension.
+fn normalized_positions(size: usize, align_corners: bool) -> Vec<f64> {
+    (0..size)
+        .map(|index| match align_corners {
+            true if size > 1 => 2. * index as f64 / (size - 1) as f64 - 1.,
+            true => 0.,
+            false => (2 * index + 1) as f64 / size as f64 - 1.,
+        })
+        .collect()
+}
+
+/// Converts the normalized coordinates in `[-1, 1]` to positions in the input.
+fn unnormalize<B: Backend>(
+    coordinate: FloatTensor<B>,
+    size: usize,
+    align_corners: bool,
+) -> FloatTensor<B> {
+    let coordinate = B::float_add_scalar(coordinate, 1.elem());
+
+    if align_corners {
+        B::float_mul_scalar(coordinate, ((size - 1) as f64 / 2.).elem())
+    } else {
+        B::float_sub_scalar(
+            B::float_mul_scalar(coordinate, (size as f64 / 2.).elem()),
+            0.5.elem(),
+        )
+    }
+}
+
+/// Moves the positions outside the input according to the padding mode.
+fn pad_coordinate<B: Backend>(
+    coordinate: FloatTensor<B>,
+    size: usize,
+    padding_mode: GridSamplePaddingMode,
+    align_corners: bool,
+) -> FloatTensor<B> {
+    let last = (size - 1) as f64;
+
+    match padding_mode {
+        GridSamplePaddingMode::Zeros => coordinate,
+        GridSamplePaddingMode::Border => B::float_clamp(coordinate, 0.elem(), last.elem()),
+        GridSamplePaddingMode::Reflection => {
+            let coordinate = match align_corners {
+                true => reflect::<B>(coordinate, 0., last),
+                false => reflect::<B>(coordinate, -0.5, size as f64 - 0.5),
+            };
+            B::float_clamp(coordinate, 0.elem(), last.elem())
+        }
+    }
+}
+
+/// Reflects the positions by the bounds until they are between them.
+fn reflect<B: Backend>(coordinate: FloatTensor<B>, low: f64, high: f64) -> FloatTensor<B> {
+    let span = high - low;
+
+    if span <= 0. {
+        return B::float_mul_scalar(coordinate, 0.elem());
+    }
+
+    let coordinate = B::float_abs(B::float_sub_scalar(coordinate, low.elem()));
+    let extra = B::float_remainder_scalar(coordinate.clone(), span.elem());
+    let flips = B::float_floor(B::float_div_scalar(coordinate, span.elem()));
+    let even_flips = B::float_equal_elem(B::float_remainder_scalar(flips, 2.elem()), 0.elem());
+
+    let forward = B::float_add_scalar(extra.clone(), low.elem());
+    let backward = B::float_add_scalar(B::float_neg(extra), (span + low).elem());
+
+    B::float_mask_where(backward, even_flips, forward)
+}
+
+/// Gathers the values of the input at the given integer positions, where the positions outside
+/// the input give zeros.
+fn sample_positions<B: Backend>(
+    x: FloatTensor<B>,
+    ix: FloatTensor<B>,
+    iy: FloatTensor<B>,
+    [channels, height, width]: [usize; 3],
+) -> FloatTensor<B> {
+    let [batch_size, num_positions] = ix.shape().dims();
+    let shape = Shape::from([batch_size, 1, num_positions]);
+
+    let inside = B::bool_and(
+        inside::<B>(ix.clone(), width),
+        inside::<B>(iy.clone(), height),
+    );
+    let inside = B::float_reshape(B::bool_into_float(inside), shape.clone());
+
+    let ix = B::float_clamp(ix, 0.elem(), ((width - 1) as f64).elem());
+    let iy = B::float_clamp(iy, 0.elem(), ((height - 1) as f64).elem());
+    let indices = B::float_add(B::float_mul_scalar(iy, (width as f64).elem()), ix);
+    let indices = B::int_reshape(B::float_into_int(B::float_detach(indices)), shape);
+    let indices = B::int_expand(indices, Shape::from([batch_size, channels, num_positions]));
+
+    B::float_mul(B::float_gather(2, x, indices), inside)
+}
+
+fn inside<B: Backend>(coordinate: FloatTensor<B>, size: usize) -> BoolTensor<B> {
+    B::bool_and(
+        B::float_greater_equal_elem(coordinate.clone(), 0.elem()),
+        B::float_lower_equal_elem(coordinate, ((size - 1) as f64).elem()),
+    )
+}
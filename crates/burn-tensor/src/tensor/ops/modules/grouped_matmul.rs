@@ -0,0 +1,89 @@
+use alloc::vec::Vec;
+use burn_common::reader::try_read_sync;
+use core::ops::Range;
+
+use crate::{
+    backend::Backend,
+    ops::{FloatTensor, IntTensor},
+    Shape, TensorMetadata,
+};
+
+/// Reads the rows of each group from the offsets holding the end of each group.
+fn group_rows<B: Backend>(offsets: IntTensor<B>) -> Vec<Range<usize>> {
+    let offsets = try_read_sync(B::int_into_data(offsets)).expect(
+        "Failed to synchronously read the group offsets. This operation is not supported until \
+         this backend has a grouped matrix multiplication implementation.",
+    );
+    let mut start = 0;
+
+    offsets
+        .iter::<i64>()
+        .map(|end| {
+            let end = end as usize;
+            let rows = start..end;
+            start = end;
+            rows
+        })
+        .collect()
+}
+
+/// Computes the grouped matrix multiplication with one matrix multiplication per group.
+pub(crate) fn grouped_matmul_from_groups<B: Backend>(
+    lhs: FloatTensor<B>,
+    rhs: FloatTensor<B>,
+    offsets: IntTensor<B>,
+) -> FloatTensor<B> {
+    let [num_rows, k] = lhs.shape().dims();
+    let [_, _, n] = rhs.shape().dims();
+    let device = B::float_device(&lhs);
+
+    let groups = group_rows::<B>(offsets);
+    let end = groups.last().map(|rows| rows.end).unwrap_or(0);
+
+    let mut outputs: Vec<_> = groups
+        .into_iter()
+        .enumerate()
+        .filter(|(_, rows)| !rows.is_empty())
+        .map(|(group, rows)| {
+            let rhs = B::float_slice(rhs.clone(), &[group..group + 1, 0..k, 0..n]);
+            let rhs = B::float_reshape(rhs, Shape::new([k, n]));
+
+            B::float_matmul(B::float_slice(lhs.clone(), &[rows, 0..k]), rhs)
+        })
+        .collect();
+
+    // The rows after the last group aren't multiplied.
+    if end < num_rows || outputs.is_empty() {
+        outputs.push(B::float_zeros(Shape::new([num_rows - end, n]), &device));
+    }
+
+    B::float_cat(outputs, 0)
+}
+
+/// Computes the gradient of each group matrix with one matrix multiplication per group.
+pub(crate) fn grouped_matmul_rhs_backward_from_groups<B: Backend>(
+    lhs: FloatTensor<B>,
+    grad: FloatTensor<B>,
+    offsets: IntTensor<B>,
+) -> FloatTensor<B> {
+    let [_, k] = lhs.shape().dims();
+    let [_, n] = grad.shape().dims();
+    let device = B::float_device(&lhs);
+
+    let grads = group_rows::<B>(offsets)
+        .into_iter()
+        .map(|rows| {
+            if rows.is_empty() {
+                return B::float_zeros(Shape::new([1, k, n]), &device);
+            }
+
+            let lhs = B::float_slice(lhs.clone(), &[rows.clone(), 0..k]);
+            let grad = B::float_slice(grad.clone(), &[rows, 0..n]);
+            let grad = B::float_matmul(B::float_transpose(lhs), grad);
+
+            B::float_reshape(grad, Shape::new([1, k, n]))
+        })
+        .collect();
+
+    B::float_cat(grads, 0)
+}
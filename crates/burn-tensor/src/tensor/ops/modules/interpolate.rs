@@ -0,0 +1,140 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{
+    backend::Backend, ops::FloatTensor, ElementConversion, Shape, TensorData, TensorMetadata,
+};
+
+use super::{InterpolateMode, InterpolateOptions};
+
+/// Computes an interpolation along every spatial dimension of the input, one dimension after the
+/// other, which is equivalent to the linear, bilinear, trilinear or bicubic interpolations since
+/// their kernels are separable.
+///
+/// The positions of each dimension are selected from the input and weighted, so the operation is
+/// differentiable by the autodiff backend without a dedicated backward pass.
+pub(crate) fn interpolate_separable<B: Backend>(
+    x: FloatTensor<B>,
+    output_size: &[usize],
+    options: InterpolateOptions,
+) -> FloatTensor<B> {
+    let device = B::float_device(&x);
+
+    output_size
+        .iter()
+        .enumerate()
+        .fold(x, |x, (index, &size_out)| {
+            let dim = index + 2;
+            let shape = x.shape();
+            let size_in = shape.dims[dim];
+            let taps = interpolation_taps(size_in, size_out, &options);
+
+            let mut weights_shape = vec![1; shape.num_dims()];
+            weights_shape[dim] = size_out;
+
+            taps.into_iter()
+                .map(|(indices, weights)| {
+                    let indices = indices
+                        .into_iter()
+                        .map(|index| (index as i64).elem())
+                        .collect::<Vec<B::IntElem>>();
+                    let weights = weights
+                        .into_iter()
+                        .map(|weight| weight.elem())
+                        .collect::<Vec<B::FloatElem>>();
+
+                    let indices = B::int_from_data(TensorData::new(indices, [size_out]), &device);
+                    let weights = B::float_from_data(
+                        TensorData::new(weights, Shape::from(weights_shape.clone())),
+                        &device,
+                    );
+
+                    B::float_mul(B::float_select(x.clone(), dim, indices), weights)
+                })
+                .reduce(B::float_add)
+                .expect("At least one position should be interpolated")
+        })
+}
+
+/// Returns the input positions with their weights for every output position of a dimension.
+///
+/// Each item is a tap of the interpolation kernel, with one input index and one weight for each
+/// output position.
+fn interpolation_taps(
+    size_in: usize,
+    size_out: usize,
+    options: &InterpolateOptions,
+) -> Vec<(Vec<usize>, Vec<f64>)> {
+    assert!(size_in > 0, "Can't interpolate a dimension of size zero");
+
+    let last = size_in - 1;
+    let num_taps = match options.mode {
+        InterpolateMode::Nearest => 1,
+        InterpolateMode::Linear | InterpolateMode::Bilinear | InterpolateMode::Trilinear => 2,
+        InterpolateMode::Bicubic => 4,
+    };
+    let mut taps = vec![(Vec::with_capacity(size_out), Vec::with_capacity(size_out)); num_taps];
+
+    for index_out in 0..size_out {
+        match options.mode {
+            InterpolateMode::Nearest => {
+                let index = (index_out * size_in / size_out).min(last);
+
+                taps[0].0.push(index);
+                taps[0].1.push(1.);
+            }
+            InterpolateMode::Linear | InterpolateMode::Bilinear | InterpolateMode::Trilinear => {
+                let position =
+                    source_position(index_out, size_in, size_out, options.align_corners).max(0.);
+                let index = (position.floor() as usize).min(last);
+                let lambda = position - index as f64;
+
+                taps[0].0.push(index);
+                taps[0].1.push(1. - lambda);
+                taps[1].0.push((index + 1).min(last));
+                taps[1].1.push(lambda);
+            }
+            InterpolateMode::Bicubic => {
+                let position = source_position(index_out, size_in, size_out, options.align_corners);
+                let start = position.floor();
+                let coefficients = cubic_coefficients(position - start);
+
+                for (offset, (tap, coefficient)) in taps.iter_mut().zip(coefficients).enumerate() {
+                    let index = (start as i64 - 1 + offset as i64).clamp(0, last as i64);
+
+                    tap.0.push(index as usize);
+                    tap.1.push(coefficient);
+                }
+            }
+        }
+    }
+
+    taps
+}
+
+/// The position in the input corresponding to an output position.
+///
+/// With `align_corners`, the centers of the corner elements of the input and output are aligned,
+/// otherwise their corners are aligned, as PyTorch and the ONNX `half_pixel` mode do.
+fn source_position(index_out: usize, size_in: usize, size_out: usize, align_corners: bool) -> f64 {
+    if align_corners {
+        if size_out > 1 {
+            index_out as f64 * (size_in - 1) as f64 / (size_out - 1) as f64
+        } else {
+            0.
+        }
+    } else {
+        (index_out as f64 + 0.5) * size_in as f64 / size_out as f64 - 0.5
+    }
+}
+
+/// The coefficients of the cubic convolution kernel with `a = -0.75` for the four neighbours
+/// `[-1, 0, 1, 2]` of a position with fractional part `t`.
+fn cubic_coefficients(t: f64) -> [f64; 4] {
+    const A: f64 = -0.75;
+
+    let near = |x: f64| ((A + 2.) * x - (A + 3.)) * x * x + 1.;
+    let far = |x: f64| ((A * x - 5. * A) * x + 8. * A) * x - 4. * A;
+
+    [far(t + 1.), near(t), near(1. - t), far(2. - t)]
+}
@@ -3,8 +3,22 @@ pub mod conv;
 
 /// Module with cat operation
 pub(crate) mod cat;
+/// Module with the CTC loss operations.
+pub(crate) mod ctc;
+/// Module with grid sample operations.
+pub(crate) mod grid_sample;
+/// Module with grouped matrix multiplication operations.
+pub(crate) mod grouped_matmul;
+/// Module with separable interpolation operations.
+pub(crate) mod interpolate;
 /// Module with repeat operation
 pub(crate) mod repeat_dim;
+/// Module with scan operations.
+pub(crate) mod scan;
+/// Module with pixel shuffle operations.
+pub(crate) mod shuffle;
+/// Module with special math functions
+pub(crate) mod special;
 /// Module with unfold operations.
 pub(crate) mod unfold;
 
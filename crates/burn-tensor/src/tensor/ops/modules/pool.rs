@@ -1,10 +1,16 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
 use crate::{
     backend::Backend,
     ops::{FloatTensor, IntTensor},
-    Shape, TensorMetadata,
+    ElementConversion, Shape, TensorMetadata,
 };
 
-use super::{MaxPool1dBackward, MaxPool1dWithIndices};
+use super::{
+    AdaptiveMaxPool1dWithIndices, AdaptiveMaxPool2dWithIndices, MaxPool1dBackward,
+    MaxPool1dWithIndices, MaxPool3dWithIndices,
+};
 
 pub(crate) fn avg_pool1d_from_2d<B: Backend>(
     x: FloatTensor<B>,
@@ -165,3 +171,401 @@ pub(crate) fn max_pool1d_with_indices_backward_from_2d<B: Backend>(
         Shape::from([batch_size, channels, length_in]),
     ))
 }
+
+pub(crate) fn max_pool3d_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> FloatTensor<B> {
+    let [batch_size, channels, depth, height, width] = x.shape().dims();
+
+    // Max pooling is separable: the height and the width are pooled first, then the depth.
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::max_pool2d(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        [dilation[1], dilation[2]],
+    );
+    let [_, _, height_out, width_out] = x.shape().dims();
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+    );
+    let x = B::max_pool2d(
+        x,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        [dilation[0], 1],
+    );
+    let [_, _, depth_out, _] = x.shape().dims();
+
+    B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth_out, height_out, width_out]),
+    )
+}
+
+pub(crate) fn max_pool3d_with_indices_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> MaxPool3dWithIndices<B> {
+    let [batch_size, channels, depth, height, width] = x.shape().dims();
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::max_pool2d_with_indices(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        [dilation[1], dilation[2]],
+    );
+    let [_, _, height_out, width_out] = x.output.shape().dims();
+    let plane_size = height_out * width_out;
+    let shape = Shape::from([batch_size, channels, depth, plane_size]);
+    let plane_indices = B::int_reshape(x.indices, shape.clone());
+
+    let x = B::max_pool2d_with_indices(
+        B::float_reshape(x.output, shape),
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        [dilation[0], 1],
+    );
+    let [_, _, depth_out, _] = x.output.shape().dims();
+    let shape = Shape::from([batch_size, channels, depth_out, height_out, width_out]);
+
+    // The indices of the depth pooling are `depth * plane_size + position`, which gives the
+    // selected depth used to find the index in the plane selected by the first pooling.
+    let depth_indices = B::int_div_scalar(x.indices, (plane_size as i64).elem());
+    let plane_indices = B::int_gather(2, plane_indices, depth_indices.clone());
+    let indices = B::int_add(
+        B::int_mul_scalar(depth_indices, ((height * width) as i64).elem()),
+        plane_indices,
+    );
+
+    MaxPool3dWithIndices::new(
+        B::float_reshape(x.output, shape.clone()),
+        B::int_reshape(indices, shape),
+    )
+}
+
+/// Computes the gradient of a max pooling operation, where the indices are the positions of the
+/// maximums in the flattened spatial dimensions of the input.
+pub(crate) fn max_pool_backward_from_indices<B: Backend>(
+    x: FloatTensor<B>,
+    output_grad: FloatTensor<B>,
+    indices: IntTensor<B>,
+) -> FloatTensor<B> {
+    let shape = x.shape();
+    let device = B::float_device(&x);
+    let [batch_size, channels] = [shape.dims[0], shape.dims[1]];
+    let input_size = shape.dims[2..].iter().product::<usize>();
+    let output_size = output_grad.shape().dims[2..].iter().product::<usize>();
+
+    let output_grad = B::float_reshape(
+        output_grad,
+        Shape::from([batch_size, channels, output_size]),
+    );
+    let indices = B::int_reshape(indices, Shape::from([batch_size, channels, output_size]));
+    let x_grad = B::float_zeros(Shape::from([batch_size, channels, input_size]), &device);
+
+    // The gradients of the windows sharing the same maximum are summed.
+    let x_grad = B::float_scatter(2, x_grad, indices, output_grad);
+
+    B::float_reshape(x_grad, shape)
+}
+
+pub(crate) fn avg_pool3d_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> FloatTensor<B> {
+    let [batch_size, channels, depth, height, width] = x.shape().dims();
+
+    // Avg pooling is separable, since the number of elements in a window is the product of its
+    // sizes: the height and the width are pooled first, then the depth.
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::avg_pool2d(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        count_include_pad,
+    );
+    let [_, _, height_out, width_out] = x.shape().dims();
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+    );
+    let x = B::avg_pool2d(
+        x,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        count_include_pad,
+    );
+    let [_, _, depth_out, _] = x.shape().dims();
+
+    B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth_out, height_out, width_out]),
+    )
+}
+
+pub(crate) fn avg_pool3d_backward_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    grad: FloatTensor<B>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> FloatTensor<B> {
+    let [batch_size, channels, depth, height, width] = x.shape().dims();
+    let [_, _, depth_out, height_out, width_out] = grad.shape().dims();
+    let device = B::float_device(&x);
+
+    // Only the shape of the input of the depth pooling is used by its backward pass.
+    let x_depth = B::float_zeros(
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+        &device,
+    );
+    let grad = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth_out, height_out * width_out]),
+    );
+    let grad = B::avg_pool2d_backward(
+        x_depth,
+        grad,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        count_include_pad,
+    );
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let grad = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels * depth, height_out, width_out]),
+    );
+    let grad = B::avg_pool2d_backward(
+        x,
+        grad,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        count_include_pad,
+    );
+
+    B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth, height, width]),
+    )
+}
+
+pub(crate) fn adaptive_avg_pool3d_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    output_size: [usize; 3],
+) -> FloatTensor<B> {
+    let [batch_size, channels, depth, height, width] = x.shape().dims();
+    let [depth_out, height_out, width_out] = output_size;
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::adaptive_avg_pool2d(x, [height_out, width_out]);
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+    );
+    let x = B::adaptive_avg_pool2d(x, [depth_out, height_out * width_out]);
+
+    B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth_out, height_out, width_out]),
+    )
+}
+
+pub(crate) fn adaptive_avg_pool3d_backward_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    grad: FloatTensor<B>,
+) -> FloatTensor<B> {
+    let [batch_size, channels, depth, height, width] = x.shape().dims();
+    let [_, _, depth_out, height_out, width_out] = grad.shape().dims();
+    let device = B::float_device(&x);
+
+    // Only the shape of the input of the depth pooling is used by its backward pass.
+    let x_depth = B::float_zeros(
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+        &device,
+    );
+    let grad = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth_out, height_out * width_out]),
+    );
+    let grad = B::adaptive_avg_pool2d_backward(x_depth, grad);
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let grad = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels * depth, height_out, width_out]),
+    );
+    let grad = B::adaptive_avg_pool2d_backward(x, grad);
+
+    B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth, height, width]),
+    )
+}
+
+pub(crate) fn adaptive_max_pool1d_with_indices_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    output_size: usize,
+) -> AdaptiveMaxPool1dWithIndices<B> {
+    let [batch_size, channels, length] = x.shape().dims();
+
+    let x = B::float_reshape(x, Shape::from([batch_size, channels, length, 1]));
+    let x = B::adaptive_max_pool2d_with_indices(x, [output_size, 1]);
+
+    // With a width of one, the indices in the flattened spatial dimensions are the positions.
+    let shape = Shape::from([batch_size, channels, output_size]);
+    AdaptiveMaxPool1dWithIndices::new(
+        B::float_reshape(x.output, shape.clone()),
+        B::int_reshape(x.indices, shape),
+    )
+}
+
+pub(crate) fn adaptive_max_pool2d_with_indices_from_slices<B: Backend>(
+    x: FloatTensor<B>,
+    output_size: [usize; 2],
+) -> AdaptiveMaxPool2dWithIndices<B> {
+    let [_, _, _, width] = x.shape().dims();
+
+    // Max pooling is separable: the width is pooled first, then the height.
+    let (x, width_indices) = adaptive_max_pool_dim::<B>(x, 3, output_size[1]);
+    let (output, height_indices) = adaptive_max_pool_dim::<B>(x, 2, output_size[0]);
+
+    let width_indices = B::int_gather(2, width_indices, height_indices.clone());
+    let indices = B::int_add(
+        B::int_mul_scalar(height_indices, (width as i64).elem()),
+        width_indices,
+    );
+
+    AdaptiveMaxPool2dWithIndices::new(output, indices)
+}
+
+/// Applies an adaptive max pooling along a single dimension, returning the maximums and their
+/// positions along that dimension.
+fn adaptive_max_pool_dim<B: Backend>(
+    x: FloatTensor<B>,
+    dim: usize,
+    output_size: usize,
+) -> (FloatTensor<B>, IntTensor<B>) {
+    let shape = x.shape();
+    let size = shape.dims[dim];
+    let mut ranges: Vec<Range<usize>> = shape.dims.iter().map(|&size| 0..size).collect();
+
+    let (values, indices): (Vec<_>, Vec<_>) = (0..output_size)
+        .map(|index| {
+            let start = index * size / output_size;
+            let end = ((index + 1) * size).div_ceil(output_size);
+            ranges[dim] = start..end;
+
+            let window = B::float_slice(x.clone(), &ranges);
+            let (values, indices) = B::float_max_dim_with_indices(window, dim);
+
+            (values, B::int_add_scalar(indices, (start as i64).elem()))
+        })
+        .unzip();
+
+    (B::float_cat(values, dim), B::int_cat(indices, dim))
+}
+
+pub(crate) fn max_unpool2d_from_scatter<B: Backend>(
+    x: FloatTensor<B>,
+    indices: IntTensor<B>,
+    output_size: [usize; 2],
+) -> FloatTensor<B> {
+    let [batch_size, channels, height, width] = x.shape().dims();
+    let [height_out, width_out] = output_size;
+    let device = B::float_device(&x);
+    let shape = Shape::from([batch_size, channels, height_out * width_out]);
+
+    let x = B::float_reshape(x, Shape::from([batch_size, channels, height * width]));
+    let indices = B::int_reshape(indices, Shape::from([batch_size, channels, height * width]));
+
+    let output = B::float_scatter(
+        2,
+        B::float_zeros(shape.clone(), &device),
+        indices.clone(),
+        x,
+    );
+    let counts = unpool_counts::<B>(indices, shape, &device);
+
+    B::float_reshape(
+        B::float_div(output, counts),
+        Shape::from([batch_size, channels, height_out, width_out]),
+    )
+}
+
+pub(crate) fn max_unpool2d_backward_from_gather<B: Backend>(
+    indices: IntTensor<B>,
+    grad: FloatTensor<B>,
+) -> FloatTensor<B> {
+    let [batch_size, channels, height, width] = indices.shape().dims();
+    let [_, _, height_out, width_out] = grad.shape().dims();
+    let device = B::float_device(&grad);
+    let shape = Shape::from([batch_size, channels, height_out * width_out]);
+
+    let indices = B::int_reshape(indices, Shape::from([batch_size, channels, height * width]));
+    let counts = unpool_counts::<B>(indices.clone(), shape.clone(), &device);
+    let grad = B::float_div(B::float_reshape(grad, shape), counts);
+
+    B::float_reshape(
+        B::float_gather(2, grad, indices),
+        Shape::from([batch_size, channels, height, width]),
+    )
+}
+
+/// Counts the values written at each position by max unpooling, with a minimum of one.
+///
+/// Overlapping pooling windows can select the same maximum more than once, in which case the
+/// value is written once.
+fn unpool_counts<B: Backend>(
+    indices: IntTensor<B>,
+    shape: Shape,
+    device: &crate::Device<B>,
+) -> FloatTensor<B> {
+    let ones = B::float_ones(indices.shape(), device);
+    let counts = B::float_scatter(2, B::float_zeros(shape, device), indices, ones);
+
+    B::float_clamp_min(counts, 1.elem())
+}
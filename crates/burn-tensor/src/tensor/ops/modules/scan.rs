@@ -0,0 +1,58 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::{backend::Backend, ops::FloatTensor, ElementConversion, Shape, TensorMetadata};
+
+/// Computes the linear recurrence `h[t] = a[t] * h[t - 1] + b[t]` with the Hillis-Steele scan.
+///
+/// Two steps `(a1, b1)` then `(a2, b2)` of the recurrence compose into the step
+/// `(a1 * a2, a2 * b1 + b2)`, which is associative, so each iteration combines every position
+/// with the one `offset` positions before it, the offset doubling until it covers the sequence.
+pub(crate) fn linear_recurrence_from_associative_scan<B: Backend>(
+    a: FloatTensor<B>,
+    b: FloatTensor<B>,
+    dim: usize,
+) -> FloatTensor<B> {
+    let length = a.shape().dims[dim];
+    let mut a = a;
+    let mut b = b;
+    let mut offset = 1;
+
+    while offset < length {
+        let b_previous = shift::<B>(b.clone(), dim, offset, 0.0);
+        b = B::float_add(b, B::float_mul(a.clone(), b_previous));
+
+        // The products of `a` are only needed by the next iterations.
+        if offset * 2 < length {
+            let a_previous = shift::<B>(a.clone(), dim, offset, 1.0);
+            a = B::float_mul(a, a_previous);
+        }
+
+        offset *= 2;
+    }
+
+    b
+}
+
+/// Shifts the tensor by `offset` positions along the dimension, filling the first positions with
+/// the given value.
+fn shift<B: Backend>(
+    tensor: FloatTensor<B>,
+    dim: usize,
+    offset: usize,
+    value: f64,
+) -> FloatTensor<B> {
+    let shape = tensor.shape();
+    let length = shape.dims[dim];
+    let device = B::float_device(&tensor);
+
+    let mut ranges: Vec<Range<usize>> = shape.dims.iter().map(|&size| 0..size).collect();
+    ranges[dim] = 0..length - offset;
+    let mut fill_shape = shape.dims.clone();
+    fill_shape[dim] = offset;
+
+    let fill = B::float_full(Shape::from(fill_shape), value.elem(), &device);
+
+    B::float_cat(vec![fill, B::float_slice(tensor, &ranges)], dim)
+}
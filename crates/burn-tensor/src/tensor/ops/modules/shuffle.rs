@@ -0,0 +1,51 @@
+use crate::{backend::Backend, ops::FloatTensor, Shape, TensorMetadata};
+
+/// Computes the pixel shuffle operation by splitting the channels, interleaving them with the
+/// spatial dimensions and merging them back.
+pub(crate) fn pixel_shuffle_from_reshape<B: Backend>(
+    x: FloatTensor<B>,
+    upscale_factor: usize,
+) -> FloatTensor<B> {
+    let [batch_size, channels, height, width] = x.shape().dims();
+    let factor = upscale_factor;
+    let channels_out = channels / (factor * factor);
+
+    let x = B::float_reshape(
+        x,
+        Shape::new([batch_size, channels_out, factor, factor, height, width]),
+    );
+    let x = B::float_permute(x, &[0, 1, 4, 2, 5, 3]);
+
+    B::float_reshape(
+        x,
+        Shape::new([batch_size, channels_out, height * factor, width * factor]),
+    )
+}
+
+/// Computes the pixel unshuffle operation, the inverse of the
+/// [pixel shuffle](pixel_shuffle_from_reshape), by splitting the spatial dimensions and moving
+/// the blocks to the channels.
+pub(crate) fn pixel_unshuffle_from_reshape<B: Backend>(
+    x: FloatTensor<B>,
+    downscale_factor: usize,
+) -> FloatTensor<B> {
+    let [batch_size, channels, height, width] = x.shape().dims();
+    let factor = downscale_factor;
+    let (height_out, width_out) = (height / factor, width / factor);
+
+    let x = B::float_reshape(
+        x,
+        Shape::new([batch_size, channels, height_out, factor, width_out, factor]),
+    );
+    let x = B::float_permute(x, &[0, 1, 3, 5, 2, 4]);
+
+    B::float_reshape(
+        x,
+        Shape::new([
+            batch_size,
+            channels * factor * factor,
+            height_out,
+            width_out,
+        ]),
+    )
+}
@@ -0,0 +1,217 @@
+use core::f64::consts::{FRAC_PI_2, PI};
+
+use crate::{backend::Backend, ops::FloatTensor, ElementConversion, TensorMetadata};
+
+/// The coefficients of the Lanczos approximation of the gamma function, with `g = 7`.
+const LANCZOS_COEFFICIENTS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+fn horner<B: Backend>(x: FloatTensor<B>, coefficients: &[f64]) -> FloatTensor<B> {
+    let device = B::float_device(&x);
+    let mut output = B::float_full(x.shape(), coefficients[0].elem(), &device);
+
+    for coefficient in &coefficients[1..] {
+        output = B::float_add_scalar(B::float_mul(output, x.clone()), coefficient.elem());
+    }
+
+    output
+}
+
+fn one_minus<B: Backend>(x: FloatTensor<B>) -> FloatTensor<B> {
+    B::float_add_scalar(B::float_neg(x), 1.elem())
+}
+
+/// Arctangent of the tensor.
+pub(crate) fn atan<B: Backend>(x: FloatTensor<B>) -> FloatTensor<B> {
+    // atan(x) = sign(x) * pi / 2 - atan(1 / x) brings the values in [-1, 1].
+    let inverted = B::float_greater_elem(B::float_abs(x.clone()), 1.elem());
+    let y = B::float_mask_where(x.clone(), inverted.clone(), B::float_recip(x.clone()));
+
+    // atan(y) = 2 * atan(y / (1 + sqrt(1 + y^2))) brings the values in [-0.2, 0.2].
+    let mut t = y;
+    for _ in 0..2 {
+        let t2 = B::float_add_scalar(B::float_mul(t.clone(), t.clone()), 1.elem());
+        let denominator = B::float_add_scalar(B::float_sqrt(t2), 1.elem());
+        t = B::float_div(t, denominator);
+    }
+
+    // Taylor series of atan(t), accurate to float precision for |t| <= 0.2.
+    let t2 = B::float_mul(t.clone(), t.clone());
+    let series = horner::<B>(
+        t2,
+        &[
+            1.0 / 13.0,
+            -1.0 / 11.0,
+            1.0 / 9.0,
+            -1.0 / 7.0,
+            1.0 / 5.0,
+            -1.0 / 3.0,
+            1.0,
+        ],
+    );
+    let output = B::float_mul_scalar(B::float_mul(t, series), 4.elem());
+
+    let complement = B::float_sub(
+        B::float_mul_scalar(B::float_sign(x), FRAC_PI_2.elem()),
+        output.clone(),
+    );
+
+    B::float_mask_where(output, inverted, complement)
+}
+
+/// Four-quadrant arctangent of `y / x`.
+pub(crate) fn atan2<B: Backend>(y: FloatTensor<B>, x: FloatTensor<B>) -> FloatTensor<B> {
+    let output = atan::<B>(B::float_div(y.clone(), x.clone()));
+
+    // Adds pi in the second quadrant and removes it in the third.
+    let x_negative = B::bool_into_float(B::float_lower_elem(x.clone(), 0.elem()));
+    let y_sign = B::float_sub_scalar(
+        B::float_mul_scalar(
+            B::bool_into_float(B::float_greater_equal_elem(y.clone(), 0.elem())),
+            2.elem(),
+        ),
+        1.elem(),
+    );
+    let correction = B::float_mul_scalar(B::float_mul(x_negative, y_sign), PI.elem());
+    let output = B::float_add(output, correction);
+
+    // The angle of the origin is zero.
+    let origin = B::float_mul(
+        B::bool_into_float(B::float_equal_elem(x, 0.elem())),
+        B::bool_into_float(B::float_equal_elem(y, 0.elem())),
+    );
+    let origin = B::float_equal_elem(origin, 1.elem());
+
+    B::float_mask_fill(output, origin, 0.elem())
+}
+
+/// Inverse error function of the tensor.
+pub(crate) fn erfinv<B: Backend>(x: FloatTensor<B>) -> FloatTensor<B> {
+    // Initial approximation from M. Giles, "Approximating the erfinv function".
+    let w = B::float_neg(B::float_log(B::float_mul(
+        one_minus::<B>(x.clone()),
+        B::float_add_scalar(x.clone(), 1.elem()),
+    )));
+    let central = horner::<B>(
+        B::float_sub_scalar(w.clone(), 2.5.elem()),
+        &[
+            2.810_226_36e-08,
+            3.432_739_39e-07,
+            -3.523_387_7e-06,
+            -4.391_506_54e-06,
+            0.000_218_580_87,
+            -0.001_253_725_03,
+            -0.004_177_681_64,
+            0.246_640_727,
+            1.501_409_41,
+        ],
+    );
+    let tail = horner::<B>(
+        B::float_sub_scalar(B::float_sqrt(w.clone()), 3.elem()),
+        &[
+            -0.000_200_214_257,
+            0.000_100_950_558,
+            0.001_349_343_22,
+            -0.003_673_428_44,
+            0.005_739_507_73,
+            -0.007_622_461_3,
+            0.009_438_870_47,
+            1.001_674_06,
+            2.832_976_82,
+        ],
+    );
+    let is_tail = B::float_greater_equal_elem(w, 5.elem());
+    let output = B::float_mul(B::float_mask_where(central, is_tail, tail), x.clone());
+
+    // A Newton step refines the approximation using the error function.
+    let derivative = B::float_mul_scalar(
+        B::float_exp(B::float_neg(B::float_mul(output.clone(), output.clone()))),
+        (2.0 / PI.sqrt()).elem(),
+    );
+    let error = B::float_sub(B::float_erf(output.clone()), x.clone());
+    let output = B::float_sub(output, B::float_div(error, derivative));
+
+    let output = B::float_mask_fill(
+        output,
+        B::float_equal_elem(x.clone(), 1.elem()),
+        f64::INFINITY.elem(),
+    );
+    B::float_mask_fill(
+        output,
+        B::float_equal_elem(x, (-1).elem()),
+        f64::NEG_INFINITY.elem(),
+    )
+}
+
+/// Natural logarithm of the absolute value of the gamma function of the tensor.
+pub(crate) fn lgamma<B: Backend>(x: FloatTensor<B>) -> FloatTensor<B> {
+    // The reflection formula gives the values below 0.5 from the values above.
+    let reflected = B::float_lower_elem(x.clone(), 0.5.elem());
+    let z = B::float_mask_where(x.clone(), reflected.clone(), one_minus::<B>(x.clone()));
+
+    let z = B::float_sub_scalar(z, 1.elem());
+    let device = B::float_device(&z);
+    let mut sum = B::float_full(z.shape(), LANCZOS_COEFFICIENTS[0].elem(), &device);
+    for (i, coefficient) in LANCZOS_COEFFICIENTS.iter().enumerate().skip(1) {
+        let term = B::float_recip(B::float_add_scalar(z.clone(), (i as f64).elem()));
+        sum = B::float_add(sum, B::float_mul_scalar(term, coefficient.elem()));
+    }
+    let t = B::float_add_scalar(z.clone(), 7.5.elem());
+    let output = B::float_add_scalar(
+        B::float_mul(B::float_add_scalar(z, 0.5.elem()), B::float_log(t.clone())),
+        (0.5 * (2.0 * PI).ln()).elem(),
+    );
+    let output = B::float_add(B::float_sub(output, t), B::float_log(sum));
+
+    // lgamma(x) = log(pi / |sin(pi * x)|) - lgamma(1 - x)
+    let sin = B::float_abs(B::float_sin(B::float_mul_scalar(x, PI.elem())));
+    let reflection = B::float_sub(
+        B::float_log(B::float_mul_scalar(B::float_recip(sin), PI.elem())),
+        output.clone(),
+    );
+
+    B::float_mask_where(output, reflected, reflection)
+}
+
+/// Logarithmic derivative of the gamma function of the tensor.
+pub(crate) fn digamma<B: Backend>(x: FloatTensor<B>) -> FloatTensor<B> {
+    // The reflection formula gives the values below 0.5 from the values above.
+    let reflected = B::float_lower_elem(x.clone(), 0.5.elem());
+    let z = B::float_mask_where(x.clone(), reflected.clone(), one_minus::<B>(x.clone()));
+
+    // digamma(z) = digamma(z + 6) - sum(1 / (z + k)) moves the values in the asymptotic range.
+    let mut shift = B::float_recip(z.clone());
+    for k in 1..6 {
+        let term = B::float_recip(B::float_add_scalar(z.clone(), k.elem()));
+        shift = B::float_add(shift, term);
+    }
+    let y = B::float_add_scalar(z, 6.elem());
+    let y2 = B::float_recip(B::float_mul(y.clone(), y.clone()));
+    let series = B::float_mul(
+        y2.clone(),
+        horner::<B>(y2, &[-1.0 / 252.0, 1.0 / 120.0, -1.0 / 12.0]),
+    );
+    let output = B::float_sub(
+        B::float_log(y.clone()),
+        B::float_div_scalar(B::float_recip(y), 2.elem()),
+    );
+    let output = B::float_sub(B::float_add(output, series), shift);
+
+    // digamma(x) = digamma(1 - x) - pi / tan(pi * x)
+    let tan = B::float_tan(B::float_mul_scalar(x, PI.elem()));
+    let reflection = B::float_sub(
+        output.clone(),
+        B::float_mul_scalar(B::float_recip(tan), PI.elem()),
+    );
+
+    B::float_mask_where(output, reflected, reflection)
+}
@@ -4,7 +4,7 @@ use crate::{ElementConversion, Shape, TensorData, TensorMetadata};
 use alloc::vec;
 use alloc::vec::Vec;
 
-use super::{ConvOptions, UnfoldOptions};
+use super::{ConvOptions, ConvTransposeOptions, UnfoldOptions};
 
 /// Constructs a special weight tensor used for unfolding.
 ///
@@ -79,3 +79,60 @@ pub(crate) fn unfold4d_using_conv2d<B: Backend>(
         Shape::new([batch_size, channels_out, out_height * out_width]),
     )
 }
+
+/// Compute the fold4d operation using the conv transpose 2d operation.
+///
+/// The folding is the adjoint of the unfolding, so it is computed with the transposed
+/// convolution using the same weight, which sums the overlapping values of the blocks.
+pub(crate) fn fold4d_using_conv_transpose2d<B: Backend>(
+    x: FloatTensor<B>,
+    output_size: [usize; 2],
+    kernel_size: [usize; 2],
+    options: UnfoldOptions,
+) -> FloatTensor<B> {
+    let [batch_size, channels_in, num_blocks] = x.shape().dims();
+    let channels_out = channels_in / (kernel_size[0] * kernel_size[1]);
+
+    let num_blocks_per_dim: [usize; 2] = core::array::from_fn(|i| {
+        (output_size[i] + 2 * options.padding[i] - options.dilation[i] * (kernel_size[i] - 1) - 1)
+            / options.stride[i]
+            + 1
+    });
+    assert_eq!(
+        num_blocks,
+        num_blocks_per_dim[0] * num_blocks_per_dim[1],
+        "The number of blocks should match the output size."
+    );
+
+    // The transposed convolution may not cover the last rows and columns of the output.
+    let padding_out: [usize; 2] = core::array::from_fn(|i| {
+        output_size[i] + 2 * options.padding[i]
+            - (num_blocks_per_dim[i] - 1) * options.stride[i]
+            - options.dilation[i] * (kernel_size[i] - 1)
+            - 1
+    });
+
+    let x = B::float_reshape(
+        x,
+        Shape::new([
+            batch_size,
+            channels_in,
+            num_blocks_per_dim[0],
+            num_blocks_per_dim[1],
+        ]),
+    );
+    let weight = create_unfolding_weight::<B>(channels_out, kernel_size, &B::float_device(&x));
+
+    B::conv_transpose2d(
+        x,
+        weight,
+        None,
+        ConvTransposeOptions::new(
+            options.stride,
+            options.padding,
+            padding_out,
+            options.dilation,
+            1,
+        ),
+    )
+}
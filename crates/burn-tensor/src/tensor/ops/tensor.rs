@@ -1,5 +1,6 @@
 use super::cat::cat_with_slice_assign;
 use super::repeat_dim::repeat_with_slice_assign;
+use super::special;
 use super::{BoolTensor, Device, FloatElem, FloatTensor, IntElem, IntTensor};
 use crate::tensor::cast::ToElement;
 use crate::{backend::Backend, tensor::Shape, Distribution, ElementConversion, Float, TensorData};
@@ -11,7 +12,10 @@ use alloc::vec::Vec;
 use core::future::Future;
 use core::ops::Range;
 
-use crate::{argsort, sort, sort_with_indices};
+use crate::{
+    argsort, pad, searchsorted_shapes, slice_assign_with_steps, slice_with_steps, sort,
+    sort_with_indices, unique, PadMode, Reduce, Slice,
+};
 
 /// Operations on float tensors.
 pub trait FloatTensorOps<B: Backend> {
@@ -315,6 +319,39 @@ pub trait FloatTensorOps<B: Backend> {
     /// The result of applying the modulus of the scalar to the tensor.
     fn float_remainder_scalar(lhs: FloatTensor<B>, rhs: FloatElem<B>) -> FloatTensor<B>;
 
+    /// Computes the floating point remainder of division between two tensors element-wise.
+    ///
+    /// Unlike [remainder](Self::float_remainder), the result has the same sign as `lhs`,
+    /// which corresponds to a division rounded towards zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The element-wise floating point remainder when dividing `lhs` by `rhs`.
+    fn float_fmod(lhs: FloatTensor<B>, rhs: FloatTensor<B>) -> FloatTensor<B> {
+        let quotient = B::float_trunc(B::float_div(lhs.clone(), rhs.clone()));
+        B::float_sub(lhs, B::float_mul(quotient, rhs))
+    }
+
+    /// Computes the floating point remainder of a tensor given a scalar.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The floating point remainder when dividing the tensor by the scalar.
+    fn float_fmod_scalar(lhs: FloatTensor<B>, rhs: FloatElem<B>) -> FloatTensor<B> {
+        let quotient = B::float_trunc(B::float_div_scalar(lhs.clone(), rhs));
+        B::float_sub(lhs, B::float_mul_scalar(quotient, rhs))
+    }
+
     /// Multiplies two tensors together using matrix multiplication.
     ///
     /// # Arguments
@@ -327,6 +364,37 @@ pub trait FloatTensorOps<B: Backend> {
     /// The result of multiplying the two tensors together using matrix multiplication.
     fn float_matmul(lhs: FloatTensor<B>, rhs: FloatTensor<B>) -> FloatTensor<B>;
 
+    /// Multiplies two complex tensors together using matrix multiplication.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs_real` - The real part of the left hand side tensor.
+    /// * `lhs_imag` - The imaginary part of the left hand side tensor.
+    /// * `rhs_real` - The real part of the right hand side tensor.
+    /// * `rhs_imag` - The imaginary part of the right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The real and imaginary parts of the result of multiplying the two complex tensors together
+    /// using matrix multiplication.
+    fn float_complex_matmul(
+        lhs_real: FloatTensor<B>,
+        lhs_imag: FloatTensor<B>,
+        rhs_real: FloatTensor<B>,
+        rhs_imag: FloatTensor<B>,
+    ) -> (FloatTensor<B>, FloatTensor<B>) {
+        let real = B::float_sub(
+            B::float_matmul(lhs_real.clone(), rhs_real.clone()),
+            B::float_matmul(lhs_imag.clone(), rhs_imag.clone()),
+        );
+        let imag = B::float_add(
+            B::float_matmul(lhs_real, rhs_imag),
+            B::float_matmul(lhs_imag, rhs_real),
+        );
+
+        (real, imag)
+    }
+
     /// Negates a tensor element-wise.
     fn float_neg(tensor: FloatTensor<B>) -> FloatTensor<B> {
         Self::float_mul_scalar(tensor, (-1.0_f32).elem::<FloatElem<B>>())
@@ -427,6 +495,29 @@ pub trait FloatTensorOps<B: Backend> {
         value: FloatTensor<B>,
     ) -> FloatTensor<B>;
 
+    /// Scatter elements into a tensor, reducing the elements scattered at the same position.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to scatter into.
+    /// * `tensor` - The tensor to scatter into.
+    /// * `indices` - The indices to scatter into.
+    /// * `value` - The value to scatter.
+    /// * `reduce` - The reduction applied to the elements at the same position.
+    /// * `include_self` - If the elements of the tensor are part of the reduction.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the scattered elements.
+    fn float_scatter_reduce(
+        dim: usize,
+        tensor: FloatTensor<B>,
+        indices: IntTensor<B>,
+        value: FloatTensor<B>,
+        reduce: Reduce,
+        include_self: bool,
+    ) -> FloatTensor<B>;
+
     /// Select tensor elements along the given dimension corresponding for the given indices.
     ///
     /// # Arguments
@@ -489,6 +580,45 @@ pub trait FloatTensorOps<B: Backend> {
         value: FloatTensor<B>,
     ) -> FloatTensor<B>;
 
+    /// Select tensor elements corresponding to the given slices, taking every `step` element of
+    /// each dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to select from.
+    /// * `slices` - The slices to select, within the bounds of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// The selected elements in a new tensor.
+    fn float_slice_with_steps(tensor: FloatTensor<B>, slices: &[Slice]) -> FloatTensor<B> {
+        slice_with_steps::<B, Float>(TensorPrimitive::Float(tensor), slices).tensor()
+    }
+
+    /// Assign the selected elements corresponding to the given slices to the given value.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to select from.
+    /// * `slices` - The slices to select, within the bounds of the tensor.
+    /// * `value` - The value to assign.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the selected elements assigned to the given value.
+    fn float_slice_assign_with_steps(
+        tensor: FloatTensor<B>,
+        slices: &[Slice],
+        value: FloatTensor<B>,
+    ) -> FloatTensor<B> {
+        slice_assign_with_steps::<B, Float>(
+            TensorPrimitive::Float(tensor),
+            slices,
+            TensorPrimitive::Float(value),
+        )
+        .tensor()
+    }
+
     /// Update the given tensor with the value tensor where the mask is true.
     ///
     /// # Arguments
@@ -982,6 +1112,281 @@ pub trait FloatTensorOps<B: Backend> {
     /// A tensor with the same shape as `tensor` with error function values.
     fn float_erf(tensor: FloatTensor<B>) -> FloatTensor<B>;
 
+    /// Returns a new tensor with arcsine values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the arcsine of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with arcsine values.
+    fn float_asin(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        // asin(x) = atan(x / sqrt(1 - x^2))
+        let cos = B::float_sqrt(B::float_add_scalar(
+            B::float_neg(B::float_mul(tensor.clone(), tensor.clone())),
+            1.elem(),
+        ));
+        special::atan::<B>(B::float_div(tensor, cos))
+    }
+
+    /// Returns a new tensor with arccosine values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the arccosine of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with arccosine values.
+    fn float_acos(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        let asin = B::float_asin(tensor);
+        B::float_add_scalar(B::float_neg(asin), core::f64::consts::FRAC_PI_2.elem())
+    }
+
+    /// Returns a new tensor with arctangent values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the arctangent of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with arctangent values.
+    fn float_atan(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::atan::<B>(tensor)
+    }
+
+    /// Returns a new tensor with the four-quadrant arctangent of `lhs / rhs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor of the y coordinates.
+    /// * `rhs` - The tensor of the x coordinates.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the inputs with angles in `[-pi, pi]`.
+    fn float_atan2(lhs: FloatTensor<B>, rhs: FloatTensor<B>) -> FloatTensor<B> {
+        special::atan2::<B>(lhs, rhs)
+    }
+
+    /// Returns a new tensor with hyperbolic sine values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the hyperbolic sine of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with hyperbolic sine values.
+    fn float_sinh(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        // sinh(|x|) = (u + u / (u + 1)) / 2 with u = exp(|x|) - 1 is accurate near zero.
+        let sign = B::float_sign(tensor.clone());
+        let u = B::float_expm1(B::float_abs(tensor));
+        let ratio = B::float_div(u.clone(), B::float_add_scalar(u.clone(), 1.elem()));
+        let output = B::float_div_scalar(B::float_add(u, ratio), 2.elem());
+        B::float_mul(output, sign)
+    }
+
+    /// Returns a new tensor with hyperbolic cosine values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the hyperbolic cosine of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with hyperbolic cosine values.
+    fn float_cosh(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        let exp = B::float_exp(B::float_abs(tensor));
+        let output = B::float_add(exp.clone(), B::float_recip(exp));
+        B::float_div_scalar(output, 2.elem())
+    }
+
+    /// Returns a new tensor with inverse hyperbolic sine values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the inverse hyperbolic sine of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with inverse hyperbolic sine values.
+    fn float_asinh(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        // asinh(|x|) = log1p(|x| + x^2 / (1 + sqrt(1 + x^2))) is accurate near zero.
+        let sign = B::float_sign(tensor.clone());
+        let abs = B::float_abs(tensor);
+        let square = B::float_mul(abs.clone(), abs.clone());
+        let root = B::float_sqrt(B::float_add_scalar(square.clone(), 1.elem()));
+        let ratio = B::float_div(square, B::float_add_scalar(root, 1.elem()));
+        let output = B::float_log1p(B::float_add(abs, ratio));
+        B::float_mul(output, sign)
+    }
+
+    /// Returns a new tensor with inverse hyperbolic cosine values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the inverse hyperbolic cosine of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with inverse hyperbolic cosine values.
+    fn float_acosh(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        // acosh(x) = log(x) + log1p(sqrt(1 - 1 / x^2)) doesn't overflow for large values.
+        let recip = B::float_recip(tensor.clone());
+        let root = B::float_sqrt(B::float_add_scalar(
+            B::float_neg(B::float_mul(recip.clone(), recip)),
+            1.elem(),
+        ));
+        B::float_add(B::float_log(tensor), B::float_log1p(root))
+    }
+
+    /// Returns a new tensor with inverse hyperbolic tangent values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the inverse hyperbolic tangent of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with inverse hyperbolic tangent values.
+    fn float_atanh(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        let positive = B::float_log1p(tensor.clone());
+        let negative = B::float_log1p(B::float_neg(tensor));
+        B::float_div_scalar(B::float_sub(positive, negative), 2.elem())
+    }
+
+    /// Returns a new tensor with `exp(x) - 1` values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to exponentiate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with `exp(x) - 1` values, accurate for
+    /// values close to zero.
+    fn float_expm1(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        // exp(x) - 1 = 2 * t / (1 - t) with t = tanh(x / 2) avoids the cancellation near zero.
+        let small = B::float_lower_elem(B::float_abs(tensor.clone()), 1.elem());
+        let t = B::float_tanh(B::float_div_scalar(tensor.clone(), 2.elem()));
+        let denominator = B::float_add_scalar(B::float_neg(t.clone()), 1.elem());
+        let approximation = B::float_div(B::float_mul_scalar(t, 2.elem()), denominator);
+        let output = B::float_sub_scalar(B::float_exp(tensor), 1.elem());
+        B::float_mask_where(output, small, approximation)
+    }
+
+    /// Returns a new tensor with the inverse error function values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the inverse error function of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with inverse error function values.
+    fn float_erfinv(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::erfinv::<B>(tensor)
+    }
+
+    /// Returns a new tensor with the natural logarithm of the absolute value of the gamma
+    /// function.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the log-gamma function of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with log-gamma values.
+    fn float_lgamma(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::lgamma::<B>(tensor)
+    }
+
+    /// Returns a new tensor with the digamma function values, the logarithmic derivative
+    /// of the gamma function.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the digamma function of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with digamma values.
+    fn float_digamma(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::digamma::<B>(tensor)
+    }
+
+    /// Returns a new tensor with the hypotenuse `sqrt(lhs^2 + rhs^2)` values.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the inputs with hypotenuse values.
+    fn float_hypot(lhs: FloatTensor<B>, rhs: FloatTensor<B>) -> FloatTensor<B> {
+        // max * sqrt(1 + (min / max)^2) avoids the overflow of the squares, equal values
+        // (including zeros and infinities) having a ratio of one.
+        let lhs = B::float_abs(lhs);
+        let rhs = B::float_abs(rhs);
+        let lhs_larger = B::float_greater(lhs.clone(), rhs.clone());
+        let max = B::float_mask_where(rhs.clone(), lhs_larger.clone(), lhs.clone());
+        let min = B::float_mask_where(lhs, lhs_larger, rhs);
+
+        let equal = B::float_equal(min.clone(), max.clone());
+        let ratio = B::float_div(min, max.clone());
+        let ratio = B::float_mask_fill(ratio, equal, 1.elem());
+        let ratio = B::float_add_scalar(B::float_mul(ratio.clone(), ratio), 1.elem());
+
+        B::float_mul(max, B::float_sqrt(ratio))
+    }
+
+    /// Returns a new tensor with truncated values, rounded towards zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to be truncated.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with truncated values.
+    fn float_trunc(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        let negative = B::float_lower_elem(tensor.clone(), 0.elem());
+        let ceil = B::float_ceil(tensor.clone());
+        B::float_mask_where(B::float_floor(tensor), negative, ceil)
+    }
+
+    /// Replaces the NaN, positive infinity and negative infinity values of a tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `nan` - The value replacing NaN.
+    /// * `posinf` - The value replacing positive infinity.
+    /// * `neginf` - The value replacing negative infinity.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with the non-finite values replaced.
+    fn float_nan_to_num(
+        tensor: FloatTensor<B>,
+        nan: FloatElem<B>,
+        posinf: FloatElem<B>,
+        neginf: FloatElem<B>,
+    ) -> FloatTensor<B> {
+        let is_nan = B::float_not_equal(tensor.clone(), tensor.clone());
+        let tensor = B::float_mask_fill(tensor, is_nan, nan);
+        let is_posinf = B::float_equal_elem(tensor.clone(), f64::INFINITY.elem());
+        let tensor = B::float_mask_fill(tensor, is_posinf, posinf);
+        let is_neginf = B::float_equal_elem(tensor.clone(), f64::NEG_INFINITY.elem());
+        B::float_mask_fill(tensor, is_neginf, neginf)
+    }
+
     /// Concatenates tensors along a dimension.
     ///
     /// # Arguments
@@ -1306,6 +1711,25 @@ pub trait FloatTensorOps<B: Backend> {
     /// Broadcasts the float `tensor` to the given `shape`.
     fn float_expand(tensor: FloatTensor<B>, shape: Shape) -> FloatTensor<B>;
 
+    /// Pads the last dimensions of the float `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to pad.
+    /// * `padding` - The padding before and after each of the last `padding.len()` dimensions.
+    /// * `mode` - The padding mode.
+    ///
+    /// # Returns
+    ///
+    /// The padded tensor.
+    fn float_pad(
+        tensor: FloatTensor<B>,
+        padding: &[(usize, usize)],
+        mode: PadMode,
+    ) -> FloatTensor<B> {
+        pad::<B, Float>(TensorPrimitive::Float(tensor), padding, mode).tensor()
+    }
+
     /// Sort the elements of the input `tensor` by value in along a given dimension.
     ///
     /// This sort is unstable (i.e., may reorder equal elements).
@@ -1363,4 +1787,95 @@ pub trait FloatTensorOps<B: Backend> {
     fn float_argsort(tensor: FloatTensor<B>, dim: usize, descending: bool) -> IntTensor<B> {
         argsort::<B, Float>(TensorPrimitive::Float(tensor), dim, descending)
     }
+
+    /// Returns the sorted unique elements of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the unique elements as a 1D tensor, the index of each element of the tensor in
+    /// the unique elements with the shape of the input tensor, and the number of occurrences of
+    /// each unique element as a 1D tensor.
+    fn float_unique(
+        tensor: FloatTensor<B>,
+    ) -> impl Future<Output = (FloatTensor<B>, IntTensor<B>, IntTensor<B>)> + 'static + Send {
+        async move {
+            // The number of unique elements is variable, so the data has to be read.
+            let (values, inverse, counts) =
+                unique::<B, Float>(TensorPrimitive::Float(tensor)).await;
+            (values.tensor(), inverse, counts)
+        }
+    }
+
+    /// Finds the indices where the values should be inserted in the sorted sequence to keep it
+    /// sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted` - The sequences sorted along the last dimension, of shape `[..., M]`.
+    /// * `values` - The values to search, of shape `[..., N]` with the same leading dimensions.
+    /// * `right` - If the index after the elements equal to the value is returned instead of the
+    ///   index before them.
+    ///
+    /// # Returns
+    ///
+    /// The insertion indices with the shape of the values.
+    fn float_searchsorted(
+        sorted: FloatTensor<B>,
+        values: FloatTensor<B>,
+        right: bool,
+    ) -> IntTensor<B> {
+        // Counts the elements of the sequence before each value.
+        let shape = values.shape();
+        let [shape_sorted, shape_values, dims] = searchsorted_shapes(&sorted.shape(), &shape);
+        let rank = shape.num_dims();
+
+        let sorted = B::float_expand(B::float_reshape(sorted, shape_sorted), dims.clone());
+        let values = B::float_expand(B::float_reshape(values, shape_values), dims);
+        let before = match right {
+            true => B::float_lower_equal(sorted, values),
+            false => B::float_lower(sorted, values),
+        };
+
+        B::int_reshape(B::int_sum_dim(B::bool_into_int(before), rank), shape)
+    }
+
+    /// Computes the histogram of the tensor, with bins of equal width between `min` and `max`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `bins` - The number of bins.
+    /// * `min` - The lower bound of the first bin.
+    /// * `max` - The upper bound of the last bin, which is greater than `min`.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements in each bin as a 1D tensor, the elements outside of the bounds being
+    /// ignored.
+    fn float_histc(tensor: FloatTensor<B>, bins: usize, min: f64, max: f64) -> FloatTensor<B> {
+        let device = B::float_device(&tensor);
+        let num_elements = tensor.shape().num_elements();
+        let tensor = B::float_reshape(tensor, Shape::new([num_elements]));
+
+        let inside = B::bool_and(
+            B::float_greater_equal_elem(tensor.clone(), min.elem()),
+            B::float_lower_equal_elem(tensor.clone(), max.elem()),
+        );
+        let scale = bins as f64 / (max - min);
+        let index = B::float_mul_scalar(B::float_sub_scalar(tensor, min.elem()), scale.elem());
+        let index = B::float_into_int(B::float_floor(index));
+        // The elements equal to the upper bound are part of the last bin.
+        let index = B::int_clamp(index, 0.elem(), (bins as i64 - 1).elem());
+
+        B::float_scatter(
+            0,
+            B::float_zeros(Shape::new([bins]), &device),
+            index,
+            B::bool_into_float(inside),
+        )
+    }
 }
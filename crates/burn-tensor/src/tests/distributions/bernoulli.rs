@@ -0,0 +1,61 @@
+#[burn_tensor_testgen::testgen(bernoulli_distribution)]
+mod tests {
+    use super::*;
+    use burn_tensor::distributions::{Bernoulli, Distribution, KlDivergence};
+    use burn_tensor::{cast::ToElement, TensorData};
+    use core::f32::consts::LN_2;
+
+    fn distribution() -> Bernoulli<TestBackend, 1> {
+        Bernoulli::from_probs(TestTensor::from_floats(
+            [0.2, 0.5, 0.9],
+            &Default::default(),
+        ))
+    }
+
+    #[test]
+    fn should_compute_log_prob() {
+        let value = TestTensor::from_floats([1.0, 0.0, 1.0], &Default::default());
+
+        let output = distribution().log_prob(value);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-1.6094379, -LN_2, -0.10536052]), 4);
+    }
+
+    #[test]
+    fn should_compute_entropy() {
+        let output = distribution().entropy();
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.50040242, LN_2, 0.32508297]), 4);
+    }
+
+    #[test]
+    fn should_compute_kl_divergence() {
+        let other = Bernoulli::from_logits(
+            TestTensor::from_floats([0.5, 0.3, 0.1], &Default::default())
+                .log()
+                .sub(TestTensor::from_floats([0.5, 0.7, 0.9], &Default::default()).log()),
+        );
+
+        let output = distribution().kl_divergence(&other);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.19274476, 0.087176694, 1.7577797]), 4);
+    }
+
+    #[test]
+    fn should_sample_with_the_given_probability() {
+        let distribution =
+            Bernoulli::from_probs(TestTensor::<1>::full([10_000], 0.3, &Default::default()));
+
+        let samples = distribution.sample();
+        let mean = samples.clone().mean().into_scalar().to_f32();
+
+        samples.into_data().assert_within_range_inclusive(0.0..=1.0);
+        assert!((mean - 0.3).abs() < 0.03, "mean {mean}");
+    }
+}
@@ -0,0 +1,66 @@
+#[burn_tensor_testgen::testgen(beta_distribution)]
+mod tests {
+    use super::*;
+    use burn_tensor::distributions::{Beta, Distribution, KlDivergence};
+    use burn_tensor::{cast::ToElement, TensorData};
+
+    fn distribution() -> Beta<TestBackend, 1> {
+        let device = Default::default();
+
+        Beta::new(
+            TestTensor::from_floats([0.5, 2.0, 3.0], &device),
+            TestTensor::from_floats([0.5, 5.0, 1.0], &device),
+        )
+    }
+
+    #[test]
+    fn should_compute_log_prob() {
+        let value = TestTensor::from_floats([0.2, 0.3, 0.9], &Default::default());
+
+        let output = distribution().log_prob(value);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-0.22843915, 0.7705248, 0.88789126]), 4);
+    }
+
+    #[test]
+    fn should_compute_entropy() {
+        let output = distribution().entropy();
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([-0.24156448, -0.48453071, -0.43194562]),
+            4,
+        );
+    }
+
+    #[test]
+    fn should_compute_kl_divergence() {
+        let device = Default::default();
+        let other = Beta::new(
+            TestTensor::from_floats([1.0, 1.0, 1.0], &device),
+            TestTensor::from_floats([1.0, 2.0, 3.0], &device),
+        );
+
+        let output = distribution().kl_divergence(&other);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.24156448, 0.1580502, 3.0]), 4);
+    }
+
+    #[test]
+    fn should_sample_with_the_given_mean() {
+        let device = Default::default();
+        let distribution = Beta::new(
+            TestTensor::<1>::full([10_000], 2.0, &device),
+            TestTensor::full([10_000], 6.0, &device),
+        );
+
+        let samples = distribution.sample();
+        let mean = samples.clone().mean().into_scalar().to_f32();
+
+        samples.into_data().assert_within_range_inclusive(0.0..=1.0);
+        assert!((mean - 0.25).abs() < 0.02, "mean {mean}");
+    }
+}
@@ -0,0 +1,69 @@
+#[burn_tensor_testgen::testgen(categorical_distribution)]
+mod tests {
+    use super::*;
+    use burn_tensor::distributions::{Categorical, Distribution, KlDivergence};
+    use burn_tensor::{cast::ToElement, TensorData};
+    use core::f32::consts::LN_2;
+
+    fn distribution() -> Categorical<TestBackend, 2> {
+        Categorical::from_probs(TestTensor::from_floats(
+            [[0.1, 0.2, 0.7], [0.5, 0.5, 0.0]],
+            &Default::default(),
+        ))
+    }
+
+    #[test]
+    fn should_compute_log_prob() {
+        let value = TestTensorInt::from_ints([[2], [0]], &Default::default());
+
+        let output = distribution().log_prob(value);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[-0.35667494], [-LN_2]]), 4);
+    }
+
+    #[test]
+    fn should_compute_entropy_with_impossible_categories() {
+        let output = distribution().entropy();
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.80181855], [LN_2]]), 4);
+    }
+
+    #[test]
+    fn should_compute_kl_divergence() {
+        let other = Categorical::from_logits(
+            TestTensor::from_floats([[1.0, 1.0, 1.0], [0.25, 0.25, 0.5]], &Default::default())
+                .log(),
+        );
+
+        let output = distribution().kl_divergence(&other);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.29679374], [LN_2]]), 4);
+    }
+
+    #[test]
+    fn should_sample_with_the_given_probabilities() {
+        let distribution = Categorical::from_probs(
+            TestTensor::<2>::from_floats([[0.2, 0.0, 0.8]], &Default::default())
+                .repeat_dim(0, 10_000),
+        );
+
+        let samples = distribution.sample();
+        let frequency = samples
+            .clone()
+            .equal_elem(2)
+            .float()
+            .mean()
+            .into_scalar()
+            .to_f32();
+
+        assert_eq!(samples.dims(), [10_000, 1]);
+        assert!(!samples.equal_elem(1).any().into_scalar().to_bool());
+        assert!((frequency - 0.8).abs() < 0.03, "frequency {frequency}");
+    }
+}
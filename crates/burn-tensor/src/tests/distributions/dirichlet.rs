@@ -0,0 +1,65 @@
+#[burn_tensor_testgen::testgen(dirichlet_distribution)]
+mod tests {
+    use super::*;
+    use burn_tensor::distributions::{Dirichlet, Distribution, KlDivergence};
+    use burn_tensor::TensorData;
+
+    fn distribution() -> Dirichlet<TestBackend, 2> {
+        Dirichlet::new(TestTensor::from_floats(
+            [[1.0, 2.0, 3.0], [0.5, 0.5, 0.5]],
+            &Default::default(),
+        ))
+    }
+
+    #[test]
+    fn should_compute_log_prob() {
+        let value =
+            TestTensor::from_floats([[0.2, 0.3, 0.5], [0.1, 0.6, 0.3]], &Default::default());
+
+        let output = distribution().log_prob(value);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.5040774], [0.17081469]]), 3);
+    }
+
+    #[test]
+    fn should_compute_entropy() {
+        let output = distribution().entropy();
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[-1.2443446], [-1.1621229]]), 3);
+    }
+
+    #[test]
+    fn should_compute_kl_divergence() {
+        let other = Dirichlet::new(TestTensor::from_floats(
+            [[1.0, 1.0, 1.0], [2.0, 1.0, 0.5]],
+            &Default::default(),
+        ));
+
+        let output = distribution().kl_divergence(&other);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.55119738], [1.5335143]]), 3);
+    }
+
+    #[test]
+    fn should_sample_probability_vectors() {
+        let distribution = Dirichlet::new(
+            TestTensor::<2>::from_floats([[1.0, 2.0, 5.0]], &Default::default())
+                .repeat_dim(0, 5_000),
+        );
+
+        let samples = distribution.sample();
+        let sums = samples.clone().sum_dim(1);
+        let mean = samples.mean_dim(0);
+
+        sums.into_data()
+            .assert_approx_eq(&TensorData::from([[1.0]; 5_000]), 4);
+        mean.into_data()
+            .assert_approx_eq_diff(&TensorData::from([[0.125, 0.25, 0.625]]), 0.02);
+    }
+}
@@ -0,0 +1,71 @@
+#[burn_tensor_testgen::testgen(gamma_distribution)]
+mod tests {
+    use super::*;
+    use burn_tensor::distributions::{Distribution, Gamma, KlDivergence};
+    use burn_tensor::{cast::ToElement, TensorData};
+
+    fn distribution() -> Gamma<TestBackend, 1> {
+        let device = Default::default();
+
+        Gamma::new(
+            TestTensor::from_floats([0.5, 2.0, 3.0], &device),
+            TestTensor::from_floats([1.0, 0.5, 2.0], &device),
+        )
+    }
+
+    #[test]
+    fn should_compute_log_prob() {
+        let value = TestTensor::from_floats([0.3, 2.0, 1.5], &Default::default());
+
+        let output = distribution().log_prob(value);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-0.27037854, -1.6931472, -0.80277542]), 4);
+    }
+
+    #[test]
+    fn should_compute_entropy() {
+        let output = distribution().entropy();
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.09060993, 2.2703628, 1.1544313]), 4);
+    }
+
+    #[test]
+    fn should_compute_kl_divergence() {
+        let device = Default::default();
+        let other = Gamma::new(
+            TestTensor::from_floats([1.0, 1.0, 2.0], &device),
+            TestTensor::from_floats([1.0, 1.0, 1.0], &device),
+        );
+
+        let output = distribution().kl_divergence(&other);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.40939007, 1.7296372, 0.11593152]), 4);
+    }
+
+    #[test]
+    fn should_sample_with_the_given_mean() {
+        let device = Default::default();
+
+        for (concentration, rate) in [(0.5, 1.0), (4.0, 2.0)] {
+            let distribution = Gamma::new(
+                TestTensor::<1>::full([10_000], concentration, &device),
+                TestTensor::full([10_000], rate, &device),
+            );
+
+            let samples = distribution.sample();
+            let mean = samples.clone().mean().into_scalar().to_f32();
+            let expected = (concentration / rate) as f32;
+
+            samples
+                .into_data()
+                .assert_within_range_inclusive(0.0..=f32::MAX);
+            assert!((mean - expected).abs() < 0.05, "mean {mean}");
+        }
+    }
+}
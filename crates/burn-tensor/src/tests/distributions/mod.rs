@@ -0,0 +1,8 @@
+mod bernoulli;
+mod beta;
+mod categorical;
+mod dirichlet;
+mod gamma;
+mod multivariate_normal;
+mod normal;
+mod poisson;
@@ -0,0 +1,93 @@
+#[burn_tensor_testgen::testgen(multivariate_normal_distribution)]
+mod tests {
+    use super::*;
+    use burn_tensor::distributions::{
+        Distribution, KlDivergence, MultivariateNormal, Reparameterized,
+    };
+    use burn_tensor::TensorData;
+
+    fn distribution() -> MultivariateNormal<TestBackend> {
+        let device = Default::default();
+
+        MultivariateNormal::new(
+            TestTensor::from_floats([[0.0, 0.0], [1.0, -1.0]], &device),
+            TestTensor::from_floats(
+                [[[1.0, 0.0], [0.0, 1.0]], [[2.0, 0.0], [1.0, 1.0]]],
+                &device,
+            ),
+        )
+    }
+
+    #[test]
+    fn should_compute_cholesky_factor_from_covariance() {
+        let device = Default::default();
+        let distribution = MultivariateNormal::from_covariance(
+            TestTensor::<2>::zeros([1, 3], &device),
+            TestTensor::from_floats(
+                [[[4.0, 2.0, -2.0], [2.0, 2.0, 0.0], [-2.0, 0.0, 6.0]]],
+                &device,
+            ),
+        );
+
+        distribution.scale_tril.into_data().assert_approx_eq(
+            &TensorData::from([[[2.0, 0.0, 0.0], [1.0, 1.0, 0.0], [-1.0, 1.0, 2.0]]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn should_compute_log_prob() {
+        let value = TestTensor::from_floats([[1.0, 1.0], [0.0, 0.0]], &Default::default());
+
+        let output = distribution().log_prob(value);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[-2.8378771], [-3.7810242]]), 4);
+    }
+
+    #[test]
+    fn should_compute_entropy() {
+        let output = distribution().entropy();
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[2.8378771], [3.5310242]]), 4);
+    }
+
+    #[test]
+    fn should_compute_kl_divergence() {
+        let device = Default::default();
+        let other = MultivariateNormal::new(
+            TestTensor::zeros([2, 2], &device),
+            TestTensor::<2>::eye(2, &device)
+                .unsqueeze::<3>()
+                .repeat_dim(0, 2),
+        );
+
+        let output = distribution().kl_divergence(&other);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.0], [2.3068528]]), 4);
+    }
+
+    #[test]
+    fn should_sample_with_the_given_covariance() {
+        let device = Default::default();
+        let distribution = MultivariateNormal::new(
+            TestTensor::<2>::from_floats([[1.0, -1.0]], &device).repeat_dim(0, 10_000),
+            TestTensor::<3>::from_floats([[[2.0, 0.0], [1.0, 1.0]]], &device).repeat_dim(0, 10_000),
+        );
+
+        let samples = distribution.rsample();
+        let mean = samples.clone().mean_dim(0);
+        let covariance = samples.transpose().cov(1, 1);
+
+        mean.into_data()
+            .assert_approx_eq_diff(&TensorData::from([[1.0, -1.0]]), 0.1);
+        covariance
+            .into_data()
+            .assert_approx_eq_diff(&TensorData::from([[4.0, 2.0], [2.0, 2.0]]), 0.3);
+    }
+}
@@ -0,0 +1,66 @@
+#[burn_tensor_testgen::testgen(normal_distribution)]
+mod tests {
+    use super::*;
+    use burn_tensor::distributions::{Distribution, KlDivergence, Normal, Reparameterized};
+    use burn_tensor::{cast::ToElement, TensorData};
+
+    fn distribution() -> Normal<TestBackend, 1> {
+        let device = Default::default();
+
+        Normal::new(
+            TestTensor::from_floats([0.0, 1.0, -2.0], &device),
+            TestTensor::from_floats([1.0, 2.0, 0.5], &device),
+        )
+    }
+
+    #[test]
+    fn should_compute_log_prob() {
+        let value = TestTensor::from_floats([0.5, -1.0, -2.5], &Default::default());
+
+        let output = distribution().log_prob(value);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-1.0439385, -2.1120857, -0.72579135]), 4);
+    }
+
+    #[test]
+    fn should_compute_entropy() {
+        let output = distribution().entropy();
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.4189385, 2.1120857, 0.72579135]), 4);
+    }
+
+    #[test]
+    fn should_compute_kl_divergence() {
+        let device = Default::default();
+        let other = Normal::new(
+            TestTensor::from_floats([1.0, 0.0, 0.0], &device),
+            TestTensor::from_floats([1.0, 1.0, 2.0], &device),
+        );
+
+        let output = distribution().kl_divergence(&other);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.5, 1.3068528, 1.4175444]), 4);
+    }
+
+    #[test]
+    fn should_sample_with_the_given_moments() {
+        let device = Default::default();
+        let distribution = Normal::new(
+            TestTensor::<1>::full([10_000], 3.0, &device),
+            TestTensor::full([10_000], 2.0, &device),
+        );
+
+        let samples = distribution.rsample();
+        let mean = samples.clone().mean().into_scalar().to_f32();
+        let std = samples.var(0).sqrt().into_scalar().to_f32();
+
+        assert!((mean - 3.0).abs() < 0.1, "mean {mean}");
+        assert!((std - 2.0).abs() < 0.1, "std {std}");
+    }
+}
@@ -0,0 +1,79 @@
+#[burn_tensor_testgen::testgen(poisson_distribution)]
+mod tests {
+    use super::*;
+    use burn_tensor::distributions::{Distribution, KlDivergence, Poisson};
+    use burn_tensor::{cast::ToElement, TensorData};
+
+    fn distribution() -> Poisson<TestBackend, 1> {
+        Poisson::new(TestTensor::from_floats(
+            [0.5, 3.0, 25.0],
+            &Default::default(),
+        ))
+    }
+
+    #[test]
+    fn should_compute_log_prob() {
+        let value = TestTensor::from_floats([0.0, 2.0, 30.0], &Default::default());
+
+        let output = distribution().log_prob(value);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-0.5, -1.4959226, -3.0919616]), 3);
+    }
+
+    #[test]
+    fn should_compute_entropy() {
+        let output = distribution().entropy();
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.92763747, 1.9314702, 3.0249727]), 3);
+    }
+
+    #[test]
+    fn should_compute_entropy_of_zero_rate() {
+        let distribution = Poisson::new(TestTensor::<1>::zeros([1], &Default::default()));
+
+        let output = distribution.entropy();
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.0]), 4);
+    }
+
+    #[test]
+    fn should_compute_kl_divergence() {
+        let other = Poisson::new(TestTensor::from_floats(
+            [1.0, 1.0, 20.0],
+            &Default::default(),
+        ));
+
+        let output = distribution().kl_divergence(&other);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.15342641, 1.2958369, 0.57858878]), 3);
+    }
+
+    #[test]
+    fn should_sample_with_the_given_mean() {
+        for rate in [3.0, 40.0] {
+            let distribution =
+                Poisson::new(TestTensor::<1>::full([10_000], rate, &Default::default()));
+
+            let samples = distribution.sample();
+            let mean = samples.clone().mean().into_scalar().to_f32();
+
+            samples
+                .clone()
+                .round()
+                .into_data()
+                .assert_eq(&samples.into_data(), true);
+            assert!(
+                (mean - rate as f32).abs() < 0.01 * rate as f32 + 0.05,
+                "mean {mean}"
+            );
+        }
+    }
+}
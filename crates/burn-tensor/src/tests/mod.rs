@@ -1,5 +1,6 @@
 mod activation;
 mod clone_invariance;
+mod distributions;
 mod module;
 mod ops;
 mod primitive;
@@ -202,15 +203,28 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_module_conv_transpose2d!();
         burn_tensor::testgen_module_conv_transpose3d!();
         burn_tensor::testgen_module_unfold4d!();
+        burn_tensor::testgen_module_fold4d!();
+        burn_tensor::testgen_module_pixel_shuffle!();
         burn_tensor::testgen_module_max_pool1d!();
         burn_tensor::testgen_module_max_pool2d!();
         burn_tensor::testgen_module_avg_pool1d!();
         burn_tensor::testgen_module_avg_pool2d!();
         burn_tensor::testgen_module_adaptive_avg_pool1d!();
         burn_tensor::testgen_module_adaptive_avg_pool2d!();
+        burn_tensor::testgen_module_max_pool3d!();
+        burn_tensor::testgen_module_avg_pool3d!();
+        burn_tensor::testgen_module_adaptive_avg_pool3d!();
+        burn_tensor::testgen_module_adaptive_max_pool1d!();
+        burn_tensor::testgen_module_adaptive_max_pool2d!();
+        burn_tensor::testgen_module_max_unpool2d!();
         burn_tensor::testgen_module_nearest_interpolate!();
         burn_tensor::testgen_module_bilinear_interpolate!();
         burn_tensor::testgen_module_bicubic_interpolate!();
+        burn_tensor::testgen_module_interpolate3d!();
+        burn_tensor::testgen_module_grid_sample!();
+        burn_tensor::testgen_module_linear_recurrence!();
+        burn_tensor::testgen_module_ctc_loss!();
+        burn_tensor::testgen_module_grouped_matmul!();
 
         // test ops
         burn_tensor::testgen_gather_scatter!();
@@ -228,6 +242,31 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_cos!();
         burn_tensor::testgen_create_like!();
         burn_tensor::testgen_div!();
+        burn_tensor::testgen_einsum!();
+        burn_tensor::testgen_scatter_reduce!();
+        burn_tensor::testgen_roll!();
+        burn_tensor::testgen_diagonal!();
+        burn_tensor::testgen_take!();
+        burn_tensor::testgen_unique!();
+        burn_tensor::testgen_sparse!();
+        burn_tensor::testgen_complex!();
+        burn_tensor::testgen_acos!();
+        burn_tensor::testgen_acosh!();
+        burn_tensor::testgen_asin!();
+        burn_tensor::testgen_asinh!();
+        burn_tensor::testgen_atan!();
+        burn_tensor::testgen_atan2!();
+        burn_tensor::testgen_atanh!();
+        burn_tensor::testgen_cosh!();
+        burn_tensor::testgen_digamma!();
+        burn_tensor::testgen_erfinv!();
+        burn_tensor::testgen_expm1!();
+        burn_tensor::testgen_fmod!();
+        burn_tensor::testgen_hypot!();
+        burn_tensor::testgen_lgamma!();
+        burn_tensor::testgen_nan_to_num!();
+        burn_tensor::testgen_sinh!();
+        burn_tensor::testgen_trunc!();
         burn_tensor::testgen_erf!();
         burn_tensor::testgen_exp!();
         burn_tensor::testgen_flatten!();
@@ -288,6 +327,16 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_cov!();
         burn_tensor::testgen_eye!();
 
+        // test distributions
+        burn_tensor::testgen_bernoulli_distribution!();
+        burn_tensor::testgen_beta_distribution!();
+        burn_tensor::testgen_categorical_distribution!();
+        burn_tensor::testgen_dirichlet_distribution!();
+        burn_tensor::testgen_gamma_distribution!();
+        burn_tensor::testgen_multivariate_normal_distribution!();
+        burn_tensor::testgen_normal_distribution!();
+        burn_tensor::testgen_poisson_distribution!();
+
         // test padding
         burn_tensor::testgen_padding!();
     };
@@ -0,0 +1,80 @@
+#[burn_tensor_testgen::testgen(module_adaptive_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::adaptive_avg_pool3d;
+
+    fn input() -> TestTensor<5> {
+        TestTensor::from([[
+            [
+                [
+                    [0.0, 14.0, 5.0, 19.0],
+                    [10.0, 1.0, 15.0, 6.0],
+                    [20.0, 11.0, 2.0, 16.0],
+                    [7.0, 21.0, 12.0, 3.0],
+                ],
+                [
+                    [17.0, 8.0, 22.0, 13.0],
+                    [4.0, 18.0, 9.0, 0.0],
+                    [14.0, 5.0, 19.0, 10.0],
+                    [1.0, 15.0, 6.0, 20.0],
+                ],
+                [
+                    [11.0, 2.0, 16.0, 7.0],
+                    [21.0, 12.0, 3.0, 17.0],
+                    [8.0, 22.0, 13.0, 4.0],
+                    [18.0, 9.0, 0.0, 14.0],
+                ],
+            ],
+            [
+                [
+                    [5.0, 19.0, 10.0, 1.0],
+                    [15.0, 6.0, 20.0, 11.0],
+                    [2.0, 16.0, 7.0, 21.0],
+                    [12.0, 3.0, 17.0, 8.0],
+                ],
+                [
+                    [22.0, 13.0, 4.0, 18.0],
+                    [9.0, 0.0, 14.0, 5.0],
+                    [19.0, 10.0, 1.0, 15.0],
+                    [6.0, 20.0, 11.0, 2.0],
+                ],
+                [
+                    [16.0, 7.0, 21.0, 12.0],
+                    [3.0, 17.0, 8.0, 22.0],
+                    [13.0, 4.0, 18.0, 9.0],
+                    [0.0, 14.0, 5.0, 19.0],
+                ],
+            ],
+        ]])
+    }
+
+    #[test]
+    fn test_adaptive_avg_pool3d() {
+        let y = TestTensor::<5>::from([[
+            [
+                [[9.0, 11.125], [10.375, 9.625], [11.75, 11.0]],
+                [[11.625, 10.875], [13.0, 9.375], [11.5, 10.75]],
+            ],
+            [
+                [[11.125, 10.375], [9.625, 11.75], [11.0, 10.25]],
+                [[10.875, 13.0], [9.375, 11.5], [10.75, 10.0]],
+            ],
+        ]]);
+
+        let output = adaptive_avg_pool3d(input(), [2, 3, 2]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_adaptive_avg_pool3d_global() {
+        let x = TestTensor::<5>::ones([2, 3, 4, 5, 6], &Default::default());
+
+        let output = adaptive_avg_pool3d(x, [1, 1, 1]);
+
+        output.into_data().assert_approx_eq(
+            &TestTensor::<5>::ones([2, 3, 1, 1, 1], &Default::default()).into_data(),
+            3,
+        );
+    }
+}
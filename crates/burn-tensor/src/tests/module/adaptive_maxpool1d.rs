@@ -0,0 +1,34 @@
+#[burn_tensor_testgen::testgen(module_adaptive_max_pool1d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{adaptive_max_pool1d, adaptive_max_pool1d_with_indices};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_adaptive_max_pool1d() {
+        let x = TestTensor::from([[
+            [0.0, 14.0, 5.0, 19.0, 10.0, 1.0, 15.0],
+            [6.0, 20.0, 11.0, 2.0, 16.0, 7.0, 21.0],
+        ]]);
+        let y = TestTensor::<3>::from([[[14.0, 19.0, 15.0], [20.0, 16.0, 21.0]]]);
+
+        let output = adaptive_max_pool1d(x, 3);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_adaptive_max_pool1d_with_indices() {
+        let x = TestTensor::from([[
+            [0.0, 14.0, 5.0, 19.0, 10.0, 1.0, 15.0],
+            [6.0, 20.0, 11.0, 2.0, 16.0, 7.0, 21.0],
+        ]]);
+        let y = TestTensor::<3>::from([[[14.0, 19.0, 15.0], [20.0, 16.0, 21.0]]]);
+        let indices_expected = TensorData::from([[[1, 3, 6], [1, 4, 6]]]);
+
+        let (output, indices) = adaptive_max_pool1d_with_indices(x, 3);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        indices.into_data().assert_eq(&indices_expected, false);
+    }
+}
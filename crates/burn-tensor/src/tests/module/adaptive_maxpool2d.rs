@@ -0,0 +1,80 @@
+#[burn_tensor_testgen::testgen(module_adaptive_max_pool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{adaptive_max_pool2d, adaptive_max_pool2d_with_indices};
+    use burn_tensor::TensorData;
+
+    fn input() -> TestTensor<4> {
+        TestTensor::from([[
+            [
+                [0.0, 14.0, 5.0, 19.0, 10.0, 1.0, 15.0],
+                [6.0, 20.0, 11.0, 2.0, 16.0, 7.0, 21.0],
+                [12.0, 3.0, 17.0, 8.0, 22.0, 13.0, 4.0],
+                [18.0, 9.0, 0.0, 14.0, 5.0, 19.0, 10.0],
+                [1.0, 15.0, 6.0, 20.0, 11.0, 2.0, 16.0],
+            ],
+            [
+                [7.0, 21.0, 12.0, 3.0, 17.0, 8.0, 22.0],
+                [13.0, 4.0, 18.0, 9.0, 0.0, 14.0, 5.0],
+                [19.0, 10.0, 1.0, 15.0, 6.0, 20.0, 11.0],
+                [2.0, 16.0, 7.0, 21.0, 12.0, 3.0, 17.0],
+                [8.0, 22.0, 13.0, 4.0, 18.0, 9.0, 0.0],
+            ],
+        ]])
+    }
+
+    #[test]
+    fn test_adaptive_max_pool2d() {
+        let y = TestTensor::<4>::from([[
+            [
+                [20.0, 20.0, 19.0, 21.0],
+                [20.0, 20.0, 22.0, 21.0],
+                [18.0, 20.0, 20.0, 19.0],
+            ],
+            [
+                [21.0, 21.0, 17.0, 22.0],
+                [19.0, 21.0, 21.0, 20.0],
+                [22.0, 22.0, 21.0, 17.0],
+            ],
+        ]]);
+
+        let output = adaptive_max_pool2d(input(), [3, 4]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_adaptive_max_pool2d_with_indices() {
+        let (output, indices) = adaptive_max_pool2d_with_indices(input(), [3, 4]);
+
+        let y = TestTensor::<4>::from([[
+            [
+                [20.0, 20.0, 19.0, 21.0],
+                [20.0, 20.0, 22.0, 21.0],
+                [18.0, 20.0, 20.0, 19.0],
+            ],
+            [
+                [21.0, 21.0, 17.0, 22.0],
+                [19.0, 21.0, 21.0, 20.0],
+                [22.0, 22.0, 21.0, 17.0],
+            ],
+        ]]);
+        let indices_expected = TensorData::from([[
+            [[8, 8, 3, 13], [8, 8, 18, 13], [21, 31, 31, 26]],
+            [[1, 1, 4, 6], [14, 24, 24, 19], [29, 29, 24, 27]],
+        ]]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        indices.into_data().assert_eq(&indices_expected, false);
+    }
+
+    #[test]
+    fn test_adaptive_max_pool2d_upsample() {
+        let x = TestTensor::from([[[[1.0, 2.0], [3.0, 4.0]]]]);
+        let y = TestTensor::<4>::from([[[[1.0, 2.0, 2.0], [3.0, 4.0, 4.0], [3.0, 4.0, 4.0]]]]);
+
+        let output = adaptive_max_pool2d(x, [3, 3]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+}
@@ -0,0 +1,94 @@
+#[burn_tensor_testgen::testgen(module_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::avg_pool3d;
+
+    fn input() -> TestTensor<5> {
+        TestTensor::from([[
+            [
+                [
+                    [0.0, 14.0, 5.0, 19.0],
+                    [10.0, 1.0, 15.0, 6.0],
+                    [20.0, 11.0, 2.0, 16.0],
+                    [7.0, 21.0, 12.0, 3.0],
+                ],
+                [
+                    [17.0, 8.0, 22.0, 13.0],
+                    [4.0, 18.0, 9.0, 0.0],
+                    [14.0, 5.0, 19.0, 10.0],
+                    [1.0, 15.0, 6.0, 20.0],
+                ],
+                [
+                    [11.0, 2.0, 16.0, 7.0],
+                    [21.0, 12.0, 3.0, 17.0],
+                    [8.0, 22.0, 13.0, 4.0],
+                    [18.0, 9.0, 0.0, 14.0],
+                ],
+            ],
+            [
+                [
+                    [5.0, 19.0, 10.0, 1.0],
+                    [15.0, 6.0, 20.0, 11.0],
+                    [2.0, 16.0, 7.0, 21.0],
+                    [12.0, 3.0, 17.0, 8.0],
+                ],
+                [
+                    [22.0, 13.0, 4.0, 18.0],
+                    [9.0, 0.0, 14.0, 5.0],
+                    [19.0, 10.0, 1.0, 15.0],
+                    [6.0, 20.0, 11.0, 2.0],
+                ],
+                [
+                    [16.0, 7.0, 21.0, 12.0],
+                    [3.0, 17.0, 8.0, 22.0],
+                    [13.0, 4.0, 18.0, 9.0],
+                    [0.0, 14.0, 5.0, 19.0],
+                ],
+            ],
+        ]])
+    }
+
+    #[test]
+    fn test_avg_pool3d_count_include_pad() {
+        let y = TestTensor::<5>::from([[
+            [
+                [[1.3889, 3.3333], [3.8889, 4.8333]],
+                [[4.0, 7.2222], [7.0556, 10.5]],
+                [[5.1667, 7.0556], [8.1667, 10.8889]],
+                [[2.5556, 3.1667], [5.0, 5.2222]],
+            ],
+            [
+                [[2.5, 3.7222], [3.0, 6.0556]],
+                [[4.9444, 6.7222], [6.5556, 10.3889]],
+                [[4.8333, 7.8333], [6.3889, 10.7778]],
+                [[2.3889, 4.8333], [2.8333, 6.4444]],
+            ],
+        ]]);
+
+        let output = avg_pool3d(input(), [2, 3, 3], [1, 2, 2], [1, 1, 1], true);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_avg_pool3d_count_exclude_pad() {
+        let y = TestTensor::<5>::from([[
+            [
+                [[6.25, 10.0], [11.6667, 9.6667]],
+                [[9.0, 10.8333], [10.5833, 10.5]],
+                [[11.625, 10.5833], [12.25, 10.8889]],
+                [[11.5, 9.5], [15.0, 10.4444]],
+            ],
+            [
+                [[11.25, 11.1667], [9.0, 12.1111]],
+                [[11.125, 10.0833], [9.8333, 10.3889]],
+                [[10.875, 11.75], [9.5833, 10.7778]],
+                [[10.75, 14.5], [8.5, 12.8889]],
+            ],
+        ]]);
+
+        let output = avg_pool3d(input(), [2, 3, 3], [1, 2, 2], [1, 1, 1], false);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+}
@@ -125,6 +125,24 @@ mod tests {
         .to_data()
         .assert_approx_eq(&output.into_data(), 3);
     }
+    #[test]
+    fn test_upsample_interpolation_half_pixel() {
+        let x = TestTensor::<4>::from([[[[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]]]);
+        let y = TestTensor::<4>::from([[[
+            [-0.3564, 0.0556, 0.7396, 1.4236, 1.8356],
+            [1.404, 1.816, 2.5, 3.184, 3.596],
+            [3.1644, 3.5764, 4.2604, 4.9444, 5.3564],
+        ]]]);
+
+        let output = interpolate(
+            x,
+            [3, 5],
+            InterpolateOptions::new(InterpolateMode::Bicubic).with_align_corners(false),
+        );
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
     struct InterpolateTestCase {
         batch_size: usize,
         channels: usize,
@@ -133,6 +133,24 @@ mod tests {
         .assert_approx_eq(&output.into_data(), 3);
     }
 
+    #[test]
+    fn test_upsample_interpolation_half_pixel() {
+        let x = TestTensor::<4>::from([[[[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]]]);
+        let y = TestTensor::<4>::from([[[
+            [0.0, 0.4, 1.0, 1.6, 2.0],
+            [1.5, 1.9, 2.5, 3.1, 3.5],
+            [3.0, 3.4, 4.0, 4.6, 5.0],
+        ]]]);
+
+        let output = interpolate(
+            x,
+            [3, 5],
+            InterpolateOptions::new(InterpolateMode::Bilinear).with_align_corners(false),
+        );
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
     struct InterpolateTestCase {
         batch_size: usize,
         channels: usize,
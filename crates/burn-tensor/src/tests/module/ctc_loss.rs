@@ -0,0 +1,57 @@
+#[burn_tensor_testgen::testgen(module_ctc_loss)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::ctc_loss;
+    use burn_tensor::TensorData;
+
+    fn log_probs() -> TestTensor<3> {
+        TestTensor::<3>::from_floats(
+            [[[0.3, 0.7], [0.6, 0.4]], [[0.5, 0.5], [0.9, 0.1]]],
+            &Default::default(),
+        )
+        .log()
+    }
+
+    #[test]
+    fn test_ctc_loss_with_padded_sequences() {
+        let device = Default::default();
+        // The padding of the second target is out of the range of the classes.
+        let targets = TestTensorInt::<2>::from_ints([[1, 1], [1, 7]], &device);
+        let input_lengths = TestTensorInt::<1>::from_ints([2, 1], &device);
+        let target_lengths = TestTensorInt::<1>::from_ints([1, 1], &device);
+
+        let loss = ctc_loss(log_probs(), targets, input_lengths, target_lengths, 0);
+
+        // -log(0.7 * 0.4 + 0.3 * 0.4 + 0.7 * 0.6) and -log(0.5).
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([0.198451, core::f32::consts::LN_2]), 4);
+    }
+
+    #[test]
+    fn test_ctc_loss_with_last_class_as_blank() {
+        let device = Default::default();
+        let targets = TestTensorInt::<2>::from_ints([[0], [0]], &device);
+        let input_lengths = TestTensorInt::<1>::from_ints([2, 2], &device);
+        let target_lengths = TestTensorInt::<1>::from_ints([1, 0], &device);
+
+        let loss = ctc_loss(log_probs(), targets, input_lengths, target_lengths, 1);
+
+        // -log(0.3 * 0.6 + 0.3 * 0.4 + 0.7 * 0.6) and the all-blank alignment -log(0.5 * 0.1).
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([0.328504, 2.995732]), 4);
+    }
+
+    #[test]
+    fn test_ctc_loss_impossible_alignment_is_infinite() {
+        let device = Default::default();
+        // Repeated classes need a blank between them, which doesn't fit in two steps.
+        let targets = TestTensorInt::<2>::from_ints([[1, 1], [1, 1]], &device);
+        let input_lengths = TestTensorInt::<1>::from_ints([2, 2], &device);
+        let target_lengths = TestTensorInt::<1>::from_ints([2, 2], &device);
+
+        let loss = ctc_loss(log_probs(), targets, input_lengths, target_lengths, 0);
+
+        loss.into_data()
+            .assert_eq(&TensorData::from([f32::INFINITY, f32::INFINITY]), false);
+    }
+}
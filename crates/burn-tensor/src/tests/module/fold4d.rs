@@ -0,0 +1,65 @@
+#[burn_tensor_testgen::testgen(module_fold4d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{fold4d, unfold4d};
+    use burn_tensor::ops::UnfoldOptions;
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn test_fold4d_should_sum_overlapping_blocks() {
+        let device = Default::default();
+        let x = TestTensor::<3>::ones([1, 4, 4], &device);
+
+        let output = fold4d(
+            x,
+            [3, 3],
+            [2, 2],
+            UnfoldOptions::new([1, 1], [0, 0], [1, 1]),
+        );
+
+        output.into_data().assert_eq(
+            &TestTensor::<4>::from_floats([[[[1., 2., 1.], [2., 4., 2.], [1., 2., 1.]]]], &device)
+                .into_data(),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_fold4d_should_invert_non_overlapping_unfold4d() {
+        let device = Default::default();
+        let x = TestTensor::<4>::random([2, 3, 4, 6], Distribution::Default, &device);
+        let options = UnfoldOptions::new([2, 3], [0, 0], [1, 1]);
+
+        let unfolded = unfold4d(x.clone(), [2, 3], options.clone());
+        let output = fold4d(unfolded, [4, 6], [2, 3], options);
+
+        output.into_data().assert_approx_eq(&x.into_data(), 3);
+    }
+
+    #[test]
+    fn test_fold4d_complex() {
+        let device = Default::default();
+        let x = TestTensor::<4>::random([1, 2, 4, 6], Distribution::Default, &device);
+        let options = UnfoldOptions::new([1, 2], [0, 1], [1, 2]);
+
+        // Each value is summed once per block containing it.
+        let counts = fold4d(
+            unfold4d(
+                TestTensor::<4>::ones([1, 2, 4, 6], &device),
+                [2, 3],
+                options.clone(),
+            ),
+            [4, 6],
+            [2, 3],
+            options.clone(),
+        );
+        let unfolded = unfold4d(x.clone(), [2, 3], options.clone());
+        assert_eq!(unfolded.dims(), [1, 12, 6]);
+        let output = fold4d(unfolded, [4, 6], [2, 3], options);
+
+        assert_eq!(output.dims(), [1, 2, 4, 6]);
+        output
+            .into_data()
+            .assert_approx_eq(&(x * counts).into_data(), 3);
+    }
+}
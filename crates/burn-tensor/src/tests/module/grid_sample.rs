@@ -0,0 +1,183 @@
+#[burn_tensor_testgen::testgen(module_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{affine_grid_2d, grid_sample_2d};
+    use burn_tensor::ops::{GridSampleOptions, GridSamplePaddingMode, InterpolateMode};
+
+    #[test]
+    fn test_grid_sample_bilinear_zeros_align_corners() {
+        let test = GridSampleTestCase {
+            mode: InterpolateMode::Bilinear,
+            padding_mode: GridSamplePaddingMode::Zeros,
+            align_corners: true,
+        };
+
+        test.assert_output(TestTensor::from([[[[1.0, 5.0, 4.5, 3.3, 5.04]]]]));
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_zeros() {
+        let test = GridSampleTestCase {
+            mode: InterpolateMode::Bilinear,
+            padding_mode: GridSamplePaddingMode::Zeros,
+            align_corners: false,
+        };
+
+        test.assert_output(TestTensor::from([[[[0.25, 5.0, 4.25, 0.0, 0.49]]]]));
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_border() {
+        let test = GridSampleTestCase {
+            mode: InterpolateMode::Bilinear,
+            padding_mode: GridSamplePaddingMode::Border,
+            align_corners: false,
+        };
+
+        test.assert_output(TestTensor::from([[[[1.0, 5.0, 4.25, 6.9, 7.0]]]]));
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_reflection() {
+        let test = GridSampleTestCase {
+            mode: InterpolateMode::Bilinear,
+            padding_mode: GridSamplePaddingMode::Reflection,
+            align_corners: false,
+        };
+
+        test.assert_output(TestTensor::from([[[[1.0, 5.0, 4.25, 6.65, 7.0]]]]));
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_reflection_align_corners() {
+        let test = GridSampleTestCase {
+            mode: InterpolateMode::Bilinear,
+            padding_mode: GridSamplePaddingMode::Reflection,
+            align_corners: true,
+        };
+
+        test.assert_output(TestTensor::from([[[[1.0, 5.0, 4.5, 6.1, 6.9]]]]));
+    }
+
+    #[test]
+    fn test_grid_sample_nearest_zeros() {
+        let x = TestTensor::<4>::from([[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]]]);
+        let grid = TestTensor::<4>::from([[[[0.0, 0.0], [0.4, -0.3]], [[1.5, 0.2], [-1.2, 1.1]]]]);
+        let y = TestTensor::<4>::from([[[[5.0, 6.0], [0.0, 0.0]]]]);
+
+        let output = grid_sample_2d(
+            x,
+            grid,
+            GridSampleOptions::new(
+                InterpolateMode::Nearest,
+                GridSamplePaddingMode::Zeros,
+                false,
+            ),
+        );
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_multiple_channels() {
+        let x = TestTensor::<4>::from([[
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]],
+            [[10.0, 11.0, 12.0], [13.0, 14.0, 15.0], [16.0, 17.0, 18.0]],
+        ]]);
+        let grid = TestTensor::<4>::from([[[
+            [-1.0, -1.0],
+            [0.0, 0.0],
+            [0.4, -0.3],
+            [1.5, 0.2],
+            [-1.2, 1.1],
+        ]]]);
+        let y = TestTensor::<4>::from([[
+            [[1.0, 5.0, 4.5, 3.3, 5.04]],
+            [[10.0, 14.0, 13.5, 7.8, 11.52]],
+        ]]);
+
+        let output = grid_sample_2d(
+            x,
+            grid,
+            GridSampleOptions::new(
+                InterpolateMode::Bilinear,
+                GridSamplePaddingMode::Zeros,
+                true,
+            ),
+        );
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_affine_grid_identity() {
+        let theta = TestTensor::<3>::from([[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]]);
+        let y = TestTensor::<4>::from([[
+            [[-1.0, -1.0], [0.0, -1.0], [1.0, -1.0]],
+            [[-1.0, 1.0], [0.0, 1.0], [1.0, 1.0]],
+        ]]);
+
+        let output = affine_grid_2d(theta, [1, 1, 2, 3], true);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_affine_grid_scale_translate() {
+        let theta = TestTensor::<3>::from([[[2.0, 0.0, 0.5], [0.0, 1.0, -1.0]]]);
+        let y = TestTensor::<4>::from([[
+            [[-0.8333, -1.5], [0.5, -1.5], [1.8333, -1.5]],
+            [[-0.8333, -0.5], [0.5, -0.5], [1.8333, -0.5]],
+        ]]);
+
+        let output = affine_grid_2d(theta, [1, 1, 2, 3], false);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_affine_grid_identity() {
+        let x = TestTensor::<4>::from([[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]]);
+        let theta = TestTensor::<3>::from([[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]]);
+
+        let grid = affine_grid_2d(theta, [1, 1, 2, 3], false);
+        let output = grid_sample_2d(
+            x.clone(),
+            grid,
+            GridSampleOptions::new(
+                InterpolateMode::Bilinear,
+                GridSamplePaddingMode::Zeros,
+                false,
+            ),
+        );
+
+        x.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    struct GridSampleTestCase {
+        mode: InterpolateMode,
+        padding_mode: GridSamplePaddingMode,
+        align_corners: bool,
+    }
+
+    impl GridSampleTestCase {
+        fn assert_output(self, y: TestTensor<4>) {
+            let x = TestTensor::<4>::from([[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]]]);
+            let grid = TestTensor::<4>::from([[[
+                [-1.0, -1.0],
+                [0.0, 0.0],
+                [0.4, -0.3],
+                [1.5, 0.2],
+                [-1.2, 1.1],
+            ]]]);
+
+            let output = grid_sample_2d(
+                x,
+                grid,
+                GridSampleOptions::new(self.mode, self.padding_mode, self.align_corners),
+            );
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
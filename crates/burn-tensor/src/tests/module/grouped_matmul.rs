@@ -0,0 +1,49 @@
+#[burn_tensor_testgen::testgen(module_grouped_matmul)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::grouped_matmul;
+
+    #[test]
+    fn test_grouped_matmul() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::from_floats(
+            [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0], [9.0, 10.0]],
+            &device,
+        );
+        let rhs = TestTensor::<3>::from_floats(
+            [[[1.0, 0.0], [0.0, 1.0]], [[1.0, 1.0], [0.0, 2.0]]],
+            &device,
+        );
+        let offsets = TestTensorInt::<1>::from_ints([2, 4], &device);
+
+        let output = grouped_matmul(lhs, rhs, offsets);
+
+        // The last row isn't part of any group.
+        output.into_data().assert_approx_eq(
+            &TestTensor::<2>::from_floats(
+                [[1.0, 2.0], [3.0, 4.0], [5.0, 17.0], [7.0, 23.0], [0.0, 0.0]],
+                &device,
+            )
+            .into_data(),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_grouped_matmul_empty_group() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let rhs = TestTensor::<3>::from_floats(
+            [[[1.0], [1.0]], [[2.0], [-1.0]], [[0.5], [0.5]]],
+            &device,
+        );
+        let offsets = TestTensorInt::<1>::from_ints([0, 2, 2], &device);
+
+        let output = grouped_matmul(lhs, rhs, offsets);
+
+        output.into_data().assert_approx_eq(
+            &TestTensor::<2>::from_floats([[0.0], [2.0]], &device).into_data(),
+            3,
+        );
+    }
+}
@@ -0,0 +1,83 @@
+#[burn_tensor_testgen::testgen(module_interpolate3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::interpolate3d;
+    use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+
+    #[test]
+    fn test_trilinear_interpolate3d_align_corners() {
+        let x = TestTensor::<5>::from([[[[[0.0, 1.0], [2.0, 3.0]], [[4.0, 5.0], [6.0, 7.0]]]]]);
+        let y = TestTensor::<5>::from([[[
+            [[0.0, 0.5, 1.0], [1.0, 1.5, 2.0], [2.0, 2.5, 3.0]],
+            [[2.0, 2.5, 3.0], [3.0, 3.5, 4.0], [4.0, 4.5, 5.0]],
+            [[4.0, 4.5, 5.0], [5.0, 5.5, 6.0], [6.0, 6.5, 7.0]],
+        ]]]);
+
+        let output = interpolate3d(
+            x,
+            [3, 3, 3],
+            InterpolateOptions::new(InterpolateMode::Trilinear),
+        );
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_trilinear_interpolate3d_half_pixel() {
+        let x = TestTensor::<5>::from([[[[[0.0, 1.0], [2.0, 3.0]], [[4.0, 5.0], [6.0, 7.0]]]]]);
+        let y = TestTensor::<5>::from([[[
+            [[0.0, 0.25, 0.75, 1.0], [2.0, 2.25, 2.75, 3.0]],
+            [[2.0, 2.25, 2.75, 3.0], [4.0, 4.25, 4.75, 5.0]],
+            [[4.0, 4.25, 4.75, 5.0], [6.0, 6.25, 6.75, 7.0]],
+        ]]]);
+
+        let output = interpolate3d(
+            x,
+            [3, 2, 4],
+            InterpolateOptions::new(InterpolateMode::Trilinear).with_align_corners(false),
+        );
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_nearest_interpolate3d() {
+        let x = TestTensor::<5>::from([[[[[0.0, 1.0]], [[2.0, 3.0]]]]]);
+        let y = TestTensor::<5>::from([[[
+            [[0.0, 0.0, 1.0, 1.0], [0.0, 0.0, 1.0, 1.0]],
+            [[2.0, 2.0, 3.0, 3.0], [2.0, 2.0, 3.0, 3.0]],
+        ]]]);
+
+        let output = interpolate3d(
+            x,
+            [2, 2, 4],
+            InterpolateOptions::new(InterpolateMode::Nearest),
+        );
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_with_bilinear_interpolate3d() {
+        let x = TestTensor::<5>::zeros([1, 1, 2, 2, 2], &Default::default());
+
+        interpolate3d(
+            x,
+            [3, 3, 3],
+            InterpolateOptions::new(InterpolateMode::Bilinear),
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_with_empty_spatial_dim() {
+        let x = TestTensor::<5>::zeros([1, 1, 0, 2, 2], &Default::default());
+
+        interpolate3d(
+            x,
+            [3, 3, 3],
+            InterpolateOptions::new(InterpolateMode::Trilinear),
+        );
+    }
+}
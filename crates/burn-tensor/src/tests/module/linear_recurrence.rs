@@ -0,0 +1,57 @@
+#[burn_tensor_testgen::testgen(module_linear_recurrence)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::linear_recurrence;
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn test_linear_recurrence_last_dim() {
+        let device = Default::default();
+        let a = TestTensor::<2>::from_floats([[0.5, 2.0, -1.0, 0.5, 1.0]], &device);
+        let b = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0, 4.0, 5.0]], &device);
+
+        let output = linear_recurrence(a, b, 1);
+
+        output.into_data().assert_approx_eq(
+            &TestTensor::<2>::from_floats([[1.0, 4.0, -1.0, 3.5, 8.5]], &device).into_data(),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_linear_recurrence_first_dim() {
+        let device = Default::default();
+        let a = TestTensor::<2>::from_floats([[0.5, 2.0], [1.0, -1.0], [2.0, 0.5]], &device);
+        let b = TestTensor::<2>::from_floats([[1.0, 1.0], [2.0, 3.0], [-1.0, 4.0]], &device);
+
+        let output = linear_recurrence(a, b, 0);
+
+        output.into_data().assert_approx_eq(
+            &TestTensor::<2>::from_floats([[1.0, 1.0], [3.0, 2.0], [5.0, 5.0]], &device)
+                .into_data(),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_linear_recurrence_should_match_sequential_computation() {
+        let device = Default::default();
+        let [batch, length, channels] = [2, 11, 3];
+        let a = TestTensor::<3>::random([batch, length, channels], Distribution::Default, &device);
+        let b = TestTensor::<3>::random([batch, length, channels], Distribution::Default, &device);
+
+        let output = linear_recurrence(a.clone(), b.clone(), 1);
+
+        let mut state = TestTensor::<3>::zeros([batch, 1, channels], &device);
+        let mut expected = Vec::with_capacity(length);
+        for t in 0..length {
+            state = a.clone().narrow(1, t, 1) * state + b.clone().narrow(1, t, 1);
+            expected.push(state.clone());
+        }
+        let expected = TestTensor::cat(expected, 1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+}
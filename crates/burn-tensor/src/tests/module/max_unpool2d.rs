@@ -0,0 +1,38 @@
+#[burn_tensor_testgen::testgen(module_max_unpool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{max_pool2d_with_indices, max_unpool2d};
+
+    #[test]
+    fn test_max_unpool2d() {
+        let x = TestTensor::from([[[
+            [0.0, 14.0, 5.0, 19.0],
+            [10.0, 1.0, 15.0, 6.0],
+            [20.0, 11.0, 2.0, 16.0],
+            [7.0, 21.0, 12.0, 3.0],
+        ]]]);
+        let y = TestTensor::<4>::from([[[
+            [0.0, 14.0, 0.0, 19.0],
+            [0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 16.0],
+            [0.0, 21.0, 0.0, 0.0],
+        ]]]);
+
+        let (pooled, indices) = max_pool2d_with_indices(x, [2, 2], [2, 2], [0, 0], [1, 1]);
+        let output = max_unpool2d(pooled, indices, [4, 4]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_max_unpool2d_overlapping_windows() {
+        let x = TestTensor::from([[[[1.0, 5.0, 2.0], [0.0, 3.0, 4.0]]]]);
+        let y = TestTensor::<4>::from([[[[0.0, 5.0, 0.0], [0.0, 0.0, 0.0]]]]);
+
+        // Both windows select the same maximum, which is written once.
+        let (pooled, indices) = max_pool2d_with_indices(x, [2, 2], [1, 1], [0, 0], [1, 1]);
+        let output = max_unpool2d(pooled, indices, [2, 3]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+}
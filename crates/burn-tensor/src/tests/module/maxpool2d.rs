@@ -319,4 +319,19 @@ mod tests {
         y.to_data().assert_approx_eq(&output.into_data(), 3);
         output_indices.into_data().assert_eq(&indices, false);
     }
+
+    #[test]
+    fn test_max_pool2d_with_indices_non_square() {
+        let x = TestTensor::from([[[
+            [0.0, 14.0, 5.0, 19.0, 10.0, 1.0],
+            [6.0, 20.0, 11.0, 2.0, 16.0, 7.0],
+        ]]]);
+        let indices = TensorData::from([[[[7, 3, 10]]]]);
+        let y = TestTensor::<4>::from([[[[20.0, 19.0, 16.0]]]]);
+
+        let (output, output_indices) = max_pool2d_with_indices(x, [2, 2], [2, 2], [0, 0], [1, 1]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        output_indices.into_data().assert_eq(&indices, false);
+    }
 }
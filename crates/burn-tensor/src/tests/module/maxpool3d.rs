@@ -0,0 +1,136 @@
+#[burn_tensor_testgen::testgen(module_max_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{max_pool3d, max_pool3d_with_indices};
+    use burn_tensor::TensorData;
+
+    fn input() -> TestTensor<5> {
+        TestTensor::from([[
+            [
+                [
+                    [0.0, 14.0, 5.0, 19.0],
+                    [10.0, 1.0, 15.0, 6.0],
+                    [20.0, 11.0, 2.0, 16.0],
+                    [7.0, 21.0, 12.0, 3.0],
+                ],
+                [
+                    [17.0, 8.0, 22.0, 13.0],
+                    [4.0, 18.0, 9.0, 0.0],
+                    [14.0, 5.0, 19.0, 10.0],
+                    [1.0, 15.0, 6.0, 20.0],
+                ],
+                [
+                    [11.0, 2.0, 16.0, 7.0],
+                    [21.0, 12.0, 3.0, 17.0],
+                    [8.0, 22.0, 13.0, 4.0],
+                    [18.0, 9.0, 0.0, 14.0],
+                ],
+            ],
+            [
+                [
+                    [5.0, 19.0, 10.0, 1.0],
+                    [15.0, 6.0, 20.0, 11.0],
+                    [2.0, 16.0, 7.0, 21.0],
+                    [12.0, 3.0, 17.0, 8.0],
+                ],
+                [
+                    [22.0, 13.0, 4.0, 18.0],
+                    [9.0, 0.0, 14.0, 5.0],
+                    [19.0, 10.0, 1.0, 15.0],
+                    [6.0, 20.0, 11.0, 2.0],
+                ],
+                [
+                    [16.0, 7.0, 21.0, 12.0],
+                    [3.0, 17.0, 8.0, 22.0],
+                    [13.0, 4.0, 18.0, 9.0],
+                    [0.0, 14.0, 5.0, 19.0],
+                ],
+            ],
+        ]])
+    }
+
+    #[test]
+    fn test_max_pool3d_simple() {
+        let x = TestTensor::from([[[
+            [[1.0, 2.0], [3.0, 4.0]],
+            [[8.0, 7.0], [6.0, 5.0]],
+            [[0.0, 9.0], [1.0, 2.0]],
+        ]]]);
+        let y = TestTensor::<5>::from([[[[[8.0]], [[9.0]]]]]);
+
+        let output = max_pool3d(x, [2, 2, 2], [1, 1, 1], [0, 0, 0], [1, 1, 1]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool3d_with_padding_and_stride() {
+        let y = TestTensor::<5>::from([[
+            [
+                [[17.0, 22.0, 19.0], [20.0, 19.0, 16.0], [7.0, 21.0, 20.0]],
+                [[17.0, 22.0, 13.0], [21.0, 22.0, 17.0], [18.0, 15.0, 20.0]],
+            ],
+            [
+                [[22.0, 19.0, 18.0], [19.0, 20.0, 21.0], [12.0, 20.0, 8.0]],
+                [[22.0, 21.0, 18.0], [19.0, 18.0, 22.0], [6.0, 20.0, 19.0]],
+            ],
+        ]]);
+
+        let output = max_pool3d(input(), [2, 2, 2], [1, 2, 2], [0, 1, 1], [1, 1, 1]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool3d_with_dilation() {
+        let x = TestTensor::from([[[
+            [[1.0, 0.0, 2.0], [0.0, 0.0, 0.0], [3.0, 0.0, 4.0]],
+            [[9.0, 9.0, 9.0], [9.0, 9.0, 9.0], [9.0, 9.0, 9.0]],
+            [[5.0, 0.0, 6.0], [0.0, 0.0, 0.0], [7.0, 0.0, 8.0]],
+        ]]]);
+        let y = TestTensor::<5>::from([[[[[8.0]]]]]);
+
+        let output = max_pool3d(x, [2, 2, 2], [1, 1, 1], [0, 0, 0], [2, 2, 2]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool3d_with_indices() {
+        let (output, indices) =
+            max_pool3d_with_indices(input(), [2, 2, 2], [1, 2, 2], [0, 1, 1], [1, 1, 1]);
+
+        let y = TestTensor::<5>::from([[
+            [
+                [[17.0, 22.0, 19.0], [20.0, 19.0, 16.0], [7.0, 21.0, 20.0]],
+                [[17.0, 22.0, 13.0], [21.0, 22.0, 17.0], [18.0, 15.0, 20.0]],
+            ],
+            [
+                [[22.0, 19.0, 18.0], [19.0, 20.0, 21.0], [12.0, 20.0, 8.0]],
+                [[22.0, 21.0, 18.0], [19.0, 18.0, 22.0], [6.0, 20.0, 19.0]],
+            ],
+        ]]);
+        let indices_expected = TensorData::from([[
+            [
+                [[16, 18, 3], [8, 26, 11], [12, 13, 31]],
+                [[16, 18, 19], [36, 41, 39], [44, 29, 31]],
+            ],
+            [
+                [[16, 1, 19], [24, 6, 11], [12, 29, 15]],
+                [[16, 34, 19], [24, 42, 39], [28, 29, 47]],
+            ],
+        ]]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        indices.into_data().assert_eq(&indices_expected, false);
+    }
+
+    #[test]
+    fn test_max_pool3d_output_shape() {
+        let x = TestTensor::<5>::zeros([2, 3, 7, 9, 11], &Default::default());
+
+        let output = max_pool3d(x, [3, 3, 3], [2, 1, 3], [1, 0, 1], [1, 2, 1]);
+
+        assert_eq!(output.dims(), [2, 3, 4, 5, 4]);
+    }
+}
@@ -0,0 +1,65 @@
+#[burn_tensor_testgen::testgen(module_pixel_shuffle)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{pixel_shuffle, pixel_unshuffle};
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn test_pixel_shuffle() {
+        let device = Default::default();
+        let x = TestTensorInt::<1>::arange(0..16, &device)
+            .reshape([1, 4, 2, 2])
+            .float();
+
+        let output = pixel_shuffle(x, 2);
+
+        output.into_data().assert_eq(
+            &TestTensor::<4>::from_floats(
+                [[[
+                    [0., 4., 1., 5.],
+                    [8., 12., 9., 13.],
+                    [2., 6., 3., 7.],
+                    [10., 14., 11., 15.],
+                ]]],
+                &device,
+            )
+            .into_data(),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_pixel_unshuffle() {
+        let device = Default::default();
+        let x = TestTensor::<4>::from_floats(
+            [[[
+                [0., 4., 1., 5.],
+                [8., 12., 9., 13.],
+                [2., 6., 3., 7.],
+                [10., 14., 11., 15.],
+            ]]],
+            &device,
+        );
+
+        let output = pixel_unshuffle(x, 2);
+
+        output.into_data().assert_eq(
+            &TestTensorInt::<1>::arange(0..16, &device)
+                .reshape([1, 4, 2, 2])
+                .float()
+                .into_data(),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_pixel_unshuffle_should_invert_pixel_shuffle() {
+        let device = Default::default();
+        let x = TestTensor::<4>::random([2, 18, 3, 5], Distribution::Default, &device);
+
+        let output = pixel_unshuffle(pixel_shuffle(x.clone(), 3), 3);
+
+        assert_eq!(output.dims(), [2, 18, 3, 5]);
+        output.into_data().assert_eq(&x.into_data(), false);
+    }
+}
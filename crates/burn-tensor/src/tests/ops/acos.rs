@@ -0,0 +1,17 @@
+#[burn_tensor_testgen::testgen(acos)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+    use core::f32::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn should_support_acos_ops() {
+        let data = TensorData::from([[-1.0, -0.5, 0.0], [0.25, 0.9, 1.0]]);
+        let tensor = TestTensor::<2>::from_data(data, &Default::default());
+
+        let output = tensor.acos();
+        let expected = TensorData::from([[PI, 2.0944, FRAC_PI_2], [1.31812, 0.451027, 0.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(acosh)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_support_acosh_ops() {
+        let data = TensorData::from([[1.0, 1.5, 2.0], [3.0, 10.0, 100.0]]);
+        let tensor = TestTensor::<2>::from_data(data, &Default::default());
+
+        let output = tensor.acosh();
+        let expected = TensorData::from([[0.0, 0.962424, 1.31696], [1.76275, 2.99322, 5.29829]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
@@ -0,0 +1,18 @@
+#[burn_tensor_testgen::testgen(asin)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+    use core::f32::consts::{FRAC_PI_2, FRAC_PI_6};
+
+    #[test]
+    fn should_support_asin_ops() {
+        let data = TensorData::from([[-1.0, -0.5, 0.0], [0.25, 0.9, 1.0]]);
+        let tensor = TestTensor::<2>::from_data(data, &Default::default());
+
+        let output = tensor.asin();
+        let expected =
+            TensorData::from([[-FRAC_PI_2, -FRAC_PI_6, 0.0], [0.25268, 1.11977, FRAC_PI_2]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
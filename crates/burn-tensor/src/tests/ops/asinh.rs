@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(asinh)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_support_asinh_ops() {
+        let data = TensorData::from([[-10.0, -0.5, 0.0], [0.001, 1.0, 4.0]]);
+        let tensor = TestTensor::<2>::from_data(data, &Default::default());
+
+        let output = tensor.asinh();
+        let expected = TensorData::from([[-2.99822, -0.481212, 0.0], [0.001, 0.881374, 2.09471]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
@@ -0,0 +1,18 @@
+#[burn_tensor_testgen::testgen(atan)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+    use core::f32::consts::FRAC_PI_4;
+
+    #[test]
+    fn should_support_atan_ops() {
+        let data = TensorData::from([[-10.0, -1.0, -0.3], [0.0, 0.7, 3.0]]);
+        let tensor = TestTensor::<2>::from_data(data, &Default::default());
+
+        let output = tensor.atan();
+        let expected =
+            TensorData::from([[-1.47113, -FRAC_PI_4, -0.291457], [0.0, 0.610726, 1.24905]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
@@ -0,0 +1,19 @@
+#[burn_tensor_testgen::testgen(atan2)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+    use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    #[test]
+    fn should_support_atan2_ops() {
+        let device = Default::default();
+        let lhs = TestTensor::<1>::from_data([1.0, 1.0, -1.0, -1.0, 0.0, 0.0, 2.0], &device);
+        let rhs = TestTensor::<1>::from_data([1.0, -1.0, -1.0, 1.0, -2.0, 0.0, 0.0], &device);
+
+        let output = lhs.atan2(rhs);
+        let expected =
+            TensorData::from([FRAC_PI_4, 2.35619, -2.35619, -FRAC_PI_4, PI, 0.0, FRAC_PI_2]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
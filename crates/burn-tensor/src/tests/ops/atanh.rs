@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(atanh)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_support_atanh_ops() {
+        let data = TensorData::from([[-0.9, -0.5, 0.0], [0.001, 0.5, 0.99]]);
+        let tensor = TestTensor::<2>::from_data(data, &Default::default());
+
+        let output = tensor.atanh();
+        let expected = TensorData::from([[-1.47222, -0.549306, 0.0], [0.001, 0.549306, 2.64665]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
@@ -0,0 +1,174 @@
+#[burn_tensor_testgen::testgen(complex)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ComplexTensor, TensorData};
+    use core::f32::consts::{FRAC_PI_2, LN_2, PI};
+
+    fn complex<const D: usize>(
+        real: TestTensor<D>,
+        imag: TestTensor<D>,
+    ) -> ComplexTensor<TestBackend, D> {
+        ComplexTensor::new(real, imag)
+    }
+
+    fn lhs() -> ComplexTensor<TestBackend, 1> {
+        let device = Default::default();
+        complex(
+            TestTensor::from_floats([1.0, 3.0], &device),
+            TestTensor::from_floats([2.0, -1.0], &device),
+        )
+    }
+
+    fn rhs() -> ComplexTensor<TestBackend, 1> {
+        let device = Default::default();
+        complex(
+            TestTensor::from_floats([2.0, -1.0], &device),
+            TestTensor::from_floats([-1.0, 1.0], &device),
+        )
+    }
+
+    fn assert_complex<const D: usize>(
+        tensor: ComplexTensor<TestBackend, D>,
+        real: TensorData,
+        imag: TensorData,
+    ) {
+        tensor
+            .into_data()
+            .assert_approx_eq(&TensorData::complex(real, imag), 3);
+    }
+
+    #[test]
+    fn should_convert_complex_data() {
+        let data = TensorData::complex(
+            TensorData::from([[1.0, 2.0], [3.0, 4.0]]),
+            TensorData::from([[0.5, -1.0], [0.0, 2.0]]),
+        );
+
+        let tensor = ComplexTensor::<TestBackend, 2>::from_data(data.clone(), &Default::default());
+
+        assert!(tensor.to_data().dtype.is_complex());
+        tensor.into_data().assert_eq(&data, false);
+    }
+
+    #[test]
+    fn should_support_arithmetic() {
+        assert_complex(
+            lhs() + rhs(),
+            TensorData::from([3.0, 2.0]),
+            TensorData::from([1.0, 0.0]),
+        );
+        assert_complex(
+            lhs() - rhs(),
+            TensorData::from([-1.0, 4.0]),
+            TensorData::from([3.0, -2.0]),
+        );
+        assert_complex(
+            lhs() * rhs(),
+            TensorData::from([4.0, -2.0]),
+            TensorData::from([3.0, 4.0]),
+        );
+        assert_complex(
+            lhs() / rhs(),
+            TensorData::from([0.0, -2.0]),
+            TensorData::from([1.0, -1.0]),
+        );
+        assert_complex(
+            -lhs(),
+            TensorData::from([-1.0, -3.0]),
+            TensorData::from([-2.0, 1.0]),
+        );
+    }
+
+    #[test]
+    fn should_support_abs_angle_and_conj() {
+        lhs()
+            .abs()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([2.236068, 3.1622777]), 3);
+        lhs()
+            .angle()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.1071487, -0.32175055]), 3);
+        assert_complex(
+            lhs().conj(),
+            TensorData::from([1.0, 3.0]),
+            TensorData::from([-2.0, 1.0]),
+        );
+    }
+
+    #[test]
+    fn should_support_real_and_imag() {
+        lhs()
+            .real()
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 3.0]), false);
+        lhs()
+            .imag()
+            .into_data()
+            .assert_eq(&TensorData::from([2.0, -1.0]), false);
+    }
+
+    #[test]
+    fn should_support_exp_and_log() {
+        let device = Default::default();
+        let tensor = complex(
+            TestTensor::<1>::from_floats([0.0, LN_2], &device),
+            TestTensor::from_floats([FRAC_PI_2, 0.0], &device),
+        );
+
+        assert_complex(
+            tensor.exp(),
+            TensorData::from([0.0, 2.0]),
+            TensorData::from([1.0, 0.0]),
+        );
+
+        let tensor = complex(
+            TestTensor::<1>::from_floats([-1.0, 0.0], &device),
+            TestTensor::from_floats([0.0, 1.0], &device),
+        );
+
+        assert_complex(
+            tensor.log(),
+            TensorData::from([0.0, 0.0]),
+            TensorData::from([PI, FRAC_PI_2]),
+        );
+    }
+
+    #[test]
+    fn should_support_matmul() {
+        let device = Default::default();
+        let lhs = complex(
+            TestTensor::<2>::from_floats([[1.0, 2.0], [0.0, 1.0]], &device),
+            TestTensor::from_floats([[1.0, 0.0], [0.0, -1.0]], &device),
+        );
+        let rhs = complex(
+            TestTensor::<2>::from_floats([[1.0, 0.0], [1.0, 2.0]], &device),
+            TestTensor::from_floats([[0.0, 1.0], [1.0, 0.0]], &device),
+        );
+
+        assert_complex(
+            lhs.matmul(rhs),
+            TensorData::from([[3.0, 3.0], [2.0, 2.0]]),
+            TensorData::from([[3.0, 1.0], [0.0, -2.0]]),
+        );
+    }
+
+    #[test]
+    fn should_support_batched_matmul_with_broadcast() {
+        let device = Default::default();
+        let lhs = complex(
+            TestTensor::<3>::from_floats([[[1.0, 2.0]], [[0.0, 1.0]]], &device),
+            TestTensor::from_floats([[[0.0, 1.0]], [[1.0, 0.0]]], &device),
+        );
+        let rhs = complex(
+            TestTensor::<3>::from_floats([[[1.0], [2.0]]], &device),
+            TestTensor::from_floats([[[1.0], [0.0]]], &device),
+        );
+
+        assert_complex(
+            lhs.matmul(rhs),
+            TensorData::from([[[5.0]], [[1.0]]]),
+            TensorData::from([[[3.0]], [[1.0]]]),
+        );
+    }
+}
@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(cosh)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_support_cosh_ops() {
+        let data = TensorData::from([[-3.0, -0.5, 0.0], [0.001, 1.0, 4.0]]);
+        let tensor = TestTensor::<2>::from_data(data, &Default::default());
+
+        let output = tensor.cosh();
+        let expected = TensorData::from([[10.0677, 1.12763, 1.0], [1.0, 1.54308, 27.3082]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
@@ -0,0 +1,66 @@
+#[burn_tensor_testgen::testgen(diagonal)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    fn matrix() -> TestTensor<2> {
+        TestTensor::from_floats(
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]],
+            &Default::default(),
+        )
+    }
+
+    #[test]
+    fn should_return_main_diagonal() {
+        let output: TestTensor<1> = matrix().diagonal(0, 0, 1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 5.0, 9.0]), false);
+    }
+
+    #[test]
+    fn should_return_diagonal_with_offset() {
+        let above: TestTensor<1> = matrix().diagonal(1, 0, 1);
+        let below: TestTensor<1> = matrix().diagonal(-2, 0, 1);
+        let transposed: TestTensor<1> = matrix().diagonal(1, 1, 0);
+
+        above
+            .into_data()
+            .assert_eq(&TensorData::from([2.0, 6.0]), false);
+        below.into_data().assert_eq(&TensorData::from([7.0]), false);
+        transposed
+            .into_data()
+            .assert_eq(&TensorData::from([4.0, 8.0]), false);
+    }
+
+    #[test]
+    fn should_return_batched_diagonal_of_non_square_matrices() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..16, &device).reshape([2, 2, 4]);
+
+        let output: TestTensorInt<2> = tensor.diagonal(1, 1, 2);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 6], [9, 14]]), false);
+    }
+
+    #[test]
+    fn should_return_diagonal_of_leading_dims() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..8, &device).reshape([2, 2, 2]);
+
+        let output: TestTensorInt<2> = tensor.diagonal(0, 0, 1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 6], [1, 7]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_offset_is_out_of_bounds() {
+        let _output: TestTensor<1> = matrix().diagonal(3, 0, 1);
+    }
+}
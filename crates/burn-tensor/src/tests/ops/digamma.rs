@@ -0,0 +1,17 @@
+#[burn_tensor_testgen::testgen(digamma)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_support_digamma_ops() {
+        let data = TensorData::from([[-2.5, 0.1, 0.5], [1.0, 3.0, 10.5]]);
+        let tensor = TestTensor::<2>::from_data(data, &Default::default());
+
+        let output = tensor.digamma();
+        let expected =
+            TensorData::from([[1.10316, -10.4238, -1.96351], [-0.577216, 0.922784, 2.303]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
@@ -0,0 +1,150 @@
+#[burn_tensor_testgen::testgen(einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::{einsum, EinsumOperand, Tensor, TensorData};
+
+    #[test]
+    fn test_einsum_matmul() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::from_floats([[1.0, 7.0], [2.0, 3.0], [1.0, 5.0]], &device);
+        let rhs = TestTensor::from_floats([[4.0, 7.0, 5.0], [2.0, 3.0, 5.0]], &device);
+
+        let output: Tensor<TestBackend, 2> = einsum("ij,jk->ik", [lhs.clone(), rhs.clone()]);
+
+        output
+            .into_data()
+            .assert_eq(&lhs.matmul(rhs).into_data(), false);
+    }
+
+    #[test]
+    fn test_einsum_attention_scores() {
+        let device = Default::default();
+        let q = TestTensor::<4>::from_floats(
+            [
+                [[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]],
+                [[[0.0, 1.0], [1.0, 0.0], [2.0, 2.0]]],
+            ],
+            &device,
+        );
+        let k = TestTensor::<4>::from_floats(
+            [[[[1.0, 0.0], [0.0, 1.0]]], [[[1.0, 1.0], [2.0, -1.0]]]],
+            &device,
+        );
+
+        let output: Tensor<TestBackend, 4> = einsum("bhqd,bhkd->bhqk", [q.clone(), k.clone()]);
+        let expected = q.matmul(k.swap_dims(2, 3));
+
+        output.into_data().assert_eq(&expected.into_data(), false);
+    }
+
+    #[test]
+    fn test_einsum_transpose() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::from_floats([[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]], &device);
+
+        let output: Tensor<TestBackend, 3> = einsum("abc->cab", [tensor.clone()]);
+
+        output
+            .into_data()
+            .assert_eq(&tensor.permute([2, 0, 1]).into_data(), false);
+    }
+
+    #[test]
+    fn test_einsum_sum_and_implicit_output() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let rows: Tensor<TestBackend, 1> = einsum("ij->i", [tensor.clone()]);
+        let total: Tensor<TestBackend, 1> = einsum("ij->", [tensor.clone()]);
+        let transposed: Tensor<TestBackend, 2> = einsum("ji", [tensor]);
+
+        rows.into_data()
+            .assert_eq(&TensorData::from([6.0, 15.0]), false);
+        total
+            .into_data()
+            .assert_eq(&TensorData::from([21.0]), false);
+        transposed.into_data().assert_eq(
+            &TensorData::from([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_einsum_diagonal_and_trace() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats(
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]],
+            &device,
+        );
+
+        let diagonal: Tensor<TestBackend, 1> = einsum("ii->i", [tensor.clone()]);
+        let trace: Tensor<TestBackend, 1> = einsum("ii", [tensor]);
+
+        diagonal
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 5.0, 9.0]), false);
+        trace
+            .into_data()
+            .assert_eq(&TensorData::from([15.0]), false);
+    }
+
+    #[test]
+    fn test_einsum_different_ranks() {
+        let device = Default::default();
+        let matrix = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let vector = TestTensor::<1>::from_floats([1.0, -1.0], &device);
+
+        let output: Tensor<TestBackend, 1> = einsum(
+            "ij,j->i",
+            [EinsumOperand::from(matrix), vector.clone().into()],
+        );
+        let outer: Tensor<TestBackend, 2> = einsum("i,j->ij", [vector.clone(), vector]);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([-1.0, -1.0]), false);
+        outer
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, -1.0], [-1.0, 1.0]]), false);
+    }
+
+    #[test]
+    fn test_einsum_three_operands() {
+        let device = Default::default();
+        let a = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let b = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [1.0, 0.0, 1.0]], &device);
+        let c = TestTensor::<2>::from_floats([[1.0], [2.0], [3.0]], &device);
+
+        let output: Tensor<TestBackend, 2> =
+            einsum("ij,jk,kl->il", [a.clone(), b.clone(), c.clone()]);
+        let expected = a.matmul(b).matmul(c);
+
+        output.into_data().assert_eq(&expected.into_data(), false);
+    }
+
+    #[test]
+    fn test_einsum_batch_with_summed_label() {
+        let device = Default::default();
+        let a = TestTensor::<3>::from_floats(
+            [[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]],
+            &device,
+        );
+        let b = TestTensor::<3>::from_floats([[[1.0, 1.0]], [[2.0, 0.0]]], &device);
+
+        // `i` is summed in `a` before the contraction over `j`, `b` is a batch dimension.
+        let output: Tensor<TestBackend, 2> = einsum("bij,bkj->bk", [a, b]);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[10.0], [24.0]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_einsum_wrong_rank_should_panic() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0]], &device);
+
+        let _output: Tensor<TestBackend, 1> = einsum("ijk->i", [tensor]);
+    }
+}
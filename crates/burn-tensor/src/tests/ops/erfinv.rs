@@ -0,0 +1,19 @@
+#[burn_tensor_testgen::testgen(erfinv)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_support_erfinv_ops() {
+        let data = TensorData::from([[-1.0, -0.5, 0.0], [0.3, 0.9, 0.999]]);
+        let tensor = TestTensor::<2>::from_data(data, &Default::default());
+
+        let output = tensor.erfinv();
+        let expected = TensorData::from([
+            [f32::NEG_INFINITY, -0.476936, 0.0],
+            [0.272463, 1.16309, 2.32675],
+        ]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
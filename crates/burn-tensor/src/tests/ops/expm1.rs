@@ -0,0 +1,19 @@
+#[burn_tensor_testgen::testgen(expm1)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_support_expm1_ops() {
+        let data = TensorData::from([[-2.0, -0.5, 0.0], [0.0001, 0.5, 2.0]]);
+        let tensor = TestTensor::<2>::from_data(data, &Default::default());
+
+        let output = tensor.expm1();
+        let expected = TensorData::from([
+            [-0.864665, -0.393469, 0.0],
+            [0.000100005, 0.648721, 6.38906],
+        ]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
@@ -0,0 +1,40 @@
+#[burn_tensor_testgen::testgen(fmod)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    /// From https://pytorch.org/docs/stable/generated/torch.fmod.html
+    #[test]
+    fn should_support_fmod_ops() {
+        let device = Default::default();
+        let lhs = TestTensor::<1>::from_data([-3.0, -2.0, -1.0, 1.0, 2.0, 3.0], &device);
+        let rhs = TestTensor::<1>::from_data([2.0, 2.0, 2.0, 2.0, 2.0, 2.0], &device);
+
+        let output = lhs.fmod(rhs);
+        let expected = TensorData::from([-1.0, -0.0, -1.0, 1.0, 0.0, 1.0]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_fmod_ops_with_negative_divisor() {
+        let device = Default::default();
+        let lhs = TestTensor::<1>::from_data([5.5, -5.5, 5.5, -5.5], &device);
+        let rhs = TestTensor::<1>::from_data([2.0, 2.0, -2.0, -2.0], &device);
+
+        let output = lhs.fmod(rhs);
+        let expected = TensorData::from([1.5, -1.5, 1.5, -1.5]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_fmod_scalar_ops() {
+        let tensor = TestTensor::<1>::from_data([1.0, 2.0, 3.0, 4.0, 5.0], &Default::default());
+
+        let output = tensor.fmod_scalar(-1.5);
+        let expected = TensorData::from([1.0, 0.5, 0.0, 1.0, 0.5]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
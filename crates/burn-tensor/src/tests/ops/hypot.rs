@@ -0,0 +1,30 @@
+#[burn_tensor_testgen::testgen(hypot)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_support_hypot_ops() {
+        let device = Default::default();
+        let lhs = TestTensor::<1>::from_data([3.0, -5.0, 0.0, 0.001, 8.0], &device);
+        let rhs = TestTensor::<1>::from_data([4.0, 12.0, 2.0, 0.001, -15.0], &device);
+
+        let output = lhs.hypot(rhs);
+        let expected = TensorData::from([5.0, 13.0, 2.0, 0.00141421, 17.0]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_not_overflow_hypot_of_large_values() {
+        let device = Default::default();
+        // The squares of the values overflow with single precision.
+        let lhs = TestTensor::<1>::from_data([5e20, -3e20, 0.0], &device);
+        let rhs = TestTensor::<1>::from_data([12e20, 4e20, 0.0], &device);
+
+        let output = lhs.hypot(rhs).div_scalar(1e20);
+        let expected = TensorData::from([13.0, 5.0, 0.0]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
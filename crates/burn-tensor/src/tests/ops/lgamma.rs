@@ -0,0 +1,17 @@
+#[burn_tensor_testgen::testgen(lgamma)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+    use core::f32::consts::LN_2;
+
+    #[test]
+    fn should_support_lgamma_ops() {
+        let data = TensorData::from([[-2.5, 0.1, 0.5], [1.0, 3.0, 10.5]]);
+        let tensor = TestTensor::<2>::from_data(data, &Default::default());
+
+        let output = tensor.lgamma();
+        let expected = TensorData::from([[-0.0562437, 2.25271, 0.572365], [0.0, LN_2, 13.9406]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
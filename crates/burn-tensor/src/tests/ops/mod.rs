@@ -1,4 +1,6 @@
 mod abs;
+mod acos;
+mod acosh;
 mod add;
 mod aggregation;
 mod all;
@@ -7,6 +9,11 @@ mod arange;
 mod arange_step;
 mod arg;
 mod argwhere_nonzero;
+mod asin;
+mod asinh;
+mod atan;
+mod atan2;
+mod atanh;
 mod bitwise;
 mod bool;
 mod cartesian_grid;
@@ -16,19 +23,29 @@ mod ceil;
 mod chunk;
 mod clamp;
 mod close;
+mod complex;
 mod cos;
+mod cosh;
 mod create_like;
+mod diagonal;
+mod digamma;
 mod div;
+mod einsum;
 mod erf;
+mod erfinv;
 mod exp;
 mod expand;
+mod expm1;
 mod flatten;
 mod flip;
 mod floor;
+mod fmod;
 mod full;
 mod gather_scatter;
+mod hypot;
 mod init;
 mod iter_dim;
+mod lgamma;
 mod log;
 mod log1p;
 mod map_comparison;
@@ -38,6 +55,7 @@ mod maxmin;
 mod movedim;
 mod mul;
 mod nan;
+mod nan_to_num;
 mod narrow;
 mod neg;
 mod one_hot;
@@ -52,20 +70,27 @@ mod remainder;
 mod repeat;
 mod repeat_dim;
 mod reshape;
+mod roll;
 mod round;
+mod scatter_reduce;
 mod select;
 mod sign;
 mod sin;
+mod sinh;
 mod slice;
 mod sort_argsort;
+mod sparse;
 mod split;
 mod sqrt;
 mod squeeze;
 mod stack;
 mod sub;
+mod take;
 mod tan;
 mod tanh;
 mod topk;
 mod transpose;
 mod tri;
 mod tri_mask;
+mod trunc;
+mod unique;
@@ -0,0 +1,34 @@
+#[burn_tensor_testgen::testgen(nan_to_num)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_support_nan_to_num_ops() {
+        let tensor = TestTensor::<1>::from_data(
+            [f32::NAN, f32::INFINITY, f32::NEG_INFINITY, 2.5],
+            &Default::default(),
+        );
+
+        let output = tensor.nan_to_num(0.0, Some(100.0), Some(-100.0));
+        let expected = TensorData::from([0.0, 100.0, -100.0, 2.5]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_replace_infinities_with_finite_limits_by_default() {
+        let tensor = TestTensor::<1>::from_data(
+            [f32::NAN, f32::INFINITY, f32::NEG_INFINITY, 2.5],
+            &Default::default(),
+        );
+
+        let output = tensor.nan_to_num(1.0, None, None).into_data();
+        let values = output.iter::<f32>().collect::<Vec<_>>();
+
+        assert_eq!(values[0], 1.0);
+        assert!(values[1].is_finite() && values[1] > 1e4);
+        assert!(values[2].is_finite() && values[2] < -1e4);
+        assert_eq!(values[3], 2.5);
+    }
+}
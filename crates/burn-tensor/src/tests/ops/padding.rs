@@ -5,7 +5,7 @@ mod tests {
         as_type,
         backend::Backend,
         tests::{Float as _, Int as _},
-        Numeric, Shape, Tensor, TensorData,
+        Numeric, PadMode, Shape, Tensor, TensorData,
     };
 
     #[test]
@@ -100,4 +100,71 @@ mod tests {
         ]]]));
         padded_tensor.into_data().assert_eq(&expected, false);
     }
+
+    #[test]
+    fn padding_reflect_test() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let padded_tensor = tensor.pad_with(&[(1, 1), (2, 1)], PadMode::Reflect);
+
+        let expected = TensorData::from([
+            [6.0, 5.0, 4.0, 5.0, 6.0, 5.0],
+            [3.0, 2.0, 1.0, 2.0, 3.0, 2.0],
+            [6.0, 5.0, 4.0, 5.0, 6.0, 5.0],
+            [3.0, 2.0, 1.0, 2.0, 3.0, 2.0],
+        ]);
+        padded_tensor.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn padding_replicate_test() {
+        let tensor = TestTensor::<3>::from([[[1.0, 2.0], [3.0, 4.0]]]);
+
+        let padded_tensor = tensor.pad_with(&[(1, 0), (0, 2)], PadMode::Replicate);
+
+        let expected = TensorData::from([[
+            [1.0, 2.0, 2.0, 2.0],
+            [1.0, 2.0, 2.0, 2.0],
+            [3.0, 4.0, 4.0, 4.0],
+        ]]);
+        padded_tensor.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn padding_circular_test() {
+        let tensor = TestTensor::<1>::from([1.0, 2.0, 3.0]);
+
+        let padded_tensor = tensor.pad_with(&[(2, 2)], PadMode::Circular);
+
+        let expected = TensorData::from([2.0, 3.0, 1.0, 2.0, 3.0, 1.0, 2.0]);
+        padded_tensor.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn padding_constant_all_dims_test() {
+        let tensor = TestTensor::<3>::from([[[1.0]], [[2.0]]]);
+
+        let padded_tensor = tensor.pad_with(&[(1, 0), (0, 0), (0, 1)], PadMode::Constant(-1.0));
+
+        let expected = TensorData::from([[[-1.0, -1.0]], [[1.0, -1.0]], [[2.0, -1.0]]]);
+        padded_tensor.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn padding_reflect_integer_test() {
+        let tensor = TestTensorInt::<2>::from([[1, 2, 3]]);
+
+        let padded_tensor = tensor.pad_with(&[(1, 1)], PadMode::Reflect);
+
+        let expected = TensorData::from([[2, 1, 2, 3, 2]]);
+        padded_tensor.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn padding_should_panic_with_too_many_dims() {
+        let tensor = TestTensor::<1>::from([1.0, 2.0, 3.0]);
+
+        let _padded_tensor = tensor.pad_with(&[(1, 1), (1, 1)], PadMode::Replicate);
+    }
 }
@@ -1,7 +1,8 @@
 #[burn_tensor_testgen::testgen(random)]
 mod tests {
     use super::*;
-    use burn_tensor::{cast::ToElement, tests::Float, Distribution, Tensor};
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{cast::ToElement, tests::Float, Distribution, Generator, Tensor};
 
     #[test]
     fn rand_default() {
@@ -35,4 +36,46 @@ mod tests {
 
         assert_eq!(tensor.into_data(), [FloatType::new(1f32); 20].into());
     }
+
+    #[test]
+    fn random_with_should_be_reproducible() {
+        let device = Default::default();
+        let distribution = Distribution::Normal(0.0, 1.0);
+
+        let tensor_1 =
+            TestTensor::<2>::random_with([4, 5], distribution, &mut Generator::new(7), &device);
+        let tensor_2 =
+            TestTensor::<2>::random_with([4, 5], distribution, &mut Generator::new(7), &device);
+
+        tensor_1.into_data().assert_eq(&tensor_2.into_data(), true);
+    }
+
+    #[test]
+    fn random_with_should_advance_the_generator() {
+        let device = Default::default();
+        let mut generator = Generator::new(7);
+
+        let tensor_1 =
+            TestTensor::<1>::random_with([20], Distribution::Default, &mut generator, &device);
+        let tensor_2 = tensor_1.random_like_with(Distribution::Default, &mut generator);
+
+        assert_ne!(tensor_1.into_data(), tensor_2.into_data());
+    }
+
+    #[test]
+    fn random_with_should_not_depend_on_the_backend_seed() {
+        let device = Default::default();
+        let distribution = Distribution::Uniform(-1.0, 1.0);
+
+        TestBackend::seed(1);
+        let tensor_1 =
+            TestTensor::<1>::random_with([20], distribution, &mut Generator::new(3), &device);
+        TestBackend::seed(2);
+        let tensor_2 =
+            TestTensor::<1>::random_with([20], distribution, &mut Generator::new(3), &device);
+
+        let data = tensor_1.into_data();
+        data.assert_eq(&tensor_2.into_data(), true);
+        data.assert_within_range_inclusive(-1.0..=1.0);
+    }
 }
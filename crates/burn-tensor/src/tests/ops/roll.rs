@@ -0,0 +1,54 @@
+#[burn_tensor_testgen::testgen(roll)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_roll_last_dim() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let output = tensor.roll([1], [-1]);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[3.0, 1.0, 2.0], [6.0, 4.0, 5.0]]), false);
+    }
+
+    #[test]
+    fn should_roll_multiple_dims_with_negative_shifts() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..9, &device).reshape([3, 3]);
+
+        let output = tensor.roll([-1, 2], [0, 1]);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[4, 5, 3], [7, 8, 6], [1, 2, 0]]), false);
+    }
+
+    #[test]
+    fn should_roll_dim_with_shift_larger_than_size() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..4, &device);
+
+        let output = tensor.clone().roll_dim(5, 0);
+        let unchanged = tensor.roll_dim(-8, 0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([3, 0, 1, 2]), false);
+        unchanged
+            .into_data()
+            .assert_eq(&TensorData::from([0, 1, 2, 3]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_rolling_out_of_bounds_dim() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..4, &device);
+
+        let _output = tensor.roll_dim(1, 1);
+    }
+}
@@ -0,0 +1,166 @@
+#[burn_tensor_testgen::testgen(scatter_reduce)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Reduce, TensorData};
+
+    fn scatter_reduce(reduce: Reduce, include_self: bool) -> TestTensor<2> {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let values = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let indices = TestTensorInt::from_ints([[0, 0, 2], [1, 1, 1]], &device);
+
+        tensor.scatter_reduce(1, indices, values, reduce, include_self)
+    }
+
+    #[test]
+    fn should_scatter_reduce_sum() {
+        let output = scatter_reduce(Reduce::Sum, true);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[3.0, 1.0, 5.0], [3.0, 19.0, 5.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_scatter_reduce_sum_without_self() {
+        let output = scatter_reduce(Reduce::Sum, false);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[3.0, 1.0, 3.0], [3.0, 15.0, 5.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_scatter_reduce_prod() {
+        let output = scatter_reduce(Reduce::Prod, true);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[0.0, 1.0, 6.0], [3.0, 480.0, 5.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_scatter_reduce_mean() {
+        let output = scatter_reduce(Reduce::Mean, true);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 1.0, 2.5], [3.0, 4.75, 5.0]]), 3);
+    }
+
+    #[test]
+    fn should_scatter_reduce_mean_without_self() {
+        let output = scatter_reduce(Reduce::Mean, false);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.5, 1.0, 3.0], [3.0, 5.0, 5.0]]), 3);
+    }
+
+    #[test]
+    fn should_scatter_reduce_max() {
+        let output = scatter_reduce(Reduce::Max, true);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2.0, 1.0, 3.0], [3.0, 6.0, 5.0]]), false);
+    }
+
+    #[test]
+    fn should_scatter_reduce_min_without_self() {
+        let output = scatter_reduce(Reduce::Min, false);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 1.0, 3.0], [3.0, 4.0, 5.0]]), false);
+    }
+
+    #[test]
+    fn should_scatter_reduce_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[0, 1, 2], [3, 4, 5]], &device);
+        let values = TestTensorInt::<2>::from_ints([[1, 2, 3], [4, 5, 6]], &device);
+        let indices = TestTensorInt::from_ints([[0, 0, 2], [1, 1, 1]], &device);
+
+        let output = tensor.scatter_reduce(1, indices, values, Reduce::Max, false);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2, 1, 3], [3, 6, 5]]), false);
+    }
+
+    #[test]
+    fn should_scatter_reduce_int_mean() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[0, 1, 2], [3, 4, 5]], &device);
+        let values = TestTensorInt::<2>::from_ints([[1, 2, 3], [4, 5, 6]], &device);
+        let indices = TestTensorInt::from_ints([[0, 0, 2], [1, 1, 1]], &device);
+
+        let output = tensor.scatter_reduce(1, indices, values, Reduce::Mean, true);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 1, 2], [3, 4, 5]]), false);
+    }
+
+    #[test]
+    fn should_index_put() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::ones([2, 3], &device);
+        let rows = TestTensorInt::from_ints([0, 1], &device);
+        let columns = TestTensorInt::from_ints([2, 0], &device);
+        let values = TestTensor::<1>::from_floats([5.0, 7.0], &device);
+
+        let output = tensor.index_put([rows, columns], values, false);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 1.0, 5.0], [7.0, 1.0, 1.0]]), false);
+    }
+
+    #[test]
+    fn should_index_put_accumulate() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::ones([2, 3], &device);
+        let rows = TestTensorInt::from_ints([0, 1, 0], &device);
+        let columns = TestTensorInt::from_ints([2, 0, 2], &device);
+        let values = TestTensor::<1>::from_floats([5.0, 7.0, 1.0], &device);
+
+        let output = tensor.index_put([rows, columns], values, true);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 1.0, 7.0], [8.0, 1.0, 1.0]]), false);
+    }
+
+    #[test]
+    fn should_index_put_leading_dims() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::ones([2, 2, 2], &device);
+        let first = TestTensorInt::from_ints([1], &device);
+        let second = TestTensorInt::from_ints([0], &device);
+        let values = TestTensor::<2>::from_floats([[3.0, 4.0]], &device);
+
+        let output = tensor.index_put([first, second], values, false);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[[1.0, 1.0], [1.0, 1.0]], [[3.0, 4.0], [1.0, 1.0]]]),
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_index_put_when_values_have_wrong_rank() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::ones([2, 2, 2], &device);
+        let first = TestTensorInt::from_ints([1], &device);
+        let second = TestTensorInt::from_ints([0], &device);
+        let values = TestTensor::<1>::from_floats([3.0], &device);
+
+        let _output = tensor.index_put([first, second], values, false);
+    }
+}
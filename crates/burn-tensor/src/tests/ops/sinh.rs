@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(sinh)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_support_sinh_ops() {
+        let data = TensorData::from([[-3.0, -0.5, 0.0], [0.001, 1.0, 4.0]]);
+        let tensor = TestTensor::<2>::from_data(data, &Default::default());
+
+        let output = tensor.sinh();
+        let expected = TensorData::from([[-10.0179, -0.521095, 0.0], [0.001, 1.1752, 27.2899]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
@@ -1,7 +1,9 @@
 #[burn_tensor_testgen::testgen(slice)]
 mod tests {
     use super::*;
-    use burn_tensor::{as_type, Int, Tensor, TensorData};
+    use burn_tensor::{
+        as_type, s, slice_assign_with_steps, slice_with_steps, Int, Slice, Tensor, TensorData,
+    };
 
     #[test]
     fn should_support_full_sliceing_1d() {
@@ -211,6 +213,143 @@ mod tests {
         output.into_data().assert_eq(&tensor.into_data(), true);
     }
 
+    #[test]
+    fn should_slice_with_steps() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..12, &device).reshape([3, 4]);
+
+        let output = tensor.slice(s![.., ..;2]);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 2], [4, 6], [8, 10]]), false);
+    }
+
+    #[test]
+    fn should_slice_with_negative_steps() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats(
+            [
+                [0.0, 1.0, 2.0, 3.0],
+                [4.0, 5.0, 6.0, 7.0],
+                [8.0, 9.0, 10.0, 11.0],
+            ],
+            &device,
+        );
+
+        let output = tensor.slice(s![..;-1, 1..;2]);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[9.0, 11.0], [5.0, 7.0], [1.0, 3.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_slice_range_with_negative_step_from_its_end() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..10, &device);
+
+        let output = tensor.slice(s![1..8;-3]);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([7, 4, 1]), false);
+    }
+
+    #[test]
+    fn should_slice_index_and_negative_index() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..12, &device).reshape([3, 4]);
+
+        let output = tensor.slice(s![1, -2..]);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[6, 7]]), false);
+    }
+
+    #[test]
+    fn should_slice_assign_with_steps() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([3, 4], &device);
+        let values = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let output = tensor.slice_assign(s![..;2, 1..;2], values);
+
+        output.into_data().assert_eq(
+            &TensorData::from([
+                [0.0, 1.0, 0.0, 2.0],
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 3.0, 0.0, 4.0],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_slice_assign_with_negative_step() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::zeros([5], &device);
+        let values = TestTensorInt::<1>::from_ints([1, 2, 3], &device);
+
+        let output = tensor.slice_assign(s![..;-2], values);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([3, 0, 2, 0, 1]), false);
+    }
+
+    #[test]
+    fn slice_with_steps_fallback_should_match_backend() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..60, &device).reshape([3, 4, 5]);
+        let slices = [
+            Slice::new(0, None, -2),
+            Slice::new(1, None, 1),
+            Slice::new(0, Some(5), 3),
+        ];
+
+        let expected = tensor.clone().slice(slices);
+        let output = TestTensorInt::<3>::from_primitive(slice_with_steps::<TestBackend, Int>(
+            tensor.into_primitive(),
+            &slices,
+        ));
+
+        output.into_data().assert_eq(&expected.into_data(), false);
+    }
+
+    #[test]
+    fn slice_assign_with_steps_fallback_should_match_backend() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..60, &device).reshape([3, 4, 5]);
+        let values = TestTensorInt::<1>::arange(100..112, &device).reshape([2, 3, 2]);
+        let slices = [
+            Slice::new(0, None, -2),
+            Slice::new(1, None, 1),
+            Slice::new(0, Some(5), 3),
+        ];
+
+        let expected = tensor.clone().slice_assign(slices, values.clone());
+        let output =
+            TestTensorInt::<3>::from_primitive(slice_assign_with_steps::<TestBackend, Int>(
+                tensor.into_primitive(),
+                &slices,
+                values.into_primitive(),
+            ));
+
+        output.into_data().assert_eq(&expected.into_data(), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_slice_step_is_zero() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..5, &device);
+
+        let _output = tensor.slice(s![..;0]);
+    }
+
     #[test]
     #[should_panic]
     fn should_panic_when_slice_with_too_many_dimensions() {
@@ -0,0 +1,244 @@
+#[burn_tensor_testgen::testgen(sparse)]
+mod tests {
+    use super::*;
+    use burn_tensor::{SparseLayout, SparseTensor, Tensor, TensorData};
+
+    fn sparse() -> SparseTensor<TestBackend, 2> {
+        let device = Default::default();
+        let indices = TestTensorInt::<2>::from_ints([[1, 0, 2, 1], [2, 1, 0, 0]], &device);
+        let values = TestTensor::<1>::from_floats([3.0, 1.0, 4.0, 2.0], &device);
+
+        SparseTensor::from_coo(indices, values, [3, 3])
+    }
+
+    #[test]
+    fn should_convert_coo_to_dense() {
+        let output = sparse().into_dense();
+
+        output.into_data().assert_eq(
+            &TensorData::from([[0.0, 1.0, 0.0], [2.0, 0.0, 3.0], [4.0, 0.0, 0.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_sum_duplicate_indices() {
+        let device = Default::default();
+        let indices = TestTensorInt::<2>::from_ints([[0, 1, 0], [1, 0, 1]], &device);
+        let values = TestTensor::<1>::from_floats([1.0, 2.0, 5.0], &device);
+
+        let output = SparseTensor::<TestBackend, 2>::from_coo(indices, values, [2, 2]).into_dense();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 6.0], [2.0, 0.0]]), false);
+    }
+
+    #[test]
+    fn should_convert_dense_to_coo() {
+        let tensor = TestTensor::<3>::from_floats(
+            [[[0.0, 1.5], [0.0, 0.0]], [[-2.0, 0.0], [0.0, 3.0]]],
+            &Default::default(),
+        );
+
+        let sparse = SparseTensor::from_dense(tensor.clone());
+
+        assert_eq!(sparse.layout(), SparseLayout::Coo);
+        assert_eq!(sparse.nnz(), 3);
+        sparse
+            .indices()
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 1], [0, 0, 1], [1, 0, 1]]), false);
+        sparse
+            .values()
+            .into_data()
+            .assert_eq(&TensorData::from([1.5, -2.0, 3.0]), false);
+        sparse
+            .into_dense()
+            .into_data()
+            .assert_eq(&tensor.into_data(), false);
+    }
+
+    #[test]
+    fn should_convert_coo_to_csr() {
+        let sparse = sparse().into_csr();
+        let (crow_indices, col_indices) = sparse.csr_indices();
+
+        assert_eq!(sparse.layout(), SparseLayout::Csr);
+        crow_indices
+            .into_data()
+            .assert_eq(&TensorData::from([0, 1, 3, 4]), false);
+        col_indices
+            .into_data()
+            .assert_eq(&TensorData::from([1, 0, 2, 0]), false);
+        sparse
+            .values()
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 2.0, 3.0, 4.0]), false);
+    }
+
+    #[test]
+    fn should_convert_csr_to_coo_and_dense() {
+        let device = Default::default();
+        let sparse = SparseTensor::<TestBackend, 2>::from_csr(
+            TestTensorInt::from_ints([0, 2, 2, 3], &device),
+            TestTensorInt::from_ints([0, 3, 1], &device),
+            TestTensor::from_floats([5.0, 6.0, 7.0], &device),
+            [3, 4],
+        );
+
+        sparse
+            .indices()
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 0, 2], [0, 3, 1]]), false);
+        sparse.clone().into_dense().into_data().assert_eq(
+            &TensorData::from([
+                [5.0, 0.0, 0.0, 6.0],
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 7.0, 0.0, 0.0],
+            ]),
+            false,
+        );
+        assert_eq!(sparse.into_coo().layout(), SparseLayout::Coo);
+    }
+
+    #[test]
+    fn should_support_spmm() {
+        let dense = TestTensor::<2>::from_floats(
+            [[1.0, -1.0], [2.0, 0.5], [3.0, 2.0]],
+            &Default::default(),
+        );
+        let expected = sparse().into_dense().matmul(dense.clone()).into_data();
+
+        let output_coo = sparse().spmm(dense.clone());
+        let output_csr = sparse().into_csr().spmm(dense);
+
+        output_coo.into_data().assert_approx_eq(&expected, 5);
+        output_csr.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_support_value_ops() {
+        let output = sparse()
+            .map_values(|values| (-values.mul_scalar(2.0)).abs().powf_scalar(2.0))
+            .into_dense();
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[0.0, 4.0, 0.0], [16.0, 0.0, 36.0], [64.0, 0.0, 0.0]]),
+            5,
+        );
+    }
+
+    #[test]
+    fn should_support_mul_dense_and_add() {
+        let dense = TestTensor::<2>::from_floats(
+            [[9.0, 2.0, 9.0], [3.0, 9.0, -1.0], [0.5, 9.0, 9.0]],
+            &Default::default(),
+        );
+
+        let product = sparse().mul_dense(dense);
+        let output = product.clone().add_sparse(sparse()).into_dense();
+
+        assert_eq!(product.nnz(), 4);
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[0.0, 3.0, 0.0], [8.0, 0.0, 0.0], [6.0, 0.0, 0.0]]),
+            5,
+        );
+    }
+
+    #[test]
+    fn should_support_transpose() {
+        let output = sparse().into_csr().transpose();
+
+        assert_eq!(output.dims(), [3, 3]);
+        output
+            .into_dense()
+            .into_data()
+            .assert_eq(&sparse().into_dense().transpose().into_data(), false);
+    }
+
+    #[test]
+    fn should_support_basic_ops_on_indices() {
+        let dense = sparse().into_dense();
+
+        let reshaped = sparse().reshape([1, 9]);
+        let flipped = sparse().flip([0, 1]);
+        let permuted = sparse().permute([1, 0]);
+        let concatenated = Tensor::cat(vec![sparse(), sparse().into_csr()], 1);
+
+        assert_eq!(reshaped.nnz(), 4);
+        reshaped
+            .into_dense()
+            .into_data()
+            .assert_eq(&dense.clone().reshape([1, 9]).into_data(), false);
+        flipped
+            .into_dense()
+            .into_data()
+            .assert_eq(&dense.clone().flip([0, 1]).into_data(), false);
+        permuted
+            .into_dense()
+            .into_data()
+            .assert_eq(&dense.clone().permute([1, 0]).into_data(), false);
+        concatenated.into_dense().into_data().assert_eq(
+            &Tensor::cat(vec![dense.clone(), dense], 1).into_data(),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_read_sparse_data_as_dense() {
+        let data = TensorData::from([[0.0, 1.0, 0.0], [2.0, 0.0, 3.0], [4.0, 0.0, 0.0]]);
+
+        let sparse = SparseTensor::<TestBackend, 2>::from_data(data.clone(), &Default::default());
+
+        assert_eq!(sparse.nnz(), 4);
+        sparse.to_data().assert_eq(&data, false);
+        sparse
+            .slice([1..3, 0..2])
+            .into_data()
+            .assert_eq(&TensorData::from([[2.0, 0.0], [4.0, 0.0]]), false);
+    }
+
+    #[test]
+    fn should_support_empty_sparse_matrices() {
+        let device = Default::default();
+        let sparse = SparseTensor::<TestBackend, 2>::from_coo(
+            TestTensorInt::<2>::empty([2, 0], &device),
+            TestTensor::<1>::empty([0], &device),
+            [2, 3],
+        );
+        let dense = TestTensor::<2>::ones([3, 2], &device);
+
+        let output = sparse.clone().spmm(dense.clone());
+        let csr = sparse.into_csr();
+        let (crow_indices, _) = csr.csr_indices();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 0.0], [0.0, 0.0]]), false);
+        crow_indices
+            .into_data()
+            .assert_eq(&TensorData::from([0, 0, 0]), false);
+        csr.clone()
+            .spmm(dense)
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 0.0], [0.0, 0.0]]), false);
+        csr.into_dense()
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0; 3]; 2]), false);
+    }
+
+    #[test]
+    fn should_support_sparse_matrices_without_rows() {
+        let device = Default::default();
+        let sparse = SparseTensor::<TestBackend, 2>::from_csr(
+            TestTensorInt::from_ints([0], &device),
+            TestTensorInt::empty([0], &device),
+            TestTensor::empty([0], &device),
+            [0, 3],
+        );
+
+        assert_eq!(sparse.indices().dims(), [2, 0]);
+        assert_eq!(sparse.into_dense().dims(), [0, 3]);
+    }
+}
@@ -0,0 +1,68 @@
+#[burn_tensor_testgen::testgen(take)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_take_with_2d_indices() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+        let indices = TestTensorInt::<2>::from_ints([[0, 2], [1, 1]], &device);
+
+        let output: TestTensor<3> = tensor.take(0, indices);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[[1.0, 2.0], [5.0, 6.0]], [[3.0, 4.0], [3.0, 4.0]]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_take_last_dim() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..6, &device).reshape([2, 3]);
+        let indices = TestTensorInt::<2>::from_ints([[2, 0]], &device);
+
+        let output: TestTensorInt<3> = tensor.take(1, indices);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[[2, 0]], [[5, 3]]]), false);
+    }
+
+    #[test]
+    fn should_take_along_dim_with_argmax() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 5.0, 3.0], [4.0, 2.0, 6.0]], &device);
+        let indices = tensor.clone().argmax(1);
+
+        let output = tensor.take_along_dim(indices, 1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[5.0], [6.0]]), false);
+    }
+
+    #[test]
+    fn should_take_along_dim_with_broadcast() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0]], &device);
+        let indices = TestTensorInt::<2>::from_ints([[2, 0], [1, 1]], &device);
+
+        let output = tensor.take_along_dim(indices, 1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[3.0, 1.0], [2.0, 2.0]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_take_along_dim_when_shapes_cant_broadcast() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([2, 3], &device);
+        let indices = TestTensorInt::<2>::zeros([3, 1], &device);
+
+        let _output = tensor.take_along_dim(indices, 1);
+    }
+}
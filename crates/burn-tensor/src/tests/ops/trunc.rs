@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(trunc)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_support_trunc_ops() {
+        let data = TensorData::from([[-2.7, -1.5, -0.2], [0.0, 1.5, 2.9]]);
+        let tensor = TestTensor::<2>::from_data(data, &Default::default());
+
+        let output = tensor.trunc();
+        let expected = TensorData::from([[-2.0, -1.0, 0.0], [0.0, 1.0, 2.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
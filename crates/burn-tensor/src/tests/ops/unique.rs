@@ -0,0 +1,162 @@
+#[burn_tensor_testgen::testgen(unique)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_return_sorted_unique_elements() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[3.0, 1.0, 3.0], [2.0, 1.0, 5.0]], &device);
+
+        let output = tensor.unique();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 2.0, 3.0, 5.0]), false);
+    }
+
+    #[test]
+    fn should_return_unique_with_inverse_and_counts() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[4, -1, 4], [0, 4, -1]], &device);
+
+        let (values, inverse, counts) = tensor.unique_with_inverse_and_counts();
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([-1, 0, 4]), false);
+        inverse
+            .into_data()
+            .assert_eq(&TensorData::from([[2, 0, 2], [1, 2, 0]]), false);
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([2, 1, 3]), false);
+    }
+
+    #[test]
+    fn should_count_values() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::from_ints([1, 3, 1, 0, 1], &device);
+
+        let output = tensor.bincount(0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 3, 0, 1]), false);
+    }
+
+    #[test]
+    fn should_count_values_with_min_length() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::from_ints([2, 0], &device);
+
+        let output = tensor.bincount(5);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 0, 1, 0, 0]), false);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-negative")]
+    fn should_panic_when_counting_negative_values() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::from_ints([1, -2, 0], &device);
+
+        let _output = tensor.bincount(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-negative")]
+    fn should_panic_when_the_bincount_length_has_negative_values() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::from_ints([1, -2, 0], &device);
+
+        let _length = burn_tensor::bincount_length::<TestBackend>(tensor.into_primitive(), 0);
+    }
+
+    #[test]
+    fn should_sum_weights_of_values() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::from_ints([1, 3, 1, 0], &device);
+        let weights = TestTensor::<1>::from_floats([0.5, 2.0, 1.5, -1.0], &device);
+
+        let output = tensor.bincount_weighted(weights, 0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([-1.0, 2.0, 0.0, 2.0]), false);
+    }
+
+    #[test]
+    fn should_compute_histogram() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0, 1.0], [4.0, -1.0, 3.5]], &device);
+
+        let output = tensor.histc(4, 0.0, 4.0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([0.0, 2.0, 1.0, 2.0]), false);
+    }
+
+    #[test]
+    fn should_compute_histogram_with_tensor_bounds() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([1.0, 2.0, 1.0, 5.0], &device);
+
+        let (counts, edges) = tensor.histogram(2, 0.0, 0.0);
+
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([3.0, 1.0]), false);
+        edges
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 3.0, 5.0]), false);
+    }
+
+    #[test]
+    fn should_search_sorted_left_and_right() {
+        let device = Default::default();
+        let sorted = TestTensor::<1>::from_floats([1.0, 3.0, 3.0, 7.0], &device);
+        let values = TestTensor::<1>::from_floats([0.0, 3.0, 5.0, 9.0], &device);
+
+        let left = sorted.clone().searchsorted(values.clone(), false);
+        let right = sorted.searchsorted(values, true);
+
+        left.into_data()
+            .assert_eq(&TensorData::from([0, 1, 3, 4]), false);
+        right
+            .into_data()
+            .assert_eq(&TensorData::from([0, 3, 3, 4]), false);
+    }
+
+    #[test]
+    fn should_search_sorted_per_row() {
+        let device = Default::default();
+        let sorted = TestTensorInt::<2>::from_ints([[1, 2, 3], [10, 20, 30]], &device);
+        let values = TestTensorInt::<2>::from_ints([[2, 4], [5, 25]], &device);
+
+        let output = sorted.searchsorted(values, false);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 3], [0, 2]]), false);
+    }
+
+    #[test]
+    fn should_bucketize() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.5, 1.0], [2.5, 4.0]], &device);
+        let boundaries = TestTensor::<1>::from_floats([1.0, 2.0, 3.0], &device);
+
+        let left = tensor.clone().bucketize(boundaries.clone(), false);
+        let right = tensor.bucketize(boundaries, true);
+
+        left.into_data()
+            .assert_eq(&TensorData::from([[0, 0], [2, 3]]), false);
+        right
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1], [2, 3]]), false);
+    }
+}
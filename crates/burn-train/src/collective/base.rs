@@ -0,0 +1,53 @@
+use burn_core::tensor::{backend::Backend, Tensor};
+
+/// The reduction applied by an [all-reduce](Collective::all_reduce) operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReduceOp {
+    /// Sum the tensors of all participants.
+    Sum,
+    /// Average the tensors of all participants.
+    Mean,
+}
+
+/// Collective operations between multiple participants, where each participant is identified by
+/// its rank.
+///
+/// Every participant must call the same operations in the same order, each call blocking until
+/// all participants reached it.
+pub trait Collective: Send {
+    /// The rank of the current participant, between zero and the [world size](Collective::world_size).
+    fn rank(&self) -> usize;
+
+    /// The number of participants.
+    fn world_size(&self) -> usize;
+
+    /// Reduce the tensors of all participants, every participant receiving the result on the device
+    /// of its own tensor.
+    fn all_reduce<B: Backend, const D: usize>(
+        &self,
+        tensor: Tensor<B, D>,
+        op: ReduceOp,
+    ) -> Tensor<B, D>;
+
+    /// Reduce the tensors of all participants, only the `root` participant receiving the result on
+    /// the device of its own tensor.
+    fn reduce<B: Backend, const D: usize>(
+        &self,
+        tensor: Tensor<B, D>,
+        root: usize,
+        op: ReduceOp,
+    ) -> Option<Tensor<B, D>>;
+
+    /// Send the tensor of the `root` participant to all participants.
+    ///
+    /// The tensors given by the other participants are only used to know on which device the
+    /// result should be returned.
+    fn broadcast<B: Backend, const D: usize>(
+        &self,
+        tensor: Tensor<B, D>,
+        root: usize,
+    ) -> Tensor<B, D>;
+
+    /// Collect the tensors of all participants, ordered by rank, on the device of the given tensor.
+    fn all_gather<B: Backend, const D: usize>(&self, tensor: Tensor<B, D>) -> Vec<Tensor<B, D>>;
+}
@@ -0,0 +1,345 @@
+use std::any::Any;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+
+use burn_core::tensor::{backend::Backend, Tensor};
+
+use super::{Collective, ReduceOp};
+
+/// [Collective](Collective) operations between threads of the same process, normally one thread
+/// per device.
+///
+/// Tensors are exchanged in memory and moved to the device of each participant.
+///
+/// When a participant panics, the collective is poisoned and every other participant panics
+/// at its next collective operation instead of waiting forever for the failed one.
+#[derive(Clone)]
+pub struct LocalCollective {
+    rank: usize,
+    world_size: usize,
+    state: Arc<ExchangeState>,
+}
+
+struct ExchangeState {
+    round: Mutex<Round>,
+    condvar: Condvar,
+}
+
+struct Round {
+    items: Vec<Option<Box<dyn Any + Send>>>,
+    /// The number of participants that still have to read the items of the current round.
+    remaining: usize,
+    /// True when all participants provided their item for the current round.
+    complete: bool,
+    /// True when a participant panicked, so the current round can never complete.
+    poisoned: bool,
+}
+
+impl LocalCollective {
+    /// Create the participants of a new collective, one for each rank.
+    pub fn new(world_size: usize) -> Vec<Self> {
+        assert!(
+            world_size > 0,
+            "A collective needs at least one participant."
+        );
+
+        let state = Arc::new(ExchangeState {
+            round: Mutex::new(Round {
+                items: (0..world_size).map(|_| None).collect(),
+                remaining: 0,
+                complete: false,
+                poisoned: false,
+            }),
+            condvar: Condvar::new(),
+        });
+
+        (0..world_size)
+            .map(|rank| Self {
+                rank,
+                world_size,
+                state: state.clone(),
+            })
+            .collect()
+    }
+
+    /// Give an item for the current round and returns the items of all participants once they
+    /// all provided theirs.
+    fn exchange<T: Clone + Send + 'static>(&self, item: T) -> Vec<T> {
+        let mut round = self.lock();
+
+        // Wait for all participants to read the items of the previous round.
+        while round.complete && !round.poisoned {
+            round = self.wait(round);
+        }
+        Self::check_poisoned(&round);
+
+        round.items[self.rank] = Some(Box::new(item));
+        round.remaining += 1;
+
+        if round.remaining == self.world_size {
+            round.complete = true;
+            self.state.condvar.notify_all();
+        } else {
+            while !round.complete && !round.poisoned {
+                round = self.wait(round);
+            }
+            Self::check_poisoned(&round);
+        }
+
+        let items = round
+            .items
+            .iter()
+            .map(|item| {
+                item.as_ref()
+                    .and_then(|item| item.downcast_ref::<T>())
+                    .expect("All participants should call the same collective operation.")
+                    .clone()
+            })
+            .collect();
+
+        round.remaining -= 1;
+
+        if round.remaining == 0 {
+            round.items.iter_mut().for_each(|item| *item = None);
+            round.complete = false;
+            self.state.condvar.notify_all();
+        }
+
+        items
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Round> {
+        // The state is only poisoned by a panic of another participant, which is handled with the
+        // poisoned flag of the round.
+        self.state
+            .round
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn wait<'a>(&self, round: MutexGuard<'a, Round>) -> MutexGuard<'a, Round> {
+        self.state
+            .condvar
+            .wait(round)
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn check_poisoned(round: &Round) {
+        if round.poisoned {
+            panic!("A participant of the collective panicked.");
+        }
+    }
+
+    fn reduce_tensors<B: Backend, const D: usize>(
+        &self,
+        tensors: Vec<Tensor<B, D>>,
+        op: ReduceOp,
+    ) -> Tensor<B, D> {
+        // Tensors are summed in the same order by every participant to get identical results.
+        let mut tensors = tensors.into_iter();
+        let mut output = tensors.next().unwrap();
+
+        for tensor in tensors {
+            output = output.add(tensor);
+        }
+
+        match op {
+            ReduceOp::Sum => output,
+            ReduceOp::Mean => output.div_scalar(self.world_size as f64),
+        }
+    }
+}
+
+impl Drop for LocalCollective {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            let mut round = self.lock();
+            round.poisoned = true;
+            self.state.condvar.notify_all();
+        }
+    }
+}
+
+impl Collective for LocalCollective {
+    fn rank(&self) -> usize {
+        self.rank
+    }
+
+    fn world_size(&self) -> usize {
+        self.world_size
+    }
+
+    fn all_reduce<B: Backend, const D: usize>(
+        &self,
+        tensor: Tensor<B, D>,
+        op: ReduceOp,
+    ) -> Tensor<B, D> {
+        self.reduce_tensors(self.all_gather(tensor), op)
+    }
+
+    fn reduce<B: Backend, const D: usize>(
+        &self,
+        tensor: Tensor<B, D>,
+        root: usize,
+        op: ReduceOp,
+    ) -> Option<Tensor<B, D>> {
+        let device = tensor.device();
+        let tensors = self.exchange(tensor);
+
+        if self.rank != root {
+            return None;
+        }
+
+        let tensors = tensors
+            .into_iter()
+            .map(|tensor| tensor.to_device(&device))
+            .collect();
+
+        Some(self.reduce_tensors(tensors, op))
+    }
+
+    fn broadcast<B: Backend, const D: usize>(
+        &self,
+        tensor: Tensor<B, D>,
+        root: usize,
+    ) -> Tensor<B, D> {
+        let device = tensor.device();
+        let mut tensors = self.exchange(tensor);
+
+        tensors.swap_remove(root).to_device(&device)
+    }
+
+    fn all_gather<B: Backend, const D: usize>(&self, tensor: Tensor<B, D>) -> Vec<Tensor<B, D>> {
+        let device = tensor.device();
+
+        self.exchange(tensor)
+            .into_iter()
+            .map(|tensor| tensor.to_device(&device))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::tensor::TensorData;
+    use std::thread;
+
+    #[test]
+    fn all_reduce_should_sum_and_average() {
+        let outputs = run(3, |collective| {
+            let device = Default::default();
+            let tensor =
+                Tensor::<TestBackend, 1>::from_floats([collective.rank() as f32, 1.0], &device);
+
+            let sum = collective.all_reduce(tensor.clone(), ReduceOp::Sum);
+            let mean = collective.all_reduce(tensor, ReduceOp::Mean);
+
+            (sum.into_data(), mean.into_data())
+        });
+
+        for (sum, mean) in outputs {
+            sum.assert_eq(&TensorData::from([3.0f32, 3.0]), false);
+            mean.assert_eq(&TensorData::from([1.0f32, 1.0]), false);
+        }
+    }
+
+    #[test]
+    fn reduce_should_only_return_result_to_root() {
+        let outputs = run(3, |collective| {
+            let device = Default::default();
+            let tensor = Tensor::<TestBackend, 1>::from_floats([collective.rank() as f32], &device);
+
+            collective
+                .reduce(tensor, 1, ReduceOp::Sum)
+                .map(|tensor| tensor.into_data())
+        });
+
+        assert!(outputs[0].is_none());
+        assert!(outputs[2].is_none());
+        outputs[1]
+            .as_ref()
+            .unwrap()
+            .assert_eq(&TensorData::from([3.0f32]), false);
+    }
+
+    #[test]
+    fn broadcast_should_return_root_tensor() {
+        let outputs = run(4, |collective| {
+            let device = Default::default();
+            let tensor = Tensor::<TestBackend, 1>::from_floats([collective.rank() as f32], &device);
+
+            collective.broadcast(tensor, 2).into_data()
+        });
+
+        for output in outputs {
+            output.assert_eq(&TensorData::from([2.0f32]), false);
+        }
+    }
+
+    #[test]
+    fn all_gather_should_be_ordered_by_rank() {
+        let outputs = run(3, |collective| {
+            let device = Default::default();
+            let tensor = Tensor::<TestBackend, 1>::from_floats([collective.rank() as f32], &device);
+
+            // Many rounds to make sure participants don't mix them up.
+            let mut gathered = Vec::new();
+            for _ in 0..10 {
+                gathered = collective.all_gather(tensor.clone());
+            }
+
+            gathered
+                .into_iter()
+                .map(|tensor| tensor.into_scalar())
+                .collect::<Vec<_>>()
+        });
+
+        for output in outputs {
+            assert_eq!(output, vec![0.0, 1.0, 2.0]);
+        }
+    }
+
+    #[test]
+    fn panic_of_participant_should_poison_collective() {
+        let handles = LocalCollective::new(3)
+            .into_iter()
+            .map(|collective| {
+                thread::spawn(move || {
+                    let device = Default::default();
+                    let tensor = Tensor::<TestBackend, 1>::from_floats([1.0], &device);
+
+                    if collective.rank() == 1 {
+                        panic!("Participant failure.");
+                    }
+
+                    collective.all_reduce(tensor, ReduceOp::Sum)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // The other participants must not wait forever for the failed one.
+        for handle in handles {
+            assert!(handle.join().is_err());
+        }
+    }
+
+    fn run<O, F>(world_size: usize, func: F) -> Vec<O>
+    where
+        O: Send + 'static,
+        F: Fn(LocalCollective) -> O + Send + Clone + 'static,
+    {
+        let handles = LocalCollective::new(world_size)
+            .into_iter()
+            .map(|collective| {
+                let func = func.clone();
+                thread::spawn(move || func(collective))
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    }
+}
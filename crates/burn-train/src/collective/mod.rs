@@ -0,0 +1,7 @@
+mod base;
+mod local;
+mod module;
+
+pub use base::*;
+pub use local::*;
+pub use module::*;
@@ -0,0 +1,285 @@
+use core::marker::PhantomData;
+
+use burn_core::module::{AutodiffModule, Module, ModuleMapper, ModuleVisitor, ParamId};
+use burn_core::optim::{GradientsParams, ParamSharding};
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::Tensor;
+
+use super::{Collective, ReduceOp};
+
+/// Reduce the gradients of the given module between all participants of the collective.
+///
+/// A participant without a gradient for a parameter contributes zeros, but only receives the
+/// reduced gradient of the parameters it has a gradient for.
+pub fn all_reduce_gradients<B, M, C>(
+    grads: GradientsParams,
+    module: &M,
+    collective: &C,
+    op: ReduceOp,
+) -> GradientsParams
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+    C: Collective,
+{
+    let mut visitor = GradientsAllReduce::<B, C>::new(grads, collective, op);
+    module.visit(&mut visitor);
+    visitor.grads
+}
+
+/// Replace the float tensors of the given module with the ones of the `root` participant of the
+/// collective.
+///
+/// The tensors are moved to the devices of the module tensors they replace.
+pub fn broadcast_module<B, M, C>(module: M, collective: &C, root: usize) -> M
+where
+    B: Backend,
+    M: Module<B>,
+    C: Collective,
+{
+    let mut mapper = ModuleBroadcast::<B, C>::new(collective, root);
+    module.map(&mut mapper)
+}
+
+/// Reduce the gradients of the given module between all participants of the collective, each
+/// participant only receiving the gradients of the parameters it owns.
+///
+/// The parameters are partitioned with the [sharding](ParamSharding) of the collective, so the
+/// result can be given to an optimizer configured with the same sharding.
+pub fn reduce_scatter_gradients<B, M, C>(
+    grads: GradientsParams,
+    module: &M,
+    collective: &C,
+    op: ReduceOp,
+) -> GradientsParams
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+    C: Collective,
+{
+    let mut visitor = GradientsReduceScatter::<B, C>::new(grads, collective, op);
+    module.visit(&mut visitor);
+    visitor.output
+}
+
+/// Replace every float tensor of the given module with the one of the participant owning it.
+///
+/// This is used after a sharded optimizer step, so all participants have the updated parameters.
+pub fn gather_sharded_module<B, M, C>(module: M, collective: &C) -> M
+where
+    B: Backend,
+    M: Module<B>,
+    C: Collective,
+{
+    let mut mapper = ShardedModuleGather::<B, C>::new(collective);
+    module.map(&mut mapper)
+}
+
+/// The [sharding](ParamSharding) of the parameters between the participants of the collective.
+pub fn param_sharding<C: Collective>(collective: &C) -> ParamSharding {
+    ParamSharding::new(collective.rank(), collective.world_size())
+}
+
+#[derive(new)]
+struct GradientsAllReduce<'a, B, C> {
+    grads: GradientsParams,
+    collective: &'a C,
+    op: ReduceOp,
+    #[new(default)]
+    phantom: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend, C: Collective> ModuleVisitor<B> for GradientsAllReduce<'_, B, C> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        let grad = self.grads.remove::<B::InnerBackend, D>(id);
+        let has_grad = grad.is_some();
+        let grad = grad.unwrap_or_else(|| tensor.clone().inner().zeros_like());
+
+        let grad = self.collective.all_reduce(grad, self.op);
+
+        if has_grad {
+            self.grads.register::<B::InnerBackend, D>(id, grad);
+        }
+    }
+}
+
+#[derive(new)]
+struct ModuleBroadcast<'a, B, C> {
+    collective: &'a C,
+    root: usize,
+    #[new(default)]
+    phantom: PhantomData<B>,
+}
+
+impl<B: Backend, C: Collective> ModuleMapper<B> for ModuleBroadcast<'_, B, C> {
+    fn map_float<const D: usize>(&mut self, _id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let is_require_grad = tensor.is_require_grad();
+        let tensor = self
+            .collective
+            .broadcast(tensor.set_require_grad(false), self.root);
+
+        tensor.set_require_grad(is_require_grad)
+    }
+}
+
+#[derive(new)]
+struct GradientsReduceScatter<'a, B, C> {
+    grads: GradientsParams,
+    collective: &'a C,
+    op: ReduceOp,
+    #[new(default)]
+    output: GradientsParams,
+    #[new(default)]
+    phantom: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend, C: Collective> ModuleVisitor<B> for GradientsReduceScatter<'_, B, C> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        let grad = self
+            .grads
+            .remove::<B::InnerBackend, D>(id)
+            .unwrap_or_else(|| tensor.clone().inner().zeros_like());
+
+        let owner = param_sharding(self.collective).owner(&id);
+
+        if let Some(grad) = self.collective.reduce(grad, owner, self.op) {
+            self.output.register::<B::InnerBackend, D>(id, grad);
+        }
+    }
+}
+
+#[derive(new)]
+struct ShardedModuleGather<'a, B, C> {
+    collective: &'a C,
+    #[new(default)]
+    phantom: PhantomData<B>,
+}
+
+impl<B: Backend, C: Collective> ModuleMapper<B> for ShardedModuleGather<'_, B, C> {
+    fn map_float<const D: usize>(&mut self, id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let owner = param_sharding(self.collective).owner(&id);
+        let is_require_grad = tensor.is_require_grad();
+        let tensor = self
+            .collective
+            .broadcast(tensor.set_require_grad(false), owner);
+
+        tensor.set_require_grad(is_require_grad)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collective::LocalCollective;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::tensor::TensorData;
+    use burn_core::test_utils::Fill;
+    use std::thread;
+
+    #[test]
+    fn broadcast_module_should_copy_root_weights() {
+        let device = Default::default();
+        let collectives = LocalCollective::new(3);
+
+        let handles = collectives
+            .into_iter()
+            .map(|collective| {
+                let layer: Linear<TestBackend> = LinearConfig::new(4, 4).init(&device);
+                thread::spawn(move || {
+                    let layer = broadcast_module(layer, &collective, 1);
+                    layer.weight.val().into_data()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let weights = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<TensorData>>();
+
+        weights[0].assert_eq(&weights[1], true);
+        weights[2].assert_eq(&weights[1], true);
+    }
+
+    #[test]
+    fn reduce_scatter_gradients_should_only_return_owned_gradients() {
+        let device = Default::default();
+        let layer: Linear<TestAutodiffBackend> = LinearConfig::new(4, 3).init(&device);
+        let collectives = LocalCollective::new(2);
+        let sharding = param_sharding(&collectives[0]);
+
+        let handles = collectives
+            .into_iter()
+            .map(|collective| {
+                let layer = layer.clone();
+                thread::spawn(move || {
+                    let input = Tensor::<_, 2>::ones([2, 4], &layer.devices()[0]);
+                    let output = layer.forward(input.mul_scalar(collective.rank() as f32 + 1.0));
+                    let grads = GradientsParams::from_grads(output.sum().backward(), &layer);
+                    let grads = reduce_scatter_gradients(grads, &layer, &collective, ReduceOp::Sum);
+
+                    (collective.rank(), grads)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            let (rank, grads) = handle.join().unwrap();
+            let weight = grads.get::<TestBackend, 2>(layer.weight.id);
+            let bias = layer.bias.as_ref().unwrap();
+            let bias = grads.get::<TestBackend, 1>(bias.id);
+
+            // Over the batch, the inputs of both participants sum to 2 and 4.
+            assert_eq!(weight.is_some(), sharding.owner(&layer.weight.id) == rank);
+            if let Some(weight) = weight {
+                assert_eq!(weight.dims(), [4, 3]);
+                weight
+                    .into_data()
+                    .assert_eq(&TensorData::from([[6.0f32; 3]; 4]), false);
+            }
+            assert_eq!(
+                bias.is_some(),
+                sharding.owner(&layer.bias.as_ref().unwrap().id) == rank
+            );
+            if let Some(bias) = bias {
+                assert_eq!(bias.dims(), [3]);
+                bias.into_data()
+                    .assert_eq(&TensorData::from([4.0f32; 3]), false);
+            }
+        }
+    }
+
+    #[test]
+    fn gather_sharded_module_should_copy_owned_params() {
+        let device = Default::default();
+        let layer: Linear<TestBackend> = LinearConfig::new(4, 4).init(&device);
+        let collectives = LocalCollective::new(2);
+        let sharding = param_sharding(&collectives[0]);
+
+        let handles = collectives
+            .into_iter()
+            .map(|collective| {
+                // Each participant only updated the parameters it owns.
+                let layer = layer.clone().map(&mut Fill(collective.rank() as f64));
+                thread::spawn(move || gather_sharded_module(layer, &collective))
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            let layer = handle.join().unwrap();
+            let weight = sharding.owner(&layer.weight.id) as f32;
+            let bias = layer.bias.as_ref().unwrap();
+            let bias_value = sharding.owner(&bias.id) as f32;
+
+            layer
+                .weight
+                .val()
+                .into_data()
+                .assert_eq(&TensorData::from([[weight; 4]; 4]), false);
+            bias.val()
+                .into_data()
+                .assert_eq(&TensorData::from([bias_value; 4]), false);
+        }
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
 use burn_core::{
     lr_scheduler::LrScheduler,
     module::{AutodiffModule, Module},
-    optim::Optimizer,
+    optim::{ModelEmaRecord, Optimizer},
     tensor::backend::AutodiffBackend,
 };
 use std::marker::PhantomData;
@@ -25,6 +25,11 @@ pub trait LearnerComponents {
         <Self::Model as Module<Self::Backend>>::Record,
         Self::Backend,
     >;
+    /// The checkpointer used for the exponential moving average of the model.
+    type CheckpointerModelEma: Checkpointer<
+        ModelEmaRecord<Self::Backend, Self::Model>,
+        Self::Backend,
+    >;
     /// The checkpointer used for the optimizer.
     type CheckpointerOptimizer: Checkpointer<
         <Self::Optimizer as Optimizer<Self::Model, Self::Backend>>::Record,
@@ -41,26 +46,28 @@ pub trait LearnerComponents {
 }
 
 /// Concrete type that implements [training components trait](TrainingComponents).
-pub struct LearnerComponentsMarker<B, LR, M, O, CM, CO, CS, EP, S> {
+pub struct LearnerComponentsMarker<B, LR, M, O, CM, CE, CO, CS, EP, S> {
     _backend: PhantomData<B>,
     _lr_scheduler: PhantomData<LR>,
     _model: PhantomData<M>,
     _optimizer: PhantomData<O>,
     _checkpointer_model: PhantomData<CM>,
+    _checkpointer_model_ema: PhantomData<CE>,
     _checkpointer_optim: PhantomData<CO>,
     _checkpointer_scheduler: PhantomData<CS>,
     _event_processor: PhantomData<EP>,
     _strategy: S,
 }
 
-impl<B, LR, M, O, CM, CO, CS, EP, S> LearnerComponents
-    for LearnerComponentsMarker<B, LR, M, O, CM, CO, CS, EP, S>
+impl<B, LR, M, O, CM, CE, CO, CS, EP, S> LearnerComponents
+    for LearnerComponentsMarker<B, LR, M, O, CM, CE, CO, CS, EP, S>
 where
     B: AutodiffBackend,
     LR: LrScheduler,
     M: AutodiffModule<B> + core::fmt::Display + 'static,
     O: Optimizer<M, B>,
     CM: Checkpointer<M::Record, B>,
+    CE: Checkpointer<ModelEmaRecord<B, M>, B>,
     CO: Checkpointer<O::Record, B>,
     CS: Checkpointer<LR::Record<B>, B>,
     EP: EventProcessor + 'static,
@@ -71,6 +78,7 @@ where
     type Model = M;
     type Optimizer = O;
     type CheckpointerModel = CM;
+    type CheckpointerModelEma = CE;
     type CheckpointerOptimizer = CO;
     type CheckpointerLrScheduler = CS;
     type EventProcessor = EP;
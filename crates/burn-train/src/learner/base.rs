@@ -1,11 +1,12 @@
 use crate::checkpoint::{Checkpointer, CheckpointingAction, CheckpointingStrategy};
 use crate::components::LearnerComponents;
+use crate::learner::callback::LearnerCallbacks;
 use crate::learner::EarlyStoppingStrategy;
 use crate::metric::store::EventStoreClient;
-use crate::LearnerSummaryConfig;
+use crate::{LearnerSummaryConfig, ShardingStrategy};
 use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::Module;
-use burn_core::optim::Optimizer;
+use burn_core::optim::{ModelEma, ModelEmaConfig, Optimizer};
 use burn_core::tensor::backend::Backend;
 use burn_core::tensor::Device;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -23,8 +24,11 @@ pub struct Learner<LC: LearnerComponents> {
     pub(crate) grad_accumulation: Option<usize>,
     pub(crate) checkpointer: Option<LearnerCheckpointer<LC>>,
     pub(crate) devices: Vec<<LC::Backend as Backend>::Device>,
+    pub(crate) sharding: ShardingStrategy,
     pub(crate) interrupter: TrainingInterrupter,
     pub(crate) early_stopping: Option<Box<dyn EarlyStoppingStrategy>>,
+    pub(crate) callbacks: LearnerCallbacks<LC>,
+    pub(crate) ema: Option<ModelEmaConfig>,
     pub(crate) event_processor: LC::EventProcessor,
     pub(crate) event_store: Arc<EventStoreClient>,
     pub(crate) summary: Option<LearnerSummaryConfig>,
@@ -33,21 +37,28 @@ pub struct Learner<LC: LearnerComponents> {
 #[derive(new)]
 pub(crate) struct LearnerCheckpointer<LC: LearnerComponents> {
     model: LC::CheckpointerModel,
+    model_ema: LC::CheckpointerModelEma,
     optim: LC::CheckpointerOptimizer,
     lr_scheduler: LC::CheckpointerLrScheduler,
     strategy: LC::CheckpointerStrategy,
 }
 
 impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
+    /// Apply the checkpointing strategy for the given epoch and returns if a checkpoint was saved.
+    ///
+    /// The exponential moving average of the model, when enabled, is saved next to the trained
+    /// model.
     pub(crate) fn checkpoint(
         &mut self,
         model: &LC::Model,
+        ema: Option<&ModelEma<LC::Backend, LC::Model>>,
         optim: &LC::Optimizer,
         scheduler: &LC::LrScheduler,
         epoch: usize,
         store: &EventStoreClient,
-    ) {
+    ) -> bool {
         let actions = self.strategy.checkpointing(epoch, store);
+        let mut saved = false;
 
         for action in actions {
             match action {
@@ -55,6 +66,9 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
                     self.model
                         .delete(epoch)
                         .expect("Can delete model checkpoint.");
+                    self.model_ema
+                        .delete(epoch)
+                        .expect("Can delete model moving average checkpoint.");
                     self.optim
                         .delete(epoch)
                         .expect("Can delete optimizer checkpoint.");
@@ -66,15 +80,23 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
                     self.model
                         .save(epoch, model.clone().into_record())
                         .expect("Can save model checkpoint.");
+                    if let Some(ema) = ema {
+                        self.model_ema
+                            .save(epoch, ema.to_record())
+                            .expect("Can save model moving average checkpoint.");
+                    }
                     self.optim
                         .save(epoch, optim.to_record())
                         .expect("Can save optimizer checkpoint.");
                     self.lr_scheduler
                         .save(epoch, scheduler.to_record())
                         .expect("Can save learning rate scheduler checkpoint.");
+                    saved = true;
                 }
             }
         }
+
+        saved
     }
 
     pub(crate) fn load_checkpoint(
@@ -105,6 +127,25 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
 
         (model, optim, scheduler)
     }
+
+    /// Restore the exponential moving average of the model, which is left unchanged when it was
+    /// not saved with the checkpoint.
+    pub(crate) fn load_ema_checkpoint(
+        &self,
+        ema: ModelEma<LC::Backend, LC::Model>,
+        device: &Device<LC::Backend>,
+        epoch: usize,
+    ) -> ModelEma<LC::Backend, LC::Model> {
+        match self.model_ema.restore(epoch, device) {
+            Ok(record) => ema.load_record(record),
+            Err(err) => {
+                log::warn!(
+                    "Could not load the model moving average checkpoint, starting a new average: {err:?}"
+                );
+                ema
+            }
+        }
+    }
 }
 
 #[derive(Clone, Default)]
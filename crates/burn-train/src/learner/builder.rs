@@ -16,12 +16,12 @@ use crate::metric::store::{Aggregate, Direction, EventStoreClient, LogEventStore
 use crate::metric::{Adaptor, LossMetric, Metric};
 use crate::renderer::{default_renderer, MetricsRenderer};
 use crate::{
-    ApplicationLoggerInstaller, FileApplicationLoggerInstaller, LearnerCheckpointer,
-    LearnerSummaryConfig,
+    ApplicationLoggerInstaller, FileApplicationLoggerInstaller, LearnerCallback,
+    LearnerCheckpointer, LearnerSummaryConfig, ShardingStrategy,
 };
 use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::AutodiffModule;
-use burn_core::optim::Optimizer;
+use burn_core::optim::{ModelEmaConfig, ModelEmaRecord, Optimizer};
 use burn_core::record::FileRecorder;
 use burn_core::tensor::backend::AutodiffBackend;
 
@@ -41,6 +41,7 @@ where
     #[allow(clippy::type_complexity)]
     checkpointers: Option<(
         AsyncCheckpointer<M::Record, B>,
+        AsyncCheckpointer<ModelEmaRecord<B, M>, B>,
         AsyncCheckpointer<O::Record, B>,
         AsyncCheckpointer<S::Record<B>, B>,
     )>,
@@ -49,6 +50,7 @@ where
     directory: PathBuf,
     grad_accumulation: Option<usize>,
     devices: Vec<B::Device>,
+    sharding: ShardingStrategy,
     renderer: Option<Box<dyn MetricsRenderer + 'static>>,
     metrics: Metrics<T, V>,
     event_store: LogEventStore,
@@ -57,6 +59,8 @@ where
     num_loggers: usize,
     checkpointer_strategy: Box<dyn CheckpointingStrategy>,
     early_stopping: Option<Box<dyn EarlyStoppingStrategy>>,
+    callbacks: Vec<Box<dyn LearnerCallback<M, T>>>,
+    ema: Option<ModelEmaConfig>,
     summary_metrics: HashSet<String>,
     summary: bool,
 }
@@ -85,6 +89,7 @@ where
             directory,
             grad_accumulation: None,
             devices: vec![B::Device::default()],
+            sharding: ShardingStrategy::None,
             metrics: Metrics::default(),
             event_store: LogEventStore::default(),
             renderer: None,
@@ -105,6 +110,8 @@ where
                     .build(),
             ),
             early_stopping: None,
+            callbacks: Vec::new(),
+            ema: None,
             summary_metrics: HashSet::new(),
             summary: false,
         }
@@ -218,6 +225,15 @@ where
         self
     }
 
+    /// Partition the parameters and the optimizer state between the devices with the given
+    /// [strategy](ShardingStrategy).
+    ///
+    /// This is only used when training on multiple [devices](Self::devices).
+    pub fn with_sharding(mut self, strategy: ShardingStrategy) -> Self {
+        self.sharding = strategy;
+        self
+    }
+
     /// The epoch from which the training must resume.
     pub fn checkpoint(mut self, checkpoint: usize) -> Self {
         self.checkpoint = Some(checkpoint);
@@ -239,6 +255,31 @@ where
         self
     }
 
+    /// Register a [callback](LearnerCallback) to be notified at different points of the training
+    /// loop.
+    ///
+    /// Callbacks are called in the order they are registered.
+    pub fn with_callback<C>(mut self, callback: C) -> Self
+    where
+        C: LearnerCallback<M, T> + 'static,
+    {
+        self.callbacks.push(Box::new(callback));
+        self
+    }
+
+    /// Keep an [exponential moving average](burn_core::optim::ModelEma) of the model weights,
+    /// updated after each optimizer step.
+    ///
+    /// # Notes
+    ///
+    /// The averaged model is used instead of the trained one for validation and is the model
+    /// returned by [fit](Learner::fit). It is checkpointed separately from the trained model, so
+    /// resuming from a checkpoint continues training the raw weights while restoring the average.
+    pub fn with_model_ema(mut self, config: ModelEmaConfig) -> Self {
+        self.ema = Some(config);
+        self
+    }
+
     /// By default, Rust logs are captured and written into
     /// `experiment.log`. If disabled, standard Rust log handling
     /// will apply.
@@ -262,6 +303,8 @@ where
     {
         let checkpoint_dir = self.directory.join("checkpoint");
         let checkpointer_model = FileCheckpointer::new(recorder.clone(), &checkpoint_dir, "model");
+        let checkpointer_model_ema =
+            FileCheckpointer::new(recorder.clone(), &checkpoint_dir, "model-ema");
         let checkpointer_optimizer =
             FileCheckpointer::new(recorder.clone(), &checkpoint_dir, "optim");
         let checkpointer_scheduler: FileCheckpointer<FR> =
@@ -269,6 +312,7 @@ where
 
         self.checkpointers = Some((
             AsyncCheckpointer::new(checkpointer_model),
+            AsyncCheckpointer::new(checkpointer_model_ema),
             AsyncCheckpointer::new(checkpointer_optimizer),
             AsyncCheckpointer::new(checkpointer_scheduler),
         ));
@@ -301,6 +345,7 @@ where
             M,
             O,
             AsyncCheckpointer<M::Record, B>,
+            AsyncCheckpointer<ModelEmaRecord<B, M>, B>,
             AsyncCheckpointer<O::Record, B>,
             AsyncCheckpointer<S::Record<B>, B>,
             AsyncProcessor<FullEventProcessor<T, V>>,
@@ -335,9 +380,17 @@ where
             event_store.clone(),
         ));
 
-        let checkpointer = self.checkpointers.map(|(model, optim, scheduler)| {
-            LearnerCheckpointer::new(model, optim, scheduler, self.checkpointer_strategy)
-        });
+        let checkpointer = self
+            .checkpointers
+            .map(|(model, model_ema, optim, scheduler)| {
+                LearnerCheckpointer::new(
+                    model,
+                    model_ema,
+                    optim,
+                    scheduler,
+                    self.checkpointer_strategy,
+                )
+            });
 
         let summary = if self.summary {
             Some(LearnerSummaryConfig {
@@ -359,8 +412,11 @@ where
             checkpoint: self.checkpoint,
             grad_accumulation: self.grad_accumulation,
             devices: self.devices,
+            sharding: self.sharding,
             interrupter: self.interrupter,
             early_stopping: self.early_stopping,
+            callbacks: self.callbacks,
+            ema: self.ema,
             summary,
         }
     }
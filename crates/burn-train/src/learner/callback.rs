@@ -0,0 +1,269 @@
+use crate::components::LearnerComponents;
+use crate::metric::processor::EventProcessor;
+use crate::metric::store::EventStoreClient;
+use crate::{TrainOutput, TrainingInterrupter};
+use burn_core::module::Module;
+use burn_core::optim::ModelEma;
+use burn_core::tensor::backend::Backend;
+
+/// The state of the training loop shared with [learner callbacks](LearnerCallback).
+pub struct CallbackContext<'a, M> {
+    /// The model being trained.
+    pub model: &'a mut M,
+    /// The current epoch.
+    pub epoch: usize,
+    /// The total number of epochs.
+    pub epoch_total: usize,
+    /// The current iteration in the epoch, zero outside of a training epoch.
+    pub iteration: usize,
+    interrupter: &'a TrainingInterrupter,
+}
+
+impl<'a, M> CallbackContext<'a, M> {
+    pub(crate) fn new(
+        model: &'a mut M,
+        epoch: usize,
+        epoch_total: usize,
+        iteration: usize,
+        interrupter: &'a TrainingInterrupter,
+    ) -> Self {
+        Self {
+            model,
+            epoch,
+            epoch_total,
+            iteration,
+            interrupter,
+        }
+    }
+
+    /// Request the learner to stop the training loop.
+    ///
+    /// The current step is completed before the learner stops, the same way as when using
+    /// the [training interrupter](TrainingInterrupter).
+    pub fn stop(&self) {
+        self.interrupter.stop();
+    }
+
+    /// True if the training loop is going to stop.
+    pub fn should_stop(&self) -> bool {
+        self.interrupter.should_stop()
+    }
+}
+
+/// Hooks called by the [learner](crate::Learner) at different points of the training loop.
+///
+/// All methods have a default implementation that does nothing, so only the hooks of interest
+/// need to be implemented. Every hook receives a [context](CallbackContext) giving mutable access
+/// to the model and allowing the callback to stop the training.
+///
+/// # Notes
+///
+/// The type `M` is the model being trained and `TO` is the training output returned by the
+/// [train step](crate::TrainStep).
+pub trait LearnerCallback<M, TO> {
+    /// Called once before the first training epoch.
+    fn on_train_begin(&mut self, _ctx: &mut CallbackContext<'_, M>) {}
+
+    /// Called at the beginning of each training epoch.
+    fn on_epoch_start(&mut self, _ctx: &mut CallbackContext<'_, M>) {}
+
+    /// Called after the forward and backward passes of each training batch, before the gradients
+    /// are given to the optimizer.
+    ///
+    /// The gradients of the [output](TrainOutput) can be modified before they are applied.
+    fn on_batch_end(&mut self, _ctx: &mut CallbackContext<'_, M>, _output: &mut TrainOutput<TO>) {}
+
+    /// Called each time the optimizer updated the model.
+    ///
+    /// With gradient accumulation, this is called less often than
+    /// [on_batch_end](LearnerCallback::on_batch_end).
+    fn on_optimizer_step(&mut self, _ctx: &mut CallbackContext<'_, M>) {}
+
+    /// Called at the end of each training epoch, before validation.
+    fn on_epoch_end(&mut self, _ctx: &mut CallbackContext<'_, M>) {}
+
+    /// Called at the end of each validation epoch.
+    ///
+    /// The metrics collected so far can be queried using the [event store](EventStoreClient).
+    fn on_validation_end(&mut self, _ctx: &mut CallbackContext<'_, M>, _store: &EventStoreClient) {}
+
+    /// Called after a checkpoint has been saved for the current epoch.
+    fn on_checkpoint(&mut self, _ctx: &mut CallbackContext<'_, M>) {}
+
+    /// Called once when the training loop is done, even if it was interrupted.
+    fn on_train_end(&mut self, _ctx: &mut CallbackContext<'_, M>) {}
+}
+
+/// The callbacks registered on a learner.
+pub(crate) type LearnerCallbacks<LC> = Vec<
+    Box<
+        dyn LearnerCallback<
+            <LC as LearnerComponents>::Model,
+            <<LC as LearnerComponents>::EventProcessor as EventProcessor>::ItemTrain,
+        >,
+    >,
+>;
+
+impl<M, TO> LearnerCallback<M, TO> for Vec<Box<dyn LearnerCallback<M, TO>>> {
+    fn on_train_begin(&mut self, ctx: &mut CallbackContext<'_, M>) {
+        for callback in self.iter_mut() {
+            callback.on_train_begin(ctx);
+        }
+    }
+
+    fn on_epoch_start(&mut self, ctx: &mut CallbackContext<'_, M>) {
+        for callback in self.iter_mut() {
+            callback.on_epoch_start(ctx);
+        }
+    }
+
+    fn on_batch_end(&mut self, ctx: &mut CallbackContext<'_, M>, output: &mut TrainOutput<TO>) {
+        for callback in self.iter_mut() {
+            callback.on_batch_end(ctx, output);
+        }
+    }
+
+    fn on_optimizer_step(&mut self, ctx: &mut CallbackContext<'_, M>) {
+        for callback in self.iter_mut() {
+            callback.on_optimizer_step(ctx);
+        }
+    }
+
+    fn on_epoch_end(&mut self, ctx: &mut CallbackContext<'_, M>) {
+        for callback in self.iter_mut() {
+            callback.on_epoch_end(ctx);
+        }
+    }
+
+    fn on_validation_end(&mut self, ctx: &mut CallbackContext<'_, M>, store: &EventStoreClient) {
+        for callback in self.iter_mut() {
+            callback.on_validation_end(ctx, store);
+        }
+    }
+
+    fn on_checkpoint(&mut self, ctx: &mut CallbackContext<'_, M>) {
+        for callback in self.iter_mut() {
+            callback.on_checkpoint(ctx);
+        }
+    }
+
+    fn on_train_end(&mut self, ctx: &mut CallbackContext<'_, M>) {
+        for callback in self.iter_mut() {
+            callback.on_train_end(ctx);
+        }
+    }
+}
+
+/// Updates the [exponential moving average](ModelEma) of the model after each optimizer step,
+/// before notifying the registered callbacks.
+pub(crate) struct CallbacksWithEma<'a, C, B: Backend, M: Module<B>> {
+    callbacks: &'a mut C,
+    ema: Option<&'a mut ModelEma<B, M>>,
+}
+
+impl<'a, C, B: Backend, M: Module<B>> CallbacksWithEma<'a, C, B, M> {
+    pub(crate) fn new(callbacks: &'a mut C, ema: Option<&'a mut ModelEma<B, M>>) -> Self {
+        Self { callbacks, ema }
+    }
+}
+
+impl<C, B, M, TO> LearnerCallback<M, TO> for CallbacksWithEma<'_, C, B, M>
+where
+    C: LearnerCallback<M, TO>,
+    B: Backend,
+    M: Module<B>,
+{
+    fn on_train_begin(&mut self, ctx: &mut CallbackContext<'_, M>) {
+        self.callbacks.on_train_begin(ctx);
+    }
+
+    fn on_epoch_start(&mut self, ctx: &mut CallbackContext<'_, M>) {
+        self.callbacks.on_epoch_start(ctx);
+    }
+
+    fn on_batch_end(&mut self, ctx: &mut CallbackContext<'_, M>, output: &mut TrainOutput<TO>) {
+        self.callbacks.on_batch_end(ctx, output);
+    }
+
+    fn on_optimizer_step(&mut self, ctx: &mut CallbackContext<'_, M>) {
+        if let Some(ema) = self.ema.as_mut() {
+            ema.update(ctx.model);
+        }
+        self.callbacks.on_optimizer_step(ctx);
+    }
+
+    fn on_epoch_end(&mut self, ctx: &mut CallbackContext<'_, M>) {
+        self.callbacks.on_epoch_end(ctx);
+    }
+
+    fn on_validation_end(&mut self, ctx: &mut CallbackContext<'_, M>, store: &EventStoreClient) {
+        self.callbacks.on_validation_end(ctx, store);
+    }
+
+    fn on_checkpoint(&mut self, ctx: &mut CallbackContext<'_, M>) {
+        self.callbacks.on_checkpoint(ctx);
+    }
+
+    fn on_train_end(&mut self, ctx: &mut CallbackContext<'_, M>) {
+        self.callbacks.on_train_end(ctx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::optim::GradientsParams;
+
+    #[derive(Default)]
+    struct StopAfter {
+        max_batches: usize,
+        num_batches: usize,
+    }
+
+    impl LearnerCallback<usize, f64> for StopAfter {
+        fn on_batch_end(
+            &mut self,
+            ctx: &mut CallbackContext<'_, usize>,
+            output: &mut TrainOutput<f64>,
+        ) {
+            self.num_batches += 1;
+            *ctx.model += 1;
+            output.item *= 2.0;
+
+            if self.num_batches >= self.max_batches {
+                ctx.stop();
+            }
+        }
+    }
+
+    #[test]
+    fn callbacks_should_all_be_notified_and_stop_training() {
+        let mut callbacks: Vec<Box<dyn LearnerCallback<usize, f64>>> = vec![
+            Box::new(StopAfter {
+                max_batches: 2,
+                ..Default::default()
+            }),
+            Box::new(StopAfter {
+                max_batches: 3,
+                ..Default::default()
+            }),
+        ];
+        let interrupter = TrainingInterrupter::new();
+        let mut model = 0;
+        let mut output = TrainOutput {
+            grads: GradientsParams::new(),
+            item: 1.0,
+        };
+
+        let mut ctx = CallbackContext::new(&mut model, 1, 1, 1, &interrupter);
+        callbacks.on_batch_end(&mut ctx, &mut output);
+        assert!(!ctx.should_stop());
+
+        callbacks.on_batch_end(&mut ctx, &mut output);
+        assert!(ctx.should_stop());
+        assert!(interrupter.should_stop());
+
+        assert_eq!(model, 4);
+        assert_eq!(output.item, 16.0);
+    }
+}
@@ -1,12 +1,18 @@
 use burn_core::{
-    data::dataloader::DataLoader, lr_scheduler::LrScheduler, module::AutodiffModule,
-    optim::GradientsAccumulator, tensor::backend::Backend,
+    data::dataloader::DataLoader,
+    lr_scheduler::LrScheduler,
+    module::{AutodiffModule, Module},
+    optim::{shard_module, GradientsAccumulator},
+    tensor::backend::Backend,
 };
 use std::sync::Arc;
 
+use crate::learner::step::train::scale_gradients;
 use crate::metric::processor::{Event, EventProcessor, LearnerItem};
 use crate::{components::LearnerComponents, learner::base::TrainingInterrupter};
-use crate::{MultiDevicesTrainStep, TrainStep, ValidStep};
+use crate::{
+    CallbackContext, LearnerCallback, MultiDevicesTrainStep, ShardingStrategy, TrainStep, ValidStep,
+};
 
 /// A validation epoch.
 #[derive(new)]
@@ -81,6 +87,7 @@ impl<TI> TrainEpoch<TI> {
     /// * `optim` - The optimizer to use.
     /// * `scheduler` - The learning rate scheduler to use.
     /// * `processor` - The event processor to use.
+    /// * `callbacks` - The callbacks to notify.
     ///
     /// # Returns
     ///
@@ -91,6 +98,7 @@ impl<TI> TrainEpoch<TI> {
         mut optim: LC::Optimizer,
         scheduler: &mut LC::LrScheduler,
         processor: &mut LC::EventProcessor,
+        callbacks: &mut impl LearnerCallback<LC::Model, TO>,
         interrupter: &TrainingInterrupter,
     ) -> (LC::Model, LC::Optimizer)
     where
@@ -110,9 +118,12 @@ impl<TI> TrainEpoch<TI> {
             log::info!("Iteration {}", iteration);
 
             let progress = iterator.progress();
-            let item = model.step(item);
+            let mut item = model.step(item);
 
-            match self.grad_accumulation {
+            let mut ctx = self.callback_context(&mut model, iteration, interrupter);
+            callbacks.on_batch_end(&mut ctx, &mut item);
+
+            let optimized = match self.grad_accumulation {
                 Some(accumulation) => {
                     accumulator.accumulate(&model, item.grads);
                     accumulation_current += 1;
@@ -121,9 +132,20 @@ impl<TI> TrainEpoch<TI> {
                         let grads = accumulator.grads();
                         model = model.optimize(&mut optim, lr, grads);
                         accumulation_current = 0;
+                        true
+                    } else {
+                        false
                     }
                 }
-                None => model = model.optimize(&mut optim, lr, item.grads),
+                None => {
+                    model = model.optimize(&mut optim, lr, item.grads);
+                    true
+                }
+            };
+
+            if optimized {
+                let mut ctx = self.callback_context(&mut model, iteration, interrupter);
+                callbacks.on_optimizer_step(&mut ctx);
             }
 
             let item = LearnerItem::new(
@@ -157,18 +179,23 @@ impl<TI> TrainEpoch<TI> {
     /// * `optim` - The optimizer to use.
     /// * `lr_scheduler` - The learning rate scheduler to use.
     /// * `processor` - The event processor to use.
+    /// * `callbacks` - The callbacks to notify.
     /// * `devices` - The devices to use.
+    /// * `sharding` - How the parameters and the optimizer state are partitioned between devices.
     ///
     /// # Returns
     ///
-    /// The trained model and the optimizer.
+    /// The trained model, on the first device, and the optimizer.
+    #[allow(clippy::too_many_arguments)]
     pub fn run_multi_device<LC: LearnerComponents, TO>(
         &self,
         mut model: LC::Model,
         mut optim: LC::Optimizer,
         lr_scheduler: &mut LC::LrScheduler,
         processor: &mut LC::EventProcessor,
+        callbacks: &mut impl LearnerCallback<LC::Model, TO>,
         devices: Vec<<LC::Backend as Backend>::Device>,
+        sharding: ShardingStrategy,
         interrupter: &TrainingInterrupter,
     ) -> (LC::Model, LC::Optimizer)
     where
@@ -188,12 +215,13 @@ impl<TI> TrainEpoch<TI> {
         let mut accumulator = GradientsAccumulator::new();
         let mut accumulation_current = 0;
 
-        let accumulation = self.grad_accumulation.unwrap_or(1) * devices.len();
-        let step = MultiDevicesTrainStep::new(&devices);
+        let accumulation = self.grad_accumulation.unwrap_or(1);
+        let mut step = MultiDevicesTrainStep::new(&devices, sharding);
 
         // The main device is always the first in the list.
         let device_main = devices.first().expect("A minimum of one device.").clone();
         let mut interrupted = false;
+        let mut lr = 0.0;
 
         loop {
             let items = step.step(&mut iterator, &model);
@@ -201,20 +229,29 @@ impl<TI> TrainEpoch<TI> {
                 break;
             }
 
-            for item in items {
+            // The scheduler follows the optimizer steps, which apply the gradients of many items.
+            if accumulation_current == 0 {
+                lr = lr_scheduler.step();
+            }
+
+            // The gradients are averaged over the items, either all-reduced so that every output
+            // holds the same gradients, or reduce-scattered to the devices owning the parameters
+            // in the first output, so only the first ones are kept.
+            let num_items = items.len();
+            let mut grads = None;
+
+            for mut item in items {
                 iteration += 1;
-                let lr = lr_scheduler.step();
                 let progress = iterator.progress();
 
-                let grads = item.grads.to_device(&device_main, &model);
-
-                accumulator.accumulate(&model, grads);
-                accumulation_current += 1;
+                let mut ctx = self.callback_context(&mut model, iteration, interrupter);
+                callbacks.on_batch_end(&mut ctx, &mut item);
 
-                if accumulation <= accumulation_current {
-                    let grads = accumulator.grads();
-                    model = model.optimize(&mut optim, lr, grads);
-                    accumulation_current = 0;
+                if grads.is_none() {
+                    grads = Some(match sharding {
+                        ShardingStrategy::None => item.grads.to_device(&device_main, &model),
+                        ShardingStrategy::OptimizerState | ShardingStrategy::Params => item.grads,
+                    });
                 }
 
                 let item = LearnerItem::new(
@@ -235,6 +272,42 @@ impl<TI> TrainEpoch<TI> {
                 }
             }
 
+            if let Some(grads) = grads {
+                // Weighted by their number of items, the accumulated gradients are the sum of the
+                // gradients of each item, like when training on a single device.
+                accumulator.accumulate(&model, scale_gradients(grads, &model, num_items as f64));
+                accumulation_current += num_items;
+
+                if accumulation <= accumulation_current {
+                    // The devices may process more items than the accumulation in a single step,
+                    // the extra items only scaling down the applied gradients.
+                    let grads = scale_gradients(
+                        accumulator.grads(),
+                        &model,
+                        accumulation as f64 / accumulation_current as f64,
+                    );
+                    model = match sharding {
+                        ShardingStrategy::None => model.optimize(&mut optim, lr, grads),
+                        ShardingStrategy::OptimizerState | ShardingStrategy::Params => {
+                            // Each parameter is updated on the device owning it, which keeps its
+                            // optimizer state.
+                            let model = shard_module(model, &devices);
+                            let model = model.optimize(&mut optim, lr, grads);
+
+                            match sharding {
+                                ShardingStrategy::Params => model,
+                                _ => model.fork(&device_main),
+                            }
+                        }
+                    };
+                    accumulation_current = 0;
+
+                    let mut ctx = self.callback_context(&mut model, iteration, interrupter);
+                    callbacks.on_optimizer_step(&mut ctx);
+                    step.model_updated();
+                }
+            }
+
             if interrupted {
                 break;
             }
@@ -242,6 +315,21 @@ impl<TI> TrainEpoch<TI> {
 
         processor.process_train(Event::EndEpoch(self.epoch));
 
+        if sharding == ShardingStrategy::Params {
+            model = model.fork(&device_main);
+        }
+
         (model, optim)
     }
 }
+
+impl<TI> TrainEpoch<TI> {
+    fn callback_context<'a, M>(
+        &self,
+        model: &'a mut M,
+        iteration: usize,
+        interrupter: &'a TrainingInterrupter,
+    ) -> CallbackContext<'a, M> {
+        CallbackContext::new(model, self.epoch, self.epoch_total, iteration, interrupter)
+    }
+}
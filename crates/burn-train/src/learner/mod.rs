@@ -1,10 +1,12 @@
 mod application_logger;
 mod base;
 mod builder;
+mod callback;
 mod classification;
 mod early_stopping;
 mod epoch;
 mod regression;
+mod sharding;
 mod step;
 mod summary;
 mod train_val;
@@ -12,10 +14,12 @@ mod train_val;
 pub use application_logger::*;
 pub use base::*;
 pub use builder::*;
+pub use callback::*;
 pub use classification::*;
 pub use early_stopping::*;
 pub use epoch::*;
 pub use regression::*;
+pub use sharding::*;
 pub use step::*;
 pub use summary::*;
 pub use train::*;
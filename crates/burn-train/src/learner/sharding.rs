@@ -0,0 +1,17 @@
+/// How the training state is partitioned between the [devices](crate::LearnerBuilder::devices) of
+/// a training on multiple devices.
+///
+/// Each parameter is owned by one device, given by its [sharding](burn_core::optim::ParamSharding)
+/// with the rank of each device being its position in the list of devices.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ShardingStrategy {
+    /// The parameters and the optimizer state are kept on the first device.
+    #[default]
+    None,
+    /// The optimizer state of each parameter is kept and updated on the device owning it, while
+    /// the parameters are kept on the first device.
+    OptimizerState,
+    /// The parameters are also kept on the device owning them, and are gathered on every device
+    /// for each training step only.
+    Params,
+}
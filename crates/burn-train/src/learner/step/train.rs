@@ -1,19 +1,67 @@
-use crate::{TrainOutput, TrainStep};
+use core::marker::PhantomData;
+
+use crate::collective::{
+    all_reduce_gradients, broadcast_module, reduce_scatter_gradients, LocalCollective, ReduceOp,
+};
+use crate::{ShardingStrategy, TrainOutput, TrainStep};
 use burn_core::{
-    data::dataloader::DataLoaderIterator, module::AutodiffModule, tensor::backend::AutodiffBackend,
+    data::dataloader::DataLoaderIterator,
+    module::{AutodiffModule, ModuleVisitor, ParamId},
+    optim::GradientsParams,
+    tensor::{backend::AutodiffBackend, Tensor},
 };
 use std::sync::mpsc::{Receiver, Sender};
 use std::thread::spawn;
 
 /// Multi devices train step.
+///
+/// Each device keeps a replica of the model, and all devices share the same collective for the
+/// whole training epoch. The weights of the model are broadcast from the first device to the
+/// others only when the model changed since the last step, every device processes its own batch,
+/// and the gradients are averaged between devices with an all-reduce, so all outputs hold the
+/// same gradients.
+///
+/// With a [sharding strategy](ShardingStrategy), the gradients are reduce-scattered instead: each
+/// device only receives the averaged gradients of the parameters it owns, and the first output
+/// holds the gradients of every device, each one staying on the device owning its parameter,
+/// while the other outputs hold none.
+///
+/// When the parameters are [sharded](ShardingStrategy::Params), the devices don't keep a replica:
+/// the parameters are gathered on every device for each step, and released after it.
 pub struct MultiDevicesTrainStep<B: AutodiffBackend, M, TI, TO> {
     workers: Vec<Worker<B, M, TI>>,
-    receiver: Receiver<TrainOutput<TO>>,
+    receiver: Receiver<WorkerOutput<TO>>,
+    sharding: ShardingStrategy,
+    /// True when the replicas have to be synchronized with the model before the next step.
+    outdated: bool,
+    /// True when the workers received a replica of the model.
+    initialized: bool,
 }
 
 struct Message<M, TI> {
-    item: TI,
-    model: M,
+    /// The item to process, none when there are fewer items than devices for this step.
+    item: Option<TI>,
+    /// The number of items processed by all devices for this step.
+    num_items: usize,
+    /// The model used for this step.
+    model: ModelSync<M>,
+}
+
+enum ModelSync<M> {
+    /// Use the replica of the previous step.
+    Replica,
+    /// Synchronize the replica with the one of the first device, the model being given to the
+    /// first device and to the devices without a replica.
+    Broadcast(Option<M>),
+    /// Gather the sharded model on the device, without keeping a replica after the step.
+    Gather(M),
+}
+
+struct WorkerOutput<TO> {
+    /// The output of the item, none when the device didn't have an item for this step.
+    item: Option<TO>,
+    /// The gradients received by the device.
+    grads: GradientsParams,
 }
 
 struct Worker<B: AutodiffBackend, M, TI> {
@@ -26,18 +74,18 @@ where
     B: AutodiffBackend,
     M: AutodiffModule<B>,
 {
-    fn register(&self, item: TI, model: &M) {
-        let message = Message {
-            item,
-            model: model.clone(),
-        };
-        self.sender_input.send(message).unwrap();
+    fn register(&self, message: Message<M, TI>) {
+        self.sender_input
+            .send(message)
+            .expect("The training worker should be running.");
     }
 
     fn start<TO>(
         &self,
-        sender_output: Sender<TrainOutput<TO>>,
+        sender_output: Sender<WorkerOutput<TO>>,
         receiver_input: Receiver<Message<M, TI>>,
+        collective: LocalCollective,
+        sharding: ShardingStrategy,
     ) where
         TI: Send + 'static,
         TO: Send + 'static,
@@ -45,17 +93,55 @@ where
     {
         let device = self.device.clone();
 
-        spawn(move || loop {
-            match receiver_input.recv() {
-                Ok(item) => {
-                    let step = item.model.fork(&device);
-                    let output = step.step(item.item);
+        spawn(move || {
+            let mut replica: Option<M> = None;
 
-                    sender_output.send(output).unwrap();
-                }
-                Err(_err) => {
-                    log::info!("Closing thread on device {:?}", device);
-                    break;
+            loop {
+                match receiver_input.recv() {
+                    Ok(message) => {
+                        let keep_replica = !matches!(message.model, ModelSync::Gather(_));
+                        let model = match message.model {
+                            ModelSync::Replica => {
+                                replica.take().expect("The replica should be created.")
+                            }
+                            ModelSync::Broadcast(model) => {
+                                let model = match model {
+                                    Some(model) => model.fork(&device),
+                                    None => replica.take().expect("The replica should be created."),
+                                };
+                                broadcast_module(model, &collective, 0)
+                            }
+                            ModelSync::Gather(model) => model.fork(&device),
+                        };
+
+                        // Devices without an item contribute zeros to the reduction.
+                        let (item, grads) = match message.item {
+                            Some(item) => {
+                                let output = model.step(item);
+                                (Some(output.item), output.grads)
+                            }
+                            None => (None, GradientsParams::new()),
+                        };
+                        let grads = match sharding {
+                            ShardingStrategy::None => {
+                                all_reduce_gradients(grads, &model, &collective, ReduceOp::Sum)
+                            }
+                            ShardingStrategy::OptimizerState | ShardingStrategy::Params => {
+                                reduce_scatter_gradients(grads, &model, &collective, ReduceOp::Sum)
+                            }
+                        };
+                        let grads = scale_gradients(grads, &model, 1.0 / message.num_items as f64);
+
+                        sender_output.send(WorkerOutput { item, grads }).unwrap();
+
+                        if keep_replica {
+                            replica = Some(model);
+                        }
+                    }
+                    Err(_err) => {
+                        log::info!("Closing thread on device {:?}", device);
+                        break;
+                    }
                 }
             }
         });
@@ -74,25 +160,28 @@ where
     /// # Arguments
     ///
     /// * `devices` - Devices.
+    /// * `sharding` - How the parameters are partitioned between devices.
     ///
     /// # Returns
     ///
     /// MultiDevicesTrainStep instance.
-    pub fn new(devices: &[B::Device]) -> Self
+    pub fn new(devices: &[B::Device], sharding: ShardingStrategy) -> Self
     where
         TI: Send + 'static,
     {
         let (sender_output, receiver_output) = std::sync::mpsc::channel();
+        let collectives = LocalCollective::new(devices.len());
         let workers = devices
             .iter()
-            .map(|device| {
+            .zip(collectives)
+            .map(|(device, collective)| {
                 let (sender_input, receiver_input) = std::sync::mpsc::channel();
                 let worker = Worker {
                     sender_input,
                     device: device.clone(),
                 };
 
-                worker.start(sender_output.clone(), receiver_input);
+                worker.start(sender_output.clone(), receiver_input, collective, sharding);
                 worker
             })
             .collect();
@@ -100,9 +189,19 @@ where
         Self {
             workers,
             receiver: receiver_output,
+            sharding,
+            outdated: true,
+            initialized: false,
         }
     }
 
+    /// Mark the model as updated, so the replicas are synchronized with it before the next step.
+    ///
+    /// This must be called every time the model is modified, normally after each optimizer step.
+    pub fn model_updated(&mut self) {
+        self.outdated = true;
+    }
+
     /// Collect outputs from workers for one step.
     ///
     /// # Arguments
@@ -114,26 +213,270 @@ where
     ///
     /// Outputs.
     pub fn step<'a>(
-        &self,
+        &mut self,
         dataloader: &mut Box<dyn DataLoaderIterator<TI> + 'a>,
         model: &M,
     ) -> Vec<TrainOutput<TO>> {
-        let mut num_send = 0;
+        let mut items = Vec::with_capacity(self.workers.len());
+
+        while items.len() < self.workers.len() {
+            match dataloader.next() {
+                Some(item) => items.push(item),
+                None => break,
+            }
+        }
+
+        let num_items = items.len();
+
+        if num_items == 0 {
+            return Vec::new();
+        }
+
+        // All devices participate in the collective operations, even without an item.
+        let mut items = items.into_iter();
 
-        for worker in self.workers.iter() {
-            if let Some(item) = dataloader.next() {
-                worker.register(item, model);
-                num_send += 1;
+        for (rank, worker) in self.workers.iter().enumerate() {
+            let model = if self.sharding == ShardingStrategy::Params {
+                ModelSync::Gather(model.clone())
+            } else if self.outdated {
+                // Only the first device needs the model to synchronize the replicas, the other
+                // ones receive it with a broadcast once they created their own replica.
+                ModelSync::Broadcast((rank == 0 || !self.initialized).then(|| model.clone()))
+            } else {
+                ModelSync::Replica
+            };
+
+            worker.register(Message {
+                item: items.next(),
+                num_items,
+                model,
+            });
+        }
+
+        self.outdated = false;
+        self.initialized = true;
+
+        let mut outputs = Vec::with_capacity(num_items);
+        let mut shards = Vec::with_capacity(self.workers.len());
+
+        for _ in 0..self.workers.len() {
+            let output = self
+                .receiver
+                .recv()
+                .expect("A training worker failed during the step.");
+
+            match output.item {
+                Some(item) => outputs.push(TrainOutput {
+                    grads: output.grads,
+                    item,
+                }),
+                None => shards.push(output.grads),
             }
         }
 
-        let mut outputs = Vec::with_capacity(num_send);
+        if self.sharding != ShardingStrategy::None {
+            // Each device only received the gradients of the parameters it owns.
+            let mut grads = GradientsParams::new();
+            let outputs_grads = outputs
+                .iter_mut()
+                .map(|output| core::mem::take(&mut output.grads));
 
-        for _ in 0..num_send {
-            let output = self.receiver.recv().unwrap();
-            outputs.push(output);
+            for shard in outputs_grads.chain(shards) {
+                grads = merge_gradients(grads, shard, model);
+            }
+
+            outputs[0].grads = grads;
         }
 
         outputs
     }
 }
+
+/// Multiply every gradient of the given module by a factor.
+pub(crate) fn scale_gradients<B, M>(
+    mut grads: GradientsParams,
+    module: &M,
+    factor: f64,
+) -> GradientsParams
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    let mut visitor = GradientsScale::<B>::new(&mut grads, factor);
+    module.visit(&mut visitor);
+    grads
+}
+
+/// Move the gradients of `other` into `grads`, the gradients of both being disjoint.
+fn merge_gradients<B, M>(
+    grads: GradientsParams,
+    other: GradientsParams,
+    module: &M,
+) -> GradientsParams
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    let mut visitor = GradientsMerge::<B>::new(grads, other);
+    module.visit(&mut visitor);
+    visitor.grads
+}
+
+#[derive(new)]
+struct GradientsMerge<B> {
+    grads: GradientsParams,
+    other: GradientsParams,
+    #[new(default)]
+    phantom: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for GradientsMerge<B> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.other.remove::<B::InnerBackend, D>(id) {
+            self.grads.register::<B::InnerBackend, D>(id, grad);
+        }
+    }
+}
+
+#[derive(new)]
+struct GradientsScale<'a, B> {
+    grads: &'a mut GradientsParams,
+    factor: f64,
+    #[new(default)]
+    phantom: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for GradientsScale<'_, B> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) {
+            self.grads
+                .register::<B::InnerBackend, D>(id, grad.mul_scalar(self.factor));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_core::data::dataloader::Progress;
+    use burn_core::module::Module;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::tensor::TensorData;
+    use burn_core::test_utils::Fill;
+
+    type B = TestAutodiffBackend;
+
+    impl TrainStep<Tensor<B, 2>, ()> for Linear<B> {
+        fn step(&self, item: Tensor<B, 2>) -> TrainOutput<()> {
+            let loss = self.forward(item).powf_scalar(2.0).sum();
+            TrainOutput::new(self, loss.backward(), ())
+        }
+    }
+
+    struct Items(std::array::IntoIter<Tensor<B, 2>, 3>);
+
+    impl Iterator for Items {
+        type Item = Tensor<B, 2>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.0.next()
+        }
+    }
+
+    impl DataLoaderIterator<Tensor<B, 2>> for Items {
+        fn progress(&self) -> Progress {
+            Progress::new(0, 0)
+        }
+    }
+
+    #[test]
+    fn outputs_should_hold_gradients_averaged_between_devices() {
+        let device = Default::default();
+        let model = LinearConfig::new(2, 2)
+            .init::<B>(&device)
+            .map(&mut Fill(1.0));
+        let items = [[[1.0, 2.0]], [[3.0, 4.0]], [[5.0, 6.0]]]
+            .map(|item| Tensor::<B, 2>::from_floats(item, &device));
+        let mut iterator: Box<dyn DataLoaderIterator<_>> = Box::new(Items(items.into_iter()));
+
+        // Two replicas of the ndarray device, the second step only having one item.
+        let mut step = MultiDevicesTrainStep::new(&[device, device], ShardingStrategy::None);
+
+        let outputs = step.step(&mut iterator, &model);
+        assert_eq!(outputs.len(), 2);
+        let expected = TensorData::from([[28.0f32, 28.0], [40.0, 40.0]]);
+        for output in outputs {
+            let grad = output.grads.get::<TestBackend, 2>(model.weight.id).unwrap();
+            grad.into_data().assert_eq(&expected, false);
+        }
+
+        let outputs = step.step(&mut iterator, &model);
+        assert_eq!(outputs.len(), 1);
+        let expected = TensorData::from([[120.0f32, 120.0], [144.0, 144.0]]);
+        let grad = outputs[0]
+            .grads
+            .get::<TestBackend, 2>(model.weight.id)
+            .unwrap();
+        grad.into_data().assert_eq(&expected, false);
+
+        assert!(step.step(&mut iterator, &model).is_empty());
+    }
+
+    #[test]
+    fn sharded_gradients_should_be_merged_in_the_first_output() {
+        let device = Default::default();
+        let model = LinearConfig::new(2, 3)
+            .init::<B>(&device)
+            .map(&mut Fill(1.0));
+        let items = [[[1.0, 2.0]], [[3.0, 4.0]], [[5.0, 6.0]]]
+            .map(|item| Tensor::<B, 2>::from_floats(item, &device));
+        let mut iterator: Box<dyn DataLoaderIterator<_>> = Box::new(Items(items.into_iter()));
+        let mut step =
+            MultiDevicesTrainStep::new(&[device, device], ShardingStrategy::OptimizerState);
+
+        // The second device doesn't have an item, but still owns some of the parameters.
+        step.step(&mut iterator, &model);
+        let outputs = step.step(&mut iterator, &model);
+        assert_eq!(outputs.len(), 1);
+
+        let grads = &outputs[0].grads;
+        assert_eq!(grads.len(), 2);
+        let weight = grads.get::<TestBackend, 2>(model.weight.id).unwrap();
+        assert_eq!(weight.dims(), [2, 3]);
+        weight
+            .into_data()
+            .assert_eq(&TensorData::from([[120.0f32; 3], [144.0; 3]]), false);
+        let bias = model.bias.as_ref().unwrap();
+        let bias = grads.get::<TestBackend, 1>(bias.id).unwrap();
+        assert_eq!(bias.dims(), [3]);
+        bias.into_data()
+            .assert_eq(&TensorData::from([24.0f32; 3]), false);
+    }
+
+    #[test]
+    fn sharded_params_should_be_gathered_for_each_step() {
+        let device = Default::default();
+        let model = LinearConfig::new(2, 2)
+            .init::<B>(&device)
+            .map(&mut Fill(0.0));
+        let items = [[[1.0, 2.0]], [[3.0, 4.0]], [[5.0, 6.0]]]
+            .map(|item| Tensor::<B, 2>::from_floats(item, &device));
+        let mut iterator: Box<dyn DataLoaderIterator<_>> = Box::new(Items(items.into_iter()));
+        let mut step = MultiDevicesTrainStep::new(&[device, device], ShardingStrategy::Params);
+
+        step.step(&mut iterator, &model);
+
+        // The model changed without notifying the step, the gradients must still be computed
+        // with the new weights.
+        let model = model.map(&mut Fill(1.0));
+        let outputs = step.step(&mut iterator, &model);
+
+        let expected = TensorData::from([[120.0f32, 120.0], [144.0, 144.0]]);
+        let grad = outputs[0]
+            .grads
+            .get::<TestBackend, 2>(model.weight.id)
+            .unwrap();
+        grad.into_data().assert_eq(&expected, false);
+    }
+}
@@ -1,10 +1,11 @@
 use crate::components::LearnerComponents;
+use crate::learner::callback::CallbacksWithEma;
 use crate::metric::processor::EventProcessor;
-use crate::{Learner, TrainEpoch, ValidEpoch};
+use crate::{CallbackContext, Learner, LearnerCallback, TrainEpoch, ValidEpoch};
 use burn_core::data::dataloader::DataLoader;
 use burn_core::module::{AutodiffModule, Module};
-use burn_core::optim::{GradientsParams, Optimizer};
-use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::optim::{GradientsParams, ModelEma, Optimizer};
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
 use std::sync::Arc;
 
 /// A training output.
@@ -144,7 +145,24 @@ impl<LC: LearnerComponents> Learner<LC> {
             None => 1,
         };
 
+        let mut ema = self.ema.as_ref().map(|config| config.init(&self.model));
+        if let (Some(checkpoint), Some(checkpointer)) = (self.checkpoint, &self.checkpointer) {
+            ema = ema
+                .map(|ema| checkpointer.load_ema_checkpoint(ema, &Default::default(), checkpoint));
+        }
+
+        let mut current_epoch = starting_epoch;
+        let mut ctx = CallbackContext::new(
+            &mut self.model,
+            current_epoch,
+            self.num_epochs,
+            0,
+            &self.interrupter,
+        );
+        self.callbacks.on_train_begin(&mut ctx);
+
         for epoch in starting_epoch..self.num_epochs + 1 {
+            current_epoch = epoch;
             let epoch_train = TrainEpoch::new(
                 dataloader_train.clone(),
                 epoch,
@@ -152,13 +170,24 @@ impl<LC: LearnerComponents> Learner<LC> {
                 self.grad_accumulation,
             );
 
+            let mut ctx = CallbackContext::new(
+                &mut self.model,
+                epoch,
+                self.num_epochs,
+                0,
+                &self.interrupter,
+            );
+            self.callbacks.on_epoch_start(&mut ctx);
+
             if self.devices.len() > 1 {
                 (self.model, self.optim) = epoch_train.run_multi_device::<LC, OutputTrain>(
                     self.model,
                     self.optim,
                     &mut self.lr_scheduler,
                     &mut self.event_processor,
+                    &mut CallbacksWithEma::new(&mut self.callbacks, ema.as_mut()),
                     self.devices.clone(),
+                    self.sharding,
                     &self.interrupter,
                 )
             } else {
@@ -167,6 +196,7 @@ impl<LC: LearnerComponents> Learner<LC> {
                     self.optim,
                     &mut self.lr_scheduler,
                     &mut self.event_processor,
+                    &mut CallbacksWithEma::new(&mut self.callbacks, ema.as_mut()),
                     &self.interrupter,
                 );
             }
@@ -175,21 +205,52 @@ impl<LC: LearnerComponents> Learner<LC> {
                 break;
             }
 
+            let mut ctx = CallbackContext::new(
+                &mut self.model,
+                epoch,
+                self.num_epochs,
+                0,
+                &self.interrupter,
+            );
+            self.callbacks.on_epoch_end(&mut ctx);
+
             let epoch_valid = ValidEpoch::new(dataloader_valid.clone(), epoch, self.num_epochs);
             epoch_valid.run::<LC, OutputValid>(
-                &self.model,
+                evaluated_model(&self.model, &ema),
                 &mut self.event_processor,
                 &self.interrupter,
             );
 
+            let mut ctx = CallbackContext::new(
+                &mut self.model,
+                epoch,
+                self.num_epochs,
+                0,
+                &self.interrupter,
+            );
+            self.callbacks
+                .on_validation_end(&mut ctx, &self.event_store);
+
             if let Some(checkpointer) = &mut self.checkpointer {
-                checkpointer.checkpoint(
+                let saved = checkpointer.checkpoint(
                     &self.model,
+                    ema.as_ref(),
                     &self.optim,
                     &self.lr_scheduler,
                     epoch,
                     &self.event_store,
                 );
+
+                if saved {
+                    let mut ctx = CallbackContext::new(
+                        &mut self.model,
+                        epoch,
+                        self.num_epochs,
+                        0,
+                        &self.interrupter,
+                    );
+                    self.callbacks.on_checkpoint(&mut ctx);
+                }
             }
 
             if let Some(early_stopping) = &mut self.early_stopping {
@@ -199,6 +260,19 @@ impl<LC: LearnerComponents> Learner<LC> {
             }
         }
 
+        let mut ctx = CallbackContext::new(
+            &mut self.model,
+            current_epoch,
+            self.num_epochs,
+            0,
+            &self.interrupter,
+        );
+        self.callbacks.on_train_end(&mut ctx);
+
+        if let Some(ema) = ema {
+            self.model = ema.into_model();
+        }
+
         // Display learner summary
         if let Some(summary) = self.summary {
             match summary.init() {
@@ -215,3 +289,15 @@ impl<LC: LearnerComponents> Learner<LC> {
         self.model
     }
 }
+
+/// When the exponential moving average is enabled, the averaged model is evaluated instead of the
+/// trained one.
+fn evaluated_model<'a, B: Backend, M: Module<B>>(
+    model: &'a M,
+    ema: &'a Option<ModelEma<B, M>>,
+) -> &'a M {
+    match ema {
+        Some(ema) => ema.model(),
+        None => model,
+    }
+}
@@ -9,6 +9,9 @@ extern crate derive_new;
 /// The checkpoint module.
 pub mod checkpoint;
 
+/// Collective operations to synchronize tensors between devices.
+pub mod collective;
+
 pub(crate) mod components;
 
 /// Renderer modules to display metrics and training information.
@@ -27,6 +30,9 @@ pub use learner::*;
 #[cfg(test)]
 pub(crate) type TestBackend = burn_ndarray::NdArray<f32>;
 
+#[cfg(test)]
+pub(crate) type TestAutodiffBackend = burn_autodiff::Autodiff<TestBackend>;
+
 #[cfg(test)]
 pub(crate) mod tests {
     use crate::TestBackend;
@@ -109,6 +109,9 @@ pub fn morph<B: Backend, K: BasicOps<B>>(
         }
         DType::U8 => morph_typed::<B, K, u8>(data, shape, kernel, op, iter, btype, bvalue, &device),
         DType::Bool => morph_bool::<B, K>(data, shape, kernel, op, iter, btype, bvalue, &device),
+        DType::Complex32 | DType::Complex64 => {
+            panic!("Morphology is not supported for complex tensors")
+        }
         DType::QFloat(scheme) => match scheme {
             QuantizationScheme::PerTensor(_mode, QuantizationType::QInt8)
             | QuantizationScheme::PerBlock(_mode, QuantizationType::QInt8, ..) => {
@@ -285,6 +285,7 @@ pub enum NodeType {
     AveragePool,
     AveragePool1d,
     AveragePool2d,
+    AveragePool3d,
     BatchNormalization,
     Bernoulli,
     BitShift,
@@ -375,6 +376,7 @@ pub enum NodeType {
     MaxPool,
     MaxPool1d,
     MaxPool2d,
+    MaxPool3d,
     MaxRoiPool,
     MaxUnpool,
     Mean,
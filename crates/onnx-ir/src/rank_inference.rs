@@ -17,6 +17,7 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::ArgMax => argmax_update_outputs(node),
         NodeType::AveragePool1d => same_as_input(node),
         NodeType::AveragePool2d => same_as_input(node),
+        NodeType::AveragePool3d => same_as_input(node),
         NodeType::BatchNormalization => same_as_input(node),
         NodeType::Cast => cast_update_outputs(node),
         NodeType::Clip => same_as_input(node),
@@ -26,6 +27,7 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::Conv1d => conv1d_update_outputs(node),
         NodeType::Conv2d => conv2d_update_outputs(node),
         NodeType::Cos => same_as_input(node),
+        NodeType::DepthToSpace => depth_to_space_update_outputs(node),
         NodeType::Div => same_as_input_broadcast(node),
         NodeType::Dropout => same_as_input(node),
         NodeType::Equal => elementwise_comparison_outputs(node),
@@ -55,6 +57,7 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::Max => same_as_input_broadcast(node),
         NodeType::MaxPool1d => same_as_input(node),
         NodeType::MaxPool2d => same_as_input(node),
+        NodeType::MaxPool3d => same_as_input(node),
         NodeType::Min => same_as_input_broadcast(node),
         NodeType::Mul => same_as_input(node),
         NodeType::Neg => same_as_input(node),
@@ -83,6 +86,7 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::Sin => same_as_input(node),
         NodeType::Slice => same_as_input(node),
         NodeType::Softmax => same_as_input(node),
+        NodeType::SpaceToDepth => space_to_depth_update_outputs(node),
         NodeType::Split => split_update_outputs(node),
         NodeType::Squeeze => squeeze_update_output(node),
         NodeType::Sqrt => same_as_input(node),
@@ -511,6 +515,60 @@ fn same_as_input(node: &mut Node) {
     node.outputs[0].ty = node.inputs[0].ty.clone();
 }
 
+/// Update the output of the DepthToSpace node, moving the channels into blocks of pixels.
+fn depth_to_space_update_outputs(node: &mut Node) {
+    let block_size = node
+        .attrs
+        .get("blocksize")
+        .map(|value| value.clone().into_i64() as usize)
+        .expect("DepthToSpace: blocksize must be present");
+
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("DepthToSpace: invalid input type"),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: tensor.elem_type.clone(),
+        rank: tensor.rank,
+        shape: tensor.shape.as_ref().map(|shape| {
+            vec![
+                shape[0],
+                shape[1] / (block_size * block_size),
+                shape[2] * block_size,
+                shape[3] * block_size,
+            ]
+        }),
+    });
+}
+
+/// Update the output of the SpaceToDepth node, moving the blocks of pixels into the channels.
+fn space_to_depth_update_outputs(node: &mut Node) {
+    let block_size = node
+        .attrs
+        .get("blocksize")
+        .map(|value| value.clone().into_i64() as usize)
+        .expect("SpaceToDepth: blocksize must be present");
+
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("SpaceToDepth: invalid input type"),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: tensor.elem_type.clone(),
+        rank: tensor.rank,
+        shape: tensor.shape.as_ref().map(|shape| {
+            vec![
+                shape[0],
+                shape[1] * block_size * block_size,
+                shape[2] / block_size,
+                shape[3] / block_size,
+            ]
+        }),
+    });
+}
+
 fn top_k_update_output(node: &mut Node) {
     let rank = match &node.inputs[0].ty {
         ArgType::Tensor(tensor) => tensor.rank,